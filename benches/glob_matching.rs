@@ -0,0 +1,105 @@
+//! Ignore pattern matching over a synthetic tree of 10k paths.
+//!
+//! Compares compiling each pattern per path, checking precompiled patterns
+//! one by one, and the `IgnoreMatcher` the file scanner uses.
+//!
+//! Run with `cargo bench --bench glob_matching`.
+
+use std::path::{Path, PathBuf};
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use glob::Pattern;
+use glot::{config::TEST_FILE_PATTERNS, core::IgnoreMatcher};
+
+const BASE_DIR: &str = "/repo";
+const PATH_COUNT: usize = 10_000;
+
+fn synthetic_paths() -> Vec<PathBuf> {
+    let dirs = [
+        "src/app/[locale]",
+        "src/components/ui",
+        "src/components/forms",
+        "src/lib",
+        "src/__tests__",
+        "node_modules/react/cjs",
+        "src/generated",
+    ];
+    let files = [
+        "page.tsx",
+        "Button.tsx",
+        "Button.stories.tsx",
+        "utils.ts",
+        "utils.test.ts",
+        "index.js",
+    ];
+    (0..PATH_COUNT)
+        .map(|i| {
+            let dir = dirs[i % dirs.len()];
+            let file = files[(i / dirs.len()) % files.len()];
+            Path::new(BASE_DIR)
+                .join(dir)
+                .join(format!("m{}", i % 97))
+                .join(file)
+        })
+        .collect()
+}
+
+fn ignore_patterns() -> Vec<String> {
+    ["**/node_modules/**", "**/*.stories.tsx", "src/generated"]
+        .iter()
+        .map(|p| p.to_string())
+        .collect()
+}
+
+fn glob_matching(c: &mut Criterion) {
+    let paths = synthetic_paths();
+    let ignores = ignore_patterns();
+    let globs: Vec<&str> = ignores
+        .iter()
+        .map(String::as_str)
+        .filter(|p| p.contains('*'))
+        .chain(TEST_FILE_PATTERNS.iter().copied())
+        .collect();
+    let literal = Path::new(BASE_DIR).join("src/generated");
+
+    let mut group = c.benchmark_group("glob_matching");
+    group.throughput(Throughput::Elements(PATH_COUNT as u64));
+
+    group.bench_function("compile per path", |b| {
+        b.iter(|| {
+            paths
+                .iter()
+                .filter(|path| {
+                    let path_str = path.to_string_lossy();
+                    path.starts_with(&literal)
+                        || globs
+                            .iter()
+                            .any(|p| Pattern::new(p).unwrap().matches(&path_str))
+                })
+                .count()
+        })
+    });
+
+    let compiled: Vec<Pattern> = globs.iter().map(|p| Pattern::new(p).unwrap()).collect();
+    group.bench_function("precompiled patterns", |b| {
+        b.iter(|| {
+            paths
+                .iter()
+                .filter(|path| {
+                    let path_str = path.to_string_lossy();
+                    path.starts_with(&literal) || compiled.iter().any(|p| p.matches(&path_str))
+                })
+                .count()
+        })
+    });
+
+    let matcher = IgnoreMatcher::new(BASE_DIR, &ignores, true, false);
+    group.bench_function("IgnoreMatcher", |b| {
+        b.iter(|| paths.iter().filter(|path| matcher.is_ignored(path)).count())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, glob_matching);
+criterion_main!(benches);
@@ -0,0 +1,142 @@
+//! Library entry point.
+//!
+//! `analyze()` runs the default checks of a config on in-memory source files
+//! and returns the issues, without the CLI layer: no arguments are parsed, no
+//! config file is searched and nothing is printed. Message files are still
+//! loaded from the messages root of the config.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::Result;
+
+use crate::{
+    cli::commands::check::{CheckRule, collect_issues},
+    config::Config,
+    core::CheckContext,
+    issues::{Issue, Severity},
+};
+
+/// Issues found by `analyze()`.
+#[derive(Debug)]
+pub struct RunResult {
+    /// Issues of the default checks of the config, sorted, parse errors last.
+    pub issues: Vec<Issue>,
+    /// Number of source files analyzed.
+    pub source_files: usize,
+    /// Number of locales whose messages were loaded.
+    pub locale_files: usize,
+}
+
+impl RunResult {
+    /// Whether any issue is an error, after the `severities` of `config`.
+    pub fn has_errors(&self, config: &Config) -> bool {
+        self.issues.iter().any(|issue| {
+            config.severity_for_rule(issue.rule(), issue.severity()) == Severity::Error
+        })
+    }
+}
+
+/// Check in-memory source files against the messages of `config`.
+///
+/// `files` maps paths to file contents; relative paths, like the messages
+/// root, are resolved against `root_dir`. Only the given files are analyzed,
+/// so registries (key objects, translation props, schemas) come from them
+/// alone. Runs the checks `glot check` runs without arguments.
+///
+/// # Errors
+///
+/// Returns error if the primary locale messages are not found.
+pub fn analyze(
+    config: Config,
+    root_dir: impl Into<PathBuf>,
+    files: HashMap<String, String>,
+) -> Result<RunResult> {
+    let ctx = CheckContext::from_sources(config, root_dir, files)?;
+    let issues = collect_issues(&ctx, &CheckRule::defaults(&ctx.config));
+    Ok(RunResult {
+        issues,
+        source_files: ctx.files.len(),
+        locale_files: ctx.messages().all_messages.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::issues::Rule;
+
+    #[test]
+    fn test_analyze_in_memory_files() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("messages")).unwrap();
+        fs::write(
+            dir.path().join("messages/en.json"),
+            r#"{"Common": {"submit": "Submit", "old": "Old"}}"#,
+        )
+        .unwrap();
+
+        let files = HashMap::from([(
+            "src/form.tsx".to_string(),
+            r#"import { useTranslations } from "next-intl";
+
+export function Form() {
+    const t = useTranslations("Common");
+    return (
+        <form>
+            <button>{t("submit")}</button>
+            <button>{t("cancel")}</button>
+            <p>Hello</p>
+        </form>
+    );
+}
+"#
+            .to_string(),
+        )]);
+
+        let config = Config::default();
+        let result = analyze(config.clone(), dir.path(), files).unwrap();
+        assert_eq!(result.source_files, 1);
+        assert_eq!(result.locale_files, 1);
+        let rules: Vec<Rule> = result.issues.iter().map(Issue::rule).collect();
+        assert_eq!(
+            rules,
+            vec![Rule::UnusedKey, Rule::MissingKey, Rule::HardcodedText]
+        );
+        assert!(result.has_errors(&config));
+    }
+
+    #[test]
+    fn test_t_has_method_call() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("messages")).unwrap();
+        fs::write(
+            dir.path().join("messages/en.json"),
+            r#"{"Common": {"beta": "Beta", "old": "Old"}}"#,
+        )
+        .unwrap();
+
+        let files = HashMap::from([(
+            "src/banner.tsx".to_string(),
+            r#"import { useTranslations } from "next-intl";
+
+export function Banner() {
+    const t = useTranslations("Common");
+    return t.has("beta") ? <p>{t("beta")}</p> : null;
+}
+
+export function Legacy() {
+    const t = useTranslations("Common");
+    return t.has("old") ? <span /> : null;
+}
+"#
+            .to_string(),
+        )]);
+
+        let result = analyze(Config::default(), dir.path(), files).unwrap();
+        assert!(result.issues.is_empty(), "{:?}", result.issues);
+    }
+}
@@ -3,39 +3,80 @@
 //! Deletes unused or orphan translation keys from JSON files.
 //! Used by the `glot clean` command.
 
+use anyhow::Result;
+
+use crate::core::MessageContext;
 use crate::issues::{OrphanKeyIssue, UnusedKeyIssue};
 
 use super::operation::{DeleteReason, Operation};
-use super::traits::Action;
+use super::traits::{ActionStats, execute_operations, plan_operations};
+
+/// An issue whose message key can be deleted.
+pub trait DeletableKey {
+    /// Reason shown in the preview.
+    const REASON: DeleteReason;
+
+    /// The message key to delete.
+    fn context(&self) -> &MessageContext;
+}
+
+impl DeletableKey for UnusedKeyIssue {
+    const REASON: DeleteReason = DeleteReason::Unused;
+
+    fn context(&self) -> &MessageContext {
+        &self.context
+    }
+}
+
+impl DeletableKey for OrphanKeyIssue {
+    const REASON: DeleteReason = DeleteReason::Orphan;
+
+    fn context(&self) -> &MessageContext {
+        &self.context
+    }
+}
 
 /// Action to delete translation keys from JSON files.
 ///
 /// This action supports multiple Issue types:
 /// - `UnusedKeyIssue`: keys defined but not used in code
 /// - `OrphanKeyIssue`: keys in non-primary locale but not in primary
+///
+/// Unlike the `Action` implementations, every method takes the key separator,
+/// which splits a key into the path of nested objects to delete.
 pub struct DeleteKey;
 
-impl Action<UnusedKeyIssue> for DeleteKey {
-    fn to_operations(issues: &[UnusedKeyIssue]) -> Vec<Operation> {
+impl DeleteKey {
+    /// Convert issues to one delete operation each.
+    pub fn to_operations<I: DeletableKey>(issues: &[I], key_separator: &str) -> Vec<Operation> {
         issues
             .iter()
             .map(|issue| Operation::DeleteJsonKey {
-                context: issue.context.clone(),
-                reason: DeleteReason::Unused,
+                context: issue.context().clone(),
+                reason: I::REASON,
+                key_separator: key_separator.to_string(),
             })
             .collect()
     }
-}
 
-impl Action<OrphanKeyIssue> for DeleteKey {
-    fn to_operations(issues: &[OrphanKeyIssue]) -> Vec<Operation> {
-        issues
-            .iter()
-            .map(|issue| Operation::DeleteJsonKey {
-                context: issue.context.clone(),
-                reason: DeleteReason::Orphan,
-            })
-            .collect()
+    /// Delete the keys from their files.
+    pub fn run<I: DeletableKey>(issues: &[I], key_separator: &str) -> Result<ActionStats> {
+        execute_operations(&Self::to_operations(issues, key_separator))
+    }
+
+    /// Plan the deletion without modifying files.
+    ///
+    /// Runs the same operations as `run` on in-memory copies of the files, so
+    /// the stats are exactly what `run` would apply.
+    pub fn plan<I: DeletableKey>(issues: &[I], key_separator: &str) -> Result<ActionStats> {
+        plan_operations(&Self::to_operations(issues, key_separator))
+    }
+
+    /// Preview the keys that would be deleted.
+    pub fn preview<I: DeletableKey>(issues: &[I], key_separator: &str) {
+        for op in Self::to_operations(issues, key_separator) {
+            op.preview();
+        }
     }
 }
 
@@ -48,13 +89,18 @@ mod tests {
     fn test_unused_key_to_operations() {
         let loc = MessageLocation::new("./messages/en.json", 5, 3);
         let ctx = MessageContext::new(loc, "Common.unused", "Unused value");
-        let issue = UnusedKeyIssue { context: ctx };
+        let issue = UnusedKeyIssue {
+            context: ctx,
+            hint: None,
+        };
 
-        let ops = DeleteKey::to_operations(&[issue]);
+        let ops = DeleteKey::to_operations(&[issue], ".");
 
         assert_eq!(ops.len(), 1);
         match &ops[0] {
-            Operation::DeleteJsonKey { context, reason } => {
+            Operation::DeleteJsonKey {
+                context, reason, ..
+            } => {
                 assert_eq!(context.file_path(), "./messages/en.json");
                 assert_eq!(context.key, "Common.unused");
                 assert_eq!(*reason, DeleteReason::Unused);
@@ -72,11 +118,13 @@ mod tests {
             locale: "zh".to_string(),
         };
 
-        let ops = DeleteKey::to_operations(&[issue]);
+        let ops = DeleteKey::to_operations(&[issue], ".");
 
         assert_eq!(ops.len(), 1);
         match &ops[0] {
-            Operation::DeleteJsonKey { context, reason } => {
+            Operation::DeleteJsonKey {
+                context, reason, ..
+            } => {
                 assert_eq!(context.file_path(), "./messages/zh.json");
                 assert_eq!(context.key, "Common.orphan");
                 assert_eq!(*reason, DeleteReason::Orphan);
@@ -89,13 +137,19 @@ mod tests {
     fn test_multiple_issues() {
         let loc1 = MessageLocation::new("./messages/en.json", 5, 3);
         let ctx1 = MessageContext::new(loc1, "Common.a", "A");
-        let issue1 = UnusedKeyIssue { context: ctx1 };
+        let issue1 = UnusedKeyIssue {
+            context: ctx1,
+            hint: None,
+        };
 
         let loc2 = MessageLocation::new("./messages/en.json", 10, 3);
         let ctx2 = MessageContext::new(loc2, "Common.b", "B");
-        let issue2 = UnusedKeyIssue { context: ctx2 };
+        let issue2 = UnusedKeyIssue {
+            context: ctx2,
+            hint: None,
+        };
 
-        let ops = DeleteKey::to_operations(&[issue1, issue2]);
+        let ops = DeleteKey::to_operations(&[issue1, issue2], ".");
 
         assert_eq!(ops.len(), 2);
     }
@@ -80,6 +80,8 @@ mod tests {
             ),
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         }
     }
 
@@ -1,14 +1,25 @@
 //! InsertMessageKeys action.
 //!
 //! Inserts `glot-message-keys` comments for dynamic translation keys.
-//! Used by the `glot fix` command.
+//! Used by the `glot fix` command and the MCP `apply_fix` tool.
+
+use std::fs;
 
 use crate::core::CommentStyle;
 use crate::issues::{Rule, UnresolvedKeyIssue};
 
-use super::operation::Operation;
+use super::operation::{Operation, extract_message_key_patterns, format_message_keys};
 use super::traits::{Action, ActionStats, execute_operations};
 
+/// Outcome of annotating a single dynamic key with `InsertMessageKeys::annotate`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnotateOutcome {
+    /// The comment was inserted above the issue line.
+    Inserted { comment: String },
+    /// A `glot-message-keys` comment already annotates the line; nothing was changed.
+    Conflict { existing: String },
+}
+
 /// Action to insert `glot-message-keys` comments.
 ///
 /// This action processes `UnresolvedKeyIssue` that have a pattern field,
@@ -18,12 +29,44 @@ pub struct InsertMessageKeys;
 impl InsertMessageKeys {
     /// Format a message-keys comment for the given pattern.
     fn format_comment(pattern: &str, comment_style: CommentStyle) -> String {
-        let directive = format!("glot-message-keys \"{}\"", pattern);
-        match comment_style {
-            CommentStyle::Js => format!("// {}", directive),
-            CommentStyle::Jsx => format!("{{/* {} */}}", directive),
-            CommentStyle::Html => format!("<!-- {} -->", directive),
+        format_message_keys(&[pattern.to_string()], comment_style)
+    }
+
+    /// Insert a comment declaring `keys` above a single issue.
+    ///
+    /// Unlike `run`, the keys are given by the caller instead of the issue's
+    /// pattern. The comment style follows the issue (JSX vs JS). If the line
+    /// above already holds a `glot-message-keys` comment, the file is left
+    /// untouched, since merging would change what that annotation declares.
+    pub fn annotate(
+        issue: &UnresolvedKeyIssue,
+        keys: &[String],
+    ) -> anyhow::Result<AnnotateOutcome> {
+        if let Some(existing) =
+            Self::existing_annotation(issue.context.file_path(), issue.context.line())?
+        {
+            return Ok(AnnotateOutcome::Conflict { existing });
         }
+
+        let comment = format_message_keys(keys, issue.context.comment_style);
+        execute_operations(&[Operation::InsertComment {
+            context: issue.context.clone(),
+            comment: comment.clone(),
+            rule: Rule::UnresolvedKey,
+        }])?;
+        Ok(AnnotateOutcome::Inserted { comment })
+    }
+
+    /// The `glot-message-keys` comment directly above `line`, if any.
+    ///
+    /// An annotated line no longer produces an unresolved key issue, so callers
+    /// that look issues up by line check this first.
+    pub fn existing_annotation(file_path: &str, line: usize) -> anyhow::Result<Option<String>> {
+        let content = fs::read_to_string(file_path)?;
+        let above = line.checked_sub(2).and_then(|idx| content.lines().nth(idx));
+        Ok(above
+            .filter(|line| extract_message_key_patterns(line).is_some())
+            .map(|line| line.trim().to_string()))
     }
 }
 
@@ -149,4 +192,57 @@ mod tests {
 
         assert_eq!(ops.len(), 1); // Only one has pattern
     }
+
+    fn issue_in(path: &std::path::Path, line: usize, style: CommentStyle) -> UnresolvedKeyIssue {
+        let loc = SourceLocation::new(path.to_string_lossy(), line, 5);
+        UnresolvedKeyIssue {
+            context: SourceContext::new(loc, "t(key)", style),
+            reason: IssueUnresolvedKeyReason::VariableKey,
+            hint: None,
+            pattern: None,
+        }
+    }
+
+    #[test]
+    fn test_annotate_inserts_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.tsx");
+        fs::write(&path, "const a = 1;\n    t(key);\n").unwrap();
+
+        let keys = vec!["Common.a".to_string(), "Common.b".to_string()];
+        let outcome =
+            InsertMessageKeys::annotate(&issue_in(&path, 2, CommentStyle::Js), &keys).unwrap();
+
+        let comment = "// glot-message-keys \"Common.a\", \"Common.b\"";
+        assert_eq!(
+            outcome,
+            AnnotateOutcome::Inserted {
+                comment: comment.to_string()
+            }
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("const a = 1;\n    {}\n    t(key);\n", comment)
+        );
+    }
+
+    #[test]
+    fn test_annotate_refuses_existing_annotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.tsx");
+        let content = "{/* glot-message-keys \"Common.*\" */}\n<p>{t(key)}</p>\n";
+        fs::write(&path, content).unwrap();
+
+        let keys = vec!["Common.a".to_string()];
+        let outcome =
+            InsertMessageKeys::annotate(&issue_in(&path, 2, CommentStyle::Jsx), &keys).unwrap();
+
+        assert_eq!(
+            outcome,
+            AnnotateOutcome::Conflict {
+                existing: "{/* glot-message-keys \"Common.*\" */}".to_string()
+            }
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), content);
+    }
 }
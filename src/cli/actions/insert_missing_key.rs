@@ -0,0 +1,500 @@
+//! InsertMissingKey action.
+//!
+//! Inserts placeholder entries for missing keys into the primary locale file.
+//! Used by `glot fix --create-missing`. With `glot fix --fill`, the other
+//! locales are backfilled too.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use clap::ValueEnum;
+
+use crate::core::{AllLocaleMessages, LocaleMessages, MessageContext, MessageLocation, ValueType};
+use crate::issues::{MissingKeyIssue, ReplicaLagIssue, Rule};
+use crate::rules::helpers::is_source_locale;
+
+use super::operation::Operation;
+use super::traits::{ActionStats, execute_operations};
+
+/// Value given to a key created in a locale (`--fill`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FillPolicy {
+    /// Insert an empty string.
+    Empty,
+    /// Copy the value of the primary locale.
+    Source,
+    /// Insert `[TODO] <key>`.
+    Todo,
+}
+
+impl FillPolicy {
+    /// Value for `key`, given the value the primary locale has (or gets).
+    fn value(self, key: &str, source: &str) -> String {
+        match self {
+            FillPolicy::Empty => String::new(),
+            FillPolicy::Source => source.to_string(),
+            FillPolicy::Todo => format!("[TODO] {}", key),
+        }
+    }
+}
+
+/// Where the other locales are backfilled from.
+pub struct Backfill<'a> {
+    pub all_messages: &'a AllLocaleMessages,
+    pub primary_locale: &'a str,
+    pub fallback_locales: &'a [String],
+    /// Separator between key segments.
+    pub key_separator: &'a str,
+    /// Keys missing from locales other than the sources.
+    pub lags: &'a [ReplicaLagIssue],
+}
+
+/// Action to insert missing keys into the primary locale file.
+///
+/// Unlike the other actions, the target file is not part of the issue (which
+/// points at the source usage), so every method takes the primary locale and
+/// the key separator.
+/// When the locale is split into namespace files (`messagePattern`), each key
+/// goes to the file of its first segment. The placeholder value is the last
+/// segment of the key, which keeps the UI readable until a real translation
+/// is written. The `FillPolicy` only applies to the other locales.
+///
+/// Backfilling works on (locale, key) pairs: every created key, and every
+/// replica-lag key, is inserted into each non-source locale that lacks it.
+/// Pairs whose key exists by then are left alone, so running it twice
+/// changes nothing.
+pub struct InsertMissingKey;
+
+impl InsertMissingKey {
+    /// Convert missing key issues to one insert operation per distinct key.
+    ///
+    /// Operations are sorted by key so repeated runs produce the same output.
+    /// Keys without a matching namespace file are left out (see `skipped_keys`).
+    pub fn to_operations(
+        issues: &[MissingKeyIssue],
+        locale: &LocaleMessages,
+        key_separator: &str,
+    ) -> Vec<Operation> {
+        Self::distinct_keys(issues)
+            .into_iter()
+            .filter_map(|key| {
+                let (file_path, namespace) = Self::target(locale, key, key_separator)?;
+                let context = MessageContext::new(
+                    MessageLocation::with_line(file_path, 0),
+                    key,
+                    Self::primary_value(key, key_separator),
+                )
+                .with_namespace(namespace);
+                Some(Operation::InsertJsonKey {
+                    context,
+                    rule: Rule::MissingKey,
+                    key_separator: key_separator.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Insert the missing keys into the locale file.
+    pub fn run(
+        issues: &[MissingKeyIssue],
+        locale: &LocaleMessages,
+        key_separator: &str,
+    ) -> anyhow::Result<ActionStats> {
+        let ops = Self::to_operations(issues, locale, key_separator);
+        let mut stats = execute_operations(&ops)?;
+        stats.skipped = Self::skipped_keys(issues, locale, key_separator).len();
+        Ok(stats)
+    }
+
+    /// Preview the keys that would be inserted.
+    pub fn preview(issues: &[MissingKeyIssue], locale: &LocaleMessages, key_separator: &str) {
+        for op in Self::to_operations(issues, locale, key_separator) {
+            op.preview();
+        }
+    }
+
+    /// Convert the missing (locale, key) pairs of the other locales to insert
+    /// operations, sorted by locale and then in the order of the primary
+    /// file: replica-lag keys by line, followed by the created keys, which
+    /// are appended to the primary file in key order.
+    ///
+    /// `issues` are the keys created in the primary locale (those without a
+    /// primary file are left out). Array values are not backfilled, since
+    /// only strings can be inserted. Pairs without a matching namespace file
+    /// are left out too (see `backfill_skipped`).
+    pub fn backfill_operations(
+        issues: &[MissingKeyIssue],
+        backfill: &Backfill,
+        fill: FillPolicy,
+    ) -> Vec<Operation> {
+        Self::backfill_pairs(issues, backfill, fill)
+            .into_iter()
+            .filter_map(|((locale, _, key), value)| {
+                let (file_path, namespace) = Self::target(
+                    backfill.all_messages.get(locale)?,
+                    key,
+                    backfill.key_separator,
+                )?;
+                let context =
+                    MessageContext::new(MessageLocation::with_line(file_path, 0), key, value)
+                        .with_namespace(namespace);
+                Some(Operation::InsertJsonKey {
+                    context,
+                    rule: Rule::ReplicaLag,
+                    key_separator: backfill.key_separator.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Insert the missing keys into the other locales.
+    pub fn run_backfill(
+        issues: &[MissingKeyIssue],
+        backfill: &Backfill,
+        fill: FillPolicy,
+    ) -> anyhow::Result<ActionStats> {
+        let ops = Self::backfill_operations(issues, backfill, fill);
+        let mut stats = execute_operations(&ops)?;
+        stats.skipped = Self::backfill_skipped(issues, backfill, fill).len();
+        Ok(stats)
+    }
+
+    /// Preview the keys that would be inserted into the other locales.
+    pub fn preview_backfill(issues: &[MissingKeyIssue], backfill: &Backfill, fill: FillPolicy) {
+        for op in Self::backfill_operations(issues, backfill, fill) {
+            op.preview();
+        }
+    }
+
+    /// (locale, key) pairs that cannot be backfilled because no namespace
+    /// file of the locale matches the key.
+    pub fn backfill_skipped<'a>(
+        issues: &'a [MissingKeyIssue],
+        backfill: &'a Backfill,
+        fill: FillPolicy,
+    ) -> Vec<(&'a str, &'a str)> {
+        Self::backfill_pairs(issues, backfill, fill)
+            .into_keys()
+            .map(|(locale, _, key)| (locale, key))
+            .filter(|(locale, key)| {
+                backfill
+                    .all_messages
+                    .get(*locale)
+                    .and_then(|messages| Self::target(messages, key, backfill.key_separator))
+                    .is_none()
+            })
+            .collect()
+    }
+
+    /// Value of each missing (locale, key) pair of the non-source locales,
+    /// keyed by locale, line of the key in the primary file (`usize::MAX` for
+    /// created keys) and key.
+    fn backfill_pairs<'a>(
+        issues: &'a [MissingKeyIssue],
+        backfill: &'a Backfill,
+        fill: FillPolicy,
+    ) -> BTreeMap<(&'a str, usize, &'a str), String> {
+        let mut pairs = BTreeMap::new();
+        let replicas: Vec<(&String, &LocaleMessages)> = backfill
+            .all_messages
+            .iter()
+            .filter(|(locale, _)| {
+                !is_source_locale(locale, backfill.primary_locale, backfill.fallback_locales)
+            })
+            .collect();
+
+        if let Some(primary) = backfill.all_messages.get(backfill.primary_locale) {
+            for key in Self::distinct_keys(issues) {
+                if Self::target(primary, key, backfill.key_separator).is_none() {
+                    continue;
+                }
+                let source = Self::primary_value(key, backfill.key_separator);
+                for (locale, messages) in &replicas {
+                    if !messages.entries.contains_key(key) {
+                        pairs.insert((locale.as_str(), usize::MAX, key), fill.value(key, &source));
+                    }
+                }
+            }
+        }
+
+        for lag in backfill.lags {
+            let key = lag.context.key.as_str();
+            let is_string = backfill
+                .all_messages
+                .get(&lag.primary_locale)
+                .and_then(|messages| messages.entries.get(key))
+                .is_some_and(|entry| entry.value_type == ValueType::String);
+            if !is_string {
+                continue;
+            }
+            for locale in &lag.missing_in {
+                pairs.insert(
+                    (locale.as_str(), lag.context.line(), key),
+                    fill.value(key, &lag.context.value),
+                );
+            }
+        }
+
+        pairs
+    }
+
+    /// Keys that cannot be inserted because no namespace file matches them.
+    pub fn skipped_keys<'a>(
+        issues: &'a [MissingKeyIssue],
+        locale: &LocaleMessages,
+        key_separator: &str,
+    ) -> Vec<&'a str> {
+        Self::distinct_keys(issues)
+            .into_iter()
+            .filter(|key| Self::target(locale, key, key_separator).is_none())
+            .collect()
+    }
+
+    fn distinct_keys(issues: &[MissingKeyIssue]) -> BTreeSet<&str> {
+        issues.iter().map(|issue| issue.key.as_str()).collect()
+    }
+
+    /// File and namespace a key should be inserted into.
+    pub(super) fn target(
+        locale: &LocaleMessages,
+        key: &str,
+        key_separator: &str,
+    ) -> Option<(String, Option<String>)> {
+        if locale.namespace_files.is_empty() {
+            // Locales only declared in Vue `<i18n>` blocks have no JSON file
+            return (!locale.file_path.ends_with(".vue")).then(|| (locale.file_path.clone(), None));
+        }
+        let (namespace, _) = key.split_once(key_separator)?;
+        let file_path = locale.namespace_files.get(namespace)?;
+        Some((file_path.clone(), Some(namespace.to_string())))
+    }
+
+    /// Value a key gets in the primary locale: its last segment.
+    fn primary_value(key: &str, key_separator: &str) -> String {
+        key.rsplit(key_separator).next().unwrap_or(key).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CommentStyle, MessageEntry, SourceContext, SourceLocation};
+
+    fn issue(key: &str, line: usize) -> MissingKeyIssue {
+        MissingKeyIssue {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", line, 1),
+                "t(\"...\")",
+                CommentStyle::Js,
+            ),
+            key: key.to_string(),
+            from_schema: None,
+        }
+    }
+
+    #[test]
+    fn test_to_operations_dedupes_and_sorts_keys() {
+        let issues = vec![
+            issue("Common.submit", 3),
+            issue("Auth.title", 1),
+            issue("Common.submit", 7),
+        ];
+
+        let locale = LocaleMessages::new("en", "./messages/en.json");
+        let ops = InsertMissingKey::to_operations(&issues, &locale, ".");
+
+        let entries: Vec<(&str, &str, &str)> = ops
+            .iter()
+            .map(|op| match op {
+                Operation::InsertJsonKey { context, .. } => (
+                    context.file_path(),
+                    context.key.as_str(),
+                    context.value.as_str(),
+                ),
+                _ => panic!("Expected InsertJsonKey"),
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("./messages/en.json", "Auth.title", "title"),
+                ("./messages/en.json", "Common.submit", "submit"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_operations_routes_to_namespace_files() {
+        let issues = vec![
+            issue("auth.title", 1),
+            issue("billing.plan", 2),
+            issue("flat", 3),
+        ];
+        let mut locale = LocaleMessages::new("en", "./locales/en/*.json");
+        locale
+            .namespace_files
+            .insert("auth".to_string(), "./locales/en/auth.json".to_string());
+
+        let ops = InsertMissingKey::to_operations(&issues, &locale, ".");
+
+        assert_eq!(ops.len(), 1);
+        let Operation::InsertJsonKey { context, .. } = &ops[0] else {
+            panic!("Expected InsertJsonKey");
+        };
+        assert_eq!(context.file_path(), "./locales/en/auth.json");
+        assert_eq!(context.file_key("."), "title");
+        assert_eq!(
+            InsertMissingKey::skipped_keys(&issues, &locale, "."),
+            vec!["billing.plan", "flat"]
+        );
+    }
+
+    fn locale_with(locale: &str, keys: &[(&str, &str, ValueType)]) -> LocaleMessages {
+        let file_path = format!("./messages/{}.json", locale);
+        let mut messages = LocaleMessages::new(locale, &file_path);
+        for (key, value, value_type) in keys {
+            messages.entries.insert(
+                key.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(&file_path, 1),
+                        *key,
+                        *value,
+                    ),
+                    value_type: *value_type,
+                },
+            );
+        }
+        messages
+    }
+
+    fn lag(key: &str, value: &str, missing_in: &[&str]) -> ReplicaLagIssue {
+        ReplicaLagIssue {
+            context: MessageContext::new(
+                MessageLocation::with_line("./messages/en.json", 1),
+                key,
+                value,
+            ),
+            primary_locale: "en".to_string(),
+            missing_in: missing_in.iter().map(|l| l.to_string()).collect(),
+            usages: Vec::new(),
+        }
+    }
+
+    fn backfilled(ops: &[Operation]) -> Vec<(&str, &str, &str)> {
+        ops.iter()
+            .map(|op| match op {
+                Operation::InsertJsonKey { context, .. } => (
+                    context.file_path(),
+                    context.key.as_str(),
+                    context.value.as_str(),
+                ),
+                _ => panic!("Expected InsertJsonKey"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_backfill_fills_each_missing_locale_key_pair() {
+        let mut all_messages = AllLocaleMessages::new();
+        all_messages.insert(
+            "en".to_string(),
+            locale_with(
+                "en",
+                &[
+                    ("Common.ok", "OK", ValueType::String),
+                    ("Common.tags", "a, b", ValueType::StringArray),
+                ],
+            ),
+        );
+        all_messages.insert(
+            "de".to_string(),
+            locale_with("de", &[("Auth.title", "Titel", ValueType::String)]),
+        );
+        all_messages.insert("fr".to_string(), locale_with("fr", &[]));
+        let lags = vec![
+            lag("Common.ok", "OK", &["de", "fr"]),
+            lag("Common.tags", "a, b", &["de", "fr"]),
+        ];
+        let backfill = Backfill {
+            all_messages: &all_messages,
+            primary_locale: "en",
+            fallback_locales: &[],
+            key_separator: ".",
+            lags: &lags,
+        };
+        let issues = vec![issue("Auth.title", 1)];
+
+        let ops = InsertMissingKey::backfill_operations(&issues, &backfill, FillPolicy::Source);
+        assert_eq!(
+            backfilled(&ops),
+            vec![
+                ("./messages/de.json", "Common.ok", "OK"),
+                ("./messages/fr.json", "Common.ok", "OK"),
+                ("./messages/fr.json", "Auth.title", "title"),
+            ]
+        );
+
+        let ops = InsertMissingKey::backfill_operations(&issues, &backfill, FillPolicy::Todo);
+        assert_eq!(
+            backfilled(&ops)[0],
+            ("./messages/de.json", "Common.ok", "[TODO] Common.ok")
+        );
+
+        let ops = InsertMissingKey::backfill_operations(&issues, &backfill, FillPolicy::Empty);
+        assert!(
+            backfilled(&ops)
+                .iter()
+                .all(|(_, _, value)| value.is_empty())
+        );
+    }
+
+    #[test]
+    fn test_backfill_follows_primary_order() {
+        let mut all_messages = AllLocaleMessages::new();
+        all_messages.insert(
+            "en".to_string(),
+            locale_with(
+                "en",
+                &[
+                    ("Common.z", "Z", ValueType::String),
+                    ("Common.a", "A", ValueType::String),
+                ],
+            ),
+        );
+        all_messages.insert("de".to_string(), locale_with("de", &[]));
+        let mut z = lag("Common.z", "Z", &["de"]);
+        z.context.location.line = 2;
+        let mut a = lag("Common.a", "A", &["de"]);
+        a.context.location.line = 3;
+        let lags = vec![a, z];
+        let backfill = Backfill {
+            all_messages: &all_messages,
+            primary_locale: "en",
+            fallback_locales: &[],
+            key_separator: ".",
+            lags: &lags,
+        };
+        let issues = vec![issue("Common.newKey", 1)];
+
+        let ops = InsertMissingKey::backfill_operations(&issues, &backfill, FillPolicy::Todo);
+        assert_eq!(
+            backfilled(&ops),
+            vec![
+                ("./messages/de.json", "Common.z", "[TODO] Common.z"),
+                ("./messages/de.json", "Common.a", "[TODO] Common.a"),
+                (
+                    "./messages/de.json",
+                    "Common.newKey",
+                    "[TODO] Common.newKey"
+                ),
+            ]
+        );
+
+        // The primary locale keeps the last segment whatever the policy
+        let ops = InsertMissingKey::to_operations(&issues, &all_messages["en"], ".");
+        assert_eq!(
+            backfilled(&ops),
+            vec![("./messages/en.json", "Common.newKey", "newKey")]
+        );
+    }
+}
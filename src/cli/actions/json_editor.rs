@@ -1,36 +1,53 @@
 use std::{
+    collections::HashSet,
     fs,
     path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
-use serde_json::Value;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use serde_json::{Map, Value, ser::PrettyFormatter, value::RawValue};
 
-/// A JSON editor that deletes keys by their key paths.
+use crate::core::parsers::{json::RawEntries, yaml::is_yaml_file};
+
+/// A JSON editor that deletes or inserts keys by their key paths.
 ///
 /// Uses serde_json for parsing and serialization.
 /// Output is always pretty-printed with 2-space indentation.
 pub struct JsonEditor {
     file_path: PathBuf,
     content: String,
+    /// Whether `save` ends the file with a newline.
+    trailing_newline: bool,
 }
 
 impl JsonEditor {
     /// Open a JSON file for editing.
+    ///
+    /// YAML locale files are loaded by `check` but can't be edited yet, so
+    /// opening one is an error.
     pub fn open(path: &Path) -> Result<Self> {
+        if is_yaml_file(path) {
+            bail!(
+                "Editing YAML locale files is not supported yet: {}",
+                path.display()
+            );
+        }
         let content = fs::read_to_string(path)
             .with_context(|| format!("Failed to read file: {}", path.display()))?;
         Ok(Self {
             file_path: path.to_path_buf(),
             content,
+            trailing_newline: true,
         })
     }
 
-    /// Delete keys by their full key paths (e.g., "Common.submit").
+    /// Delete keys by their full key paths (e.g., "Common.submit"), whose
+    /// segments are joined with `separator`.
     ///
     /// This method parses the JSON, removes the specified keys,
     /// removes empty parent objects, and re-serializes with 2-space indentation.
-    pub fn delete_keys(&mut self, key_paths: &[&str]) -> Result<usize> {
+    pub fn delete_keys(&mut self, key_paths: &[&str], separator: &str) -> Result<usize> {
         // Parse the content as JSON
         let mut value: Value =
             serde_json::from_str(&self.content).with_context(|| "Failed to parse JSON")?;
@@ -38,7 +55,7 @@ impl JsonEditor {
         // Delete each key path
         let mut deleted = 0;
         for key_path in key_paths {
-            if delete_key_path(&mut value, key_path) {
+            if delete_key_path(&mut value, key_path, separator) {
                 deleted += 1;
             }
         }
@@ -53,10 +70,153 @@ impl JsonEditor {
         Ok(deleted)
     }
 
+    /// Insert string values at their full key paths (e.g., "Common.submit"),
+    /// whose segments are joined with `separator`.
+    ///
+    /// Missing parent objects are created and new keys are appended after
+    /// existing siblings. Keys that already exist, or whose parent path is
+    /// occupied by a non-object value, are left untouched.
+    pub fn insert_keys(&mut self, entries: &[(&str, &str)], separator: &str) -> Result<usize> {
+        let mut value: Value =
+            serde_json::from_str(&self.content).with_context(|| "Failed to parse JSON")?;
+
+        let mut inserted = 0;
+        for (key_path, text) in entries {
+            if insert_key_path(&mut value, key_path, text, separator) {
+                inserted += 1;
+            }
+        }
+
+        self.content =
+            serde_json::to_string_pretty(&value).with_context(|| "Failed to serialize JSON")?;
+
+        Ok(inserted)
+    }
+
+    /// Sort object keys recursively. Array elements keep their order.
+    ///
+    /// Unlike the other edits, values are moved between keys as written:
+    /// only the objects and arrays that contain unsorted keys are laid out
+    /// again, with the file's indentation, while strings, numbers and sorted
+    /// containers keep their original text (escapes, inline arrays). Keys of
+    /// re-laid objects are re-escaped. The trailing newline is kept too, so
+    /// sorting an already sorted file is a no-op. Returns whether the key
+    /// order changed; the content is left untouched otherwise.
+    pub fn sort_keys(&mut self) -> Result<bool> {
+        let root: &RawValue =
+            serde_json::from_str(&self.content).with_context(|| "Failed to parse JSON")?;
+        let indent = String::from_utf8(detect_indent(&self.content))?;
+        let Some(sorted) = sort_raw_value(root, &indent, 0)? else {
+            return Ok(false);
+        };
+
+        self.trailing_newline = self.content.ends_with('\n');
+        self.content = sorted;
+        Ok(true)
+    }
+
+    /// Rewrite dotted keys as nested objects (`"Common.submit"` →
+    /// `{"Common": {"submit": ...}}`).
+    ///
+    /// Like `sort_keys`, the indentation and trailing newline are kept and
+    /// the return value tells whether the content changed. Keys are split on
+    /// `separator`. Errors when a key is both a value and a parent of other
+    /// keys, or is defined twice, since no nested file can hold both.
+    pub fn nest_keys(&mut self, separator: &str) -> Result<bool> {
+        self.restructure(separator, |leaves| {
+            let mut root = Map::new();
+            for (path, leaf) in leaves {
+                let mut segments: Vec<&str> = path.split(separator).collect();
+                let last = segments.pop().unwrap_or_default();
+                let mut current = &mut root;
+                for segment in segments {
+                    current = current
+                        .entry(segment)
+                        .or_insert_with(|| Value::Object(Map::new()))
+                        .as_object_mut()
+                        .expect("collisions are rejected before nesting");
+                }
+                current.insert(last.to_string(), leaf);
+            }
+            Value::Object(root)
+        })
+    }
+
+    /// Rewrite nested objects as dotted keys (`{"Common": {"submit": ...}}` →
+    /// `"Common.submit"`), joined with `separator`.
+    ///
+    /// The counterpart of `nest_keys`, with the same collision errors.
+    pub fn flatten_keys(&mut self, separator: &str) -> Result<bool> {
+        self.restructure(separator, |leaves| {
+            Value::Object(leaves.into_iter().collect())
+        })
+    }
+
+    /// Rebuild the file from its leaves with `build`, after checking that no
+    /// key is both a value and a parent.
+    fn restructure(
+        &mut self,
+        separator: &str,
+        build: impl FnOnce(Vec<(String, Value)>) -> Value,
+    ) -> Result<bool> {
+        let original: Value =
+            serde_json::from_str(&self.content).with_context(|| "Failed to parse JSON")?;
+        let Value::Object(root) = &original else {
+            bail!("Expected a JSON object in {}", self.file_path.display());
+        };
+        let mut leaves = Vec::new();
+        for (key, child) in root {
+            collect_leaves(child, separator, &mut key.clone(), &mut leaves);
+        }
+
+        let mut paths: HashSet<&str> = HashSet::new();
+        for (path, _) in &leaves {
+            if !paths.insert(path) {
+                bail!(
+                    "Key \"{}\" is defined more than once in {}",
+                    path,
+                    self.file_path.display()
+                );
+            }
+        }
+        for (path, _) in &leaves {
+            let parent = path
+                .match_indices(separator)
+                .map(|(index, _)| &path[..index])
+                .find(|parent| paths.contains(parent));
+            if let Some(parent) = parent {
+                bail!(
+                    "Key \"{}\" is both a value and a parent of \"{}\" in {}",
+                    parent,
+                    path,
+                    self.file_path.display()
+                );
+            }
+        }
+
+        let restructured = build(leaves);
+        // `Value` equality ignores key order, so compare serialized forms
+        if serde_json::to_string(&original)? == serde_json::to_string(&restructured)? {
+            return Ok(false);
+        }
+
+        let indent = detect_indent(&self.content);
+        let mut buf = Vec::new();
+        let mut serializer =
+            serde_json::Serializer::with_formatter(&mut buf, PrettyFormatter::with_indent(&indent));
+        restructured
+            .serialize(&mut serializer)
+            .with_context(|| "Failed to serialize JSON")?;
+
+        self.trailing_newline = self.content.ends_with('\n');
+        self.content = String::from_utf8(buf).with_context(|| "Failed to serialize JSON")?;
+        Ok(true)
+    }
+
     /// Save the modified content back to the file.
     pub fn save(&self) -> Result<()> {
         // Ensure trailing newline
-        let content = if self.content.ends_with('\n') {
+        let content = if self.content.ends_with('\n') || !self.trailing_newline {
             self.content.clone()
         } else {
             format!("{}\n", self.content)
@@ -76,8 +236,8 @@ impl JsonEditor {
 }
 
 /// Delete a key path from a JSON value (e.g., "Common.submit" or "Faq.items.0.answer").
-fn delete_key_path(value: &mut Value, key_path: &str) -> bool {
-    let parts: Vec<&str> = key_path.split('.').collect();
+fn delete_key_path(value: &mut Value, key_path: &str, separator: &str) -> bool {
+    let parts: Vec<&str> = key_path.split(separator).collect();
     if parts.is_empty() {
         return false;
     }
@@ -128,6 +288,125 @@ fn delete_key_path(value: &mut Value, key_path: &str) -> bool {
     }
 }
 
+/// Insert a string value at a key path, creating parent objects as needed.
+fn insert_key_path(value: &mut Value, key_path: &str, text: &str, separator: &str) -> bool {
+    let parts: Vec<&str> = key_path.split(separator).collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        return false;
+    }
+
+    let mut current = value;
+    for part in &parts[..parts.len() - 1] {
+        let Value::Object(map) = current else {
+            return false;
+        };
+        current = map
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Default::default()));
+    }
+
+    let final_part = parts[parts.len() - 1];
+    match current {
+        Value::Object(map) if !map.contains_key(final_part) => {
+            map.insert(final_part.to_string(), Value::String(text.to_string()));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Collect the values under `path` by their full key path, in file order.
+///
+/// Non-empty objects are descended into; everything else (strings, arrays,
+/// empty objects) is a leaf kept as is.
+fn collect_leaves(
+    value: &Value,
+    separator: &str,
+    path: &mut String,
+    leaves: &mut Vec<(String, Value)>,
+) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let len = path.len();
+                path.push_str(separator);
+                path.push_str(key);
+                collect_leaves(child, separator, path, leaves);
+                path.truncate(len);
+            }
+        }
+        _ => leaves.push((path.clone(), value.clone())),
+    }
+}
+
+/// Sort the keys of every object in `raw`, including objects inside arrays.
+///
+/// Returns `None` when every key is already sorted. Otherwise the objects
+/// and arrays on the way to an unsorted object are laid out again at `depth`
+/// with `indent`, and everything else is copied as written.
+fn sort_raw_value(raw: &RawValue, indent: &str, depth: usize) -> Result<Option<String>> {
+    let text = raw.get();
+    let outer = indent.repeat(depth);
+    let inner = indent.repeat(depth + 1);
+
+    if text.starts_with('{') {
+        let RawEntries(mut entries) = serde_json::from_str(text)?;
+        let mut changed = !entries.is_sorted_by(|a, b| a.0 <= b.0);
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut lines = Vec::with_capacity(entries.len());
+        for (key, value) in entries {
+            let value = match sort_raw_value(value, indent, depth + 1)? {
+                Some(sorted) => {
+                    changed = true;
+                    sorted
+                }
+                None => value.get().to_string(),
+            };
+            lines.push(format!(
+                "{}{}: {}",
+                inner,
+                serde_json::to_string(&key)?,
+                value
+            ));
+        }
+        return Ok(changed.then(|| format!("{{\n{}\n{}}}", lines.join(",\n"), outer)));
+    }
+
+    if text.starts_with('[') {
+        let items: Vec<&RawValue> = serde_json::from_str(text)?;
+        let mut changed = false;
+        let mut lines = Vec::with_capacity(items.len());
+        for item in items {
+            let item = match sort_raw_value(item, indent, depth + 1)? {
+                Some(sorted) => {
+                    changed = true;
+                    sorted
+                }
+                None => item.get().to_string(),
+            };
+            lines.push(format!("{}{}", inner, item));
+        }
+        return Ok(changed.then(|| format!("[\n{}\n{}]", lines.join(",\n"), outer)));
+    }
+
+    Ok(None)
+}
+
+/// Indentation of the first indented line, defaulting to two spaces.
+fn detect_indent(content: &str) -> Vec<u8> {
+    content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start_matches([' ', '\t']);
+            &line[..line.len() - trimmed.len()]
+        })
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+        .as_bytes()
+        .to_vec()
+}
+
 /// Recursively remove empty objects from a JSON value.
 fn remove_empty_objects(value: &mut Value) {
     match value {
@@ -171,13 +450,99 @@ mod tests {
         (temp_dir, file_path)
     }
 
+    #[test]
+    fn test_sort_keys_recursively() {
+        let json = "{\n    \"b\": {\"z\": \"1\", \"a\": \"2\"},\n    \"a\": [{\"y\": \"1\", \"x\": \"2\"}, \"first\"]\n}";
+        let (_temp, path) = create_temp_json(json);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        assert!(editor.sort_keys().unwrap());
+
+        // Indentation is kept, array elements are not reordered
+        let expected = r#"{
+    "a": [
+        {
+            "x": "2",
+            "y": "1"
+        },
+        "first"
+    ],
+    "b": {
+        "a": "2",
+        "z": "1"
+    }
+}"#;
+        assert_eq!(editor.content(), expected);
+
+        editor.save().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sort_keys_already_sorted() {
+        let json = "{\"a\": \"1\", \"b\": [\"z\", \"a\"]}\n";
+        let (_temp, path) = create_temp_json(json);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        assert!(!editor.sort_keys().unwrap());
+        assert_eq!(editor.content(), json);
+    }
+
+    #[test]
+    fn test_nest_and_flatten_keys() {
+        let flat = "{\n    \"Common.submit\": \"Submit\",\n    \"Common.tags\": [\"a\"],\n    \"Home.title\": \"Home\"\n}";
+        let nested = "{\n    \"Common\": {\n        \"submit\": \"Submit\",\n        \"tags\": [\n            \"a\"\n        ]\n    },\n    \"Home\": {\n        \"title\": \"Home\"\n    }\n}";
+        let (_temp, path) = create_temp_json(flat);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        assert!(editor.nest_keys(".").unwrap());
+        assert_eq!(editor.content(), nested);
+        assert!(!editor.nest_keys(".").unwrap());
+
+        assert!(editor.flatten_keys(".").unwrap());
+        assert_eq!(
+            editor.content(),
+            "{\n    \"Common.submit\": \"Submit\",\n    \"Common.tags\": [\n        \"a\"\n    ],\n    \"Home.title\": \"Home\"\n}"
+        );
+        editor.save().unwrap();
+        // No trailing newline in the original file
+        assert!(!fs::read_to_string(&path).unwrap().ends_with('\n'));
+    }
+
+    #[test]
+    fn test_nest_keys_rejects_collisions() {
+        let (_temp, path) = create_temp_json(
+            r#"{"Common.submit": "Submit", "Common": {"submit": {"label": "Go"}}}"#,
+        );
+        let mut editor = JsonEditor::open(&path).unwrap();
+        let err = editor.nest_keys(".").unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "\"Common.submit\" is both a value and a parent of \"Common.submit.label\""
+            )
+        );
+        assert!(editor.flatten_keys(".").is_err());
+
+        // Not adjacent once sorted: '-' sorts before '.'
+        let (_temp, path) = create_temp_json(r#"{"a.b": "1", "a.b-x": "2", "a.b.c": "3"}"#);
+        assert!(JsonEditor::open(&path).unwrap().nest_keys(".").is_err());
+
+        let (_temp, path) =
+            create_temp_json(r#"{"Common.submit": "Submit", "Common": {"submit": "Go"}}"#);
+        let err = JsonEditor::open(&path).unwrap().nest_keys(".").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("\"Common.submit\" is defined more than once")
+        );
+    }
+
     #[test]
     fn test_delete_simple_key() {
         let json = r#"{"key1": "value1", "key2": "value2", "key3": "value3"}"#;
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["key2"]).unwrap();
+        editor.delete_keys(&["key2"], ".").unwrap();
 
         let expected = r#"{
   "key1": "value1",
@@ -192,7 +557,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Common.submit"]).unwrap();
+        editor.delete_keys(&["Common.submit"], ".").unwrap();
 
         let expected = r#"{
   "Common": {
@@ -209,7 +574,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Common.submit"]).unwrap();
+        editor.delete_keys(&["Common.submit"], ".").unwrap();
 
         // Common should be removed since it's now empty
         let expected = r#"{
@@ -224,7 +589,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["key2", "key4"]).unwrap();
+        editor.delete_keys(&["key2", "key4"], ".").unwrap();
 
         let expected = r#"{
   "key1": "v1",
@@ -239,7 +604,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["a"]).unwrap();
+        editor.delete_keys(&["a"], ".").unwrap();
 
         // Key order should be preserved (z, m)
         let content = editor.content();
@@ -254,7 +619,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["a.b.c.d"]).unwrap();
+        editor.delete_keys(&["a.b.c.d"], ".").unwrap();
 
         // All empty parents should be removed
         let expected = "{}";
@@ -268,7 +633,7 @@ mod tests {
         let mut editor = JsonEditor::open(&path).unwrap();
 
         // Deleting non-existent key should not error
-        editor.delete_keys(&["nonexistent"]).unwrap();
+        editor.delete_keys(&["nonexistent"], ".").unwrap();
 
         // Original content should be unchanged (just reformatted)
         let expected = r#"{
@@ -284,8 +649,8 @@ mod tests {
         let mut editor = JsonEditor::open(&path).unwrap();
 
         // Deleting non-existent nested key should not error
-        editor.delete_keys(&["Common.nonexistent"]).unwrap();
-        editor.delete_keys(&["Nonexistent.key"]).unwrap();
+        editor.delete_keys(&["Common.nonexistent"], ".").unwrap();
+        editor.delete_keys(&["Nonexistent.key"], ".").unwrap();
 
         let expected = r#"{
   "Common": {
@@ -302,7 +667,7 @@ mod tests {
         let mut editor = JsonEditor::open(&path).unwrap();
 
         // Deleting from empty object should not error
-        editor.delete_keys(&["key"]).unwrap();
+        editor.delete_keys(&["key"], ".").unwrap();
 
         assert_eq!(editor.content(), "{}");
     }
@@ -313,7 +678,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Faq.items.0.question"]).unwrap();
+        editor.delete_keys(&["Faq.items.0.question"], ".").unwrap();
 
         let expected = r#"{
   "Faq": {
@@ -337,7 +702,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Faq.items.1.question"]).unwrap();
+        editor.delete_keys(&["Faq.items.1.question"], ".").unwrap();
 
         let expected = r#"{
   "Faq": {
@@ -359,7 +724,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Faq.items.9.question"]).unwrap();
+        editor.delete_keys(&["Faq.items.9.question"], ".").unwrap();
 
         let expected = r#"{
   "Faq": {
@@ -379,7 +744,9 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Faq.items.first.question"]).unwrap();
+        editor
+            .delete_keys(&["Faq.items.first.question"], ".")
+            .unwrap();
 
         let expected = r#"{
   "Faq": {
@@ -399,7 +766,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&[]).unwrap();
+        editor.delete_keys(&[], ".").unwrap();
         editor.save().unwrap();
 
         // Read the file directly and verify trailing newline
@@ -416,7 +783,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["key1", "key2"]).unwrap();
+        editor.delete_keys(&["key1", "key2"], ".").unwrap();
 
         assert_eq!(editor.content(), "{}");
     }
@@ -427,7 +794,7 @@ mod tests {
         let (_temp, path) = create_temp_json(json);
         let mut editor = JsonEditor::open(&path).unwrap();
 
-        editor.delete_keys(&["Common.hello"]).unwrap();
+        editor.delete_keys(&["Common.hello"], ".").unwrap();
 
         let content = editor.content();
         assert!(!content.contains("你好"));
@@ -441,7 +808,7 @@ mod tests {
         let mut editor = JsonEditor::open(&path).unwrap();
 
         // Delete one key from Common
-        editor.delete_keys(&["Common.a"]).unwrap();
+        editor.delete_keys(&["Common.a"], ".").unwrap();
 
         let content = editor.content();
         assert!(!content.contains("\"a\""));
@@ -459,7 +826,7 @@ mod tests {
 
         // Delete zebra and middle, keeping alpha and gamma
         editor
-            .delete_keys(&["Common.zebra", "Common.middle"])
+            .delete_keys(&["Common.zebra", "Common.middle"], ".")
             .unwrap();
 
         let content = editor.content();
@@ -474,4 +841,84 @@ mod tests {
             gamma_pos
         );
     }
+
+    #[test]
+    fn test_insert_creates_nested_objects() {
+        let json = r#"{"Common": {"submit": "Submit"}}"#;
+        let (_temp, path) = create_temp_json(json);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        let inserted = editor
+            .insert_keys(
+                &[("Common.cancel", "cancel"), ("Auth.login.title", "title")],
+                ".",
+            )
+            .unwrap();
+
+        assert_eq!(inserted, 2);
+        let expected = r#"{
+  "Common": {
+    "submit": "Submit",
+    "cancel": "cancel"
+  },
+  "Auth": {
+    "login": {
+      "title": "title"
+    }
+  }
+}"#;
+        assert_eq!(editor.content(), expected);
+    }
+
+    #[test]
+    fn test_insert_existing_key_is_noop() {
+        let json = r#"{"Common": {"submit": "Submit"}}"#;
+        let (_temp, path) = create_temp_json(json);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        let inserted = editor
+            .insert_keys(&[("Common.submit", "submit")], ".")
+            .unwrap();
+
+        assert_eq!(inserted, 0);
+        assert!(editor.content().contains("\"Submit\""));
+    }
+
+    #[test]
+    fn test_insert_under_string_value_is_skipped() {
+        let json = r#"{"Common": "Common"}"#;
+        let (_temp, path) = create_temp_json(json);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        let inserted = editor
+            .insert_keys(&[("Common.submit", "submit")], ".")
+            .unwrap();
+
+        assert_eq!(inserted, 0);
+        let expected = r#"{
+  "Common": "Common"
+}"#;
+        assert_eq!(editor.content(), expected);
+    }
+
+    #[test]
+    fn test_edit_keys_with_custom_separator() {
+        let json = r#"{"Common": {"v1.0": "Old", "keep": "Keep"}}"#;
+        let (_temp, path) = create_temp_json(json);
+        let mut editor = JsonEditor::open(&path).unwrap();
+
+        assert_eq!(editor.delete_keys(&["Common/v1.0"], "/").unwrap(), 1);
+        assert_eq!(
+            editor.insert_keys(&[("Common/v2.0", "New")], "/").unwrap(),
+            1
+        );
+
+        let expected = r#"{
+  "Common": {
+    "keep": "Keep",
+    "v2.0": "New"
+  }
+}"#;
+        assert_eq!(editor.content(), expected);
+    }
 }
@@ -18,7 +18,10 @@
 //! ## Actions
 //!
 //! - [`InsertDisableComment`]: Insert `glot-disable-next-line` comments (baseline)
+//! - [`RemoveDisableComment`]: Remove stale suppression comments (baseline --prune)
 //! - [`InsertMessageKeys`]: Insert `glot-message-keys` comments (fix)
+//! - [`InsertMissingKey`]: Insert missing keys into the primary locale (fix)
+//! - [`PromoteOrphan`]: Insert orphan keys into the primary locale (fix orphan)
 //! - [`DeleteKey`]: Delete keys from JSON files (clean)
 //!
 //! ## Example
@@ -36,12 +39,19 @@
 mod delete_key;
 mod insert_disable_comment;
 mod insert_message_keys;
+mod insert_missing_key;
 mod json_editor;
 mod operation;
+mod promote_orphan;
+mod remove_disable_comment;
 mod traits;
 
-pub use delete_key::DeleteKey;
+pub use delete_key::{DeletableKey, DeleteKey};
 pub use insert_disable_comment::InsertDisableComment;
-pub use insert_message_keys::InsertMessageKeys;
+pub use insert_message_keys::{AnnotateOutcome, InsertMessageKeys};
+pub use insert_missing_key::{Backfill, FillPolicy, InsertMissingKey};
+pub use json_editor::JsonEditor;
+pub use promote_orphan::{PromoteOrphan, PromoteValue};
+pub use remove_disable_comment::{RemoveDisableComment, StaleDirective};
 pub(crate) use traits::execute_operations;
-pub use traits::{Action, ActionStats};
+pub use traits::{Action, ActionStats, FileDiff};
@@ -1,9 +1,11 @@
 //! Low-level file operations.
 //!
 //! Operations are the primitive building blocks for file modifications.
-//! There are only two types:
+//! There are four types:
 //! - `InsertComment`: Insert a comment line above a source location
+//! - `RemoveComment`: Remove a glot comment line
 //! - `DeleteJsonKey`: Delete a key from a JSON file
+//! - `InsertJsonKey`: Insert a key into a JSON file
 //!
 //! Each operation knows how to execute itself and preview itself.
 
@@ -29,10 +31,23 @@ pub enum Operation {
         comment: String,
         rule: Rule,
     },
+    /// Remove the glot comment line at a source location.
+    RemoveComment { context: SourceContext },
     /// Delete a key from a JSON file.
+    ///
+    /// `key_separator` splits the key into the path of nested objects.
     DeleteJsonKey {
         context: MessageContext,
         reason: DeleteReason,
+        key_separator: String,
+    },
+    /// Insert a key with a value into a JSON file.
+    ///
+    /// `rule` is the rule whose issue is fixed, shown in the preview.
+    InsertJsonKey {
+        context: MessageContext,
+        rule: Rule,
+        key_separator: String,
     },
 }
 
@@ -59,6 +74,18 @@ pub enum OperationResult {
 }
 
 impl Operation {
+    /// Path of the file this operation edits.
+    pub fn file_path(&self) -> &str {
+        match self {
+            Operation::InsertComment { context, .. } | Operation::RemoveComment { context } => {
+                context.file_path()
+            }
+            Operation::DeleteJsonKey { context, .. } | Operation::InsertJsonKey { context, .. } => {
+                context.file_path()
+            }
+        }
+    }
+
     /// Execute this operation (modify files).
     #[cfg(test)]
     pub fn execute(&self) -> anyhow::Result<OperationResult> {
@@ -66,7 +93,17 @@ impl Operation {
             Operation::InsertComment {
                 context, comment, ..
             } => Self::execute_insert_comment(context, comment),
-            Operation::DeleteJsonKey { context, .. } => Self::execute_delete_json_key(context),
+            Operation::RemoveComment { context } => Self::execute_remove_comment(context),
+            Operation::DeleteJsonKey {
+                context,
+                key_separator,
+                ..
+            } => Self::execute_delete_json_key(context, key_separator),
+            Operation::InsertJsonKey {
+                context,
+                key_separator,
+                ..
+            } => Self::execute_insert_json_key(context, key_separator),
         }
     }
 
@@ -80,9 +117,17 @@ impl Operation {
             } => {
                 Self::preview_insert_comment(context, comment, *rule);
             }
-            Operation::DeleteJsonKey { context, reason } => {
+            Operation::RemoveComment { context } => {
+                Self::preview_remove_comment(context);
+            }
+            Operation::DeleteJsonKey {
+                context, reason, ..
+            } => {
                 Self::preview_delete_json_key(context, *reason);
             }
+            Operation::InsertJsonKey { context, rule, .. } => {
+                Self::preview_insert_json_key(context, *rule);
+            }
         }
     }
 
@@ -211,15 +256,119 @@ impl Operation {
         println!();
     }
 
+    // ========== RemoveComment implementation ==========
+
+    #[cfg(test)]
+    fn execute_remove_comment(context: &SourceContext) -> anyhow::Result<OperationResult> {
+        let applied = Self::apply_remove_comment_ops(
+            &[Operation::RemoveComment {
+                context: context.clone(),
+            }],
+            true,
+        )?;
+        Ok(if applied > 0 {
+            OperationResult::Applied
+        } else {
+            OperationResult::Noop
+        })
+    }
+
+    /// Remove comment lines from a single file.
+    ///
+    /// A line is only removed while it still holds nothing but a glot
+    /// suppression comment, so code is never deleted if the file changed.
+    pub(crate) fn apply_remove_comment_ops(
+        ops: &[Operation],
+        write: bool,
+    ) -> anyhow::Result<usize> {
+        let mut file_path: Option<&str> = None;
+        let mut lines_to_remove: Vec<usize> = Vec::new();
+
+        for op in ops {
+            if let Operation::RemoveComment { context } = op {
+                let op_path = context.file_path();
+                if let Some(existing) = file_path {
+                    if existing != op_path {
+                        anyhow::bail!("apply_remove_comment_ops expects ops from a single file");
+                    }
+                } else {
+                    file_path = Some(op_path);
+                }
+                lines_to_remove.push(context.line());
+            }
+        }
+
+        let Some(file_path) = file_path else {
+            return Ok(0);
+        };
+
+        let content = fs::read_to_string(file_path)?;
+        let newline = if content.contains("\r\n") {
+            "\r\n"
+        } else {
+            "\n"
+        };
+        let had_trailing_newline = content.ends_with(newline);
+        let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
+
+        lines_to_remove.sort_unstable_by(|a, b| b.cmp(a));
+        lines_to_remove.dedup();
+
+        let mut removed = 0;
+        for line in lines_to_remove {
+            let idx = line.saturating_sub(1);
+            let is_suppression = lines.get(idx).is_some_and(|text| {
+                matches!(
+                    parse_comment_directive(text),
+                    Some(
+                        Directive::Disable { .. }
+                            | Directive::Enable { .. }
+                            | Directive::DisableNextLine { .. }
+                            | Directive::DisableFile { .. }
+                    )
+                )
+            });
+            if is_suppression {
+                lines.remove(idx);
+                removed += 1;
+            }
+        }
+
+        if removed > 0 && write {
+            let mut new_content = lines.join(newline);
+            if had_trailing_newline {
+                new_content.push_str(newline);
+            }
+            fs::write(file_path, new_content)?;
+        }
+
+        Ok(removed)
+    }
+
+    fn preview_remove_comment(context: &SourceContext) {
+        println!(
+            "  {} {}:{}",
+            "-->".blue(),
+            context.file_path(),
+            context.line()
+        );
+        println!("     {}", "|".blue());
+        println!("  {} {}", "-".red().bold(), context.source_line.red());
+        println!();
+    }
+
     // ========== DeleteJsonKey implementation ==========
 
     #[cfg(test)]
-    fn execute_delete_json_key(context: &MessageContext) -> anyhow::Result<OperationResult> {
+    fn execute_delete_json_key(
+        context: &MessageContext,
+        key_separator: &str,
+    ) -> anyhow::Result<OperationResult> {
         let file_path = Path::new(context.file_path());
-        let key = context.key.as_str();
+        let key = context.file_key(key_separator);
 
         let mut editor = JsonEditor::open(file_path)?;
-        let deleted = editor.delete_keys(&[key])?;
+        let deleted = editor.delete_keys(&[key], key_separator)?;
         if deleted > 0 {
             editor.save()?;
             return Ok(OperationResult::Applied);
@@ -228,16 +377,25 @@ impl Operation {
         Ok(OperationResult::Noop)
     }
 
-    pub(crate) fn apply_delete_json_key_ops(ops: &[Operation]) -> anyhow::Result<usize> {
+    pub(crate) fn apply_delete_json_key_ops(
+        ops: &[Operation],
+        write: bool,
+    ) -> anyhow::Result<usize> {
         if ops.is_empty() {
             return Ok(0);
         }
 
         let mut file_path: Option<&str> = None;
+        let mut separator: Option<&str> = None;
         let mut keys: Vec<&str> = Vec::new();
 
         for op in ops {
-            if let Operation::DeleteJsonKey { context, .. } = op {
+            if let Operation::DeleteJsonKey {
+                context,
+                key_separator,
+                ..
+            } = op
+            {
                 let op_path = context.file_path();
                 if let Some(existing) = file_path {
                     if existing != op_path {
@@ -246,25 +404,95 @@ impl Operation {
                 } else {
                     file_path = Some(op_path);
                 }
-                keys.push(context.key.as_str());
+                separator = Some(key_separator);
+                keys.push(context.file_key(key_separator));
             }
         }
 
-        let file_path = match file_path {
-            Some(path) => path,
-            None => return Ok(0),
+        let (Some(file_path), Some(separator)) = (file_path, separator) else {
+            return Ok(0);
         };
 
         let mut editor = JsonEditor::open(Path::new(file_path))?;
-        let deleted = editor.delete_keys(&keys)?;
-        if deleted > 0 {
+        let deleted = editor.delete_keys(&keys, separator)?;
+        if deleted > 0 && write {
             editor.save()?;
         }
 
         Ok(deleted)
     }
 
-    pub(crate) fn apply_insert_comment_ops(ops: &[Operation]) -> anyhow::Result<usize> {
+    // ========== InsertJsonKey implementation ==========
+
+    #[cfg(test)]
+    fn execute_insert_json_key(
+        context: &MessageContext,
+        key_separator: &str,
+    ) -> anyhow::Result<OperationResult> {
+        let file_path = Path::new(context.file_path());
+
+        let mut editor = JsonEditor::open(file_path)?;
+        let inserted = editor.insert_keys(
+            &[(context.file_key(key_separator), context.value.as_str())],
+            key_separator,
+        )?;
+        if inserted > 0 {
+            editor.save()?;
+            return Ok(OperationResult::Applied);
+        }
+
+        Ok(OperationResult::Noop)
+    }
+
+    pub(crate) fn apply_insert_json_key_ops(
+        ops: &[Operation],
+        write: bool,
+    ) -> anyhow::Result<usize> {
+        if ops.is_empty() {
+            return Ok(0);
+        }
+
+        let mut file_path: Option<&str> = None;
+        let mut separator: Option<&str> = None;
+        let mut entries: Vec<(&str, &str)> = Vec::new();
+
+        for op in ops {
+            if let Operation::InsertJsonKey {
+                context,
+                key_separator,
+                ..
+            } = op
+            {
+                let op_path = context.file_path();
+                if let Some(existing) = file_path {
+                    if existing != op_path {
+                        anyhow::bail!("apply_insert_json_key_ops expects ops from a single file");
+                    }
+                } else {
+                    file_path = Some(op_path);
+                }
+                separator = Some(key_separator);
+                entries.push((context.file_key(key_separator), context.value.as_str()));
+            }
+        }
+
+        let (Some(file_path), Some(separator)) = (file_path, separator) else {
+            return Ok(0);
+        };
+
+        let mut editor = JsonEditor::open(Path::new(file_path))?;
+        let inserted = editor.insert_keys(&entries, separator)?;
+        if inserted > 0 && write {
+            editor.save()?;
+        }
+
+        Ok(inserted)
+    }
+
+    pub(crate) fn apply_insert_comment_ops(
+        ops: &[Operation],
+        write: bool,
+    ) -> anyhow::Result<usize> {
         if ops.is_empty() {
             return Ok(0);
         }
@@ -376,7 +604,7 @@ impl Operation {
             }
         }
 
-        if changed {
+        if changed && write {
             let mut new_content = lines.join(newline);
             if had_trailing_newline {
                 new_content.push_str(newline);
@@ -408,6 +636,20 @@ impl Operation {
         );
         println!();
     }
+
+    fn preview_insert_json_key(context: &MessageContext, rule: Rule) {
+        println!("  {} {}", "-->".blue(), context.file_path());
+        println!("     {}", "|".blue());
+        println!(
+            "   {} {} \"{}\": \"{}\" {}",
+            "+".green(),
+            "|".blue(),
+            context.key,
+            context.value,
+            format!("[{}]", rule).dimmed().cyan()
+        );
+        println!();
+    }
 }
 
 fn parse_comment_directive(comment: &str) -> Option<Directive> {
@@ -615,7 +857,7 @@ fn merge_directives(
     }
 }
 
-fn extract_message_key_patterns(comment: &str) -> Option<Vec<String>> {
+pub(super) fn extract_message_key_patterns(comment: &str) -> Option<Vec<String>> {
     let text = strip_comment_markers(comment)?;
     if !text.trim_start().starts_with("glot-message-keys") {
         return None;
@@ -686,7 +928,7 @@ fn format_disable_next_line(
     }
 }
 
-fn format_message_keys(patterns: &[String], style: CommentStyle) -> String {
+pub(super) fn format_message_keys(patterns: &[String], style: CommentStyle) -> String {
     let quoted: Vec<String> = patterns.iter().map(|p| format!("\"{}\"", p)).collect();
     let directive = format!("glot-message-keys {}", quoted.join(", "));
     match style {
@@ -734,10 +976,13 @@ mod tests {
         let op = Operation::DeleteJsonKey {
             context: ctx,
             reason: DeleteReason::Unused,
+            key_separator: ".".to_string(),
         };
 
         match &op {
-            Operation::DeleteJsonKey { context, reason } => {
+            Operation::DeleteJsonKey {
+                context, reason, ..
+            } => {
                 assert_eq!(context.file_path(), "./messages/en.json");
                 assert_eq!(context.key, "Common.unused");
                 assert_eq!(*reason, DeleteReason::Unused);
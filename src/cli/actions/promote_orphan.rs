@@ -0,0 +1,259 @@
+//! PromoteOrphan action.
+//!
+//! Inserts orphan keys (keys of another locale that the primary locale lacks)
+//! into the primary locale file, instead of deleting them.
+//! Used by `glot fix orphan`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::core::{LocaleMessages, MessageContext, MessageLocation};
+use crate::issues::{OrphanKeyIssue, Rule};
+
+use super::insert_missing_key::InsertMissingKey;
+use super::operation::Operation;
+use super::traits::{ActionStats, execute_operations};
+
+/// Value given to a promoted key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromoteValue {
+    /// Copy the value of the locale that defines the key.
+    Copy,
+    /// Insert an empty string, to be filled in by hand.
+    Empty,
+}
+
+/// Why an orphan key can't be promoted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromoteSkip<'a> {
+    /// The key is a parent or a child of an existing primary key, so inserting
+    /// it would replace an object with a string or the other way around.
+    Collision { existing: &'a str },
+    /// The primary locale uses namespace files and none matches the key.
+    NoNamespaceFile,
+}
+
+impl fmt::Display for PromoteSkip<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PromoteSkip::Collision { existing } => write!(f, "collides with \"{}\"", existing),
+            PromoteSkip::NoNamespaceFile => write!(f, "no matching namespace file"),
+        }
+    }
+}
+
+/// Action to promote orphan keys into the primary locale file.
+///
+/// Like `InsertMissingKey`, every method takes the primary locale and the key
+/// separator, because the issues point at the other locales. A key that is orphan in several
+/// locales is promoted once, with the value of the first file defining it.
+pub struct PromoteOrphan;
+
+impl PromoteOrphan {
+    /// Convert orphan key issues to one insert operation per distinct key.
+    ///
+    /// Operations are sorted by key. Keys that can't be promoted are left out
+    /// (see `skipped_keys`).
+    pub fn to_operations(
+        issues: &[OrphanKeyIssue],
+        primary: &LocaleMessages,
+        key_separator: &str,
+        value: PromoteValue,
+    ) -> Vec<Operation> {
+        Self::distinct_keys(issues)
+            .into_iter()
+            .filter(|(key, _)| Self::collision(primary, key, key_separator).is_none())
+            .filter_map(|(key, issue)| {
+                let (file_path, namespace) = InsertMissingKey::target(primary, key, key_separator)?;
+                let value = match value {
+                    PromoteValue::Copy => issue.context.value.as_str(),
+                    PromoteValue::Empty => "",
+                };
+                let context =
+                    MessageContext::new(MessageLocation::with_line(file_path, 0), key, value)
+                        .with_namespace(namespace);
+                Some(Operation::InsertJsonKey {
+                    context,
+                    rule: Rule::OrphanKey,
+                    key_separator: key_separator.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Insert the orphan keys into the primary locale file.
+    pub fn run(
+        issues: &[OrphanKeyIssue],
+        primary: &LocaleMessages,
+        key_separator: &str,
+        value: PromoteValue,
+    ) -> anyhow::Result<ActionStats> {
+        let ops = Self::to_operations(issues, primary, key_separator, value);
+        let mut stats = execute_operations(&ops)?;
+        stats.skipped = Self::skipped_keys(issues, primary, key_separator).len();
+        Ok(stats)
+    }
+
+    /// Preview the keys that would be promoted.
+    pub fn preview(
+        issues: &[OrphanKeyIssue],
+        primary: &LocaleMessages,
+        key_separator: &str,
+        value: PromoteValue,
+    ) {
+        for op in Self::to_operations(issues, primary, key_separator, value) {
+            op.preview();
+        }
+    }
+
+    /// Keys that can't be promoted, sorted by key.
+    pub fn skipped_keys<'a>(
+        issues: &'a [OrphanKeyIssue],
+        primary: &'a LocaleMessages,
+        key_separator: &str,
+    ) -> Vec<(&'a str, PromoteSkip<'a>)> {
+        Self::distinct_keys(issues)
+            .into_keys()
+            .filter_map(|key| {
+                if let Some(existing) = Self::collision(primary, key, key_separator) {
+                    Some((key, PromoteSkip::Collision { existing }))
+                } else if InsertMissingKey::target(primary, key, key_separator).is_none() {
+                    Some((key, PromoteSkip::NoNamespaceFile))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// First issue of each orphan key, by key.
+    fn distinct_keys(issues: &[OrphanKeyIssue]) -> BTreeMap<&str, &OrphanKeyIssue> {
+        let mut keys = BTreeMap::new();
+        for issue in issues {
+            keys.entry(issue.context.key.as_str()).or_insert(issue);
+        }
+        keys
+    }
+
+    /// The first primary key that is a parent or a child of `key`.
+    fn collision<'a>(
+        primary: &'a LocaleMessages,
+        key: &str,
+        key_separator: &str,
+    ) -> Option<&'a str> {
+        primary
+            .entries
+            .keys()
+            .filter(|existing| {
+                is_nested(existing, key, key_separator) || is_nested(key, existing, key_separator)
+            })
+            .min()
+            .map(String::as_str)
+    }
+}
+
+/// Whether `child` is nested under `parent` (`Common.submit` under `Common`).
+fn is_nested(child: &str, parent: &str, key_separator: &str) -> bool {
+    child
+        .strip_prefix(parent)
+        .is_some_and(|rest| rest.starts_with(key_separator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{MessageEntry, ValueType};
+
+    fn issue(locale: &str, key: &str, value: &str) -> OrphanKeyIssue {
+        OrphanKeyIssue {
+            context: MessageContext::new(
+                MessageLocation::with_line(format!("./messages/{}.json", locale), 1),
+                key,
+                value,
+            ),
+            locale: locale.to_string(),
+        }
+    }
+
+    fn primary(keys: &[&str]) -> LocaleMessages {
+        let mut locale = LocaleMessages::new("en", "./messages/en.json");
+        for key in keys {
+            locale.entries.insert(
+                key.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line("./messages/en.json", 1),
+                        *key,
+                        "",
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        locale
+    }
+
+    fn entries(ops: &[Operation]) -> Vec<(&str, &str)> {
+        ops.iter()
+            .map(|op| match op {
+                Operation::InsertJsonKey { context, rule, .. } => {
+                    assert_eq!(*rule, Rule::OrphanKey);
+                    (context.key.as_str(), context.value.as_str())
+                }
+                _ => panic!("Expected InsertJsonKey"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_to_operations_copies_first_value() {
+        let issues = vec![
+            issue("de", "Common.cancel", "Abbrechen"),
+            issue("fr", "Common.cancel", "Annuler"),
+            issue("de", "Auth.title", "Anmelden"),
+        ];
+        let primary = primary(&["Common.submit"]);
+
+        let ops = PromoteOrphan::to_operations(&issues, &primary, ".", PromoteValue::Copy);
+        assert_eq!(
+            entries(&ops),
+            vec![("Auth.title", "Anmelden"), ("Common.cancel", "Abbrechen")]
+        );
+
+        let ops = PromoteOrphan::to_operations(&issues, &primary, ".", PromoteValue::Empty);
+        assert_eq!(
+            entries(&ops),
+            vec![("Auth.title", ""), ("Common.cancel", "")]
+        );
+    }
+
+    #[test]
+    fn test_collisions_are_skipped() {
+        let issues = vec![
+            issue("de", "Common.submit.label", "Senden"),
+            issue("de", "Nav", "Navigation"),
+            issue("de", "Common.submitted", "Gesendet"),
+        ];
+        let primary = primary(&["Common.submit", "Nav.home", "Nav.about"]);
+
+        let ops = PromoteOrphan::to_operations(&issues, &primary, ".", PromoteValue::Copy);
+        assert_eq!(entries(&ops), vec![("Common.submitted", "Gesendet")]);
+        assert_eq!(
+            PromoteOrphan::skipped_keys(&issues, &primary, "."),
+            vec![
+                (
+                    "Common.submit.label",
+                    PromoteSkip::Collision {
+                        existing: "Common.submit"
+                    }
+                ),
+                (
+                    "Nav",
+                    PromoteSkip::Collision {
+                        existing: "Nav.about"
+                    }
+                ),
+            ]
+        );
+    }
+}
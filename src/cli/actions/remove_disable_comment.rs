@@ -0,0 +1,84 @@
+//! RemoveDisableComment action.
+//!
+//! Removes `glot-disable-next-line`, `glot-disable` and `glot-enable` comments
+//! that no longer suppress any issue. Used by `glot baseline --prune`.
+
+use crate::core::SourceContext;
+
+use super::operation::Operation;
+use super::traits::Action;
+
+/// A suppression comment that no longer suppresses any issue.
+#[derive(Debug, Clone)]
+pub struct StaleDirective {
+    /// Location and text of the comment line.
+    pub context: SourceContext,
+}
+
+/// Action to remove stale suppression comments.
+pub struct RemoveDisableComment;
+
+impl Action<StaleDirective> for RemoveDisableComment {
+    fn to_operations(directives: &[StaleDirective]) -> Vec<Operation> {
+        directives
+            .iter()
+            .map(|directive| Operation::RemoveComment {
+                context: directive.context.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::core::{CommentStyle, SourceLocation};
+
+    fn stale(file: &str, line: usize, text: &str) -> StaleDirective {
+        StaleDirective {
+            context: SourceContext::new(SourceLocation::new(file, line, 1), text, CommentStyle::Js),
+        }
+    }
+
+    #[test]
+    fn test_remove_stale_comments() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.tsx");
+        fs::write(
+            &path,
+            "// glot-disable-next-line hardcoded\nconst a = 1;\n  {/* glot-disable */}\n  <p>x</p>\n",
+        )
+        .unwrap();
+        let file = path.to_string_lossy().to_string();
+
+        let stats = RemoveDisableComment::run(&[
+            stale(&file, 1, "// glot-disable-next-line hardcoded"),
+            stale(&file, 3, "{/* glot-disable */}"),
+        ])
+        .unwrap();
+
+        assert_eq!(stats.changes_applied, 2);
+        assert_eq!(stats.files_modified, 1);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "const a = 1;\n  <p>x</p>\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_skips_non_comment_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.tsx");
+        let content = "const a = 1; // glot-disable-next-line\nconst b = 2;\n";
+        fs::write(&path, content).unwrap();
+        let file = path.to_string_lossy().to_string();
+
+        let stats =
+            RemoveDisableComment::run(&[stale(&file, 1, "// glot-disable-next-line")]).unwrap();
+
+        assert_eq!(stats.changes_applied, 0);
+        assert_eq!(fs::read_to_string(&path).unwrap(), content);
+    }
+}
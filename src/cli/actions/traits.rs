@@ -3,10 +3,13 @@
 //! Actions convert Issues into Operations. Each Action can be implemented
 //! for multiple Issue types, providing type-safe handling.
 
-use anyhow::Result;
-use std::collections::{HashMap, HashSet};
+use anyhow::{Result, bail};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
 
 use super::operation::Operation;
+use crate::core::parsers::yaml::is_yaml_file;
 
 /// Statistics from running an action.
 #[derive(Debug, Clone, Default)]
@@ -19,6 +22,19 @@ pub struct ActionStats {
     pub changes_applied: usize,
     /// Number of files modified.
     pub files_modified: usize,
+    /// Number of changes applied per modified file.
+    pub changes_by_file: BTreeMap<String, usize>,
+    /// Content of each modified file before and after the changes.
+    ///
+    /// Only filled when files are written, not when planning.
+    pub diffs: BTreeMap<String, FileDiff>,
+}
+
+/// Content of a file before and after an action modified it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileDiff {
+    pub before: String,
+    pub after: String,
 }
 
 impl std::ops::AddAssign for ActionStats {
@@ -27,6 +43,16 @@ impl std::ops::AddAssign for ActionStats {
         self.skipped += other.skipped;
         self.changes_applied += other.changes_applied;
         self.files_modified += other.files_modified;
+        for (file_path, changes) in other.changes_by_file {
+            *self.changes_by_file.entry(file_path).or_default() += changes;
+        }
+        // A file changed twice keeps its first `before` and latest `after`
+        for (file_path, diff) in other.diffs {
+            self.diffs
+                .entry(file_path)
+                .and_modify(|existing| existing.after = diff.after.clone())
+                .or_insert(diff);
+        }
     }
 }
 
@@ -71,12 +97,50 @@ pub trait Action<I> {
 }
 
 pub(crate) fn execute_operations(ops: &[Operation]) -> Result<ActionStats> {
+    run_operations(ops, true)
+}
+
+/// Compute the stats of `execute_operations` without writing any file.
+pub(crate) fn plan_operations(ops: &[Operation]) -> Result<ActionStats> {
+    run_operations(ops, false)
+}
+
+fn run_operations(ops: &[Operation], write: bool) -> Result<ActionStats> {
+    // Fail before editing any file, rather than part way through
+    let yaml_file = ops.iter().find_map(|op| match op {
+        Operation::DeleteJsonKey { context, .. } | Operation::InsertJsonKey { context, .. }
+            if is_yaml_file(Path::new(context.file_path())) =>
+        {
+            Some(context.file_path())
+        }
+        _ => None,
+    });
+    if let Some(file_path) = yaml_file {
+        bail!(
+            "Editing YAML locale files is not supported yet: {}",
+            file_path
+        );
+    }
+
     let total = ops.len();
-    let mut files_modified: HashSet<String> = HashSet::new();
+    let mut before_by_file: HashMap<String, String> = HashMap::new();
+    if write {
+        for op in ops {
+            let file_path = op.file_path();
+            if !before_by_file.contains_key(file_path)
+                && let Ok(content) = fs::read_to_string(file_path)
+            {
+                before_by_file.insert(file_path.to_string(), content);
+            }
+        }
+    }
+    let mut changes_by_file: BTreeMap<String, usize> = BTreeMap::new();
     let mut changes_applied = 0;
 
     let mut insert_ops_by_file: HashMap<String, Vec<Operation>> = HashMap::new();
+    let mut remove_ops_by_file: HashMap<String, Vec<Operation>> = HashMap::new();
     let mut delete_ops_by_file: HashMap<String, Vec<Operation>> = HashMap::new();
+    let mut insert_key_ops_by_file: HashMap<String, Vec<Operation>> = HashMap::new();
 
     for op in ops {
         match op {
@@ -86,28 +150,67 @@ pub(crate) fn execute_operations(ops: &[Operation]) -> Result<ActionStats> {
                     .or_default()
                     .push(op.clone());
             }
+            Operation::RemoveComment { context } => {
+                remove_ops_by_file
+                    .entry(context.file_path().to_string())
+                    .or_default()
+                    .push(op.clone());
+            }
             Operation::DeleteJsonKey { context, .. } => {
                 delete_ops_by_file
                     .entry(context.file_path().to_string())
                     .or_default()
                     .push(op.clone());
             }
+            Operation::InsertJsonKey { context, .. } => {
+                insert_key_ops_by_file
+                    .entry(context.file_path().to_string())
+                    .or_default()
+                    .push(op.clone());
+            }
         }
     }
 
     for (file_path, file_ops) in insert_ops_by_file {
-        let applied = Operation::apply_insert_comment_ops(&file_ops)?;
+        let applied = Operation::apply_insert_comment_ops(&file_ops, write)?;
+        if applied > 0 {
+            changes_applied += applied;
+            *changes_by_file.entry(file_path).or_default() += applied;
+        }
+    }
+
+    for (file_path, file_ops) in remove_ops_by_file {
+        let applied = Operation::apply_remove_comment_ops(&file_ops, write)?;
         if applied > 0 {
             changes_applied += applied;
-            files_modified.insert(file_path);
+            *changes_by_file.entry(file_path).or_default() += applied;
         }
     }
 
     for (file_path, file_ops) in delete_ops_by_file {
-        let applied = Operation::apply_delete_json_key_ops(&file_ops)?;
+        let applied = Operation::apply_delete_json_key_ops(&file_ops, write)?;
+        if applied > 0 {
+            changes_applied += applied;
+            *changes_by_file.entry(file_path).or_default() += applied;
+        }
+    }
+
+    for (file_path, file_ops) in insert_key_ops_by_file {
+        let applied = Operation::apply_insert_json_key_ops(&file_ops, write)?;
         if applied > 0 {
             changes_applied += applied;
-            files_modified.insert(file_path);
+            *changes_by_file.entry(file_path).or_default() += applied;
+        }
+    }
+
+    let mut diffs = BTreeMap::new();
+    for file_path in changes_by_file.keys() {
+        let Some(before) = before_by_file.remove(file_path) else {
+            continue;
+        };
+        let after = fs::read_to_string(file_path)?;
+        if before != after {
+            diffs.insert(file_path.clone(), FileDiff { before, after });
         }
     }
 
@@ -115,7 +218,9 @@ pub(crate) fn execute_operations(ops: &[Operation]) -> Result<ActionStats> {
         processed: total,
         skipped: 0,
         changes_applied,
-        files_modified: files_modified.len(),
+        files_modified: changes_by_file.len(),
+        changes_by_file,
+        diffs,
     })
 }
 
@@ -130,12 +235,28 @@ mod tests {
             skipped: 1,
             changes_applied: 4,
             files_modified: 2,
+            changes_by_file: BTreeMap::from([("a.json".to_string(), 3), ("b.json".to_string(), 1)]),
+            diffs: BTreeMap::from([(
+                "a.json".to_string(),
+                FileDiff {
+                    before: "v1".to_string(),
+                    after: "v2".to_string(),
+                },
+            )]),
         };
         let stats2 = ActionStats {
             processed: 3,
             skipped: 2,
             changes_applied: 1,
             files_modified: 1,
+            changes_by_file: BTreeMap::from([("a.json".to_string(), 1)]),
+            diffs: BTreeMap::from([(
+                "a.json".to_string(),
+                FileDiff {
+                    before: "v2".to_string(),
+                    after: "v3".to_string(),
+                },
+            )]),
         };
 
         stats1 += stats2;
@@ -144,6 +265,42 @@ mod tests {
         assert_eq!(stats1.skipped, 3);
         assert_eq!(stats1.changes_applied, 5);
         assert_eq!(stats1.files_modified, 3);
+        assert_eq!(stats1.changes_by_file["a.json"], 4);
+        assert_eq!(stats1.changes_by_file["b.json"], 1);
+        assert_eq!(stats1.diffs["a.json"].before, "v1");
+        assert_eq!(stats1.diffs["a.json"].after, "v3");
+    }
+
+    #[test]
+    fn test_execute_operations_records_diffs() {
+        use crate::core::{CommentStyle, SourceContext, SourceLocation};
+        use crate::issues::Rule;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("app.tsx");
+        fs::write(&file_path, "const a = 1;\nconst b = 2;\n").unwrap();
+        let file_path = file_path.to_string_lossy().to_string();
+        let op = Operation::InsertComment {
+            context: SourceContext::new(
+                SourceLocation::new(&file_path, 2, 1),
+                "const b = 2;",
+                CommentStyle::Js,
+            ),
+            comment: "// glot-disable-next-line hardcoded".to_string(),
+            rule: Rule::HardcodedText,
+        };
+
+        let planned = plan_operations(std::slice::from_ref(&op)).unwrap();
+        assert_eq!(planned.changes_applied, 1);
+        assert!(planned.diffs.is_empty());
+
+        let stats = execute_operations(&[op]).unwrap();
+        let diff = &stats.diffs[&file_path];
+        assert_eq!(diff.before, "const a = 1;\nconst b = 2;\n");
+        assert_eq!(
+            diff.after,
+            "const a = 1;\n// glot-disable-next-line hardcoded\nconst b = 2;\n"
+        );
     }
 
     #[test]
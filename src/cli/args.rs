@@ -10,20 +10,44 @@
 //! - `baseline`: Add disable comments to suppress existing issues
 //! - `fix`: Fix unresolved key issues with glot-message-keys comments
 //! - `init`: Initialize glot configuration file
+//! - `watch`: Re-run checks on file changes
+//! - `stats`: Summarize translation coverage per locale
+//! - `explain`: Describe a rule and how to fix its issues
+//! - `completions`: Print a shell completion script
 //! - `serve`: Start MCP server for AI integration
 
+use std::ffi::OsStr;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
 
+use clap::builder::{PossibleValue, StringValueParser, TypedValueParser};
 use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
 
+use super::actions::FillPolicy;
 use super::commands::check::CheckRule;
+use crate::config::{Framework, PathStyle};
 use crate::core::collect::SuppressibleRule;
+use crate::issues::Rule;
 
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 pub struct Arguments {
     #[command(subcommand)]
     pub command: Option<Command>,
+
+    /// When to use colors (`always` and `never` override `NO_COLOR`)
+    #[arg(long, global = true, value_enum, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
 }
 
 impl Arguments {
@@ -44,13 +68,38 @@ impl Arguments {
             Some(Command::Clean(cmd)) => cmd.args.common.verbose,
             Some(Command::Baseline(cmd)) => cmd.args.common.verbose,
             Some(Command::Fix(cmd)) => cmd.args.common.verbose,
-            Some(Command::Init) | Some(Command::Serve) | None => false,
+            Some(Command::Watch(cmd)) => cmd.args.common.verbose,
+            Some(Command::Stats(cmd)) => cmd.args.common.verbose,
+            Some(Command::Migrate(cmd)) => cmd.args.common.verbose,
+            Some(Command::Explain(_))
+            | Some(Command::Init(_))
+            | Some(Command::Completions(_))
+            | Some(Command::Serve)
+            | None => false,
+        }
+    }
+
+    /// Get the worker thread count from the command's common args.
+    pub fn jobs(&self) -> Option<usize> {
+        match &self.command {
+            Some(Command::Check(cmd)) => cmd.args.common.jobs,
+            Some(Command::Clean(cmd)) => cmd.args.common.jobs,
+            Some(Command::Baseline(cmd)) => cmd.args.common.jobs,
+            Some(Command::Fix(cmd)) => cmd.args.common.jobs,
+            Some(Command::Watch(cmd)) => cmd.args.common.jobs,
+            Some(Command::Stats(cmd)) => cmd.args.common.jobs,
+            Some(Command::Migrate(cmd)) => cmd.args.common.jobs,
+            Some(Command::Explain(_))
+            | Some(Command::Init(_))
+            | Some(Command::Completions(_))
+            | Some(Command::Serve)
+            | None => None,
         }
     }
 }
 
 /// Common arguments shared by all commands.
-#[derive(Debug, Clone, Args)]
+#[derive(Debug, Clone, Default, Args)]
 pub struct CommonArgs {
     /// Primary locale (overrides config file)
     #[arg(long)]
@@ -67,6 +116,26 @@ pub struct CommonArgs {
     /// Enable verbose output
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Number of worker threads (defaults to all CPUs)
+    #[arg(short, long)]
+    pub jobs: Option<usize>,
+
+    /// Reuse parse results of unchanged files between runs
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Cache file path (defaults to .glotcache in the source root)
+    #[arg(long, requires = "cache")]
+    pub cache_location: Option<PathBuf>,
+
+    /// Scan test and story files (overrides `ignoreTestFiles`)
+    #[arg(long)]
+    pub include_tests: bool,
+
+    /// How issue locations print file paths (overrides `pathStyle`)
+    #[arg(long, value_enum)]
+    pub path_style: Option<PathStyle>,
 }
 
 #[derive(Debug, Parser)]
@@ -77,6 +146,94 @@ pub struct CheckArgs {
     /// Exit with code 1 when any warning is found
     #[arg(long)]
     pub error_on_warnings: bool,
+
+    /// Exit with code 1 when more than N warnings are found
+    #[arg(long, value_name = "N")]
+    pub max_warnings: Option<usize>,
+
+    /// Exit with code 3 instead of 1 when a source file can't be parsed
+    #[arg(long)]
+    pub fail_on_parse_error: bool,
+
+    /// Only report issues on lines added since this git ref
+    #[arg(long, value_name = "REF")]
+    pub diff_base: Option<String>,
+
+    /// Only report issues on lines added by this patch
+    #[arg(long, value_name = "PATH", conflicts_with = "diff_base")]
+    pub diff_file: Option<PathBuf>,
+
+    /// Only load these locales, next to the primary locale
+    #[arg(long, value_name = "LOCALES", value_delimiter = ',')]
+    pub locales: Vec<String>,
+
+    /// Only compare these locales with the primary locale
+    #[arg(long, value_name = "LOCALES", value_delimiter = ',')]
+    pub only_changed_locales: Vec<String>,
+
+    /// Only report untranslated keys added longer ago than this, like 7d
+    #[arg(long, value_name = "DURATION", value_parser = super::blame::parse_duration)]
+    pub since: Option<Duration>,
+
+    /// Ignore issues recorded in this baseline file
+    #[arg(long, value_name = "PATH")]
+    pub baseline: Option<PathBuf>,
+
+    /// Report new, pre-existing and fixed issues against this baseline file
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["baseline", "format", "group_by"])]
+    pub baseline_compare: Option<PathBuf>,
+
+    /// Only print issues, without success or timing messages
+    #[arg(long, short)]
+    pub quiet: bool,
+
+    /// Don't print the problem counts after the issues
+    #[arg(long)]
+    pub no_summary: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = CheckFormat::Text)]
+    pub format: CheckFormat,
+
+    /// Write the `--format` output to this file and print text to stdout
+    #[arg(long, value_name = "PATH", conflicts_with = "baseline_compare")]
+    pub report_file: Option<PathBuf>,
+
+    /// Print issues in sections
+    #[arg(long, value_enum, value_name = "GROUP")]
+    pub group_by: Option<GroupBy>,
+
+    /// Print how many issues each rule produced after the issues
+    #[arg(long)]
+    pub stats_footer: bool,
+
+    /// Skip this check; can be repeated
+    #[arg(long, value_enum, value_name = "RULE", hide_possible_values = true)]
+    pub exclude_rule: Vec<CheckRule>,
+
+    /// Run the checks listed in this file, one per line or as a JSON array
+    #[arg(long, value_name = "PATH")]
+    pub rules_from_file: Option<PathBuf>,
+
+    /// Print the time spent in each phase and rule to stderr
+    #[arg(long)]
+    pub profile: bool,
+
+    /// Print how the translation calls on a line resolve to stderr, for debugging
+    #[arg(long, value_name = "FILE:LINE")]
+    pub explain_resolution: Option<SourceLine>,
+
+    /// Check a single file read from stdin (JSON output)
+    #[arg(
+        long,
+        requires = "stdin_filename",
+        conflicts_with_all = ["diff_base", "diff_file", "locales", "only_changed_locales", "since", "baseline", "baseline_compare", "format", "profile", "explain_resolution", "report_file", "rules_from_file"]
+    )]
+    pub stdin: bool,
+
+    /// Path of the --stdin file, relative to the source root
+    #[arg(long, value_name = "PATH", requires = "stdin")]
+    pub stdin_filename: Option<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -101,6 +258,14 @@ pub struct CleanArgs {
     #[arg(long)]
     pub apply: bool,
 
+    /// Preview deletions without modifying files (the default)
+    #[arg(long, conflicts_with = "apply")]
+    pub dry_run: bool,
+
+    /// Don't print a diff of the files changed by `--apply`
+    #[arg(long, short)]
+    pub quiet: bool,
+
     /// Rules to clean (default: all)
     /// Can be specified multiple times: --rules unused --rules orphan
     #[arg(long, value_enum)]
@@ -122,10 +287,22 @@ pub struct BaselineArgs {
     #[arg(long)]
     pub apply: bool,
 
+    /// Don't print a diff of the files changed by `--apply`
+    #[arg(long, short)]
+    pub quiet: bool,
+
     /// Rules to add disable comments for (default: all)
     /// Can be specified multiple times: --rule hardcoded --rule untranslated
-    #[arg(long, value_enum)]
+    #[arg(long, visible_alias = "rule", value_enum)]
     pub rules: Vec<SuppressibleRule>,
+
+    /// Remove disable comments that no longer suppress any issue instead
+    #[arg(long)]
+    pub prune: bool,
+
+    /// Record current issues in a baseline file instead of inserting comments
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["apply", "prune", "rules"])]
+    pub to: Option<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -142,17 +319,206 @@ pub struct FixArgs {
     /// Actually insert comments (default is dry-run)
     #[arg(long)]
     pub apply: bool,
+
+    /// Don't print a diff of the files changed by `--apply`
+    #[arg(long, short)]
+    pub quiet: bool,
+
+    /// Also add missing keys to the primary locale file with placeholder values
+    #[arg(long)]
+    pub create_missing: bool,
+
+    /// Also add keys missing from the other locales, with empty values, the
+    /// primary value, or a `[TODO] <key>` marker
+    #[arg(long, value_enum, value_name = "POLICY")]
+    pub fill: Option<FillPolicy>,
+
+    /// Also sort keys in locale files alphabetically
+    #[arg(long)]
+    pub sort_keys: bool,
+
+    /// Only report locale files with unsorted keys, without other fixes
+    #[arg(long, requires = "sort_keys", conflicts_with = "apply")]
+    pub check: bool,
+
+    /// With `orphan`, insert empty values instead of copies
+    #[arg(long, requires = "rule")]
+    pub empty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FixRule {
+    /// Copy orphan keys into the primary locale
+    Orphan,
 }
 
 #[derive(Debug, Args)]
 pub struct FixCommand {
+    /// Fix only this rule instead of the default fixes
+    #[arg(value_enum, conflicts_with_all = ["create_missing", "fill", "sort_keys"])]
+    pub rule: Option<FixRule>,
     #[command(flatten)]
     pub args: FixArgs,
 }
 
+/// A line of a source file, written `FILE:LINE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLine {
+    pub path: PathBuf,
+    /// 1-based line.
+    pub line: usize,
+}
+
+impl FromStr for SourceLine {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (path, line) = value
+            .rsplit_once(':')
+            .filter(|(path, _)| !path.is_empty())
+            .ok_or_else(|| format!("invalid location '{}': use FILE:LINE", value))?;
+        match line.parse::<usize>() {
+            Ok(line) if line > 0 => Ok(Self {
+                path: PathBuf::from(path),
+                line,
+            }),
+            _ => Err(format!(
+                "invalid location '{}': expected a line number after ':'",
+                value
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum CheckFormat {
+    #[default]
+    Text,
+    Jsonl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    Rule,
+    File,
+    Key,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum StatsFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+#[derive(Debug, Parser)]
+pub struct StatsArgs {
+    #[command(flatten)]
+    pub common: CommonArgs,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = StatsFormat::Table)]
+    pub format: StatsFormat,
+}
+
+#[derive(Debug, Args)]
+pub struct StatsCommand {
+    #[command(flatten)]
+    pub args: StatsArgs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MigrateTarget {
+    Nested,
+    Flat,
+}
+
+#[derive(Debug, Parser)]
+pub struct MigrateArgs {
+    #[command(flatten)]
+    pub common: CommonArgs,
+
+    /// Key layout to convert locale files to
+    #[arg(long, value_enum)]
+    pub to: MigrateTarget,
+
+    /// Only report files that would change, without writing them
+    #[arg(long)]
+    pub check: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct MigrateCommand {
+    #[command(flatten)]
+    pub args: MigrateArgs,
+}
+
+#[derive(Debug, Args)]
+pub struct InitCommand {
+    /// i18n library (detected from package.json by default)
+    #[arg(long, value_enum)]
+    pub library: Option<Framework>,
+
+    /// Overwrite an existing .glotrc.json
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ExplainCommand {
+    /// Rule name, as used by `check` or shown in reports (e.g. replica-lag)
+    #[arg(value_parser = RuleParser, hide_possible_values = true)]
+    pub rule: Rule,
+}
+
+#[derive(Debug, Args)]
+pub struct CompletionsCommand {
+    /// Shell to print the completion script for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+/// Parses rule names like `Rule::from_str`, and lists the names reports use
+/// so shell completion can offer them.
+#[derive(Debug, Clone)]
+struct RuleParser;
+
+impl TypedValueParser for RuleParser {
+    type Value = Rule;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &OsStr,
+    ) -> Result<Rule, clap::Error> {
+        StringValueParser::new()
+            .try_map(|name| name.parse::<Rule>())
+            .parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            Rule::ALL
+                .iter()
+                .map(|rule| PossibleValue::new(rule.to_string())),
+        ))
+    }
+}
+
+/// Exit codes of `check`, listed at the end of its `--help`.
+const CHECK_EXIT_CODES: &str = "\
+Exit codes:
+  0  No errors found
+  1  Errors found, including files that could not be parsed, or too many
+     warnings (--error-on-warnings, --max-warnings)
+  2  Invalid config or arguments, or another failure
+  3  Files could not be parsed, with --fail-on-parse-error";
+
 #[derive(Debug, Subcommand)]
 pub enum Command {
     /// Check for i18n issues (hardcoded text, missing keys, orphan keys, untranslated values)
+    #[command(after_help = CHECK_EXIT_CODES)]
     Check(CheckCommand),
     /// Remove unused or orphan translation keys from JSON files
     Clean(CleanCommand),
@@ -160,8 +526,18 @@ pub enum Command {
     Baseline(BaselineCommand),
     /// Insert glot-message-keys comments for dynamic translation keys
     Fix(FixCommand),
+    /// Re-run checks whenever source or message files change
+    Watch(CheckCommand),
+    /// Show translation coverage per locale
+    Stats(StatsCommand),
+    /// Convert locale files between flat dotted keys and nested objects
+    Migrate(MigrateCommand),
+    /// Explain a rule and how to fix or suppress its issues
+    Explain(ExplainCommand),
     /// Initialize a new .glotrc.json configuration file
-    Init,
+    Init(InitCommand),
+    /// Print a shell completion script (bash, zsh, fish, powershell, elvish)
+    Completions(CompletionsCommand),
     /// Start MCP server for AI coding agents
     Serve,
 }
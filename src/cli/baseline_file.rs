@@ -0,0 +1,186 @@
+//! Baseline files for `baseline --to`, `check --baseline` and
+//! `check --baseline-compare`.
+//!
+//! A baseline file records the fingerprints of accepted issues (see
+//! `IssueFingerprint`), so they can be suppressed without inline comments.
+//! Issues that are not in the file are still reported. Parse errors are
+//! never recorded: a file that can't be parsed can't be checked either.
+
+use std::{collections::HashSet, fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::issues::{Issue, IssueFingerprint};
+
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    issues: Vec<IssueFingerprint>,
+}
+
+/// Current issues split against a baseline.
+#[derive(Debug, Default)]
+pub struct BaselineComparison {
+    /// Issues not recorded in the baseline.
+    pub new: Vec<Issue>,
+    /// Issues recorded in the baseline.
+    pub existing: Vec<Issue>,
+    /// Baseline entries that no current issue matches anymore, sorted.
+    pub fixed: Vec<IssueFingerprint>,
+}
+
+/// Fingerprints of the accepted issues.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    fingerprints: HashSet<IssueFingerprint>,
+}
+
+impl Baseline {
+    /// Read a baseline file written by `write`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read baseline file: {}", path.display()))?;
+        let file: BaselineFile = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse baseline file: {}", path.display()))?;
+        if file.version != BASELINE_VERSION {
+            bail!(
+                "Unsupported baseline file version {} in {} (expected {})",
+                file.version,
+                path.display(),
+                BASELINE_VERSION
+            );
+        }
+        Ok(Self {
+            fingerprints: file.issues.into_iter().collect(),
+        })
+    }
+
+    /// Write the fingerprints of `issues` to `path`, sorted and deduplicated.
+    ///
+    /// Returns the number of fingerprints written.
+    pub fn write(path: &Path, root: &Path, issues: &[Issue]) -> Result<usize> {
+        let mut fingerprints: Vec<IssueFingerprint> = issues
+            .iter()
+            .filter(|issue| !matches!(issue, Issue::ParseError(_)))
+            .map(|issue| issue.fingerprint(root))
+            .collect();
+        fingerprints.sort();
+        fingerprints.dedup();
+
+        let count = fingerprints.len();
+        let file = BaselineFile {
+            version: BASELINE_VERSION,
+            issues: fingerprints,
+        };
+        let content = serde_json::to_string_pretty(&file)?;
+        fs::write(path, content + "\n")
+            .with_context(|| format!("Failed to write baseline file: {}", path.display()))?;
+        Ok(count)
+    }
+
+    /// Split `issues` into new and pre-existing ones, and list the recorded
+    /// issues none of them matches as fixed.
+    pub fn compare(&self, issues: &[Issue], root: &Path) -> BaselineComparison {
+        let mut comparison = BaselineComparison::default();
+        let mut matched = HashSet::new();
+        for issue in issues {
+            let fingerprint = issue.fingerprint(root);
+            if !matches!(issue, Issue::ParseError(_)) && self.fingerprints.contains(&fingerprint) {
+                matched.insert(fingerprint);
+                comparison.existing.push(issue.clone());
+            } else {
+                comparison.new.push(issue.clone());
+            }
+        }
+        comparison.fixed = self
+            .fingerprints
+            .iter()
+            .filter(|fingerprint| !matched.contains(*fingerprint))
+            .cloned()
+            .collect();
+        comparison.fixed.sort();
+        comparison
+    }
+
+    /// Whether `issue` is recorded in the baseline.
+    pub fn contains(&self, issue: &Issue, root: &Path) -> bool {
+        !matches!(issue, Issue::ParseError(_))
+            && self.fingerprints.contains(&issue.fingerprint(root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CommentStyle, SourceContext, SourceLocation};
+    use crate::issues::{HardcodedTextIssue, ParseErrorFileType, ParseErrorIssue, Report};
+
+    fn hardcoded(text: &str) -> Issue {
+        Issue::HardcodedText(HardcodedTextIssue {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 1, 1),
+                format!("<p>{}</p>", text),
+                CommentStyle::Jsx,
+            ),
+            text: text.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_write_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".glot-baseline.json");
+        let root = Path::new(".");
+        let parse_error = Issue::ParseError(ParseErrorIssue {
+            file_path: "./src/broken.tsx".to_string(),
+            error: "Unexpected token".to_string(),
+            file_type: ParseErrorFileType::Source,
+        });
+
+        let count = Baseline::write(
+            &path,
+            root,
+            &[hardcoded("Hello"), hardcoded("Hello"), parse_error.clone()],
+        )
+        .unwrap();
+        assert_eq!(count, 1);
+
+        let baseline = Baseline::load(&path).unwrap();
+        assert!(baseline.contains(&hardcoded("Hello"), root));
+        assert!(!baseline.contains(&hardcoded("World"), root));
+        assert!(!baseline.contains(&parse_error, root));
+    }
+
+    #[test]
+    fn test_compare() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".glot-baseline.json");
+        let root = Path::new(".");
+        Baseline::write(&path, root, &[hardcoded("Hello"), hardcoded("Bye")]).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        let comparison = baseline.compare(&[hardcoded("Hello"), hardcoded("World")], root);
+        assert_eq!(comparison.new.len(), 1);
+        assert_eq!(comparison.new[0].message(), "World");
+        assert_eq!(comparison.existing.len(), 1);
+        assert_eq!(comparison.existing[0].message(), "Hello");
+        let fixed: Vec<&str> = comparison.fixed.iter().map(|f| f.key.as_str()).collect();
+        assert_eq!(fixed, vec!["Bye"]);
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".glot-baseline.json");
+        fs::write(&path, r#"{"version": 2, "issues": []}"#).unwrap();
+
+        let err = Baseline::load(&path).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unsupported baseline file version 2")
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Line ages for `check --since`.
+//!
+//! Runs `git blame` on the primary locale files to find when each line, and
+//! so each key, was added. Untranslated keys added more recently than the
+//! `--since` duration are not reported, since their translations are likely
+//! still on the way.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+
+use crate::issues::{Issue, Report, ReportLocation};
+
+/// Author time of each line, keyed by path under the source root.
+#[derive(Debug)]
+pub struct LineAges {
+    root: PathBuf,
+    files: HashMap<PathBuf, Vec<Option<SystemTime>>>,
+    /// Lines added after this are recent.
+    cutoff: SystemTime,
+}
+
+/// One line of `git blame --line-porcelain` output.
+#[derive(Debug, PartialEq)]
+struct BlameLine {
+    author_time: Option<SystemTime>,
+    /// Commit and path the line was changed from, for lines changed after
+    /// the file was added.
+    previous: Option<(String, String)>,
+    content: String,
+}
+
+impl LineAges {
+    /// Blame `files` (paths relative to `root`) with git.
+    ///
+    /// Files git can't blame, like untracked files or files outside a
+    /// repository, are left out: their lines have no age. Lines that aren't
+    /// committed yet are as old as their last change on disk.
+    ///
+    /// Adding a key after the last one of an object adds a comma to the line
+    /// before it, so a recent line whose text, without the trailing comma,
+    /// was already in the revision it changed from has no age either.
+    pub fn from_git<'a>(
+        root: &Path,
+        files: impl IntoIterator<Item = &'a str>,
+        cutoff: SystemTime,
+    ) -> Result<Self> {
+        let mut ages = HashMap::new();
+        let mut previous_lines: HashMap<(String, String), HashSet<String>> = HashMap::new();
+        for file in files {
+            let output = git(root, &["blame", "--line-porcelain", "--", file])?;
+            let Some(blame) = output else {
+                continue;
+            };
+            let lines = parse_blame(&blame)
+                .into_iter()
+                .map(|line| {
+                    let recent = line.author_time.is_some_and(|time| time > cutoff);
+                    let Some(previous) = line.previous.filter(|_| recent) else {
+                        return Ok(line.author_time);
+                    };
+                    if !previous_lines.contains_key(&previous) {
+                        let spec = format!("{}:{}", previous.0, previous.1);
+                        let content = git(root, &["show", &spec])?.unwrap_or_default();
+                        let lines = content.lines().map(normalize_line).collect();
+                        previous_lines.insert(previous.clone(), lines);
+                    }
+                    let unchanged =
+                        previous_lines[&previous].contains(&normalize_line(&line.content));
+                    Ok(if unchanged { None } else { line.author_time })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            ages.insert(root.join(file), lines);
+        }
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            files: ages,
+            cutoff,
+        })
+    }
+
+    /// When `line` of `file_path` was added, if git knows.
+    pub fn added_at(&self, file_path: &str, line: usize) -> Option<SystemTime> {
+        self.files
+            .get(&self.root.join(file_path))?
+            .get(line.checked_sub(1)?)
+            .copied()
+            .flatten()
+    }
+
+    /// Whether an issue's line was added after the cutoff.
+    ///
+    /// Issues without a line, or whose line has no age, count as old.
+    pub fn is_recent(&self, issue: &Issue) -> bool {
+        let added_at = match issue.location() {
+            ReportLocation::Source(ctx) => self.added_at(ctx.file_path(), ctx.line()),
+            ReportLocation::Message(ctx) => self.added_at(ctx.file_path(), ctx.line()),
+            ReportLocation::File { .. } => None,
+        };
+        added_at.is_some_and(|added_at| added_at > self.cutoff)
+    }
+}
+
+/// Run git in `root` and return its output, or `None` when git fails.
+fn git(root: &Path, args: &[&str]) -> Result<Option<String>> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .with_context(|| "Failed to run git. Is it installed and on PATH?")?;
+    Ok(output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned()))
+}
+
+/// Parse `git blame --line-porcelain` output, one entry per line of the file.
+///
+/// Each line of the file is a header (`<sha> <orig> <final>`), its
+/// `author-time` and `previous` among other fields, then the content after a
+/// tab.
+fn parse_blame(blame: &str) -> Vec<BlameLine> {
+    let mut lines = Vec::new();
+    let mut author_time = None;
+    let mut previous = None;
+    for line in blame.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            lines.push(BlameLine {
+                author_time: author_time.take(),
+                previous: previous.take(),
+                content: content.to_string(),
+            });
+        } else if let Some(seconds) = line.strip_prefix("author-time ") {
+            author_time = seconds
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds));
+        } else if let Some(rest) = line.strip_prefix("previous ") {
+            previous = rest
+                .split_once(' ')
+                .map(|(sha, path)| (sha.to_string(), path.to_string()));
+        }
+    }
+    lines
+}
+
+/// Line text without surrounding whitespace and a trailing comma.
+fn normalize_line(line: &str) -> String {
+    let line = line.trim();
+    line.strip_suffix(',')
+        .unwrap_or(line)
+        .trim_end()
+        .to_string()
+}
+
+/// Parse a duration like `30m`, `12h`, `7d` or `2w`.
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value.len() - value.chars().last().map_or(0, char::len_utf8);
+    let (amount, unit) = value.split_at(split);
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "invalid duration '{}': use a number followed by s, m, h, d or w, like 7d",
+                value
+            ));
+        }
+    };
+    let amount: u64 = amount.parse().map_err(|_| {
+        format!(
+            "invalid duration '{}': expected a number before the unit",
+            value
+        )
+    })?;
+    amount
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("invalid duration '{}': too long", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_duration("12h"), Ok(Duration::from_secs(12 * 3600)));
+        assert_eq!(parse_duration("7d"), Ok(Duration::from_secs(7 * 86400)));
+        assert_eq!(parse_duration("2w"), Ok(Duration::from_secs(14 * 86400)));
+        assert!(parse_duration("7").is_err());
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("7y").is_err());
+        assert!(parse_duration("").is_err());
+    }
+
+    #[test]
+    fn test_parse_blame() {
+        let blame = "\
+aaaaaaaa 1 1 2
+author Alice
+author-time 1700000000
+author-tz +0000
+boundary
+filename messages/en.json
+\t{
+bbbbbbbb 2 2
+author Alice
+author-time 1700000000
+previous aaaaaaaa messages/en.json
+filename messages/en.json
+\t  \"title\": \"Title\",
+";
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(
+            parse_blame(blame),
+            vec![
+                BlameLine {
+                    author_time: Some(time),
+                    previous: None,
+                    content: "{".to_string(),
+                },
+                BlameLine {
+                    author_time: Some(time),
+                    previous: Some(("aaaaaaaa".to_string(), "messages/en.json".to_string())),
+                    content: "  \"title\": \"Title\",".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            normalize_line("  \"title\": \"Title\" , "),
+            "\"title\": \"Title\""
+        );
+    }
+
+    #[test]
+    fn test_from_git() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .env("GIT_AUTHOR_DATE", "2020-01-01T00:00:00Z")
+                .env("GIT_COMMITTER_DATE", "2020-01-01T00:00:00Z")
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "test"]);
+        fs::write(dir.path().join("en.json"), "{\n  \"old\": \"Old\"\n}\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+        fs::write(
+            dir.path().join("en.json"),
+            "{\n  \"old\": \"Old\",\n  \"new\": \"New\"\n}\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("untracked.json"), "{}\n").unwrap();
+
+        let cutoff = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+        let ages = LineAges::from_git(dir.path(), ["en.json", "untracked.json"], cutoff).unwrap();
+        let committed = UNIX_EPOCH + Duration::from_secs(1_577_836_800);
+        assert_eq!(ages.added_at("en.json", 1), Some(committed));
+        // Only a comma was added to the line of "old"
+        assert_eq!(ages.added_at("en.json", 2), None);
+        assert!(ages.added_at("./en.json", 3).unwrap() > cutoff);
+        assert_eq!(ages.added_at("en.json", 4), Some(committed));
+        assert_eq!(ages.added_at("en.json", 5), None);
+        assert_eq!(ages.added_at("untracked.json", 1), None);
+    }
+}
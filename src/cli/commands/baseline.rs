@@ -7,26 +7,55 @@
 //! - `hardcoded`: Suppress hardcoded text issues
 //! - `untranslated`: Suppress untranslated value issues
 //!
-//! Use `--apply` to actually insert comments (default is dry-run mode).
+//! Use `--apply` to actually insert comments (default is dry-run mode). The
+//! inserted comments are then printed as a unified diff, unless `--quiet` is
+//! given.
+//!
+//! With `--prune`, the command works the other way around: it removes
+//! suppression comments whose lines no longer produce any of the suppressed
+//! issues.
+//!
+//! With `--to <file>`, no comment is inserted: the issues of every check are
+//! recorded in a baseline file instead, for `check --baseline <file>`.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
 
 use anyhow::Result;
 use colored::Colorize;
 
 use super::super::{
-    actions::{Action, ActionStats, InsertDisableComment, execute_operations},
+    actions::{
+        Action, ActionStats, InsertDisableComment, RemoveDisableComment, StaleDirective,
+        execute_operations,
+    },
     args::BaselineCommand,
+    baseline_file::Baseline,
+    commands::check::collect_issues,
     exit_status::ExitStatus,
-    report::{self, FAILURE_MARK},
+    report::{self, FAILURE_MARK, SUCCESS_MARK},
 };
 use crate::{
-    core::{CheckContext, collect::SuppressibleRule},
+    core::{CheckContext, CommentStyle, SourceContext, SourceLocation, collect::SuppressibleRule},
     issues::{HardcodedTextIssue, UntranslatedIssue},
-    rules::{hardcoded::check_hardcoded_text_issues, untranslated::check_untranslated_issues},
+    rules::{
+        empty_key::check_empty_key_issues, hardcoded::check_hardcoded_text_issues,
+        interpolation_mismatch::check_interpolation_mismatch_issues,
+        key_naming::check_key_naming_issues, markup_only::check_markup_only_issues,
+        namespace_consistency::check_namespace_consistency_issues,
+        unmatched_message_keys::check_unmatched_message_keys_issues,
+        untranslated::check_untranslated_issues,
+    },
 };
 
 pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
+    if cmd.args.prune {
+        return prune(cmd, verbose);
+    }
+    if let Some(path) = &cmd.args.to {
+        return write_baseline_file(&cmd, path, verbose);
+    }
+
     let args = &cmd.args;
     let rules = &cmd.args.rules;
     let ctx = CheckContext::new(&args.common)?;
@@ -50,6 +79,13 @@ pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
                 let issues = check_untranslated_issues(&ctx);
                 untranslated_issues.extend(issues);
             }
+            // Only reachable through the default rule set; not baseline rules
+            SuppressibleRule::KeyNaming
+            | SuppressibleRule::MarkupOnly
+            | SuppressibleRule::UnmatchedMessageKeys
+            | SuppressibleRule::NamespaceConsistency
+            | SuppressibleRule::InterpolationMismatch
+            | SuppressibleRule::EmptyKey => {}
         }
     }
 
@@ -73,6 +109,7 @@ pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
     let comment_total = hardcoded_count + untranslated_usage_count;
     let has_issues = comment_total > 0 || unsuppressible_untranslated_count > 0;
 
+    let mut diffs = BTreeMap::new();
     let (file_count, applied_hardcoded_count, applied_untranslated_count, applied_total_count) =
         if apply {
             let mut ops = Vec::new();
@@ -91,6 +128,7 @@ pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
                 execute_operations(&ops)?
             };
 
+            diffs = stats.diffs;
             let applied_hardcoded_count = unique_hardcoded_lines(&hardcoded_issues);
             let applied_untranslated_count =
                 unique_untranslated_lines(&suppressible_untranslated_issues);
@@ -129,6 +167,9 @@ pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
         }
 
         if apply {
+            if !args.quiet {
+                report::print_diffs(&diffs);
+            }
             if comment_total > 0 {
                 println!(
                     "{} {} comment(s) in {} file(s) (processed {} issue(s)):",
@@ -177,7 +218,7 @@ pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
     }
 
     let parse_error_count = ctx.parsed_files_errors().len();
-    report::print_parse_error(parse_error_count, verbose);
+    report::print_parse_error(ctx.parsed_files_errors(), verbose);
 
     // Determine exit status
     // In dry-run mode, finding issues is considered "Failure" (exit 1)
@@ -191,6 +232,286 @@ pub fn baseline(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
     }
 }
 
+/// Record the issues of every check in a baseline file.
+fn write_baseline_file(cmd: &BaselineCommand, path: &Path, verbose: bool) -> Result<ExitStatus> {
+    let ctx = CheckContext::new(&cmd.args.common)?;
+    let issues = collect_issues(&ctx, &[]);
+    let count = Baseline::write(path, &ctx.root_dir, &issues)?;
+    println!(
+        "{} {} issue(s) to {}.",
+        "Recorded".green().bold(),
+        count,
+        path.display()
+    );
+
+    let parse_error_count = ctx.parsed_files_errors().len();
+    report::print_parse_error(ctx.parsed_files_errors(), verbose);
+
+    if parse_error_count > 0 {
+        Ok(ExitStatus::Error)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Remove suppression comments that no longer suppress any issue.
+fn prune(cmd: BaselineCommand, verbose: bool) -> Result<ExitStatus> {
+    let args = &cmd.args;
+    let mut ctx = CheckContext::new(&args.common)?;
+    ctx.include_suppressed();
+
+    let rules = if args.rules.is_empty() {
+        SuppressibleRule::all()
+    } else {
+        args.rules.iter().copied().collect()
+    };
+
+    let stale = find_stale_directives(&ctx, &rules);
+    let file_count = stale
+        .iter()
+        .map(|directive| directive.context.file_path())
+        .collect::<HashSet<_>>()
+        .len();
+
+    if stale.is_empty() {
+        println!("{} No stale disable comments found.", SUCCESS_MARK.green());
+    } else if args.apply {
+        let stats = RemoveDisableComment::run(&stale)?;
+        if !args.quiet {
+            report::print_diffs(&stats.diffs);
+        }
+        println!(
+            "{} {} stale comment(s) in {} file(s).",
+            "Removed".green().bold(),
+            stats.changes_applied,
+            stats.files_modified
+        );
+    } else {
+        RemoveDisableComment::preview(&stale);
+        println!(
+            "{} {} stale comment(s) in {} file(s).",
+            "Would remove".yellow().bold(),
+            stale.len(),
+            file_count
+        );
+        println!("Run with {} to remove these comments.", "--apply".cyan());
+    }
+
+    let parse_error_count = ctx.parsed_files_errors().len();
+    report::print_parse_error(ctx.parsed_files_errors(), verbose);
+
+    if parse_error_count > 0 {
+        Ok(ExitStatus::Error)
+    } else if !stale.is_empty() && !args.apply {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Lines with an issue, per file and rule, ignoring suppressions.
+type IssueLines = HashMap<String, HashMap<SuppressibleRule, HashSet<usize>>>;
+
+/// Find suppression comments whose lines no longer produce a suppressed issue.
+///
+/// - `glot-disable-next-line` is stale when none of its rules has an issue on
+///   the target line
+/// - `glot-disable` is stale when every range it opens is free of issues
+/// - `glot-enable` is stale when every range it closes is stale and opened by
+///   a stale `glot-disable`, so a remaining `glot-disable` never loses its end
+///
+/// Comments naming a rule outside `rules` are kept.
+fn find_stale_directives(
+    ctx: &CheckContext,
+    rules: &HashSet<SuppressibleRule>,
+) -> Vec<StaleDirective> {
+    let issue_lines = collect_issue_lines(ctx, rules);
+    let no_lines = HashMap::new();
+
+    let mut file_paths: Vec<&String> = ctx.file_comments().keys().collect();
+    file_paths.sort();
+
+    let mut stale = Vec::new();
+    for file_path in file_paths {
+        let suppressions = &ctx.file_comments()[file_path].suppressions;
+        let lines = issue_lines.get(file_path).unwrap_or(&no_lines);
+        let has_issue = |rule: SuppressibleRule, start: usize, end: usize| {
+            !rules.contains(&rule)
+                || lines
+                    .get(&rule)
+                    .is_some_and(|lines| lines.iter().any(|line| (start..=end).contains(line)))
+        };
+
+        let mut stale_lines = BTreeSet::new();
+        for directive in &suppressions.next_line_directives {
+            let target = directive.target_line;
+            if !directive
+                .rules
+                .iter()
+                .any(|rule| has_issue(*rule, target, target))
+            {
+                stale_lines.insert(directive.line);
+            }
+        }
+
+        // Line of each range comment -> whether any of its ranges is needed
+        let mut disables: HashMap<usize, bool> = HashMap::new();
+        let mut enables: HashMap<usize, Vec<(usize, bool)>> = HashMap::new();
+        for (rule, ranges) in &suppressions.disabled_ranges {
+            for range in ranges {
+                let needed = has_issue(*rule, range.start, range.end);
+                *disables.entry(range.start).or_default() |= needed;
+                if range.end != usize::MAX {
+                    enables
+                        .entry(range.end + 1)
+                        .or_default()
+                        .push((range.start, needed));
+                }
+            }
+        }
+        let stale_disables: HashSet<usize> = disables
+            .into_iter()
+            .filter(|(_, needed)| !needed)
+            .map(|(line, _)| line)
+            .collect();
+        for (line, closed) in enables {
+            if closed
+                .iter()
+                .all(|(start, needed)| !needed && stale_disables.contains(start))
+            {
+                stale_lines.insert(line);
+            }
+        }
+        stale_lines.extend(stale_disables);
+
+        if stale_lines.is_empty() {
+            continue;
+        }
+        let source = std::fs::read_to_string(file_path).unwrap_or_default();
+        let source_lines: Vec<&str> = source.lines().collect();
+        for line in stale_lines {
+            let text = source_lines
+                .get(line - 1)
+                .map(|text| text.trim())
+                .unwrap_or_default();
+            stale.push(StaleDirective {
+                context: SourceContext::new(
+                    SourceLocation::new(file_path, line, 1),
+                    text,
+                    CommentStyle::Js,
+                ),
+            });
+        }
+    }
+
+    stale
+}
+
+fn collect_issue_lines(ctx: &CheckContext, rules: &HashSet<SuppressibleRule>) -> IssueLines {
+    let mut issue_lines: IssueLines = HashMap::new();
+    let mut add = |file_path: &str, line: usize, rule: SuppressibleRule| {
+        issue_lines
+            .entry(file_path.to_string())
+            .or_default()
+            .entry(rule)
+            .or_default()
+            .insert(line);
+    };
+
+    if rules.contains(&SuppressibleRule::Hardcoded) {
+        for issue in check_hardcoded_text_issues(ctx) {
+            add(
+                issue.context.file_path(),
+                issue.context.line(),
+                SuppressibleRule::Hardcoded,
+            );
+        }
+    }
+    if rules.contains(&SuppressibleRule::Untranslated) {
+        for issue in check_untranslated_issues(ctx) {
+            for usage in &issue.usages {
+                add(
+                    usage.context.file_path(),
+                    usage.context.line(),
+                    SuppressibleRule::Untranslated,
+                );
+            }
+        }
+    }
+    if rules.contains(&SuppressibleRule::KeyNaming) {
+        for issue in check_key_naming_issues(ctx) {
+            for usage in &issue.usages {
+                add(
+                    usage.context.file_path(),
+                    usage.context.line(),
+                    SuppressibleRule::KeyNaming,
+                );
+            }
+        }
+    }
+
+    if rules.contains(&SuppressibleRule::MarkupOnly) {
+        for issue in check_markup_only_issues(ctx) {
+            for usage in &issue.usages {
+                add(
+                    usage.context.file_path(),
+                    usage.context.line(),
+                    SuppressibleRule::MarkupOnly,
+                );
+            }
+        }
+    }
+
+    if rules.contains(&SuppressibleRule::UnmatchedMessageKeys) {
+        for issue in check_unmatched_message_keys_issues(ctx) {
+            add(
+                issue.context.file_path(),
+                issue.context.line(),
+                SuppressibleRule::UnmatchedMessageKeys,
+            );
+        }
+    }
+
+    if rules.contains(&SuppressibleRule::NamespaceConsistency) {
+        for issue in check_namespace_consistency_issues(ctx) {
+            add(
+                issue.context.file_path(),
+                issue.context.line(),
+                SuppressibleRule::NamespaceConsistency,
+            );
+            for usage in &issue.usages {
+                add(
+                    usage.context.file_path(),
+                    usage.context.line(),
+                    SuppressibleRule::NamespaceConsistency,
+                );
+            }
+        }
+    }
+
+    if rules.contains(&SuppressibleRule::InterpolationMismatch) {
+        for issue in check_interpolation_mismatch_issues(ctx) {
+            add(
+                issue.context.file_path(),
+                issue.context.line(),
+                SuppressibleRule::InterpolationMismatch,
+            );
+        }
+    }
+
+    if rules.contains(&SuppressibleRule::EmptyKey) {
+        for issue in check_empty_key_issues(ctx) {
+            add(
+                issue.context.file_path(),
+                issue.context.line(),
+                SuppressibleRule::EmptyKey,
+            );
+        }
+    }
+
+    issue_lines
+}
+
 fn print_untranslated_without_usages(issues: &[&UntranslatedIssue]) {
     eprintln!(
         "Error: {} {} untranslated key issue(s) cannot be suppressed with source comments because no usages were found.",
@@ -4,29 +4,119 @@
 //! - `hardcoded`: Detect hardcoded text that should be translated
 //! - `missing`: Find translation keys used in code but not in message files
 //! - `unused`: Find keys in message files that are never used
+//! - `unused-namespace`: Find top-level namespaces that are never referenced
 //! - `orphan`: Find keys in message files that don't exist in primary locale
 //! - `replica-lag`: Find keys missing in non-primary locales
 //! - `untranslated`: Find keys with untranslated values (same as English)
 //! - `type-mismatch`: Find keys with mismatched value types across locales
+//! - `placeholder-mismatch`: Find keys whose ICU placeholders differ across locales
+//! - `key-naming`: Find keys that don't follow the configured `keyNaming` case
+//! - `empty-value`: Find empty or whitespace-only translation values
+//! - `plural-categories`: Find ICU plurals missing or misusing CLDR categories
+//! - `markup-only`: Find values made of rich text tags without any text
+//! - `rich-tag-mismatch`: Find `t.rich`/`t.markup` keys whose tags differ across locales
 //! - `unresolved`: Find dynamic keys that couldn't be statically resolved
+//! - `unmatched-message-keys`: Find `glot-message-keys` patterns that match no key
+//! - `namespace-consistency`: Find keys reached through different namespaces
+//! - `unpaired-directive`: Find `glot-enable`/`glot-disable` comments that don't pair up
+//! - `interpolation-mismatch`: Find `t()` values that differ from the message's ICU arguments
+//! - `duplicate-value`: Find values of the primary locale shared by many keys
+//! - `stray-whitespace`: Find values with leading or trailing whitespace, or differing from the primary value only by it
+//! - `empty-key`: Find `t("")` calls with an empty or whitespace-only key
+//! - `duplicate-key`: Find keys defined more than once in the same object of a locale file
 //!
-//! By default, all checks are run. You can specify specific checks to run.
+//! By default, the checks listed in the config's `rules` are run. Without
+//! `rules`, all checks are run except `unmatched-message-keys`,
+//! `namespace-consistency` and `duplicate-value`, which only run when named
+//! or given a severity in `severities`. You can specify
+//! specific checks to run, read them from a file with `--rules-from-file
+//! <path>`, or leave some out with `--exclude-rule <rule>`.
+//! With `--diff-base <ref>` (or `--diff-file <patch>`), only issues on lines
+//! added since `ref` are reported.
+//!
+//! With `--only-changed-locales de,fr`, the rules comparing locales with the
+//! primary locale only look at the listed locales, so CI can skip the locales
+//! a change didn't touch. Source file rules still run on the whole project.
+//!
+//! With `--since 7d`, untranslated keys whose line in the primary locale file
+//! `git blame` dates to the last 7 days are not reported yet.
+//!
+//! With `--baseline <file>`, issues recorded by `baseline --to <file>` are
+//! not reported. New issues still are.
+//!
+//! With `--baseline-compare <file>`, the issues are split against the file
+//! instead: new issues are reported in full, then pre-existing issues and
+//! the recorded issues that were fixed are listed. Only new issues fail the
+//! command.
+//!
+//! `--quiet` only prints the issues (the exit code still reflects parse
+//! errors), and `--no-summary` drops the problem counts after them.
+//!
+//! `--group-by rule|file|key` prints the issues in sections with a count each,
+//! instead of one list sorted by location.
+//!
+//! `--profile` prints the wall-clock time of each phase (file scanning,
+//! locale loading, parsing, Phases 1-3) and of each rule to stderr.
+//!
+//! `--explain-resolution FILE:LINE` prints to stderr how each translation call
+//! on that line resolves: its binding, the registries and candidate values
+//! its key came from, and the keys it resolved to.
+//!
+//! With `--format jsonl`, each issue is written as one JSON object per line
+//! as soon as its check finishes, followed by a summary line with the counts.
+//!
+//! Errors fail the command (exit 1). Warnings only do with
+//! `--error-on-warnings` or when there are more than `--max-warnings N`.
+//! Files that can't be parsed are errors too, or exit with 3 instead with
+//! `--fail-on-parse-error`. Config and usage errors exit with 2.
+//!
+//! When the config lists `projects`, each project is checked on its own and
+//! the issues are reported in one section per project. JSON Lines records
+//! name their project, and `--group-by` groups within each project.
+//!
+//! With `--stdin --stdin-filename <path>`, a single file is read from stdin
+//! and only the per-file checks (`hardcoded`, `unresolved`) run against it.
+//! The project is not scanned, and diagnostics are printed as JSON with byte
+//! offsets for editor integrations.
+
+use std::collections::BTreeSet;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
 use clap::ValueEnum;
+use colored::Colorize;
+use serde::Serialize;
 
-use super::super::args::CheckCommand;
+use super::super::args::{CheckArgs, CheckCommand, CheckFormat};
+use super::super::baseline_file::Baseline;
+use super::super::blame::LineAges;
+use super::super::diff::ChangedLines;
 use super::super::exit_status::ExitStatus;
-use super::super::report;
+use super::super::report::{self, FAILURE_MARK};
+use super::super::resolution;
 
 use crate::{
+    config::Config,
     core::CheckContext,
-    issues::{Issue, Severity},
+    issues::{Issue, Report, ReportLocation, Rule, Severity},
     rules::{
-        hardcoded::check_hardcoded_text_issues, missing::check_missing_keys_issues,
-        orphan::check_orphan_keys_issues, replica_lag::check_replica_lag_issues,
-        type_mismatch::check_type_mismatch_issues, unresolved::check_unresolved_keys_issues,
-        untranslated::check_untranslated_issues, unused::check_unused_keys_issues,
+        duplicate_key::check_duplicate_key_issues, duplicate_value::check_duplicate_value_issues,
+        empty_key::check_empty_key_issues, empty_value::check_empty_value_issues,
+        hardcoded::check_hardcoded_text_issues,
+        interpolation_mismatch::check_interpolation_mismatch_issues,
+        key_naming::check_key_naming_issues, markup_only::check_markup_only_issues,
+        missing::check_missing_keys_issues,
+        namespace_consistency::check_namespace_consistency_issues,
+        orphan::check_orphan_keys_issues, placeholder_mismatch::check_placeholder_mismatch_issues,
+        plural_categories::check_plural_categories_issues, replica_lag::check_replica_lag_issues,
+        rich_tag_mismatch::check_rich_tag_mismatch_issues,
+        stray_whitespace::check_stray_whitespace_issues, type_mismatch::check_type_mismatch_issues,
+        unmatched_message_keys::check_unmatched_message_keys_issues,
+        unpaired_directive::check_unpaired_directive_issues,
+        unresolved::check_unresolved_keys_issues, untranslated::check_untranslated_issues,
+        unused::check_unused_keys_issues, unused_namespace::check_unused_namespaces_issues,
     },
 };
 
@@ -35,11 +125,26 @@ pub enum CheckRule {
     Hardcoded,
     Missing,
     Unused,
+    UnusedNamespace,
     Orphan,
     ReplicaLag,
     Untranslated,
     TypeMismatch,
+    PlaceholderMismatch,
+    KeyNaming,
+    EmptyValue,
+    PluralCategories,
+    MarkupOnly,
+    RichTagMismatch,
     Unresolved,
+    UnmatchedMessageKeys,
+    NamespaceConsistency,
+    UnpairedDirective,
+    InterpolationMismatch,
+    DuplicateValue,
+    StrayWhitespace,
+    EmptyKey,
+    DuplicateKey,
 }
 
 impl CheckRule {
@@ -48,91 +153,929 @@ impl CheckRule {
             CheckRule::Hardcoded,
             CheckRule::Missing,
             CheckRule::Unused,
+            CheckRule::UnusedNamespace,
             CheckRule::Orphan,
             CheckRule::ReplicaLag,
             CheckRule::Untranslated,
             CheckRule::TypeMismatch,
+            CheckRule::PlaceholderMismatch,
+            CheckRule::KeyNaming,
+            CheckRule::EmptyValue,
+            CheckRule::PluralCategories,
+            CheckRule::MarkupOnly,
+            CheckRule::RichTagMismatch,
             CheckRule::Unresolved,
+            CheckRule::UnmatchedMessageKeys,
+            CheckRule::NamespaceConsistency,
+            CheckRule::UnpairedDirective,
+            CheckRule::InterpolationMismatch,
+            CheckRule::DuplicateValue,
+            CheckRule::StrayWhitespace,
+            CheckRule::EmptyKey,
+            CheckRule::DuplicateKey,
         ]
     }
+
+    /// Rule of the issues this check reports.
+    pub fn rule(self) -> Rule {
+        match self {
+            CheckRule::Hardcoded => Rule::HardcodedText,
+            CheckRule::Missing => Rule::MissingKey,
+            CheckRule::Unused => Rule::UnusedKey,
+            CheckRule::UnusedNamespace => Rule::UnusedNamespace,
+            CheckRule::Orphan => Rule::OrphanKey,
+            CheckRule::ReplicaLag => Rule::ReplicaLag,
+            CheckRule::Untranslated => Rule::Untranslated,
+            CheckRule::TypeMismatch => Rule::TypeMismatch,
+            CheckRule::PlaceholderMismatch => Rule::PlaceholderMismatch,
+            CheckRule::KeyNaming => Rule::KeyNaming,
+            CheckRule::EmptyValue => Rule::EmptyValue,
+            CheckRule::PluralCategories => Rule::PluralCategories,
+            CheckRule::MarkupOnly => Rule::MarkupOnly,
+            CheckRule::RichTagMismatch => Rule::RichTagMismatch,
+            CheckRule::Unresolved => Rule::UnresolvedKey,
+            CheckRule::UnmatchedMessageKeys => Rule::UnmatchedMessageKeys,
+            CheckRule::NamespaceConsistency => Rule::NamespaceConsistency,
+            CheckRule::UnpairedDirective => Rule::UnpairedDirective,
+            CheckRule::InterpolationMismatch => Rule::InterpolationMismatch,
+            CheckRule::DuplicateValue => Rule::DuplicateValue,
+            CheckRule::StrayWhitespace => Rule::StrayWhitespace,
+            CheckRule::EmptyKey => Rule::EmptyKey,
+            CheckRule::DuplicateKey => Rule::DuplicateKey,
+        }
+    }
+
+    /// Checks run when none are given: the config's `rules` when set.
+    /// Otherwise all of them, except the opt-in ones that `severities`
+    /// doesn't set to `error` or `warning`.
+    ///
+    /// The opt-in checks are off by default: they only run when named on the
+    /// command line or in `rules`, or when `severities` gives them a severity.
+    pub fn defaults(config: &Config) -> Vec<CheckRule> {
+        if !config.rules.is_empty() {
+            let rules = config.selected_rules();
+            return Self::all()
+                .into_iter()
+                .filter(|check| rules.contains(&check.rule()))
+                .collect();
+        }
+        Self::all()
+            .into_iter()
+            .filter(|check| {
+                let opt_in = matches!(
+                    check,
+                    CheckRule::UnmatchedMessageKeys
+                        | CheckRule::NamespaceConsistency
+                        | CheckRule::DuplicateValue
+                );
+                !opt_in
+                    || config
+                        .severities
+                        .get(&check.rule())
+                        .is_some_and(|o| o.severity().is_some())
+            })
+            .collect()
+    }
+}
+
+/// Diagnostics for the file checked with `--stdin`.
+#[derive(Debug, Serialize)]
+pub struct StdinReport {
+    pub file: String,
+    pub diagnostics: Vec<StdinDiagnostic>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StdinDiagnostic {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// 1-based line.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// Byte offset of `line:column` in the file content.
+    pub offset: usize,
 }
 
 pub fn check(cmd: CheckCommand, verbose: bool) -> Result<ExitStatus> {
     let args = &cmd.args;
-    let checks = &cmd.checks;
-    let ctx = CheckContext::new(&args.common)?;
+    if let Some(file_path) = args.stdin_filename.as_ref().filter(|_| args.stdin) {
+        return check_stdin(&cmd, &file_path.to_string_lossy());
+    }
+    let mut contexts = CheckContext::for_projects(&args.common)?;
+    if let Some(target) = &args.explain_resolution {
+        resolution::print_explanation(&contexts, target);
+    }
+    if contexts.len() > 1 {
+        return check_projects(&cmd, contexts, verbose);
+    }
+    let mut ctx = contexts.remove(0);
+    if !args.locales.is_empty() {
+        ctx.only_load_locales(&args.locales)?;
+    }
+    if !args.only_changed_locales.is_empty() {
+        ctx.only_compare_locales(&args.only_changed_locales)?;
+    }
 
-    let checks = if checks.is_empty() {
-        CheckRule::all()
+    let filter = IssueFilter::new(args, &ctx)?;
+    if args.format == CheckFormat::Jsonl && args.report_file.is_none() {
+        let status = check_jsonl(&cmd, &ctx, &filter)?;
+        if args.profile {
+            report::print_profile(&ctx.profile().timings());
+        }
+        return Ok(status);
+    }
+
+    let checks = selected_checks(&cmd, &ctx.config)?;
+    let mut all_issues = collect_issues(&ctx, &checks);
+    if let Some(path) = &args.baseline_compare {
+        return check_baseline_compare(args, &ctx, &all_issues, &filter, path, verbose);
+    }
+    all_issues.retain(|issue| filter.keeps(issue));
+    let parse_errors = ctx.parsed_files_errors();
+    if let Some(path) = &args.report_file {
+        write_report_file(args, &ctx, &all_issues, path)?;
+    }
+
+    let parse_error_count = parse_errors.len();
+    let has_errors = all_issues.iter().any(|issue| {
+        ctx.config.severity_for_rule(issue.rule(), issue.severity()) == Severity::Error
+    });
+    let warning_count = all_issues
+        .iter()
+        .filter(|issue| {
+            ctx.config.severity_for_rule(issue.rule(), issue.severity()) == Severity::Warning
+        })
+        .count();
+    let too_many_warnings = args.max_warnings.is_some_and(|max| warning_count > max);
+
+    // Print output
+    if all_issues.is_empty() {
+        if !args.quiet {
+            report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+        }
+    } else if let Some(group_by) = args.group_by {
+        report::report_grouped_with_config(
+            &all_issues,
+            &ctx.config,
+            &ctx.config_root,
+            group_by,
+            !args.no_summary,
+        );
+    } else if args.no_summary {
+        report::report_without_summary_with_config(&all_issues, &ctx.config, &ctx.config_root);
     } else {
-        checks.clone()
-    };
+        report::report_with_config(&all_issues, &ctx.config, &ctx.config_root);
+    }
+    if args.stats_footer {
+        report::print_rule_counts(&all_issues);
+    }
+    if !args.quiet {
+        report::print_parse_error(parse_errors, verbose);
+    }
+    if let Some(max) = args.max_warnings.filter(|_| too_many_warnings) {
+        println!(
+            "{} Too many warnings ({}, maximum: {}).",
+            FAILURE_MARK.red(),
+            warning_count,
+            max
+        );
+    }
+    if args.profile {
+        report::print_profile(&ctx.profile().timings());
+    }
 
-    let mut all_issues: Vec<Issue> = Vec::new();
+    Ok(check_exit_status(
+        args,
+        parse_error_count,
+        has_errors,
+        warning_count,
+    ))
+}
 
-    for check in checks {
-        match check {
-            CheckRule::Hardcoded => {
-                let issues = check_hardcoded_text_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::HardcodedText));
-            }
-            CheckRule::Missing => {
-                let issues = check_missing_keys_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::MissingKey));
-            }
-            CheckRule::Unused => {
-                let issues = check_unused_keys_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::UnusedKey));
-            }
-            CheckRule::Orphan => {
-                let issues = check_orphan_keys_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::OrphanKey));
+/// Report `issues` as new, pre-existing or fixed against a baseline file.
+///
+/// Fixed issues are found before `filter` applies, so an issue that is only
+/// left out of the report (by `--diff-base`, for instance) isn't taken for
+/// fixed.
+fn check_baseline_compare(
+    args: &CheckArgs,
+    ctx: &CheckContext,
+    issues: &[Issue],
+    filter: &IssueFilter,
+    path: &Path,
+    verbose: bool,
+) -> Result<ExitStatus> {
+    let baseline = Baseline::load(path)?;
+    let mut comparison = baseline.compare(issues, &ctx.root_dir);
+    comparison.new.retain(|issue| filter.keeps(issue));
+    comparison.existing.retain(|issue| filter.keeps(issue));
+
+    report::report_baseline_comparison_with_config(&comparison, &ctx.config, &ctx.config_root);
+    let parse_errors = ctx.parsed_files_errors();
+    if !args.quiet {
+        report::print_parse_error(parse_errors, verbose);
+    }
+    if args.profile {
+        report::print_profile(&ctx.profile().timings());
+    }
+
+    Ok(if args.fail_on_parse_error && !parse_errors.is_empty() {
+        ExitStatus::ParseError
+    } else if !comparison.new.is_empty() {
+        ExitStatus::Failure
+    } else {
+        ExitStatus::Success
+    })
+}
+
+/// Check every project of a monorepo config on its own, then report the
+/// issues in one section per project with a single summary.
+///
+/// Each project has its own context, so keys and registries of one project
+/// never resolve usages in another.
+fn check_projects(
+    cmd: &CheckCommand,
+    contexts: Vec<CheckContext>,
+    verbose: bool,
+) -> Result<ExitStatus> {
+    let args = &cmd.args;
+    if args.baseline.is_some() || args.baseline_compare.is_some() || args.report_file.is_some() {
+        bail!(
+            "--baseline, --baseline-compare and --report-file don't support a config with 'projects'"
+        );
+    }
+
+    // Projects only differ in roots, messages and includes, so severity
+    // overrides, the path style and the config root are the same for all
+    // of them
+    let config = contexts[0].config.clone();
+    let config_root = contexts[0].config_root.clone();
+    let mut projects = Vec::new();
+    let mut parse_errors = Vec::new();
+    let (mut source_files, mut locale_files) = (0, 0);
+    for mut ctx in contexts {
+        if !args.locales.is_empty() {
+            ctx.only_load_locales(&args.locales)?;
+        }
+        if !args.only_changed_locales.is_empty() {
+            ctx.only_compare_locales(&args.only_changed_locales)?;
+        }
+        let filter = IssueFilter::new(args, &ctx)?;
+        let checks = selected_checks(cmd, &ctx.config)?;
+        let mut issues = collect_issues(&ctx, &checks);
+        issues.retain(|issue| filter.keeps(issue));
+        parse_errors.extend(ctx.parsed_files_errors().iter().cloned());
+        source_files += ctx.files.len();
+        locale_files += ctx.messages().all_messages.len();
+        if args.profile {
+            eprintln!("{}", ctx.project.as_deref().unwrap_or_default().bold());
+            report::print_profile(&ctx.profile().timings());
+        }
+        projects.push((ctx.project.clone().unwrap_or_default(), issues));
+    }
+
+    if args.format == CheckFormat::Jsonl {
+        return write_projects_jsonl(args, &config, &projects, parse_errors.len());
+    }
+
+    let all_issues: Vec<Issue> = projects
+        .iter()
+        .flat_map(|(_, issues)| issues.iter().cloned())
+        .collect();
+    let severity = |issue: &Issue| config.severity_for_rule(issue.rule(), issue.severity());
+    let has_errors = all_issues
+        .iter()
+        .any(|issue| severity(issue) == Severity::Error);
+    let warning_count = all_issues
+        .iter()
+        .filter(|issue| severity(issue) == Severity::Warning)
+        .count();
+    let too_many_warnings = args.max_warnings.is_some_and(|max| warning_count > max);
+
+    if all_issues.is_empty() {
+        if !args.quiet {
+            report::print_no_issue(source_files, locale_files);
+        }
+    } else {
+        report::report_projects_with_config(
+            &projects,
+            &config,
+            &config_root,
+            args.group_by,
+            !args.no_summary,
+        );
+    }
+    if args.stats_footer {
+        report::print_rule_counts(&all_issues);
+    }
+    if !args.quiet {
+        report::print_parse_error(&parse_errors, verbose);
+    }
+    if let Some(max) = args.max_warnings.filter(|_| too_many_warnings) {
+        println!(
+            "{} Too many warnings ({}, maximum: {}).",
+            FAILURE_MARK.red(),
+            warning_count,
+            max
+        );
+    }
+
+    Ok(check_exit_status(
+        args,
+        parse_errors.len(),
+        has_errors,
+        warning_count,
+    ))
+}
+
+/// Check the content of stdin as if it were `file_path`.
+///
+/// Cross-file rules are skipped: only `hardcoded` and `unresolved` run.
+fn check_stdin(cmd: &CheckCommand, file_path: &str) -> Result<ExitStatus> {
+    let args = &cmd.args;
+    let mut content = String::new();
+    std::io::stdin()
+        .read_to_string(&mut content)
+        .context("Failed to read source from stdin")?;
+
+    let ctx = CheckContext::for_source(&args.common, file_path, content.clone())?;
+    let source_path = ctx.source_override_path().unwrap_or(file_path);
+
+    let per_file = [
+        CheckRule::Hardcoded,
+        CheckRule::Unresolved,
+        CheckRule::EmptyKey,
+    ];
+    let checks: Vec<CheckRule> = per_file
+        .into_iter()
+        .filter(|check| cmd.checks.is_empty() || cmd.checks.contains(check))
+        .filter(|check| !args.exclude_rule.contains(check))
+        .collect();
+    // An empty selection would mean "all checks" to `collect_issues`
+    let issues: Vec<Issue> = if checks.is_empty() {
+        Vec::new()
+    } else {
+        collect_issues(&ctx, &checks)
+            .into_iter()
+            .filter(|issue| issue_file_path(issue) == source_path)
+            .collect()
+    };
+
+    let diagnostics: Vec<StdinDiagnostic> = issues
+        .iter()
+        .map(|issue| {
+            let (line, column) = match issue.location() {
+                ReportLocation::Source(ctx) => (ctx.line(), ctx.col()),
+                ReportLocation::Message(ctx) => (ctx.line(), ctx.col()),
+                ReportLocation::File { .. } => (1, 1),
+            };
+            StdinDiagnostic {
+                rule: issue.rule(),
+                severity: ctx.config.severity_for_rule(issue.rule(), issue.severity()),
+                message: issue.message(),
+                details: issue.details(),
+                hint: issue.hint().map(str::to_string),
+                line,
+                column,
+                offset: byte_offset(&content, line, column),
             }
-            CheckRule::ReplicaLag => {
-                let issues = check_replica_lag_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::ReplicaLag));
+        })
+        .collect();
+
+    let parse_errors = issues
+        .iter()
+        .filter(|issue| matches!(issue, Issue::ParseError(_)))
+        .count();
+    let has_errors = diagnostics.iter().any(|d| d.severity == Severity::Error);
+    let warning_count = diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Warning)
+        .count();
+
+    let report = StdinReport {
+        file: file_path.to_string(),
+        diagnostics,
+    };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    Ok(check_exit_status(
+        args,
+        parse_errors,
+        has_errors,
+        warning_count,
+    ))
+}
+
+fn issue_file_path(issue: &Issue) -> &str {
+    match issue.location() {
+        ReportLocation::Source(ctx) => ctx.file_path(),
+        ReportLocation::Message(ctx) => ctx.file_path(),
+        ReportLocation::File { path } => path,
+    }
+}
+
+/// Byte offset of a 1-based line and character column, clamped to the content.
+fn byte_offset(content: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = content
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let rest = &content[line_start..];
+    let line_len = rest.find('\n').unwrap_or(rest.len());
+    let col_offset = rest[..line_len]
+        .char_indices()
+        .nth(column.saturating_sub(1))
+        .map_or(line_len, |(i, _)| i);
+    line_start + col_offset
+}
+
+/// Issues to leave out of the report, from `--diff-base`/`--diff-file`,
+/// `--since` and `--baseline`.
+pub struct IssueFilter {
+    root: PathBuf,
+    changed: Option<ChangedLines>,
+    /// Ages of the primary locale lines, to leave out recent untranslated keys.
+    recent: Option<LineAges>,
+    baseline: Option<Baseline>,
+}
+
+impl IssueFilter {
+    pub fn new(args: &CheckArgs, ctx: &CheckContext) -> Result<Self> {
+        let changed = match (&args.diff_base, &args.diff_file) {
+            (Some(base), _) => Some(ChangedLines::from_git(&ctx.root_dir, base)?),
+            (None, Some(patch)) => Some(ChangedLines::from_patch_file(&ctx.root_dir, patch)?),
+            (None, None) => None,
+        };
+        let recent = match args.since {
+            Some(since) => {
+                let files: BTreeSet<&str> = ctx
+                    .messages()
+                    .primary_messages
+                    .entries
+                    .values()
+                    .map(|entry| entry.context.file_path())
+                    .collect();
+                let cutoff = SystemTime::now()
+                    .checked_sub(since)
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                Some(LineAges::from_git(&ctx.root_dir, files, cutoff)?)
             }
-            CheckRule::Untranslated => {
-                let issues = check_untranslated_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::Untranslated));
+            None => None,
+        };
+        let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+        Ok(Self {
+            root: ctx.root_dir.clone(),
+            changed,
+            recent,
+            baseline,
+        })
+    }
+
+    /// Whether `issue` is on an added line, isn't an untranslated key added
+    /// within `--since`, and isn't recorded in the baseline.
+    pub fn keeps(&self, issue: &Issue) -> bool {
+        self.changed
+            .as_ref()
+            .is_none_or(|changed| changed.contains_issue(issue))
+            && self.recent.as_ref().is_none_or(|ages| {
+                !matches!(issue, Issue::Untranslated(_)) || !ages.is_recent(issue)
+            })
+            && self
+                .baseline
+                .as_ref()
+                .is_none_or(|baseline| !baseline.contains(issue, &self.root))
+    }
+}
+
+/// One line of `--format jsonl` output.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum JsonlRecord {
+    #[serde(rename_all = "camelCase")]
+    Issue {
+        rule: Rule,
+        severity: Severity,
+        file: String,
+        /// 1-based line, 0 for file-level issues.
+        line: usize,
+        /// 1-based column, 0 for file-level issues.
+        column: usize,
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        details: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
+        /// Root of the project, only with a `projects` config.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        project: Option<String>,
+    },
+    /// Written last, after every issue.
+    #[serde(rename_all = "camelCase")]
+    Summary {
+        errors: usize,
+        warnings: usize,
+        parse_errors: usize,
+    },
+}
+
+impl JsonlRecord {
+    fn issue(issue: &Issue, severity: Severity, project: Option<&str>) -> Self {
+        let (line, column) = match issue.location() {
+            ReportLocation::Source(ctx) => (ctx.line(), ctx.col()),
+            ReportLocation::Message(ctx) => (ctx.line(), ctx.col()),
+            ReportLocation::File { .. } => (0, 0),
+        };
+        JsonlRecord::Issue {
+            rule: issue.rule(),
+            severity,
+            file: issue_file_path(issue).to_string(),
+            line,
+            column,
+            message: issue.message(),
+            details: issue.details(),
+            hint: issue.hint().map(str::to_string),
+            project: project.map(str::to_string),
+        }
+    }
+}
+
+/// Stream issues as JSON Lines, one object per issue, then a summary line.
+///
+/// Each line is flushed as soon as it's written, and issues are written as
+/// each check finishes instead of once every check has run. Issues are
+/// sorted within a check, not across checks.
+fn check_jsonl(cmd: &CheckCommand, ctx: &CheckContext, filter: &IssueFilter) -> Result<ExitStatus> {
+    let args = &cmd.args;
+    let mut out = std::io::stdout().lock();
+    let mut errors = 0;
+    let mut warnings = 0;
+    let mut write_result = Ok(());
+
+    let checks = selected_checks(cmd, &ctx.config)?;
+    for_each_issue(ctx, &checks, |issue| {
+        if write_result.is_err() || !filter.keeps(&issue) {
+            return;
+        }
+        let severity = ctx.config.severity_for_rule(issue.rule(), issue.severity());
+        match severity {
+            Severity::Error => errors += 1,
+            Severity::Warning => warnings += 1,
+        }
+        write_result = write_jsonl(&mut out, &JsonlRecord::issue(&issue, severity, None));
+    });
+    write_result?;
+
+    let parse_errors = ctx.parsed_files_errors().len();
+    write_jsonl(
+        &mut out,
+        &JsonlRecord::Summary {
+            errors,
+            warnings,
+            parse_errors,
+        },
+    )?;
+
+    Ok(check_exit_status(args, parse_errors, errors > 0, warnings))
+}
+
+/// Write the issues of every project as JSON Lines, each with its project,
+/// then one summary line.
+///
+/// Unlike `check_jsonl`, issues are written once every project is checked.
+fn write_projects_jsonl(
+    args: &CheckArgs,
+    config: &Config,
+    projects: &[(String, Vec<Issue>)],
+    parse_errors: usize,
+) -> Result<ExitStatus> {
+    let mut out = std::io::stdout().lock();
+    let (mut errors, mut warnings) = (0, 0);
+    for (project, issues) in projects {
+        for issue in issues {
+            let severity = config.severity_for_rule(issue.rule(), issue.severity());
+            match severity {
+                Severity::Error => errors += 1,
+                Severity::Warning => warnings += 1,
             }
-            CheckRule::TypeMismatch => {
-                let issues = check_type_mismatch_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::TypeMismatch));
+            write_jsonl(
+                &mut out,
+                &JsonlRecord::issue(issue, severity, Some(project)),
+            )?;
+        }
+    }
+    write_jsonl(
+        &mut out,
+        &JsonlRecord::Summary {
+            errors,
+            warnings,
+            parse_errors,
+        },
+    )?;
+
+    Ok(check_exit_status(args, parse_errors, errors > 0, warnings))
+}
+
+/// Write the `--format` output of `issues` to `--report-file`.
+///
+/// JSON Lines are written as `--format jsonl` prints them. Text is written
+/// without colors, whatever `--color` says, since it's meant to be read
+/// outside a terminal.
+fn write_report_file(
+    args: &CheckArgs,
+    ctx: &CheckContext,
+    issues: &[Issue],
+    path: &Path,
+) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create report file: {}", path.display()))?;
+    let mut out = std::io::BufWriter::new(file);
+    let parse_errors = ctx.parsed_files_errors();
+    match args.format {
+        CheckFormat::Jsonl => {
+            let (mut errors, mut warnings) = (0, 0);
+            for issue in issues {
+                let severity = ctx.config.severity_for_rule(issue.rule(), issue.severity());
+                match severity {
+                    Severity::Error => errors += 1,
+                    Severity::Warning => warnings += 1,
+                }
+                write_jsonl(&mut out, &JsonlRecord::issue(issue, severity, None))?;
             }
-            CheckRule::Unresolved => {
-                let issues = check_unresolved_keys_issues(&ctx);
-                all_issues.extend(issues.into_iter().map(Issue::UnresolvedKey));
+            write_jsonl(
+                &mut out,
+                &JsonlRecord::Summary {
+                    errors,
+                    warnings,
+                    parse_errors: parse_errors.len(),
+                },
+            )?;
+        }
+        CheckFormat::Text => {
+            let colorize = colored::control::SHOULD_COLORIZE.should_colorize();
+            colored::control::set_override(false);
+            if issues.is_empty() {
+                report::print_success_to(
+                    ctx.files.len(),
+                    ctx.messages().all_messages.len(),
+                    &mut out,
+                );
+            } else {
+                report::report_to_with_config(issues, &ctx.config, &ctx.config_root, &mut out);
             }
+            report::print_parse_error_to(parse_errors, false, &mut out);
+            colored::control::set_override(colorize);
         }
     }
+    out.flush()
+        .with_context(|| format!("Failed to write report file: {}", path.display()))
+}
 
-    let parse_errors = ctx.parsed_files_errors();
-    all_issues.extend(parse_errors.iter().map(|i| Issue::ParseError(i.clone())));
-    all_issues.sort();
+/// Exit status of a check run.
+///
+/// With `--fail-on-parse-error`, parse errors exit with 3. Otherwise they
+/// count among the errors, which exit with 1, like warnings with
+/// `--error-on-warnings` or when there are more than `--max-warnings`.
+fn check_exit_status(
+    args: &CheckArgs,
+    parse_errors: usize,
+    has_errors: bool,
+    warnings: usize,
+) -> ExitStatus {
+    let too_many_warnings = args.max_warnings.is_some_and(|max| warnings > max);
+    if args.fail_on_parse_error && parse_errors > 0 {
+        ExitStatus::ParseError
+    } else if has_errors || (args.error_on_warnings && warnings > 0) || too_many_warnings {
+        ExitStatus::Failure
+    } else {
+        ExitStatus::Success
+    }
+}
 
-    let parse_error_count = parse_errors.len();
-    let has_errors = all_issues.iter().any(|issue| {
-        ctx.config.severity_for_rule(issue.rule(), issue.severity()) == Severity::Error
-    });
-    let has_warnings = all_issues.iter().any(|issue| {
-        ctx.config.severity_for_rule(issue.rule(), issue.severity()) == Severity::Warning
-    });
+fn write_jsonl<W: Write>(out: &mut W, record: &JsonlRecord) -> Result<()> {
+    serde_json::to_writer(&mut *out, record)?;
+    writeln!(out)?;
+    out.flush()?;
+    Ok(())
+}
 
-    // Print output
-    if all_issues.is_empty() {
-        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+/// Checks named on the command line, read with `--rules-from-file`, or the
+/// defaults, without `--exclude-rule` ones.
+///
+/// Fails when every check is excluded, since an empty selection would mean
+/// all checks.
+pub fn selected_checks(cmd: &CheckCommand, config: &Config) -> Result<Vec<CheckRule>> {
+    let checks = match &cmd.args.rules_from_file {
+        Some(_) if !cmd.checks.is_empty() => {
+            bail!("Name checks on the command line or with --rules-from-file, not both")
+        }
+        Some(path) => read_rules_file(path)?,
+        None if cmd.checks.is_empty() => CheckRule::defaults(config),
+        None => cmd.checks.clone(),
+    };
+    let checks: Vec<CheckRule> = checks
+        .into_iter()
+        .filter(|check| !cmd.args.exclude_rule.contains(check))
+        .collect();
+    if checks.is_empty() {
+        bail!("No checks left to run: --exclude-rule excludes every selected check");
+    }
+    Ok(checks)
+}
+
+/// Read the checks listed in a `--rules-from-file` file.
+///
+/// The file holds a JSON array of rule names, or one name per line, where
+/// blank lines and `#` comments are skipped. Names are the ones `severities`
+/// takes, like `missing` or `missing-key`.
+fn read_rules_file(path: &Path) -> Result<Vec<CheckRule>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read rules file {}", path.display()))?;
+    let names: Vec<String> = if content.trim_start().starts_with('[') {
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse rules file {}", path.display()))?
     } else {
-        report::report_with_config(&all_issues, &ctx.config);
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect()
+    };
+
+    let mut checks = Vec::new();
+    for name in &names {
+        let check = Rule::parse(name).and_then(|rule| {
+            CheckRule::all()
+                .into_iter()
+                .find(|check| check.rule() == rule)
+        });
+        let Some(check) = check else {
+            let valid: Vec<String> = CheckRule::all()
+                .iter()
+                .filter_map(|check| check.to_possible_value())
+                .map(|value| value.get_name().to_string())
+                .collect();
+            bail!(
+                "Unknown rule \"{}\" in {}; valid rules: {}",
+                name,
+                path.display(),
+                valid.join(", ")
+            );
+        };
+        if !checks.contains(&check) {
+            checks.push(check);
+        }
+    }
+    if checks.is_empty() {
+        bail!("No rules listed in {}", path.display());
     }
-    report::print_parse_error(parse_error_count, verbose);
+    Ok(checks)
+}
 
-    // Determine exit status
-    if parse_error_count > 0 {
-        Ok(ExitStatus::Error)
-    } else if has_errors || (args.error_on_warnings && has_warnings) {
-        Ok(ExitStatus::Failure)
+/// Run the selected checks (all when empty) and return issues, including parse errors, sorted.
+///
+/// Issues of rules turned `off` in `severities` are dropped.
+pub fn collect_issues(ctx: &CheckContext, checks: &[CheckRule]) -> Vec<Issue> {
+    let mut all_issues: Vec<Issue> = Vec::new();
+    for_each_issue(ctx, checks, |issue| all_issues.push(issue));
+    all_issues.sort();
+    all_issues
+}
+
+/// Run the selected checks (all when empty) and pass each issue to `emit`.
+///
+/// Issues are emitted as each check finishes, sorted within the check, and
+/// parse errors come last. Issues of rules turned `off` in `severities` are
+/// dropped.
+pub fn for_each_issue<F: FnMut(Issue)>(ctx: &CheckContext, checks: &[CheckRule], mut emit: F) {
+    let checks = if checks.is_empty() {
+        CheckRule::defaults(&ctx.config)
     } else {
-        Ok(ExitStatus::Success)
+        checks.to_vec()
+    };
+
+    // Rules set to "off" in `severities` behave as if they were not selected
+    let mut emit_all = |mut issues: Vec<Issue>| {
+        issues.retain(|issue| !ctx.config.is_rule_off(issue.rule()));
+        issues.sort();
+        issues.into_iter().for_each(&mut emit);
+    };
+
+    for check in checks {
+        let mut all_issues: Vec<Issue> = Vec::new();
+        let rule_name = check
+            .to_possible_value()
+            .map_or_else(String::new, |value| value.get_name().to_string());
+        ctx.profile()
+            .time(format!("rule: {}", rule_name), || match check {
+                CheckRule::Hardcoded => {
+                    let issues = check_hardcoded_text_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::HardcodedText));
+                }
+                CheckRule::Missing => {
+                    let issues = check_missing_keys_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::MissingKey));
+                }
+                CheckRule::Unused => {
+                    let issues = check_unused_keys_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::UnusedKey));
+                }
+                CheckRule::UnusedNamespace => {
+                    let issues = check_unused_namespaces_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::UnusedNamespace));
+                }
+                CheckRule::Orphan => {
+                    let issues = check_orphan_keys_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::OrphanKey));
+                }
+                CheckRule::ReplicaLag => {
+                    let issues = check_replica_lag_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::ReplicaLag));
+                }
+                CheckRule::Untranslated => {
+                    let issues = check_untranslated_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::Untranslated));
+                }
+                CheckRule::TypeMismatch => {
+                    let issues = check_type_mismatch_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::TypeMismatch));
+                }
+                CheckRule::PlaceholderMismatch => {
+                    let issues = check_placeholder_mismatch_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::PlaceholderMismatch));
+                }
+                CheckRule::KeyNaming => {
+                    let issues = check_key_naming_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::KeyNaming));
+                }
+                CheckRule::EmptyValue => {
+                    let issues = check_empty_value_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::EmptyValue));
+                }
+                CheckRule::PluralCategories => {
+                    let issues = check_plural_categories_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::PluralCategories));
+                }
+                CheckRule::MarkupOnly => {
+                    let issues = check_markup_only_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::MarkupOnly));
+                }
+                CheckRule::RichTagMismatch => {
+                    let issues = check_rich_tag_mismatch_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::RichTagMismatch));
+                }
+                CheckRule::Unresolved => {
+                    let issues = check_unresolved_keys_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::UnresolvedKey));
+                }
+                CheckRule::UnmatchedMessageKeys => {
+                    let issues = check_unmatched_message_keys_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::UnmatchedMessageKeys));
+                }
+                CheckRule::NamespaceConsistency => {
+                    let issues = check_namespace_consistency_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::NamespaceConsistency));
+                }
+                CheckRule::UnpairedDirective => {
+                    let issues = check_unpaired_directive_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::UnpairedDirective));
+                }
+                CheckRule::InterpolationMismatch => {
+                    let issues = check_interpolation_mismatch_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::InterpolationMismatch));
+                }
+                CheckRule::DuplicateValue => {
+                    let issues = check_duplicate_value_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::DuplicateValue));
+                }
+                CheckRule::StrayWhitespace => {
+                    let issues = check_stray_whitespace_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::StrayWhitespace));
+                }
+                CheckRule::EmptyKey => {
+                    let issues = check_empty_key_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::EmptyKey));
+                }
+                CheckRule::DuplicateKey => {
+                    let issues = check_duplicate_key_issues(ctx);
+                    all_issues.extend(issues.into_iter().map(Issue::DuplicateKey));
+                }
+            });
+        emit_all(all_issues);
     }
+
+    emit_all(
+        ctx.parsed_files_errors()
+            .iter()
+            .chain(ctx.message_parse_errors())
+            .map(|i| Issue::ParseError(i.clone()))
+            .collect(),
+    );
 }
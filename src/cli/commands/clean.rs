@@ -8,7 +8,9 @@
 //! - Blocks if any message files failed to parse
 //! - Blocks if any unresolved key warnings exist (dynamic keys can't be tracked)
 //!
-//! Use `--apply` to actually delete keys (default is dry-run mode).
+//! Use `--apply` to actually delete keys (default is dry-run mode, also
+//! selected explicitly with `--dry-run`). `--apply` prints the deletions
+//! as a unified diff, unless `--quiet` is given.
 
 use std::collections::HashSet;
 
@@ -16,7 +18,7 @@ use anyhow::Result;
 use colored::Colorize;
 
 use super::super::{
-    actions::{Action, ActionStats, DeleteKey},
+    actions::{ActionStats, DeletableKey, DeleteKey},
     args::{CleanCommand, CleanRule},
     exit_status::ExitStatus,
     report::{self, FAILURE_MARK},
@@ -76,7 +78,7 @@ pub fn clean(cmd: CleanCommand, verbose: bool) -> Result<ExitStatus> {
             .into_iter()
             .map(Issue::UnresolvedKey)
             .collect();
-        report::report_to_stderr_with_config(&issues, &ctx.config);
+        report::report_to_stderr_with_config(&issues, &ctx.config, &ctx.config_root);
 
         return Ok(ExitStatus::Error);
     }
@@ -103,89 +105,77 @@ pub fn clean(cmd: CleanCommand, verbose: bool) -> Result<ExitStatus> {
         }
     }
 
+    // Keys declared in Vue `<i18n>` blocks are reported by `check` but never edited
+    unused_issues.retain(|issue| !issue.context.file_path().ends_with(".vue"));
+    orphan_issues.retain(|issue| !issue.context.file_path().ends_with(".vue"));
+
     let unused_count = unused_issues.len();
     let orphan_count = orphan_issues.len();
     let total = unused_count + orphan_count;
 
-    let (file_count, applied_unused_count, applied_orphan_count, applied_total_count) = if apply {
-        let mut stats = ActionStats::default();
-        let mut unused_stats = ActionStats::default();
-        let mut orphan_stats = ActionStats::default();
+    let key_separator = ctx.config.key_separator.as_str();
+    let unused_stats = delete_keys(&unused_issues, key_separator, apply)?;
+    let orphan_stats = delete_keys(&orphan_issues, key_separator, apply)?;
+    let mut stats = unused_stats.clone();
+    stats += orphan_stats.clone();
+    let file_count = stats.changes_by_file.len();
 
+    // Print output
+    if total == 0 {
+        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+    } else if apply {
+        if !args.quiet {
+            report::print_diffs(&stats.diffs);
+        }
+        println!(
+            "{} {} key(s) in {} file(s) (processed {} key(s)).",
+            "Deleted".green().bold(),
+            stats.changes_applied,
+            file_count,
+            total
+        );
+        if unused_count > 0 {
+            println!(
+                "  - unused: {} key(s) (from {} issue(s))",
+                unused_stats.changes_applied, unused_count
+            );
+        }
+        if orphan_count > 0 {
+            println!(
+                "  - orphan: {} key(s) (from {} issue(s))",
+                orphan_stats.changes_applied, orphan_count
+            );
+        }
+    } else {
         if !unused_issues.is_empty() {
-            unused_stats = DeleteKey::run(&unused_issues)?;
-            stats += unused_stats.clone();
+            DeleteKey::preview(&unused_issues, key_separator);
         }
         if !orphan_issues.is_empty() {
-            orphan_stats = DeleteKey::run(&orphan_issues)?;
-            stats += orphan_stats.clone();
+            DeleteKey::preview(&orphan_issues, key_separator);
         }
 
-        (
-            stats.files_modified,
+        println!(
+            "{} {} unused key(s) and {} orphan key(s) from {} file(s).",
+            "Would delete".yellow().bold(),
             unused_stats.changes_applied,
             orphan_stats.changes_applied,
-            unused_stats.changes_applied + orphan_stats.changes_applied,
-        )
-    } else {
-        let mut files: HashSet<&str> = HashSet::new();
-        for issue in &unused_issues {
-            files.insert(issue.context.file_path());
-        }
-        for issue in &orphan_issues {
-            files.insert(issue.context.file_path());
-        }
-        (files.len(), 0, 0, 0)
-    };
-
-    // Print output
-    if total == 0 {
-        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
-    } else {
-        // Show preview in dry-run mode
-        if !apply {
-            if !unused_issues.is_empty() {
-                DeleteKey::preview(&unused_issues);
-            }
-            if !orphan_issues.is_empty() {
-                DeleteKey::preview(&orphan_issues);
-            }
+            file_count
+        );
+        for (file_path, changes) in &stats.changes_by_file {
+            println!("  - {}: {} key(s)", file_path, changes);
         }
-
-        if apply {
+        let skipped = total.saturating_sub(stats.changes_applied);
+        if skipped > 0 {
             println!(
-                "{} {} key(s) in {} file(s) (processed {} key(s)).",
-                "Deleted".green().bold(),
-                applied_total_count,
-                file_count,
-                total
+                "  {} reported key(s) could not be located and would be skipped.",
+                skipped
             );
-            if unused_count > 0 {
-                println!(
-                    "  - unused: {} key(s) (from {} issue(s))",
-                    applied_unused_count, unused_count
-                );
-            }
-            if orphan_count > 0 {
-                println!(
-                    "  - orphan: {} key(s) (from {} issue(s))",
-                    applied_orphan_count, orphan_count
-                );
-            }
-        } else {
-            println!(
-                "{} {} unused key(s) and {} orphan key(s) from {} file(s).",
-                "Would delete".yellow().bold(),
-                unused_count,
-                orphan_count,
-                file_count
-            );
-            println!("Run with {} to delete these keys.", "--apply".cyan());
         }
+        println!("Run with {} to delete these keys.", "--apply".cyan());
     }
 
     let parse_error_count = ctx.parsed_files_errors().len();
-    report::print_parse_error(parse_error_count, verbose);
+    report::print_parse_error(ctx.parsed_files_errors(), verbose);
 
     // Determine exit status
     // In dry-run mode, finding issues to clean is considered "Failure" (exit 1)
@@ -198,3 +188,18 @@ pub fn clean(cmd: CleanCommand, verbose: bool) -> Result<ExitStatus> {
         Ok(ExitStatus::Success)
     }
 }
+
+/// Delete the keys of `issues`, or only plan the deletion in dry-run mode.
+fn delete_keys<I: DeletableKey>(
+    issues: &[I],
+    key_separator: &str,
+    apply: bool,
+) -> Result<ActionStats> {
+    if issues.is_empty() {
+        Ok(ActionStats::default())
+    } else if apply {
+        DeleteKey::run(issues, key_separator)
+    } else {
+        DeleteKey::plan(issues, key_separator)
+    }
+}
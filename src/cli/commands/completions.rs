@@ -0,0 +1,22 @@
+//! Completions command - Print a shell completion script.
+//!
+//! The script is generated from the clap definition of `Arguments`, so it
+//! completes every subcommand and flag, and rule names where a rule is
+//! expected. Always exits with 0.
+
+use std::io;
+
+use anyhow::Result;
+use clap::CommandFactory;
+
+use super::super::{
+    args::{Arguments, CompletionsCommand},
+    exit_status::ExitStatus,
+};
+
+pub fn completions(cmd: CompletionsCommand) -> Result<ExitStatus> {
+    let mut command = Arguments::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(cmd.shell, &mut command, name, &mut io::stdout());
+    Ok(ExitStatus::Success)
+}
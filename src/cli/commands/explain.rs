@@ -0,0 +1,32 @@
+//! Explain command - Describe a rule reported by `check`.
+//!
+//! Prints what the rule reports, an example of the problem and how to fix or
+//! suppress it. The text comes from `rules::docs`. Always exits with 0.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::super::{args::ExplainCommand, exit_status::ExitStatus};
+use crate::rules::docs::rule_doc;
+
+pub fn explain(cmd: ExplainCommand) -> Result<ExitStatus> {
+    let doc = rule_doc(cmd.rule);
+
+    println!("{}", cmd.rule.to_string().bold());
+    println!();
+    println!("{}", doc.summary);
+    println!();
+    println!("{}", "Example:".bold());
+    print_indented(doc.example);
+    println!();
+    println!("{}", "How to fix:".bold());
+    print_indented(doc.fix);
+
+    Ok(ExitStatus::Success)
+}
+
+fn print_indented(text: &str) {
+    for line in text.lines() {
+        println!("  {}", line);
+    }
+}
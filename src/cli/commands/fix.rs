@@ -6,40 +6,105 @@
 //! Only unresolved key issues with a valid pattern suggestion can be fixed.
 //! Issues without a pattern (e.g., completely dynamic keys) are skipped and reported.
 //!
-//! Use `--apply` to actually insert comments (default is dry-run mode).
+//! With `--create-missing`, keys used in code but absent from the primary
+//! locale are also added to its JSON file, using the last key segment as a
+//! placeholder value. Dynamic keys are never created.
+//!
+//! With `--fill empty|source|todo`, keys missing from the other locales are
+//! added as well: the keys created in the primary locale and the replica-lag
+//! gaps. Each missing (locale, key) pair gets an empty string, the primary
+//! value, or `"[TODO] <key>"`. Keys created in the primary locale keep their
+//! last-segment placeholder there.
+//!
+//! With `--sort-keys`, object keys in every locale file are sorted
+//! alphabetically (array elements keep their order). `--sort-keys --check`
+//! only reports unsorted files, so CI can enforce the ordering.
+//!
+//! `glot fix orphan` runs none of the above. It copies orphan keys (keys of
+//! other locales missing from the primary locale) into the primary locale,
+//! or inserts empty values with `--empty`. Keys that would replace an
+//! existing object or string of the primary locale are skipped and reported.
+//!
+//! Use `--apply` to actually modify files (default is dry-run mode). The
+//! changes are then printed as a unified diff, unless `--quiet` is given.
 
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fs,
+    path::Path,
+};
 
 use anyhow::Result;
 use colored::Colorize;
 use unicode_width::UnicodeWidthStr;
 
 use super::super::{
-    actions::{Action, ActionStats, InsertMessageKeys},
-    args::FixCommand,
+    actions::{
+        Action, ActionStats, Backfill, FileDiff, FillPolicy, InsertMessageKeys, InsertMissingKey,
+        JsonEditor, PromoteOrphan, PromoteValue,
+    },
+    args::{FixCommand, FixRule},
     exit_status::ExitStatus,
-    report::{self, FAILURE_MARK},
+    report::{self, FAILURE_MARK, SUCCESS_MARK},
 };
 use crate::{
     core::CheckContext,
-    issues::{Rule, UnresolvedKeyIssue},
-    rules::unresolved::check_unresolved_keys_issues,
+    issues::{MissingKeyIssue, OrphanKeyIssue, ReplicaLagIssue, Rule, UnresolvedKeyIssue},
+    rules::{
+        missing::check_missing_keys_issues, orphan::check_orphan_keys_issues,
+        replica_lag::check_replica_lag_issues, unresolved::check_unresolved_keys_issues,
+    },
 };
 
 pub fn fix(cmd: FixCommand, verbose: bool) -> Result<ExitStatus> {
     let args = &cmd.args;
     let ctx = CheckContext::new(&args.common)?;
     let apply = args.apply;
+    let quiet = args.quiet;
+
+    if args.check {
+        return check_sorted_keys(&ctx, verbose);
+    }
+    if cmd.rule == Some(FixRule::Orphan) {
+        let value = if args.empty {
+            PromoteValue::Empty
+        } else {
+            PromoteValue::Copy
+        };
+        return promote_orphans(&ctx, value, apply, quiet, verbose);
+    }
 
     let unresolved_issues: Vec<UnresolvedKeyIssue> = check_unresolved_keys_issues(&ctx);
     let unresolved_count = unresolved_issues.len();
+    let missing_issues: Vec<MissingKeyIssue> = if args.create_missing {
+        check_missing_keys_issues(&ctx)
+    } else {
+        Vec::new()
+    };
+    let lag_issues: Vec<ReplicaLagIssue> = if args.fill.is_some() {
+        check_replica_lag_issues(&ctx)
+    } else {
+        Vec::new()
+    };
+    let backfill = Backfill {
+        all_messages: &ctx.messages().all_messages,
+        primary_locale: &ctx.config.primary_locale,
+        fallback_locales: &ctx.config.fallback_locales,
+        key_separator: &ctx.config.key_separator,
+        lags: &lag_issues,
+    };
+    let backfill_count = args.fill.map_or(0, |fill| {
+        InsertMissingKey::backfill_operations(&missing_issues, &backfill, fill).len()
+    });
 
+    let mut diffs = BTreeMap::new();
     let (processed_count, applied_count, skipped_count, file_count) = if apply {
         let stats = if unresolved_issues.is_empty() {
             ActionStats::default()
         } else {
             InsertMessageKeys::run(&unresolved_issues)?
         };
+        diffs = stats.diffs;
         (
             stats.processed,
             stats.changes_applied,
@@ -70,9 +135,19 @@ pub fn fix(cmd: FixCommand, verbose: bool) -> Result<ExitStatus> {
     let has_fixable = processed_count > 0;
     let has_unfixable = !unfixable_issues.is_empty();
 
-    if unresolved_count == 0 {
-        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+    let unsorted_files = if args.sort_keys {
+        unsorted_locale_files(&ctx)?
     } else {
+        Vec::new()
+    };
+
+    if unresolved_count == 0
+        && missing_issues.is_empty()
+        && backfill_count == 0
+        && unsorted_files.is_empty()
+    {
+        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+    } else if unresolved_count > 0 {
         // Print unfixable keys first
         if has_unfixable {
             print_unfixable_keys(&unfixable_issues);
@@ -84,6 +159,9 @@ pub fn fix(cmd: FixCommand, verbose: bool) -> Result<ExitStatus> {
         }
 
         if apply {
+            if !quiet {
+                report::print_diffs(&diffs);
+            }
             if has_fixable {
                 println!(
                     "{} {} comment(s) in {} file(s) (processed {} issue(s)).",
@@ -119,21 +197,299 @@ pub fn fix(cmd: FixCommand, verbose: bool) -> Result<ExitStatus> {
         }
     }
 
+    if !missing_issues.is_empty() {
+        create_missing_keys(&ctx, &missing_issues, apply, quiet)?;
+    }
+
+    if let Some(fill) = args.fill
+        && backfill_count > 0
+    {
+        backfill_locales(&missing_issues, &backfill, fill, apply, quiet)?;
+    }
+
+    if !unsorted_files.is_empty() {
+        sort_keys(&unsorted_files, apply, quiet)?;
+    }
+
     let parse_error_count = ctx.parsed_files_errors().len();
-    report::print_parse_error(parse_error_count, verbose);
+    report::print_parse_error(ctx.parsed_files_errors(), verbose);
 
     // Determine exit status
     // In dry-run mode, finding issues is considered "Failure" (exit 1)
     // to signal that there's work to be done
     if parse_error_count > 0 {
         Ok(ExitStatus::Error)
-    } else if unresolved_count > 0 && !apply {
+    } else if (unresolved_count > 0
+        || !missing_issues.is_empty()
+        || backfill_count > 0
+        || !unsorted_files.is_empty())
+        && !apply
+    {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+fn create_missing_keys(
+    ctx: &CheckContext,
+    issues: &[MissingKeyIssue],
+    apply: bool,
+    quiet: bool,
+) -> Result<()> {
+    let primary = &ctx.messages().primary_messages;
+    let locale_file = primary.file_path.as_str();
+    let key_separator = ctx.config.key_separator.as_str();
+
+    let skipped = InsertMissingKey::skipped_keys(issues, primary, key_separator);
+    if !skipped.is_empty() {
+        println!(
+            "{} Cannot place {} missing key(s) without a matching namespace file: {}",
+            FAILURE_MARK.red(),
+            skipped.len(),
+            skipped.join(", ")
+        );
+        println!();
+    }
+
+    if apply {
+        let stats = InsertMissingKey::run(issues, primary, key_separator)?;
+        if !quiet {
+            report::print_diffs(&stats.diffs);
+        }
+        println!(
+            "{} {} missing key(s) to {}.",
+            "Added".green().bold(),
+            stats.changes_applied,
+            locale_file
+        );
+    } else {
+        InsertMissingKey::preview(issues, primary, key_separator);
+        let key_count = issues
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect::<HashSet<_>>()
+            .len()
+            - skipped.len();
+        println!(
+            "{} {} missing key(s) to {}.",
+            "Would add".yellow().bold(),
+            key_count,
+            locale_file
+        );
+        println!("Run with {} to add these keys.", "--apply".cyan());
+    }
+
+    Ok(())
+}
+
+fn backfill_locales(
+    issues: &[MissingKeyIssue],
+    backfill: &Backfill,
+    fill: FillPolicy,
+    apply: bool,
+    quiet: bool,
+) -> Result<()> {
+    let skipped = InsertMissingKey::backfill_skipped(issues, backfill, fill);
+    if !skipped.is_empty() {
+        let pairs: Vec<String> = skipped
+            .iter()
+            .map(|(locale, key)| format!("{} ({})", key, locale))
+            .collect();
+        println!(
+            "{} Cannot place {} key(s) without a matching namespace file: {}",
+            FAILURE_MARK.red(),
+            skipped.len(),
+            pairs.join(", ")
+        );
+        println!();
+    }
+
+    if apply {
+        let stats = InsertMissingKey::run_backfill(issues, backfill, fill)?;
+        if !quiet {
+            report::print_diffs(&stats.diffs);
+        }
+        println!(
+            "{} {} key(s) to the other locales.",
+            "Added".green().bold(),
+            stats.changes_applied
+        );
+    } else {
+        InsertMissingKey::preview_backfill(issues, backfill, fill);
+        println!(
+            "{} {} key(s) to the other locales.",
+            "Would add".yellow().bold(),
+            InsertMissingKey::backfill_operations(issues, backfill, fill).len()
+        );
+        println!("Run with {} to add these keys.", "--apply".cyan());
+    }
+
+    Ok(())
+}
+
+fn promote_orphans(
+    ctx: &CheckContext,
+    value: PromoteValue,
+    apply: bool,
+    quiet: bool,
+    verbose: bool,
+) -> Result<ExitStatus> {
+    let issues: Vec<OrphanKeyIssue> = check_orphan_keys_issues(ctx);
+    let primary = &ctx.messages().primary_messages;
+    let locale_file = primary.file_path.as_str();
+    let key_separator = ctx.config.key_separator.as_str();
+
+    let skipped = PromoteOrphan::skipped_keys(&issues, primary, key_separator);
+    let promotable = PromoteOrphan::to_operations(&issues, primary, key_separator, value).len();
+
+    if issues.is_empty() {
+        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+    } else {
+        if !skipped.is_empty() {
+            println!(
+                "{} Cannot promote {} orphan key(s):",
+                FAILURE_MARK.red(),
+                skipped.len()
+            );
+            for (key, reason) in &skipped {
+                println!("  - {} ({})", key, reason);
+            }
+            println!();
+        }
+
+        if apply {
+            let stats = PromoteOrphan::run(&issues, primary, key_separator, value)?;
+            if !quiet {
+                report::print_diffs(&stats.diffs);
+            }
+            println!(
+                "{} {} orphan key(s) to {}.",
+                "Promoted".green().bold(),
+                stats.changes_applied,
+                locale_file
+            );
+        } else if promotable > 0 {
+            PromoteOrphan::preview(&issues, primary, key_separator, value);
+            println!(
+                "{} {} orphan key(s) to {}.",
+                "Would promote".yellow().bold(),
+                promotable,
+                locale_file
+            );
+            println!("Run with {} to promote these keys.", "--apply".cyan());
+        }
+    }
+
+    let parse_error_count = ctx.message_parse_errors().len();
+    report::print_parse_error(ctx.message_parse_errors(), verbose);
+
+    if parse_error_count > 0 {
+        Ok(ExitStatus::Error)
+    } else if !issues.is_empty() && !apply {
         Ok(ExitStatus::Failure)
     } else {
         Ok(ExitStatus::Success)
     }
 }
 
+/// Locale files whose keys are not sorted, in path order.
+///
+/// Files that failed to parse are not loaded and therefore skipped.
+fn unsorted_locale_files(ctx: &CheckContext) -> Result<Vec<String>> {
+    let mut unsorted = Vec::new();
+    for file in editable_locale_files(ctx) {
+        if JsonEditor::open(Path::new(file))?.sort_keys()? {
+            unsorted.push(file.to_string());
+        }
+    }
+    Ok(unsorted)
+}
+
+/// Locale files `JsonEditor` can rewrite, in path order.
+///
+/// Namespace files are listed one by one. Files that failed to parse are
+/// not loaded and therefore skipped.
+pub(super) fn editable_locale_files(ctx: &CheckContext) -> BTreeSet<&str> {
+    ctx.messages()
+        .all_messages
+        .values()
+        .flat_map(|messages| {
+            if messages.namespace_files.is_empty() {
+                vec![messages.file_path.as_str()]
+            } else {
+                messages
+                    .namespace_files
+                    .values()
+                    .map(String::as_str)
+                    .collect()
+            }
+        })
+        // Vue `<i18n>` blocks are left alone
+        .filter(|file| !file.ends_with(".vue"))
+        .collect()
+}
+
+fn sort_keys(files: &[String], apply: bool, quiet: bool) -> Result<()> {
+    if apply {
+        let mut diffs = BTreeMap::new();
+        for file in files {
+            let before = fs::read_to_string(file)?;
+            let mut editor = JsonEditor::open(Path::new(file))?;
+            editor.sort_keys()?;
+            editor.save()?;
+            let after = fs::read_to_string(file)?;
+            diffs.insert(file.clone(), FileDiff { before, after });
+        }
+        if !quiet {
+            report::print_diffs(&diffs);
+        }
+        println!(
+            "{} keys in {} locale file(s).",
+            "Sorted".green().bold(),
+            files.len()
+        );
+    } else {
+        print_unsorted_files(files);
+        println!("Run with {} to sort these files.", "--apply".cyan());
+    }
+    Ok(())
+}
+
+fn check_sorted_keys(ctx: &CheckContext, verbose: bool) -> Result<ExitStatus> {
+    let unsorted_files = unsorted_locale_files(ctx)?;
+    let parse_error_count = ctx.message_parse_errors().len();
+
+    if unsorted_files.is_empty() {
+        println!(
+            "{} Keys are sorted in all locale files.",
+            SUCCESS_MARK.green()
+        );
+    } else {
+        print_unsorted_files(&unsorted_files);
+    }
+    report::print_parse_error(ctx.message_parse_errors(), verbose);
+
+    if parse_error_count > 0 {
+        Ok(ExitStatus::Error)
+    } else if !unsorted_files.is_empty() {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+fn print_unsorted_files(files: &[String]) {
+    println!(
+        "{} keys in {} locale file(s):",
+        "Would sort".yellow().bold(),
+        files.len()
+    );
+    for file in files {
+        println!("  {} {}", "-->".blue(), file);
+    }
+}
+
 fn print_unfixable_keys(issues: &[&UnresolvedKeyIssue]) {
     println!(
         "{} Cannot fix {} unresolved key(s) (variable keys without pattern hints):",
@@ -3,6 +3,7 @@ use std::{fs, path::Path};
 use anyhow::Result;
 use colored::Colorize;
 
+use super::super::args::InitCommand;
 use super::super::exit_status::ExitStatus;
 use super::super::report::SUCCESS_MARK;
 use crate::config::{CONFIG_FILE_NAME, Framework, default_config_json};
@@ -26,6 +27,8 @@ fn detect_framework() -> Framework {
 
     if has_dep("next-intl") {
         Framework::NextIntl
+    } else if has_dep("react-intl") {
+        Framework::ReactIntl
     } else if has_dep("react-i18next") || has_dep("i18next") {
         Framework::ReactI18next
     } else {
@@ -35,28 +38,46 @@ fn detect_framework() -> Framework {
     }
 }
 
-pub fn init() -> Result<ExitStatus> {
+pub fn init(cmd: InitCommand) -> Result<ExitStatus> {
     let config_path = Path::new(CONFIG_FILE_NAME);
 
-    if config_path.exists() {
-        eprintln!("Error: {} already exists", CONFIG_FILE_NAME);
+    let exists = config_path.exists();
+    if exists && !cmd.force {
+        eprintln!(
+            "Error: {} already exists (use --force to overwrite it)",
+            CONFIG_FILE_NAME
+        );
         return Ok(ExitStatus::Failure);
     }
 
-    let framework = detect_framework();
+    let framework = cmd.library.unwrap_or_else(detect_framework);
     fs::write(config_path, default_config_json(framework)?)?;
 
+    if exists {
+        eprintln!(
+            "{} Overwrote the existing {}",
+            "warning:".bold().yellow(),
+            CONFIG_FILE_NAME
+        );
+    }
+
     let framework_label = match framework {
         Framework::NextIntl => "next-intl",
         Framework::ReactI18next => "react-i18next",
+        Framework::ReactIntl => "react-intl",
     };
 
+    let source = if cmd.library.is_some() {
+        "framework"
+    } else {
+        "detected framework"
+    };
     println!(
         "{} {}",
         SUCCESS_MARK.green(),
         format!(
-            "Created {} (detected framework: {})",
-            CONFIG_FILE_NAME, framework_label
+            "Created {} ({}: {})",
+            CONFIG_FILE_NAME, source, framework_label
         )
         .green()
     );
@@ -0,0 +1,88 @@
+//! Migrate command - Convert locale files between flat and nested keys.
+//!
+//! `glot migrate --to nested` rewrites dotted keys like `"Common.submit"` as
+//! nested objects, and `--to flat` does the opposite. Values, key order,
+//! indentation and the trailing newline are kept. Keys are split and joined
+//! with `keySeparator`.
+//!
+//! Every file is converted before any is written, so a key that is both a
+//! value and a parent (`"Common.submit"` next to `"Common.submit.label"`)
+//! fails the command without touching the files.
+//!
+//! With `--check`, the files that would change are only reported, and the
+//! command exits with 1 when there are any.
+
+use std::path::Path;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::super::{
+    actions::JsonEditor,
+    args::{MigrateCommand, MigrateTarget},
+    exit_status::ExitStatus,
+    report::{self, SUCCESS_MARK},
+};
+use super::fix::editable_locale_files;
+use crate::core::CheckContext;
+
+pub fn migrate(cmd: MigrateCommand, verbose: bool) -> Result<ExitStatus> {
+    let args = &cmd.args;
+    let ctx = CheckContext::new(&args.common)?;
+    let separator = ctx.config.key_separator.as_str();
+
+    let mut changed = Vec::new();
+    for file in editable_locale_files(&ctx) {
+        let mut editor = JsonEditor::open(Path::new(file))?;
+        let modified = match args.to {
+            MigrateTarget::Nested => editor.nest_keys(separator)?,
+            MigrateTarget::Flat => editor.flatten_keys(separator)?,
+        };
+        if modified {
+            changed.push((file, editor));
+        }
+    }
+
+    let layout = match args.to {
+        MigrateTarget::Nested => "nested keys",
+        MigrateTarget::Flat => "flat keys",
+    };
+    if changed.is_empty() {
+        println!(
+            "{} All locale files already use {}.",
+            SUCCESS_MARK.green(),
+            layout
+        );
+    } else if args.check {
+        println!(
+            "{} {} locale file(s) to {}:",
+            "Would migrate".yellow().bold(),
+            changed.len(),
+            layout
+        );
+        for (file, _) in &changed {
+            println!("  {} {}", "-->".blue(), file);
+        }
+    } else {
+        for (_, editor) in &changed {
+            editor.save()?;
+        }
+        println!(
+            "{} {} locale file(s) to {}.",
+            "Migrated".green().bold(),
+            changed.len(),
+            layout
+        );
+    }
+
+    let parse_error_count = ctx.message_parse_errors().len();
+    report::print_parse_error(ctx.message_parse_errors(), verbose);
+
+    if parse_error_count > 0 {
+        Ok(ExitStatus::Error)
+    } else if args.check && !changed.is_empty() {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
@@ -1,5 +1,10 @@
 pub mod baseline;
 pub mod check;
 pub mod clean;
+pub mod completions;
+pub mod explain;
 pub mod fix;
 pub mod init;
+pub mod migrate;
+pub mod stats;
+pub mod watch;
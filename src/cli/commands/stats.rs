@@ -0,0 +1,199 @@
+//! Stats command - Summarize translation coverage per locale.
+//!
+//! For every locale, counts:
+//! - `keys`: keys defined in the locale
+//! - `missing`: primary keys absent from the locale (see `replica-lag`)
+//! - `untranslated`: values identical to the primary locale or empty (see `untranslated`)
+//! - `orphan`: keys that don't exist in the primary locale (see `orphan`)
+//! - `coverage`: share of primary keys that are present and translated
+//!
+//! The counts come from the same rules as `check`, so suppression comments
+//! apply. The command is informational and always exits with 0.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+
+use super::super::{
+    args::{StatsCommand, StatsFormat},
+    exit_status::ExitStatus,
+};
+use crate::{
+    core::CheckContext,
+    rules::{
+        orphan::check_orphan_keys_issues, replica_lag::check_replica_lag_issues,
+        untranslated::check_untranslated_issues,
+    },
+};
+
+/// Coverage summary for the whole project.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+    pub primary_locale: String,
+    /// Number of keys in the primary locale.
+    pub total_keys: usize,
+    /// Primary locale first, then the other locales sorted by code.
+    pub locales: Vec<LocaleStats>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocaleStats {
+    pub locale: String,
+    pub keys: usize,
+    pub missing: usize,
+    pub untranslated: usize,
+    pub orphan: usize,
+    /// Percentage of primary keys that are present and translated.
+    pub coverage: f64,
+}
+
+pub fn stats(cmd: StatsCommand) -> Result<ExitStatus> {
+    let args = &cmd.args;
+    let ctx = CheckContext::new(&args.common)?;
+    let stats = compute_stats(&ctx);
+
+    match args.format {
+        StatsFormat::Table => print_table(&stats),
+        StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+fn compute_stats(ctx: &CheckContext) -> Stats {
+    let primary_locale = &ctx.config.primary_locale;
+    let all_messages = &ctx.messages().all_messages;
+
+    let mut locales: BTreeMap<&str, LocaleStats> = all_messages
+        .iter()
+        .map(|(locale, messages)| {
+            let stats = LocaleStats {
+                locale: locale.clone(),
+                keys: messages.entries.len(),
+                ..LocaleStats::default()
+            };
+            (locale.as_str(), stats)
+        })
+        .collect();
+
+    for issue in check_replica_lag_issues(ctx) {
+        for locale in &issue.missing_in {
+            if let Some(stats) = locales.get_mut(locale.as_str()) {
+                stats.missing += 1;
+            }
+        }
+    }
+    for issue in check_untranslated_issues(ctx) {
+        for locale in issue.identical_in.iter().chain(&issue.empty_in) {
+            if let Some(stats) = locales.get_mut(locale.as_str()) {
+                stats.untranslated += 1;
+            }
+        }
+    }
+    for issue in check_orphan_keys_issues(ctx) {
+        if let Some(stats) = locales.get_mut(issue.locale.as_str()) {
+            stats.orphan += 1;
+        }
+    }
+
+    let total_keys = ctx.messages().primary_messages.entries.len();
+    for stats in locales.values_mut() {
+        stats.coverage = coverage(total_keys, stats.missing + stats.untranslated);
+    }
+
+    let primary = locales.remove(primary_locale.as_str());
+    Stats {
+        primary_locale: primary_locale.clone(),
+        total_keys,
+        locales: primary.into_iter().chain(locales.into_values()).collect(),
+    }
+}
+
+/// Percentage of `total` keys that are not `lacking`, rounded to one decimal.
+fn coverage(total: usize, lacking: usize) -> f64 {
+    if total == 0 {
+        return 100.0;
+    }
+    let translated = total.saturating_sub(lacking) as f64;
+    (translated / total as f64 * 1000.0).round() / 10.0
+}
+
+fn print_table(stats: &Stats) {
+    let headers = [
+        "Locale",
+        "Keys",
+        "Missing",
+        "Untranslated",
+        "Orphan",
+        "Coverage",
+    ];
+    let rows: Vec<[String; 6]> = stats
+        .locales
+        .iter()
+        .map(|locale| {
+            let name = if locale.locale == stats.primary_locale {
+                format!("{} (primary)", locale.locale)
+            } else {
+                locale.locale.clone()
+            };
+            [
+                name,
+                locale.keys.to_string(),
+                locale.missing.to_string(),
+                locale.untranslated.to_string(),
+                locale.orphan.to_string(),
+                format!("{:.1}%", locale.coverage),
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].len())
+                .chain([headers[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    // Locale column is left-aligned, counts are right-aligned
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                if i == 0 {
+                    format!("{:<width$}", cell, width = widths[i])
+                } else {
+                    format!("{:>width$}", cell, width = widths[i])
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    let header: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+    println!("{}", format_row(&header).bold());
+    for row in &rows {
+        println!("{}", format_row(row));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coverage() {
+        assert_eq!(coverage(0, 0), 100.0);
+        assert_eq!(coverage(4, 0), 100.0);
+        assert_eq!(coverage(4, 1), 75.0);
+        assert_eq!(coverage(3, 1), 66.7);
+        assert_eq!(coverage(2, 5), 0.0);
+    }
+}
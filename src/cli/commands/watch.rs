@@ -0,0 +1,397 @@
+//! Watch command - Re-run checks whenever source or message files change.
+//!
+//! Runs a full `check` first, then watches the included source directories,
+//! the messages directory and the config file with `notify`. Once a burst of
+//! changes settles (see `DEBOUNCE`), the changed paths are handed to
+//! `CheckContext::update()`, which re-analyzes only the affected files and
+//! keeps the Phase 1 data of the others, and only the delta is printed:
+//! issues that appeared and issues that were resolved.
+//!
+//! A change to the config or `.glotignore` reloads everything. Stop with
+//! Ctrl-C.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+use anyhow::Result;
+use colored::Colorize;
+use notify_debouncer_full::{
+    DebounceEventResult, Debouncer, RecommendedCache, new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+};
+
+use super::super::{
+    args::CheckCommand,
+    exit_status::ExitStatus,
+    report::{self, PathDisplay, SUCCESS_MARK},
+};
+use super::check::{IssueFilter, collect_issues, selected_checks};
+use crate::{
+    config::CONFIG_FILE_NAMES,
+    core::{CheckContext, IGNORE_FILE_NAME},
+    issues::{Issue, Report, ReportLocation, Rule},
+};
+
+/// How long files must stay unchanged before checks re-run.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// File extensions that can affect check results.
+const WATCHED_EXTENSIONS: &[&str] = &[
+    "tsx", "ts", "jsx", "js", "astro", "vue", "json", "yml", "yaml",
+];
+
+pub fn watch(mut cmd: CheckCommand, verbose: bool) -> Result<ExitStatus> {
+    cmd.args.common.cache = true;
+
+    let mut ctx = CheckContext::new(&cmd.args.common)?;
+    let mut issues = filtered_issues(&cmd, &ctx)?;
+    if issues.is_empty() {
+        report::print_no_issue(ctx.files.len(), ctx.messages().all_messages.len());
+    } else {
+        report::report_with_config(&issues, &ctx.config, &ctx.config_root);
+    }
+    report::print_parse_error(ctx.parsed_files_errors(), verbose);
+
+    let (tx, rx) = mpsc::channel();
+    let mut debouncer = start_watching(&ctx, tx.clone())?;
+    print_waiting();
+
+    for events in rx {
+        // Reading files while checking shows up as access events; skip them
+        let changed: Vec<PathBuf> = match events {
+            Ok(events) => events
+                .into_iter()
+                .filter(|event| !event.kind.is_access())
+                .flat_map(|event| event.event.paths)
+                .filter(|path| is_watched(path))
+                .collect(),
+            Err(errors) => {
+                for e in errors {
+                    eprintln!("Warning: {}", e);
+                }
+                continue;
+            }
+        };
+        if changed.is_empty() {
+            continue;
+        }
+
+        // Config or messages may be broken mid-edit; report and keep watching
+        let config_changed = changed.iter().any(|path| is_config_file(path));
+        let updated = if config_changed {
+            CheckContext::new(&cmd.args.common).map(|new_ctx| ctx = new_ctx)
+        } else {
+            ctx.update(&changed)
+        };
+        let next_issues = updated.and_then(|()| filtered_issues(&cmd, &ctx));
+        let next_issues = match next_issues {
+            Ok(issues) => issues,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                print_waiting();
+                continue;
+            }
+        };
+        let delta = IssueDelta::between(&issues, &next_issues);
+        print_delta(&delta, &ctx, next_issues.len());
+        issues = next_issues;
+
+        // Includes or messagesRoot may have changed with the config
+        if config_changed {
+            debouncer = start_watching(&ctx, tx.clone())?;
+        }
+        print_waiting();
+    }
+    drop(debouncer);
+
+    Ok(ExitStatus::Success)
+}
+
+/// Issues of the selected checks, without those left out by `IssueFilter`.
+fn filtered_issues(cmd: &CheckCommand, ctx: &CheckContext) -> Result<Vec<Issue>> {
+    let filter = IssueFilter::new(&cmd.args, ctx)?;
+    let checks = selected_checks(cmd, &ctx.config)?;
+    let mut issues = collect_issues(ctx, &checks);
+    issues.retain(|issue| filter.keeps(issue));
+    Ok(issues)
+}
+
+fn print_waiting() {
+    println!("{}", "Watching for changes... (Ctrl-C to stop)".dimmed());
+}
+
+/// Issues that appeared and disappeared between two runs.
+#[derive(Debug, Default)]
+struct IssueDelta {
+    added: Vec<Issue>,
+    resolved: Vec<Issue>,
+}
+
+impl IssueDelta {
+    /// Compare two runs.
+    ///
+    /// Issues are matched by rule, file and message rather than exact position,
+    /// so inserting a line above an existing issue doesn't report it as both
+    /// resolved and new.
+    fn between(previous: &[Issue], current: &[Issue]) -> Self {
+        let mut remaining: HashMap<IssueIdentity, usize> = HashMap::new();
+        for issue in previous {
+            *remaining.entry(IssueIdentity::of(issue)).or_default() += 1;
+        }
+
+        let mut added = Vec::new();
+        for issue in current {
+            match remaining.get_mut(&IssueIdentity::of(issue)) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => added.push(issue.clone()),
+            }
+        }
+
+        // Whatever is left over from the previous run has been resolved
+        let mut resolved = Vec::new();
+        for issue in previous.iter().rev() {
+            let identity = IssueIdentity::of(issue);
+            if let Some(count) = remaining.get_mut(&identity)
+                && *count > 0
+            {
+                *count -= 1;
+                resolved.push(issue.clone());
+            }
+        }
+        resolved.reverse();
+
+        Self { added, resolved }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.resolved.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct IssueIdentity {
+    rule: Rule,
+    file_path: String,
+    message: String,
+}
+
+impl IssueIdentity {
+    fn of(issue: &Issue) -> Self {
+        Self {
+            rule: issue.report_rule(),
+            file_path: location_path(issue).to_string(),
+            message: issue.message(),
+        }
+    }
+}
+
+fn location_path(issue: &Issue) -> &str {
+    match issue.location() {
+        ReportLocation::Source(ctx) => ctx.file_path(),
+        ReportLocation::Message(ctx) => ctx.file_path(),
+        ReportLocation::File { path } => path,
+    }
+}
+
+fn print_delta(delta: &IssueDelta, ctx: &CheckContext, total: usize) {
+    println!();
+    if delta.is_empty() {
+        println!("No changes in issues ({} total).", total);
+        return;
+    }
+
+    let paths = PathDisplay::for_config(&ctx.config, &ctx.config_root);
+    for issue in &delta.resolved {
+        println!(
+            "{} {}  {}  {}",
+            SUCCESS_MARK.green(),
+            issue.message(),
+            format!("[{}]", issue.report_rule()).dimmed().cyan(),
+            paths.display(location_path(issue)).dimmed()
+        );
+    }
+    if !delta.resolved.is_empty() {
+        println!();
+    }
+
+    report::report_with_config(&delta.added, &ctx.config, &ctx.config_root);
+
+    println!(
+        "{} new, {} resolved, {} total.",
+        delta.added.len(),
+        delta.resolved.len(),
+        total
+    );
+}
+
+/// Watch the roots of `ctx`, sending each settled burst of changes to `tx`.
+fn start_watching(
+    ctx: &CheckContext,
+    tx: mpsc::Sender<DebounceEventResult>,
+) -> Result<Debouncer<RecommendedWatcher, RecommendedCache>> {
+    let mut debouncer = new_debouncer(DEBOUNCE, None, tx)?;
+    for (path, mode) in watch_roots(ctx) {
+        if let Err(e) = debouncer.watch(&path, mode)
+            && ctx.verbose
+        {
+            eprintln!("Warning: can't watch {}: {}", path.display(), e);
+        }
+    }
+    Ok(debouncer)
+}
+
+/// Directories whose changes trigger a re-run.
+///
+/// Included source directories and the messages directory are watched
+/// recursively. The source root is watched for the config file; when it is
+/// itself a root, its subdirectories are watched except `node_modules` and
+/// hidden ones, so directories created there later are not picked up.
+fn watch_roots(ctx: &CheckContext) -> Vec<(PathBuf, RecursiveMode)> {
+    let mut roots: Vec<PathBuf> = ctx
+        .config
+        .includes
+        .iter()
+        .map(|include| ctx.root_dir.join(static_prefix(include)))
+        .collect();
+    if roots.is_empty() {
+        roots.push(ctx.root_dir.clone());
+    }
+    match ctx.resolved_message_pattern() {
+        Some(pattern) => roots.push(static_prefix(&pattern.to_string_lossy())),
+        None => roots.push(ctx.resolved_messages_dir()),
+    }
+
+    let mut watched = vec![(ctx.root_dir.clone(), RecursiveMode::NonRecursive)];
+    for root in roots {
+        if !is_source_root(&root, &ctx.root_dir) {
+            watched.push((root, RecursiveMode::Recursive));
+            continue;
+        }
+        let Ok(entries) = fs::read_dir(&root) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            if entry.path().is_dir() && !is_skipped_dir(&name.to_string_lossy()) {
+                watched.push((entry.path(), RecursiveMode::Recursive));
+            }
+        }
+    }
+    watched
+}
+
+/// Whether `root` is the source root, e.g. `.` or `./` for a root of `.`.
+fn is_source_root(root: &Path, root_dir: &Path) -> bool {
+    let normalize = |path: &Path| -> PathBuf {
+        path.components()
+            .filter(|c| !matches!(c, std::path::Component::CurDir))
+            .collect()
+    };
+    normalize(root) == normalize(root_dir)
+}
+
+/// Leading path components of a pattern before any glob wildcard or `{locale}`.
+fn static_prefix(pattern: &str) -> PathBuf {
+    Path::new(pattern)
+        .components()
+        .take_while(|c| {
+            let s = c.as_os_str().to_string_lossy();
+            !s.contains(['*', '?', '{'])
+        })
+        .collect()
+}
+
+/// Whether a change to `path` can affect check results.
+fn is_watched(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| WATCHED_EXTENSIONS.contains(&ext))
+        || is_config_file(path)
+}
+
+/// Config files and `.glotignore`, whose changes reload everything.
+fn is_config_file(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| {
+        name == IGNORE_FILE_NAME || CONFIG_FILE_NAMES.iter().any(|config| name == *config)
+    })
+}
+
+fn is_skipped_dir(name: &str) -> bool {
+    name == "node_modules" || name.starts_with('.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CommentStyle, SourceContext, SourceLocation};
+    use crate::issues::HardcodedTextIssue;
+
+    fn hardcoded(file: &str, line: usize, text: &str) -> Issue {
+        Issue::HardcodedText(HardcodedTextIssue {
+            context: SourceContext::new(
+                SourceLocation::new(file, line, 1),
+                text,
+                CommentStyle::Jsx,
+            ),
+            text: text.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_delta_reports_added_and_resolved() {
+        let previous = vec![hardcoded("a.tsx", 1, "Hello"), hardcoded("a.tsx", 2, "Bye")];
+        let current = vec![hardcoded("a.tsx", 1, "Hello"), hardcoded("b.tsx", 4, "New")];
+
+        let delta = IssueDelta::between(&previous, &current);
+
+        assert_eq!(delta.added, vec![hardcoded("b.tsx", 4, "New")]);
+        assert_eq!(delta.resolved, vec![hardcoded("a.tsx", 2, "Bye")]);
+    }
+
+    #[test]
+    fn test_delta_ignores_moved_issues() {
+        let previous = vec![hardcoded("a.tsx", 3, "Hello")];
+        let current = vec![hardcoded("a.tsx", 5, "Hello")];
+
+        assert!(IssueDelta::between(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn test_delta_counts_duplicates() {
+        let previous = vec![hardcoded("a.tsx", 1, "Hi")];
+        let current = vec![hardcoded("a.tsx", 1, "Hi"), hardcoded("a.tsx", 9, "Hi")];
+
+        let delta = IssueDelta::between(&previous, &current);
+
+        assert_eq!(delta.added, vec![hardcoded("a.tsx", 9, "Hi")]);
+        assert!(delta.resolved.is_empty());
+    }
+
+    #[test]
+    fn test_static_prefix() {
+        assert_eq!(static_prefix("src"), PathBuf::from("src"));
+        assert_eq!(static_prefix("app/**/*.tsx"), PathBuf::from("app"));
+        assert_eq!(static_prefix("app/[locale]"), PathBuf::from("app/[locale]"));
+        assert_eq!(
+            static_prefix("./locales/{locale}/*.json"),
+            PathBuf::from("./locales")
+        );
+    }
+
+    #[test]
+    fn test_is_watched() {
+        assert!(is_watched(Path::new("./src/app.tsx")));
+        assert!(is_watched(Path::new("/project/messages/en.json")));
+        assert!(!is_watched(Path::new("./src/app.tsx.swp")));
+        assert!(!is_watched(Path::new("./README.md")));
+        assert!(is_config_file(Path::new("/project/.glotrc.json")));
+        assert!(!is_config_file(Path::new("/project/messages/en.json")));
+        assert!(is_watched(Path::new("/project/.glotignore")));
+        assert!(is_config_file(Path::new("/project/.glotignore")));
+    }
+}
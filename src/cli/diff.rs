@@ -0,0 +1,217 @@
+//! Changed-line filtering for `check --diff-base` and `check --diff-file`.
+//!
+//! Parses a unified diff (from `git diff --unified=0` or a supplied patch)
+//! into the line ranges added per file, so only issues introduced by a change
+//! are reported. Removed lines are ignored: they have no location in the new
+//! version of the file.
+
+use std::{
+    collections::HashMap,
+    fs,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use anyhow::{Context, Result, bail};
+
+use crate::issues::{Issue, Report, ReportLocation};
+
+/// Lines added per file, keyed by path under the source root.
+#[derive(Debug, Default)]
+pub struct ChangedLines {
+    root: PathBuf,
+    files: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+impl ChangedLines {
+    /// Lines added in the working tree compared to `base`.
+    ///
+    /// Runs `git diff --relative` in `root`, so only changes under the source
+    /// root are included and paths are relative to it. Untracked files are
+    /// not part of `git diff` and are not reported.
+    pub fn from_git(root: &Path, base: &str) -> Result<Self> {
+        let output = Command::new("git")
+            .args([
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--relative",
+                base,
+                "--",
+            ])
+            .current_dir(root)
+            .output()
+            .with_context(|| "Failed to run git. Is it installed and on PATH?")?;
+
+        if !output.status.success() {
+            bail!(
+                "git diff against '{}' failed: {}",
+                base,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(Self::parse(root, &String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Lines added by a unified diff file.
+    ///
+    /// Paths in the patch are resolved against `root` (e.g. a patch produced
+    /// with `git diff --relative` from the source root).
+    pub fn from_patch_file(root: &Path, patch: &Path) -> Result<Self> {
+        let content = fs::read_to_string(patch)
+            .with_context(|| format!("Failed to read diff file: {}", patch.display()))?;
+        Ok(Self::parse(root, &content))
+    }
+
+    /// Parse a unified diff.
+    ///
+    /// Only `+++` file headers and `@@` hunk headers are read; the hunk
+    /// header gives the added range directly (`+start,count`).
+    pub fn parse(root: &Path, diff: &str) -> Self {
+        let mut files: HashMap<PathBuf, Vec<RangeInclusive<usize>>> = HashMap::new();
+        let mut current: Option<PathBuf> = None;
+
+        for line in diff.lines() {
+            if let Some(target) = line.strip_prefix("+++ ") {
+                // Deleted files have `/dev/null` as the new path
+                let target = target.split('\t').next().unwrap_or(target).trim();
+                current = (target != "/dev/null").then(|| {
+                    let path = target.strip_prefix("b/").unwrap_or(target);
+                    root.join(path)
+                });
+                if let Some(path) = &current {
+                    files.entry(path.clone()).or_default();
+                }
+            } else if let Some(header) = line.strip_prefix("@@ ")
+                && let Some(path) = &current
+                && let Some(range) = parse_added_range(header)
+            {
+                files.entry(path.clone()).or_default().push(range);
+            }
+        }
+
+        Self {
+            root: root.to_path_buf(),
+            files,
+        }
+    }
+
+    /// Whether `line` of `file_path` was added.
+    pub fn contains(&self, file_path: &str, line: usize) -> bool {
+        self.ranges(file_path)
+            .is_some_and(|ranges| ranges.iter().any(|range| range.contains(&line)))
+    }
+
+    /// Whether an issue is located on an added line.
+    ///
+    /// File-level issues (parse errors) have no line and are kept when their
+    /// file is part of the diff at all.
+    pub fn contains_issue(&self, issue: &Issue) -> bool {
+        match issue.location() {
+            ReportLocation::Source(ctx) => self.contains(ctx.file_path(), ctx.line()),
+            ReportLocation::Message(ctx) => self.contains(ctx.file_path(), ctx.line()),
+            ReportLocation::File { path } => self.ranges(path).is_some(),
+        }
+    }
+
+    fn ranges(&self, file_path: &str) -> Option<&Vec<RangeInclusive<usize>>> {
+        self.files.get(&self.root.join(file_path))
+    }
+}
+
+/// Added line range from a hunk header like `-12,3 +14,2 @@ fn main()`.
+///
+/// Returns `None` for hunks that only remove lines (`+14,0`).
+fn parse_added_range(header: &str) -> Option<RangeInclusive<usize>> {
+    let added = header
+        .split_whitespace()
+        .find_map(|part| part.strip_prefix('+'))?;
+    let (start, count) = match added.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (added.parse::<usize>().ok()?, 1),
+    };
+    (count > 0).then(|| start..=start + count - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/src/app.tsx b/src/app.tsx
+index 1111111..2222222 100644
+--- a/src/app.tsx
++++ b/src/app.tsx
+@@ -3,0 +4,2 @@ export function App() {
++    <h1>Hello</h1>
++    <p>World</p>
+@@ -10 +12 @@ export function App() {
+-    old
++    new
+@@ -20,2 +21,0 @@
+-    removed
+-    removed
+diff --git a/messages/old.json b/messages/old.json
+deleted file mode 100644
+--- a/messages/old.json
++++ /dev/null
+@@ -1,3 +0,0 @@
+-{
+-}
+";
+
+    #[test]
+    fn test_parse_added_range() {
+        assert_eq!(parse_added_range("-3,0 +4,2 @@ fn"), Some(4..=5));
+        assert_eq!(parse_added_range("-10 +12 @@"), Some(12..=12));
+        assert_eq!(parse_added_range("-20,2 +21,0 @@"), None);
+    }
+
+    #[test]
+    fn test_parse_diff() {
+        let root = Path::new("/repo");
+        let changed = ChangedLines::parse(root, DIFF);
+
+        assert!(changed.contains("src/app.tsx", 4));
+        assert!(changed.contains("./src/app.tsx", 5));
+        assert!(changed.contains("/repo/src/app.tsx", 12));
+        assert!(!changed.contains("src/app.tsx", 6));
+        assert!(!changed.contains("src/app.tsx", 21));
+        assert!(!changed.contains("messages/old.json", 1));
+        assert!(!changed.contains("src/other.tsx", 4));
+    }
+
+    #[test]
+    fn test_from_git() {
+        let dir = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "test"]);
+        fs::write(dir.path().join("a.tsx"), "one\ntwo\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "init"]);
+        fs::write(dir.path().join("a.tsx"), "one\nnew\ntwo\n").unwrap();
+
+        let changed = ChangedLines::from_git(dir.path(), "HEAD").unwrap();
+        assert!(changed.contains("a.tsx", 2));
+        assert!(!changed.contains("a.tsx", 1));
+
+        let err = ChangedLines::from_git(dir.path(), "no-such-ref").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("git diff against 'no-such-ref' failed")
+        );
+    }
+}
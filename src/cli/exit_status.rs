@@ -4,15 +4,18 @@ use std::process::ExitCode;
 ///
 /// - `Success` (0): Command completed successfully, no issues found
 /// - `Failure` (1): Command completed but found issues (errors/warnings)
-/// - `Error` (2): Command failed due to internal error (parse error, config error, etc.)
+/// - `Error` (2): Command failed due to a config, usage or internal error
+/// - `ParseError` (3): Source files couldn't be parsed (`check --fail-on-parse-error`)
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ExitStatus {
     /// Command completed successfully, no issues found.
     Success,
     /// Command completed but found issues (errors/warnings).
     Failure,
-    /// Command failed due to internal error (parse error, config error, etc.).
+    /// Command failed due to a config, usage or internal error.
     Error,
+    /// Source files couldn't be parsed.
+    ParseError,
 }
 
 impl From<ExitStatus> for ExitCode {
@@ -21,6 +24,7 @@ impl From<ExitStatus> for ExitCode {
             ExitStatus::Success => ExitCode::from(0),
             ExitStatus::Failure => ExitCode::from(1),
             ExitStatus::Error => ExitCode::from(2),
+            ExitStatus::ParseError => ExitCode::from(3),
         }
     }
 }
@@ -34,5 +38,6 @@ mod tests {
         assert_eq!(ExitCode::from(ExitStatus::Success), ExitCode::from(0));
         assert_eq!(ExitCode::from(ExitStatus::Failure), ExitCode::from(1));
         assert_eq!(ExitCode::from(ExitStatus::Error), ExitCode::from(2));
+        assert_eq!(ExitCode::from(ExitStatus::ParseError), ExitCode::from(3));
     }
 }
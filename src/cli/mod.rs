@@ -7,9 +7,12 @@
 //!
 //! - `actions`: Issue-specific actions (fix operations for check issues)
 //! - `args`: CLI argument definitions using clap
-//! - `commands`: Command implementations (check, clean, baseline, fix)
+//! - `baseline_file`: Baseline files for `baseline --to` and `check --baseline`
+//! - `commands`: Command implementations (check, clean, baseline, fix, watch, stats, migrate)
+//! - `diff`: Changed-line filtering for `check --diff-base`
 //! - `exit_status`: Exit status codes
 //! - `report`: Issue reporting and formatting
+//! - `resolution`: Resolution traces for `check --explain-resolution`
 //! - `run`: Command dispatcher
 
 use std::process::ExitCode;
@@ -19,11 +22,15 @@ use anyhow::Result;
 pub use args::{Arguments, Command};
 pub use exit_status::ExitStatus;
 
-mod actions;
+pub(crate) mod actions;
 pub mod args;
-mod commands;
+mod baseline_file;
+mod blame;
+pub(crate) mod commands;
+mod diff;
 mod exit_status;
 pub mod report;
+mod resolution;
 mod run;
 
 pub fn run_cli(args: Arguments) -> Result<ExitCode> {
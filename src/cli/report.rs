@@ -3,14 +3,21 @@
 //! This module provides functions to display issues in cargo-style format.
 //! Separate from core logic to allow glot to be used as a library.
 
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use colored::Colorize;
 use unicode_width::UnicodeWidthStr;
 
-use crate::core::ResolvedKeyUsage;
-use crate::issues::{Issue, Report, ReportLocation, Severity};
+use super::actions::FileDiff;
+use super::args::GroupBy;
+use super::baseline_file::BaselineComparison;
+use crate::config::{Config, PathStyle};
+use crate::core::{ResolvedKeyUsage, profile::PhaseTiming};
+use crate::issues::{Issue, ParseErrorIssue, Report, ReportLocation, Severity};
 
 /// Success mark for consistent output formatting.
 pub const SUCCESS_MARK: &str = "\u{2713}"; // checkmark
@@ -21,6 +28,55 @@ pub const FAILURE_MARK: &str = "\u{2718}"; // X mark
 /// Maximum number of usages to display per issue.
 const MAX_USAGES_DISPLAY: usize = 3;
 
+/// How issue locations print file paths.
+///
+/// Issues keep the paths they were found under, so that suppressions,
+/// baselines and fixes keep working on them; they are only converted here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathDisplay {
+    /// Paths as the issues hold them.
+    AsGiven,
+    /// Paths relative to this root, `./`-prefixed. Paths outside of it are
+    /// printed as given.
+    Relative(PathBuf),
+    /// Absolute paths.
+    Absolute,
+}
+
+impl PathDisplay {
+    /// Display for `pathStyle`, with relative paths based on `root`.
+    pub fn new(style: PathStyle, root: &Path) -> Self {
+        match style {
+            PathStyle::Relative => Self::Relative(absolute(root)),
+            PathStyle::Absolute => Self::Absolute,
+        }
+    }
+
+    /// Display for the `pathStyle` of `config`.
+    pub fn for_config(config: &Config, root: &Path) -> Self {
+        Self::new(config.path_style, root)
+    }
+
+    /// `path` as it should be printed.
+    pub fn display<'a>(&self, path: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::AsGiven => Cow::Borrowed(path),
+            Self::Relative(root) => match absolute(Path::new(path)).strip_prefix(root) {
+                Ok(relative) => Cow::Owned(format!("./{}", relative.display())),
+                Err(_) => Cow::Borrowed(path),
+            },
+            Self::Absolute => Cow::Owned(absolute(Path::new(path)).display().to_string()),
+        }
+    }
+}
+
+/// `path` made absolute, resolving symlinks and `..` when it exists.
+fn absolute(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
 /// Print issues in cargo-style format to stdout.
 ///
 /// This is the main entry point for reporting. Issues are sorted and
@@ -39,40 +95,82 @@ pub fn report(issues: &[Issue]) {
     report_to(issues, &mut io::stdout().lock());
 }
 
-/// Print issues using per-rule severity overrides from configuration.
-pub fn report_with_config(issues: &[Issue], config: &crate::config::Config) {
-    report_to_with_config(issues, config, &mut io::stdout().lock());
+/// Print issues using per-rule severity overrides and the path style from
+/// configuration, with relative paths based on `root`.
+pub fn report_with_config(issues: &[Issue], config: &Config, root: &Path) {
+    report_to_with_config(issues, config, root, &mut io::stdout().lock());
+}
+
+/// Print issues using severity overrides, without the trailing counts line.
+pub fn report_without_summary_with_config(issues: &[Issue], config: &Config, root: &Path) {
+    let writer = &mut io::stdout().lock();
+    let paths = PathDisplay::for_config(config, root);
+    report_to_with_severity(issues, writer, &paths, false, |issue| {
+        config.severity_for_rule(issue.report_rule(), issue.report_severity())
+    });
+}
+
+/// Print issues in sections by rule, file or key, using severity overrides.
+///
+/// Sections are sorted by name, and issues keep the flat report's order within
+/// each section. Issues without a key are grouped last under `(no key)`.
+pub fn report_grouped_with_config(
+    issues: &[Issue],
+    config: &Config,
+    root: &Path,
+    group_by: GroupBy,
+    summary: bool,
+) {
+    report_grouped_to(
+        issues,
+        group_by,
+        &mut io::stdout().lock(),
+        &PathDisplay::for_config(config, root),
+        summary,
+        |issue| config.severity_for_rule(issue.report_rule(), issue.report_severity()),
+    );
 }
 
 pub fn report_to_stderr(issues: &[Issue]) {
     report_to(issues, &mut io::stderr().lock());
 }
 
-/// Print issues to stderr using per-rule severity overrides from configuration.
-pub fn report_to_stderr_with_config(issues: &[Issue], config: &crate::config::Config) {
-    report_to_with_config(issues, config, &mut io::stderr().lock());
+/// Print issues to stderr using per-rule severity overrides and the path
+/// style from configuration.
+pub fn report_to_stderr_with_config(issues: &[Issue], config: &Config, root: &Path) {
+    report_to_with_config(issues, config, root, &mut io::stderr().lock());
 }
 
-/// Print issues to a custom writer.
+/// Print issues to a custom writer, with paths as given.
 ///
 /// Useful for testing or redirecting output.
 pub fn report_to<W: Write>(issues: &[Issue], writer: &mut W) {
-    report_to_with_severity(issues, writer, |issue| issue.report_severity());
+    report_to_with_severity(issues, writer, &PathDisplay::AsGiven, true, |issue| {
+        issue.report_severity()
+    });
 }
 
-/// Print issues to a custom writer using per-rule severity overrides.
+/// Print issues to a custom writer using per-rule severity overrides and the
+/// path style from configuration.
 pub fn report_to_with_config<W: Write>(
     issues: &[Issue],
-    config: &crate::config::Config,
+    config: &Config,
+    root: &Path,
     writer: &mut W,
 ) {
-    report_to_with_severity(issues, writer, |issue| {
+    let paths = PathDisplay::for_config(config, root);
+    report_to_with_severity(issues, writer, &paths, true, |issue| {
         config.severity_for_rule(issue.report_rule(), issue.report_severity())
     });
 }
 
-fn report_to_with_severity<W, F>(issues: &[Issue], writer: &mut W, severity_for: F)
-where
+fn report_to_with_severity<W, F>(
+    issues: &[Issue],
+    writer: &mut W,
+    paths: &PathDisplay,
+    summary: bool,
+    severity_for: F,
+) where
     W: Write,
     F: Fn(&Issue) -> Severity,
 {
@@ -87,10 +185,304 @@ where
     let max_line_width = calculate_max_line_width(&sorted);
 
     for issue in &sorted {
-        print_issue(issue, writer, max_line_width, severity_for(issue));
+        print_issue(issue, writer, paths, max_line_width, severity_for(issue));
+    }
+
+    if summary {
+        print_summary(&sorted, writer, &severity_for);
+    }
+}
+
+fn report_grouped_to<W, F>(
+    issues: &[Issue],
+    group_by: GroupBy,
+    writer: &mut W,
+    paths: &PathDisplay,
+    summary: bool,
+    severity_for: F,
+) where
+    W: Write,
+    F: Fn(&Issue) -> Severity,
+{
+    if issues.is_empty() {
+        return;
+    }
+
+    let mut sorted = issues.to_vec();
+    sorted.sort_by(compare_issues);
+    let max_line_width = calculate_max_line_width(&sorted);
+
+    let refs: Vec<&Issue> = sorted.iter().collect();
+    print_groups(
+        &refs,
+        group_by,
+        writer,
+        paths,
+        max_line_width,
+        &severity_for,
+    );
+
+    if summary {
+        print_summary(&sorted, writer, &severity_for);
+    }
+}
+
+/// Print sorted issues in one section per group.
+fn print_groups<W, F>(
+    sorted: &[&Issue],
+    group_by: GroupBy,
+    writer: &mut W,
+    paths: &PathDisplay,
+    max_line_width: usize,
+    severity_for: &F,
+) where
+    W: Write,
+    F: Fn(&Issue) -> Severity,
+{
+    // `None` sorts first, so keyless issues are collected separately and printed last
+    let mut groups: BTreeMap<String, Vec<&Issue>> = BTreeMap::new();
+    let mut ungrouped = Vec::new();
+    for issue in sorted {
+        match group_name(issue, group_by, paths) {
+            Some(name) => groups.entry(name).or_default().push(*issue),
+            None => ungrouped.push(*issue),
+        }
+    }
+    let ungrouped = (!ungrouped.is_empty()).then(|| ("(no key)".to_string(), ungrouped));
+
+    for (name, group) in groups.into_iter().chain(ungrouped) {
+        print_section(&name, &group, writer, paths, max_line_width, severity_for);
+    }
+}
+
+/// Print the issues of each project of a monorepo config in its own section,
+/// using severity overrides, with one summary for all projects.
+///
+/// With `group_by`, each project is headed by its problem count and its
+/// issues are grouped as `report_grouped_with_config` does. Projects without
+/// issues are left out.
+pub fn report_projects_with_config(
+    projects: &[(String, Vec<Issue>)],
+    config: &Config,
+    root: &Path,
+    group_by: Option<GroupBy>,
+    summary: bool,
+) {
+    let paths = PathDisplay::for_config(config, root);
+    report_projects_to(
+        projects,
+        group_by,
+        &mut io::stdout().lock(),
+        &paths,
+        summary,
+        |issue| config.severity_for_rule(issue.report_rule(), issue.report_severity()),
+    );
+}
+
+fn report_projects_to<W, F>(
+    projects: &[(String, Vec<Issue>)],
+    group_by: Option<GroupBy>,
+    writer: &mut W,
+    paths: &PathDisplay,
+    summary: bool,
+    severity_for: F,
+) where
+    W: Write,
+    F: Fn(&Issue) -> Severity,
+{
+    let all: Vec<Issue> = projects
+        .iter()
+        .flat_map(|(_, issues)| issues.iter().cloned())
+        .collect();
+    if all.is_empty() {
+        return;
+    }
+    let max_line_width = calculate_max_line_width(&all);
+
+    for (name, issues) in projects {
+        let mut sorted: Vec<&Issue> = issues.iter().collect();
+        sorted.sort_by(|a, b| compare_issues(a, b));
+        if sorted.is_empty() {
+            continue;
+        }
+        match group_by {
+            Some(group_by) => {
+                print_heading(name, sorted.len(), writer);
+                print_groups(
+                    &sorted,
+                    group_by,
+                    writer,
+                    paths,
+                    max_line_width,
+                    &severity_for,
+                );
+            }
+            None => print_section(name, &sorted, writer, paths, max_line_width, &severity_for),
+        }
+    }
+
+    if summary {
+        print_summary(&all, writer, &severity_for);
+    }
+}
+
+/// Print `check --baseline-compare` sections using severity overrides.
+///
+/// New issues are printed in full. Pre-existing issues and the baseline
+/// entries that were fixed are listed one per line after them.
+pub fn report_baseline_comparison_with_config(
+    comparison: &BaselineComparison,
+    config: &Config,
+    root: &Path,
+) {
+    let paths = PathDisplay::for_config(config, root);
+    report_baseline_comparison_to(comparison, &mut io::stdout().lock(), &paths, |issue| {
+        config.severity_for_rule(issue.report_rule(), issue.report_severity())
+    });
+}
+
+fn report_baseline_comparison_to<W, F>(
+    comparison: &BaselineComparison,
+    writer: &mut W,
+    paths: &PathDisplay,
+    severity_for: F,
+) where
+    W: Write,
+    F: Fn(&Issue) -> Severity,
+{
+    let mut new: Vec<&Issue> = comparison.new.iter().collect();
+    new.sort_by(|a, b| compare_issues(a, b));
+    if !new.is_empty() {
+        let max_line_width = calculate_max_line_width(&comparison.new);
+        print_section(
+            "New issues",
+            &new,
+            writer,
+            paths,
+            max_line_width,
+            &severity_for,
+        );
+    }
+
+    let mut existing: Vec<&Issue> = comparison.existing.iter().collect();
+    existing.sort_by(|a, b| compare_issues(a, b));
+    if !existing.is_empty() {
+        print_heading("Pre-existing issues", existing.len(), writer);
+        for issue in existing {
+            let loc = issue.location();
+            let (file_path, line, col, _) = extract_location_info(&loc);
+            let file_path = paths.display(file_path);
+            let location = if line == 0 {
+                file_path.to_string()
+            } else {
+                format!("{}:{}:{}", file_path, line, col)
+            };
+            let _ = writeln!(
+                writer,
+                "  {}  \"{}\"  {}",
+                location,
+                issue.message(),
+                format!("[{}]", issue.report_rule()).dimmed().cyan()
+            );
+        }
+        let _ = writeln!(writer);
+    }
+
+    if !comparison.fixed.is_empty() {
+        print_heading("Fixed issues", comparison.fixed.len(), writer);
+        for fingerprint in &comparison.fixed {
+            let _ = writeln!(
+                writer,
+                "  {}  \"{}\"  {}",
+                fingerprint.file,
+                fingerprint.key,
+                format!("[{}]", fingerprint.rule).dimmed().cyan()
+            );
+        }
+        let _ = writeln!(writer);
+    }
+
+    let errors = comparison
+        .new
+        .iter()
+        .filter(|issue| severity_for(issue) == Severity::Error)
+        .count();
+    let warnings = comparison.new.len() - errors;
+    let counts = format!(
+        "{} pre-existing, {} fixed",
+        comparison.existing.len(),
+        comparison.fixed.len()
+    );
+    if comparison.new.is_empty() {
+        let _ = writeln!(
+            writer,
+            "{} No new problems ({})",
+            SUCCESS_MARK.green(),
+            counts
+        );
+    } else {
+        let _ = writeln!(
+            writer,
+            "{} {} new {} ({} {}, {} {}); {}",
+            FAILURE_MARK.red(),
+            comparison.new.len(),
+            if comparison.new.len() == 1 {
+                "problem"
+            } else {
+                "problems"
+            },
+            errors,
+            if errors == 1 { "error" } else { "errors" }.red(),
+            warnings,
+            if warnings == 1 { "warning" } else { "warnings" }.yellow(),
+            counts
+        );
     }
+}
 
-    print_summary(&sorted, writer, &severity_for);
+/// Print a section heading with its problem count, then its issues.
+fn print_section<W, F>(
+    name: &str,
+    issues: &[&Issue],
+    writer: &mut W,
+    paths: &PathDisplay,
+    max_line_width: usize,
+    severity_for: &F,
+) where
+    W: Write,
+    F: Fn(&Issue) -> Severity,
+{
+    print_heading(name, issues.len(), writer);
+    for issue in issues {
+        print_issue(issue, writer, paths, max_line_width, severity_for(issue));
+    }
+}
+
+/// Print a section heading with its problem count.
+fn print_heading<W: Write>(name: &str, count: usize, writer: &mut W) {
+    let _ = writeln!(
+        writer,
+        "{} {}\n",
+        name.bold().underline(),
+        format!(
+            "({} {})",
+            count,
+            if count == 1 { "problem" } else { "problems" }
+        )
+        .dimmed()
+    );
+}
+
+/// Section an issue belongs to; `None` for issues without a key.
+fn group_name(issue: &Issue, group_by: GroupBy, paths: &PathDisplay) -> Option<String> {
+    match group_by {
+        GroupBy::Rule => Some(issue.report_rule().to_string()),
+        GroupBy::File => {
+            let loc = issue.location();
+            Some(paths.display(extract_location_info(&loc).0).into_owned())
+        }
+        GroupBy::Key => issue.key().map(str::to_string),
+    }
 }
 
 /// Print a success message when no issues are found.
@@ -129,29 +521,41 @@ pub fn print_success_to<W: Write>(source_files: usize, locale_files: usize, writ
 }
 
 /// Print a error about files that could not be parsed.
-pub fn print_parse_error(count: usize, verbose: bool) {
-    print_parse_error_to(count, verbose, &mut io::stderr().lock());
+///
+/// With `verbose`, each file is listed with its parse error.
+pub fn print_parse_error(errors: &[ParseErrorIssue], verbose: bool) {
+    print_parse_error_to(errors, verbose, &mut io::stderr().lock());
 }
 
 /// Print a parse error to a custom writer.
-pub fn print_parse_error_to<W: Write>(count: usize, verbose: bool, writer: &mut W) {
-    if count > 0 {
-        if verbose {
-            let _ = writeln!(
-                writer,
-                "{} {} file(s) could not be parsed",
-                "error:".bold().red(),
-                count,
-            );
-        } else {
+pub fn print_parse_error_to<W: Write>(errors: &[ParseErrorIssue], verbose: bool, writer: &mut W) {
+    if errors.is_empty() {
+        return;
+    }
+    if verbose {
+        let _ = writeln!(
+            writer,
+            "{} {} file(s) could not be parsed:",
+            "error:".bold().red(),
+            errors.len(),
+        );
+        for error in errors {
             let _ = writeln!(
                 writer,
-                "{} {} file(s) could not be parsed (use {} for details)",
-                "error:".bold().red(),
-                count,
-                "-v".cyan()
+                "  {} {}: {}",
+                "-->".blue(),
+                error.file_path,
+                error.error
             );
         }
+    } else {
+        let _ = writeln!(
+            writer,
+            "{} {} file(s) could not be parsed (use {} for details)",
+            "error:".bold().red(),
+            errors.len(),
+            "-v".cyan()
+        );
     }
 }
 
@@ -170,13 +574,273 @@ pub fn print_execution_time_to<W: Write>(duration: Duration, writer: &mut W) {
     let _ = writeln!(writer, "{}", format!("Completed in {}ms", millis).dimmed());
 }
 
+/// Print `check --profile` timings to stderr.
+///
+/// With `GLOT_DISABLE_TIMING` set, only the step names are printed so the
+/// output stays deterministic.
+pub fn print_profile(timings: &[PhaseTiming]) {
+    let durations = std::env::var("GLOT_DISABLE_TIMING").is_err();
+    print_profile_to(timings, durations, &mut io::stderr().lock());
+}
+
+/// Print profile timings to a custom writer (for testing).
+pub fn print_profile_to<W: Write>(timings: &[PhaseTiming], durations: bool, writer: &mut W) {
+    let total: Duration = timings.iter().map(|t| t.duration).sum();
+    let width = timings
+        .iter()
+        .map(|t| t.name.len())
+        .chain(["total".len()])
+        .max()
+        .unwrap_or_default();
+
+    let _ = writeln!(writer, "{}", "Profile:".bold());
+    let rows = timings
+        .iter()
+        .map(|t| (t.name.as_str(), t.duration))
+        .chain([("total", total)]);
+    for (name, duration) in rows {
+        if !durations {
+            let _ = writeln!(writer, "  {}", name);
+            continue;
+        }
+        let percent = if total.is_zero() {
+            0.0
+        } else {
+            duration.as_secs_f64() / total.as_secs_f64() * 100.0
+        };
+        let _ = writeln!(
+            writer,
+            "  {:<width$}  {:>9.1}ms  {:>5.1}%",
+            name,
+            duration.as_secs_f64() * 1000.0,
+            percent
+        );
+    }
+}
+
+/// Print how many issues each rule produced, most frequent first.
+pub fn print_rule_counts(issues: &[Issue]) {
+    print_rule_counts_to(issues, &mut io::stdout().lock());
+}
+
+/// Print per-rule issue counts to a custom writer (for testing).
+pub fn print_rule_counts_to<W: Write>(issues: &[Issue], writer: &mut W) {
+    if issues.is_empty() {
+        return;
+    }
+
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for issue in issues {
+        *counts.entry(issue.report_rule().to_string()).or_default() += 1;
+    }
+    // Stable sort keeps ties in rule-name order
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1));
+    let width = counts
+        .iter()
+        .map(|(rule, _)| rule.len())
+        .max()
+        .unwrap_or_default();
+
+    let _ = writeln!(writer, "\n{}", "Issues by rule:".bold());
+    for (rule, count) in counts {
+        let _ = writeln!(writer, "  {:<width$}  {:>5}", rule, count);
+    }
+}
+
+/// Lines of context around each change in a diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// Print the changes to modified files as a unified diff.
+pub fn print_diffs(diffs: &BTreeMap<String, FileDiff>) {
+    print_diffs_to(diffs, &mut io::stdout().lock());
+}
+
+/// Print a unified diff of modified files to a custom writer (for testing).
+///
+/// Paths get the `a/` and `b/` prefixes of git, so the output can be
+/// applied with `git apply`.
+pub fn print_diffs_to<W: Write>(diffs: &BTreeMap<String, FileDiff>, writer: &mut W) {
+    for (file_path, diff) in diffs {
+        let path = file_path.strip_prefix("./").unwrap_or(file_path);
+        let _ = writeln!(writer, "{}", format!("--- a/{}", path).bold());
+        let _ = writeln!(writer, "{}", format!("+++ b/{}", path).bold());
+        for line in unified_diff(&diff.before, &diff.after) {
+            let colored = match line.chars().next() {
+                Some('@') => line.cyan(),
+                Some('-') => line.red(),
+                Some('+') => line.green(),
+                _ => line.normal(),
+            };
+            let _ = writeln!(writer, "{}", colored);
+        }
+    }
+    if !diffs.is_empty() {
+        let _ = writeln!(writer);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Hunks of a unified diff between two file contents, without file headers.
+fn unified_diff(before: &str, after: &str) -> Vec<String> {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+    let lines = diff_lines(&before, &after);
+
+    let changes: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(idx, _)| idx)
+        .collect();
+    let Some(&first) = changes.first() else {
+        return Vec::new();
+    };
+
+    // Group changes whose contexts touch or overlap into one hunk
+    let mut groups = vec![(first, first)];
+    for &idx in &changes[1..] {
+        let last = groups.last_mut().unwrap();
+        if idx - last.1 <= 2 * DIFF_CONTEXT + 1 {
+            last.1 = idx;
+        } else {
+            groups.push((idx, idx));
+        }
+    }
+
+    let mut output = Vec::new();
+    for (first, last) in groups {
+        let start = first.saturating_sub(DIFF_CONTEXT);
+        let end = (last + DIFF_CONTEXT).min(lines.len() - 1);
+        let hunk = &lines[start..=end];
+
+        let old_before = lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Added(_)))
+            .count();
+        let new_before = lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Removed(_)))
+            .count();
+        let old_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Added(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Removed(_)))
+            .count();
+        // An empty side starts at the line before, like `diff -u`
+        let old_start = if old_count == 0 {
+            old_before
+        } else {
+            old_before + 1
+        };
+        let new_start = if new_count == 0 {
+            new_before
+        } else {
+            new_before + 1
+        };
+
+        output.push(format!(
+            "@@ -{},{} +{},{} @@",
+            old_start, old_count, new_start, new_count
+        ));
+        for line in hunk {
+            output.push(match line {
+                DiffLine::Same(text) => format!(" {}", text),
+                DiffLine::Removed(text) => format!("-{}", text),
+                DiffLine::Added(text) => format!("+{}", text),
+            });
+        }
+    }
+    output
+}
+
+/// Shortest line edit script from `before` to `after` (Myers' algorithm).
+///
+/// Runs in O((N+M)D) for D changed lines, which stays fast on large files
+/// with a few edits.
+fn diff_lines<'a>(before: &[&'a str], after: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let (n, m) = (before.len() as isize, after.len() as isize);
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max as isize {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && before[x as usize] == after[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let idx = (k + offset) as usize;
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            lines.push(DiffLine::Same(before[x as usize]));
+        }
+        if d > 0 {
+            if x == prev_x {
+                y -= 1;
+                lines.push(DiffLine::Added(after[y as usize]));
+            } else {
+                x -= 1;
+                lines.push(DiffLine::Removed(before[x as usize]));
+            }
+        }
+    }
+    lines.reverse();
+    lines
+}
+
 // ============================================================
 // Internal Functions
 // ============================================================
 
-fn print_issue<W: Write>(issue: &Issue, writer: &mut W, max_line_width: usize, severity: Severity) {
+fn print_issue<W: Write>(
+    issue: &Issue,
+    writer: &mut W,
+    paths: &PathDisplay,
+    max_line_width: usize,
+    severity: Severity,
+) {
     let loc = issue.location();
     let (file_path, line, col, source_line) = extract_location_info(&loc);
+    let file_path = paths.display(file_path);
 
     // Print severity and message (cargo-style)
     let severity_str = match severity {
@@ -266,7 +930,7 @@ fn print_issue<W: Write>(issue: &Issue, writer: &mut W, max_line_width: usize, s
     // Print usages if present
     let usages = issue.usages();
     if !usages.is_empty() {
-        print_usages(usages, writer, max_line_width);
+        print_usages(usages, writer, paths, max_line_width);
     } else if matches!(
         issue,
         Issue::ReplicaLag(_) | Issue::Untranslated(_) | Issue::TypeMismatch(_)
@@ -286,7 +950,12 @@ fn print_issue<W: Write>(issue: &Issue, writer: &mut W, max_line_width: usize, s
     let _ = writeln!(writer); // Empty line between issues
 }
 
-fn print_usages<W: Write>(usages: &[ResolvedKeyUsage], writer: &mut W, max_line_width: usize) {
+fn print_usages<W: Write>(
+    usages: &[ResolvedKeyUsage],
+    writer: &mut W,
+    paths: &PathDisplay,
+    max_line_width: usize,
+) {
     let total = usages.len();
     let display_count = total.min(MAX_USAGES_DISPLAY);
 
@@ -305,7 +974,7 @@ fn print_usages<W: Write>(usages: &[ResolvedKeyUsage], writer: &mut W, max_line_
             "",
             "=".blue(),
             "used:".bold(),
-            usage.context.file_path(),
+            paths.display(usage.context.file_path()),
             usage.context.line(),
             usage.context.col(),
             suffix,
@@ -407,7 +1076,7 @@ mod tests {
     };
     use crate::issues::{
         HardcodedTextIssue, IssueUnresolvedKeyReason, MissingKeyIssue, OrphanKeyIssue,
-        ParseErrorFileType, ParseErrorIssue, ReplicaLagIssue, Rule, Severity, TypeMismatchIssue,
+        ParseErrorFileType, ParseErrorIssue, ReplicaLagIssue, Rule, TypeMismatchIssue,
         UnresolvedKeyIssue, UntranslatedIssue, UnusedKeyIssue,
     };
 
@@ -487,7 +1156,10 @@ mod tests {
     fn test_report_unused_key() {
         let loc = MessageLocation::new("./messages/en.json", 5, 3);
         let ctx = MessageContext::new(loc, "Common.unused", "Unused Value");
-        let issue = Issue::UnusedKey(UnusedKeyIssue { context: ctx });
+        let issue = Issue::UnusedKey(UnusedKeyIssue {
+            context: ctx,
+            hint: None,
+        });
 
         let mut output = Vec::new();
         report_to(&[issue], &mut output);
@@ -513,6 +1185,8 @@ mod tests {
             context: usage_ctx,
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         };
 
         let issue = Issue::ReplicaLag(ReplicaLagIssue {
@@ -544,7 +1218,10 @@ mod tests {
 
         let loc2 = MessageLocation::new("./messages/en.json", 5, 3);
         let ctx2 = MessageContext::new(loc2, "Common.unused", "Unused");
-        let issue2 = Issue::UnusedKey(UnusedKeyIssue { context: ctx2 });
+        let issue2 = Issue::UnusedKey(UnusedKeyIssue {
+            context: ctx2,
+            hint: None,
+        });
 
         let mut output = Vec::new();
         report_to(&[issue1, issue2], &mut output);
@@ -661,14 +1338,14 @@ mod tests {
             usages: vec![],
         });
         let config = crate::config::Config {
-            severities: [(Rule::Untranslated, Severity::Warning)]
+            severities: [(Rule::Untranslated, crate::config::SeverityOverride::Warning)]
                 .into_iter()
                 .collect(),
             ..crate::config::Config::default()
         };
 
         let mut output = Vec::new();
-        report_to_with_config(&[issue], &config, &mut output);
+        report_to_with_config(&[issue], &config, Path::new("."), &mut output);
         let output_str = String::from_utf8(output).unwrap();
         let stripped = strip_ansi(&output_str);
 
@@ -676,6 +1353,25 @@ mod tests {
         assert!(stripped.contains("1 problems (0 errors, 1 warning)"));
     }
 
+    #[test]
+    fn test_path_display() {
+        let relative = PathDisplay::Relative(PathBuf::from("/repo"));
+        assert_eq!(relative.display("/repo/src/app.tsx"), "./src/app.tsx");
+        assert_eq!(relative.display("/other/app.tsx"), "/other/app.tsx");
+        assert_eq!(
+            PathDisplay::Absolute.display("/repo/./src/app.tsx"),
+            "/repo/src/app.tsx"
+        );
+        assert_eq!(
+            PathDisplay::AsGiven.display("./src/app.tsx"),
+            "./src/app.tsx"
+        );
+
+        let cwd = PathDisplay::new(PathStyle::Relative, Path::new("."));
+        assert_eq!(cwd.display("./src/app.tsx"), "./src/app.tsx");
+        assert_eq!(cwd.display("src/app.tsx"), "./src/app.tsx");
+    }
+
     #[test]
     fn test_report_type_mismatch() {
         let msg_loc = MessageLocation::new("./messages/en.json", 8, 3);
@@ -761,6 +1457,8 @@ mod tests {
                     context: ctx,
                     suppressed_rules: HashSet::new(),
                     from_schema: None,
+                    rich_text: false,
+                    namespace: None,
                 }
             })
             .collect();
@@ -843,6 +1541,62 @@ mod tests {
         assert!(output_str.contains("^"));
     }
 
+    #[test]
+    fn test_report_grouped_by_key() {
+        let hardcoded = Issue::HardcodedText(HardcodedTextIssue {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 3, 5),
+                "<p>Hello</p>",
+                CommentStyle::Jsx,
+            ),
+            text: "Hello".to_string(),
+        });
+        let unused = |line, key: &str| {
+            Issue::UnusedKey(UnusedKeyIssue {
+                context: MessageContext::new(
+                    MessageLocation::new("./messages/en.json", line, 3),
+                    key,
+                    "value",
+                ),
+                hint: None,
+            })
+        };
+        let issues = vec![
+            hardcoded,
+            unused(4, "Common.submit"),
+            unused(2, "Common.cancel"),
+            unused(9, "Common.submit"),
+        ];
+
+        let mut output = Vec::new();
+        report_grouped_to(
+            &issues,
+            GroupBy::Key,
+            &mut output,
+            &PathDisplay::AsGiven,
+            true,
+            |issue| issue.report_severity(),
+        );
+        let stripped = strip_ansi(&String::from_utf8(output).unwrap());
+
+        let headers: Vec<&str> = stripped
+            .lines()
+            .filter(|line| line.ends_with("problem)") || line.ends_with("problems)"))
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                "Common.cancel (1 problem)",
+                "Common.submit (2 problems)",
+                "(no key) (1 problem)"
+            ]
+        );
+        let first = stripped.find("en.json:4:3").unwrap();
+        let second = stripped.find("en.json:9:3").unwrap();
+        assert!(first < second);
+        assert!(stripped.contains("4 problems (1 error, 3 warnings)"));
+    }
+
     #[test]
     fn test_print_execution_time() {
         let mut output = Vec::new();
@@ -858,4 +1612,150 @@ mod tests {
         let stripped = strip_ansi(&String::from_utf8(output).unwrap());
         assert!(stripped.contains("Completed in 45ms"));
     }
+
+    #[test]
+    fn test_print_profile() {
+        let timings = vec![
+            PhaseTiming {
+                name: "file scanning".to_string(),
+                duration: Duration::from_millis(30),
+            },
+            PhaseTiming {
+                name: "rule: missing".to_string(),
+                duration: Duration::from_millis(10),
+            },
+        ];
+        let mut output = Vec::new();
+        print_profile_to(&timings, true, &mut output);
+        let stripped = strip_ansi(&String::from_utf8(output).unwrap());
+        assert_eq!(
+            stripped,
+            "Profile:\n\
+             \x20 file scanning       30.0ms   75.0%\n\
+             \x20 rule: missing       10.0ms   25.0%\n\
+             \x20 total               40.0ms  100.0%\n"
+        );
+
+        let mut output = Vec::new();
+        print_profile_to(&timings, false, &mut output);
+        let stripped = strip_ansi(&String::from_utf8(output).unwrap());
+        assert_eq!(
+            stripped,
+            "Profile:\n  file scanning\n  rule: missing\n  total\n"
+        );
+    }
+
+    #[test]
+    fn test_print_parse_error_lists_files_when_verbose() {
+        let errors = vec![ParseErrorIssue {
+            file_path: "./src/broken.tsx".to_string(),
+            error: "Expected '</'".to_string(),
+            file_type: ParseErrorFileType::Source,
+        }];
+
+        let mut output = Vec::new();
+        print_parse_error_to(&errors, true, &mut output);
+        let stripped = strip_ansi(&String::from_utf8(output).unwrap());
+        assert_eq!(
+            stripped,
+            "error: 1 file(s) could not be parsed:\n  --> ./src/broken.tsx: Expected '</'\n"
+        );
+
+        let mut output = Vec::new();
+        print_parse_error_to(&errors, false, &mut output);
+        let stripped = strip_ansi(&String::from_utf8(output).unwrap());
+        assert_eq!(
+            stripped,
+            "error: 1 file(s) could not be parsed (use -v for details)\n"
+        );
+
+        let mut output = Vec::new();
+        print_parse_error_to(&[], true, &mut output);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_print_rule_counts_sorted_by_count() {
+        let hardcoded = Issue::HardcodedText(HardcodedTextIssue {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 3, 5),
+                "<p>Hello</p>",
+                CommentStyle::Jsx,
+            ),
+            text: "Hello".to_string(),
+        });
+        let unused = |key: &str| {
+            Issue::UnusedKey(UnusedKeyIssue {
+                context: MessageContext::new(
+                    MessageLocation::new("./messages/en.json", 2, 3),
+                    key,
+                    "value",
+                ),
+                hint: None,
+            })
+        };
+        let issues = vec![hardcoded, unused("Common.submit"), unused("Common.cancel")];
+
+        let mut output = Vec::new();
+        print_rule_counts_to(&issues, &mut output);
+        let stripped = strip_ansi(&String::from_utf8(output).unwrap());
+        assert_eq!(
+            stripped,
+            "\nIssues by rule:\n  unused-key      2\n  hardcoded       1\n"
+        );
+
+        let mut output = Vec::new();
+        print_rule_counts_to(&[], &mut output);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_unified_diff_hunks() {
+        let before = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+        let after = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\nn\n";
+        assert_eq!(
+            unified_diff(before, after),
+            vec![
+                "@@ -1,5 +1,5 @@",
+                " a",
+                "-b",
+                "+B",
+                " c",
+                " d",
+                " e",
+                "@@ -11,3 +11,4 @@",
+                " k",
+                " l",
+                " m",
+                "+n",
+            ]
+        );
+        assert!(unified_diff(before, before).is_empty());
+        assert_eq!(unified_diff("", "x\n"), vec!["@@ -0,0 +1,1 @@", "+x"]);
+    }
+
+    #[test]
+    fn test_print_diffs() {
+        let diffs = BTreeMap::from([(
+            "./messages/en.json".to_string(),
+            FileDiff {
+                before: "{\n  \"a\": \"A\",\n  \"b\": \"B\"\n}\n".to_string(),
+                after: "{\n  \"a\": \"A\"\n}\n".to_string(),
+            },
+        )]);
+
+        let mut output = Vec::new();
+        print_diffs_to(&diffs, &mut output);
+        assert_eq!(
+            strip_ansi(&String::from_utf8(output).unwrap()),
+            "--- a/messages/en.json\n\
+             +++ b/messages/en.json\n\
+             @@ -1,4 +1,3 @@\n \
+             {\n\
+             -  \"a\": \"A\",\n\
+             -  \"b\": \"B\"\n\
+             +  \"a\": \"A\"\n \
+             }\n\n"
+        );
+    }
 }
@@ -0,0 +1,236 @@
+//! Resolution traces for `check --explain-resolution`.
+//!
+//! For each translation call on one line, prints to stderr how its key was
+//! resolved: the translation function binding the call matched, the
+//! registries its argument was looked up in, the candidate values, the final
+//! `ValueSource`, and the keys Phase 3 produced. Meant for debugging a dynamic
+//! key that warns, or resolves, unexpectedly.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::{
+    cli::args::SourceLine,
+    core::{
+        CheckContext, FileTrace,
+        extract::{RawTranslationCall, TranslationCallKind, TranslationSource, ValueSource},
+    },
+};
+
+/// Print the trace of `target` to stderr, from the first context checking
+/// its file.
+pub fn print_explanation<'a>(
+    contexts: impl IntoIterator<Item = &'a CheckContext>,
+    target: &SourceLine,
+) {
+    eprint!("{}", explain(contexts, target));
+}
+
+fn explain<'a>(
+    contexts: impl IntoIterator<Item = &'a CheckContext>,
+    target: &SourceLine,
+) -> String {
+    let location = format!("{}:{}", target.path.display(), target.line);
+    let found = contexts.into_iter().find_map(|ctx| {
+        let file_path = ctx
+            .files
+            .iter()
+            .find(|file_path| same_file(Path::new(file_path), &target.path))?;
+        Some((file_path, ctx.trace_file(file_path)))
+    });
+    let Some((file_path, trace)) = found else {
+        return format!(
+            "explain-resolution: {} is not a checked source file\n",
+            location
+        );
+    };
+    let Some(trace) = trace else {
+        return format!("explain-resolution: {} could not be parsed\n", file_path);
+    };
+    let mut out = format!("explain-resolution: {}:{}\n", file_path, target.line);
+    let calls: Vec<_> = trace
+        .raw_calls
+        .iter()
+        .filter(|call| call.context.line() == target.line)
+        .collect();
+    if calls.is_empty() {
+        out.push_str("  no translation call on this line\n");
+    }
+    for call in calls {
+        write_call(&mut out, call, &trace);
+    }
+    out
+}
+
+fn write_call(out: &mut String, call: &RawTranslationCall, trace: &FileTrace) {
+    let (line, col) = (call.context.line(), call.context.col());
+    let kind = match &call.call_kind {
+        TranslationCallKind::Direct => "direct call".to_string(),
+        TranslationCallKind::Method(method) => format!(".{}() call", method),
+    };
+    out.push_str(&format!("  {} at {}:{}\n", kind, line, col));
+    out.push_str(&format!(
+        "    binding: {}\n",
+        describe_binding(&call.translation_source)
+    ));
+    out.push_str(&format!(
+        "    argument: {}\n",
+        call.argument.source_description()
+    ));
+    let mut registries = Vec::new();
+    collect_registries(&call.argument, &mut registries);
+    if !registries.is_empty() {
+        out.push_str(&format!("    registry: {}\n", registries.join(", ")));
+    }
+    match call.argument.resolve_keys() {
+        Ok(candidates) => out.push_str(&format!("    candidates: {}\n", candidates.join(", "))),
+        Err(_) => out.push_str("    candidates: (none)\n"),
+    }
+    out.push_str(&format!("    value source: {:?}\n", call.argument));
+
+    let usages = &trace.key_usages;
+    let at_call = |l: usize, c: usize| l == line && c == col;
+    let resolved: Vec<_> = usages
+        .resolved
+        .iter()
+        .filter(|usage| at_call(usage.context.line(), usage.context.col()))
+        .map(|usage| usage.key.as_str())
+        .collect();
+    if !resolved.is_empty() {
+        out.push_str(&format!("    resolved: {}\n", resolved.join(", ")));
+    }
+    for usage in usages
+        .unresolved
+        .iter()
+        .filter(|usage| at_call(usage.context.line(), usage.context.col()))
+    {
+        out.push_str(&format!("    unresolved: {}\n", usage.reason));
+        if let Some(hint) = &usage.hint {
+            out.push_str(&format!("    hint: {}\n", hint));
+        }
+    }
+}
+
+fn describe_binding(source: &TranslationSource) -> String {
+    let namespaces = |namespaces: &[Option<String>]| {
+        if namespaces.is_empty() {
+            return "no call sites found".to_string();
+        }
+        namespaces
+            .iter()
+            .map(|namespace| match namespace {
+                Some(namespace) => format!("\"{}\"", namespace),
+                None => "(no namespace)".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match source {
+        TranslationSource::Direct {
+            namespace: Some(namespace),
+        } => format!("translation hook, namespace \"{}\"", namespace),
+        TranslationSource::Direct { namespace: None } => {
+            "translation hook, no namespace".to_string()
+        }
+        TranslationSource::FromProps { namespaces: ns } => {
+            format!("translation function prop, namespaces {}", namespaces(ns))
+        }
+        TranslationSource::FromFnCall { namespaces: ns } => {
+            format!(
+                "translation function parameter, namespaces {}",
+                namespaces(ns)
+            )
+        }
+        TranslationSource::Shadowed => "shadowed".to_string(),
+    }
+}
+
+/// The Phase 1 registries a value source was looked up in.
+fn collect_registries(source: &ValueSource, registries: &mut Vec<String>) {
+    match source {
+        ValueSource::Template { inner, .. } => collect_registries(inner, registries),
+        ValueSource::Conditional {
+            consequent,
+            alternate,
+        } => {
+            collect_registries(consequent, registries);
+            collect_registries(alternate, registries);
+        }
+        ValueSource::ObjectAccess { object_name, .. } => {
+            registries.push(format!("key object \"{}\" (values)", object_name));
+        }
+        ValueSource::ObjectKeys { object_name, .. } => {
+            registries.push(format!("key object \"{}\" (property names)", object_name));
+        }
+        ValueSource::ArrayIteration {
+            array_name,
+            property_name,
+            ..
+        } => {
+            registries.push(format!(
+                "key array \"{}\" (property \"{}\")",
+                array_name, property_name
+            ));
+        }
+        ValueSource::StringArrayElement { array_name, .. } => {
+            registries.push(format!("string array \"{}\"", array_name));
+        }
+        ValueSource::Literal(_) | ValueSource::Unresolvable { .. } => {}
+    }
+}
+
+/// Whether two paths name the same file, ignoring `./` segments.
+fn same_file(a: &Path, b: &Path) -> bool {
+    let normalize = |path: &Path| -> PathBuf {
+        path.components()
+            .filter(|component| *component != Component::CurDir)
+            .collect()
+    };
+    if normalize(a) == normalize(b) {
+        return true;
+    }
+    matches!(
+        (a.canonicalize(), b.canonicalize()),
+        (Ok(a), Ok(b)) if a == b
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_file() {
+        assert!(same_file(
+            Path::new("./src/app.tsx"),
+            Path::new("src/app.tsx")
+        ));
+        assert!(!same_file(
+            Path::new("./src/app.tsx"),
+            Path::new("src/page.tsx")
+        ));
+    }
+
+    #[test]
+    fn test_collect_registries() {
+        let source = ValueSource::Template {
+            prefix: "tools.".to_string(),
+            suffix: String::new(),
+            inner: Box::new(ValueSource::Conditional {
+                consequent: Box::new(ValueSource::ObjectAccess {
+                    object_name: "toolKeys".to_string(),
+                    candidate_values: vec!["create".to_string()],
+                }),
+                alternate: Box::new(ValueSource::StringArrayElement {
+                    array_name: "KEYS".to_string(),
+                    candidate_values: vec!["edit".to_string()],
+                }),
+            }),
+        };
+        let mut registries = Vec::new();
+        collect_registries(&source, &mut registries);
+        assert_eq!(
+            registries,
+            vec!["key object \"toolKeys\" (values)", "string array \"KEYS\""]
+        );
+    }
+}
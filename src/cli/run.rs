@@ -5,8 +5,8 @@
 use std::time::Instant;
 
 use super::{
-    args::{Arguments, Command},
-    commands::{baseline, check, clean, fix, init},
+    args::{Arguments, CheckFormat, ColorChoice, Command},
+    commands::{baseline, check, clean, completions, explain, fix, init, migrate, stats, watch},
     exit_status::ExitStatus,
     report,
 };
@@ -14,12 +14,21 @@ use anyhow::Result;
 
 pub fn run(args: Arguments) -> Result<ExitStatus> {
     let verbose = args.verbose();
+    configure_color(args.color);
+    if let Some(jobs) = args.jobs() {
+        configure_thread_pool(jobs)?;
+    }
 
     match args.command {
         Some(Command::Check(cmd)) => {
             let start = Instant::now();
+            // No timing line with --quiet, or in JSON Lines output on stdout
+            let timed = !cmd.args.quiet
+                && (cmd.args.format == CheckFormat::Text || cmd.args.report_file.is_some());
             let result = check::check(cmd, verbose)?;
-            report::print_execution_time(start.elapsed());
+            if timed {
+                report::print_execution_time(start.elapsed());
+            }
             Ok(result)
         }
         Some(Command::Clean(cmd)) => {
@@ -40,7 +49,18 @@ pub fn run(args: Arguments) -> Result<ExitStatus> {
             report::print_execution_time(start.elapsed());
             Ok(result)
         }
-        Some(Command::Init) => init::init(),
+        Some(Command::Migrate(cmd)) => {
+            let start = Instant::now();
+            let result = migrate::migrate(cmd, verbose)?;
+            report::print_execution_time(start.elapsed());
+            Ok(result)
+        }
+        Some(Command::Watch(cmd)) => watch::watch(cmd, verbose),
+        // No timing line: it would break `--format json` output
+        Some(Command::Stats(cmd)) => stats::stats(cmd),
+        Some(Command::Explain(cmd)) => explain::explain(cmd),
+        Some(Command::Init(cmd)) => init::init(cmd),
+        Some(Command::Completions(cmd)) => completions::completions(cmd),
         Some(Command::Serve) => {
             // Serve command is handled in main.rs before calling run()
             anyhow::bail!("Serve command should be handled before run()")
@@ -50,3 +70,25 @@ pub fn run(args: Arguments) -> Result<ExitStatus> {
         }
     }
 }
+
+/// Size rayon's global pool used by file parsing and analysis.
+///
+/// `--jobs 1` runs everything on a single worker, which is handy when debugging.
+fn configure_thread_pool(jobs: usize) -> Result<()> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build_global()
+        .map_err(|e| anyhow::anyhow!("Failed to configure {} worker thread(s): {}", jobs, e))
+}
+
+/// Apply `--color` before anything is printed.
+///
+/// `always` and `never` override `NO_COLOR`, `CLICOLOR` and terminal detection;
+/// `auto` leaves that detection to `colored`.
+fn configure_color(color: ColorChoice) {
+    match color {
+        ColorChoice::Auto => colored::control::unset_override(),
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+    }
+}
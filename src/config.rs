@@ -1,13 +1,30 @@
 //! Configuration file loading and parsing.
 //!
-//! This module handles loading configuration from `.glotrc.json` files
-//! and provides default configuration values.
+//! This module handles loading configuration from `glot.config.json` or
+//! `.glotrc.json` files and provides default configuration values.
 //!
 //! ## Configuration Priority
 //!
 //! 1. CLI arguments (highest priority)
-//! 2. .glotrc.json file
+//! 2. Config file
 //! 3. Built-in defaults (lowest priority)
+//!
+//! ## Config File Discovery
+//!
+//! Starting from the source root, each directory up to the repository root
+//! (the first one containing `.git`) is searched for the names in
+//! [`CONFIG_FILE_NAMES`]. The nearest directory wins; within a directory,
+//! `glot.config.json` wins over `.glotrc.json`. Only one file is loaded, they
+//! are never merged.
+//!
+//! Both are read as JSON with `//` and `/* */` comments and trailing commas.
+//!
+//! ## Projects
+//!
+//! A monorepo root config can list `projects`, each with its own root,
+//! messages, primary locale and includes. Other settings are shared. `glot
+//! check` at the root checks every project on its own; other commands run on
+//! the project whose root is given with `--source-root`.
 
 use std::{
     collections::BTreeMap,
@@ -17,21 +34,61 @@ use std::{
 
 use anyhow::{Context, Result};
 use glob::Pattern;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::issues::{Rule, Severity};
+use crate::utils::strip_jsonc;
 
 pub const CONFIG_FILE_NAME: &str = ".glotrc.json";
 
+/// Config file names, in order of precedence within a directory.
+pub const CONFIG_FILE_NAMES: &[&str] = &["glot.config.json", CONFIG_FILE_NAME];
+
+/// Placeholder for the locale code in `messagePattern`.
+pub const LOCALE_PLACEHOLDER: &str = "{locale}";
+
 /// Supported i18n framework.
 #[derive(
-    Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, schemars::JsonSchema,
+    Debug,
+    Deserialize,
+    Serialize,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    schemars::JsonSchema,
+    clap::ValueEnum,
 )]
 #[serde(rename_all = "kebab-case")]
 pub enum Framework {
     ReactI18next,
     #[default]
     NextIntl,
+    ReactIntl,
+}
+
+/// UI framework whose component files are scanned besides JS/TS sources.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum UiFramework {
+    /// `.vue` single-file components using vue-i18n.
+    Vue,
+    /// `.astro` components. They are always scanned, so listing them only
+    /// documents the project's setup.
+    Astro,
+}
+
+/// How reported issue locations print file paths.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum PathStyle {
+    /// Relative to the directory of the config file, e.g. `./src/app.tsx`.
+    #[default]
+    Relative,
+    /// Absolute, e.g. `/repo/src/app.tsx`.
+    Absolute,
 }
 
 pub const TEST_FILE_PATTERNS: &[&str] = &[
@@ -43,9 +100,187 @@ pub const TEST_FILE_PATTERNS: &[&str] = &[
     "**/*.spec.ts",
     "**/*.spec.jsx",
     "**/*.spec.js",
+    "**/*.stories.tsx",
+    "**/*.stories.ts",
+    "**/*.stories.jsx",
+    "**/*.stories.js",
     "**/__tests__/**",
 ];
 
+/// Casing convention for translation key segments.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyCase {
+    /// `submitButton`
+    Camel,
+    /// `submit_button`
+    Snake,
+    /// `submit-button`
+    Kebab,
+    /// `SubmitButton`
+    Pascal,
+}
+
+impl std::fmt::Display for KeyCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyCase::Camel => write!(f, "camelCase"),
+            KeyCase::Snake => write!(f, "snake_case"),
+            KeyCase::Kebab => write!(f, "kebab-case"),
+            KeyCase::Pascal => write!(f, "PascalCase"),
+        }
+    }
+}
+
+/// Naming convention enforced by the `key-naming` rule.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyNamingConfig {
+    /// Case every key segment must follow.
+    pub case: KeyCase,
+    /// Separator between key segments.
+    #[serde(default = "default_key_naming_separator")]
+    pub separator: String,
+}
+
+fn default_key_naming_separator() -> String {
+    ".".to_string()
+}
+
+/// Heuristics of the `hardcoded` rule for text that isn't real copy.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HardcodedConfig {
+    /// Minimum length of reported text, in characters (graphemes).
+    pub min_length: usize,
+    /// Regexes matched against the trimmed text; matching text is not reported.
+    pub ignore_patterns: Vec<String>,
+    /// JSX elements whose direct text children are not reported (e.g., `Code`).
+    pub ignore_components: Vec<String>,
+}
+
+impl Default for HardcodedConfig {
+    fn default() -> Self {
+        Self {
+            min_length: 1,
+            ignore_patterns: Vec::new(),
+            ignore_components: Vec::new(),
+        }
+    }
+}
+
+impl HardcodedConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Compiled `ignorePatterns`, failing on the first invalid regex.
+    pub fn ignore_regexes(&self) -> Result<Vec<Regex>> {
+        self.ignore_patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).with_context(|| {
+                    format!(
+                        "Invalid regex in 'hardcoded.ignorePatterns': \"{}\"",
+                        pattern
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// Values the `untranslated` rule expects to be identical across locales by
+/// default: numbers, percentages, URLs and emails.
+pub const DEFAULT_UNTRANSLATED_IGNORE_PATTERNS: &[&str] = &[
+    r"^[+-]?\d+([.,]\d+)*$",
+    r"^[+-]?\d+([.,]\d+)*\s?%$",
+    r"^(https?://|www\.)\S+$",
+    r"^[^\s@]+@[^\s@]+\.[^\s@]+$",
+];
+
+/// Options of the `untranslated` rule.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct UntranslatedConfig {
+    /// Regexes matched against the trimmed source value; matching values are
+    /// not reported. Replaces the defaults; `[]` disables them.
+    pub ignore_patterns: Vec<String>,
+}
+
+impl Default for UntranslatedConfig {
+    fn default() -> Self {
+        Self {
+            ignore_patterns: DEFAULT_UNTRANSLATED_IGNORE_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl UntranslatedConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Compiled `ignorePatterns`, failing on the first invalid regex.
+    pub fn ignore_regexes(&self) -> Result<Vec<Regex>> {
+        self.ignore_patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).with_context(|| {
+                    format!(
+                        "Invalid regex in 'untranslated.ignorePatterns': \"{}\"",
+                        pattern
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// Options of the `duplicate-value` rule.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DuplicateValueConfig {
+    /// Minimum number of keys sharing a value for the value to be reported.
+    pub min_keys: usize,
+}
+
+impl Default for DuplicateValueConfig {
+    fn default() -> Self {
+        Self { min_keys: 3 }
+    }
+}
+
+impl DuplicateValueConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Value of a rule in `severities`.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SeverityOverride {
+    Error,
+    Warning,
+    /// Don't report the rule at all, as if it was not selected.
+    Off,
+}
+
+impl SeverityOverride {
+    /// The overriding severity, or `None` when the rule is off.
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            SeverityOverride::Error => Some(Severity::Error),
+            SeverityOverride::Warning => Some(Severity::Warning),
+            SeverityOverride::Off => None,
+        }
+    }
+}
+
 /// Additional member-call pattern treated as a translation usage.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -120,12 +355,33 @@ impl TranslationMemberCallPattern {
     }
 }
 
+/// One project of a monorepo root config (`projects`).
+///
+/// Paths are relative to the project's `root`. Unset fields are taken from
+/// the root config.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectConfig {
+    /// Project directory, relative to the config file (e.g. `apps/web`).
+    pub root: String,
+    #[serde(
+        default,
+        alias = "messagesDir",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub messages_root: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_locale: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub includes: Option<Vec<String>>,
+}
+
 /// Raw config as deserialized from JSON. Framework-dependent fields use Option
 /// to distinguish "not set" from "explicitly set".
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct RawConfig {
-    #[serde(default)]
+    #[serde(default, alias = "library")]
     framework: Framework,
     #[serde(default)]
     ignores: Vec<String>,
@@ -133,11 +389,18 @@ struct RawConfig {
     #[serde(default = "default_checked_attributes")]
     checked_attributes: Vec<String>,
     #[serde(default)]
+    check_all_text_attributes: bool,
+    #[serde(default)]
     ignore_texts: Vec<String>,
     #[serde(alias = "messagesDir")]
     messages_root: Option<String>,
+    message_pattern: Option<String>,
     #[serde(default = "default_primary_locale")]
     primary_locale: String,
+    #[serde(default)]
+    fallback_locales: Vec<String>,
+    #[serde(default)]
+    locales: Vec<String>,
     source_root: Option<String>,
     #[serde(default = "default_ignore_test_files")]
     ignore_test_files: bool,
@@ -146,7 +409,26 @@ struct RawConfig {
     #[serde(default)]
     extra_translation_member_calls: Vec<TranslationMemberCallPattern>,
     #[serde(default)]
-    severities: BTreeMap<Rule, Severity>,
+    rules: Vec<String>,
+    #[serde(default)]
+    severities: BTreeMap<Rule, SeverityOverride>,
+    key_naming: Option<KeyNamingConfig>,
+    #[serde(default)]
+    hardcoded: HardcodedConfig,
+    #[serde(default)]
+    untranslated: UntranslatedConfig,
+    #[serde(default)]
+    duplicate_value: DuplicateValueConfig,
+    #[serde(default = "default_key_separator")]
+    key_separator: String,
+    #[serde(default)]
+    frameworks: Vec<UiFramework>,
+    #[serde(default)]
+    namespace_roots: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    projects: Vec<ProjectConfig>,
+    #[serde(default)]
+    path_style: PathStyle,
 }
 
 impl RawConfig {
@@ -158,24 +440,38 @@ impl RawConfig {
             ignores: self.ignores,
             includes: self.includes.unwrap_or_else(|| default_includes_for(fw)),
             checked_attributes: self.checked_attributes,
+            check_all_text_attributes: self.check_all_text_attributes,
             ignore_texts: self.ignore_texts,
             messages_root: self
                 .messages_root
                 .unwrap_or_else(|| default_messages_root_for(fw)),
+            message_pattern: self.message_pattern,
             primary_locale: self.primary_locale,
+            fallback_locales: self.fallback_locales,
+            locales: self.locales,
             source_root: self.source_root.unwrap_or_else(default_source_root),
             ignore_test_files: self.ignore_test_files,
             extra_translation_callees: self.extra_translation_callees,
             extra_translation_member_calls: self.extra_translation_member_calls,
+            rules: self.rules,
             severities: self.severities,
+            key_naming: self.key_naming,
+            hardcoded: self.hardcoded,
+            untranslated: self.untranslated,
+            duplicate_value: self.duplicate_value,
+            key_separator: self.key_separator,
+            frameworks: self.frameworks,
+            namespace_roots: self.namespace_roots,
+            projects: self.projects,
+            path_style: self.path_style,
         }
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
-    #[serde(default)]
+    #[serde(default, alias = "library")]
     pub framework: Framework,
     #[serde(default)]
     pub ignores: Vec<String>,
@@ -183,12 +479,36 @@ pub struct Config {
     pub includes: Vec<String>,
     #[serde(default = "default_checked_attributes")]
     pub checked_attributes: Vec<String>,
+    /// Also check every attribute in `TEXT_ATTRIBUTES`, in addition to
+    /// `checkedAttributes`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub check_all_text_attributes: bool,
     #[serde(default)]
     pub ignore_texts: Vec<String>,
     #[serde(default = "default_messages_root", alias = "messagesDir")]
     pub messages_root: String,
+    /// Glob for multiple files per locale (e.g. `locales/{locale}/*.json`).
+    ///
+    /// When set, it replaces `messagesRoot`. Each matched file's keys are
+    /// prefixed with the file's basename as namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_pattern: Option<String>,
     #[serde(default = "default_primary_locale")]
     pub primary_locale: String,
+    /// Locales that also count as a source, in lookup order after the primary
+    /// locale (e.g. `["en-US"]` next to `en`).
+    ///
+    /// A key defined in a fallback locale is not an orphan, and replicas are
+    /// compared with the first source locale that defines the key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_locales: Vec<String>,
+    /// Locales to load from the messages directory, next to the primary and
+    /// fallback locales.
+    ///
+    /// When empty, every message file whose name looks like a locale code is
+    /// loaded, and other files are skipped with a warning.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locales: Vec<String>,
     #[serde(default = "default_source_root")]
     pub source_root: String,
     #[serde(default = "default_ignore_test_files")]
@@ -197,9 +517,53 @@ pub struct Config {
     pub extra_translation_callees: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub extra_translation_member_calls: Vec<TranslationMemberCallPattern>,
-    /// Per-rule severity overrides. Defaults are defined by each rule.
+    /// Rules `glot check` runs when none are named on the command line;
+    /// the default set when empty.
+    ///
+    /// Names are validated by `validate()`, like check names on the command
+    /// line (`missing` or `missing-key`); see `selected_rules()`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<String>,
+    /// Per-rule severity overrides; `off` disables a rule. Defaults are defined by each rule.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severities: BTreeMap<Rule, SeverityOverride>,
+    /// Naming convention for keys in the primary locale; `key-naming` is off when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_naming: Option<KeyNamingConfig>,
+    /// Minimum length, ignore patterns and ignored components for hardcoded text.
+    #[serde(default, skip_serializing_if = "HardcodedConfig::is_default")]
+    pub hardcoded: HardcodedConfig,
+    /// Values `untranslated` expects to be identical across locales.
+    #[serde(default, skip_serializing_if = "UntranslatedConfig::is_default")]
+    pub untranslated: UntranslatedConfig,
+    /// Number of keys sharing a value from which `duplicate-value` reports it.
+    #[serde(default, skip_serializing_if = "DuplicateValueConfig::is_default")]
+    pub duplicate_value: DuplicateValueConfig,
+    /// Separator between the segments of a message key (`Common.submit`).
+    ///
+    /// Used to join namespaces and nested JSON keys, and to split keys and
+    /// `glot-message-keys` patterns into segments.
+    #[serde(
+        default = "default_key_separator",
+        skip_serializing_if = "is_default_key_separator"
+    )]
+    pub key_separator: String,
+    /// UI frameworks to support, e.g. `["vue"]` to scan `.vue` files.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub frameworks: Vec<UiFramework>,
+    /// Source paths where the keys of a top-level namespace are used, e.g.
+    /// `{"Dashboard": ["src/dashboard/**"]}`.
+    ///
+    /// A mapped namespace's key is unused unless a file under one of its roots
+    /// uses it. Patterns without wildcards are directories, like `includes`.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
-    pub severities: BTreeMap<Rule, Severity>,
+    pub namespace_roots: BTreeMap<String, Vec<String>>,
+    /// Projects of a monorepo, each checked on its own with its own messages.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectConfig>,
+    /// How reported issue locations print file paths.
+    #[serde(default, skip_serializing_if = "is_default_path_style")]
+    pub path_style: PathStyle,
 }
 
 fn default_includes() -> Vec<String> {
@@ -209,6 +573,7 @@ fn default_includes() -> Vec<String> {
 fn default_includes_for(framework: Framework) -> Vec<String> {
     match framework {
         Framework::ReactI18next => vec!["src/components".to_string()],
+        Framework::ReactIntl => vec!["src".to_string()],
         Framework::NextIntl => {
             let root_dirs = ["src", ""];
             let sub_dirs = ["app/[locale]", "components"];
@@ -229,6 +594,42 @@ fn default_includes_for(framework: Framework) -> Vec<String> {
     }
 }
 
+/// Attributes whose values are shown to users, checked with
+/// `checkAllTextAttributes` even when missing from `checkedAttributes`.
+pub const TEXT_ATTRIBUTES: &[&str] = &[
+    "alt",
+    "title",
+    "aria-label",
+    "aria-placeholder",
+    "placeholder",
+    "label",
+];
+
+/// Whether attribute `name` matches one of the `checkedAttributes` patterns.
+///
+/// A pattern is an attribute name in which `*` matches any run of
+/// characters: `aria-*` matches every ARIA attribute and `*` matches all
+/// attributes. Names without `*` match exactly.
+pub fn matches_attribute(patterns: &[String], name: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        let Some((first, rest)) = pattern.split_once('*') else {
+            return pattern == name;
+        };
+        let Some(mut remaining) = name.strip_prefix(first) else {
+            return false;
+        };
+        let mut parts: Vec<&str> = rest.split('*').collect();
+        let last = parts.pop().unwrap_or_default();
+        for part in parts {
+            match remaining.find(part) {
+                Some(idx) => remaining = &remaining[idx + part.len()..],
+                None => return false,
+            }
+        }
+        remaining.ends_with(last)
+    })
+}
+
 fn default_checked_attributes() -> Vec<String> {
     [
         "placeholder",
@@ -252,6 +653,7 @@ fn default_messages_root_for(framework: Framework) -> String {
     match framework {
         Framework::ReactI18next => "./src/locales".to_string(),
         Framework::NextIntl => "./messages".to_string(),
+        Framework::ReactIntl => "./lang".to_string(),
     }
 }
 
@@ -267,6 +669,22 @@ fn default_ignore_test_files() -> bool {
     true
 }
 
+fn default_key_separator() -> String {
+    ".".to_string()
+}
+
+fn is_default_key_separator(separator: &str) -> bool {
+    separator == "."
+}
+
+fn is_default_path_style(style: &PathStyle) -> bool {
+    *style == PathStyle::default()
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self::for_framework(Framework::default())
@@ -281,17 +699,88 @@ impl Config {
             ignores: Vec::new(),
             includes: default_includes_for(framework),
             checked_attributes: default_checked_attributes(),
+            check_all_text_attributes: false,
             ignore_texts: Vec::new(),
             messages_root: default_messages_root_for(framework),
+            message_pattern: None,
             primary_locale: default_primary_locale(),
+            fallback_locales: Vec::new(),
+            locales: Vec::new(),
             source_root: default_source_root(),
             ignore_test_files: default_ignore_test_files(),
             extra_translation_callees: Vec::new(),
             extra_translation_member_calls: Vec::new(),
+            rules: Vec::new(),
             severities: BTreeMap::new(),
+            key_naming: None,
+            hardcoded: HardcodedConfig::default(),
+            untranslated: UntranslatedConfig::default(),
+            duplicate_value: DuplicateValueConfig::default(),
+            key_separator: default_key_separator(),
+            frameworks: Vec::new(),
+            namespace_roots: BTreeMap::new(),
+            projects: Vec::new(),
+            path_style: PathStyle::default(),
         }
     }
 
+    /// Config of one of `projects`: this config with the project's own
+    /// messages, primary locale and includes, and no projects.
+    pub fn for_project(&self, project: &ProjectConfig) -> Config {
+        let mut config = self.clone();
+        if let Some(messages_root) = &project.messages_root {
+            config.messages_root = messages_root.clone();
+        }
+        if let Some(primary_locale) = &project.primary_locale {
+            config.primary_locale = primary_locale.clone();
+        }
+        if let Some(includes) = &project.includes {
+            config.includes = includes.clone();
+        }
+        config.projects = Vec::new();
+        config
+    }
+
+    /// Attributes checked for hardcoded text: `checkedAttributes`, plus
+    /// `TEXT_ATTRIBUTES` when `checkAllTextAttributes` is set.
+    pub fn text_attributes(&self) -> Vec<String> {
+        let mut attributes = self.checked_attributes.clone();
+        if self.check_all_text_attributes {
+            for attribute in TEXT_ATTRIBUTES {
+                if !attributes.iter().any(|a| a == attribute) {
+                    attributes.push(attribute.to_string());
+                }
+            }
+        }
+        attributes
+    }
+
+    /// Whether the keys of `namespace` may be used in `file_path`.
+    ///
+    /// `file_path` is relative to the source root. Namespaces without
+    /// `namespaceRoots` may be used anywhere.
+    pub fn namespace_allows_path(&self, namespace: &str, file_path: &str) -> bool {
+        let Some(roots) = self.namespace_roots.get(namespace) else {
+            return true;
+        };
+        roots.iter().any(|root| {
+            if root.contains('*') || root.contains('?') {
+                Pattern::new(root).is_ok_and(|pattern| pattern.matches(file_path))
+            } else {
+                let root = root.trim_end_matches('/');
+                let root = root.strip_prefix("./").unwrap_or(root);
+                file_path
+                    .strip_prefix(root)
+                    .is_some_and(|rest| rest.starts_with('/'))
+            }
+        })
+    }
+
+    /// Whether `.vue` single-file components are scanned.
+    pub fn vue_enabled(&self) -> bool {
+        self.frameworks.contains(&UiFramework::Vue)
+    }
+
     /// Validate configuration values.
     ///
     /// Returns an error if any glob patterns in `ignores` or `includes` are invalid.
@@ -313,6 +802,19 @@ impl Config {
             }
         }
 
+        if let Some(pattern) = &self.message_pattern {
+            if !pattern.contains(LOCALE_PLACEHOLDER) {
+                return Err(anyhow::anyhow!(
+                    "Invalid 'messagePattern': \"{}\" must contain '{}'",
+                    pattern,
+                    LOCALE_PLACEHOLDER
+                ));
+            }
+            Pattern::new(&pattern.replace(LOCALE_PLACEHOLDER, "*")).with_context(|| {
+                format!("Invalid glob pattern in 'messagePattern': \"{}\"", pattern)
+            })?;
+        }
+
         for callee in &self.extra_translation_callees {
             if callee.trim().is_empty() {
                 return Err(anyhow::anyhow!(
@@ -325,18 +827,127 @@ impl Config {
             pattern.validate()?;
         }
 
+        if let Some(key_naming) = &self.key_naming
+            && key_naming.separator.is_empty()
+        {
+            return Err(anyhow::anyhow!(
+                "Invalid 'keyNaming': 'separator' must not be empty"
+            ));
+        }
+
+        self.hardcoded.ignore_regexes()?;
+        self.untranslated.ignore_regexes()?;
+
+        if self.duplicate_value.min_keys < 2 {
+            return Err(anyhow::anyhow!(
+                "Invalid 'duplicateValue': 'minKeys' must be at least 2"
+            ));
+        }
+
+        for locale in &self.fallback_locales {
+            if locale.trim().is_empty() || *locale == self.primary_locale {
+                return Err(anyhow::anyhow!(
+                    "Invalid value in 'fallbackLocales': \"{}\" must be a locale other than 'primaryLocale'",
+                    locale
+                ));
+            }
+        }
+
+        if self.locales.iter().any(|locale| locale.trim().is_empty()) {
+            return Err(anyhow::anyhow!(
+                "Invalid value in 'locales': entries must not be empty"
+            ));
+        }
+
+        if self.key_separator.is_empty() {
+            return Err(anyhow::anyhow!("Invalid 'keySeparator': must not be empty"));
+        }
+
+        for (namespace, roots) in &self.namespace_roots {
+            for pattern in roots {
+                Pattern::new(pattern).with_context(|| {
+                    format!(
+                        "Invalid glob pattern in 'namespaceRoots.{}': \"{}\"",
+                        namespace, pattern
+                    )
+                })?;
+            }
+        }
+
+        for (idx, project) in self.projects.iter().enumerate() {
+            if project.root.trim().is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Invalid 'projects[{}]': 'root' must not be empty",
+                    idx
+                ));
+            }
+            if self.projects[..idx].iter().any(|p| p.root == project.root) {
+                return Err(anyhow::anyhow!(
+                    "Invalid 'projects[{}]': root \"{}\" is listed twice",
+                    idx,
+                    project.root
+                ));
+            }
+            for pattern in project.includes.iter().flatten() {
+                if pattern.contains('*') || pattern.contains('?') {
+                    Pattern::new(pattern).with_context(|| {
+                        format!(
+                            "Invalid glob pattern in 'projects[{}].includes': \"{}\"",
+                            idx, pattern
+                        )
+                    })?;
+                }
+            }
+        }
+
         if self.severities.contains_key(&Rule::ParseError) {
             return Err(anyhow::anyhow!(
                 "Invalid rule in 'severities': 'parse-error' cannot be configured"
             ));
         }
 
+        for name in &self.rules {
+            match Rule::parse(name) {
+                None => {
+                    return Err(anyhow::anyhow!(
+                        "Invalid rule in 'rules': \"{}\" is not a rule",
+                        name
+                    ));
+                }
+                Some(Rule::ParseError) => {
+                    return Err(anyhow::anyhow!(
+                        "Invalid rule in 'rules': 'parse-error' is always reported"
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+
         Ok(())
     }
 
+    /// Rules listed in `rules`, skipping names that aren't rules.
+    pub fn selected_rules(&self) -> Vec<Rule> {
+        self.rules
+            .iter()
+            .filter_map(|name| Rule::parse(name))
+            .collect()
+    }
+
     /// Return the effective severity for a rule after applying config overrides.
+    ///
+    /// Rules set to `off` keep their default here; their issues are dropped
+    /// before severities are looked up (see `is_rule_off`).
     pub fn severity_for_rule(&self, rule: Rule, default: Severity) -> Severity {
-        self.severities.get(&rule).copied().unwrap_or(default)
+        self.severities
+            .get(&rule)
+            .and_then(|o| o.severity())
+            .unwrap_or(default)
+    }
+
+    /// Whether a rule is disabled with `"off"` in `severities`.
+    pub fn is_rule_off(&self, rule: Rule) -> bool {
+        self.severities.get(&rule) == Some(&SeverityOverride::Off)
     }
 }
 
@@ -349,9 +960,12 @@ pub fn find_config_file(start_dir: &Path) -> Option<PathBuf> {
     let mut current = start_dir.to_path_buf();
 
     loop {
-        let config_path = current.join(CONFIG_FILE_NAME);
-        if config_path.exists() {
-            return Some(config_path);
+        let found = CONFIG_FILE_NAMES
+            .iter()
+            .map(|name| current.join(name))
+            .find(|path| path.exists());
+        if found.is_some() {
+            return found;
         }
         if current.join(".git").exists() {
             return None;
@@ -367,24 +981,28 @@ pub struct ConfigLoadResult {
     pub config: Config,
     /// True if config was loaded from a file, false if using defaults.
     pub from_file: bool,
+    /// Path of the loaded config file.
+    pub path: Option<PathBuf>,
 }
 
 pub fn load_config(start_dir: &Path) -> Result<ConfigLoadResult> {
     match find_config_file(start_dir) {
         Some(path) => {
             let content = fs::read_to_string(&path)?;
-            let raw: RawConfig = serde_json::from_str(&content)
+            let raw: RawConfig = serde_json::from_str(&strip_jsonc(&content))
                 .with_context(|| format!("Failed to parse config file: {:?}", path))?;
             let config = raw.into_config();
             config.validate()?;
             Ok(ConfigLoadResult {
                 config,
                 from_file: true,
+                path: Some(path),
             })
         }
         None => Ok(ConfigLoadResult {
             config: Config::default(),
             from_file: false,
+            path: None,
         }),
     }
 }
@@ -417,6 +1035,55 @@ mod tests {
         assert_eq!(config.checked_attributes, vec!["placeholder"]);
     }
 
+    #[test]
+    fn test_matches_attribute() {
+        let patterns = |list: &[&str]| list.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+
+        let exact = patterns(&["title", "aria-label"]);
+        assert!(matches_attribute(&exact, "title"));
+        assert!(matches_attribute(&exact, "aria-label"));
+        assert!(!matches_attribute(&exact, "aria-labelledby"));
+        assert!(!matches_attribute(&exact, "subtitle"));
+
+        let families = patterns(&["aria-*", "*-text", "data-*-label"]);
+        assert!(matches_attribute(&families, "aria-label"));
+        assert!(matches_attribute(&families, "aria-"));
+        assert!(!matches_attribute(&families, "data-aria"));
+        assert!(matches_attribute(&families, "help-text"));
+        assert!(!matches_attribute(&families, "text-color"));
+        assert!(matches_attribute(&families, "data-step-label"));
+        assert!(!matches_attribute(&families, "data-label"));
+
+        let all = patterns(&["*"]);
+        assert!(matches_attribute(&all, "className"));
+        assert!(matches_attribute(&all, "data-testid"));
+        assert!(!matches_attribute(&[], "title"));
+    }
+
+    #[test]
+    fn test_text_attributes() {
+        let json = r#"{"checkedAttributes": ["placeholder", "data-tooltip"]}"#;
+        let mut config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.text_attributes(),
+            vec!["placeholder", "data-tooltip"]
+        );
+
+        config.check_all_text_attributes = true;
+        assert_eq!(
+            config.text_attributes(),
+            vec![
+                "placeholder",
+                "data-tooltip",
+                "alt",
+                "title",
+                "aria-label",
+                "aria-placeholder",
+                "label"
+            ]
+        );
+    }
+
     #[test]
     fn test_find_config_file() {
         let dir = tempdir().unwrap();
@@ -431,6 +1098,47 @@ mod tests {
         assert_eq!(found.unwrap(), config_path);
     }
 
+    #[test]
+    fn test_find_config_file_precedence() {
+        let dir = tempdir().unwrap();
+        let sub_dir = dir.path().join("app");
+        fs::create_dir_all(&sub_dir).unwrap();
+
+        // Within a directory, glot.config.json wins
+        File::create(dir.path().join(CONFIG_FILE_NAME)).unwrap();
+        File::create(dir.path().join("glot.config.json")).unwrap();
+        assert_eq!(
+            find_config_file(&sub_dir),
+            Some(dir.path().join("glot.config.json"))
+        );
+
+        // The nearest directory wins over the file name
+        File::create(sub_dir.join(CONFIG_FILE_NAME)).unwrap();
+        assert_eq!(
+            find_config_file(&sub_dir),
+            Some(sub_dir.join(CONFIG_FILE_NAME))
+        );
+    }
+
+    #[test]
+    fn test_load_config_with_comments_and_trailing_commas() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("glot.config.json"),
+            r#"{
+                // Only scan the app sources
+                "includes": ["src/**", /* "scripts/**" */],
+                "primaryLocale": "de",
+            }"#,
+        )
+        .unwrap();
+
+        let result = load_config(dir.path()).unwrap();
+        assert!(result.from_file);
+        assert_eq!(result.config.includes, vec!["src/**"]);
+        assert_eq!(result.config.primary_locale, "de");
+    }
+
     #[test]
     fn test_find_config_not_found() {
         let dir = tempdir().unwrap();
@@ -515,6 +1223,144 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_validate_message_pattern_requires_locale() {
+        let config = Config {
+            message_pattern: Some("locales/*.json".to_string()),
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("messagePattern"));
+
+        let config = Config {
+            message_pattern: Some("locales/{locale}/*.json".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_parse_key_naming() {
+        let json = r#"{ "keyNaming": { "case": "snake" } }"#;
+        let raw: RawConfig = serde_json::from_str(json).unwrap();
+        let config = raw.into_config();
+        assert_eq!(
+            config.key_naming,
+            Some(KeyNamingConfig {
+                case: KeyCase::Snake,
+                separator: ".".to_string(),
+            })
+        );
+
+        let json = r#"{ "keyNaming": { "case": "screaming" } }"#;
+        assert!(serde_json::from_str::<RawConfig>(json).is_err());
+    }
+
+    #[test]
+    fn test_parse_hardcoded_config() {
+        let json = r#"{ "hardcoded": { "minLength": 2, "ignorePatterns": ["^[\\d\\s\\p{P}]+$"], "ignoreComponents": ["Code"] } }"#;
+        let raw: RawConfig = serde_json::from_str(json).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.hardcoded.min_length, 2);
+        assert_eq!(config.hardcoded.ignore_components, vec!["Code"]);
+        assert!(config.validate().is_ok());
+        assert!(config.hardcoded.ignore_regexes().unwrap()[0].is_match("1, 2"));
+
+        let raw: RawConfig = serde_json::from_str(r#"{ "hardcoded": {} }"#).unwrap();
+        assert_eq!(raw.into_config().hardcoded, HardcodedConfig::default());
+
+        let config = Config {
+            hardcoded: HardcodedConfig {
+                ignore_patterns: vec!["(".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("hardcoded.ignorePatterns")
+        );
+    }
+
+    #[test]
+    fn test_parse_path_style() {
+        let raw: RawConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(raw.into_config().path_style, PathStyle::Relative);
+
+        let raw: RawConfig = serde_json::from_str(r#"{ "pathStyle": "absolute" }"#).unwrap();
+        assert_eq!(raw.into_config().path_style, PathStyle::Absolute);
+
+        assert!(serde_json::from_str::<RawConfig>(r#"{ "pathStyle": "short" }"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_untranslated_config() {
+        let raw: RawConfig = serde_json::from_str("{}").unwrap();
+        let regexes = raw.into_config().untranslated.ignore_regexes().unwrap();
+        let ignored = |value: &str| regexes.iter().any(|regex| regex.is_match(value));
+        assert!(ignored("100"));
+        assert!(ignored("3.14"));
+        assert!(ignored("100%"));
+        assert!(ignored("https://example.com/docs"));
+        assert!(ignored("support@example.com"));
+        assert!(!ignored("Submit"));
+        assert!(!ignored("v2.0"));
+
+        let raw: RawConfig = serde_json::from_str(
+            r#"{ "untranslated": { "ignorePatterns": ["^v\\d+(\\.\\d+)*$"] } }"#,
+        )
+        .unwrap();
+        let config = raw.into_config();
+        assert!(config.validate().is_ok());
+        let regexes = config.untranslated.ignore_regexes().unwrap();
+        assert_eq!(regexes.len(), 1);
+        assert!(regexes[0].is_match("v2.0"));
+
+        let raw: RawConfig =
+            serde_json::from_str(r#"{ "untranslated": { "ignorePatterns": [] } }"#).unwrap();
+        assert!(raw.into_config().untranslated.ignore_patterns.is_empty());
+
+        let config = Config {
+            untranslated: UntranslatedConfig {
+                ignore_patterns: vec!["(".to_string()],
+            },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("untranslated.ignorePatterns")
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_value_config() {
+        let raw: RawConfig =
+            serde_json::from_str(r#"{ "duplicateValue": { "minKeys": 5 } }"#).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.duplicate_value.min_keys, 5);
+        assert!(config.validate().is_ok());
+
+        let raw: RawConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(raw.into_config().duplicate_value.min_keys, 3);
+
+        let config = Config {
+            duplicate_value: DuplicateValueConfig { min_keys: 1 },
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("minKeys"));
+    }
+
     #[test]
     fn test_load_config_with_invalid_pattern_fails() {
         let dir = tempdir().unwrap();
@@ -540,6 +1386,35 @@ mod tests {
         assert_eq!(config.messages_root, "./i18n");
     }
 
+    #[test]
+    fn test_projects_override_root_config() {
+        let json = r#"{
+            "primaryLocale": "en",
+            "ignoreTexts": ["N/A"],
+            "projects": [
+                { "root": "apps/web", "messagesDir": "./messages" },
+                { "root": "apps/admin", "primaryLocale": "de", "includes": ["src"] }
+            ]
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert!(config.validate().is_ok());
+
+        let web = config.for_project(&config.projects[0]);
+        assert_eq!(web.messages_root, "./messages");
+        assert_eq!(web.primary_locale, "en");
+        assert_eq!(web.ignore_texts, vec!["N/A"]);
+        assert!(web.projects.is_empty());
+
+        let admin = config.for_project(&config.projects[1]);
+        assert_eq!(admin.primary_locale, "de");
+        assert_eq!(admin.includes, vec!["src"]);
+
+        let json = r#"{"projects": [{ "root": "apps/web" }, { "root": "apps/web" }]}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("listed twice"), "{}", err);
+    }
+
     #[test]
     fn test_source_root_default() {
         let config = Config::default();
@@ -559,16 +1434,57 @@ mod tests {
 
         assert_eq!(
             config.severities.get(&Rule::Untranslated),
-            Some(&Severity::Warning)
+            Some(&SeverityOverride::Warning)
         );
         assert_eq!(
             config.severities.get(&Rule::UnusedKey),
-            Some(&Severity::Error)
+            Some(&SeverityOverride::Error)
         );
         assert_eq!(
             config.severities.get(&Rule::MissingKey),
-            Some(&Severity::Warning)
+            Some(&SeverityOverride::Warning)
+        );
+    }
+
+    #[test]
+    fn test_severity_off_disables_rule() {
+        let json = r#"{ "severities": { "untranslated": "off", "unused": "error" } }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        assert!(config.is_rule_off(Rule::Untranslated));
+        assert!(!config.is_rule_off(Rule::UnusedKey));
+        assert_eq!(
+            config.severity_for_rule(Rule::UnusedKey, Severity::Warning),
+            Severity::Error
+        );
+        assert_eq!(
+            config.severity_for_rule(Rule::Untranslated, Severity::Error),
+            Severity::Error
+        );
+    }
+
+    #[test]
+    fn test_parse_rules() {
+        let raw: RawConfig =
+            serde_json::from_str(r#"{ "rules": ["hardcoded", "missing-key", "orphan"] }"#).unwrap();
+        let config = raw.into_config();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.selected_rules(),
+            vec![Rule::HardcodedText, Rule::MissingKey, Rule::OrphanKey]
         );
+
+        for (rules, message) in [
+            (vec!["hardcode"], "\"hardcode\" is not a rule"),
+            (vec!["parse-error"], "'parse-error' is always reported"),
+        ] {
+            let config = Config {
+                rules: rules.into_iter().map(str::to_string).collect(),
+                ..Default::default()
+            };
+            let err = config.validate().unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
     }
 
     #[test]
@@ -647,6 +1563,67 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("objectName"));
     }
 
+    #[test]
+    fn test_validate_fallback_locales() {
+        let raw: RawConfig =
+            serde_json::from_str(r#"{ "fallbackLocales": ["en-US", "en-GB"] }"#).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.fallback_locales, vec!["en-US", "en-GB"]);
+        assert!(config.validate().is_ok());
+
+        let config = Config {
+            fallback_locales: vec!["en".to_string()],
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("fallbackLocales"));
+    }
+
+    #[test]
+    fn test_validate_locales() {
+        let raw: RawConfig = serde_json::from_str(r#"{ "locales": ["de", "fr"] }"#).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.locales, vec!["de", "fr"]);
+        assert!(config.validate().is_ok());
+
+        let config = Config {
+            locales: vec![" ".to_string()],
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.unwrap_err().to_string().contains("'locales'"));
+    }
+
+    #[test]
+    fn test_namespace_roots() {
+        let raw: RawConfig = serde_json::from_str(
+            r#"{ "namespaceRoots": { "Dashboard": ["src/dashboard/**", "./src/shared/"] } }"#,
+        )
+        .unwrap();
+        let config = raw.into_config();
+        assert!(config.validate().is_ok());
+
+        assert!(config.namespace_allows_path("Dashboard", "src/dashboard/page.tsx"));
+        assert!(config.namespace_allows_path("Dashboard", "src/dashboard/a/b.tsx"));
+        assert!(config.namespace_allows_path("Dashboard", "src/shared/nav.tsx"));
+        assert!(!config.namespace_allows_path("Dashboard", "src/settings/page.tsx"));
+        assert!(!config.namespace_allows_path("Dashboard", "src/shared-old/nav.tsx"));
+        assert!(config.namespace_allows_path("Common", "src/settings/page.tsx"));
+
+        let config = Config {
+            namespace_roots: [("Dashboard".to_string(), vec!["src/[".to_string()])].into(),
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("'namespaceRoots.Dashboard'")
+        );
+    }
+
     #[test]
     fn test_serialization_uses_new_names() {
         let config = Config::default();
@@ -669,6 +1646,9 @@ mod tests {
 
         let json = serde_json::to_string(&Framework::NextIntl).unwrap();
         assert_eq!(json, r#""next-intl""#);
+
+        let json = serde_json::to_string(&Framework::ReactIntl).unwrap();
+        assert_eq!(json, r#""react-intl""#);
     }
 
     #[test]
@@ -678,6 +1658,9 @@ mod tests {
 
         let fw: Framework = serde_json::from_str(r#""next-intl""#).unwrap();
         assert_eq!(fw, Framework::NextIntl);
+
+        let fw: Framework = serde_json::from_str(r#""react-intl""#).unwrap();
+        assert_eq!(fw, Framework::ReactIntl);
     }
 
     #[test]
@@ -701,6 +1684,29 @@ mod tests {
         assert_eq!(config.messages_root, "./messages");
     }
 
+    #[test]
+    fn test_config_for_framework_react_intl() {
+        let config = Config::for_framework(Framework::ReactIntl);
+        assert_eq!(config.framework, Framework::ReactIntl);
+        assert_eq!(config.includes, vec!["src"]);
+        assert_eq!(config.messages_root, "./lang");
+    }
+
+    #[test]
+    fn test_frameworks_vue() {
+        let raw: RawConfig = serde_json::from_str(r#"{ "frameworks": ["vue"] }"#).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.frameworks, vec![UiFramework::Vue]);
+        assert!(config.vue_enabled());
+        assert!(!Config::default().vue_enabled());
+        assert!(serde_json::from_str::<RawConfig>(r#"{ "frameworks": ["svelte"] }"#).is_err());
+
+        let raw: RawConfig = serde_json::from_str(r#"{ "frameworks": ["astro"] }"#).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.frameworks, vec![UiFramework::Astro]);
+        assert!(!config.vue_enabled());
+    }
+
     #[test]
     fn test_no_framework_field_defaults_to_next_intl() {
         // Backward compatibility: existing configs without framework field
@@ -733,6 +1739,15 @@ mod tests {
         assert_eq!(config.messages_root, "./custom");
     }
 
+    #[test]
+    fn test_library_alias_for_framework() {
+        let json = r#"{ "library": "react-i18next" }"#;
+        let raw: RawConfig = serde_json::from_str(json).unwrap();
+        let config = raw.into_config();
+        assert_eq!(config.framework, Framework::ReactI18next);
+        assert_eq!(config.messages_root, "./src/locales");
+    }
+
     #[test]
     fn test_load_config_with_framework_react_i18next() {
         let dir = tempdir().unwrap();
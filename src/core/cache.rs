@@ -0,0 +1,290 @@
+//! Persistent parse cache used by `--cache`.
+//!
+//! The cache maps each source file to the hash of its content plus the data
+//! produced for it by Phase 1 (registries and comments) and Phase 2+3
+//! (resolved key usages and hardcoded text). Unchanged files skip parsing.
+//!
+//! ## Invalidation
+//!
+//! - **Phase 1** output depends only on the file itself, so it is reused
+//!   whenever the content hash matches.
+//! - **Phase 2+3** output also depends on cross-file registries, the config
+//!   and the primary locale keys. These are summarized in a single
+//!   fingerprint; when it changes, every file is re-analyzed (unchanged files
+//!   still reuse their Phase 1 output).
+//!
+//! A missing, unreadable or incompatible cache file is treated as empty, so
+//! the worst case is a full rescan.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    config::Config,
+    core::{collect::CollectedFile, context::FileResolvedData},
+};
+
+/// Default cache file name, created in the source root.
+pub const DEFAULT_CACHE_FILE: &str = ".glotcache";
+
+/// Cached data for a single source file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Hash of the file content when the entry was written.
+    pub content_hash: u64,
+    /// Phase 1 output for the file.
+    pub collected: CollectedFile,
+    /// Phase 2+3 output for the file, valid for the cache fingerprint.
+    pub resolved: FileResolvedData,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// glot version that wrote the cache; other versions are ignored.
+    version: String,
+    /// Fingerprint of everything Phase 2+3 depends on besides the file itself.
+    fingerprint: u64,
+    entries: HashMap<String, CacheEntry>,
+}
+
+/// On-disk cache of per-file analysis results.
+pub struct SourceCache {
+    path: PathBuf,
+    data: CacheFile,
+}
+
+impl SourceCache {
+    /// Load the cache from `path`, falling back to an empty cache on any error.
+    pub fn load(path: &Path, verbose: bool) -> Self {
+        let data = match fs::read_to_string(path) {
+            Ok(content) => match serde_json::from_str::<CacheFile>(&content) {
+                Ok(data) if data.version == env!("CARGO_PKG_VERSION") => data,
+                Ok(_) => CacheFile::default(),
+                Err(e) => {
+                    if verbose {
+                        eprintln!("Warning: ignoring corrupt cache {} - {}", path.display(), e);
+                    }
+                    CacheFile::default()
+                }
+            },
+            Err(_) => CacheFile::default(),
+        };
+
+        Self {
+            path: path.to_path_buf(),
+            data,
+        }
+    }
+
+    /// Cached Phase 1 output for a file, if its content is unchanged.
+    pub fn collected(&self, file_path: &str, content_hash: u64) -> Option<&CollectedFile> {
+        self.fresh_entry(file_path, content_hash)
+            .map(|entry| &entry.collected)
+    }
+
+    /// Cached Phase 2+3 output for a file, if neither it nor the fingerprint changed.
+    pub fn resolved(
+        &self,
+        file_path: &str,
+        content_hash: u64,
+        fingerprint: u64,
+    ) -> Option<&FileResolvedData> {
+        if self.data.fingerprint != fingerprint {
+            return None;
+        }
+        self.fresh_entry(file_path, content_hash)
+            .map(|entry| &entry.resolved)
+    }
+
+    /// Cached data for a file, whatever its current content.
+    pub fn entry(&self, file_path: &str) -> Option<&CacheEntry> {
+        self.data.entries.get(file_path)
+    }
+
+    /// Replace all entries and write the cache to disk.
+    ///
+    /// Files that are no longer scanned are dropped from the cache. The new
+    /// entries are kept in memory even if writing fails.
+    pub fn save(&mut self, fingerprint: u64, entries: HashMap<String, CacheEntry>) -> Result<()> {
+        self.data = CacheFile {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            fingerprint,
+            entries,
+        };
+        let content =
+            serde_json::to_string(&self.data).with_context(|| "Failed to serialize cache")?;
+        fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write cache: {}", self.path.display()))
+    }
+
+    fn fresh_entry(&self, file_path: &str, content_hash: u64) -> Option<&CacheEntry> {
+        self.data
+            .entries
+            .get(file_path)
+            .filter(|entry| entry.content_hash == content_hash)
+    }
+}
+
+/// Hash a file's content.
+pub fn content_hash(content: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Compute the fingerprint of the inputs shared by every file in Phase 2+3.
+///
+/// Comments are excluded from each file's Phase 1 output because they only
+/// affect the file they belong to, which is already covered by its content hash.
+pub fn fingerprint<'a>(
+    config: &Config,
+    available_keys: &HashSet<String>,
+    collected: impl IntoIterator<Item = (&'a str, &'a CollectedFile)>,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    if let Ok(config) = serde_json::to_value(config) {
+        hash_canonical(&config, &mut hasher);
+    }
+
+    let mut keys: Vec<&String> = available_keys.iter().collect();
+    keys.sort();
+    keys.hash(&mut hasher);
+
+    let mut files: Vec<(&str, &CollectedFile)> = collected.into_iter().collect();
+    files.sort_by_key(|(path, _)| *path);
+    for (path, file) in files {
+        path.hash(&mut hasher);
+        if let Ok(mut value) = serde_json::to_value(file) {
+            if let Value::Object(map) = &mut value {
+                map.shift_remove("comments");
+            }
+            hash_canonical(&value, &mut hasher);
+        }
+    }
+
+    hasher.finish()
+}
+
+/// Hash a JSON value with object keys sorted, so `HashMap` ordering doesn't matter.
+fn hash_canonical(value: &Value, hasher: &mut DefaultHasher) {
+    match value {
+        Value::Null => 0u8.hash(hasher),
+        Value::Bool(b) => (1u8, b).hash(hasher),
+        Value::Number(n) => (2u8, n.to_string()).hash(hasher),
+        Value::String(s) => (3u8, s).hash(hasher),
+        Value::Array(items) => {
+            (4u8, items.len()).hash(hasher);
+            for item in items {
+                hash_canonical(item, hasher);
+            }
+        }
+        Value::Object(map) => {
+            (5u8, map.len()).hash(hasher);
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by_key(|(key, _)| *key);
+            for (key, value) in entries {
+                key.hash(hasher);
+                hash_canonical(value, hasher);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::collect::ImportInfo;
+
+    fn collected_with_import(module_path: &str) -> CollectedFile {
+        CollectedFile {
+            imports: vec![ImportInfo {
+                local_name: "keys".to_string(),
+                imported_name: "keys".to_string(),
+                module_path: module_path.to_string(),
+            }],
+            ..CollectedFile::default()
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_file_order() {
+        let a = collected_with_import("./a");
+        let b = collected_with_import("./b");
+        let config = Config::default();
+        let keys = HashSet::from(["Common.submit".to_string()]);
+
+        assert_eq!(
+            fingerprint(&config, &keys, [("a.tsx", &a), ("b.tsx", &b)]),
+            fingerprint(&config, &keys, [("b.tsx", &b), ("a.tsx", &a)])
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_registry_data() {
+        let config = Config::default();
+        let keys = HashSet::new();
+        let before = collected_with_import("./a");
+        let after = collected_with_import("./b");
+
+        assert_ne!(
+            fingerprint(&config, &keys, [("app.tsx", &before)]),
+            fingerprint(&config, &keys, [("app.tsx", &after)])
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_comments() {
+        let config = Config::default();
+        let keys = HashSet::new();
+        let plain = CollectedFile::default();
+        let mut commented = CollectedFile::default();
+        commented.comments.declarations.comment_lines.insert(3);
+
+        assert_eq!(
+            fingerprint(&config, &keys, [("app.tsx", &plain)]),
+            fingerprint(&config, &keys, [("app.tsx", &commented)])
+        );
+    }
+
+    #[test]
+    fn test_load_corrupt_cache_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_CACHE_FILE);
+        fs::write(&path, "{ not json").unwrap();
+
+        let cache = SourceCache::load(&path, false);
+        assert!(cache.collected("app.tsx", 0).is_none());
+    }
+
+    #[test]
+    fn test_save_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_CACHE_FILE);
+        let hash = content_hash("export const a = 1;");
+
+        let entries = HashMap::from([(
+            "app.tsx".to_string(),
+            CacheEntry {
+                content_hash: hash,
+                collected: collected_with_import("./a"),
+                resolved: FileResolvedData::default(),
+            },
+        )]);
+        SourceCache::load(&path, false).save(42, entries).unwrap();
+
+        let cache = SourceCache::load(&path, false);
+        assert!(cache.collected("app.tsx", hash).is_some());
+        assert!(cache.collected("app.tsx", hash + 1).is_none());
+        assert!(cache.resolved("app.tsx", hash, 42).is_some());
+        assert!(cache.resolved("app.tsx", hash, 7).is_none());
+    }
+}
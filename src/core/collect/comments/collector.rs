@@ -24,16 +24,17 @@
 //! {t(`${key}`)}  // <- Directive does NOT apply (blank line breaks chain)
 //! ```
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 /// Maximum number of consecutive comment lines to traverse when looking for
 /// the target code line or searching backwards for declarations.
 pub const MAX_COMMENT_CHAIN_LINES: usize = 10;
-use swc_common::SourceMap;
+use swc_common::{BytePos, SourceMap};
 
 use crate::core::collect::comments::directive::Directive;
 use crate::core::collect::types::{
-    Declarations, DisabledRange, FileComments, SuppressibleRule, Suppressions,
+    Declarations, DisabledRange, FileComments, NextLineDirective, SuppressibleRule, Suppressions,
+    UnpairedDirective, UnpairedDirectiveKind,
 };
 use crate::core::parsers::jsx::ExtractedComments;
 
@@ -44,7 +45,8 @@ impl CommentCollector {
     /// Collect all glot comments from a file in a single pass.
     ///
     /// This performs a single traversal of SWC's parsed comments to extract:
-    /// 1. Suppression directives (glot-disable, glot-enable, glot-disable-next-line)
+    /// 1. Suppression directives (glot-disable, glot-enable, glot-disable-next-line,
+    ///    glot-disable-file)
     /// 2. Key declarations (glot-message-keys)
     ///
     /// # Arguments
@@ -54,15 +56,16 @@ impl CommentCollector {
         let mut suppressions = Suppressions::default();
         let mut declaration_entries = HashMap::new();
 
-        // Collect all comments with their line numbers (computed once)
+        // Collect all comments with their line numbers (computed once).
+        // Detached comments (Astro/Vue templates) have no span.
         let (leading, trailing) = swc_comments.borrow_all();
-        let mut comments_with_lines: Vec<(usize, String)> = leading
+        let mut comments_with_lines: Vec<(usize, String, Option<BytePos>)> = leading
             .iter()
             .chain(trailing.iter())
             .flat_map(|(_, cmts)| cmts.iter())
             .map(|cmt| {
                 let line = source_map.lookup_char_pos(cmt.span.lo).line;
-                (line, cmt.text.to_string())
+                (line, cmt.text.to_string(), Some(cmt.span.lo))
             })
             .collect();
 
@@ -70,20 +73,24 @@ impl CommentCollector {
             swc_comments
                 .detached
                 .iter()
-                .map(|comment| (comment.line, comment.text.clone())),
+                .map(|comment| (comment.line, comment.text.clone(), None)),
         );
 
         // Sort by line number
-        comments_with_lines.sort_by_key(|(line, _)| *line);
+        comments_with_lines.sort_by_key(|(line, _, _)| *line);
 
         // Collect all comment line numbers for consecutive comment handling
-        let comment_lines: HashSet<usize> =
-            comments_with_lines.iter().map(|(line, _)| *line).collect();
+        let comment_lines: HashSet<usize> = comments_with_lines
+            .iter()
+            .map(|(line, _, _)| *line)
+            .collect();
 
         // Track open disable ranges per rule
         let mut open_ranges: HashMap<SuppressibleRule, usize> = HashMap::new();
+        // Line of each `glot-disable` comment -> where it is, for reporting it unterminated
+        let mut disable_comments: HashMap<usize, (usize, String)> = HashMap::new();
 
-        for (line, text) in comments_with_lines {
+        for (line, text, pos) in comments_with_lines {
             let text = text.trim();
 
             if let Some(directive) = Directive::parse(text) {
@@ -92,8 +99,12 @@ impl CommentCollector {
                         for rule in rules {
                             open_ranges.entry(rule).or_insert(line);
                         }
+                        disable_comments
+                            .entry(line)
+                            .or_insert_with(|| Self::comment_position(text, pos, source_map));
                     }
                     Directive::Enable { rules } => {
+                        let mut closed_any = false;
                         for rule in rules {
                             if let Some(start) = open_ranges.remove(&rule) {
                                 let end = line.saturating_sub(1);
@@ -102,19 +113,45 @@ impl CommentCollector {
                                     .entry(rule)
                                     .or_default()
                                     .push(DisabledRange { start, end });
+                                closed_any = true;
                             }
                         }
+                        if !closed_any {
+                            let (col, source_line) = Self::comment_position(text, pos, source_map);
+                            suppressions.unpaired_directives.push(UnpairedDirective {
+                                line,
+                                col,
+                                source_line,
+                                kind: UnpairedDirectiveKind::UnmatchedEnable,
+                            });
+                        }
                     }
                     Directive::DisableNextLine { rules } => {
                         // Find the next non-comment line
                         let target_line = Self::find_next_non_comment_line(line, &comment_lines);
-                        for rule in rules {
+                        for rule in &rules {
                             suppressions
                                 .disabled_lines
-                                .entry(rule)
+                                .entry(*rule)
                                 .or_default()
                                 .insert(target_line);
                         }
+                        suppressions.next_line_directives.push(NextLineDirective {
+                            line,
+                            target_line,
+                            rules,
+                        });
+                    }
+                    Directive::DisableFile { rules } => {
+                        // Covers every line, wherever the comment is
+                        for rule in rules {
+                            suppressions.disabled_ranges.entry(rule).or_default().push(
+                                DisabledRange {
+                                    start: 0,
+                                    end: usize::MAX,
+                                },
+                            );
+                        }
                     }
                     Directive::MessageKeys(decl) => {
                         declaration_entries.insert(line, decl);
@@ -124,6 +161,7 @@ impl CommentCollector {
         }
 
         // Close any open ranges (extend to end of file)
+        let mut unterminated_lines = BTreeSet::new();
         for (rule, start) in open_ranges {
             suppressions
                 .disabled_ranges
@@ -133,7 +171,19 @@ impl CommentCollector {
                     start,
                     end: usize::MAX,
                 });
+            unterminated_lines.insert(start);
+        }
+        for line in unterminated_lines {
+            if let Some((col, source_line)) = disable_comments.remove(&line) {
+                suppressions.unpaired_directives.push(UnpairedDirective {
+                    line,
+                    col,
+                    source_line,
+                    kind: UnpairedDirectiveKind::UnterminatedDisable,
+                });
+            }
         }
+        suppressions.unpaired_directives.sort_by_key(|d| d.line);
 
         FileComments {
             suppressions,
@@ -144,6 +194,29 @@ impl CommentCollector {
         }
     }
 
+    /// Column and source line of a directive comment, for reporting it.
+    ///
+    /// Detached comments have no position in the source map; their column is
+    /// reported as 1 and the comment text stands in for the source line.
+    fn comment_position(
+        text: &str,
+        pos: Option<BytePos>,
+        source_map: &SourceMap,
+    ) -> (usize, String) {
+        match pos {
+            Some(pos) => {
+                let loc = source_map.lookup_char_pos(pos);
+                let source_line = loc
+                    .file
+                    .get_line(loc.line - 1)
+                    .map(|line| line.to_string())
+                    .unwrap_or_else(|| text.to_string());
+                (loc.col_display + 1, source_line)
+            }
+            None => (1, text.to_string()),
+        }
+    }
+
     /// Find the next non-comment line after the given line.
     ///
     /// This skips over any consecutive comment lines to find the actual
@@ -199,7 +272,7 @@ t(`${key}`)
 "#;
         let comments = parse_and_collect(source);
 
-        let decl = comments.declarations.get_declaration(3);
+        let decl = comments.declarations.get_declaration(3, ".");
         assert!(decl.is_some());
         assert_eq!(
             decl.unwrap().absolute_patterns,
@@ -216,7 +289,7 @@ t(`${key}`)
 "#;
         let comments = parse_and_collect(source);
 
-        let decl = comments.declarations.get_declaration(3);
+        let decl = comments.declarations.get_declaration(3, ".");
         assert!(decl.is_some());
         assert!(decl.unwrap().absolute_patterns.is_empty());
         assert_eq!(decl.unwrap().relative_patterns, vec![".submit".to_string()]);
@@ -230,7 +303,7 @@ t(`${key}`)
 "#;
         let comments = parse_and_collect(source);
 
-        let decl = comments.declarations.get_declaration(3);
+        let decl = comments.declarations.get_declaration(3, ".");
         assert!(decl.is_some());
         assert_eq!(
             decl.unwrap().absolute_patterns,
@@ -290,8 +363,9 @@ const x = 1;
         let source = r#"// glot-message-keys "*.title""#;
         let comments = parse_and_collect(source);
 
-        // Invalid pattern is skipped, no entry created
-        assert_eq!(comments.declarations.entries.len(), 0);
+        // The entry is kept, but an invalid pattern declares nothing
+        assert_eq!(comments.declarations.entries.len(), 1);
+        assert!(comments.declarations.get_declaration(2, ".").is_none());
     }
 
     #[test]
@@ -371,7 +445,7 @@ const { t } = useTranslation();
 "#;
         let comments = parse_astro_and_collect(source);
 
-        let decl = comments.declarations.get_declaration(6).unwrap();
+        let decl = comments.declarations.get_declaration(6, ".").unwrap();
         assert_eq!(
             decl.absolute_patterns,
             vec!["Features.items.*.title".to_string()]
@@ -527,7 +601,7 @@ const x = 1;
         let comments = parse_and_collect(source);
 
         // Declaration should be accessible for line 4 (t() call)
-        let decl = comments.declarations.get_declaration(4);
+        let decl = comments.declarations.get_declaration(4, ".");
         assert!(decl.is_some(), "Declaration should be found for line 4");
         assert_eq!(decl.unwrap().absolute_patterns, vec!["Common.*"]);
     }
@@ -565,7 +639,7 @@ const x = 1;
         );
 
         // Declaration should be found
-        let decl = comments.declarations.get_declaration(5);
+        let decl = comments.declarations.get_declaration(5, ".");
         assert!(decl.is_some());
         assert_eq!(decl.unwrap().absolute_patterns, vec!["Status.*"]);
     }
@@ -614,7 +688,7 @@ const x = 1;
         let comments = parse_and_collect(source);
 
         // Declaration on line 2 should be found for line 4
-        let decl = comments.declarations.get_declaration(4);
+        let decl = comments.declarations.get_declaration(4, ".");
         assert!(
             decl.is_some(),
             "Declaration should be found through comment chain"
@@ -640,10 +714,117 @@ const x = 1;
         let comments = parse_and_collect(source);
 
         // Declaration on line 2 should NOT be found for line 4 (blank line breaks chain)
-        let decl = comments.declarations.get_declaration(4);
+        let decl = comments.declarations.get_declaration(4, ".");
         assert!(
             decl.is_none(),
             "Declaration should not be found due to blank line gap"
         );
     }
+
+    // ============================================================
+    // Unpaired glot-disable / glot-enable
+    // ============================================================
+
+    #[test]
+    fn test_paired_range_is_not_reported() {
+        let source = r#"
+// glot-disable hardcoded
+const a = <div>Hello</div>;
+// glot-enable hardcoded
+"#;
+        let comments = parse_and_collect(source);
+        assert!(comments.suppressions.unpaired_directives.is_empty());
+        assert!(
+            comments
+                .suppressions
+                .is_suppressed(3, SuppressibleRule::Hardcoded)
+        );
+    }
+
+    #[test]
+    fn test_enable_without_disable_is_reported() {
+        let source = r#"
+// glot-disable
+const a = <div>Hello</div>;
+// glot-enable
+const b = <div>World</div>;
+    // glot-enable hardcoded
+"#;
+        let comments = parse_and_collect(source);
+        assert_eq!(
+            comments.suppressions.unpaired_directives,
+            vec![UnpairedDirective {
+                line: 6,
+                col: 5,
+                source_line: "    // glot-enable hardcoded".to_string(),
+                kind: UnpairedDirectiveKind::UnmatchedEnable,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_enable_of_other_rule_is_reported() {
+        let source = r#"
+// glot-disable hardcoded
+const a = <div>Hello</div>;
+// glot-enable untranslated
+"#;
+        let comments = parse_and_collect(source);
+        let kinds: Vec<(usize, UnpairedDirectiveKind)> = comments
+            .suppressions
+            .unpaired_directives
+            .iter()
+            .map(|d| (d.line, d.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (2, UnpairedDirectiveKind::UnterminatedDisable),
+                (4, UnpairedDirectiveKind::UnmatchedEnable),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_disable_is_reported_once() {
+        let source = r#"
+{/* glot-disable hardcoded untranslated */}
+<div>Hello</div>
+{/* glot-disable hardcoded */}
+"#;
+        let comments = parse_and_collect(source);
+        let lines: Vec<(usize, UnpairedDirectiveKind)> = comments
+            .suppressions
+            .unpaired_directives
+            .iter()
+            .map(|d| (d.line, d.kind))
+            .collect();
+        assert_eq!(lines, vec![(2, UnpairedDirectiveKind::UnterminatedDisable)]);
+        // The range still covers the rest of the file
+        assert!(
+            comments
+                .suppressions
+                .is_suppressed(100, SuppressibleRule::Untranslated)
+        );
+    }
+
+    // ============================================================
+    // glot-disable-file
+    // ============================================================
+
+    #[test]
+    fn test_disable_file_suppresses_rule_on_every_line() {
+        let source = r#"
+const a = <div>Hello</div>;
+// glot-disable-file hardcoded
+const b = <div>World</div>;
+"#;
+        let comments = parse_and_collect(source);
+        let suppressions = &comments.suppressions;
+        for line in [0, 1, 2, 4, 100] {
+            assert!(suppressions.is_suppressed(line, SuppressibleRule::Hardcoded));
+            assert!(!suppressions.is_suppressed(line, SuppressibleRule::Untranslated));
+        }
+        assert!(suppressions.unpaired_directives.is_empty());
+    }
 }
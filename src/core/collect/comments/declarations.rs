@@ -3,6 +3,9 @@
 //! Provides query and expansion methods for declarations:
 //! - `Declarations::get_declaration`: Look up declaration by line number
 //! - `KeyDeclaration::expand`: Expand patterns to concrete keys
+//! - `KeyDeclaration::unmatched_patterns`: Find patterns that match no key
+//!
+//! Invalid patterns (see `is_valid_pattern`) are ignored by all of them.
 
 use std::collections::HashSet;
 
@@ -20,18 +23,24 @@ impl KeyDeclaration {
     /// # Arguments
     /// * `namespace` - Namespace for expanding relative patterns
     /// * `available_keys` - Available keys for glob matching
+    /// * `separator` - Key separator used to join the namespace and to split segments
     ///
     /// # Returns
     /// A vector of concrete keys. If a glob pattern doesn't match any keys,
     /// the pattern itself is preserved (for missing-key detection).
     #[cfg(test)]
-    pub fn expand(&self, namespace: Option<&str>, available_keys: &HashSet<String>) -> Vec<String> {
+    pub fn expand(
+        &self,
+        namespace: Option<&str>,
+        available_keys: &HashSet<String>,
+        separator: &str,
+    ) -> Vec<String> {
         let mut result = Vec::new();
 
         // Expand absolute patterns
-        for pattern in &self.absolute_patterns {
+        for pattern in self.absolute(separator) {
             if is_glob_pattern(pattern) {
-                let expanded = expand_glob_pattern(pattern, available_keys);
+                let expanded = expand_glob_pattern(pattern, available_keys, separator);
                 if expanded.is_empty() {
                     result.push(pattern.clone());
                 } else {
@@ -43,15 +52,15 @@ impl KeyDeclaration {
         }
 
         // Expand relative patterns (with namespace prefix)
-        for pattern in &self.relative_patterns {
+        for pattern in self.relative(separator) {
             let relative_path = &pattern[1..]; // Remove leading `.`
             let full_pattern = match namespace {
-                Some(ns) => format!("{}.{}", ns, relative_path),
+                Some(ns) => format!("{}{}{}", ns, separator, relative_path),
                 None => relative_path.to_string(),
             };
 
             if is_glob_pattern(&full_pattern) {
-                let expanded = expand_glob_pattern(&full_pattern, available_keys);
+                let expanded = expand_glob_pattern(&full_pattern, available_keys, separator);
                 if expanded.is_empty() {
                     result.push(full_pattern);
                 } else {
@@ -73,17 +82,19 @@ impl KeyDeclaration {
     /// # Arguments
     /// * `namespaces` - List of namespaces (for FromProps/FromFnCall sources)
     /// * `available_keys` - Available keys for glob matching
+    /// * `separator` - Key separator used to join namespaces and to split segments
     pub fn expand_all(
         &self,
         namespaces: &[Option<String>],
         available_keys: &HashSet<String>,
+        separator: &str,
     ) -> Vec<String> {
         let mut result = Vec::new();
 
         // Expand absolute patterns once
-        for pattern in &self.absolute_patterns {
+        for pattern in self.absolute(separator) {
             if is_glob_pattern(pattern) {
-                let expanded = expand_glob_pattern(pattern, available_keys);
+                let expanded = expand_glob_pattern(pattern, available_keys, separator);
                 if expanded.is_empty() {
                     result.push(pattern.clone());
                 } else {
@@ -95,17 +106,17 @@ impl KeyDeclaration {
         }
 
         // Expand relative patterns for each namespace
-        for pattern in &self.relative_patterns {
+        for pattern in self.relative(separator) {
             let relative_path = &pattern[1..]; // Remove leading `.`
 
             for namespace in namespaces {
                 let full_pattern = match namespace {
-                    Some(ns) => format!("{}.{}", ns, relative_path),
+                    Some(ns) => format!("{}{}{}", ns, separator, relative_path),
                     None => relative_path.to_string(),
                 };
 
                 if is_glob_pattern(&full_pattern) {
-                    let expanded = expand_glob_pattern(&full_pattern, available_keys);
+                    let expanded = expand_glob_pattern(&full_pattern, available_keys, separator);
                     if expanded.is_empty() {
                         result.push(full_pattern);
                     } else {
@@ -119,6 +130,80 @@ impl KeyDeclaration {
 
         result
     }
+
+    /// Patterns that match no key in `available_keys`.
+    ///
+    /// Relative patterns are joined to each namespace, like in `expand_all`,
+    /// and are returned in that form (`.statsu.*` → `Common.statsu.*`).
+    pub fn unmatched_patterns(
+        &self,
+        namespaces: &[Option<String>],
+        available_keys: &HashSet<String>,
+        separator: &str,
+    ) -> Vec<String> {
+        let relative = self.relative(separator).flat_map(|pattern| {
+            let relative_path = &pattern[1..];
+            namespaces.iter().map(move |namespace| match namespace {
+                Some(ns) => format!("{}{}{}", ns, separator, relative_path),
+                None => relative_path.to_string(),
+            })
+        });
+
+        self.absolute(separator)
+            .cloned()
+            .chain(relative)
+            .filter(|pattern| expand_glob_pattern(pattern, available_keys, separator).is_empty())
+            .collect()
+    }
+
+    /// Whether any pattern is valid, i.e. the declaration declares something.
+    pub fn has_valid_pattern(&self, separator: &str) -> bool {
+        self.absolute(separator)
+            .chain(self.relative(separator))
+            .next()
+            .is_some()
+    }
+
+    fn absolute<'a>(&'a self, separator: &'a str) -> impl Iterator<Item = &'a String> {
+        self.absolute_patterns
+            .iter()
+            .filter(move |pattern| is_valid_pattern(pattern, separator))
+    }
+
+    fn relative<'a>(&'a self, separator: &'a str) -> impl Iterator<Item = &'a String> {
+        self.relative_patterns
+            .iter()
+            .filter(move |pattern| is_valid_pattern(pattern, separator))
+    }
+}
+
+/// Validate a pattern whose segments are joined with `separator`.
+///
+/// Valid patterns:
+/// - Absolute: `Namespace.key.path` or `key.path`
+/// - Relative: `.key.path` (will be expanded with namespace at runtime)
+/// - Glob: `Namespace.features.*` or `.features.*`
+///
+/// Invalid patterns:
+/// - Prefix wildcard: `*.suffix` (not supported)
+/// - Empty segments: `foo..bar`
+///
+/// The leading `.` of relative patterns is part of the directive syntax, so
+/// it is kept whatever the separator (`.features/*` with `/`).
+fn is_valid_pattern(pattern: &str, separator: &str) -> bool {
+    let pattern = pattern.strip_prefix('.').unwrap_or(pattern);
+    let segments: Vec<&str> = pattern.split(separator).collect();
+
+    // Check for prefix wildcard pattern like "*.suffix"
+    if let Some(first) = segments.first()
+        && *first == "*"
+        && segments.len() > 1
+    {
+        return false;
+    }
+
+    // Check for empty segments
+    !segments.iter().any(|s| s.is_empty())
 }
 
 impl Declarations {
@@ -135,10 +220,17 @@ impl Declarations {
     /// ```
     ///
     /// **Note**: Blank lines break the search chain. The search is also limited
-    /// to [`MAX_COMMENT_CHAIN_LINES`] to avoid traversing too far.
-    pub fn get_declaration(&self, line: usize) -> Option<&KeyDeclaration> {
+    /// to [`MAX_COMMENT_CHAIN_LINES`] to avoid traversing too far. Declarations
+    /// without a valid pattern for `separator` are skipped.
+    pub fn get_declaration(&self, line: usize, separator: &str) -> Option<&KeyDeclaration> {
+        let declaration = |line: usize| {
+            self.entries
+                .get(&line)
+                .filter(|decl| decl.has_valid_pattern(separator))
+        };
+
         // Check current line first
-        if let Some(decl) = self.entries.get(&line) {
+        if let Some(decl) = declaration(line) {
             return Some(decl);
         }
 
@@ -149,7 +241,7 @@ impl Declarations {
 
         while prev >= 1 && prev >= min_line {
             // Check if this line has a declaration
-            if let Some(decl) = self.entries.get(&prev) {
+            if let Some(decl) = declaration(prev) {
                 return Some(decl);
             }
 
@@ -181,10 +273,57 @@ mod tests {
         }
 
         // Line 3 should find declaration from line 2
-        let decl = declarations.get_declaration(3);
+        let decl = declarations.get_declaration(3, ".");
         assert!(decl.is_some());
     }
 
+    #[test]
+    fn test_get_declaration_skips_invalid_patterns() {
+        let mut declarations = Declarations::default();
+        for (line, text) in [
+            (1, "glot-message-keys \"Common.key\""),
+            (2, "glot-message-keys \"*.title\""),
+        ] {
+            if let Some(Directive::MessageKeys(decl)) = Directive::parse(text) {
+                declarations.entries.insert(line, decl);
+            }
+            declarations.comment_lines.insert(line);
+        }
+
+        // The prefix wildcard declares nothing, so the search goes on to line 1
+        let decl = declarations.get_declaration(3, ".").unwrap();
+        assert_eq!(decl.absolute_patterns, vec!["Common.key"]);
+    }
+
+    #[test]
+    fn test_invalid_patterns_are_ignored() {
+        let decl = KeyDeclaration {
+            absolute_patterns: vec![
+                "*.title".to_string(),
+                "Common..submit".to_string(),
+                "Common.cancel".to_string(),
+            ],
+            relative_patterns: vec![".".to_string()],
+        };
+
+        assert_eq!(
+            decl.expand(Some("App"), &HashSet::new(), "."),
+            vec!["Common.cancel"]
+        );
+        assert_eq!(
+            decl.unmatched_patterns(&[None], &HashSet::new(), "."),
+            vec!["Common.cancel"]
+        );
+    }
+
+    #[test]
+    fn test_is_valid_pattern_custom_separator() {
+        assert!(is_valid_pattern("Common/v1..0", "/"));
+        assert!(is_valid_pattern(".features/*", "/"));
+        assert!(!is_valid_pattern("*/title", "/"));
+        assert!(!is_valid_pattern("Common//submit", "/"));
+    }
+
     #[test]
     fn test_expand_absolute_patterns() {
         let decl = KeyDeclaration {
@@ -192,7 +331,7 @@ mod tests {
             relative_patterns: vec![],
         };
 
-        let expanded = decl.expand(None, &HashSet::new());
+        let expanded = decl.expand(None, &HashSet::new(), ".");
 
         assert_eq!(expanded.len(), 2);
         assert!(expanded.contains(&"Common.submit".to_string()));
@@ -206,7 +345,7 @@ mod tests {
             relative_patterns: vec![".submit".to_string(), ".cancel".to_string()],
         };
 
-        let expanded = decl.expand(Some("Common"), &HashSet::new());
+        let expanded = decl.expand(Some("Common"), &HashSet::new(), ".");
 
         assert_eq!(expanded.len(), 2);
         assert!(expanded.contains(&"Common.submit".to_string()));
@@ -220,7 +359,7 @@ mod tests {
             relative_patterns: vec![".submit".to_string()],
         };
 
-        let expanded = decl.expand(None, &HashSet::new());
+        let expanded = decl.expand(None, &HashSet::new(), ".");
 
         assert_eq!(expanded, vec!["submit".to_string()]);
     }
@@ -236,7 +375,7 @@ mod tests {
             relative_patterns: vec![],
         };
 
-        let expanded = decl.expand(None, &available_keys);
+        let expanded = decl.expand(None, &available_keys, ".");
 
         assert_eq!(expanded.len(), 2);
         assert!(expanded.contains(&"Common.btn.submit".to_string()));
@@ -254,13 +393,57 @@ mod tests {
             relative_patterns: vec![".btn.*".to_string()],
         };
 
-        let expanded = decl.expand(Some("Common"), &available_keys);
+        let expanded = decl.expand(Some("Common"), &available_keys, ".");
 
         assert_eq!(expanded.len(), 2);
         assert!(expanded.contains(&"Common.btn.submit".to_string()));
         assert!(expanded.contains(&"Common.btn.cancel".to_string()));
     }
 
+    #[test]
+    fn test_expand_relative_glob_pattern_custom_separator() {
+        let mut available_keys = HashSet::new();
+        available_keys.insert("Common/btn/submit".to_string());
+
+        let decl = KeyDeclaration {
+            absolute_patterns: vec![],
+            relative_patterns: vec![".btn/*".to_string()],
+        };
+
+        let expanded = decl.expand(Some("Common"), &available_keys, "/");
+
+        assert_eq!(expanded, vec!["Common/btn/submit".to_string()]);
+    }
+
+    #[test]
+    fn test_unmatched_patterns() {
+        let available_keys: HashSet<String> = ["Common.btn.submit", "Auth.title"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let decl = KeyDeclaration {
+            absolute_patterns: vec![
+                "Common.btn.*".to_string(),
+                "Common.bnt.*".to_string(),
+                "Auth.title".to_string(),
+                "Auth.subtitle".to_string(),
+            ],
+            relative_patterns: vec![".btn.*".to_string()],
+        };
+
+        let unmatched = decl.unmatched_patterns(
+            &[Some("Common".to_string()), Some("Auth".to_string())],
+            &available_keys,
+            ".",
+        );
+
+        assert_eq!(
+            unmatched,
+            vec!["Common.bnt.*", "Auth.subtitle", "Auth.btn.*"]
+        );
+    }
+
     #[test]
     fn test_expand_mixed_patterns() {
         let decl = KeyDeclaration {
@@ -268,7 +451,7 @@ mod tests {
             relative_patterns: vec![".features.name".to_string()],
         };
 
-        let expanded = decl.expand(Some("App"), &HashSet::new());
+        let expanded = decl.expand(Some("App"), &HashSet::new(), ".");
 
         assert_eq!(expanded.len(), 2);
         assert!(expanded.contains(&"Common.title".to_string()));
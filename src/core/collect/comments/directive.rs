@@ -2,6 +2,7 @@
 //!
 //! All glot comment directives:
 //! - `glot-disable` / `glot-enable` / `glot-disable-next-line` - suppression
+//! - `glot-disable-file` - suppression for the whole file
 //! - `glot-message-keys` - key declarations
 
 use regex::Regex;
@@ -18,6 +19,7 @@ pub enum Directive {
     Disable { rules: HashSet<SuppressibleRule> },
     Enable { rules: HashSet<SuppressibleRule> },
     DisableNextLine { rules: HashSet<SuppressibleRule> },
+    DisableFile { rules: HashSet<SuppressibleRule> },
     MessageKeys(KeyDeclaration),
 }
 
@@ -39,6 +41,11 @@ impl Directive {
                 rules: parse_rules(rest),
             });
         }
+        if let Some(rest) = strip_directive_prefix(text, "glot-disable-file") {
+            return Some(Self::DisableFile {
+                rules: parse_rules(rest),
+            });
+        }
         if let Some(rest) = strip_directive_prefix(text, "glot-disable") {
             return Some(Self::Disable {
                 rules: parse_rules(rest),
@@ -92,7 +99,8 @@ fn parse_rules(rest: &str) -> HashSet<SuppressibleRule> {
 
 /// Parse glot-message-keys declaration
 ///
-/// Only performs text parsing, no glob expansion (expansion is deferred until use)
+/// Only performs text parsing: patterns are validated and expanded when used,
+/// since both depend on the key separator (see `KeyDeclaration::expand_all`).
 fn parse_message_keys(rest: &str) -> Option<KeyDeclaration> {
     // Extract quoted patterns
     let patterns: Vec<String> = QUOTED_STRING_REGEX
@@ -104,21 +112,11 @@ fn parse_message_keys(rest: &str) -> Option<KeyDeclaration> {
         return None;
     }
 
-    // Validate and filter patterns
-    let valid_patterns: Vec<String> = patterns
-        .into_iter()
-        .filter(|p| is_valid_pattern(p))
-        .collect();
-
-    if valid_patterns.is_empty() {
-        return None;
-    }
-
     // Categorize patterns (but don't expand)
     let mut absolute_patterns = Vec::new();
     let mut relative_patterns = Vec::new();
 
-    for pattern in valid_patterns {
+    for pattern in patterns {
         if pattern.starts_with('.') {
             relative_patterns.push(pattern);
         } else {
@@ -132,42 +130,6 @@ fn parse_message_keys(rest: &str) -> Option<KeyDeclaration> {
     })
 }
 
-/// Validate a pattern. Returns true if valid, false if invalid.
-///
-/// Valid patterns:
-/// - Absolute: `Namespace.key.path` or `key.path`
-/// - Relative: `.key.path` (will be expanded with namespace at runtime)
-/// - Glob: `Namespace.features.*` or `.features.*`
-///
-/// Invalid patterns:
-/// - Prefix wildcard: `*.suffix` (not supported)
-/// - Empty segments: `foo..bar`
-fn is_valid_pattern(pattern: &str) -> bool {
-    // Handle relative patterns (starting with `.`)
-    let pattern_to_check = if let Some(stripped) = pattern.strip_prefix('.') {
-        stripped
-    } else {
-        pattern
-    };
-
-    let segments: Vec<&str> = pattern_to_check.split('.').collect();
-
-    // Check for prefix wildcard pattern like "*.suffix"
-    if let Some(first) = segments.first()
-        && *first == "*"
-        && segments.len() > 1
-    {
-        return false;
-    }
-
-    // Check for empty segments
-    if segments.iter().any(|s| s.is_empty()) {
-        return false;
-    }
-
-    true
-}
-
 #[cfg(test)]
 mod tests {
     use crate::core::collect::comments::directive::*;
@@ -183,7 +145,13 @@ mod tests {
             Directive::DisableNextLine { rules } => {
                 assert!(rules.contains(&SuppressibleRule::Hardcoded));
                 assert!(rules.contains(&SuppressibleRule::Untranslated));
-                assert_eq!(rules.len(), 2);
+                assert!(rules.contains(&SuppressibleRule::KeyNaming));
+                assert!(rules.contains(&SuppressibleRule::MarkupOnly));
+                assert!(rules.contains(&SuppressibleRule::UnmatchedMessageKeys));
+                assert!(rules.contains(&SuppressibleRule::NamespaceConsistency));
+                assert!(rules.contains(&SuppressibleRule::InterpolationMismatch));
+                assert!(rules.contains(&SuppressibleRule::EmptyKey));
+                assert_eq!(rules.len(), 8);
             }
             _ => panic!("expected DisableNextLine"),
         }
@@ -277,6 +245,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_directive_parse_disable_file() {
+        let d = Directive::parse("glot-disable-file").unwrap();
+        match d {
+            Directive::DisableFile { rules } => {
+                assert_eq!(rules, SuppressibleRule::all());
+            }
+            _ => panic!("expected DisableFile"),
+        }
+
+        let d = Directive::parse("glot-disable-file untranslated").unwrap();
+        match d {
+            Directive::DisableFile { rules } => {
+                assert!(!rules.contains(&SuppressibleRule::Hardcoded));
+                assert!(rules.contains(&SuppressibleRule::Untranslated));
+            }
+            _ => panic!("expected DisableFile"),
+        }
+        assert!(Directive::parse("glot-disable-files").is_none());
+    }
+
     #[test]
     fn test_directive_parse_enable_no_args() {
         let d = Directive::parse("glot-enable").unwrap();
@@ -393,9 +382,15 @@ mod tests {
     }
 
     #[test]
-    fn test_directive_parse_message_keys_prefix_wildcard_invalid() {
-        // Prefix wildcard should be filtered out as invalid
-        assert!(Directive::parse("glot-message-keys \"*.title\"").is_none());
+    fn test_directive_parse_message_keys_keeps_invalid_patterns() {
+        // Validation depends on the key separator, so it's left to expansion
+        let d = Directive::parse("glot-message-keys \"*.title\"").unwrap();
+        match d {
+            Directive::MessageKeys(decl) => {
+                assert_eq!(decl.absolute_patterns, vec!["*.title"]);
+            }
+            _ => panic!("expected MessageKeys"),
+        }
     }
 
     #[test]
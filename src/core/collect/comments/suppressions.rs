@@ -14,13 +14,30 @@ impl SuppressibleRule {
         match s.to_lowercase().as_str() {
             "hardcoded" => Some(Self::Hardcoded),
             "untranslated" => Some(Self::Untranslated),
+            "key-naming" => Some(Self::KeyNaming),
+            "markup-only" => Some(Self::MarkupOnly),
+            "unmatched-message-keys" => Some(Self::UnmatchedMessageKeys),
+            "namespace-consistency" => Some(Self::NamespaceConsistency),
+            "interpolation-mismatch" => Some(Self::InterpolationMismatch),
+            "empty-key" => Some(Self::EmptyKey),
             _ => None,
         }
     }
 
     /// All available rules (for "no args = all rules" case).
     pub fn all() -> HashSet<Self> {
-        [Self::Hardcoded, Self::Untranslated].into_iter().collect()
+        [
+            Self::Hardcoded,
+            Self::Untranslated,
+            Self::KeyNaming,
+            Self::MarkupOnly,
+            Self::UnmatchedMessageKeys,
+            Self::NamespaceConsistency,
+            Self::InterpolationMismatch,
+            Self::EmptyKey,
+        ]
+        .into_iter()
+        .collect()
     }
 
     /// Get the suffix string for this rule (used in baseline comments).
@@ -28,6 +45,12 @@ impl SuppressibleRule {
         match self {
             Self::Hardcoded => "hardcoded",
             Self::Untranslated => "untranslated",
+            Self::KeyNaming => "key-naming",
+            Self::MarkupOnly => "markup-only",
+            Self::UnmatchedMessageKeys => "unmatched-message-keys",
+            Self::NamespaceConsistency => "namespace-consistency",
+            Self::InterpolationMismatch => "interpolation-mismatch",
+            Self::EmptyKey => "empty-key",
         }
     }
 
@@ -103,6 +126,30 @@ mod tests {
 
     #[test]
     fn test_suppressible_rule_parse_unknown() {
+        assert_eq!(
+            SuppressibleRule::parse("key-naming"),
+            Some(SuppressibleRule::KeyNaming)
+        );
+        assert_eq!(
+            SuppressibleRule::parse("markup-only"),
+            Some(SuppressibleRule::MarkupOnly)
+        );
+        assert_eq!(
+            SuppressibleRule::parse("unmatched-message-keys"),
+            Some(SuppressibleRule::UnmatchedMessageKeys)
+        );
+        assert_eq!(
+            SuppressibleRule::parse("namespace-consistency"),
+            Some(SuppressibleRule::NamespaceConsistency)
+        );
+        assert_eq!(
+            SuppressibleRule::parse("interpolation-mismatch"),
+            Some(SuppressibleRule::InterpolationMismatch)
+        );
+        assert_eq!(
+            SuppressibleRule::parse("empty-key"),
+            Some(SuppressibleRule::EmptyKey)
+        );
         assert_eq!(SuppressibleRule::parse("unknown"), None);
         assert_eq!(SuppressibleRule::parse(""), None);
         assert_eq!(SuppressibleRule::parse("hard-coded"), None);
@@ -113,7 +160,13 @@ mod tests {
         let all = SuppressibleRule::all();
         assert!(all.contains(&SuppressibleRule::Hardcoded));
         assert!(all.contains(&SuppressibleRule::Untranslated));
-        assert_eq!(all.len(), 2);
+        assert!(all.contains(&SuppressibleRule::KeyNaming));
+        assert!(all.contains(&SuppressibleRule::MarkupOnly));
+        assert!(all.contains(&SuppressibleRule::UnmatchedMessageKeys));
+        assert!(all.contains(&SuppressibleRule::NamespaceConsistency));
+        assert!(all.contains(&SuppressibleRule::InterpolationMismatch));
+        assert!(all.contains(&SuppressibleRule::EmptyKey));
+        assert_eq!(all.len(), 8);
     }
 
     #[test]
@@ -5,7 +5,10 @@
 
 use std::collections::HashMap;
 
-use swc_ecma_ast::{ArrayLit, CallExpr, Expr, Lit, ObjectLit, PropName, TsEntityName, TsType};
+use swc_ecma_ast::{
+    ArrayLit, CallExpr, Expr, JSXAttr, JSXAttrName, JSXAttrOrSpread, JSXAttrValue, JSXElement, Lit,
+    ObjectLit, Prop, PropName, PropOrSpread, TsEntityName, TsEnumDecl, TsEnumMemberId, TsType,
+};
 
 /// Check if a TypeScript type is `TFunction`.
 pub fn is_tfunction_type(ts_type: &TsType) -> bool {
@@ -58,7 +61,13 @@ pub fn unwrap_ts_expr(expr: &Expr) -> &Expr {
 
 /// Extract string values from an object literal.
 /// Returns None if object has spread or no string values.
-pub fn extract_string_value(obj: &ObjectLit) -> Option<Vec<String>> {
+///
+/// Values are collected for identifier, string and numeric keys, and for
+/// computed keys that resolve statically (see `resolve_prop_name`).
+pub fn extract_string_value(
+    obj: &ObjectLit,
+    string_consts: &HashMap<String, String>,
+) -> Option<Vec<String>> {
     use swc_ecma_ast::{Prop, PropOrSpread};
 
     let mut values = Vec::new();
@@ -71,7 +80,7 @@ pub fn extract_string_value(obj: &ObjectLit) -> Option<Vec<String>> {
             }
             PropOrSpread::Prop(prop) => {
                 if let Prop::KeyValue(kv) = &**prop
-                    && matches!(&kv.key, PropName::Ident(_) | PropName::Str(_))
+                    && resolve_prop_name(&kv.key, string_consts).is_some()
                     && let Expr::Lit(Lit::Str(s)) = &*kv.value
                     && let Some(v) = s.value.as_str()
                 {
@@ -88,6 +97,35 @@ pub fn extract_string_value(obj: &ObjectLit) -> Option<Vec<String>> {
     }
 }
 
+/// Extract the property names of an object literal, as `Object.keys` lists them.
+/// Returns an empty list if object has spread or a key that doesn't resolve statically.
+pub fn extract_property_names(
+    obj: &ObjectLit,
+    string_consts: &HashMap<String, String>,
+) -> Vec<String> {
+    use swc_ecma_ast::{Prop, PropOrSpread};
+
+    let mut names = Vec::new();
+
+    for prop in &obj.props {
+        let name = match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(kv) => resolve_prop_name(&kv.key, string_consts),
+                Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+                Prop::Method(method) => resolve_prop_name(&method.key, string_consts),
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        };
+        match name {
+            Some(name) => names.push(name),
+            None => return Vec::new(),
+        }
+    }
+
+    names
+}
+
 /// Extract string values from an array literal.
 /// Returns None if array has spread or non-string elements.
 pub fn extract_string_array(arr: &ArrayLit) -> Option<Vec<String>> {
@@ -128,6 +166,32 @@ pub fn extract_string_array(arr: &ArrayLit) -> Option<Vec<String>> {
     }
 }
 
+/// Extract the member names and values of a string enum, in declaration order.
+/// Returns None if a member isn't initialized with a string literal: numeric
+/// enums map numbers, not keys.
+pub fn extract_enum_members(decl: &TsEnumDecl) -> Option<(Vec<String>, Vec<String>)> {
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+
+    for member in &decl.members {
+        let name = match &member.id {
+            TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+            TsEnumMemberId::Str(s) => s.value.as_str()?.to_string(),
+        };
+        let Expr::Lit(Lit::Str(s)) = unwrap_ts_expr(member.init.as_deref()?) else {
+            return None;
+        };
+        names.push(name);
+        values.push(s.value.as_str()?.to_string());
+    }
+
+    if values.is_empty() {
+        None
+    } else {
+        Some((names, values))
+    }
+}
+
 /// Extract property values from an array of objects.
 /// Returns a map of property names to their values across all objects.
 pub fn extract_array_properties(arr: &ArrayLit) -> Option<HashMap<String, Vec<String>>> {
@@ -180,6 +244,52 @@ pub fn extract_prop_name(key: &PropName) -> Option<String> {
     }
 }
 
+/// Resolve a property name to its string form, including numeric keys
+/// (`1: "x"`) and computed keys that are literals or reference a module-level
+/// string constant (`[SOME_CONST]: "x"`).
+pub fn resolve_prop_name(
+    key: &PropName,
+    string_consts: &HashMap<String, String>,
+) -> Option<String> {
+    match key {
+        PropName::Num(num) => Some(num.value.to_string()),
+        PropName::Computed(computed) => match unwrap_ts_expr(&computed.expr) {
+            Expr::Lit(Lit::Str(s)) => s.value.as_str().map(|s| s.to_string()),
+            Expr::Lit(Lit::Num(num)) => Some(num.value.to_string()),
+            Expr::Ident(ident) => string_consts.get(ident.sym.as_str()).cloned(),
+            _ => None,
+        },
+        _ => extract_prop_name(key),
+    }
+}
+
+/// Find the value of the attribute called `name` on a JSX element.
+pub fn find_jsx_attr<'a>(node: &'a JSXElement, name: &str) -> Option<&'a JSXAttrValue> {
+    node.opening.attrs.iter().find_map(|attr| match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(ident),
+            value: Some(value),
+            ..
+        }) if ident.sym == name => Some(value),
+        _ => None,
+    })
+}
+
+/// Find the value of the property called `name` in an object literal.
+///
+/// Only `name: value` properties are matched; spreads and shorthands are skipped.
+pub fn find_object_prop<'a>(obj: &'a ObjectLit, name: &str) -> Option<&'a Expr> {
+    obj.props.iter().find_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &**prop {
+            Prop::KeyValue(kv) if extract_prop_name(&kv.key).as_deref() == Some(name) => {
+                Some(&*kv.value)
+            }
+            _ => None,
+        },
+        PropOrSpread::Spread(_) => None,
+    })
+}
+
 /// Extract the full name from a JSX member expression (e.g., "Foo.Bar.Baz").
 pub fn extract_jsx_member_name(member: &swc_ecma_ast::JSXMemberExpr) -> String {
     let object_name = match &member.obj {
@@ -7,31 +7,43 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use swc_ecma_ast::{
-    CallExpr, Callee, DefaultDecl, Expr, ImportSpecifier, JSXAttr, JSXAttrName, JSXAttrOrSpread,
-    JSXAttrValue, JSXElement, JSXElementName, JSXExpr, ModuleExportName, Pat, VarDecl,
+    CallExpr, Callee, DefaultDecl, ExportSpecifier, Expr, ImportSpecifier, JSXAttr, JSXAttrName,
+    JSXAttrOrSpread, JSXAttrValue, JSXElement, JSXElementName, JSXExpr, Lit, ModuleExportName,
+    ObjectPatProp, Pat, PropName, TsEnumDecl, VarDecl, VarDeclKind,
 };
 
 use crate::core::utils::{
     extract_namespace_from_call, extract_t_from_destructuring, is_destructuring_hook,
-    is_translation_hook,
+    is_non_translation_hook, is_translation_hook, zip_array_destructuring,
 };
 
 use crate::core::collect::registry::helpers::{
-    extract_array_properties, extract_jsx_member_name, extract_string_array, extract_string_value,
-    unwrap_ts_expr,
+    extract_array_properties, extract_enum_members, extract_jsx_member_name,
+    extract_property_names, extract_string_array, extract_string_value, unwrap_ts_expr,
 };
 use crate::core::collect::types::{
-    FileImports, ImportInfo, KeyArray, KeyObject, StringArray, TranslationBindingValue,
-    TranslationFnCall, TranslationFnForward, TranslationProp, extract_binding_names,
-    resolve_import_path,
+    FileImports, FileReExports, ImportInfo, KeyArray, KeyObject, ReExport, StringArray,
+    TranslationBindingValue, TranslationFnCall, TranslationFnForward, TranslationProp,
+    TranslationPropForward, extract_binding_names, resolve_import_path,
 };
 
+/// Name of an import or export specifier (`toolKeys` or `"tool-keys"`).
+fn module_export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(s) => s.value.to_string_lossy().to_string(),
+    }
+}
+
 /// Named function context used for translation-function forwarding analysis.
 #[derive(Debug, Clone)]
 pub struct FunctionContext {
     pub file_path: String,
     pub registry_names: Vec<String>,
     param_indices: HashMap<String, usize>,
+    /// Bindings destructured from the first parameter (the props), mapped
+    /// to their prop names: `{ t: translate }` maps `translate` to `t`.
+    prop_bindings: HashMap<String, String>,
 }
 
 impl FunctionContext {
@@ -48,16 +60,52 @@ impl FunctionContext {
             })
             .collect();
 
+        let mut prop_bindings = HashMap::new();
+        if let Some(Pat::Object(obj_pat)) = params.first() {
+            for prop in &obj_pat.props {
+                match prop {
+                    ObjectPatProp::KeyValue(kv) => {
+                        let binding = match &*kv.value {
+                            Pat::Ident(ident) => Some(&ident.id),
+                            Pat::Assign(assign) => assign.left.as_ident().map(|ident| &ident.id),
+                            _ => None,
+                        };
+                        if let PropName::Ident(key) = &kv.key
+                            && let Some(binding) = binding
+                        {
+                            prop_bindings.insert(binding.sym.to_string(), key.sym.to_string());
+                        }
+                    }
+                    ObjectPatProp::Assign(assign) => {
+                        let name = assign.key.sym.to_string();
+                        prop_bindings.insert(name.clone(), name);
+                    }
+                    ObjectPatProp::Rest(_) => {}
+                }
+            }
+        }
+
         Self {
             file_path: file_path.to_string(),
             registry_names,
             param_indices,
+            prop_bindings,
         }
     }
 
     pub fn param_index(&self, name: &str) -> Option<usize> {
         self.param_indices.get(name).copied()
     }
+
+    /// Prop name of a binding destructured from the props parameter.
+    pub fn prop_name(&self, binding: &str) -> Option<&str> {
+        self.prop_bindings.get(binding).map(String::as_str)
+    }
+
+    /// Whether `name` is bound by a parameter of this function.
+    fn declares(&self, name: &str) -> bool {
+        self.param_indices.contains_key(name) || self.prop_bindings.contains_key(name)
+    }
 }
 
 /// Internal state for key data collection.
@@ -67,12 +115,16 @@ impl FunctionContext {
 pub struct KeyDataInternalState {
     /// Stack of translation function bindings scoped by function/arrow.
     bindings_stack: Vec<HashMap<String, TranslationBindingValue>>,
+    /// Module-level `const NAME = "value"` declarations seen so far, used to
+    /// resolve computed keys in key objects.
+    string_consts: HashMap<String, String>,
 }
 
 impl KeyDataInternalState {
     pub fn new() -> Self {
         Self {
             bindings_stack: vec![HashMap::new()],
+            string_consts: HashMap::new(),
         }
     }
 
@@ -137,6 +189,21 @@ impl KeyDataInternalState {
         None
     }
 
+    /// Value of the module-level string constant `name`, unless a parameter or
+    /// local variable of the same name shadows it.
+    fn string_const(&self, name: &str) -> Option<String> {
+        let shadowed = self
+            .bindings_stack
+            .iter()
+            .skip(1)
+            .any(|scope| scope.contains_key(name));
+        if shadowed {
+            None
+        } else {
+            self.string_consts.get(name).cloned()
+        }
+    }
+
     // ============================================================
     // Variable declaration handling
     // ============================================================
@@ -169,14 +236,20 @@ impl KeyDataInternalState {
             let inner_expr = unwrap_ts_expr(init);
 
             match inner_expr {
+                Expr::Lit(Lit::Str(s)) if is_module_level && node.kind == VarDeclKind::Const => {
+                    if let Some(value) = s.value.as_str() {
+                        self.string_consts.insert(name, value.to_string());
+                    }
+                }
                 Expr::Object(obj) => {
-                    if let Some(candidate_keys) = extract_string_value(obj) {
+                    if let Some(candidate_keys) = extract_string_value(obj, &self.string_consts) {
                         objects.push(KeyObject {
                             name,
                             file_path: file_path.to_string(),
                             is_exported,
                             is_module_level,
                             candidate_keys,
+                            property_names: extract_property_names(obj, &self.string_consts),
                         });
                     }
                 }
@@ -207,12 +280,51 @@ impl KeyDataInternalState {
         }
     }
 
+    /// Check an enum declaration for a string enum, collected as a key object.
+    ///
+    /// `enum Tool { Create = "tools.create" }` resolves like
+    /// `const Tool = { Create: "tools.create" }`, so `t(Tool[action])` and
+    /// `t(Tool.Create)` find their keys.
+    pub fn check_ts_enum(
+        &mut self,
+        node: &TsEnumDecl,
+        is_exported: bool,
+        is_module_level: bool,
+        file_path: &str,
+        objects: &mut Vec<KeyObject>,
+    ) {
+        let name = node.id.sym.to_string();
+        self.shadow_local_bindings(std::iter::once(name.clone()));
+
+        if let Some((property_names, candidate_keys)) = extract_enum_members(node) {
+            objects.push(KeyObject {
+                name,
+                file_path: file_path.to_string(),
+                is_exported,
+                is_module_level,
+                candidate_keys,
+                property_names,
+            });
+        }
+    }
+
     /// Check and track a translation binding.
     ///
     /// Handles both direct binding and destructuring:
     /// - next-intl: `const t = useTranslations("ns")` (Pat::Ident)
     /// - react-i18next: `const { t } = useTranslation("ns")` (Pat::Object)
+    /// - array literals: `const [t] = [useTranslations("ns")]` (Pat::Array)
+    ///
+    /// Results of non-translation hooks (`const t = useFormatter()`) replace any
+    /// outer translation binding of the same name in the current scope.
     fn check_translation_binding(&mut self, pat: &swc_ecma_ast::Pat, init: &Expr) {
+        if let Some(pairs) = zip_array_destructuring(pat, init) {
+            for (elem_pat, elem) in pairs {
+                self.check_translation_binding(elem_pat, elem);
+            }
+            return;
+        }
+
         let call_expr = match init {
             Expr::Call(call) => Some(call),
             Expr::Await(await_expr) => match &*await_expr.arg {
@@ -228,7 +340,7 @@ impl KeyDataInternalState {
         {
             let fn_name = ident.sym.as_str();
             if is_translation_hook(fn_name) {
-                let namespace = extract_namespace_from_call(call);
+                let namespace = extract_namespace_from_call(call, |name| self.string_const(name));
 
                 if is_destructuring_hook(fn_name) {
                     // react-i18next: const { t } = useTranslation("ns")
@@ -239,6 +351,12 @@ impl KeyDataInternalState {
                     // next-intl: const t = useTranslations("ns")
                     self.insert_translation_binding(binding_ident.id.sym.to_string(), namespace);
                 }
+            } else if is_non_translation_hook(fn_name)
+                && let Some(scope) = self.bindings_stack.last_mut()
+            {
+                for name in extract_binding_names(pat) {
+                    scope.insert(name, TranslationBindingValue::ShadowedLocal);
+                }
             }
         }
     }
@@ -264,10 +382,7 @@ impl KeyDataInternalState {
                         let imported_name = named
                             .imported
                             .as_ref()
-                            .map(|i| match i {
-                                ModuleExportName::Ident(ident) => ident.sym.to_string(),
-                                ModuleExportName::Str(s) => s.value.to_string_lossy().to_string(),
-                            })
+                            .map(module_export_name)
                             .unwrap_or_else(|| local_name.clone());
 
                         imports.push(ImportInfo {
@@ -295,6 +410,47 @@ impl KeyDataInternalState {
         }
     }
 
+    /// Collect `export { x as y } from "./module"` re-exports.
+    pub fn collect_named_re_export(
+        &mut self,
+        node: &swc_ecma_ast::NamedExport,
+        re_exports: &mut FileReExports,
+    ) {
+        let Some(module_path) = node.src.as_ref().and_then(|src| src.value.as_str()) else {
+            return;
+        };
+        for specifier in &node.specifiers {
+            if let ExportSpecifier::Named(named) = specifier {
+                let imported_name = module_export_name(&named.orig);
+                let exported_name = named
+                    .exported
+                    .as_ref()
+                    .map(module_export_name)
+                    .unwrap_or_else(|| imported_name.clone());
+                re_exports.push(ReExport {
+                    exported_name,
+                    imported_name,
+                    module_path: module_path.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Collect `export * from "./module"` re-exports.
+    pub fn collect_export_all(
+        &mut self,
+        node: &swc_ecma_ast::ExportAll,
+        re_exports: &mut FileReExports,
+    ) {
+        if let Some(module_path) = node.src.value.as_str() {
+            re_exports.push(ReExport {
+                exported_name: "*".to_string(),
+                imported_name: "*".to_string(),
+                module_path: module_path.to_string(),
+            });
+        }
+    }
+
     // ============================================================
     // Default export handling
     // ============================================================
@@ -414,10 +570,16 @@ impl KeyDataInternalState {
     // ============================================================
 
     /// Check a JSX element for translation props.
+    ///
+    /// A prop of the enclosing component passed on as is, like `t` in
+    /// `function Card({ t }) { return <CardTitle t={t} />; }`, is recorded as
+    /// a forward, resolved once every file is collected.
     pub fn check_jsx_element(
         &self,
         node: &JSXElement,
+        function_contexts: &[FunctionContext],
         translation_props: &mut Vec<TranslationProp>,
+        translation_prop_forwards: &mut Vec<TranslationPropForward>,
     ) {
         let component_name = match &node.opening.name {
             JSXElementName::Ident(ident) => {
@@ -454,6 +616,23 @@ impl KeyDataInternalState {
                                 namespace,
                                 translation_props,
                             );
+                        } else if matches!(
+                            self.get_binding_value(&var_name),
+                            Some(TranslationBindingValue::ShadowedParam)
+                        ) && let Some(function_context) = function_contexts
+                            .iter()
+                            .rev()
+                            .find(|context| context.declares(&var_name))
+                            && let Some(from_prop) = function_context.prop_name(&var_name)
+                        {
+                            for registry_name in &function_context.registry_names {
+                                translation_prop_forwards.push(TranslationPropForward {
+                                    from_component: registry_name.clone(),
+                                    from_prop: from_prop.to_string(),
+                                    to_component: comp_name.clone(),
+                                    to_prop: prop_name.clone(),
+                                });
+                            }
                         }
                     }
                 }
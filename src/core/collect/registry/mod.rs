@@ -7,19 +7,23 @@
 //!
 //! - `helpers`: Pure functions for AST node extraction
 //! - `schema`: Schema function detection and collection
-//! - `key_data`: Key objects, arrays, imports, and translation bindings
+//! - `key_data`: Key objects (including string enums), arrays, imports, re-exports, and
+//!   translation bindings
+//! - `provider`: `NextIntlClientProvider` boundaries and the namespaces they provide
 //!
 //! ## Single-Pass Optimization
 //!
 //! The collector merges multiple sub-collectors (schema functions, key objects, imports,
 //! translation bindings) into a single visitor to minimize AST traversals.
 
-mod helpers;
+pub(crate) mod helpers;
 mod key_data;
+mod provider;
 mod schema;
 
 use swc_ecma_ast::{
-    ArrowExpr, Decl, DefaultDecl, Expr, FnDecl, Function, Pat, VarDecl, VarDeclarator,
+    ArrowExpr, BlockStmt, Decl, DefaultDecl, Expr, FnDecl, Function, Pat, TsEnumDecl, VarDecl,
+    VarDeclarator,
 };
 use swc_ecma_visit::{Visit, VisitWith};
 
@@ -27,10 +31,12 @@ use key_data::{FunctionContext, KeyDataInternalState};
 use schema::SchemaInternalState;
 
 use crate::core::schema::SchemaFunction;
+use crate::core::utils::{normalize_call, opt_chain_call};
 
 use crate::core::collect::types::{
-    FileImports, KeyArray, KeyObject, StringArray, TranslationFnCall, TranslationFnForward,
-    TranslationProp, extract_binding_names,
+    FileImports, FileReExports, KeyArray, KeyObject, MessageProvider, StringArray,
+    TranslationFnCall, TranslationFnForward, TranslationProp, TranslationPropForward,
+    extract_binding_names,
 };
 
 /// Combined collector that gathers both schema functions and key objects
@@ -64,14 +70,20 @@ pub struct RegistryCollector {
     pub string_arrays: Vec<StringArray>,
     /// Collected import declarations.
     pub imports: FileImports,
+    /// Collected re-exports (`export * from`, `export { x } from`).
+    pub re_exports: FileReExports,
     /// Collected translation props (JSX components receiving translation functions).
     pub translation_props: Vec<TranslationProp>,
+    /// Forwarding edges between component props and props of rendered components.
+    pub translation_prop_forwards: Vec<TranslationPropForward>,
     /// Collected translation function calls.
     pub translation_fn_calls: Vec<TranslationFnCall>,
     /// Forwarding edges between function parameters and nested helper calls.
     pub translation_fn_forwards: Vec<TranslationFnForward>,
     /// Name of the default export, if any.
     pub default_export_name: Option<String>,
+    /// Collected `NextIntlClientProvider` elements.
+    pub message_providers: Vec<MessageProvider>,
 
     // === Internal state (private) ===
     /// Schema collection state.
@@ -92,10 +104,13 @@ impl RegistryCollector {
             arrays: Vec::new(),
             string_arrays: Vec::new(),
             imports: Vec::new(),
+            re_exports: Vec::new(),
             translation_props: Vec::new(),
+            translation_prop_forwards: Vec::new(),
             translation_fn_calls: Vec::new(),
             translation_fn_forwards: Vec::new(),
             default_export_name: None,
+            message_providers: Vec::new(),
             schema_state: SchemaInternalState::new(),
             key_data_state: KeyDataInternalState::new(),
             function_context_stack: Vec::new(),
@@ -130,6 +145,18 @@ impl RegistryCollector {
         );
     }
 
+    /// Check an enum declaration for a string enum.
+    fn check_ts_enum(&mut self, node: &TsEnumDecl, is_exported: bool) {
+        let is_module_level = self.scope_depth == 0;
+        self.key_data_state.check_ts_enum(
+            node,
+            is_exported,
+            is_module_level,
+            &self.file_path,
+            &mut self.objects,
+        );
+    }
+
     fn enter_function_context(&mut self, registry_names: Vec<String>, params: &[Pat]) -> bool {
         self.function_context_stack.push(FunctionContext::new(
             &self.file_path,
@@ -194,6 +221,11 @@ impl Visit for RegistryCollector {
             return;
         }
 
+        if let Decl::TsEnum(enum_decl) = &node.decl {
+            self.check_ts_enum(enum_decl, true);
+            return;
+        }
+
         node.visit_children_with(self);
     }
 
@@ -267,6 +299,10 @@ impl Visit for RegistryCollector {
         node.visit_children_with(self);
     }
 
+    fn visit_ts_enum_decl(&mut self, node: &TsEnumDecl) {
+        self.check_ts_enum(node, false);
+    }
+
     fn visit_var_declarator(&mut self, node: &VarDeclarator) {
         if let swc_ecma_ast::Pat::Ident(binding_ident) = &node.name
             && let Some(init) = &node.init
@@ -305,6 +341,16 @@ impl Visit for RegistryCollector {
         self.key_data_state.collect_import(node, &mut self.imports);
     }
 
+    fn visit_named_export(&mut self, node: &swc_ecma_ast::NamedExport) {
+        self.key_data_state
+            .collect_named_re_export(node, &mut self.re_exports);
+    }
+
+    fn visit_export_all(&mut self, node: &swc_ecma_ast::ExportAll) {
+        self.key_data_state
+            .collect_export_all(node, &mut self.re_exports);
+    }
+
     fn visit_function(&mut self, node: &Function) {
         let params: Vec<_> = node.params.iter().map(|param| param.pat.clone()).collect();
         let shadows_t = self.enter_function_context(Vec::new(), &params);
@@ -318,7 +364,26 @@ impl Visit for RegistryCollector {
         self.exit_function_context(shadows_t);
     }
 
+    fn visit_block_stmt(&mut self, node: &BlockStmt) {
+        // Block-scoped declarations must not leak past the closing brace
+        self.key_data_state.enter_scope();
+        node.visit_children_with(self);
+        self.key_data_state.exit_scope();
+    }
+
+    fn visit_opt_chain_expr(&mut self, node: &swc_ecma_ast::OptChainExpr) {
+        match opt_chain_call(node) {
+            Some(call) => self.visit_call_expr(&call),
+            None => node.visit_children_with(self),
+        }
+    }
+
     fn visit_call_expr(&mut self, node: &swc_ecma_ast::CallExpr) {
+        // t!("key") and t!.raw("key") are handled like plain calls
+        if let Some(call) = normalize_call(node) {
+            return self.visit_call_expr(&call);
+        }
+
         // Schema logic: check for t() calls
         self.schema_state.check_call_expr(node);
 
@@ -337,8 +402,17 @@ impl Visit for RegistryCollector {
 
     fn visit_jsx_element(&mut self, node: &swc_ecma_ast::JSXElement) {
         // Key data logic: check for translation props
-        self.key_data_state
-            .check_jsx_element(node, &mut self.translation_props);
+        self.key_data_state.check_jsx_element(
+            node,
+            &self.function_context_stack,
+            &mut self.translation_props,
+            &mut self.translation_prop_forwards,
+        );
+
+        // Provider logic: record namespaces passed to client components
+        if let Some(provider) = provider::collect_message_provider(node) {
+            self.message_providers.push(provider);
+        }
 
         node.visit_children_with(self);
     }
@@ -0,0 +1,121 @@
+//! `NextIntlClientProvider` detection.
+//!
+//! Records which namespaces a `<NextIntlClientProvider messages={...}>` passes
+//! to client components and which components are rendered inside it.
+//!
+//! Recognized `messages` values:
+//! - `pick(messages, "Settings")` / `pick(messages, ["Settings", "Common"])`
+//! - `{ Settings: messages.Settings, Common }`
+//!
+//! Any other value (usually the whole `messages` object) provides every
+//! namespace and is recorded with `namespaces: None`.
+
+use swc_ecma_ast::{
+    Callee, Expr, JSXAttrValue, JSXElement, JSXElementName, JSXExpr, Lit, ObjectLit, Prop,
+    PropOrSpread,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use super::helpers::{
+    extract_jsx_member_name, extract_prop_name, extract_string_array, find_jsx_attr, unwrap_ts_expr,
+};
+use crate::core::collect::types::MessageProvider;
+
+/// Name of the next-intl client provider component.
+const PROVIDER_NAME: &str = "NextIntlClientProvider";
+
+/// Collect a `MessageProvider` if `node` is a `NextIntlClientProvider` element.
+pub fn collect_message_provider(node: &JSXElement) -> Option<MessageProvider> {
+    if !is_provider(node) {
+        return None;
+    }
+
+    let namespaces = match find_jsx_attr(node, "messages") {
+        Some(JSXAttrValue::JSXExprContainer(container)) => match &container.expr {
+            JSXExpr::Expr(expr) => Some(extract_provided_namespaces(expr)),
+            JSXExpr::JSXEmptyExpr(_) => None,
+        },
+        _ => None,
+    };
+
+    let mut collector = ComponentCollector::default();
+    node.children.visit_with(&mut collector);
+
+    Some(MessageProvider {
+        components: collector.components,
+        namespaces: namespaces.flatten(),
+    })
+}
+
+fn is_provider(node: &JSXElement) -> bool {
+    matches!(&node.opening.name, JSXElementName::Ident(ident) if ident.sym == PROVIDER_NAME)
+}
+
+/// Top-level namespaces selected by a `messages` value, or `None` for all.
+fn extract_provided_namespaces(expr: &Expr) -> Option<Vec<String>> {
+    match unwrap_ts_expr(expr) {
+        Expr::Call(call) => {
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            if !matches!(&**callee, Expr::Ident(ident) if ident.sym == "pick") {
+                return None;
+            }
+            match call.args.get(1).map(|arg| unwrap_ts_expr(&arg.expr)) {
+                Some(Expr::Lit(Lit::Str(s))) => s.value.as_str().map(|s| vec![s.to_string()]),
+                Some(Expr::Array(arr)) => extract_string_array(arr),
+                _ => None,
+            }
+        }
+        Expr::Object(obj) => extract_object_namespaces(obj),
+        _ => None,
+    }
+}
+
+/// Property names of `{ Settings: messages.Settings, Common }`; `None` on spread.
+fn extract_object_namespaces(obj: &ObjectLit) -> Option<Vec<String>> {
+    obj.props
+        .iter()
+        .map(|prop| match prop {
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::KeyValue(kv) => extract_prop_name(&kv.key),
+                Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+                _ => None,
+            },
+            PropOrSpread::Spread(_) => None,
+        })
+        .collect()
+}
+
+/// Names of the components rendered inside a provider.
+///
+/// Stops at nested providers, which are collected on their own.
+#[derive(Default)]
+struct ComponentCollector {
+    components: Vec<String>,
+}
+
+impl Visit for ComponentCollector {
+    fn visit_jsx_element(&mut self, node: &JSXElement) {
+        if is_provider(node) {
+            return;
+        }
+
+        let name = match &node.opening.name {
+            JSXElementName::Ident(ident)
+                if ident.sym.chars().next().is_some_and(|c| c.is_uppercase()) =>
+            {
+                Some(ident.sym.to_string())
+            }
+            JSXElementName::JSXMemberExpr(member) => Some(extract_jsx_member_name(member)),
+            _ => None,
+        };
+        if let Some(name) = name
+            && !self.components.contains(&name)
+        {
+            self.components.push(name);
+        }
+
+        node.visit_children_with(self);
+    }
+}
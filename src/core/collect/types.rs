@@ -12,6 +12,7 @@
 //! All types in this module are **created in Phase 1** by `RegistryCollector`
 //! and **consumed in Phase 2** by `FileAnalyzer` to resolve translation calls.
 
+use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
     path::Path,
@@ -19,7 +20,7 @@ use std::{
 
 use swc_ecma_ast::{ObjectPatProp, Pat};
 
-use crate::core::schema::SchemaRegistry;
+use crate::core::schema::{SchemaFunction, SchemaRegistry};
 
 /// Object literal containing translation key candidates.
 ///
@@ -32,7 +33,7 @@ use crate::core::schema::SchemaRegistry;
 /// ```
 ///
 /// Enables resolution of dynamic keys like `t(toolKeys[action])` in Phase 2.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyObject {
     /// Variable name (e.g., "toolKeys").
     pub name: String,
@@ -45,6 +46,10 @@ pub struct KeyObject {
     pub is_module_level: bool,
     /// All string values from the object (e.g., ["tools.create", "tools.edit"]).
     pub candidate_keys: Vec<String>,
+    /// Property names of the object (e.g., ["create", "edit"]), for
+    /// `Object.keys(obj)` iteration. Empty when they can't all be resolved.
+    #[serde(default)]
+    pub property_names: Vec<String>,
 }
 
 /// Registry of all objects that may contain translation key mappings.
@@ -62,7 +67,7 @@ pub type KeyObjectRegistry = HashMap<String, KeyObject>;
 /// import { toolKeys as keys } from "./constants";
 /// t(keys[action]); // Resolve 'keys' back to 'toolKeys' in "./constants"
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportInfo {
     /// Local name in the importing file (e.g., "keys").
     pub local_name: String,
@@ -78,6 +83,35 @@ pub struct ImportInfo {
 /// **Phase 2**: Used by `ValueAnalyzer` to resolve imported variable references
 pub type FileImports = Vec<ImportInfo>;
 
+/// Re-export statement in a barrel file.
+///
+/// Tracks names a file passes through from another module, so an import from
+/// the barrel can be traced to the file that defines the name:
+/// ```typescript
+/// export * from "./keys";                     // exported_name: "*"
+/// export { toolKeys as keys } from "./tools"; // exported_name: "keys"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReExport {
+    /// Name the barrel exports (e.g., "keys"), or "*" for `export * from`.
+    pub exported_name: String,
+    /// Name in the re-exported module (e.g., "toolKeys"), or "*" for `export * from`.
+    pub imported_name: String,
+    /// Re-export source path (e.g., "./tools").
+    pub module_path: String,
+}
+
+/// All re-exports of a single file.
+///
+/// **Phase 1**: Created by `RegistryCollector`
+/// **Phase 2**: Used by `ValueAnalyzer` to follow imports through barrel files
+pub type FileReExports = Vec<ReExport>;
+
+/// Re-exports of every barrel file, indexed by file path.
+///
+/// **Key format**: File path (relative to source root)
+pub type ReExportRegistry = HashMap<String, FileReExports>;
+
 /// Array of objects containing translation key candidates.
 ///
 /// Used for patterns like:
@@ -89,7 +123,7 @@ pub type FileImports = Vec<ImportInfo>;
 /// ```
 ///
 /// Enables resolution of patterns like `capabilities.map(c => t(c.titleKey))` in Phase 2.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyArray {
     /// Variable name (e.g., "capabilities").
     pub name: String,
@@ -121,7 +155,7 @@ pub type KeyArrayRegistry = HashMap<String, KeyArray>;
 /// ```
 ///
 /// Enables resolution of patterns like `FEATURE_KEYS.map(k => t(\`features.\${k}\`))` in Phase 2.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StringArray {
     /// Variable name (e.g., "FEATURE_KEYS").
     pub name: String,
@@ -154,7 +188,7 @@ pub type StringArrayRegistry = HashMap<String, StringArray>;
 ///
 /// In Phase 2, when analyzing `MyComponent`, we check if it receives a translation
 /// prop and register the prop parameter as a translation binding.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationProp {
     /// Component name receiving the prop (e.g., "MyComponent", "UI.Button").
     pub component_name: String,
@@ -166,6 +200,29 @@ pub struct TranslationProp {
     pub namespaces: Vec<Option<String>>,
 }
 
+/// Forwarding edge for a component prop passed on as a prop of another component.
+///
+/// Example:
+/// ```typescript
+/// function Card({ t }) {
+///   return <CardTitle t={t} />;
+/// }
+/// ```
+///
+/// If `Card` is later proven to receive a translation function as `t`, this
+/// edge lets us propagate its namespaces to `CardTitle.t`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslationPropForward {
+    /// Component forwarding the prop (e.g., "Card").
+    pub from_component: String,
+    /// Prop of the forwarding component (e.g., "t").
+    pub from_prop: String,
+    /// Component receiving the prop, as written in the JSX (e.g., "CardTitle").
+    pub to_component: String,
+    /// Prop of the receiving component.
+    pub to_prop: String,
+}
+
 /// Registry of translation functions passed as JSX props.
 ///
 /// **Phase 1**: Created by `RegistryCollector` when it sees `<Component t={translationVar} />`
@@ -189,6 +246,35 @@ pub fn make_translation_prop_key(component_name: &str, prop_name: &str) -> Strin
     format!("{}.{}", component_name, prop_name)
 }
 
+/// `NextIntlClientProvider` element and the components rendered inside it.
+///
+/// Used for patterns like:
+/// ```typescript
+/// <NextIntlClientProvider messages={pick(messages, ["Settings"])}>
+///   <SettingsForm />
+/// </NextIntlClientProvider>
+/// ```
+///
+/// Client components rendered inside the provider only receive the messages
+/// of the listed namespaces.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageProvider {
+    /// Components rendered inside the provider (e.g., "SettingsForm", "UI.Dialog").
+    pub components: Vec<String>,
+    /// Top-level namespaces passed via the `messages` prop.
+    /// `None` when all messages are passed or the prop can't be read statically.
+    pub namespaces: Option<Vec<String>>,
+}
+
+/// Registry of namespaces provided to client components.
+///
+/// **Phase 1**: Created by `RegistryCollector` when it sees `<NextIntlClientProvider messages={...}>`
+/// **Phase 2**: Used by `FileAnalyzer` to attach provided namespaces to translation calls
+///
+/// **Key format**: Component name (e.g., `"SettingsForm"`). Components that are
+/// also rendered inside a provider passing all messages are left out.
+pub type MessageProviderRegistry = HashMap<String, Vec<String>>;
+
 /// Translation function passed as a regular function call argument.
 ///
 /// Used for patterns like:
@@ -202,7 +288,7 @@ pub fn make_translation_prop_key(component_name: &str, prop_name: &str) -> Strin
 ///
 /// In Phase 2, when analyzing the definition of `myHelper`, we check if it receives
 /// a translation function argument and register the parameter as a translation binding.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationFnCall {
     /// File where the called function is defined (relative to source root).
     pub fn_file_path: String,
@@ -227,7 +313,7 @@ pub struct TranslationFnCall {
 ///
 /// If `createOtpSchema` parameter 0 is later proven to be a translation function,
 /// this edge lets us propagate that knowledge to `otpField` argument 0.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationFnForward {
     /// File where the caller function is defined.
     pub from_fn_file_path: String,
@@ -353,30 +439,87 @@ pub fn make_registry_key(file_path: &str, name: &str) -> String {
 // ============================================================
 
 /// Rules that can be suppressed via glot comments.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum, Serialize, Deserialize)]
 pub enum SuppressibleRule {
     Hardcoded,
     Untranslated,
+    /// Not offered by `baseline`: keys are defined in JSON, which has no comments.
+    #[value(skip)]
+    KeyNaming,
+    /// Not offered by `baseline`, for the same reason as `KeyNaming`.
+    #[value(skip)]
+    MarkupOnly,
+    /// Not offered by `baseline`: the rule is off by default.
+    #[value(skip)]
+    UnmatchedMessageKeys,
+    /// Not offered by `baseline`, for the same reason as `UnmatchedMessageKeys`.
+    #[value(skip)]
+    NamespaceConsistency,
+    /// Not offered by `baseline`: a call missing values throws at runtime, so
+    /// it should be fixed rather than suppressed.
+    #[value(skip)]
+    InterpolationMismatch,
+    /// Not offered by `baseline`, for the same reason as `InterpolationMismatch`.
+    #[value(skip)]
+    EmptyKey,
 }
 
 /// Range representing disabled lines [start, end] inclusive.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DisabledRange {
     pub start: usize,
     pub end: usize, // usize::MAX for open-ended
 }
 
+/// A `glot-disable-next-line` comment and the line it applies to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NextLineDirective {
+    /// Line of the comment itself.
+    pub line: usize,
+    /// First non-comment line after it.
+    pub target_line: usize,
+    pub rules: HashSet<SuppressibleRule>,
+}
+
+/// A `glot-enable` or `glot-disable` comment without its counterpart.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnpairedDirective {
+    /// Line of the comment.
+    pub line: usize,
+    /// Column of the comment (1-based).
+    pub col: usize,
+    /// Source line of the comment, for display.
+    pub source_line: String,
+    pub kind: UnpairedDirectiveKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnpairedDirectiveKind {
+    /// `glot-enable` with no open `glot-disable` for any of its rules.
+    UnmatchedEnable,
+    /// `glot-disable` whose range is still open at the end of the file.
+    UnterminatedDisable,
+}
+
 /// Tracks suppressed lines per rule for a single file.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Suppressions {
     /// Single-line suppressions: rule -> set of line numbers
     pub disabled_lines: HashMap<SuppressibleRule, HashSet<usize>>,
-    /// Range-based suppressions: rule -> list of ranges
+    /// Range-based suppressions: rule -> list of ranges.
+    /// A range starts at its `glot-disable` comment and ends the line before `glot-enable`;
+    /// a `glot-disable-file` range covers the whole file.
     pub disabled_ranges: HashMap<SuppressibleRule, Vec<DisabledRange>>,
+    /// The `glot-disable-next-line` comments behind `disabled_lines`.
+    #[serde(default)]
+    pub next_line_directives: Vec<NextLineDirective>,
+    /// `glot-enable`/`glot-disable` comments that don't pair up, by line.
+    #[serde(default)]
+    pub unpaired_directives: Vec<UnpairedDirective>,
 }
 
 /// Key declaration for a single line.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyDeclaration {
     /// Absolute patterns (e.g., "Common.key", "errors.*")
     /// These patterns are fully qualified and ready for expansion.
@@ -387,7 +530,7 @@ pub struct KeyDeclaration {
 }
 
 /// Stores parsed glot-message-keys declarations for a file.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Declarations {
     /// Line number -> key declaration
     pub entries: HashMap<usize, KeyDeclaration>,
@@ -399,7 +542,7 @@ pub struct Declarations {
 ///
 /// **Phase 1**: Collected by comment parsers alongside other registries
 /// **Phase 2**: Passed to `FileAnalyzer` to check suppressions and expand declarations
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FileComments {
     /// Suppression directives (glot-disable, glot-enable, glot-disable-next-line).
     /// Checked during Phase 2 to skip reporting suppressed issues.
@@ -418,6 +561,33 @@ pub struct FileComments {
 /// **Key format**: File path (relative to source root)
 pub type AllFileComments = HashMap<String, FileComments>;
 
+/// Everything Phase 1 collects from a single file.
+///
+/// **Phase 1**: Built from a `RegistryCollector` and the file's comments
+/// **Phase 1 merge**: Combined across files into `Registries`, `AllFileImports`
+/// and `AllFileComments`
+///
+/// Kept as a standalone value so it can be cached between runs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CollectedFile {
+    pub schema_functions: Vec<SchemaFunction>,
+    pub objects: Vec<KeyObject>,
+    pub arrays: Vec<KeyArray>,
+    pub string_arrays: Vec<StringArray>,
+    pub imports: FileImports,
+    #[serde(default)]
+    pub re_exports: FileReExports,
+    pub translation_props: Vec<TranslationProp>,
+    #[serde(default)]
+    pub translation_prop_forwards: Vec<TranslationPropForward>,
+    pub translation_fn_calls: Vec<TranslationFnCall>,
+    pub translation_fn_forwards: Vec<TranslationFnForward>,
+    pub default_export_name: Option<String>,
+    #[serde(default)]
+    pub message_providers: Vec<MessageProvider>,
+    pub comments: FileComments,
+}
+
 // ============================================================
 // Aggregated Registry Types
 // ============================================================
@@ -447,6 +617,10 @@ pub struct Registries {
     /// Used to resolve `arr.map(k => k)` or `arr[0]` expressions.
     pub string_array: StringArrayRegistry,
 
+    /// Re-exports of barrel files (`export * from`, `export { x } from`).
+    /// Used to trace imports from a barrel to the file that defines the name.
+    pub re_exports: ReExportRegistry,
+
     /// Translation functions passed as JSX props.
     /// Used to register translation bindings when entering component definitions.
     pub translation_prop: TranslationPropRegistry,
@@ -459,6 +633,10 @@ pub struct Registries {
     /// Used to match translation function calls with default imported functions.
     /// Example: `{ "src/utils.ts": "myHelper" }` means `utils.ts` exports `myHelper` as default.
     pub default_exports: HashMap<String, String>,
+
+    /// Namespaces passed to components by `NextIntlClientProvider`.
+    /// Used to associate translation calls in client components with the provided namespaces.
+    pub message_provider: MessageProviderRegistry,
 }
 
 /// All file imports across the codebase, indexed by file path.
@@ -568,6 +746,125 @@ mod tests {
         assert_eq!(collector.objects[0].name, "base");
     }
 
+    #[test]
+    fn test_collect_object_with_as_const_satisfies() {
+        let code = r#"
+            const keys = {
+                a: "alpha",
+            } as const satisfies Record<string, string>;
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.objects.len(), 1);
+        assert_eq!(collector.objects[0].candidate_keys, vec!["alpha"]);
+    }
+
+    #[test]
+    fn test_collect_object_with_computed_const_keys() {
+        let code = r#"
+            const CREATE = "create";
+            const keys = {
+                [CREATE]: "createNovel",
+                ["update"]: "updateNovel",
+                [UNKNOWN]: "skipped",
+                [`${CREATE}2`]: "alsoSkipped",
+            };
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.objects.len(), 1);
+        assert_eq!(
+            collector.objects[0].candidate_keys,
+            vec!["createNovel", "updateNovel"]
+        );
+    }
+
+    #[test]
+    fn test_computed_key_requires_module_level_const() {
+        let code = r#"
+            let MUTABLE = "a";
+            function build() {
+                const LOCAL = "b";
+                const inner = { [LOCAL]: "local" };
+                return inner;
+            }
+            const keys = { [MUTABLE]: "mutable", ok: "kept" };
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.objects.len(), 1);
+        assert_eq!(collector.objects[0].candidate_keys, vec!["kept"]);
+    }
+
+    #[test]
+    fn test_collect_object_with_numeric_keys() {
+        let code = r#"
+            const statusKeys = {
+                404: "notFound",
+                500: "serverError",
+                [401]: "unauthorized",
+            };
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.objects.len(), 1);
+        assert_eq!(
+            collector.objects[0].candidate_keys,
+            vec!["notFound", "serverError", "unauthorized"]
+        );
+    }
+
+    #[test]
+    fn test_skip_object_with_spread_and_computed_keys() {
+        let code = r#"
+            const KEY = "key";
+            const base = { a: "a" };
+            const extended = { ...base, [KEY]: "b" };
+        "#;
+        let collector = parse_and_collect(code);
+
+        // Spread is still unsupported, even when the other keys resolve
+        assert_eq!(collector.objects.len(), 1);
+        assert_eq!(collector.objects[0].name, "base");
+    }
+
+    #[test]
+    fn test_collect_string_enums() {
+        let code = r#"
+            export enum Tool {
+                Create = "tools.create",
+                "Edit" = "tools.edit",
+            }
+            const enum Local {
+                A = "a",
+            }
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.objects.len(), 2);
+        assert_eq!(collector.objects[0].name, "Tool");
+        assert!(collector.objects[0].is_exported);
+        assert_eq!(
+            collector.objects[0].candidate_keys,
+            vec!["tools.create", "tools.edit"]
+        );
+        assert_eq!(collector.objects[0].property_names, vec!["Create", "Edit"]);
+        assert_eq!(collector.objects[1].name, "Local");
+        assert!(!collector.objects[1].is_exported);
+    }
+
+    #[test]
+    fn test_skip_numeric_enums() {
+        let code = r#"
+            enum Status { Draft, Published }
+            enum Code { NotFound = 404 }
+            enum Mixed { A = "a", B = 1 }
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert!(collector.objects.is_empty());
+    }
+
     #[test]
     fn test_collect_imports() {
         let code = r#"
@@ -606,6 +903,37 @@ mod tests {
         assert_eq!(collector.imports[0].imported_name, "default");
     }
 
+    #[test]
+    fn test_collect_re_exports() {
+        let code = r#"
+            export * from "./keys";
+            export { toolKeys as keys, otherKeys } from "./tools";
+            const local = 1;
+            export { local };
+        "#;
+        let collector = parse_and_collect(code);
+
+        let re_exports: Vec<_> = collector
+            .re_exports
+            .iter()
+            .map(|r| {
+                (
+                    r.exported_name.as_str(),
+                    r.imported_name.as_str(),
+                    r.module_path.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            re_exports,
+            vec![
+                ("*", "*", "./keys"),
+                ("keys", "toolKeys", "./tools"),
+                ("otherKeys", "otherKeys", "./tools"),
+            ]
+        );
+    }
+
     #[test]
     fn test_skip_non_string_values() {
         let code = r#"
@@ -940,6 +1268,44 @@ mod tests {
         assert_eq!(prop.namespaces, vec![Some("MyNamespace".to_string())]);
     }
 
+    #[test]
+    fn test_collect_translation_prop_forward() {
+        let code = r#"
+            export function Card({ t: translate, title }) {
+                return (
+                    <div>
+                        <CardTitle t={translate} text={title} />
+                        {items.map((item) => <CardItem label={translate} />)}
+                        {items.map((translate) => <Ignored t={translate} />)}
+                    </div>
+                );
+            }
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert!(collector.translation_props.is_empty());
+        let forwards: Vec<_> = collector
+            .translation_prop_forwards
+            .iter()
+            .map(|f| {
+                (
+                    f.from_component.as_str(),
+                    f.from_prop.as_str(),
+                    f.to_component.as_str(),
+                    f.to_prop.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            forwards,
+            vec![
+                ("Card", "t", "CardTitle", "t"),
+                ("Card", "title", "CardTitle", "text"),
+                ("Card", "t", "CardItem", "label"),
+            ]
+        );
+    }
+
     #[test]
     fn test_collect_translation_prop_without_namespace() {
         let code = r#"
@@ -970,6 +1336,20 @@ mod tests {
         assert_eq!(prop.namespaces, vec![Some("ServerNs".to_string())]);
     }
 
+    #[test]
+    fn test_collect_translation_prop_with_create_translator() {
+        let code = r#"
+            const t = createTranslator({ locale, messages, namespace: "CoreNs" });
+            <ServerComponent t={t} />;
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.translation_props.len(), 1);
+        let prop = &collector.translation_props[0];
+        assert_eq!(prop.component_name, "ServerComponent");
+        assert_eq!(prop.namespaces, vec![Some("CoreNs".to_string())]);
+    }
+
     #[test]
     fn test_collect_multiple_translation_props() {
         let code = r#"
@@ -1039,6 +1419,30 @@ mod tests {
         assert_eq!(prop.prop_name, "t");
     }
 
+    #[test]
+    fn test_skip_formatter_bound_to_t() {
+        let code = r#"
+            function Price({ value }) {
+                const t = useTranslations("Shop");
+                if (value) {
+                    const t = useFormatter();
+                    formatTotal(t);
+                    return <Total t={t} value={t.number(value)} />;
+                }
+                return <Total t={t} />;
+            }
+        "#;
+        let collector = parse_and_collect(code);
+
+        // Only the outer `t` from useTranslations is a translation binding
+        assert_eq!(collector.translation_props.len(), 1);
+        assert_eq!(
+            collector.translation_props[0].namespaces,
+            vec![Some("Shop".to_string())]
+        );
+        assert!(collector.translation_fn_calls.is_empty());
+    }
+
     #[test]
     fn test_skip_lowercase_elements() {
         let code = r#"
@@ -1140,6 +1544,31 @@ mod tests {
         assert_eq!(call.namespaces, vec![Some("ServerNs".to_string())]);
     }
 
+    #[test]
+    fn test_collect_translation_fn_call_with_get_translator() {
+        let code = r#"
+            const t = await getTranslator({ locale: "en", namespace: "ServerNs" });
+            processTranslations(t);
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.translation_fn_calls.len(), 1);
+        let call = &collector.translation_fn_calls[0];
+        assert_eq!(call.namespaces, vec![Some("ServerNs".to_string())]);
+    }
+
+    #[test]
+    fn test_collect_translation_fn_call_translator_ignores_positional_string() {
+        let code = r#"
+            const t = createTranslator("en");
+            processTranslations(t);
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.translation_fn_calls.len(), 1);
+        assert_eq!(collector.translation_fn_calls[0].namespaces, vec![None]);
+    }
+
     #[test]
     fn test_collect_translation_fn_call_multiple_functions() {
         let code = r#"
@@ -1212,6 +1641,65 @@ mod tests {
         assert!(collector.translation_fn_calls.is_empty());
     }
 
+    // ============================================================
+    // Message provider collection tests
+    // ============================================================
+
+    #[test]
+    fn test_collect_message_provider_with_pick() {
+        let code = r#"
+            <NextIntlClientProvider messages={pick(messages, ["Settings", "Common"])}>
+                <Header />
+                <main>{open && <UI.Dialog><SettingsForm /></UI.Dialog>}</main>
+            </NextIntlClientProvider>;
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.message_providers.len(), 1);
+        let provider = &collector.message_providers[0];
+        assert_eq!(
+            provider.components,
+            vec!["Header", "UI.Dialog", "SettingsForm"]
+        );
+        assert_eq!(
+            provider.namespaces,
+            Some(vec!["Settings".to_string(), "Common".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_collect_message_provider_namespaces() {
+        let code = r#"
+            <NextIntlClientProvider messages={{ Auth: messages.Auth, Common }}>
+                <LoginForm />
+            </NextIntlClientProvider>;
+            <NextIntlClientProvider messages={messages}>
+                <App />
+                <NextIntlClientProvider messages={pick(messages, "Nested")}>
+                    <Inner />
+                </NextIntlClientProvider>
+            </NextIntlClientProvider>;
+        "#;
+        let collector = parse_and_collect(code);
+
+        let providers: Vec<_> = collector
+            .message_providers
+            .iter()
+            .map(|p| (p.components.clone(), p.namespaces.clone()))
+            .collect();
+        assert_eq!(
+            providers,
+            vec![
+                (
+                    vec!["LoginForm".to_string()],
+                    Some(vec!["Auth".to_string(), "Common".to_string()])
+                ),
+                (vec!["App".to_string()], None),
+                (vec!["Inner".to_string()], Some(vec!["Nested".to_string()])),
+            ]
+        );
+    }
+
     // ============================================================
     // Default export tracking tests
     // ============================================================
@@ -1526,16 +2014,31 @@ mod tests {
     }
 
     #[test]
-    fn test_react_i18next_renamed_t_not_tracked() {
+    fn test_react_i18next_renamed_t_tracked() {
         // Renaming: const { t: translate } = useTranslation("ns")
-        // "translate" is not "t", so it should NOT be tracked
         let code = r#"
             const { t: translate } = useTranslation("MyNs");
             <MyComponent translate={translate} />;
         "#;
         let collector = parse_and_collect(code);
 
-        // translate is not tracked as a translation binding
-        assert_eq!(collector.translation_props.len(), 0);
+        assert_eq!(collector.translation_props.len(), 1);
+        let prop = &collector.translation_props[0];
+        assert_eq!(prop.prop_name, "translate");
+        assert_eq!(prop.namespaces, vec![Some("MyNs".to_string())]);
+    }
+
+    #[test]
+    fn test_translation_binding_from_array_literal() {
+        let code = r#"
+            const [t, format] = [useTranslations("MyNs"), useFormatter()];
+            <MyComponent t={t} format={format} />;
+        "#;
+        let collector = parse_and_collect(code);
+
+        assert_eq!(collector.translation_props.len(), 1);
+        let prop = &collector.translation_props[0];
+        assert_eq!(prop.prop_name, "t");
+        assert_eq!(prop.namespaces, vec![Some("MyNs".to_string())]);
     }
 }
@@ -2,29 +2,35 @@ use std::{
     cell::OnceCell,
     collections::HashSet,
     path::{Component, Path, PathBuf},
+    time::{Duration, Instant},
 };
 
-use anyhow::{Context as _, Result, anyhow};
+use anyhow::{Context as _, Result, anyhow, bail};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use swc_ecma_visit::VisitWith;
 
 use crate::{
     cli::args::CommonArgs,
     config::{Config, load_config},
     core::{
-        AllKeyUsages, AllLocaleMessages, LocaleMessages,
+        AllKeyUsages, AllLocaleMessages, FileKeyUsages, LocaleMessages,
+        cache::{self, CacheEntry, DEFAULT_CACHE_FILE, SourceCache},
         collect::{
-            AllFileComments, AllFileImports, CommentCollector, FileImports, Registries,
-            RegistryCollector, TranslationFnCall, TranslationProp, make_registry_key,
+            AllFileComments, AllFileImports, CollectedFile, CommentCollector, FileComments,
+            FileImports, MessageProvider, MessageProviderRegistry, Registries, RegistryCollector,
+            TranslationFnCall, TranslationProp, TranslationPropForward, make_registry_key,
             make_translation_fn_call_key, make_translation_prop_key, resolve_import_path,
         },
-        extract::FileAnalyzer,
-        file_scanner::scan_files,
+        extract::{FileAnalysisResult, FileAnalyzer, RawTranslationCall},
+        file_scanner::{is_scannable_file, scan_files},
         parsers::{
             astro::parse_astro_source,
-            json::scan_message_files,
+            json::{LocaleFilter, ScanMessagesResult, scan_message_files, scan_message_pattern},
             jsx::{ParsedJSX, parse_jsx_source},
+            vue::{VUE_TRANSLATION_CALLEES, parse_vue_source, scan_sfc_messages},
         },
+        profile::Profile,
         resolve::resolve_translation_calls,
         schema::{ExpandResult, SchemaRegistry, expand_schema_keys},
     },
@@ -85,6 +91,28 @@ pub struct ResolvedData {
 
     /// Hardcoded text issues found during extraction (directly reportable).
     pub hardcoded_issues: AllHardcodedTextIssues,
+
+    /// Namespaces passed to translation hooks across all files.
+    pub namespaces: HashSet<String>,
+}
+
+/// Phase 2+3 output for a single file, merged into `ResolvedData`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileResolvedData {
+    pub key_usages: FileKeyUsages,
+    pub hardcoded_issues: Vec<HardcodedTextIssue>,
+    pub namespaces: HashSet<String>,
+}
+
+/// Phase 2+3 output for a single file, with the Phase 2 calls kept.
+///
+/// Built on demand by `CheckContext::trace_file` for `check --explain-resolution`.
+#[derive(Debug)]
+pub struct FileTrace {
+    /// Translation calls as Phase 2 extracted them.
+    pub raw_calls: Vec<RawTranslationCall>,
+    /// What Phase 3 resolved them to.
+    pub key_usages: FileKeyUsages,
 }
 
 /// Core analysis context orchestrating the three-phase pipeline.
@@ -122,15 +150,31 @@ pub struct CheckContext {
     /// Project root directory (for resolving relative paths).
     pub root_dir: PathBuf,
 
+    /// Directory of the config file, or the source root without one.
+    ///
+    /// Reported issue locations are printed relative to it with the default
+    /// `pathStyle`; issues keep the paths they were found under.
+    pub config_root: PathBuf,
+
+    /// Root of the config's `projects` entry being checked, as written in the
+    /// config (e.g. `apps/web`). `None` without `projects`.
+    pub project: Option<String>,
+
     /// All source files to analyze (TSX/JSX/TS/JS/Astro).
     pub files: HashSet<String>,
 
     /// Hardcoded texts to ignore (from config `ignoreTexts`).
     pub ignore_texts: HashSet<String>,
 
+    /// Attributes checked for hardcoded text (see `Config::text_attributes()`).
+    pub checked_attributes: Vec<String>,
+
     /// Whether to print verbose diagnostic messages.
     pub verbose: bool,
 
+    /// Parse cache file, when running with `--cache`.
+    pub cache_path: Option<PathBuf>,
+
     // ============================================================
     // Lazily initialized pipeline data
     // ============================================================
@@ -160,6 +204,22 @@ pub struct CheckContext {
 
     /// Parse errors from message files (collected during context creation).
     message_parse_errors: Vec<ParseErrorIssue>,
+
+    /// Report issues on suppressed lines as well (see `include_suppressed()`).
+    include_suppressed: bool,
+
+    /// In-memory content of source files, by path (see `for_source()` and
+    /// `from_sources()`).
+    source_overrides: HashMap<String, String>,
+
+    /// Locales given to the locale comparison rules (see `only_compare_locales()`).
+    compared_messages: Option<AllLocaleMessages>,
+
+    /// Parse cache of the last `--cache` run, kept for `update()`.
+    source_cache: OnceCell<SourceCache>,
+
+    /// Time spent in each phase, for `check --profile`.
+    profile: Profile,
 }
 
 impl CheckContext {
@@ -178,36 +238,96 @@ impl CheckContext {
     /// - Primary locale messages are not found
     /// - Messages directory doesn't exist
     pub fn new(common_args: &CommonArgs) -> Result<Self> {
-        let verbose = common_args.verbose;
+        Self::load(common_args, None)
+    }
 
-        // ============================================================
-        // 1. Determine source_root for config file search
-        // ============================================================
-        // Priority: CLI --source-root arg > current directory
-        let source_root = common_args
-            .source_root
-            .clone()
-            .unwrap_or_else(|| PathBuf::from("."));
-
-        let root_dir = source_root.clone();
-        let path = source_root
-            .to_str()
-            .with_context(|| anyhow!("Invalid path: {:?}", source_root))?;
+    /// Create a `CheckContext` for a single file whose content is in memory.
+    ///
+    /// Used by `check --stdin`. The project is not scanned: `file_path` is the
+    /// only source file, so Phase 1 registries and imports come from it alone.
+    /// Messages are loaded as usual. The parse cache is not used.
+    pub fn for_source(common_args: &CommonArgs, file_path: &str, content: String) -> Result<Self> {
+        Self::load(common_args, Some(vec![(file_path.to_string(), content)]))
+    }
 
-        // ============================================================
-        // 2. Load config from source_root
-        // ============================================================
-        let config_result = load_config(Path::new(path))?;
+    /// Create a `CheckContext` from an explicit config and in-memory source files.
+    ///
+    /// Used to embed glot as a library (see `glot::analyze()`): no config file
+    /// is loaded and the current directory is not used. `files` maps paths to
+    /// contents and are the only source files, checked as given (`includes`
+    /// and `ignores` don't apply). Relative paths, `messagesRoot` and
+    /// `messagePattern` are resolved against `root_dir`; messages are still
+    /// loaded from there.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the primary locale messages are not found.
+    pub fn from_sources(
+        config: Config,
+        root_dir: impl Into<PathBuf>,
+        files: HashMap<String, String>,
+    ) -> Result<Self> {
+        let root_dir = root_dir.into();
+        let scope = ProjectScope {
+            project: None,
+            config_root: root_dir.clone(),
+            root_dir,
+            config,
+        };
+        Self::from_scope(
+            &CommonArgs::default(),
+            scope,
+            Some(files.into_iter().collect()),
+        )
+    }
 
-        // In verbose mode, inform user if using default config
-        if verbose && !config_result.from_file {
-            eprintln!("Note: No .glotrc.json found, using default configuration");
+    /// Create one `CheckContext` per project of a monorepo config.
+    ///
+    /// When the config lists `projects` and the source root isn't one of
+    /// their roots, every project is loaded with its own root, messages and
+    /// files, so Phase 1 registries never cross projects. Otherwise this is
+    /// a single context, as with `new()`.
+    pub fn for_projects(common_args: &CommonArgs) -> Result<Vec<Self>> {
+        resolve_projects(common_args)?
+            .into_iter()
+            .map(|scope| Self::from_scope(common_args, scope, None))
+            .collect()
+    }
+
+    fn load(common_args: &CommonArgs, sources: Option<Vec<(String, String)>>) -> Result<Self> {
+        let mut scopes = resolve_projects(common_args)?;
+        if scopes.len() > 1 {
+            let roots: Vec<_> = scopes
+                .iter()
+                .filter_map(|scope| scope.project.as_deref())
+                .collect();
+            bail!(
+                "The config lists 'projects' ({}): only `glot check` runs them all, use --source-root <project root> to pick one",
+                roots.join(", ")
+            );
         }
+        let scope = scopes.remove(0);
+        Self::from_scope(common_args, scope, sources)
+    }
 
-        let mut config = config_result.config;
+    fn from_scope(
+        common_args: &CommonArgs,
+        scope: ProjectScope,
+        sources: Option<Vec<(String, String)>>,
+    ) -> Result<Self> {
+        let verbose = common_args.verbose;
+        let ProjectScope {
+            project,
+            root_dir,
+            config_root,
+            mut config,
+        } = scope;
+        let path = root_dir
+            .to_str()
+            .with_context(|| anyhow!("Invalid path: {:?}", root_dir))?;
 
         // ============================================================
-        // 3. Apply CLI overrides (CLI > config file > defaults)
+        // Apply CLI overrides (CLI > config file > defaults)
         // ============================================================
         if let Some(ref primary_locale) = common_args.primary_locale {
             config.primary_locale = primary_locale.clone();
@@ -217,89 +337,103 @@ impl CheckContext {
             config.messages_root = messages_root.to_string_lossy().to_string();
         }
 
+        if common_args.include_tests {
+            config.ignore_test_files = false;
+        }
+
+        if let Some(path_style) = common_args.path_style {
+            config.path_style = path_style;
+        }
+
         // Note: config's source_root is used for file scanning,
         // but CLI's source_root already determined where to find the config
 
-        // Compute message directory before parallel section (it depends on config)
-        let message_dir = {
-            let p = Path::new(&config.messages_root);
-            if p.is_absolute() {
-                p.to_path_buf()
-            } else {
-                let is_cur_dir = root_dir
-                    .components()
-                    .all(|c| matches!(c, Component::CurDir));
-                if is_cur_dir {
-                    p.to_path_buf()
-                } else {
-                    let rel = p.strip_prefix(Path::new(".")).unwrap_or(p);
-                    root_dir.join(rel)
-                }
-            }
-        };
-
         // Run file scanning and message scanning in parallel
-        let (scan_result, scan_message_result) = rayon::join(
+        let ((scan_result, scan_time), (scan_message_result, mut locale_time)) = rayon::join(
             || {
-                scan_files(
-                    path,
-                    &config.includes,
-                    &config.ignores,
-                    config.ignore_test_files,
-                    verbose,
-                )
+                let start = Instant::now();
+                let result = sources.is_none().then(|| {
+                    scan_files(
+                        path,
+                        &config.includes,
+                        &config.ignores,
+                        config.ignore_test_files,
+                        verbose,
+                    )
+                });
+                (result, start.elapsed())
+            },
+            || {
+                let start = Instant::now();
+                let result = scan_messages(&config, &root_dir);
+                (result, start.elapsed())
             },
-            || scan_message_files(&message_dir),
         );
 
-        if scan_result.skipped_count > 0 {
-            eprintln!(
-                "Warning: {} path(s) skipped due to access errors{}",
-                scan_result.skipped_count,
-                if verbose { "" } else { " (use -v for details)" }
-            );
+        let in_memory = sources.is_some();
+        let source_overrides: HashMap<String, String> = sources
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(file_path, content)| {
+                (
+                    resolve_from_root(&root_dir, Path::new(&file_path))
+                        .to_string_lossy()
+                        .to_string(),
+                    content,
+                )
+            })
+            .collect();
+        let mut files = match scan_result {
+            Some(scan_result) => {
+                if scan_result.skipped_count > 0 {
+                    eprintln!(
+                        "Warning: {} path(s) skipped due to access errors{}",
+                        scan_result.skipped_count,
+                        if verbose { "" } else { " (use -v for details)" }
+                    );
+                }
+                scan_result.files
+            }
+            None => source_overrides.keys().cloned().collect(),
+        };
+
+        // `.vue` files are only analyzed when the Vue framework is enabled
+        if !config.vue_enabled() {
+            files.retain(|file| !file.ends_with(".vue"));
         }
 
         let ignore_texts = config.ignore_texts.iter().cloned().collect();
+        let checked_attributes = config.text_attributes();
 
-        // Propagate message scan errors
-        let scan_results = scan_message_result?;
+        let cache_path = (common_args.cache && !in_memory).then(|| {
+            common_args
+                .cache_location
+                .clone()
+                .unwrap_or_else(|| root_dir.join(DEFAULT_CACHE_FILE))
+        });
 
-        // Convert message warnings to ParseErrorIssue
-        let message_parse_errors: Vec<ParseErrorIssue> = scan_results
-            .warnings
-            .iter()
-            .map(|warning| ParseErrorIssue {
-                file_path: warning.file_path.clone(),
-                error: warning.error.clone(),
-                file_type: ParseErrorFileType::Message,
-            })
-            .collect();
+        // Propagate message scan errors
+        let (message_data, message_parse_errors, sfc_time) =
+            load_messages(&config, &root_dir, &files, scan_message_result?)?;
+        locale_time += sfc_time;
 
-        let primary_messages = scan_results
-            .messages
-            .get(&config.primary_locale)
-            .ok_or_else(|| {
-                anyhow!(
-                    "Primary locale '{}' messages not found in '{}'",
-                    config.primary_locale,
-                    message_dir.display()
-                )
-            })?
-            .clone();
+        let profile = Profile::default();
+        profile.record("file scanning", scan_time);
+        profile.record("locale loading", locale_time);
 
         let messages = OnceCell::new();
-        let _ = messages.set(MessageData {
-            all_messages: scan_results.messages,
-            primary_messages,
-        });
+        let _ = messages.set(message_data);
 
         Ok(Self {
             config,
             root_dir,
-            files: scan_result.files,
+            config_root,
+            project,
+            files,
             ignore_texts,
+            checked_attributes,
             verbose,
+            cache_path,
             parsed_files: OnceCell::new(),
             parsed_files_errors: OnceCell::new(),
             source_metadata: OnceCell::new(),
@@ -307,12 +441,27 @@ impl CheckContext {
             messages,
             used_keys: OnceCell::new(),
             message_parse_errors,
+            include_suppressed: false,
+            source_overrides,
+            compared_messages: None,
+            source_cache: OnceCell::new(),
+            profile,
         })
     }
 
+    /// Time spent in each phase so far, for `check --profile`.
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    /// Path of the in-memory source file given to `for_source()`.
+    pub fn source_override_path(&self) -> Option<&str> {
+        self.source_overrides.keys().next().map(String::as_str)
+    }
+
     /// Get parsed AST for all source files (lazy initialization).
     ///
-    /// Parses all TSX/JSX/TS/JS/Astro files. Parse errors are collected
+    /// Parses all TSX/JSX/TS/JS/Astro/Vue files. Parse errors are collected
     /// separately and can be retrieved via `parsed_files_errors()`.
     ///
     /// ## Performance Note
@@ -326,59 +475,44 @@ impl CheckContext {
     /// This achieves 4-6x speedup on multi-core CPUs for medium-to-large codebases.
     pub fn parsed_files(&self) -> &HashMap<String, ParsedJSX> {
         self.parsed_files.get_or_init(|| {
-            use std::sync::Arc;
-
-            // Parallel file reading AND parsing (both I/O-bound and CPU-bound)
-            let parse_results: Vec<_> = self
-                .files
-                .par_iter()
-                .map(|file_path| {
-                    // Read file
-                    let content_result = std::fs::read_to_string(file_path)
-                        .map_err(|e| anyhow::anyhow!("Failed to read file: {}", e));
-
-                    // Parse with per-file SourceMap
-                    let parse_result = match content_result {
-                        Ok(code) => {
-                            // Each thread creates its own SourceMap
-                            let source_map = Arc::new(swc_common::SourceMap::default());
-                            if file_path.ends_with(".astro") {
-                                parse_astro_source(code, file_path, source_map)
-                            } else {
-                                parse_jsx_source(code, file_path, source_map)
-                            }
+            self.profile.time("source parsing", || {
+                // Parallel file reading AND parsing (both I/O-bound and CPU-bound)
+                let source_overrides = &self.source_overrides;
+                let parse_results: Vec<_> = self
+                    .files
+                    .par_iter()
+                    .map(|file_path| {
+                        let code = match source_overrides.get(file_path) {
+                            Some(content) => Ok(content.clone()),
+                            None => read_source_file(file_path),
+                        };
+                        let parse_result = code.and_then(|code| parse_source(file_path, code));
+                        (file_path.clone(), parse_result)
+                    })
+                    .collect();
+
+                // Sequential error collection and HashMap construction
+                let mut parsed = HashMap::new();
+                let mut errors = Vec::new();
+
+                for (file_path, result) in parse_results {
+                    match result {
+                        Ok(p) => {
+                            parsed.insert(file_path, p);
                         }
-                        Err(e) => Err(e),
-                    };
-
-                    (file_path.clone(), parse_result)
-                })
-                .collect();
-
-            // Sequential error collection and HashMap construction
-            let mut parsed = HashMap::new();
-            let mut errors = Vec::new();
-
-            for (file_path, result) in parse_results {
-                match result {
-                    Ok(p) => {
-                        parsed.insert(file_path, p);
-                    }
-                    Err(e) => {
-                        if self.verbose {
-                            eprintln!("Warning: {} - {}", file_path, e);
+                        Err(e) => {
+                            errors.push(ParseErrorIssue {
+                                file_path,
+                                error: e.to_string(),
+                                file_type: ParseErrorFileType::Source,
+                            });
                         }
-                        errors.push(ParseErrorIssue {
-                            file_path,
-                            error: e.to_string(),
-                            file_type: ParseErrorFileType::Source,
-                        });
                     }
                 }
-            }
 
-            let _ = self.parsed_files_errors.set(errors);
-            parsed
+                let _ = self.parsed_files_errors.set(errors);
+                parsed
+            })
         })
     }
 
@@ -437,10 +571,19 @@ impl CheckContext {
         &self.resolved_data().hardcoded_issues
     }
 
+    /// Get set of namespaces passed to translation hooks (lazy initialization).
+    ///
+    /// Used by the unused-namespace rule alongside `used_keys`.
+    pub fn used_namespaces(&self) -> &HashSet<String> {
+        &self.resolved_data().namespaces
+    }
+
     /// Get set of all keys used in source code (lazy initialization).
     ///
     /// This is a flattened set of all resolved keys from all files.
     /// Used by the unused-key rule to find keys in locale files that aren't used.
+    /// Usages on lines with `glot-disable` comments count too: suppressing
+    /// another rule there doesn't make the key unused.
     pub fn used_keys(&self) -> &HashSet<String> {
         self.used_keys.get_or_init(|| {
             let mut used_keys = HashSet::new();
@@ -462,37 +605,149 @@ impl CheckContext {
         self.messages().primary_messages.keys().cloned().collect()
     }
 
+    /// Run Phases 2 and 3 again for one checked file, keeping the raw calls.
+    ///
+    /// Returns None if the file can't be read or parsed. Phase 1 data comes
+    /// from `source_metadata()`, so the trace matches what the rules see.
+    pub fn trace_file(&self, file_path: &str) -> Option<FileTrace> {
+        let code = match self.source_overrides.get(file_path) {
+            Some(content) => content.clone(),
+            None => read_source_file(file_path).ok()?,
+        };
+        let parsed = parse_source(file_path, code).ok()?;
+        let available_keys = self.available_keys();
+        let inputs = self.extract_inputs(self.source_metadata(), &available_keys);
+        let result = extract_file(file_path, &parsed, &inputs);
+        let raw_calls = result.raw_calls.clone();
+        let resolved = resolve_file(file_path, result, &inputs);
+        Some(FileTrace {
+            raw_calls,
+            key_usages: resolved.key_usages,
+        })
+    }
+
     /// Get Phase 1 source metadata (lazy initialization).
     ///
     /// Runs Phase 1: Collection to gather all cross-file dependencies.
     /// This includes registries, imports, and comment annotations.
     pub fn source_metadata(&self) -> &SourceMetadata {
+        self.run_cached_pipeline();
         self.source_metadata.get_or_init(|| {
             let available_keys = self.available_keys();
 
             let parsed_files = self.parsed_files();
 
-            // Phase 1: Collection - Collect registries and comments in single AST pass
-            let (registries, file_imports, file_comments) =
-                collect_registries_and_comments(parsed_files, &available_keys);
+            self.profile.time("phase 1: collection", || {
+                // Phase 1: Collection - Collect registries and comments in single AST pass
+                let (registries, file_imports, file_comments) =
+                    collect_registries_and_comments(parsed_files, &available_keys);
 
-            // Phase 1.5: Build schema cache
-            let schema_cache = build_schema_cache(&registries.schema);
+                // Phase 1.5: Build schema cache
+                let schema_cache = build_schema_cache(&registries.schema);
 
-            SourceMetadata {
-                registries,
-                file_imports,
-                file_comments,
-                schema_cache,
-            }
+                SourceMetadata {
+                    registries,
+                    file_imports,
+                    file_comments,
+                    schema_cache,
+                }
+            })
         })
     }
 
+    /// Ignore `glot-disable` comments when extracting issues.
+    ///
+    /// Rules then report suppressed issues too, while `source_metadata()` still
+    /// holds the suppression comments, so callers can tell which comments are
+    /// still needed. Must be called before any pipeline data is requested; the
+    /// parse cache is not used.
+    pub fn include_suppressed(&mut self) {
+        self.include_suppressed = true;
+        self.cache_path = None;
+    }
+
+    /// Drop the messages of every locale not in `locales`.
+    ///
+    /// The primary and fallback locales are always kept, since the other
+    /// locales are compared against them. Unlike `only_compare_locales()`,
+    /// every rule then only sees the kept locales, and `messages()` only
+    /// counts their files. Must be called before any pipeline data is
+    /// requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a locale has no message files.
+    pub fn only_load_locales(&mut self, locales: &[String]) -> Result<()> {
+        let primary_locale = &self.config.primary_locale;
+        let fallback_locales = &self.config.fallback_locales;
+        let all_messages = &mut self
+            .messages
+            .get_mut()
+            .expect("Messages should be initialized in CheckContext::new()")
+            .all_messages;
+        if let Some(unknown) = locales.iter().find(|l| !all_messages.contains_key(*l)) {
+            bail!("Locale '{}' has no message files", unknown);
+        }
+        all_messages.retain(|locale, _| {
+            locale == primary_locale
+                || fallback_locales.contains(locale)
+                || locales.contains(locale)
+        });
+        Ok(())
+    }
+
+    /// Restrict the locale comparison rules to `locales`.
+    ///
+    /// Rules comparing a locale with the primary locale (`replica-lag`,
+    /// `orphan`, `untranslated`, `type-mismatch`, `placeholder-mismatch`,
+    /// `rich-tag-mismatch`) then only see the listed locales, next to the primary and fallback locales
+    /// they compare against. When the primary or a fallback locale is listed,
+    /// every pair is affected and nothing is restricted.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a locale has no message files.
+    pub fn only_compare_locales(&mut self, locales: &[String]) -> Result<()> {
+        let primary_locale = &self.config.primary_locale;
+        let fallback_locales = &self.config.fallback_locales;
+        let all_messages = &self.messages().all_messages;
+        if let Some(unknown) = locales.iter().find(|l| !all_messages.contains_key(*l)) {
+            bail!("Locale '{}' has no message files", unknown);
+        }
+        if locales
+            .iter()
+            .any(|l| l == primary_locale || fallback_locales.contains(l))
+        {
+            return Ok(());
+        }
+        let compared = all_messages
+            .iter()
+            .filter(|(locale, _)| {
+                *locale == primary_locale
+                    || fallback_locales.contains(locale)
+                    || locales.contains(locale)
+            })
+            .map(|(locale, messages)| (locale.clone(), messages.clone()))
+            .collect();
+        self.compared_messages = Some(compared);
+        Ok(())
+    }
+
+    /// Messages of the locales given to the locale comparison rules.
+    ///
+    /// All locales, unless restricted by `only_compare_locales()`.
+    pub fn compared_messages(&self) -> &AllLocaleMessages {
+        self.compared_messages
+            .as_ref()
+            .unwrap_or(&self.messages().all_messages)
+    }
+
     /// Get Phase 2+3 resolved data (lazy initialization).
     ///
     /// Runs Phase 2 (Extraction) and Phase 3 (Resolution) to produce
     /// final resolved/unresolved key usages and hardcoded issues.
     fn resolved_data(&self) -> &ResolvedData {
+        self.run_cached_pipeline();
         self.resolved_data.get_or_init(|| {
             let parsed_files = self.parsed_files();
 
@@ -500,25 +755,306 @@ impl CheckContext {
 
             let metadata = self.source_metadata();
 
-            let (key_usages, hardcoded_issues) = extract_from_files(
-                &self.files,
-                parsed_files,
-                &metadata.registries,
-                &metadata.file_imports,
-                &metadata.file_comments,
-                &metadata.schema_cache,
-                &self.config.checked_attributes,
-                &self.ignore_texts,
-                &self.config.extra_translation_callees,
-                &self.config.extra_translation_member_calls,
-                &available_keys,
+            let mut inputs = self.extract_inputs(metadata, &available_keys);
+            let unsuppressed: AllFileComments;
+            if self.include_suppressed {
+                unsuppressed = metadata
+                    .file_comments
+                    .iter()
+                    .map(|(file, comments)| {
+                        let comments = FileComments {
+                            suppressions: Default::default(),
+                            declarations: comments.declarations.clone(),
+                        };
+                        (file.clone(), comments)
+                    })
+                    .collect();
+                inputs.file_comments = &unsuppressed;
+            }
+            extract_from_files(&self.files, parsed_files, &inputs, &self.profile)
+        })
+    }
+
+    fn extract_inputs<'a>(
+        &'a self,
+        metadata: &'a SourceMetadata,
+        available_keys: &'a HashSet<String>,
+    ) -> ExtractInputs<'a> {
+        ExtractInputs {
+            registries: &metadata.registries,
+            file_imports: &metadata.file_imports,
+            file_comments: &metadata.file_comments,
+            schema_cache: &metadata.schema_cache,
+            checked_attributes: &self.checked_attributes,
+            ignore_texts: &self.ignore_texts,
+            ignore_components: &self.config.hardcoded.ignore_components,
+            extra_translation_callees: &self.config.extra_translation_callees,
+            extra_translation_member_calls: &self.config.extra_translation_member_calls,
+            framework: self.config.framework,
+            available_keys,
+            key_separator: &self.config.key_separator,
+        }
+    }
+
+    /// Run Phases 1-3 through the parse cache (`--cache` only).
+    ///
+    /// Fills `source_metadata`, `resolved_data` and `parsed_files_errors` in
+    /// one go, parsing only the files whose cached data can't be reused. See
+    /// `core::cache` for the invalidation rules. Failing to write the cache
+    /// is reported as a warning and never fails the run.
+    fn run_cached_pipeline(&self) {
+        let Some(cache_path) = &self.cache_path else {
+            return;
+        };
+        if self.resolved_data.get().is_some() {
+            return;
+        }
+        self.profile.time("phases 1-3 (cached)", || {
+            self.run_cached_pipeline_at(cache_path)
+        });
+    }
+
+    fn run_cached_pipeline_at(&self, cache_path: &Path) {
+        let source_cache = SourceCache::load(cache_path, self.verbose);
+        self.run_pipeline_with(source_cache, None);
+    }
+
+    /// Run Phases 1-3 reusing the entries of `source_cache`.
+    ///
+    /// With `changed`, files outside it are trusted to match their entry and
+    /// are not read again (see `update()`). The cache is saved and kept in
+    /// memory for the next `update()`.
+    fn run_pipeline_with(&self, mut source_cache: SourceCache, changed: Option<&HashSet<String>>) {
+        let available_keys = self.available_keys();
+
+        // Read every file and reuse Phase 1 output for unchanged ones
+        let results: Vec<_> = self
+            .files
+            .par_iter()
+            .map(|file_path| {
+                let trusted = source_cache
+                    .entry(file_path)
+                    .filter(|_| changed.is_some_and(|changed| !changed.contains(file_path)));
+                if let Some(entry) = trusted {
+                    let file = (entry.content_hash, None, None, entry.collected.clone());
+                    return (file_path.clone(), Ok(file));
+                }
+                let result = read_source_file(file_path).and_then(|code| {
+                    let hash = cache::content_hash(&code);
+                    if let Some(collected) = source_cache.collected(file_path, hash) {
+                        return Ok((hash, Some(code), None, collected.clone()));
+                    }
+                    let parsed = parse_source(file_path, code.clone())?;
+                    let collected = collect_file(file_path, &parsed);
+                    Ok((hash, Some(code), Some(parsed), collected))
+                });
+                (file_path.clone(), result)
+            })
+            .collect();
+
+        let mut files = Vec::new();
+        let mut errors = Vec::new();
+        for (file_path, result) in results {
+            match result {
+                Ok(file) => files.push((file_path, file)),
+                Err(e) => {
+                    errors.push(ParseErrorIssue {
+                        file_path,
+                        error: e.to_string(),
+                        file_type: ParseErrorFileType::Source,
+                    });
+                }
+            }
+        }
+        let _ = self.parsed_files_errors.set(errors);
+
+        let fingerprint = cache::fingerprint(
+            &self.config,
+            &available_keys,
+            files
+                .iter()
+                .map(|(path, (_, _, _, collected))| (path.as_str(), collected)),
+        );
+
+        let (registries, file_imports, file_comments) = merge_collected_files(
+            files
+                .iter()
+                .map(|(path, (_, _, _, collected))| (path.clone(), collected.clone()))
+                .collect(),
+        );
+        let schema_cache = build_schema_cache(&registries.schema);
+        let _ = self.source_metadata.set(SourceMetadata {
+            registries,
+            file_imports,
+            file_comments,
+            schema_cache,
+        });
+        let metadata = self.source_metadata.get().expect("set above");
+        let inputs = self.extract_inputs(metadata, &available_keys);
+
+        // Reuse Phase 2+3 output when possible, parsing the rest on demand
+        let analyzed: Vec<_> = files
+            .into_par_iter()
+            .filter_map(|(file_path, (hash, code, parsed, collected))| {
+                let resolved = match source_cache.resolved(&file_path, hash, fingerprint) {
+                    Some(resolved) => resolved.clone(),
+                    None => {
+                        let parsed = match parsed {
+                            Some(parsed) => parsed,
+                            None => {
+                                let code = match code {
+                                    Some(code) => code,
+                                    None => read_source_file(&file_path).ok()?,
+                                };
+                                parse_source(&file_path, code).ok()?
+                            }
+                        };
+                        analyze_file(&file_path, &parsed, &inputs)
+                    }
+                };
+                Some((file_path, hash, collected, resolved))
+            })
+            .collect();
+
+        let mut entries = HashMap::new();
+        let mut resolved_files = Vec::new();
+        for (file_path, content_hash, collected, resolved) in analyzed {
+            entries.insert(
+                file_path.clone(),
+                CacheEntry {
+                    content_hash,
+                    collected,
+                    resolved: resolved.clone(),
+                },
             );
+            resolved_files.push((file_path, resolved));
+        }
+
+        if let Err(e) = source_cache.save(fingerprint, entries) {
+            eprintln!("Warning: {}", e);
+        }
 
-            ResolvedData {
-                key_usages,
-                hardcoded_issues,
+        let _ = self.resolved_data.set(merge_resolved_files(resolved_files));
+        let _ = self.source_cache.set(source_cache);
+    }
+
+    /// Bring the context up to date after `changed` paths were modified,
+    /// created or removed.
+    ///
+    /// This is the incremental entry point used by `glot watch`; paths may be
+    /// given in any form (relative or absolute). The source files are scanned
+    /// again only when a source file was created or removed, and the messages
+    /// are reloaded when any other file changed.
+    ///
+    /// After a `--cache` run, only the changed source files are read and go
+    /// through Phase 1 again: the Phase 1 output of the others is kept in
+    /// memory and merged with theirs into the cross-file registries. Phase
+    /// 2+3 re-runs for the changed files alone, unless the registries, the
+    /// primary locale keys or the config changed (see `core::cache`). Without
+    /// `--cache`, every phase re-runs on next access.
+    ///
+    /// Restrictions from `only_load_locales()` and `only_compare_locales()`
+    /// are not applied to reloaded messages. The config is never reloaded:
+    /// create a new context when it changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the messages can't be reloaded (e.g. the primary
+    /// locale file was removed). The context is left unchanged then.
+    pub fn update(&mut self, changed: &[PathBuf]) -> Result<()> {
+        let root = absolute_path(&self.root_dir);
+        let canonical_root = root.canonicalize().unwrap_or_else(|_| root.clone());
+        let comparable = |path: &Path| {
+            let path = absolute_path(path);
+            match path.strip_prefix(&root) {
+                Ok(relative) => canonical_root.join(relative),
+                Err(_) => path,
             }
-        })
+        };
+        let changed: HashSet<PathBuf> = changed.iter().map(|path| comparable(path)).collect();
+        let changed_sources: Vec<&PathBuf> = changed
+            .iter()
+            .filter(|path| is_scannable_file(path))
+            .collect();
+
+        // Paths of the checked files, as they compare with `changed`
+        let mut known: HashMap<PathBuf, &String> = self
+            .files
+            .iter()
+            .map(|file| (comparable(Path::new(file)), file))
+            .collect();
+        let mut files = None;
+        let in_memory = !self.source_overrides.is_empty();
+        if !in_memory
+            && changed_sources
+                .iter()
+                .any(|path| known.contains_key(*path) != path.is_file())
+        {
+            let start = Instant::now();
+            let path = self.root_dir.to_string_lossy();
+            let mut scanned = scan_files(
+                &path,
+                &self.config.includes,
+                &self.config.ignores,
+                self.config.ignore_test_files,
+                self.verbose,
+            )
+            .files;
+            if !self.config.vue_enabled() {
+                scanned.retain(|file| !file.ends_with(".vue"));
+            }
+            self.profile.record("file scanning", start.elapsed());
+            files = Some(scanned);
+        }
+        let files_ref = files.as_ref().unwrap_or(&self.files);
+        if files.is_some() {
+            known = files_ref
+                .iter()
+                .map(|file| (comparable(Path::new(file)), file))
+                .collect();
+        }
+
+        let reload_messages = changed.iter().any(|path| {
+            !is_scannable_file(path)
+                || (self.config.vue_enabled() && path.extension().is_some_and(|ext| ext == "vue"))
+        });
+        let messages = if reload_messages {
+            let start = Instant::now();
+            let scanned = scan_messages(&self.config, &self.root_dir)?;
+            let loaded = load_messages(&self.config, &self.root_dir, files_ref, scanned)?;
+            self.profile.record("locale loading", start.elapsed());
+            Some(loaded)
+        } else {
+            None
+        };
+
+        let changed_files: HashSet<String> = changed_sources
+            .iter()
+            .filter_map(|path| known.get(*path).map(|file| (*file).clone()))
+            .collect();
+
+        if let Some(files) = files {
+            self.files = files;
+        }
+        if let Some((message_data, message_parse_errors, _)) = messages {
+            self.messages = OnceCell::new();
+            let _ = self.messages.set(message_data);
+            self.message_parse_errors = message_parse_errors;
+            self.compared_messages = None;
+        }
+
+        let source_cache = self.source_cache.take();
+        self.parsed_files = OnceCell::new();
+        self.parsed_files_errors = OnceCell::new();
+        self.source_metadata = OnceCell::new();
+        self.resolved_data = OnceCell::new();
+        self.used_keys = OnceCell::new();
+        if let Some(source_cache) = source_cache {
+            self.profile.time("phases 1-3 (incremental)", || {
+                self.run_pipeline_with(source_cache, Some(&changed_files))
+            });
+        }
+        Ok(())
     }
 
     // ============================================================
@@ -527,25 +1063,234 @@ impl CheckContext {
 
     /// Resolve the messages directory path relative to root_dir.
     pub fn resolved_messages_dir(&self) -> PathBuf {
-        let p = Path::new(&self.config.messages_root);
-        if p.is_absolute() {
+        resolve_from_root(&self.root_dir, Path::new(&self.config.messages_root))
+    }
+
+    /// Get the `messagePattern` resolved against the root directory, if set.
+    pub fn resolved_message_pattern(&self) -> Option<PathBuf> {
+        self.config
+            .message_pattern
+            .as_ref()
+            .map(|pattern| resolve_from_root(&self.root_dir, Path::new(pattern)))
+    }
+}
+
+/// Root directory and config of one `CheckContext`.
+struct ProjectScope {
+    project: Option<String>,
+    root_dir: PathBuf,
+    config_root: PathBuf,
+    config: Config,
+}
+
+/// Load the config from the source root and split it into projects.
+///
+/// Without `projects`, or when the source root is one of their roots, there
+/// is a single scope. Otherwise there is one per project, rooted at the
+/// project's directory next to the config file.
+fn resolve_projects(common_args: &CommonArgs) -> Result<Vec<ProjectScope>> {
+    // Priority: CLI --source-root arg > current directory
+    let source_root = common_args
+        .source_root
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
+    let config_result = load_config(&source_root)?;
+
+    // In verbose mode, inform user if using default config
+    if common_args.verbose && !config_result.from_file {
+        eprintln!("Note: No glot.config.json or .glotrc.json found, using default configuration");
+    }
+
+    let config = config_result.config;
+    let config_dir = config_result
+        .path
+        .as_deref()
+        .and_then(Path::parent)
+        .unwrap_or(Path::new("."));
+    let config_root = match &config_result.path {
+        Some(_) => config_dir.to_path_buf(),
+        None => source_root.clone(),
+    };
+    if config.projects.is_empty() {
+        return Ok(vec![ProjectScope {
+            project: None,
+            root_dir: source_root,
+            config_root,
+            config,
+        }]);
+    }
+
+    let project_dirs: Vec<PathBuf> = config
+        .projects
+        .iter()
+        .map(|project| {
+            let root = Path::new(&project.root);
+            config_dir.join(root.strip_prefix(".").unwrap_or(root))
+        })
+        .collect();
+
+    // `--source-root apps/web` checks that project alone
+    let selected = source_root.canonicalize().ok().and_then(|source_root| {
+        project_dirs
+            .iter()
+            .position(|dir| dir.canonicalize().is_ok_and(|dir| dir == source_root))
+    });
+    if let Some(idx) = selected {
+        let project = &config.projects[idx];
+        return Ok(vec![ProjectScope {
+            project: Some(project.root.clone()),
+            root_dir: source_root,
+            config_root,
+            config: config.for_project(project),
+        }]);
+    }
+
+    config
+        .projects
+        .iter()
+        .zip(project_dirs)
+        .map(|(project, root_dir)| {
+            if !root_dir.is_dir() {
+                bail!(
+                    "Project root '{}' not found: {}",
+                    project.root,
+                    root_dir.display()
+                );
+            }
+            Ok(ProjectScope {
+                project: Some(project.root.clone()),
+                root_dir,
+                config_root: config_root.clone(),
+                config: config.for_project(project),
+            })
+        })
+        .collect()
+}
+
+/// Resolve a config path against the root directory.
+fn resolve_from_root(root_dir: &Path, p: &Path) -> PathBuf {
+    if p.is_absolute() {
+        p.to_path_buf()
+    } else {
+        // If user runs with `--source-root .`, keep the original relative path (e.g. "./messages")
+        // to avoid noisy paths like "././messages" in output/snapshots.
+        let is_cur_dir = root_dir
+            .components()
+            .all(|c| matches!(c, Component::CurDir));
+        if is_cur_dir {
             p.to_path_buf()
         } else {
-            // If user runs with `--source-root .`, keep the original relative path (e.g. "./messages")
-            // to avoid noisy paths like "././messages" in output/snapshots.
-            let is_cur_dir = self
-                .root_dir
-                .components()
-                .all(|c| matches!(c, Component::CurDir));
-            if is_cur_dir {
-                p.to_path_buf()
-            } else {
-                // Strip leading "./" so joins become "<root>/messages" instead of "<root>/./messages".
-                let rel = p.strip_prefix(Path::new(".")).unwrap_or(p);
-                self.root_dir.join(rel)
+            // Strip leading "./" so joins become "<root>/messages" instead of "<root>/./messages".
+            let rel = p.strip_prefix(Path::new(".")).unwrap_or(p);
+            root_dir.join(rel)
+        }
+    }
+}
+
+/// Scan the locale files from `messagePattern`, or from `messagesRoot` without one.
+fn scan_messages(config: &Config, root_dir: &Path) -> Result<ScanMessagesResult> {
+    let locale_filter = LocaleFilter::from_config(config);
+    match &config.message_pattern {
+        Some(pattern) => scan_message_pattern(
+            &resolve_from_root(root_dir, Path::new(pattern)).to_string_lossy(),
+            &config.key_separator,
+            &locale_filter,
+        ),
+        None => scan_message_files(
+            resolve_from_root(root_dir, Path::new(&config.messages_root)),
+            &config.key_separator,
+            &locale_filter,
+        ),
+    }
+}
+
+/// Build `MessageData` from scanned locale files.
+///
+/// With the Vue framework, messages from the `<i18n>` blocks of `files` fill
+/// in keys the locale files don't define. Returns the message data, the
+/// message parse errors and the time spent on `<i18n>` blocks.
+///
+/// # Errors
+///
+/// Returns error if the primary locale messages are not found.
+fn load_messages(
+    config: &Config,
+    root_dir: &Path,
+    files: &HashSet<String>,
+    mut scan_results: ScanMessagesResult,
+) -> Result<(MessageData, Vec<ParseErrorIssue>, Duration)> {
+    for skipped in &scan_results.skipped {
+        eprintln!("Warning: skipped {}: {}", skipped.file_path, skipped.error);
+    }
+
+    // Messages from `<i18n>` blocks fill in keys the locale files don't define
+    let start = Instant::now();
+    if config.vue_enabled() {
+        let sfc_results = scan_sfc_messages(files, &config.key_separator);
+        scan_results.warnings.extend(sfc_results.warnings);
+        for (locale, sfc_messages) in sfc_results.messages {
+            let messages = scan_results.messages.entry(locale).or_insert_with(|| {
+                LocaleMessages::new(&sfc_messages.locale, &sfc_messages.file_path)
+            });
+            for (key, entry) in sfc_messages.entries {
+                messages.entries.entry(key).or_insert(entry);
             }
+            messages.duplicate_keys.extend(sfc_messages.duplicate_keys);
         }
     }
+    let sfc_time = start.elapsed();
+
+    // Convert message warnings to ParseErrorIssue
+    let message_parse_errors: Vec<ParseErrorIssue> = scan_results
+        .warnings
+        .iter()
+        .map(|warning| ParseErrorIssue {
+            file_path: warning.file_path.clone(),
+            error: warning.error.clone(),
+            file_type: ParseErrorFileType::Message,
+        })
+        .collect();
+
+    let primary_messages = scan_results
+        .messages
+        .get(&config.primary_locale)
+        .ok_or_else(|| {
+            let location = config
+                .message_pattern
+                .as_ref()
+                .unwrap_or(&config.messages_root);
+            anyhow!(
+                "Primary locale '{}' messages not found in '{}'",
+                config.primary_locale,
+                resolve_from_root(root_dir, Path::new(location)).display()
+            )
+        })?
+        .clone();
+
+    let message_data = MessageData {
+        all_messages: scan_results.messages,
+        primary_messages,
+    };
+    Ok((message_data, message_parse_errors, sfc_time))
+}
+
+/// Lexically absolute form of `path`, with `.` and `..` components resolved.
+fn absolute_path(path: &Path) -> PathBuf {
+    let path = match std::env::current_dir() {
+        Ok(cwd) => cwd.join(path),
+        Err(_) => path.to_path_buf(),
+    };
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
 }
 
 /// Phase 1.5: Pre-compute all schema expansions.
@@ -588,6 +1333,7 @@ fn build_schema_cache(schema_registry: &SchemaRegistry) -> HashMap<String, Expan
 /// - Schema function registries
 /// - Key object/array registries
 /// - Translation prop/function call registries
+/// - `NextIntlClientProvider` namespaces per component
 /// - Import resolution data
 /// - Comment annotations (disable directives, glot-message-keys)
 ///
@@ -599,19 +1345,43 @@ fn collect_registries_and_comments(
     // Parallel collection per file
     let results: Vec<_> = parsed_files
         .par_iter()
-        .map(|(file_path, parsed)| {
-            // Collect registries
-            let mut collector = RegistryCollector::new(file_path);
-            parsed.module.visit_with(&mut collector);
+        .map(|(file_path, parsed)| (file_path.clone(), collect_file(file_path, parsed)))
+        .collect();
 
-            // Comments already extracted during parsing
-            let comments = CommentCollector::collect(&parsed.comments, &parsed.source_map);
+    merge_collected_files(results)
+}
 
-            (file_path.clone(), collector, comments)
-        })
-        .collect();
+/// Phase 1 for a single file: collect registry entries and comments.
+fn collect_file(file_path: &str, parsed: &ParsedJSX) -> CollectedFile {
+    let mut collector = RegistryCollector::new(file_path);
+    parsed.module.visit_with(&mut collector);
+
+    // Comments already extracted during parsing
+    let comments = CommentCollector::collect(&parsed.comments, &parsed.source_map);
+
+    CollectedFile {
+        schema_functions: collector.schema_functions,
+        objects: collector.objects,
+        arrays: collector.arrays,
+        string_arrays: collector.string_arrays,
+        imports: collector.imports,
+        re_exports: collector.re_exports,
+        translation_props: collector.translation_props,
+        translation_prop_forwards: collector.translation_prop_forwards,
+        translation_fn_calls: collector.translation_fn_calls,
+        translation_fn_forwards: collector.translation_fn_forwards,
+        default_export_name: collector.default_export_name,
+        message_providers: collector.message_providers,
+        comments,
+    }
+}
 
-    // Sequential merge (avoid lock contention)
+/// Merge per-file Phase 1 output into cross-file registries.
+///
+/// Runs sequentially to avoid lock contention.
+fn merge_collected_files(
+    results: Vec<(String, CollectedFile)>,
+) -> (Registries, AllFileImports, AllFileComments) {
     let mut schema = HashMap::new();
     let mut key_object = HashMap::new();
     let mut key_array = HashMap::new();
@@ -620,11 +1390,14 @@ fn collect_registries_and_comments(
     let mut translation_fn_call = HashMap::new();
     let mut translation_fn_forwards = Vec::new();
     let mut default_exports = HashMap::new();
+    let mut re_exports = HashMap::new();
     let mut file_imports: AllFileImports = HashMap::new();
     let mut file_comments: AllFileComments = HashMap::new();
     let mut translation_props_by_file: Vec<(String, Vec<TranslationProp>)> = Vec::new();
+    let mut translation_prop_forwards: Vec<(String, TranslationPropForward)> = Vec::new();
+    let mut message_providers_by_file: Vec<(String, Vec<MessageProvider>)> = Vec::new();
 
-    for (file_path, collector, comments) in results {
+    for (file_path, collector) in results {
         // Merge schema functions
         for func in collector.schema_functions {
             if !schema.contains_key(&func.name) {
@@ -634,9 +1407,12 @@ fn collect_registries_and_comments(
 
         // Merge imports
         file_imports.insert(file_path.clone(), collector.imports);
+        if !collector.re_exports.is_empty() {
+            re_exports.insert(file_path.clone(), collector.re_exports);
+        }
 
         // Merge comments
-        file_comments.insert(file_path.clone(), comments);
+        file_comments.insert(file_path.clone(), collector.comments);
 
         // Merge key objects
         for obj in collector.objects {
@@ -658,6 +1434,13 @@ fn collect_registries_and_comments(
 
         // Collect translation props for later resolution
         translation_props_by_file.push((file_path.clone(), collector.translation_props));
+        translation_prop_forwards.extend(
+            collector
+                .translation_prop_forwards
+                .into_iter()
+                .map(|forward| (file_path.clone(), forward)),
+        );
+        message_providers_by_file.push((file_path.clone(), collector.message_providers));
 
         // Merge translation function calls
         for fn_call in collector.translation_fn_calls {
@@ -713,19 +1496,83 @@ fn collect_registries_and_comments(
         }
     }
 
+    // Components forward props to components defined in other files
+    let translation_prop_forwards: Vec<TranslationPropForward> = translation_prop_forwards
+        .into_iter()
+        .map(|(file_path, mut forward)| {
+            let imports = file_imports.get(&file_path).cloned().unwrap_or_default();
+            forward.to_component = resolve_component_name_for_prop(
+                &file_path,
+                &forward.to_component,
+                &imports,
+                &default_exports,
+            );
+            forward
+        })
+        .collect();
+    propagate_translation_props(&translation_prop_forwards, &mut translation_prop);
+
+    let message_provider =
+        merge_message_providers(message_providers_by_file, &file_imports, &default_exports);
+
     let registries = Registries {
         schema,
         key_object,
         key_array,
         string_array,
+        re_exports,
         translation_prop,
         translation_fn_call,
         default_exports,
+        message_provider,
     };
 
     (registries, file_imports, file_comments)
 }
 
+/// Merge `NextIntlClientProvider` boundaries into namespaces per component.
+///
+/// A component rendered inside several providers gets the union of their
+/// namespaces, unless one of them passes all messages.
+fn merge_message_providers(
+    providers_by_file: Vec<(String, Vec<MessageProvider>)>,
+    file_imports: &AllFileImports,
+    default_exports: &HashMap<String, String>,
+) -> MessageProviderRegistry {
+    let mut message_provider: MessageProviderRegistry = HashMap::new();
+    let mut unrestricted = HashSet::new();
+
+    for (file_path, providers) in providers_by_file {
+        let imports = file_imports.get(&file_path).cloned().unwrap_or_default();
+        for provider in providers {
+            for component in &provider.components {
+                let component = resolve_component_name_for_prop(
+                    &file_path,
+                    component,
+                    &imports,
+                    default_exports,
+                );
+                match &provider.namespaces {
+                    Some(namespaces) => {
+                        let provided = message_provider.entry(component).or_default();
+                        for ns in namespaces {
+                            if !provided.contains(ns) {
+                                provided.push(ns.clone());
+                            }
+                        }
+                    }
+                    None => {
+                        unrestricted.insert(component);
+                    }
+                }
+            }
+        }
+    }
+
+    message_provider.retain(|component, _| !unrestricted.contains(component));
+    message_provider
+}
+
 /// Propagate translation-function usage through helper-to-helper forwarding edges.
 fn propagate_translation_fn_calls(
     forwards: &[crate::core::collect::TranslationFnForward],
@@ -777,6 +1624,46 @@ fn propagate_translation_fn_calls(
     }
 }
 
+/// Propagate translation props through components forwarding them to other components.
+fn propagate_translation_props(
+    forwards: &[TranslationPropForward],
+    translation_prop: &mut HashMap<String, TranslationProp>,
+) {
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for forward in forwards {
+            let from_key = make_translation_prop_key(&forward.from_component, &forward.from_prop);
+            let Some(source) = translation_prop.get(&from_key).cloned() else {
+                continue;
+            };
+
+            let to_key = make_translation_prop_key(&forward.to_component, &forward.to_prop);
+            match translation_prop.entry(to_key) {
+                std::collections::hash_map::Entry::Occupied(mut existing) => {
+                    let entry = existing.get_mut();
+                    for namespace in &source.namespaces {
+                        if !entry.namespaces.contains(namespace) {
+                            entry.namespaces.push(namespace.clone());
+                            changed = true;
+                        }
+                    }
+                }
+                std::collections::hash_map::Entry::Vacant(vacant) => {
+                    vacant.insert(TranslationProp {
+                        component_name: forward.to_component.clone(),
+                        prop_name: forward.to_prop.clone(),
+                        namespaces: source.namespaces.clone(),
+                    });
+                    changed = true;
+                }
+            }
+        }
+    }
+}
+
 /// Resolve a component name to its original definition name.
 ///
 /// When a component is imported as a default export and passed a translation prop,
@@ -810,84 +1697,183 @@ fn resolve_component_name_for_prop(
         .unwrap_or_else(|| component_name.to_string())
 }
 
+/// Inputs shared by every file in Phase 2 & 3.
+struct ExtractInputs<'a> {
+    registries: &'a Registries,
+    file_imports: &'a AllFileImports,
+    file_comments: &'a AllFileComments,
+    schema_cache: &'a HashMap<String, ExpandResult>,
+    checked_attributes: &'a [String],
+    ignore_texts: &'a HashSet<String>,
+    ignore_components: &'a [String],
+    extra_translation_callees: &'a [String],
+    extra_translation_member_calls: &'a [crate::config::TranslationMemberCallPattern],
+    framework: crate::config::Framework,
+    available_keys: &'a HashSet<String>,
+    key_separator: &'a str,
+}
+
 /// Phase 2 & 3: Extraction and Resolution.
 ///
-/// For each file:
 /// - **Phase 2 (Extraction)**: Collect raw translation calls and detect hardcoded text
 /// - **Phase 3 (Resolution)**: Resolve ValueSource to static keys, expand schema calls,
 ///   apply glot-message-keys, and generate final ResolvedKeyUsage/UnresolvedKeyUsage
 ///
-/// This phase is parallelized using rayon for improved performance.
-#[allow(clippy::too_many_arguments)]
+/// Each phase runs over all files before the next starts, so `profile` can
+/// time them apart. Both are parallelized using rayon for improved performance.
 fn extract_from_files(
-    files: &std::collections::HashSet<String>,
+    files: &HashSet<String>,
     parsed_files: &HashMap<String, ParsedJSX>,
-    registries: &Registries,
-    file_imports: &AllFileImports,
-    file_comments: &AllFileComments,
-    schema_cache: &HashMap<String, ExpandResult>,
-    checked_attributes: &[String],
-    ignore_texts: &std::collections::HashSet<String>,
-    extra_translation_callees: &[String],
-    extra_translation_member_calls: &[crate::config::TranslationMemberCallPattern],
-    available_keys: &std::collections::HashSet<String>,
-) -> (AllKeyUsages, AllHardcodedTextIssues) {
-    // Parallel extraction and resolution per file
-    let results: Vec<_> = files
-        .par_iter()
-        .filter_map(|file_path| {
-            let parsed = parsed_files.get(file_path)?;
-            let imports = file_imports.get(file_path).cloned().unwrap_or_default();
-            let comments = file_comments
-                .get(file_path)
-                .expect("Comments should be collected in Phase 1");
-
-            // Phase 2: Extraction
-            let analyzer = FileAnalyzer::new(
-                file_path,
-                &parsed.source_map,
-                comments,
-                checked_attributes,
-                ignore_texts,
-                !file_path.ends_with(".astro"),
-                parsed.astro_template_start_line,
-                registries,
-                &imports,
-                extra_translation_callees,
-                extra_translation_member_calls,
-            );
-            let result = analyzer.analyze(&parsed.module);
-
-            // Phase 3: Resolution
-            let file_key_usages = resolve_translation_calls(
-                &result.raw_calls,
-                &result.schema_calls,
-                file_path,
-                comments,
-                schema_cache,
-                &registries.schema,
-                available_keys,
-            );
+    inputs: &ExtractInputs,
+    profile: &Profile,
+) -> ResolvedData {
+    let extracted: Vec<(&String, FileAnalysisResult)> = profile.time("phase 2: extraction", || {
+        files
+            .par_iter()
+            .filter_map(|file_path| {
+                let parsed = parsed_files.get(file_path)?;
+                Some((file_path, extract_file(file_path, parsed, inputs)))
+            })
+            .collect()
+    });
 
-            Some((file_path.clone(), file_key_usages, result.hardcoded_issues))
-        })
-        .collect();
+    let results = profile.time("phase 3: resolution", || {
+        extracted
+            .into_par_iter()
+            .map(|(file_path, result)| (file_path.clone(), resolve_file(file_path, result, inputs)))
+            .collect()
+    });
+
+    merge_resolved_files(results)
+}
+
+/// Phase 2 & 3 for a single file.
+fn analyze_file(file_path: &str, parsed: &ParsedJSX, inputs: &ExtractInputs) -> FileResolvedData {
+    let result = extract_file(file_path, parsed, inputs);
+    resolve_file(file_path, result, inputs)
+}
+
+/// Phase 2: Extraction for a single file.
+fn extract_file(file_path: &str, parsed: &ParsedJSX, inputs: &ExtractInputs) -> FileAnalysisResult {
+    let imports = inputs
+        .file_imports
+        .get(file_path)
+        .cloned()
+        .unwrap_or_default();
+    let comments = file_comments(file_path, inputs);
+
+    // vue-i18n's `$t` and `useI18n()`'s `t` are plain functions without a namespace
+    let vue_callees: Vec<String>;
+    let extra_translation_callees = if file_path.ends_with(".vue") {
+        vue_callees = inputs
+            .extra_translation_callees
+            .iter()
+            .cloned()
+            .chain(
+                VUE_TRANSLATION_CALLEES
+                    .iter()
+                    .map(|callee| callee.to_string()),
+            )
+            .collect();
+        &vue_callees
+    } else {
+        inputs.extra_translation_callees
+    };
+
+    let analyzer = FileAnalyzer::new(
+        file_path,
+        &parsed.source_map,
+        comments,
+        inputs.checked_attributes,
+        inputs.ignore_texts,
+        inputs.ignore_components,
+        !file_path.ends_with(".astro"),
+        parsed.astro_template_start_line,
+        parsed.vue_template_lines.clone(),
+        inputs.registries,
+        &imports,
+        extra_translation_callees,
+        inputs.extra_translation_member_calls,
+        inputs.framework,
+    );
+    analyzer.analyze(&parsed.module)
+}
 
-    // Sequential merge
+/// Phase 3: Resolution for a single file.
+fn resolve_file(
+    file_path: &str,
+    result: FileAnalysisResult,
+    inputs: &ExtractInputs,
+) -> FileResolvedData {
+    let mut key_usages = resolve_translation_calls(
+        &result.raw_calls,
+        &result.schema_calls,
+        file_path,
+        file_comments(file_path, inputs),
+        inputs.schema_cache,
+        &inputs.registries.schema,
+        inputs.available_keys,
+        inputs.key_separator,
+    );
+    key_usages.unresolved.extend(result.unsupported_bindings);
+
+    FileResolvedData {
+        key_usages,
+        hardcoded_issues: result.hardcoded_issues,
+        namespaces: result.namespaces,
+    }
+}
+
+fn file_comments<'a>(file_path: &str, inputs: &ExtractInputs<'a>) -> &'a FileComments {
+    inputs
+        .file_comments
+        .get(file_path)
+        .expect("Comments should be collected in Phase 1")
+}
+
+/// Sequential merge of per-file Phase 2 & 3 output.
+fn merge_resolved_files(results: Vec<(String, FileResolvedData)>) -> ResolvedData {
     let mut key_usages = HashMap::new();
     let mut hardcoded_issues = HashMap::new();
+    let mut namespaces = HashSet::new();
 
-    for (file_path, usages, issues) in results {
-        key_usages.insert(file_path.clone(), usages);
-        hardcoded_issues.insert(file_path, issues);
+    for (file_path, resolved) in results {
+        key_usages.insert(file_path.clone(), resolved.key_usages);
+        hardcoded_issues.insert(file_path, resolved.hardcoded_issues);
+        namespaces.extend(resolved.namespaces);
     }
 
-    (key_usages, hardcoded_issues)
+    ResolvedData {
+        key_usages,
+        hardcoded_issues,
+        namespaces,
+    }
+}
+
+/// Read a source file, mapping I/O errors to a parse error message.
+fn read_source_file(file_path: &str) -> Result<String> {
+    std::fs::read_to_string(file_path).map_err(|e| anyhow!("Failed to read file: {}", e))
+}
+
+/// Parse a source file with its own SourceMap (one per file keeps parsing thread-safe).
+fn parse_source(file_path: &str, code: String) -> Result<ParsedJSX> {
+    let source_map = std::sync::Arc::new(swc_common::SourceMap::default());
+    if file_path.ends_with(".astro") {
+        parse_astro_source(code, file_path, source_map)
+    } else if file_path.ends_with(".vue") {
+        parse_vue_source(code, file_path, source_map)
+    } else {
+        parse_jsx_source(code, file_path, source_map)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{cell::OnceCell, collections::HashSet, path::PathBuf};
+    use std::{
+        cell::OnceCell,
+        collections::{HashMap, HashSet},
+        path::PathBuf,
+    };
 
     use crate::{config::Config, core::*};
 
@@ -899,9 +1885,13 @@ mod tests {
                 ..Config::default()
             },
             root_dir: PathBuf::from(root_dir),
+            config_root: PathBuf::from(root_dir),
+            project: None,
             files: HashSet::new(),
             ignore_texts: HashSet::new(),
+            checked_attributes: Vec::new(),
             verbose: false,
+            cache_path: None,
             parsed_files: OnceCell::new(),
             parsed_files_errors: OnceCell::new(),
             source_metadata: OnceCell::new(),
@@ -909,6 +1899,11 @@ mod tests {
             used_keys: OnceCell::new(),
             resolved_data: OnceCell::new(),
             message_parse_errors: Vec::new(),
+            include_suppressed: false,
+            source_overrides: HashMap::new(),
+            compared_messages: None,
+            source_cache: OnceCell::new(),
+            profile: Default::default(),
         }
     }
 
@@ -975,4 +1970,71 @@ mod tests {
         assert_eq!(result.keys[0].full_key, "key1"); // No namespace
         assert!(!result.keys[0].has_namespace);
     }
+
+    #[test]
+    fn test_update_reanalyzes_changed_files() {
+        use std::fs;
+
+        use crate::cli::args::CommonArgs;
+
+        let source = |key: &str| {
+            format!(
+                "import {{ useTranslations }} from 'next-intl';\n\
+                 export function App() {{\n\
+                 const t = useTranslations('Common');\n\
+                 return <p>{{t('{key}')}}</p>;\n\
+                 }}\n"
+            )
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("messages")).unwrap();
+        fs::create_dir_all(root.join("src/components")).unwrap();
+        fs::write(
+            root.join("messages/en.json"),
+            r#"{"Common": {"a": "A", "b": "B"}}"#,
+        )
+        .unwrap();
+        let app = root.join("src/components/app.tsx");
+        fs::write(&app, source("a")).unwrap();
+
+        let args = CommonArgs {
+            source_root: Some(root.to_path_buf()),
+            cache: true,
+            ..CommonArgs::default()
+        };
+        let mut ctx = CheckContext::new(&args).unwrap();
+        assert_eq!(ctx.used_keys(), &HashSet::from(["Common.a".to_string()]));
+
+        // A changed file is analyzed again, given in another path form
+        fs::write(&app, source("b")).unwrap();
+        let relative = app.strip_prefix(root).unwrap();
+        ctx.update(&[root.join(".").join(relative)]).unwrap();
+        assert_eq!(ctx.used_keys(), &HashSet::from(["Common.b".to_string()]));
+
+        // A new file is picked up, an untouched one keeps its usages
+        let page = root.join("src/components/page.tsx");
+        fs::write(&page, source("c")).unwrap();
+        ctx.update(std::slice::from_ref(&page)).unwrap();
+        assert_eq!(ctx.files.len(), 2);
+        assert_eq!(
+            ctx.used_keys(),
+            &HashSet::from(["Common.b".to_string(), "Common.c".to_string()])
+        );
+
+        // Message changes reload the locale files
+        fs::write(
+            root.join("messages/en.json"),
+            r#"{"Common": {"a": "A", "b": "B", "c": "C"}}"#,
+        )
+        .unwrap();
+        ctx.update(&[root.join("messages/en.json")]).unwrap();
+        assert!(ctx.available_keys().contains("Common.c"));
+
+        // Removed files are dropped
+        fs::remove_file(&page).unwrap();
+        ctx.update(&[page]).unwrap();
+        assert_eq!(ctx.files.len(), 1);
+        assert_eq!(ctx.used_keys(), &HashSet::from(["Common.b".to_string()]));
+    }
 }
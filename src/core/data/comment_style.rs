@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// Comment style to use when inserting comments in source code.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CommentStyle {
     /// JavaScript comment: `// comment`
     Js,
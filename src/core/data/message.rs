@@ -1,4 +1,7 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
 /// Position information in message/locale files (JSON).
 ///
@@ -35,7 +38,8 @@ impl MessageLocation {
 
 /// Value type for translation messages.
 ///
-/// next-intl supports two value types for translation messages:
+/// next-intl supports two value types for translation messages, and keys can
+/// also name a nested object of messages:
 ///
 /// 1. **String**: A simple string value (most common)
 ///    - JSON: `"key": "value"`
@@ -46,6 +50,11 @@ impl MessageLocation {
 ///    - Usage: `t.raw("key")` returns array
 ///    - The `raw()` method explicitly requests array type
 ///
+/// 3. **Object**: A nested object of messages
+///    - JSON: `"key": { "title": "Title" }`
+///    - Only used to compare shapes between locales; object keys are not
+///      message entries
+///
 /// Type mismatches are detected when:
 /// - Code uses `t.raw("key")` but locale has string value
 /// - Code uses `t("key")` expecting string but locale has array
@@ -58,6 +67,9 @@ pub enum ValueType {
     /// A string array value: `"rich": ["Bold ", "text"]`
     /// Accessed via `t.raw("rich")` to get the array.
     StringArray,
+
+    /// A nested object: `"Common": { "submit": "Submit" }`
+    Object,
 }
 
 impl fmt::Display for ValueType {
@@ -65,6 +77,7 @@ impl fmt::Display for ValueType {
         match self {
             ValueType::String => write!(f, "string"),
             ValueType::StringArray => write!(f, "array"),
+            ValueType::Object => write!(f, "object"),
         }
     }
 }
@@ -94,6 +107,34 @@ impl LocaleTypeMismatch {
     }
 }
 
+/// Information about a locale whose ICU placeholders differ from the primary locale.
+///
+/// Used in `PlaceholderMismatchIssue`. Names are sorted for stable output.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LocalePlaceholderMismatch {
+    pub locale: String,
+    /// Placeholders present in the primary locale but absent here.
+    pub missing: Vec<String>,
+    /// Placeholders present here but absent in the primary locale.
+    pub extra: Vec<String>,
+    /// Placeholders present in both but with a different argument type.
+    pub changed_type: Vec<String>,
+    pub location: MessageLocation,
+}
+
+/// Information about a locale whose rich text tags differ from the primary locale.
+///
+/// Used in `RichTagMismatchIssue`. Tag names are sorted for stable output.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LocaleRichTagMismatch {
+    pub locale: String,
+    /// Tags present in the primary locale but absent here.
+    pub missing: Vec<String>,
+    /// Tags present here but absent in the primary locale.
+    pub extra: Vec<String>,
+    pub location: MessageLocation,
+}
+
 /// Position with context information in message files.
 ///
 /// Contains the key and value at this location.
@@ -104,6 +145,10 @@ pub struct MessageContext {
     pub key: String,
     /// The translation value.
     pub value: String,
+    /// Namespace prefixed to the key when its file was loaded via `messagePattern`.
+    ///
+    /// The prefix is not part of the file itself; see `file_key()`.
+    pub namespace: Option<String>,
 }
 
 impl MessageContext {
@@ -116,9 +161,26 @@ impl MessageContext {
             location,
             key: key.into(),
             value: value.into(),
+            namespace: None,
         }
     }
 
+    /// Set the namespace the key was prefixed with.
+    pub fn with_namespace(mut self, namespace: Option<String>) -> Self {
+        self.namespace = namespace;
+        self
+    }
+
+    /// The key as written in its file, without the namespace prefix and the
+    /// `separator` that follows it.
+    pub fn file_key(&self, separator: &str) -> &str {
+        self.namespace
+            .as_deref()
+            .and_then(|ns| self.key.strip_prefix(ns))
+            .and_then(|rest| rest.strip_prefix(separator))
+            .unwrap_or(&self.key)
+    }
+
     // Convenience accessors
     pub fn file_path(&self) -> &str {
         &self.location.file_path
@@ -147,6 +209,17 @@ pub struct MessageEntry {
     pub value_type: ValueType,
 }
 
+/// A key defined more than once in the same object of a locale file.
+///
+/// Only the last definition is loaded; the earlier ones are lost silently.
+#[derive(Debug, Clone)]
+pub struct DuplicateKey {
+    /// Location of the repeated definition, with the value that is kept.
+    pub context: MessageContext,
+    /// Line of the first definition.
+    pub first_line: usize,
+}
+
 /// All messages for a single locale.
 #[derive(Debug, Clone)]
 pub struct LocaleMessages {
@@ -156,6 +229,21 @@ pub struct LocaleMessages {
     pub file_path: String,
     /// All message entries, keyed by translation key.
     pub entries: HashMap<String, MessageEntry>,
+    /// Locations of nested objects, keyed by their key path (e.g., "Common").
+    ///
+    /// Kept apart from `entries` so rules only see leaf messages.
+    pub objects: HashMap<String, MessageLocation>,
+    /// Locations of empty arrays, keyed by their key path.
+    ///
+    /// Kept apart from `entries` as an empty array holds no message; only
+    /// empty-value reports them.
+    pub empty_arrays: HashMap<String, MessageLocation>,
+    /// Keys defined more than once in the same object, one per repeat.
+    pub duplicate_keys: Vec<DuplicateKey>,
+    /// Namespace to file path, for locales loaded via `messagePattern`.
+    ///
+    /// Empty when the locale is a single file.
+    pub namespace_files: BTreeMap<String, String>,
 }
 
 pub type AllLocaleMessages = HashMap<String, LocaleMessages>;
@@ -167,6 +255,10 @@ impl LocaleMessages {
             locale: locale.into(),
             file_path: file_path.into(),
             entries: HashMap::new(),
+            objects: HashMap::new(),
+            empty_arrays: HashMap::new(),
+            duplicate_keys: Vec::new(),
+            namespace_files: BTreeMap::new(),
         }
     }
 
@@ -175,6 +267,17 @@ impl LocaleMessages {
         self.entries.get(key)
     }
 
+    /// Get the value type and location of a key, including nested objects.
+    pub fn shape(&self, key: &str) -> Option<(ValueType, &MessageLocation)> {
+        match self.entries.get(key) {
+            Some(entry) => Some((entry.value_type, &entry.context.location)),
+            None => self
+                .objects
+                .get(key)
+                .map(|location| (ValueType::Object, location)),
+        }
+    }
+
     /// Check if a key exists.
     pub fn contains_key(&self, key: &str) -> bool {
         self.entries.contains_key(key)
@@ -225,6 +328,25 @@ mod tests {
         assert_eq!(ctx.key, "Common.submit");
         assert_eq!(ctx.value, "Submit");
     }
+
+    #[test]
+    fn test_message_context_file_key() {
+        let loc = MessageLocation::new("./locales/en/common.json", 2, 1);
+        let plain = MessageContext::new(loc.clone(), "common.submit", "Submit");
+        assert_eq!(plain.file_key("."), "common.submit");
+
+        let namespaced = plain.with_namespace(Some("common".to_string()));
+        assert_eq!(namespaced.file_key("."), "submit");
+
+        let other = MessageContext::new(loc.clone(), "commonly.used", "Used")
+            .with_namespace(Some("common".to_string()));
+        assert_eq!(other.file_key("."), "commonly.used");
+
+        let slashed = MessageContext::new(loc, "common/v1.0", "Version")
+            .with_namespace(Some("common".to_string()));
+        assert_eq!(slashed.file_key("/"), "v1.0");
+    }
+
     #[test]
     fn test_locale_type_mismatch_new() {
         let loc = MessageLocation::new("./messages/zh.json", 8, 1);
@@ -238,6 +360,7 @@ mod tests {
     fn test_value_type_display() {
         assert_eq!(ValueType::String.to_string(), "string");
         assert_eq!(ValueType::StringArray.to_string(), "array");
+        assert_eq!(ValueType::Object.to_string(), "object");
     }
 
     #[test]
@@ -15,7 +15,8 @@ pub mod source;
 
 pub use comment_style::CommentStyle;
 pub use message::{
-    AllLocaleMessages, LocaleMessages, LocaleTypeMismatch, MessageContext, MessageEntry,
-    MessageLocation, ValueType,
+    AllLocaleMessages, DuplicateKey, LocaleMessages, LocalePlaceholderMismatch,
+    LocaleRichTagMismatch, LocaleTypeMismatch, MessageContext, MessageEntry, MessageLocation,
+    ValueType,
 };
 pub use source::{SourceContext, SourceLocation};
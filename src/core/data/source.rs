@@ -1,7 +1,8 @@
 use crate::core::data::comment_style::CommentStyle;
+use serde::{Deserialize, Serialize};
 
 /// Pure position information in source code files (TSX/JSX/TS/JS/Astro).
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct SourceLocation {
     pub file_path: String,
     pub line: usize,
@@ -23,7 +24,7 @@ impl SourceLocation {
 /// Contains everything needed for:
 /// - Reporter: to display the issue location and source context
 /// - Action: to insert comments (knows whether to use JSX or JS comment style)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SourceContext {
     pub location: SourceLocation,
     /// The source code line content for display.
@@ -10,8 +10,8 @@ use crate::core::extract::translation_source::TranslationSource;
 /// Manages translation function bindings with scope tracking.
 ///
 /// This struct maintains a stack of scopes, where each scope contains
-/// bindings for translation functions. When entering a function or
-/// arrow function body, a new scope is pushed. When exiting, it's popped.
+/// bindings for translation functions. When entering a function, arrow
+/// function or block body, a new scope is pushed. When exiting, it's popped.
 ///
 /// # Example
 /// ```ignore
@@ -87,6 +87,15 @@ impl BindingContext {
             .is_some_and(|scope| scope.contains_key(name))
     }
 
+    /// Check if a name is bound in any scope other than the global one, like a
+    /// function parameter or a local variable.
+    pub fn has_local_binding(&self, name: &str) -> bool {
+        self.bindings_stack
+            .iter()
+            .skip(1) // Skip global scope
+            .any(|scope| scope.contains_key(name))
+    }
+
     /// Check if a name has a binding in any outer scope (excluding current scope).
     /// Used for detecting shadowing.
     pub fn has_outer_binding(&self, name: &str) -> bool {
@@ -203,4 +212,19 @@ mod tests {
         // Still has outer binding even though current scope also has t
         assert!(ctx.has_outer_binding("t"));
     }
+
+    #[test]
+    fn test_has_local_binding() {
+        let mut ctx = BindingContext::new();
+        ctx.shadow_bindings(["NS".to_string()].into_iter());
+        assert!(!ctx.has_local_binding("NS")); // Global scope only
+
+        ctx.enter_scope();
+        assert!(!ctx.has_local_binding("NS"));
+        ctx.shadow_bindings(["NS".to_string()].into_iter());
+        assert!(ctx.has_local_binding("NS"));
+
+        ctx.exit_scope();
+        assert!(!ctx.has_local_binding("NS"));
+    }
 }
@@ -11,26 +11,35 @@
 //! The actual resolution of translation calls to ResolvedKeyUsage/UnresolvedKeyUsage
 //! happens in Phase 3 (see `crate::core::resolve`).
 
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
 
-use swc_common::{Loc, SourceMap, Spanned};
+use swc_common::{Loc, SourceMap, Span, Spanned};
 use swc_ecma_ast::{
-    BinaryOp, BlockStmtOrExpr, CallExpr, Callee, DefaultDecl, Expr, FnDecl, JSXAttr, JSXAttrName,
-    JSXAttrValue, JSXElement, JSXElementName, JSXExpr, JSXExprContainer, JSXFragment, JSXText, Lit,
-    MemberProp, Module, ObjectPatProp, Pat, ReturnStmt, VarDecl, VarDeclarator,
+    BinaryOp, BlockStmt, BlockStmtOrExpr, CallExpr, Callee, DefaultDecl, Expr, ExprOrSpread,
+    FnDecl, JSXAttr, JSXAttrName, JSXAttrValue, JSXElement, JSXElementName, JSXExpr,
+    JSXExprContainer, JSXFragment, JSXText, Lit, MemberProp, Module, ObjectPatProp, OptChainExpr,
+    Pat, Prop, PropOrSpread, ReturnStmt, TaggedTpl, VarDecl, VarDeclarator,
 };
 use swc_ecma_visit::{Visit, VisitWith};
 
-use crate::config::TranslationMemberCallPattern;
+use crate::config::{Framework, TranslationMemberCallPattern, matches_attribute};
 use crate::core::collect::SuppressibleRule;
-use crate::core::{CommentStyle, SourceContext, SourceLocation};
+use crate::core::{
+    CommentStyle, SourceContext, SourceLocation, UnresolvedKeyUsage, UsageUnresolvedKeyReason,
+};
 use crate::issues::HardcodedTextIssue;
 use crate::utils::contains_alphabetic;
 
 use crate::core::collect::Registries;
+use crate::core::collect::registry::helpers::{
+    extract_jsx_member_name, extract_prop_name, find_jsx_attr, find_object_prop, unwrap_ts_expr,
+};
 use crate::core::extract::{
     BindingContext, RawTranslationCall, TranslationCallKind, TranslationSource, ValueAnalyzer,
-    ValueSource,
+    ValueSource, value_analyzer::IteratorBindingType,
 };
 use crate::core::{
     collect::types::{
@@ -38,11 +47,21 @@ use crate::core::{
     },
     schema::SchemaCallInfo,
     utils::{
-        extract_namespace_from_call, extract_t_from_destructuring, is_destructuring_hook,
-        is_translation_hook,
+        VUE_I18N_HOOKS, extract_namespace_from_call, extract_t_from_destructuring,
+        is_destructuring_hook, is_non_translation_hook, is_translation_hook, module_string_consts,
+        normalize_call, opt_chain_call, zip_array_destructuring,
     },
 };
 
+/// Separator between namespace and key in react-i18next keys (`ns:key`).
+const NAMESPACE_SEPARATOR: char = ':';
+
+/// react-intl component that renders the message named by its `id` prop.
+const FORMATTED_MESSAGE: &str = "FormattedMessage";
+
+/// react-intl function that formats a message descriptor (`{ id, defaultMessage }`).
+const FORMAT_MESSAGE: &str = "formatMessage";
+
 /// Tracks JSX context state during AST traversal.
 ///
 /// These flags are independent (not mutually exclusive) and help determine:
@@ -59,6 +78,9 @@ struct JsxState {
     /// Inside a checked attribute (placeholder, title, alt, aria-*, etc.).
     /// Only these attributes are checked for hardcoded text.
     in_checked_attr: bool,
+    /// Inside the children of an element listed in `hardcoded.ignoreComponents`.
+    /// Nested elements reset it, so only direct text children are skipped.
+    in_ignored_component: bool,
     /// Inside a JSX expression container `{...}`.
     in_expr: bool,
     /// The line where the current expression container opens (1-indexed).
@@ -99,6 +121,7 @@ impl JsxState {
             in_context: true,
             in_attr: false,
             in_checked_attr: false,
+            in_ignored_component: false,
             in_expr: false,
             expr_start_line: 0,
         }
@@ -118,6 +141,11 @@ pub struct FileAnalysisResult {
     pub raw_calls: Vec<RawTranslationCall>,
     /// Schema function calls (e.g., `loginSchema(t)`) for schema validation rules.
     pub schema_calls: Vec<SchemaCallInfo>,
+    /// Namespaces passed to translation hooks (e.g., `useTranslations("Common")`).
+    pub namespaces: HashSet<String>,
+    /// Translation functions destructured from next-intl hooks, like
+    /// `const { t } = useTranslations()`, whose calls can't be tracked.
+    pub unsupported_bindings: Vec<UnresolvedKeyUsage>,
 }
 
 /// Combined analyzer that collects translation calls and detects hardcoded text in a single AST pass.
@@ -160,6 +188,7 @@ pub struct FileAnalysisResult {
 /// let file_imports: &FileImports = /* ... */;
 /// let checked_attributes: &[String] = /* ... */;
 /// let ignore_texts: &HashSet<String> = /* ... */;
+/// let ignore_components: &[String] = /* ... */;
 /// let module: &Module = /* parsed AST */;
 ///
 /// let analyzer = FileAnalyzer::new(
@@ -168,6 +197,7 @@ pub struct FileAnalysisResult {
 ///     file_comments,
 ///     checked_attributes,
 ///     ignore_texts,
+///     ignore_components,
 ///     registries,
 ///     file_imports,
 /// );
@@ -196,6 +226,9 @@ pub struct FileAnalyzer<'a> {
     /// Stack of statement contexts for determining comment style.
     stmt_context: Vec<StmtContext>,
 
+    /// Names of the enclosing named functions (components), innermost last.
+    component_names: Vec<String>,
+
     // ============================================================
     // Hardcoded text detection fields
     // ============================================================
@@ -206,6 +239,10 @@ pub struct FileAnalyzer<'a> {
     /// Hardcoded text values to ignore (from config `ignoreTexts`).
     ignore_texts: &'a HashSet<String>,
 
+    /// JSX elements whose text children are not checked (from config
+    /// `hardcoded.ignoreComponents`).
+    ignore_components: &'a [String],
+
     /// Whether hardcoded text detection should run for this file.
     enable_hardcoded_checks: bool,
 
@@ -215,6 +252,12 @@ pub struct FileAnalyzer<'a> {
     /// frontmatter, which affects the preferred comment syntax for fixes.
     astro_template_start_line: Option<usize>,
 
+    /// Template lines for Vue files.
+    ///
+    /// Translation calls on these lines were copied out of the `<template>`
+    /// block, so fixes must use HTML comments there.
+    vue_template_lines: Option<RangeInclusive<usize>>,
+
     // ============================================================
     // Translation call collection fields
     // ============================================================
@@ -235,6 +278,10 @@ pub struct FileAnalyzer<'a> {
     /// Additional member-call patterns configured by the user.
     extra_translation_member_calls: &'a [TranslationMemberCallPattern],
 
+    /// Configured i18n framework. Under react-i18next, literal keys written as
+    /// `ns:key` select their namespace explicitly.
+    framework: Framework,
+
     /// Registries from Phase 1 (translation props, fn calls, key objects, etc.).
     registries: &'a Registries,
 
@@ -249,6 +296,15 @@ pub struct FileAnalyzer<'a> {
 
     /// Schema function calls collected during traversal.
     schema_calls: Vec<SchemaCallInfo>,
+
+    /// Module-level string constants, for hooks given their namespace by name.
+    string_consts: HashMap<String, String>,
+
+    /// Namespaces passed to translation hooks during traversal.
+    namespaces: HashSet<String>,
+
+    /// Translation functions destructured from next-intl hooks during traversal.
+    unsupported_bindings: Vec<UnresolvedKeyUsage>,
 }
 
 impl<'a> FileAnalyzer<'a> {
@@ -259,12 +315,15 @@ impl<'a> FileAnalyzer<'a> {
         file_comments: &'a FileComments,
         checked_attributes: &'a [String],
         ignore_texts: &'a HashSet<String>,
+        ignore_components: &'a [String],
         enable_hardcoded_checks: bool,
         astro_template_start_line: Option<usize>,
+        vue_template_lines: Option<RangeInclusive<usize>>,
         registries: &'a Registries,
         file_imports: &'a FileImports,
         extra_translation_callees: &'a [String],
         extra_translation_member_calls: &'a [TranslationMemberCallPattern],
+        framework: Framework,
     ) -> Self {
         Self {
             file_path,
@@ -272,10 +331,13 @@ impl<'a> FileAnalyzer<'a> {
             file_comments,
             jsx_state: JsxState::default(),
             stmt_context: Vec::new(),
+            component_names: Vec::new(),
             checked_attributes,
             ignore_texts,
+            ignore_components,
             enable_hardcoded_checks,
             astro_template_start_line,
+            vue_template_lines,
             binding_context: BindingContext::new(),
             value_analyzer: ValueAnalyzer::new(
                 file_path,
@@ -283,24 +345,32 @@ impl<'a> FileAnalyzer<'a> {
                 &registries.key_array,
                 &registries.string_array,
                 file_imports,
-            ),
+            )
+            .with_re_exports(&registries.re_exports),
             file_imports,
             extra_translation_callees,
             extra_translation_member_calls,
+            framework,
             registries,
             hardcoded_issues: Vec::new(),
             raw_calls: Vec::new(),
             schema_calls: Vec::new(),
+            string_consts: HashMap::new(),
+            namespaces: HashSet::new(),
+            unsupported_bindings: Vec::new(),
         }
     }
 
     /// Main entry point: analyze a module and return results.
     pub fn analyze(mut self, module: &Module) -> FileAnalysisResult {
+        self.string_consts = module_string_consts(module);
         self.visit_module(module);
         FileAnalysisResult {
             hardcoded_issues: self.hardcoded_issues,
             raw_calls: self.raw_calls,
             schema_calls: self.schema_calls,
+            namespaces: self.namespaces,
+            unsupported_bindings: self.unsupported_bindings,
         }
     }
 
@@ -313,12 +383,17 @@ impl<'a> FileAnalyzer<'a> {
     /// Returns false if:
     /// - Line is suppressed with `glot-disable-next-line hardcoded`
     /// - Text is in the ignore list (config `ignoreTexts`)
+    /// - Text is a child of an ignored component (config `hardcoded.ignoreComponents`)
     /// - Text contains no alphabetic characters (pure numbers/symbols)
     fn should_report_hardcoded(&self, line: usize, text: &str) -> bool {
         if !self.enable_hardcoded_checks {
             return false;
         }
 
+        if self.jsx_state.in_ignored_component {
+            return false;
+        }
+
         if self
             .file_comments
             .suppressions
@@ -351,6 +426,15 @@ impl<'a> FileAnalyzer<'a> {
         let trimmed_line = source_line.trim_start();
         let in_astro_template = self.is_astro_template_line(line);
 
+        // Vue template -> markup comment; no JS or JSX exists on these lines
+        if self
+            .vue_template_lines
+            .as_ref()
+            .is_some_and(|lines| lines.contains(&line))
+        {
+            return CommentStyle::Html;
+        }
+
         // A) JSX/Astro attributes -> JS comment
         if self.jsx_state.in_attr {
             return CommentStyle::Js;
@@ -387,6 +471,18 @@ impl<'a> FileAnalyzer<'a> {
         CommentStyle::Js
     }
 
+    /// Whether an element is listed in `hardcoded.ignoreComponents`.
+    ///
+    /// Member expressions match by their full name (e.g., `UI.Code`).
+    fn is_ignored_component(&self, name: &JSXElementName) -> bool {
+        let name = match name {
+            JSXElementName::Ident(ident) => ident.sym.to_string(),
+            JSXElementName::JSXMemberExpr(member) => extract_jsx_member_name(member),
+            JSXElementName::JSXNamespacedName(_) => return false,
+        };
+        self.ignore_components.contains(&name)
+    }
+
     fn is_astro_template_line(&self, line: usize) -> bool {
         self.astro_template_start_line
             .is_some_and(|start_line| line >= start_line)
@@ -485,22 +581,256 @@ impl<'a> FileAnalyzer<'a> {
     /// - `translation_source`: Where the translation function came from (direct, props, fn params)
     /// - `argument`: The key argument (resolved, unresolved literal, template, etc.)
     /// - `call_kind`: Direct call (`t()`) or method call (`t.raw()`, `t.rich()`)
+    /// - `values`: Names of the interpolation values passed with the key, if known
     fn collect_translation_call(
         &mut self,
         loc: Loc,
         translation_source: TranslationSource,
         argument: ValueSource,
         call_kind: TranslationCallKind,
+        values: Option<Vec<String>>,
     ) {
+        let (translation_source, argument) =
+            self.apply_namespace_separator(translation_source, argument);
         let context = self.make_source_context(&loc);
+        let provided_namespaces = self.provided_namespaces();
         self.raw_calls.push(RawTranslationCall {
             context,
             translation_source,
             argument,
             call_kind,
+            provided_namespaces,
+            values,
         });
     }
 
+    /// Track the translation function a hook call binds to `pat`.
+    ///
+    /// - next-intl: `const t = useTranslations("ns")`
+    /// - react-i18next / vue-i18n: `const { t } = useTranslation("ns")`, also
+    ///   renamed as `const { t: translate } = ...`
+    /// - array literals: `const [t, format] = [useTranslations("ns"), useFormatter()]`
+    ///
+    /// next-intl hooks return the translation function itself, so
+    /// `const { t } = useTranslations("ns")` binds nothing. It is reported as
+    /// an unresolved binding instead of silently dropping its keys.
+    fn bind_hook_result(&mut self, pat: &Pat, init: &Expr, span: Span) {
+        if let Some(pairs) = zip_array_destructuring(pat, init) {
+            for (elem_pat, elem) in pairs {
+                self.bind_hook_result(elem_pat, elem, span);
+            }
+            return;
+        }
+
+        let call_expr = match init {
+            Expr::Call(call) => Some(call),
+            Expr::Await(await_expr) => match &*await_expr.arg {
+                Expr::Call(call) => Some(call),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let Some(call) = call_expr else { return };
+        let Callee::Expr(expr) = &call.callee else {
+            return;
+        };
+        let Expr::Ident(ident) = &**expr else { return };
+
+        let fn_name = ident.sym.as_str();
+        let is_vue_hook = self.file_path.ends_with(".vue") && VUE_I18N_HOOKS.contains(&fn_name);
+        if is_translation_hook(fn_name) || is_vue_hook {
+            let namespace = extract_namespace_from_call(call, |name| {
+                if self.binding_context.has_local_binding(name) {
+                    None
+                } else {
+                    self.string_consts.get(name).cloned()
+                }
+            });
+            if let Some(ns) = &namespace {
+                self.namespaces.insert(ns.clone());
+            }
+
+            if is_destructuring_hook(fn_name) {
+                // react-i18next: const { t } = useTranslation("ns")
+                // vue-i18n: const { t } = useI18n()
+                if let Some(t_name) = extract_t_from_destructuring(pat) {
+                    self.binding_context
+                        .insert_binding(t_name, TranslationSource::Direct { namespace });
+                }
+            } else if let Pat::Ident(binding_ident) = pat {
+                // next-intl: const t = useTranslations("ns")
+                let var_name = binding_ident.id.sym.to_string();
+                self.binding_context
+                    .insert_binding(var_name, TranslationSource::Direct { namespace });
+            } else if extract_t_from_destructuring(pat).is_some() {
+                // next-intl: const { t } = useTranslations("ns") is undefined at runtime
+                let loc = self.source_map.lookup_char_pos(span.lo);
+                self.unsupported_bindings.push(UnresolvedKeyUsage {
+                    context: self.make_source_context(&loc),
+                    reason: UsageUnresolvedKeyReason::DestructuredTranslator {
+                        hook: fn_name.to_string(),
+                    },
+                    hint: Some(format!(
+                        "{}() returns the translation function itself: use `const t = {}(...)`",
+                        fn_name, fn_name
+                    )),
+                    pattern: None,
+                });
+            }
+        } else if is_non_translation_hook(fn_name) {
+            // next-intl: const format = useFormatter() never translates,
+            // even when named `t` inside a block under an outer `t`
+            for name in extract_binding_names(pat) {
+                self.binding_context
+                    .insert_binding(name, TranslationSource::Shadowed);
+            }
+        }
+    }
+
+    /// Namespaces a `NextIntlClientProvider` passes to the enclosing component.
+    ///
+    /// Looks up the innermost enclosing function that Phase 1 saw rendered
+    /// inside a provider. Components are matched by name only.
+    fn provided_namespaces(&self) -> Option<Vec<String>> {
+        self.component_names
+            .iter()
+            .rev()
+            .find_map(|name| self.registries.message_provider.get(name))
+            .cloned()
+    }
+
+    /// Apply react-i18next's `ns:key` syntax to a literal key argument.
+    ///
+    /// `t("common:save")` looks up `save` in the `common` namespace regardless of
+    /// the namespace the `t` binding was created with. Other frameworks and
+    /// non-literal arguments are returned unchanged.
+    fn apply_namespace_separator(
+        &self,
+        translation_source: TranslationSource,
+        argument: ValueSource,
+    ) -> (TranslationSource, ValueSource) {
+        if self.framework != Framework::ReactI18next || translation_source.is_shadowed() {
+            return (translation_source, argument);
+        }
+
+        if let ValueSource::Literal(key) = &argument
+            && let Some((namespace, rest)) = key.split_once(NAMESPACE_SEPARATOR)
+            && !namespace.is_empty()
+            && !rest.is_empty()
+        {
+            return (
+                TranslationSource::Direct {
+                    namespace: Some(namespace.to_string()),
+                },
+                ValueSource::Literal(rest.to_string()),
+            );
+        }
+
+        (translation_source, argument)
+    }
+
+    /// Collect the `id` prop of a react-intl `<FormattedMessage>` as a used key.
+    ///
+    /// react-intl keys are flat, so no namespace is applied.
+    fn collect_formatted_message(&mut self, node: &JSXElement) {
+        if self.framework != Framework::ReactIntl
+            || !matches!(&node.opening.name, JSXElementName::Ident(ident) if ident.sym == FORMATTED_MESSAGE)
+        {
+            return;
+        }
+
+        let argument = match find_jsx_attr(node, "id") {
+            Some(JSXAttrValue::Str(s)) => match s.value.as_str() {
+                Some(id) => ValueSource::Literal(id.to_string()),
+                None => return,
+            },
+            Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            })) => self.value_analyzer.analyze_expr(expr),
+            _ => return,
+        };
+
+        let loc = self.source_map.lookup_char_pos(node.span.lo);
+        self.collect_translation_call(
+            loc,
+            TranslationSource::Direct { namespace: None },
+            argument,
+            TranslationCallKind::Direct,
+            None,
+        );
+    }
+
+    /// Collect the `id` of a react-intl `formatMessage({ id: "..." })` descriptor.
+    ///
+    /// Matches both `intl.formatMessage(...)` and a destructured `formatMessage(...)`.
+    fn collect_format_message(&mut self, node: &CallExpr) {
+        if self.framework != Framework::ReactIntl {
+            return;
+        }
+
+        let is_format_message = match &node.callee {
+            Callee::Expr(expr) => match &**expr {
+                Expr::Ident(ident) => ident.sym == FORMAT_MESSAGE,
+                Expr::Member(member) => {
+                    matches!(&member.prop, MemberProp::Ident(prop) if prop.sym == FORMAT_MESSAGE)
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_format_message {
+            return;
+        }
+
+        if let Some(arg) = node.args.first()
+            && let Expr::Object(descriptor) = unwrap_ts_expr(&arg.expr)
+            && let Some(id) = find_object_prop(descriptor, "id")
+        {
+            let loc = self.source_map.lookup_char_pos(node.span.lo);
+            let argument = self.value_analyzer.analyze_expr(id);
+            self.collect_translation_call(
+                loc,
+                TranslationSource::Direct { namespace: None },
+                argument,
+                TranslationCallKind::Direct,
+                None,
+            );
+        }
+    }
+
+    /// Names of the interpolation values in `t("key", { name, count: 1 })`.
+    ///
+    /// Returns an empty list without a second argument, and `None` when the
+    /// names can't be listed statically: a variable, a spread, or a computed
+    /// property name.
+    fn extract_values_arg(args: &[ExprOrSpread]) -> Option<Vec<String>> {
+        let Some(arg) = args.get(1) else {
+            return Some(Vec::new());
+        };
+        if arg.spread.is_some() {
+            return None;
+        }
+        let Expr::Object(values) = unwrap_ts_expr(&arg.expr) else {
+            return None;
+        };
+
+        values
+            .props
+            .iter()
+            .map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(kv) => extract_prop_name(&kv.key),
+                    Prop::Shorthand(ident) => Some(ident.sym.to_string()),
+                    Prop::Method(method) => extract_prop_name(&method.key),
+                    _ => None,
+                },
+                PropOrSpread::Spread(_) => None,
+            })
+            .collect()
+    }
+
     /// Resolve a bare callee name to a translation source.
     fn resolve_direct_translation_source(&self, fn_name: &str) -> Option<TranslationSource> {
         if let Some(translation_source) = self.binding_context.get_binding(fn_name).cloned() {
@@ -808,6 +1138,62 @@ impl<'a> FileAnalyzer<'a> {
         None
     }
 
+    /// Extract the object and callback parameters of an `Object.keys(obj)` or
+    /// `Object.entries(obj)` iteration.
+    ///
+    /// `receiver` is the expression the iteration method is called on, and
+    /// `callback` its first argument. `Object.keys` binds the first parameter
+    /// to the keys; `Object.entries` destructures `[key, value]`.
+    fn extract_object_iteration(
+        receiver: &Expr,
+        callback: &Expr,
+    ) -> Option<(String, Vec<(String, IteratorBindingType)>)> {
+        let Expr::Call(call) = receiver else {
+            return None;
+        };
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let Expr::Member(member) = &**callee else {
+            return None;
+        };
+        let (Expr::Ident(object_ident), MemberProp::Ident(method)) = (&*member.obj, &member.prop)
+        else {
+            return None;
+        };
+        if object_ident.sym != "Object" {
+            return None;
+        }
+        let Some(Expr::Ident(target)) = call.args.first().map(|arg| &*arg.expr) else {
+            return None;
+        };
+        let Expr::Arrow(arrow) = callback else {
+            return None;
+        };
+        let first_param = arrow.params.first()?;
+
+        let params = match (method.sym.as_str(), first_param) {
+            ("keys", Pat::Ident(ident)) => {
+                vec![(ident.id.sym.to_string(), IteratorBindingType::ObjectKeys)]
+            }
+            ("entries", Pat::Array(array)) => array
+                .elems
+                .iter()
+                .zip([
+                    IteratorBindingType::ObjectKeys,
+                    IteratorBindingType::ObjectValues,
+                ])
+                .filter_map(|(elem, binding_type)| match elem {
+                    Some(Pat::Ident(ident)) => Some((ident.id.sym.to_string(), binding_type)),
+                    _ => None,
+                })
+                .collect(),
+            _ => return None,
+        };
+
+        Some((target.sym.to_string(), params))
+    }
+
     /// Execute a closure with a statement context active for a specific line.
     ///
     /// This tracks that JSX on a given line is part of a specific kind of statement,
@@ -821,6 +1207,16 @@ impl<'a> FileAnalyzer<'a> {
         self.stmt_context.pop();
     }
 
+    /// Execute a closure with a named function (component) entered.
+    fn with_component<F>(&mut self, name: &str, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.component_names.push(name.to_string());
+        f(self);
+        self.component_names.pop();
+    }
+
     /// Get the statement context for a specific line, if any.
     fn statement_context_for_line(&self, line: usize) -> Option<StmtKind> {
         self.stmt_context.last().and_then(|ctx| {
@@ -869,8 +1265,14 @@ impl<'a> Visit for FileAnalyzer<'a> {
     }
 
     fn visit_jsx_element(&mut self, node: &JSXElement) {
+        let prev_state = self.jsx_state;
+
+        // Attributes of any element are checked, even inside an ignored component
+        self.jsx_state.in_ignored_component = false;
+
         // Visit opening element (attributes)
         node.opening.visit_with(self);
+        self.collect_formatted_message(node);
 
         // Check if this is a <style> tag (from HardcodedChecker)
         let is_style = if let JSXElementName::Ident(ident) = &node.opening.name {
@@ -883,12 +1285,13 @@ impl<'a> Visit for FileAnalyzer<'a> {
             if let Some(closing) = &node.closing {
                 closing.visit_with(self);
             }
+            self.jsx_state = prev_state;
             return;
         }
 
         // Visit children with JSX context state
-        let prev_state = self.jsx_state;
         self.jsx_state = prev_state.for_children();
+        self.jsx_state.in_ignored_component = self.is_ignored_component(&node.opening.name);
 
         for child in &node.children {
             child.visit_with(self);
@@ -904,8 +1307,10 @@ impl<'a> Visit for FileAnalyzer<'a> {
     fn visit_jsx_fragment(&mut self, node: &JSXFragment) {
         node.opening.visit_with(self);
 
+        // A fragment is transparent: `<Code><>GET</></Code>` is still ignored
         let prev_state = self.jsx_state;
         self.jsx_state = prev_state.for_children();
+        self.jsx_state.in_ignored_component = prev_state.in_ignored_component;
 
         for child in &node.children {
             child.visit_with(self);
@@ -965,7 +1370,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
         self.jsx_state.in_attr = true;
 
         // Only set in_checked_attr for attributes we want to check for hardcoded text
-        if self.checked_attributes.contains(&attr_name) {
+        if matches_attribute(self.checked_attributes, &attr_name) {
             self.jsx_state.in_checked_attr = true;
 
             // Hardcoded detection (only for string values)
@@ -998,7 +1403,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
         self.register_translation_props_from_params(&fn_name, &node.function.params);
         self.register_translation_fn_params(&fn_name, &params);
 
-        node.function.visit_children_with(self);
+        self.with_component(&fn_name, |this| node.function.visit_children_with(this));
         self.binding_context.exit_scope();
     }
 
@@ -1027,7 +1432,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
             self.register_translation_props_from_params(&fn_name, &fn_expr.function.params);
             self.register_translation_fn_params(&fn_name, &params);
 
-            fn_expr.function.visit_children_with(self);
+            self.with_component(&fn_name, |this| fn_expr.function.visit_children_with(this));
             self.binding_context.exit_scope();
             return;
         }
@@ -1049,7 +1454,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
 
             self.register_translation_fn_params(fn_name, &arrow.params);
 
-            arrow.visit_children_with(self);
+            self.with_component(fn_name, |this| arrow.visit_children_with(this));
             self.binding_context.exit_scope();
             return;
         }
@@ -1074,7 +1479,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
             self.register_translation_props_from_params(&fn_name, &fn_expr.function.params);
             self.register_translation_fn_params(&fn_name, &params);
 
-            fn_expr.function.visit_children_with(self);
+            self.with_component(&fn_name, |this| fn_expr.function.visit_children_with(this));
             self.binding_context.exit_scope();
             return;
         }
@@ -1109,6 +1514,13 @@ impl<'a> Visit for FileAnalyzer<'a> {
         self.binding_context.exit_scope();
     }
 
+    fn visit_block_stmt(&mut self, node: &BlockStmt) {
+        // `const`/`let` in a block only shadow outer bindings until the block ends
+        self.binding_context.enter_scope();
+        node.visit_children_with(self);
+        self.binding_context.exit_scope();
+    }
+
     fn visit_var_declarator(&mut self, node: &VarDeclarator) {
         node.name.visit_with(self);
         if let Some(init) = &node.init {
@@ -1135,7 +1547,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
 
                 self.register_translation_fn_params(&fn_name, &arrow.params);
 
-                arrow.visit_children_with(self);
+                self.with_component(&fn_name, |this| arrow.visit_children_with(this));
                 self.binding_context.exit_scope();
                 continue;
             }
@@ -1149,47 +1561,13 @@ impl<'a> Visit for FileAnalyzer<'a> {
                 self.register_translation_props_from_params(&fn_name, &function.params);
                 self.register_translation_fn_params(&fn_name, &params);
 
-                function.visit_children_with(self);
+                self.with_component(&fn_name, |this| function.visit_children_with(this));
                 self.binding_context.exit_scope();
                 continue;
             }
 
             if let Some(init) = &decl.init {
-                let call_expr = match &**init {
-                    Expr::Call(call) => Some(call),
-                    Expr::Await(await_expr) => match &*await_expr.arg {
-                        Expr::Call(call) => Some(call),
-                        _ => None,
-                    },
-                    _ => None,
-                };
-
-                if let Some(call) = call_expr
-                    && let Callee::Expr(expr) = &call.callee
-                    && let Expr::Ident(ident) = &**expr
-                {
-                    let fn_name = ident.sym.as_str();
-                    if is_translation_hook(fn_name) {
-                        let namespace = extract_namespace_from_call(call);
-
-                        if is_destructuring_hook(fn_name) {
-                            // react-i18next: const { t } = useTranslation("ns")
-                            if let Some(t_name) = extract_t_from_destructuring(&decl.name) {
-                                self.binding_context.insert_binding(
-                                    t_name,
-                                    TranslationSource::Direct {
-                                        namespace: namespace.clone(),
-                                    },
-                                );
-                            }
-                        } else if let Pat::Ident(binding_ident) = &decl.name {
-                            // next-intl: const t = useTranslations("ns")
-                            let var_name = binding_ident.id.sym.to_string();
-                            self.binding_context
-                                .insert_binding(var_name, TranslationSource::Direct { namespace });
-                        }
-                    }
-                }
+                self.bind_hook_result(&decl.name, init, decl.span);
 
                 if let Some(object_name) = Self::extract_object_access_name(init)
                     && let Pat::Ident(binding_ident) = &decl.name
@@ -1203,7 +1581,40 @@ impl<'a> Visit for FileAnalyzer<'a> {
         }
     }
 
+    fn visit_opt_chain_expr(&mut self, node: &OptChainExpr) {
+        // t?.("key") and t?.raw("key") are handled like plain calls
+        match opt_chain_call(node) {
+            Some(call) => self.visit_call_expr(&call),
+            None => node.visit_children_with(self),
+        }
+    }
+
+    fn visit_tagged_tpl(&mut self, node: &TaggedTpl) {
+        // Tagged translations: t`key` is handled like t(`key`)
+        if let Expr::Ident(ident) = &*node.tag
+            && let Some(translation_source) =
+                self.resolve_direct_translation_source(ident.sym.as_str())
+        {
+            let loc = self.source_map.lookup_char_pos(node.span.lo);
+            let argument = self.value_analyzer.analyze_template(&node.tpl);
+            self.collect_translation_call(
+                loc,
+                translation_source,
+                argument,
+                TranslationCallKind::Direct,
+                None,
+            );
+        }
+
+        node.visit_children_with(self);
+    }
+
     fn visit_call_expr(&mut self, node: &CallExpr) {
+        // t!("key") and t!.raw("key") are handled like plain calls
+        if let Some(call) = normalize_call(node) {
+            return self.visit_call_expr(&call);
+        }
+
         // Handle direct translation calls: t("key") or configured bare callees
         if let Callee::Expr(expr) = &node.callee
             && let Expr::Ident(ident) = &**expr
@@ -1215,11 +1626,13 @@ impl<'a> Visit for FileAnalyzer<'a> {
             {
                 let loc = self.source_map.lookup_char_pos(node.span.lo);
                 let argument = self.value_analyzer.analyze_expr(&arg.expr);
+                let values = Self::extract_values_arg(&node.args);
                 self.collect_translation_call(
                     loc,
                     translation_source,
                     argument,
                     TranslationCallKind::Direct,
+                    values,
                 );
             }
 
@@ -1259,7 +1672,8 @@ impl<'a> Visit for FileAnalyzer<'a> {
             }
         }
 
-        // Handle method calls: t.raw("key"), t.rich("key"), t.markup("key"), i18n.t("key")
+        // Handle method calls: t.raw("key"), t.rich("key"), t.markup("key"), t.has("key"),
+        // i18n.t("key")
         if let Callee::Expr(expr) = &node.callee
             && let Expr::Member(member) = &**expr
             && let Expr::Ident(obj_ident) = &*member.obj
@@ -1268,7 +1682,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
             let obj_name = obj_ident.sym.as_str();
             let method_name = method_ident.sym.as_str();
 
-            if matches!(method_name, "raw" | "rich" | "markup")
+            if matches!(method_name, "raw" | "rich" | "markup" | "has")
                 && let Some(translation_source) =
                     self.binding_context.get_binding(obj_name).cloned()
                 && !translation_source.is_shadowed()
@@ -1281,6 +1695,7 @@ impl<'a> Visit for FileAnalyzer<'a> {
                     translation_source,
                     argument,
                     TranslationCallKind::Method(method_name.to_string()),
+                    None,
                 );
             } else if self.is_translation_member_call(obj_name, method_name)
                 && let Some(arg) = node.args.first()
@@ -1292,10 +1707,14 @@ impl<'a> Visit for FileAnalyzer<'a> {
                     TranslationSource::Direct { namespace: None },
                     argument,
                     TranslationCallKind::Direct,
+                    None,
                 );
             }
         }
 
+        // react-intl message descriptors: intl.formatMessage({ id: "key" })
+        self.collect_format_message(node);
+
         // Detect iterator patterns (array.map, forEach, etc.)
         // This enables resolving keys in patterns like: KEYS.map(k => t(`prefix.${k}`))
         let entered_scope = if let Callee::Expr(callee_expr) = &node.callee
@@ -1305,15 +1724,32 @@ impl<'a> Visit for FileAnalyzer<'a> {
                 method.sym.as_str(),
                 "map" | "forEach" | "filter" | "find" | "some" | "every" | "flatMap"
             )
-            && let Expr::Ident(array_ident) = &*member.obj
             && let Some(arg) = node.args.first()
-            && let Some(param_name) = Self::extract_arrow_first_param(&arg.expr)
         {
-            let array_name = array_ident.sym.to_string();
-            self.value_analyzer.enter_scope();
-            self.value_analyzer
-                .register_iterator(&param_name, &array_name);
-            true
+            if let Expr::Ident(array_ident) = &*member.obj
+                && let Some(param_name) = Self::extract_arrow_first_param(&arg.expr)
+            {
+                let array_name = array_ident.sym.to_string();
+                self.value_analyzer.enter_scope();
+                self.value_analyzer
+                    .register_iterator(&param_name, &array_name);
+                true
+            } else if let Some((object_name, params)) =
+                Self::extract_object_iteration(&member.obj, &arg.expr)
+            {
+                // Object.keys(obj).map(k => ...), Object.entries(obj).map(([k, v]) => ...)
+                self.value_analyzer.enter_scope();
+                for (param_name, binding_type) in params {
+                    self.value_analyzer.register_object_iterator(
+                        &param_name,
+                        &object_name,
+                        binding_type,
+                    );
+                }
+                true
+            } else {
+                false
+            }
         } else {
             false
         };
@@ -15,7 +15,7 @@ pub mod value_analyzer;
 pub mod value_source;
 
 pub use binding_context::BindingContext;
-pub use file_analyzer::FileAnalyzer;
+pub use file_analyzer::{FileAnalysisResult, FileAnalyzer};
 pub use raw_call::{RawTranslationCall, TranslationCallKind};
 pub use translation_source::TranslationSource;
 pub use value_analyzer::ValueAnalyzer;
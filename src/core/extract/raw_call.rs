@@ -22,14 +22,15 @@ pub enum TranslationCallKind {
     /// The value type (string vs array) is determined from the locale file.
     Direct,
 
-    /// Method call: `t.raw("key")`, `t.rich("key")`, `t.markup("key")`
+    /// Method call: `t.raw("key")`, `t.rich("key")`, `t.markup("key")`, `t.has("key")`
     ///
     /// These methods explicitly specify the expected value type:
     /// - `raw()`: Expects a plain string value (not a rich text object)
     /// - `rich()`: Expects a rich text object with formatting
     /// - `markup()`: Expects markup/HTML string
+    /// - `has()`: Checks whether the key exists, so it counts as used
     ///
-    /// The string is the method name (e.g., "raw", "rich", "markup").
+    /// The string is the method name (e.g., "raw", "rich", "markup", "has").
     Method(String),
 }
 
@@ -94,4 +95,20 @@ pub struct RawTranslationCall {
     /// - `Direct`: Accept any value type from locale file
     /// - `Method("raw")`: Expect string value (not rich text object)
     pub call_kind: TranslationCallKind,
+
+    /// Namespaces passed by a `NextIntlClientProvider` to the component
+    /// containing this call (see `MessageProviderRegistry`).
+    ///
+    /// `None` when the component isn't known to be rendered inside a provider
+    /// that restricts its messages. Phase 3 uses this to enrich hints for
+    /// unresolved keys.
+    pub provided_namespaces: Option<Vec<String>>,
+
+    /// Names of the interpolation values passed with the key, e.g.
+    /// `["name"]` for `t("greeting", { name })`.
+    ///
+    /// Only recorded for direct `t()` calls: an empty list when no values are
+    /// passed, `None` when they can't be listed statically (or for other
+    /// calls). Phase 3 keeps them for static keys only.
+    pub values: Option<Vec<String>>,
 }
@@ -4,14 +4,17 @@
 //! into `ValueSource` representations. It tracks variable bindings, iterator
 //! parameters, and performs registry lookups to resolve dynamic keys.
 
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
 
 use swc_ecma_ast::{BinExpr, BinaryOp, CondExpr, Expr, Ident, Lit, MemberExpr, MemberProp, Tpl};
 
 use crate::core::{
     collect::types::{
-        FileImports, KeyArrayRegistry, KeyObjectRegistry, StringArrayRegistry, make_registry_key,
-        resolve_import_path,
+        FileImports, KeyArrayRegistry, KeyObjectRegistry, ReExportRegistry, StringArrayRegistry,
+        make_registry_key, resolve_import_path,
     },
     extract::value_source::{UnresolvableReason, ValueSource},
 };
@@ -68,11 +71,16 @@ pub enum IteratorBindingType {
     StringArrayDirect,
     /// Iterating over object array, accessing properties: `items.map(i => t(i.key))`
     ObjectArrayProperty,
+    /// Iterating over object keys: `Object.keys(obj).map(k => t(k))`
+    ObjectKeys,
+    /// Iterating over object values: `Object.entries(obj).map(([k, v]) => t(v))`
+    ObjectValues,
 }
 
 /// Information about an iterator variable binding
 #[derive(Debug, Clone)]
 pub struct IteratorBinding {
+    /// Array being iterated, or object for `ObjectKeys`/`ObjectValues`.
     pub array_name: String,
     pub binding_type: IteratorBindingType,
 }
@@ -96,6 +104,9 @@ pub struct ValueAnalyzer<'a> {
     /// Import information for cross-file resolution
     file_imports: &'a FileImports,
 
+    /// Barrel re-exports, followed when an import target doesn't define the name
+    re_exports: Option<&'a ReExportRegistry>,
+
     /// Maps variable name to its resolved ValueSource
     /// e.g., `{ "key": ObjectAccess { object_name: "toolKeys" } }`
     variable_bindings: HashMap<String, ValueSource>,
@@ -121,12 +132,19 @@ impl<'a> ValueAnalyzer<'a> {
             key_array_registry,
             string_array_registry,
             file_imports,
+            re_exports: None,
             variable_bindings: HashMap::new(),
             // Start with one global scope
             iterator_scopes: vec![HashMap::new()],
         }
     }
 
+    /// Follow barrel re-exports (`export * from`) when resolving imports.
+    pub fn with_re_exports(mut self, re_exports: &'a ReExportRegistry) -> Self {
+        self.re_exports = Some(re_exports);
+        self
+    }
+
     /// Enter a new scope for iterator bindings.
     /// Call this when entering a callback body (e.g., map/forEach arrow function).
     pub fn enter_scope(&mut self) {
@@ -192,7 +210,9 @@ impl<'a> ValueAnalyzer<'a> {
     }
 
     /// Analyze a template literal expression.
-    fn analyze_template(&self, tpl: &Tpl) -> ValueSource {
+    ///
+    /// Also used for the template of tagged translations (`` t`key` ``).
+    pub fn analyze_template(&self, tpl: &Tpl) -> ValueSource {
         // No expressions: static template
         if tpl.exprs.is_empty() {
             return tpl
@@ -301,6 +321,8 @@ impl<'a> ValueAnalyzer<'a> {
                         reason: UnresolvableReason::UnknownVariable(name),
                     }
                 }
+                IteratorBindingType::ObjectKeys => self.resolve_object_keys(&binding.array_name),
+                IteratorBindingType::ObjectValues => self.resolve_object(&binding.array_name),
             };
         }
 
@@ -323,6 +345,29 @@ impl<'a> ValueAnalyzer<'a> {
             if let Some(binding) = self.get_iterator_binding(&obj_name) {
                 return self.resolve_array_property(&binding.array_name, &prop_name);
             }
+
+            // Key object property: Tool.Create
+            if let Some(value) = self.resolve_object_property(&obj_name, &prop_name) {
+                return ValueSource::Literal(value);
+            }
+        }
+
+        // Key object access: Tool[action], or Tool["Create"]
+        if let Expr::Ident(obj_ident) = &*member.obj
+            && let MemberProp::Computed(computed) = &member.prop
+            && self.get_iterator_binding(obj_ident.sym.as_str()).is_none()
+        {
+            let obj_name = obj_ident.sym.as_str();
+            if let Expr::Lit(Lit::Str(s)) = crate::core::utils::unwrap_paren(&computed.expr)
+                && let Some(prop_name) = s.value.as_str()
+                && let Some(value) = self.resolve_object_property(obj_name, prop_name)
+            {
+                return ValueSource::Literal(value);
+            }
+            let source = self.resolve_object(obj_name);
+            if !matches!(source, ValueSource::Unresolvable { .. }) {
+                return source;
+            }
         }
 
         ValueSource::Unresolvable {
@@ -364,6 +409,49 @@ impl<'a> ValueAnalyzer<'a> {
         }
     }
 
+    /// Resolve the property names of an object from the registry.
+    pub fn resolve_object_keys(&self, name: &str) -> ValueSource {
+        let local_key = make_registry_key(self.file_path, name);
+        let object = self.key_object_registry.get(&local_key).or_else(|| {
+            self.resolve_import(name, |key| {
+                self.key_object_registry
+                    .get(key)
+                    .filter(|obj| obj.is_exported && obj.is_module_level)
+            })
+        });
+
+        match object {
+            Some(obj) if !obj.property_names.is_empty() => ValueSource::ObjectKeys {
+                object_name: name.to_string(),
+                candidate_values: obj.property_names.clone(),
+            },
+            _ => ValueSource::Unresolvable {
+                reason: UnresolvableReason::UnknownObject(name.to_string()),
+            },
+        }
+    }
+
+    /// Resolve the value of one property of an object from the registry.
+    ///
+    /// Only objects whose properties all hold strings, like string enums, have
+    /// their property names and values line up; others return None.
+    pub fn resolve_object_property(&self, name: &str, prop_name: &str) -> Option<String> {
+        let local_key = make_registry_key(self.file_path, name);
+        let object = self.key_object_registry.get(&local_key).or_else(|| {
+            self.resolve_import(name, |key| {
+                self.key_object_registry
+                    .get(key)
+                    .filter(|obj| obj.is_exported && obj.is_module_level)
+            })
+        })?;
+
+        if object.property_names.len() != object.candidate_keys.len() {
+            return None;
+        }
+        let index = object.property_names.iter().position(|p| p == prop_name)?;
+        Some(object.candidate_keys[index].clone())
+    }
+
     /// Resolve a string array from the registry.
     pub fn resolve_string_array(&self, name: &str) -> ValueSource {
         // Check local file first
@@ -441,8 +529,49 @@ impl<'a> ValueAnalyzer<'a> {
             resolve_import_path(Path::new(self.file_path), &import_info.module_path)?;
 
         // Look up in the registry using the resolved path
-        let key = make_registry_key(&resolved_path, &import_info.imported_name);
-        lookup(&key)
+        self.resolve_export(
+            &resolved_path,
+            &import_info.imported_name,
+            &lookup,
+            &mut HashSet::new(),
+        )
+    }
+
+    /// Look up a name exported by a file, following its re-exports.
+    ///
+    /// Named re-exports are tried before `export *`, which never passes on
+    /// `default`. `visited` stops re-export cycles between barrels.
+    fn resolve_export<T, F>(
+        &self,
+        file_path: &str,
+        name: &str,
+        lookup: &F,
+        visited: &mut HashSet<(String, String)>,
+    ) -> Option<&'a T>
+    where
+        F: Fn(&str) -> Option<&'a T>,
+    {
+        if let Some(item) = lookup(&make_registry_key(file_path, name)) {
+            return Some(item);
+        }
+        if !visited.insert((file_path.to_string(), name.to_string())) {
+            return None;
+        }
+
+        let re_exports = self.re_exports?.get(file_path)?;
+        let named = re_exports.iter().filter(|r| r.exported_name == name);
+        let star = re_exports
+            .iter()
+            .filter(|r| r.exported_name == "*" && name != "default");
+        named.chain(star).find_map(|re_export| {
+            let target = resolve_import_path(Path::new(file_path), &re_export.module_path)?;
+            let target_name = if re_export.imported_name == "*" {
+                name
+            } else {
+                &re_export.imported_name
+            };
+            self.resolve_export(&target, target_name, lookup, visited)
+        })
     }
 
     // ============================================================
@@ -503,11 +632,32 @@ impl<'a> ValueAnalyzer<'a> {
             );
         }
     }
+
+    /// Register an iterator variable from an `Object.keys`/`Object.entries` iteration.
+    ///
+    /// e.g., `Object.entries(labels).map(([k, v]) => ...)` -> register "k" as
+    /// `ObjectKeys` and "v" as `ObjectValues` of "labels"
+    pub fn register_object_iterator(
+        &mut self,
+        param_name: &str,
+        object_name: &str,
+        binding_type: IteratorBindingType,
+    ) {
+        if let Some(current_scope) = self.iterator_scopes.last_mut() {
+            current_scope.insert(
+                param_name.to_string(),
+                IteratorBinding {
+                    array_name: object_name.to_string(),
+                    binding_type,
+                },
+            );
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::core::collect::types::{ImportInfo, KeyArray, KeyObject, StringArray};
+    use crate::core::collect::types::{ImportInfo, KeyArray, KeyObject, ReExport, StringArray};
     use crate::core::extract::value_analyzer::*;
 
     fn create_empty_analyzer<'a>(
@@ -577,6 +727,7 @@ mod tests {
                 is_exported: false,
                 is_module_level: true,
                 candidate_keys: vec!["keyA".to_string(), "keyB".to_string()],
+                property_names: vec!["create".to_string(), "edit".to_string()],
             },
         );
 
@@ -598,6 +749,105 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_resolve_object_property() {
+        let mut object_registry = KeyObjectRegistry::new();
+        let array_registry = KeyArrayRegistry::new();
+        let string_array_registry = StringArrayRegistry::new();
+        let imports = FileImports::new();
+
+        object_registry.insert(
+            make_registry_key("test.tsx", "Tool"),
+            KeyObject {
+                name: "Tool".to_string(),
+                file_path: "test.tsx".to_string(),
+                is_exported: false,
+                is_module_level: true,
+                candidate_keys: vec!["create".to_string(), "edit".to_string()],
+                property_names: vec!["Create".to_string(), "Edit".to_string()],
+            },
+        );
+        // `{ icon, save: "save" }`: names and values don't line up
+        object_registry.insert(
+            make_registry_key("test.tsx", "mixed"),
+            KeyObject {
+                name: "mixed".to_string(),
+                file_path: "test.tsx".to_string(),
+                is_exported: false,
+                is_module_level: true,
+                candidate_keys: vec!["save".to_string()],
+                property_names: vec!["icon".to_string(), "save".to_string()],
+            },
+        );
+
+        let analyzer = create_empty_analyzer(
+            "test.tsx",
+            &object_registry,
+            &array_registry,
+            &string_array_registry,
+            &imports,
+        );
+
+        assert_eq!(
+            analyzer.resolve_object_property("Tool", "Edit").as_deref(),
+            Some("edit")
+        );
+        assert_eq!(analyzer.resolve_object_property("Tool", "Delete"), None);
+        assert_eq!(analyzer.resolve_object_property("mixed", "save"), None);
+    }
+
+    #[test]
+    fn test_object_keys_and_entries_iteration() {
+        let mut object_registry = KeyObjectRegistry::new();
+        let array_registry = KeyArrayRegistry::new();
+        let string_array_registry = StringArrayRegistry::new();
+        let imports = FileImports::new();
+
+        object_registry.insert(
+            make_registry_key("test.tsx", "labels"),
+            KeyObject {
+                name: "labels".to_string(),
+                file_path: "test.tsx".to_string(),
+                is_exported: false,
+                is_module_level: true,
+                candidate_keys: vec!["saveLabel".to_string(), "loadLabel".to_string()],
+                property_names: vec!["save".to_string(), "load".to_string()],
+            },
+        );
+
+        let mut analyzer = create_empty_analyzer(
+            "test.tsx",
+            &object_registry,
+            &array_registry,
+            &string_array_registry,
+            &imports,
+        );
+
+        // Object.entries(labels).map(([key, label]) => ...)
+        analyzer.enter_scope();
+        analyzer.register_object_iterator("key", "labels", IteratorBindingType::ObjectKeys);
+        analyzer.register_object_iterator("label", "labels", IteratorBindingType::ObjectValues);
+        // Object.keys(unknown).map((other) => ...)
+        analyzer.register_object_iterator("other", "unknown", IteratorBindingType::ObjectKeys);
+
+        let ident = |name: &str| Expr::Ident(Ident::new_no_ctxt(name.into(), swc_common::DUMMY_SP));
+        assert_eq!(
+            analyzer.analyze_expr(&ident("key")).resolve_keys(),
+            Ok(vec!["save".to_string(), "load".to_string()])
+        );
+        assert_eq!(
+            analyzer.analyze_expr(&ident("label")).resolve_keys(),
+            Ok(vec!["saveLabel".to_string(), "loadLabel".to_string()])
+        );
+        assert_eq!(
+            analyzer.analyze_expr(&ident("other")).resolve_keys(),
+            Err(UnresolvableReason::UnknownObject("unknown".to_string()))
+        );
+
+        analyzer.exit_scope();
+        assert!(analyzer.analyze_expr(&ident("key")).resolve_keys().is_err());
+    }
+
     #[test]
     fn test_resolve_array_property_local() {
         let object_registry = KeyObjectRegistry::new();
@@ -758,6 +1008,7 @@ mod tests {
                 is_exported: true,
                 is_module_level: true,
                 candidate_keys: vec!["create".to_string()],
+                property_names: vec!["create".to_string()],
             },
         );
 
@@ -787,4 +1038,107 @@ mod tests {
             if object_name == "toolKeys" && candidate_values == vec!["create".to_string()]
         ));
     }
+
+    #[test]
+    fn test_import_resolves_through_two_barrel_re_exports() {
+        // component.tsx imports from "./barrel", which re-exports "./nested",
+        // which re-exports "./constants" where `toolKeys` is defined
+        let mut object_registry = KeyObjectRegistry::new();
+        object_registry.insert(
+            make_registry_key("constants.ts", "toolKeys"),
+            KeyObject {
+                name: "toolKeys".to_string(),
+                file_path: "constants.ts".to_string(),
+                is_exported: true,
+                is_module_level: true,
+                candidate_keys: vec!["create".to_string()],
+                property_names: vec!["create".to_string()],
+            },
+        );
+
+        let mut re_exports = ReExportRegistry::new();
+        re_exports.insert(
+            "barrel.ts".to_string(),
+            vec![ReExport {
+                exported_name: "keys".to_string(),
+                imported_name: "toolKeys".to_string(),
+                module_path: "./nested".to_string(),
+            }],
+        );
+        re_exports.insert(
+            "nested.ts".to_string(),
+            vec![ReExport {
+                exported_name: "*".to_string(),
+                imported_name: "*".to_string(),
+                module_path: "./constants".to_string(),
+            }],
+        );
+
+        let imports = vec![ImportInfo {
+            local_name: "keys".to_string(),
+            imported_name: "keys".to_string(),
+            module_path: "./barrel".to_string(),
+        }];
+
+        let array_registry = KeyArrayRegistry::new();
+        let string_array_registry = StringArrayRegistry::new();
+
+        let analyzer = create_empty_analyzer(
+            "component.tsx",
+            &object_registry,
+            &array_registry,
+            &string_array_registry,
+            &imports,
+        )
+        .with_re_exports(&re_exports);
+
+        let result = analyzer.resolve_object("keys");
+        assert!(matches!(
+            result,
+            ValueSource::ObjectAccess { object_name, candidate_values }
+            if object_name == "keys" && candidate_values == vec!["create".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_re_export_cycle_returns_unresolvable() {
+        let object_registry = KeyObjectRegistry::new();
+
+        let mut re_exports = ReExportRegistry::new();
+        for (file, target) in [("a.ts", "./b"), ("b.ts", "./a")] {
+            re_exports.insert(
+                file.to_string(),
+                vec![ReExport {
+                    exported_name: "*".to_string(),
+                    imported_name: "*".to_string(),
+                    module_path: target.to_string(),
+                }],
+            );
+        }
+
+        let imports = vec![ImportInfo {
+            local_name: "toolKeys".to_string(),
+            imported_name: "toolKeys".to_string(),
+            module_path: "./a".to_string(),
+        }];
+
+        let array_registry = KeyArrayRegistry::new();
+        let string_array_registry = StringArrayRegistry::new();
+
+        let analyzer = create_empty_analyzer(
+            "component.tsx",
+            &object_registry,
+            &array_registry,
+            &string_array_registry,
+            &imports,
+        )
+        .with_re_exports(&re_exports);
+
+        assert!(matches!(
+            analyzer.resolve_object("toolKeys"),
+            ValueSource::Unresolvable {
+                reason: UnresolvableReason::UnknownObject(_)
+            }
+        ));
+    }
 }
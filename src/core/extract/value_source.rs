@@ -83,6 +83,20 @@ pub enum ValueSource {
         candidate_values: Vec<String>,
     },
 
+    /// Object key iteration: `Object.keys(obj).map(k => k)`
+    ///
+    /// For example, `Object.keys(labels).map(k => t(k))` where
+    /// `labels = { save: "Save", load: "Load" }` resolves to `["save", "load"]`.
+    /// `Object.entries(obj)` destructured as `([k, v])` gives the keys for `k`.
+    ///
+    /// The object must be registered in Phase 1 (Collection) for this to work.
+    ObjectKeys {
+        /// Name of the object variable (e.g., "labels").
+        object_name: String,
+        /// All property names of the object (collected in Phase 1).
+        candidate_values: Vec<String>,
+    },
+
     /// Array iteration accessing a property: `arr.map(item => item.prop)`
     ///
     /// For example, `capabilities.map(cap => cap.titleKey)` where
@@ -246,6 +260,10 @@ impl ValueSource {
                 candidate_values, ..
             } => Ok(candidate_values.clone()),
 
+            ValueSource::ObjectKeys {
+                candidate_values, ..
+            } => Ok(candidate_values.clone()),
+
             ValueSource::ArrayIteration {
                 candidate_values, ..
             } => Ok(candidate_values.clone()),
@@ -259,7 +277,6 @@ impl ValueSource {
     }
 
     /// Get a human-readable description of the value source for error messages.
-    #[allow(dead_code)]
     pub fn source_description(&self) -> String {
         match self {
             ValueSource::Literal(s) => format!("literal \"{}\"", s),
@@ -268,6 +285,9 @@ impl ValueSource {
             ValueSource::ObjectAccess { object_name, .. } => {
                 format!("object \"{}\"", object_name)
             }
+            ValueSource::ObjectKeys { object_name, .. } => {
+                format!("keys of object \"{}\"", object_name)
+            }
             ValueSource::ArrayIteration {
                 array_name,
                 property_name,
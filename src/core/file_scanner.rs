@@ -1,6 +1,7 @@
 use std::{
     collections::HashSet,
-    path::{Path, PathBuf},
+    ffi::OsStr,
+    path::{self, Path, PathBuf},
     sync::Mutex,
 };
 
@@ -9,6 +10,7 @@ use glob::{Pattern, glob};
 use rayon::prelude::*;
 use walkdir::WalkDir;
 
+use super::ignore_file::IgnoreFile;
 use crate::config::TEST_FILE_PATTERNS;
 
 /// Check if a pattern contains glob wildcards (* or ?).
@@ -17,12 +19,158 @@ fn is_glob_pattern(pattern: &str) -> bool {
     pattern.contains('*') || pattern.contains('?')
 }
 
+/// Whether a glob pattern contains no metacharacters at all.
+fn is_literal(text: &str) -> bool {
+    !text.contains(['*', '?', '[', ']'])
+}
+
+/// Config `ignores` and test file patterns, compiled once per scan.
+///
+/// Matching is the same as checking every pattern with `Pattern::matches`,
+/// but the common shapes skip the glob engine: `**/*.stories.tsx` is a
+/// suffix check, and `**/node_modules/**` a check of the path's directories.
+#[derive(Debug, Default)]
+pub struct IgnoreMatcher {
+    /// Root the patterns are relative to; directories above it never match
+    /// `dir_names`.
+    base_dir: PathBuf,
+    /// Literal paths, ignored with everything below them.
+    literal_paths: Vec<PathBuf>,
+    /// `**/*<suffix>`: `*` also matches `/`, so any path ending with `suffix`.
+    suffixes: Vec<String>,
+    /// `**/<name>/**`: paths with a directory named `name`.
+    dir_names: Vec<String>,
+    /// Everything else.
+    globs: Vec<Pattern>,
+}
+
+impl IgnoreMatcher {
+    /// Compile `ignore_patterns` (and the test file patterns) for `base_dir`.
+    ///
+    /// Invalid patterns are skipped, with a warning when `verbose`.
+    pub fn new(
+        base_dir: &str,
+        ignore_patterns: &[String],
+        ignore_test_files: bool,
+        verbose: bool,
+    ) -> Self {
+        let mut matcher = Self {
+            base_dir: PathBuf::from(base_dir),
+            ..Self::default()
+        };
+
+        for p in ignore_patterns {
+            if is_glob_pattern(p) {
+                if let Err(e) = matcher.add_glob(p)
+                    && verbose
+                {
+                    eprintln!(
+                        "{} Invalid ignore pattern '{}': {}",
+                        "warning:".bold().yellow(),
+                        p,
+                        e
+                    );
+                }
+            } else {
+                // Literal path mode: convert to absolute path for prefix matching
+                matcher.literal_paths.push(Path::new(base_dir).join(p));
+            }
+        }
+
+        // Add test file patterns (these are always glob patterns)
+        if ignore_test_files {
+            for p in TEST_FILE_PATTERNS {
+                let _ = matcher.add_glob(p);
+            }
+        }
+
+        matcher
+    }
+
+    fn add_glob(&mut self, pattern: &str) -> Result<(), glob::PatternError> {
+        if let Some(suffix) = pattern.strip_prefix("**/*")
+            && !suffix.is_empty()
+            && is_literal(suffix)
+            && !suffix.contains(path::is_separator)
+        {
+            self.suffixes.push(suffix.to_string());
+        } else if let Some(name) = pattern
+            .strip_prefix("**/")
+            .and_then(|rest| rest.strip_suffix("/**"))
+            && !name.is_empty()
+            && is_literal(name)
+            && !name.contains(path::is_separator)
+        {
+            self.dir_names.push(name.to_string());
+        } else {
+            self.globs.push(Pattern::new(pattern)?);
+        }
+        Ok(())
+    }
+
+    /// Whether `path` (as walked, including `base_dir`) is ignored.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        // Check if path matches any literal ignore path (prefix match)
+        if self
+            .literal_paths
+            .iter()
+            .any(|ignore_path| path.starts_with(ignore_path))
+        {
+            return true;
+        }
+
+        let path_str = path.to_string_lossy();
+        self.matches_str(&path_str)
+    }
+
+    /// Whether everything below `dir` is ignored by a `**/<name>/**` pattern,
+    /// because `dir` or one of its parents below `base_dir` is named `name`.
+    ///
+    /// `is_ignored(dir)` is false for the directory `name` itself, as the
+    /// pattern only matches paths below it.
+    pub fn covers_dir(&self, dir: &Path) -> bool {
+        let relative = dir.strip_prefix(&self.base_dir).unwrap_or(dir);
+        relative.components().any(|component| {
+            self.dir_names
+                .iter()
+                .any(|name| component.as_os_str() == OsStr::new(name))
+        })
+    }
+
+    fn matches_str(&self, path: &str) -> bool {
+        if self.suffixes.iter().any(|suffix| path.ends_with(suffix)) {
+            return true;
+        }
+        if !self.dir_names.is_empty() {
+            let relative = Path::new(path)
+                .strip_prefix(&self.base_dir)
+                .ok()
+                .and_then(Path::to_str)
+                .unwrap_or(path);
+            // Every segment but the last is followed by a separator
+            let mut segments = relative.split(path::is_separator).peekable();
+            while let Some(segment) = segments.next() {
+                if segments.peek().is_some() && self.dir_names.iter().any(|name| name == segment) {
+                    return true;
+                }
+            }
+        }
+        self.globs.iter().any(|p| p.matches(path))
+    }
+}
+
 /// Result of scanning files.
 pub struct ScanResult {
     pub files: HashSet<String>,
     pub skipped_count: usize,
 }
 
+/// Scan `includes` under `base_dir` for source files.
+///
+/// A path is skipped when it matches `ignore_patterns` (config `ignores`) or
+/// the `.glotignore` file in `base_dir`; the two are unioned. Test and story
+/// files are skipped too with `ignore_test_files`, unless an include names
+/// them: `src/__tests__` or `src/Button.stories.tsx` is scanned in full.
 pub fn scan_files(
     base_dir: &str,
     includes: &[String],
@@ -30,41 +178,9 @@ pub fn scan_files(
     ignore_test_files: bool,
     verbose: bool,
 ) -> ScanResult {
-    // Separate ignore patterns into literal paths and glob patterns
-    let mut literal_ignore_paths: Vec<PathBuf> = Vec::new();
-    let mut glob_patterns: Vec<Pattern> = Vec::new();
-
-    // Process user-defined ignore patterns
-    for p in ignore_patterns {
-        if is_glob_pattern(p) {
-            match Pattern::new(p) {
-                Ok(pattern) => glob_patterns.push(pattern),
-                Err(e) => {
-                    if verbose {
-                        eprintln!(
-                            "{} Invalid ignore pattern '{}': {}",
-                            "warning:".bold().yellow(),
-                            p,
-                            e
-                        );
-                    }
-                }
-            }
-        } else {
-            // Literal path mode: convert to absolute path for prefix matching
-            let path = Path::new(base_dir).join(p);
-            literal_ignore_paths.push(path);
-        }
-    }
-
-    // Add test file patterns (these are always glob patterns)
-    if ignore_test_files {
-        for p in TEST_FILE_PATTERNS {
-            if let Ok(pattern) = Pattern::new(p) {
-                glob_patterns.push(pattern);
-            }
-        }
-    }
+    let ignore_matcher = IgnoreMatcher::new(base_dir, ignore_patterns, false, verbose);
+    let test_matcher = IgnoreMatcher::new(base_dir, &[], ignore_test_files, false);
+    let ignore_file = IgnoreFile::load(Path::new(base_dir), verbose);
 
     let dirs_to_scan: Vec<PathBuf> = if includes.is_empty() {
         vec![Path::new(base_dir).to_path_buf()]
@@ -78,7 +194,7 @@ pub fn scan_files(
                 match glob(&pattern_str) {
                     Ok(entries) => {
                         for entry in entries.flatten() {
-                            if entry.is_dir() {
+                            if entry.is_dir() || is_scannable_file(&entry) {
                                 paths.push(entry);
                             }
                         }
@@ -118,11 +234,19 @@ pub fn scan_files(
     let warnings_mutex = Mutex::new(Vec::new());
 
     dirs_to_scan.par_iter().for_each(|dir| {
+        let names_tests = test_matcher.is_ignored(dir) || test_matcher.covers_dir(dir);
         let mut local_files = HashSet::new();
         let mut local_skipped = 0;
         let mut local_warnings = Vec::new();
 
-        for entry in WalkDir::new(dir) {
+        // `.glotignore` prunes whole directories, like git
+        let walker = WalkDir::new(dir).into_iter().filter_entry(|entry| {
+            let path = entry.path();
+            let relative = path.strip_prefix(base_dir).unwrap_or(path);
+            !ignore_file.is_ignored(relative, entry.file_type().is_dir())
+        });
+
+        for entry in walker {
             let entry = match entry {
                 Ok(e) => e,
                 Err(e) => {
@@ -138,23 +262,12 @@ pub fn scan_files(
                 }
             };
             let path = entry.path();
-            let path_str = path.to_string_lossy();
-
-            // Check if path matches any literal ignore path (prefix match)
-            if literal_ignore_paths
-                .iter()
-                .any(|ignore_path| path.starts_with(ignore_path))
-            {
-                continue;
-            }
-
-            // Check if path matches any glob pattern
-            if glob_patterns.iter().any(|p| p.matches(&path_str)) {
+            if ignore_matcher.is_ignored(path) || (!names_tests && test_matcher.is_ignored(path)) {
                 continue;
             }
 
             if path.is_file() && is_scannable_file(path) {
-                local_files.insert(path_str.into());
+                local_files.insert(path.to_string_lossy().into());
             }
         }
 
@@ -182,10 +295,11 @@ pub fn scan_files(
     }
 }
 
-fn is_scannable_file(path: &Path) -> bool {
+/// Whether `path` has the extension of a source file (TSX/JSX/TS/JS/Astro/Vue).
+pub fn is_scannable_file(path: &Path) -> bool {
     matches!(
         path.extension().and_then(|e| e.to_str()),
-        Some("tsx" | "ts" | "jsx" | "js" | "astro")
+        Some("tsx" | "ts" | "jsx" | "js" | "astro" | "vue")
     )
 }
 
@@ -271,6 +385,7 @@ mod tests {
         assert!(is_scannable_file(Path::new("app.jsx")));
         assert!(is_scannable_file(Path::new("app.js")));
         assert!(is_scannable_file(Path::new("app.astro")));
+        assert!(is_scannable_file(Path::new("App.vue")));
         assert!(!is_scannable_file(Path::new("style.css")));
         assert!(!is_scannable_file(Path::new("data.json")));
         assert!(!is_scannable_file(Path::new("README.md")));
@@ -382,6 +497,109 @@ mod tests {
         assert_eq!(result.files.len(), 2);
     }
 
+    #[test]
+    fn test_scan_ignores_story_files() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path();
+
+        File::create(dir_path.join("Button.tsx")).unwrap();
+        File::create(dir_path.join("Button.stories.tsx")).unwrap();
+        File::create(dir_path.join("Card.stories.js")).unwrap();
+
+        let result = scan_files(dir_path.to_str().unwrap(), &[], &[], true, false);
+
+        assert_eq!(result.files.len(), 1);
+        assert!(result.files.iter().any(|f| f.ends_with("Button.tsx")));
+    }
+
+    #[test]
+    fn test_scan_explicit_includes_keep_test_files() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path();
+
+        let src = dir_path.join("src");
+        let tests_dir = src.join("__tests__");
+        fs::create_dir_all(&tests_dir).unwrap();
+        File::create(src.join("app.tsx")).unwrap();
+        File::create(src.join("app.test.tsx")).unwrap();
+        File::create(src.join("Button.stories.tsx")).unwrap();
+        File::create(tests_dir.join("fixture.tsx")).unwrap();
+
+        let includes = vec![
+            "src".to_string(),
+            "src/__tests__".to_string(),
+            "src/Button.stories.tsx".to_string(),
+        ];
+        let result = scan_files(dir_path.to_str().unwrap(), &includes, &[], true, false);
+
+        let mut names: Vec<_> = result
+            .files
+            .iter()
+            .map(|f| {
+                Path::new(f)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["Button.stories.tsx", "app.tsx", "fixture.tsx"]);
+    }
+
+    #[test]
+    fn test_scan_glob_includes_keep_test_files() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path();
+
+        let src = dir_path.join("src");
+        fs::create_dir_all(&src).unwrap();
+        File::create(src.join("app.tsx")).unwrap();
+        File::create(src.join("app.test.tsx")).unwrap();
+        File::create(src.join("Button.stories.tsx")).unwrap();
+
+        let includes = vec!["src/**/*.stories.tsx".to_string()];
+        let result = scan_files(dir_path.to_str().unwrap(), &includes, &[], true, false);
+
+        let names: Vec<_> = result
+            .files
+            .iter()
+            .map(|f| {
+                Path::new(f)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(names, vec!["Button.stories.tsx"]);
+    }
+
+    #[test]
+    fn test_scan_ignores_test_dirs_above_base_dir() {
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("__tests__").join("proj");
+        fs::create_dir_all(&project).unwrap();
+        File::create(project.join("a.tsx")).unwrap();
+        File::create(project.join("a.test.tsx")).unwrap();
+        File::create(project.join("a.stories.tsx")).unwrap();
+
+        let result = scan_files(project.to_str().unwrap(), &[], &[], true, false);
+
+        let names: Vec<_> = result
+            .files
+            .iter()
+            .map(|f| {
+                Path::new(f)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(names, vec!["a.tsx"]);
+    }
+
     #[test]
     fn test_scan_deduplicates_overlapping_includes() {
         let dir = tempdir().unwrap();
@@ -477,6 +695,74 @@ mod tests {
         assert!(!result.files.iter().any(|f| f.ends_with("other.tsx")));
     }
 
+    #[test]
+    fn test_ignore_matcher_agrees_with_glob() {
+        let patterns = [
+            "**/node_modules/**",
+            "**/*.stories.tsx",
+            "**/__tests__/**",
+            "**/*.test.ts",
+            "**/generated/*.ts",
+            "src/**/legacy-*",
+        ];
+        let paths = [
+            "/repo/node_modules/lib.ts",
+            "/repo/node_modules",
+            "node_modules/a.ts",
+            "/repo/my_node_modules/a.ts",
+            "/repo/node_modules_x/a.ts",
+            "/repo/src/Button.stories.tsx",
+            "Button.stories.tsx",
+            ".stories.tsx",
+            "/repo/src/stories.tsx",
+            "/repo/src/Button.stories.tsx/index.ts",
+            "/repo/src/__tests__/a.ts",
+            "/repo/src/__tests__",
+            "/repo/src/app.test.ts",
+            "/repo/src/generated/types.ts",
+            "/repo/src/generated/deep/types.ts",
+            "src/app/legacy-page.tsx",
+            "/repo/src/app.tsx",
+        ];
+
+        for pattern in patterns {
+            let matcher = IgnoreMatcher::new("/repo", &[pattern.to_string()], false, false);
+            let glob = Pattern::new(pattern).unwrap();
+            for path in paths {
+                assert_eq!(
+                    matcher.matches_str(path),
+                    glob.matches(path),
+                    "{} against {}",
+                    pattern,
+                    path
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ignore_matcher_covers_dir() {
+        let matcher = IgnoreMatcher::new("/repo", &["**/generated/*.ts".to_string()], true, false);
+
+        assert!(matcher.covers_dir(Path::new("/repo/src/__tests__")));
+        assert!(matcher.covers_dir(Path::new("/repo/src/__tests__/unit")));
+        assert!(!matcher.is_ignored(Path::new("/repo/src/__tests__")));
+        assert!(!matcher.covers_dir(Path::new("/repo/src/my__tests__")));
+        // Only `**/<name>/**` patterns cover whole directories
+        assert!(!matcher.covers_dir(Path::new("/repo/src/generated")));
+    }
+
+    #[test]
+    fn test_ignore_matcher_skips_dirs_above_base_dir() {
+        let matcher = IgnoreMatcher::new("/tmp/__tests__/proj", &[], true, false);
+
+        assert!(!matcher.covers_dir(Path::new("/tmp/__tests__/proj")));
+        assert!(!matcher.covers_dir(Path::new("/tmp/__tests__/proj/src")));
+        assert!(!matcher.is_ignored(Path::new("/tmp/__tests__/proj/src/app.tsx")));
+        assert!(matcher.is_ignored(Path::new("/tmp/__tests__/proj/src/app.test.tsx")));
+        assert!(matcher.covers_dir(Path::new("/tmp/__tests__/proj/src/__tests__")));
+    }
+
     #[test]
     fn test_is_glob_pattern() {
         assert!(is_glob_pattern("src/*"));
@@ -551,6 +837,37 @@ mod tests {
         assert!(!result.files.iter().any(|f| f.contains("stories")));
     }
 
+    #[test]
+    fn test_scan_unions_glotignore_with_ignores() {
+        let dir = tempdir().unwrap();
+        let dir_path = dir.path();
+
+        // src/app.tsx, src/legacy/Old.tsx, src/legacy/Keep.tsx, src/Button.stories.tsx
+        let legacy = dir_path.join("src").join("legacy");
+        fs::create_dir_all(&legacy).unwrap();
+        File::create(dir_path.join("src/app.tsx")).unwrap();
+        File::create(dir_path.join("src/Button.stories.tsx")).unwrap();
+        File::create(legacy.join("Old.tsx")).unwrap();
+        File::create(legacy.join("Keep.tsx")).unwrap();
+        fs::write(
+            dir_path.join(".glotignore"),
+            "# old code\nlegacy/\n!legacy/Keep.tsx\n",
+        )
+        .unwrap();
+
+        let result = scan_files(
+            dir_path.to_str().unwrap(),
+            &["src".to_owned()],
+            &["**/*.stories.tsx".to_owned()],
+            false,
+            false,
+        );
+
+        // Files under an ignored directory cannot be re-included
+        assert_eq!(result.files.len(), 1);
+        assert!(result.files.iter().any(|f| f.ends_with("src/app.tsx")));
+    }
+
     #[test]
     fn test_scan_ignores_nested_literal_path() {
         let dir = tempdir().unwrap();
@@ -0,0 +1,199 @@
+//! `.glotignore` support.
+//!
+//! The file lives in the source root and uses gitignore syntax:
+//!
+//! - Blank lines and lines starting with `#` are skipped (`\#` for a literal `#`)
+//! - `!pattern` re-includes paths excluded by an earlier pattern
+//! - A trailing `/` only matches directories
+//! - Patterns containing `/` are anchored to the source root; others match at any depth
+//!
+//! As with git, the last matching pattern wins, and a path inside an ignored
+//! directory cannot be re-included (the directory is never walked).
+
+use std::{fs, path::Path};
+
+use colored::Colorize;
+use glob::{MatchOptions, Pattern};
+
+pub const IGNORE_FILE_NAME: &str = ".glotignore";
+
+/// `*` and `?` never match `/`, as in gitignore.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+#[derive(Debug)]
+struct IgnoreRule {
+    pattern: Pattern,
+    negated: bool,
+    dir_only: bool,
+}
+
+/// Parsed `.glotignore` patterns.
+#[derive(Debug, Default)]
+pub struct IgnoreFile {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreFile {
+    /// Load `.glotignore` from `base_dir`. A missing file yields no patterns.
+    pub fn load(base_dir: &Path, verbose: bool) -> Self {
+        match fs::read_to_string(base_dir.join(IGNORE_FILE_NAME)) {
+            Ok(content) => Self::parse(&content, verbose),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Parse gitignore-style content. Invalid patterns are skipped.
+    pub fn parse(content: &str, verbose: bool) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let rule = parse_line(line)?;
+                match rule {
+                    Ok(rule) => Some(rule),
+                    Err(e) => {
+                        if verbose {
+                            eprintln!(
+                                "{} Invalid pattern '{}' in {}: {}",
+                                "warning:".bold().yellow(),
+                                line.trim(),
+                                IGNORE_FILE_NAME,
+                                e
+                            );
+                        }
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether a path relative to the source root is ignored.
+    pub fn is_ignored(&self, relative_path: &Path, is_dir: bool) -> bool {
+        let path = relative_path.to_string_lossy();
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                (is_dir || !rule.dir_only) && rule.pattern.matches_with(&path, MATCH_OPTIONS)
+            })
+            .is_some_and(|rule| !rule.negated)
+    }
+}
+
+fn parse_line(line: &str) -> Option<Result<IgnoreRule, glob::PatternError>> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (negated, line) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let (dir_only, line) = match line.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    if line.is_empty() {
+        return None;
+    }
+
+    // A slash anywhere but the end anchors the pattern to the root
+    let glob = match line.strip_prefix('/') {
+        Some(anchored) => escape(anchored),
+        None if line.contains('/') => escape(line),
+        None => format!("**/{}", escape(line)),
+    };
+
+    Some(Pattern::new(&glob).map(|pattern| IgnoreRule {
+        pattern,
+        negated,
+        dir_only,
+    }))
+}
+
+/// Convert gitignore backslash escapes to glob syntax (`\*` becomes `[*]`).
+fn escape(pattern: &str) -> String {
+    let mut out = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some(next)) => {
+                chars.next();
+                if matches!(next, '*' | '?' | '[' | ']') {
+                    out.push('[');
+                    out.push(next);
+                    out.push(']');
+                } else {
+                    out.push(next);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignored(content: &str, path: &str, is_dir: bool) -> bool {
+        IgnoreFile::parse(content, false).is_ignored(Path::new(path), is_dir)
+    }
+
+    #[test]
+    fn test_comments_and_blank_lines() {
+        let file = IgnoreFile::parse("# generated code\n\n   \n", false);
+        assert!(file.rules.is_empty());
+        assert!(ignored("\\#notes.tsx", "#notes.tsx", false));
+    }
+
+    #[test]
+    fn test_unanchored_matches_any_depth() {
+        assert!(ignored("*.stories.tsx", "Button.stories.tsx", false));
+        assert!(ignored("*.stories.tsx", "src/ui/Button.stories.tsx", false));
+        assert!(!ignored("*.stories.tsx", "src/ui/Button.tsx", false));
+        assert!(ignored("generated", "src/generated", true));
+    }
+
+    #[test]
+    fn test_anchored_patterns() {
+        assert!(ignored("src/generated", "src/generated", true));
+        assert!(!ignored("src/generated", "lib/src/generated", true));
+        assert!(ignored("/mocks", "mocks", true));
+        assert!(!ignored("/mocks", "src/mocks", true));
+        // `*` does not cross directories
+        assert!(!ignored("src/*.tsx", "src/app/page.tsx", false));
+        assert!(ignored("src/**/*.tsx", "src/app/page.tsx", false));
+    }
+
+    #[test]
+    fn test_directory_only() {
+        assert!(ignored("legacy/", "src/legacy", true));
+        assert!(!ignored("legacy/", "src/legacy", false));
+    }
+
+    #[test]
+    fn test_negation_last_match_wins() {
+        let content = "*.ts\n!keep.ts";
+        assert!(ignored(content, "src/drop.ts", false));
+        assert!(!ignored(content, "src/keep.ts", false));
+        assert!(ignored("!keep.ts\n*.ts", "src/keep.ts", false));
+    }
+
+    #[test]
+    fn test_escaped_brackets() {
+        assert!(ignored(
+            "app/\\[locale\\]/admin",
+            "app/[locale]/admin",
+            true
+        ));
+        assert!(!ignored("app/\\[locale\\]/admin", "app/l/admin", true));
+    }
+}
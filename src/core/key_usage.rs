@@ -8,6 +8,7 @@
 //! - **Created in**: Phase 3 (Resolution) from `RawTranslationCall` data
 //! - **Consumed in**: Phase 3+ (Rules) to generate user-facing issues
 
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 use crate::core::collect::SuppressibleRule;
@@ -31,7 +32,7 @@ use crate::core::SourceContext;
 ///
 /// When converting `UnresolvedKeyUsage` to `UnresolvedKeyIssue`, we map this enum
 /// to the user-facing version, dropping internal-only fields like `raw_key`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UsageUnresolvedKeyReason {
     /// Key is a variable: `t(keyName)`
     VariableKey,
@@ -46,6 +47,22 @@ pub enum UsageUnresolvedKeyReason {
         /// The unresolved key pattern (kept for internal tracking).
         raw_key: String,
     },
+
+    /// Key comes from an object none of whose candidate keys exist:
+    /// `t(statusColors[status])` with `statusColors = { ok: "green", ... }`.
+    NotAKeyMap {
+        /// Object variable name (e.g., "statusColors").
+        object_name: String,
+        /// Number of distinct candidate keys.
+        candidate_count: usize,
+    },
+
+    /// Translation function destructured from a hook that returns it directly:
+    /// `const { t } = useTranslations("ns")`.
+    DestructuredTranslator {
+        /// Hook name (e.g., "useTranslations").
+        hook: String,
+    },
 }
 
 impl std::fmt::Display for UsageUnresolvedKeyReason {
@@ -56,6 +73,12 @@ impl std::fmt::Display for UsageUnresolvedKeyReason {
             UsageUnresolvedKeyReason::UnknownNamespace { schema_name, .. } => {
                 write!(f, "unknown namespace for schema '{}'", schema_name)
             }
+            UsageUnresolvedKeyReason::NotAKeyMap { object_name, .. } => {
+                write!(f, "no value of '{}' is a translation key", object_name)
+            }
+            UsageUnresolvedKeyReason::DestructuredTranslator { hook } => {
+                write!(f, "t destructured from {}()", hook)
+            }
         }
     }
 }
@@ -73,7 +96,7 @@ impl std::fmt::Display for UsageUnresolvedKeyReason {
 /// - `"Common.submit"` (namespace "Common", key "submit")
 /// - `"Home.title"` (namespace "Home", key "title")
 /// - `"errors.validation.required"` (namespace "errors", nested keys)
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct FullKey(pub String);
 
 impl FullKey {
@@ -96,7 +119,7 @@ impl std::fmt::Display for FullKey {
 ///
 /// When a translation key comes from a schema call like `loginSchema(t)`,
 /// we track the schema name and file for better error reporting.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SchemaSource {
     /// Schema function name (e.g., "loginSchema").
     pub schema_name: String,
@@ -124,7 +147,7 @@ pub struct SchemaSource {
 /// // Dynamic call with resolved template
 /// KEYS.map(k => t(`prefix.${k}`)) → Multiple ResolvedKeyUsage entries
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResolvedKeyUsage {
     /// The resolved full key (namespace + key path).
     pub key: FullKey,
@@ -139,6 +162,18 @@ pub struct ResolvedKeyUsage {
     /// If this key came from a schema function call (e.g., `loginSchema(t)`),
     /// track the schema source for better error messages.
     pub from_schema: Option<SchemaSource>,
+
+    /// Whether the key is rendered with `t.rich()` or `t.markup()`, so its
+    /// rich text tags must match across locales.
+    #[serde(default)]
+    pub rich_text: bool,
+
+    /// Namespace the written key was joined to: `Some(Some("Common"))` for
+    /// `t("submit")` under `useTranslations("Common")`, `Some(None)` for a key
+    /// written in full. `None` when unknown, as for keys expanded from a
+    /// `glot-message-keys` declaration.
+    #[serde(default)]
+    pub namespace: Option<Option<String>>,
 }
 
 /// An unresolved translation key usage (Phase 3 output).
@@ -161,7 +196,7 @@ pub struct ResolvedKeyUsage {
 /// // Complex template
 /// t(`${a}.${b}`) → UnresolvedKeyUsage { reason: TemplateWithExpr, ... }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnresolvedKeyUsage {
     /// Source code context (file, line, column, source line, comment style).
     pub context: SourceContext,
@@ -179,19 +214,88 @@ pub struct UnresolvedKeyUsage {
     pub pattern: Option<String>,
 }
 
+/// A `glot-message-keys` pattern that matches no key (Phase 3 output).
+///
+/// **Created in**: Phase 3 (Resolution) when a declaration is expanded for an
+/// unresolved call.
+///
+/// **Used in**: Phase 3+ (Rules) to generate `UnmatchedMessageKeysIssue` warnings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnmatchedPatternUsage {
+    /// Source context of the call the declaration applies to.
+    pub context: SourceContext,
+
+    /// The pattern, joined to the call's namespace if it was relative.
+    pub pattern: String,
+
+    /// Rules suppressed on the call's line.
+    pub suppressed_rules: HashSet<SuppressibleRule>,
+}
+
+/// Interpolation values passed with a static key (Phase 3 output).
+///
+/// **Created in**: Phase 3 (Resolution) for direct `t("key", { ... })` calls
+/// whose key is static and whose values can be listed.
+///
+/// **Used in**: Phase 3+ (Rules) to generate `InterpolationMismatchIssue`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterpolationUsage {
+    /// The resolved full key.
+    pub key: FullKey,
+
+    /// Source context of the call.
+    pub context: SourceContext,
+
+    /// Names of the values passed, in source order.
+    pub values: Vec<String>,
+
+    /// Rules suppressed on the call's line.
+    pub suppressed_rules: HashSet<SuppressibleRule>,
+}
+
+/// A static key that is empty or only whitespace (Phase 3 output).
+///
+/// **Created in**: Phase 3 (Resolution) for `t("")` calls, including a
+/// conditional branch that is an empty literal. Such keys are not resolved.
+///
+/// **Used in**: Phase 3+ (Rules) to generate `EmptyKeyIssue`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmptyKeyUsage {
+    /// Source context of the call.
+    pub context: SourceContext,
+
+    /// The key as written, e.g. `""` or `"  "`.
+    pub key: String,
+
+    /// Rules suppressed on the call's line.
+    pub suppressed_rules: HashSet<SuppressibleRule>,
+}
+
 /// Key usages extracted from a single file (Phase 3 output).
 ///
 /// This is the output of `resolve_translation_calls()` for one file.
 ///
 /// **Created in**: Phase 3 (Resolution)
 /// **Consumed in**: Phase 3+ (Rules) to check against locale files
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct FileKeyUsages {
     /// Successfully resolved key usages (can be checked against locale files).
     pub resolved: Vec<ResolvedKeyUsage>,
 
     /// Unresolved key usages (generate warnings, cannot be validated).
     pub unresolved: Vec<UnresolvedKeyUsage>,
+
+    /// `glot-message-keys` patterns that matched no key of the primary locale.
+    #[serde(default)]
+    pub unmatched_patterns: Vec<UnmatchedPatternUsage>,
+
+    /// Interpolation values passed with static keys.
+    #[serde(default)]
+    pub interpolations: Vec<InterpolationUsage>,
+
+    /// Static keys that are empty or only whitespace.
+    #[serde(default)]
+    pub empty_keys: Vec<EmptyKeyUsage>,
 }
 
 /// All key usages across the codebase, indexed by file path.
@@ -271,6 +375,8 @@ mod tests {
             context: ctx,
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         };
         assert_eq!(usage.key.as_str(), "Common.submit");
         assert!(usage.from_schema.is_none());
@@ -288,6 +394,8 @@ mod tests {
                 schema_name: "formSchema".to_string(),
                 schema_file: "./src/schemas/form.ts".to_string(),
             }),
+            rich_text: false,
+            namespace: None,
         };
         assert!(usage.from_schema.is_some());
         let schema = usage.from_schema.unwrap();
@@ -4,8 +4,11 @@
 //!
 //! ## Module Structure
 //!
+//! - `cache`: Persistent per-file parse cache (`--cache`)
 //! - `collect`: Phase 1 - Cross-file dependency and comment collection
 //! - `extract`: Phase 2 - File-level raw data collection
+//! - `ignore_file`: `.glotignore` parsing (gitignore syntax)
+//! - `profile`: Wall-clock timings per phase (`check --profile`)
 //! - `resolve`: Phase 3 - Resolution to final ResolvedKeyUsage/UnresolvedKeyUsage
 //! - `schema`: Schema function handling
 //! - `utils`: Helper functions and utilities
@@ -28,28 +31,36 @@
 //!    - Generate warnings for unresolvable dynamic keys
 //!    - Output: Final ResolvedKeyUsage/UnresolvedKeyUsage results
 
+pub mod cache;
 pub mod collect;
 pub mod context;
 mod data;
 pub mod extract;
 mod file_scanner;
+mod ignore_file;
 pub mod key_usage;
 pub mod module_resolver;
 pub mod parsers;
+pub mod profile;
 pub mod resolve;
 pub mod schema;
 pub mod utils;
 
 pub use key_usage::{
-    AllKeyUsages, FileKeyUsages, FullKey, HardcodedText, ResolvedKeyUsage, SchemaSource,
-    UnresolvedKeyUsage, UsageUnresolvedKeyReason,
+    AllKeyUsages, EmptyKeyUsage, FileKeyUsages, FullKey, HardcodedText, InterpolationUsage,
+    ResolvedKeyUsage, SchemaSource, UnmatchedPatternUsage, UnresolvedKeyUsage,
+    UsageUnresolvedKeyReason,
 };
 
 pub use data::{
-    AllLocaleMessages, CommentStyle, LocaleMessages, LocaleTypeMismatch, MessageContext,
-    MessageEntry, MessageLocation, SourceContext, SourceLocation, ValueType,
+    AllLocaleMessages, CommentStyle, DuplicateKey, LocaleMessages, LocalePlaceholderMismatch,
+    LocaleRichTagMismatch, LocaleTypeMismatch, MessageContext, MessageEntry, MessageLocation,
+    SourceContext, SourceLocation, ValueType,
 };
 
+pub use file_scanner::IgnoreMatcher;
+pub use ignore_file::IGNORE_FILE_NAME;
+
 pub use context::{
-    AllHardcodedTextIssues, CheckContext, MessageData, ResolvedData, SourceMetadata,
+    AllHardcodedTextIssues, CheckContext, FileTrace, MessageData, ResolvedData, SourceMetadata,
 };
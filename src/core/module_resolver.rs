@@ -21,6 +21,8 @@ use std::{
 use regex::Regex;
 use serde::Deserialize;
 
+use crate::utils::strip_jsonc;
+
 const PROJECT_CONFIG_FILES: &[&str] = &["tsconfig.json", "jsconfig.json"];
 const SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx"];
 
@@ -264,9 +266,7 @@ fn parse_project_config(config_path: &Path) -> Option<ProjectConfig> {
 
 fn read_project_config(config_path: &Path) -> Option<RawProjectConfig> {
     let content = fs::read_to_string(config_path).ok()?;
-    let without_comments = strip_json_comments(content.trim_start_matches('\u{feff}'));
-    let sanitized = strip_trailing_commas(&without_comments);
-    serde_json::from_str(&sanitized).ok()
+    serde_json::from_str(&strip_jsonc(&content)).ok()
 }
 
 fn resolve_extends_path(config_dir: &Path, extends: &str) -> Option<PathBuf> {
@@ -398,122 +398,6 @@ fn normalize_path(path: PathBuf) -> PathBuf {
     }
 }
 
-fn strip_json_comments(input: &str) -> String {
-    let mut result = String::with_capacity(input.len());
-    let mut chars = input.chars().peekable();
-    let mut in_string = false;
-    let mut escaped = false;
-    let mut in_line_comment = false;
-    let mut in_block_comment = false;
-
-    while let Some(ch) = chars.next() {
-        if in_line_comment {
-            if ch == '\n' {
-                in_line_comment = false;
-                result.push(ch);
-            }
-            continue;
-        }
-
-        if in_block_comment {
-            if ch == '*' && chars.peek() == Some(&'/') {
-                chars.next();
-                in_block_comment = false;
-            } else if ch == '\n' {
-                result.push('\n');
-            }
-            continue;
-        }
-
-        if in_string {
-            result.push(ch);
-            if escaped {
-                escaped = false;
-            } else if ch == '\\' {
-                escaped = true;
-            } else if ch == '"' {
-                in_string = false;
-            }
-            continue;
-        }
-
-        if ch == '"' {
-            in_string = true;
-            result.push(ch);
-            continue;
-        }
-
-        if ch == '/' {
-            match chars.peek().copied() {
-                Some('/') => {
-                    chars.next();
-                    in_line_comment = true;
-                    continue;
-                }
-                Some('*') => {
-                    chars.next();
-                    in_block_comment = true;
-                    continue;
-                }
-                _ => {}
-            }
-        }
-
-        result.push(ch);
-    }
-
-    result
-}
-
-fn strip_trailing_commas(input: &str) -> String {
-    let chars: Vec<char> = input.chars().collect();
-    let mut result = String::with_capacity(input.len());
-    let mut idx = 0;
-    let mut in_string = false;
-    let mut escaped = false;
-
-    while idx < chars.len() {
-        let ch = chars[idx];
-
-        if in_string {
-            result.push(ch);
-            if escaped {
-                escaped = false;
-            } else if ch == '\\' {
-                escaped = true;
-            } else if ch == '"' {
-                in_string = false;
-            }
-            idx += 1;
-            continue;
-        }
-
-        if ch == '"' {
-            in_string = true;
-            result.push(ch);
-            idx += 1;
-            continue;
-        }
-
-        if ch == ',' {
-            let mut next = idx + 1;
-            while next < chars.len() && chars[next].is_whitespace() {
-                next += 1;
-            }
-
-            if next < chars.len() && matches!(chars[next], '}' | ']') {
-                idx += 1;
-                continue;
-            }
-        }
-
-        result.push(ch);
-        idx += 1;
-    }
-
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use std::fs;
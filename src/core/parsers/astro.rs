@@ -184,7 +184,7 @@ fn raw_text_tag_name(rest: &str) -> Option<&'static str> {
     }
 }
 
-fn blank_preserve_bytes(source: &str) -> String {
+pub(crate) fn blank_preserve_bytes(source: &str) -> String {
     let mut bytes = source.as_bytes().to_vec();
     for byte in &mut bytes {
         if *byte != b'\n' && *byte != b'\r' {
@@ -194,7 +194,7 @@ fn blank_preserve_bytes(source: &str) -> String {
     String::from_utf8(bytes).expect("blanked source should stay valid UTF-8")
 }
 
-fn count_newlines(source: &str) -> usize {
+pub(crate) fn count_newlines(source: &str) -> usize {
     source.bytes().filter(|byte| *byte == b'\n').count()
 }
 
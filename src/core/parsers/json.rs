@@ -1,11 +1,20 @@
-use std::{fs, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, fs,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, Result, bail};
 use rayon::prelude::*;
-use serde_json::Value;
+use regex::Regex;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde_json::{Value, value::RawValue};
 
+use super::yaml::{is_yaml_file, parse_yaml_file};
+use crate::config::{Config, LOCALE_PLACEHOLDER};
 use crate::core::{
-    AllLocaleMessages, LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType,
+    AllLocaleMessages, DuplicateKey, LocaleMessages, MessageContext, MessageEntry, MessageLocation,
+    ValueType,
 };
 
 /// A warning from scanning message files.
@@ -19,9 +28,94 @@ pub struct MessageScanWarning {
 pub struct ScanMessagesResult {
     pub messages: AllLocaleMessages,
     pub warnings: Vec<MessageScanWarning>,
+    /// Files left out because their name is not a locale code.
+    pub skipped: Vec<MessageScanWarning>,
+}
+
+/// Decides which message files are locales.
+///
+/// With a `locales` allowlist, only the listed locales are loaded and other
+/// files are skipped quietly. Without one, any name that looks like a locale
+/// code is loaded, and other files are skipped with a warning. The primary
+/// and fallback locales are always loaded.
+#[derive(Debug, Clone, Default)]
+pub struct LocaleFilter {
+    allowlist: Vec<String>,
+    known: Vec<String>,
+}
+
+impl LocaleFilter {
+    pub fn from_config(config: &Config) -> Self {
+        let mut known = vec![config.primary_locale.clone()];
+        known.extend(config.fallback_locales.iter().cloned());
+        Self {
+            allowlist: config.locales.clone(),
+            known,
+        }
+    }
+
+    /// Whether `locale` is loaded, or why it is skipped: `Err(None)` when it
+    /// is left out by the allowlist.
+    fn check(&self, locale: &str) -> std::result::Result<(), Option<String>> {
+        if self.known.iter().any(|known| known == locale) {
+            Ok(())
+        } else if !self.allowlist.is_empty() {
+            if self.allowlist.iter().any(|allowed| allowed == locale) {
+                Ok(())
+            } else {
+                Err(None)
+            }
+        } else if is_locale_code(locale) {
+            Ok(())
+        } else {
+            Err(Some(format!(
+                "\"{}\" is not a locale code; list locales in 'locales' to load it anyway",
+                locale
+            )))
+        }
+    }
+}
+
+/// Whether `name` looks like a BCP 47 language tag, like `en`, `en-US`,
+/// `zh-Hans`, `es-419`, `de-CH-1996` or `pt_BR`.
+///
+/// Lenient on purpose: tags are not checked against the registry, only their
+/// shape, so `backup`, `common` or `en.old` are rejected but `old` or `tmp`
+/// are accepted.
+pub fn is_locale_code(name: &str) -> bool {
+    let mut subtags = name.split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags.all(|subtag| match subtag.len() {
+            // Region
+            2 => subtag.chars().all(|c| c.is_ascii_alphabetic()),
+            // Script, or a variant starting with a digit
+            4 => {
+                subtag.chars().all(|c| c.is_ascii_alphabetic())
+                    || (subtag.starts_with(|c: char| c.is_ascii_digit())
+                        && subtag.chars().all(|c| c.is_ascii_alphanumeric()))
+            }
+            // Numeric region
+            3 => subtag.chars().all(|c| c.is_ascii_digit()),
+            // Variant
+            5..=8 => subtag.chars().all(|c| c.is_ascii_alphanumeric()),
+            _ => false,
+        })
+}
+
+/// A parsed message file and the structural problems found in it.
+///
+/// Files with warnings are still loaded; the warnings are reported as parse
+/// errors so the file gets fixed.
+#[derive(Debug)]
+pub struct ParsedJsonFile {
+    pub messages: LocaleMessages,
+    pub warnings: Vec<MessageScanWarning>,
 }
 
-pub fn parse_json_file(path: &Path, locale: &str) -> Result<LocaleMessages> {
+/// Parse a JSON message file, joining nested keys with `key_separator`.
+pub fn parse_json_file(path: &Path, locale: &str, key_separator: &str) -> Result<ParsedJsonFile> {
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read JSON file: {:?}", path))?;
 
@@ -35,12 +129,165 @@ pub fn parse_json_file(path: &Path, locale: &str) -> Result<LocaleMessages> {
     flatten_json(
         &json,
         String::new(),
+        key_separator,
         &file_path,
         &content,
         &line_index,
         &mut messages,
     );
-    Ok(messages)
+    let repeated = find_duplicate_keys(&content, &line_index)
+        .with_context(|| format!("Failed to parse JSON file: {:?}", path))?;
+    add_duplicate_keys(&mut messages, repeated, key_separator);
+    let warnings = find_shadowed_keys(&json, &content, &line_index, key_separator)
+        .into_iter()
+        .map(|error| MessageScanWarning {
+            file_path: file_path.clone(),
+            error,
+        })
+        .collect();
+    Ok(ParsedJsonFile { messages, warnings })
+}
+
+/// Parse a JSON or YAML message file, depending on its extension.
+pub fn parse_message_file(
+    path: &Path,
+    locale: &str,
+    key_separator: &str,
+) -> Result<ParsedJsonFile> {
+    if is_yaml_file(path) {
+        parse_yaml_file(path, locale, key_separator)
+    } else {
+        parse_json_file(path, locale, key_separator)
+    }
+}
+
+/// Parse a JSON or YAML file whose keys belong to `namespace`.
+///
+/// Keys are prefixed with `namespace` and `key_separator`, while lines are
+/// still looked up using the keys as written in the file.
+pub fn parse_namespaced_json_file(
+    path: &Path,
+    locale: &str,
+    namespace: &str,
+    key_separator: &str,
+) -> Result<ParsedJsonFile> {
+    let parsed = parse_message_file(path, locale, key_separator)?;
+    let mut messages = LocaleMessages::new(locale.to_string(), parsed.messages.file_path);
+    for (key, mut entry) in parsed.messages.entries {
+        let full_key = format!("{}{}{}", namespace, key_separator, key);
+        entry.context.key = full_key.clone();
+        entry.context.namespace = Some(namespace.to_string());
+        messages.entries.insert(full_key, entry);
+    }
+    for (key, location) in parsed.messages.objects {
+        let full_key = format!("{}{}{}", namespace, key_separator, key);
+        messages.objects.insert(full_key, location);
+    }
+    for (key, location) in parsed.messages.empty_arrays {
+        let full_key = format!("{}{}{}", namespace, key_separator, key);
+        messages.empty_arrays.insert(full_key, location);
+    }
+    for mut duplicate in parsed.messages.duplicate_keys {
+        duplicate.context.key = format!("{}{}{}", namespace, key_separator, duplicate.context.key);
+        duplicate.context.namespace = Some(namespace.to_string());
+        messages.duplicate_keys.push(duplicate);
+    }
+    Ok(ParsedJsonFile {
+        messages,
+        warnings: parsed.warnings,
+    })
+}
+
+/// Parse messages embedded in another file, such as a Vue `<i18n>` block.
+///
+/// `content` must keep the block at its original offsets (everything else
+/// blanked) so reported lines match the host file. With `locale` set, the
+/// JSON holds that locale's messages; otherwise its top-level keys are locale
+/// codes (`{"en": {...}, "ja": {...}}`).
+pub fn parse_embedded_messages(
+    content: &str,
+    file_path: &str,
+    locale: Option<&str>,
+    key_separator: &str,
+) -> Result<Vec<LocaleMessages>> {
+    let json: Value = serde_json::from_str(content)
+        .with_context(|| format!("Failed to parse <i18n> block in {}", file_path))?;
+    let line_index = build_line_index(content);
+    let repeated = find_duplicate_keys(content, &line_index)
+        .with_context(|| format!("Failed to parse <i18n> block in {}", file_path))?;
+
+    let keyed_by_locale = locale.is_none();
+    let locales: Vec<(String, &Value)> = match locale {
+        Some(locale) => vec![(locale.to_string(), &json)],
+        None => json
+            .as_object()
+            .map(|map| map.iter().map(|(k, v)| (k.clone(), v)).collect())
+            .unwrap_or_default(),
+    };
+
+    let mut result = Vec::new();
+    for (locale, value) in locales {
+        let mut messages = LocaleMessages::new(locale.clone(), file_path.to_string());
+        if keyed_by_locale {
+            // Flatten under the locale so line lookups start at its object
+            let mut prefixed = LocaleMessages::new(locale.clone(), file_path.to_string());
+            flatten_json(
+                value,
+                locale.clone(),
+                key_separator,
+                file_path,
+                content,
+                &line_index,
+                &mut prefixed,
+            );
+            let prefix = format!("{}{}", locale, key_separator);
+            for (key, mut entry) in prefixed.entries {
+                let Some(key) = key.strip_prefix(&prefix) else {
+                    continue;
+                };
+                entry.context.key = key.to_string();
+                messages.entries.insert(key.to_string(), entry);
+            }
+            for (key, location) in prefixed.objects {
+                if let Some(key) = key.strip_prefix(&prefix) {
+                    messages.objects.insert(key.to_string(), location);
+                }
+            }
+            for (key, location) in prefixed.empty_arrays {
+                if let Some(key) = key.strip_prefix(&prefix) {
+                    messages.empty_arrays.insert(key.to_string(), location);
+                }
+            }
+            // Keys below the locale object, or the locale code itself when
+            // its whole object is repeated
+            let own = repeated
+                .iter()
+                .filter(|key| key.path[0] == locale)
+                .cloned()
+                .map(|mut key| {
+                    if key.path.len() > 1 {
+                        key.path.remove(0);
+                    }
+                    key
+                })
+                .collect();
+            add_duplicate_keys(&mut messages, own, key_separator);
+        } else {
+            flatten_json(
+                value,
+                String::new(),
+                key_separator,
+                file_path,
+                content,
+                &line_index,
+                &mut messages,
+            );
+            add_duplicate_keys(&mut messages, repeated.clone(), key_separator);
+        }
+        result.push(messages);
+    }
+
+    Ok(result)
 }
 
 /// Build an index of line start byte offsets for O(log n) line lookups.
@@ -77,12 +324,18 @@ fn offset_to_line(line_index: &[usize], offset: usize) -> usize {
 /// rather than a string value that happens to contain the same text.
 ///
 /// Uses pre-computed line index for O(log n) line number lookup.
-fn find_key_line(content: &str, key_path: &str, line_index: &[usize]) -> usize {
+fn find_key_line(content: &str, key_path: &str, separator: &str, line_index: &[usize]) -> usize {
     // Split key path: "Common.submit" -> ["Common", "submit"]
-    let parts: Vec<&str> = key_path.split('.').collect();
+    let parts: Vec<&str> = key_path.split(separator).collect();
+    find_key_parts_line(content, &parts, line_index)
+}
 
+/// Find the line of a key given its JSON path segments as written in the file.
+///
+/// Unlike `find_key_line`, a segment may itself contain dots (`"a.b": "x"`).
+fn find_key_parts_line(content: &str, parts: &[&str], line_index: &[usize]) -> usize {
     let mut search_start = 0;
-    for part in &parts {
+    for part in parts {
         let pattern = format!("\"{}\"", part);
         let remaining = &content[search_start..];
 
@@ -122,6 +375,7 @@ fn find_key_line(content: &str, key_path: &str, line_index: &[usize]) -> usize {
 fn flatten_json(
     value: &Value,
     prefix: String,
+    separator: &str,
     file_path: &str,
     content: &str,
     line_index: &[usize],
@@ -129,17 +383,26 @@ fn flatten_json(
 ) {
     match value {
         Value::Object(map) => {
+            if !prefix.is_empty() {
+                let line = find_key_line(content, &prefix, separator, line_index);
+                result.objects.insert(
+                    prefix.clone(),
+                    MessageLocation::with_line(file_path.to_string(), line),
+                );
+            }
             for (key, val) in map {
                 let new_prefix = if prefix.is_empty() {
                     key.clone()
                 } else {
-                    format!("{}.{}", prefix, key)
+                    format!("{}{}{}", prefix, separator, key)
                 };
-                flatten_json(val, new_prefix, file_path, content, line_index, result);
+                flatten_json(
+                    val, new_prefix, separator, file_path, content, line_index, result,
+                );
             }
         }
         Value::String(s) => {
-            let line = find_key_line(content, &prefix, line_index);
+            let line = find_key_line(content, &prefix, separator, line_index);
             let context = MessageContext::new(
                 MessageLocation::with_line(file_path.to_string(), line),
                 prefix.clone(),
@@ -154,8 +417,15 @@ fn flatten_json(
             );
         }
         Value::Array(arr) => {
-            // Empty arrays don't produce any keys
+            // Empty arrays don't produce any keys, only a location for empty-value
             if arr.is_empty() {
+                if !prefix.is_empty() {
+                    let line = find_key_line(content, &prefix, separator, line_index);
+                    result.empty_arrays.insert(
+                        prefix,
+                        MessageLocation::with_line(file_path.to_string(), line),
+                    );
+                }
                 return;
             }
 
@@ -167,7 +437,7 @@ fn flatten_json(
                 // String arrays are accessed as a whole (e.g., t.raw("benefits"))
                 // Store as a single key with joined value for display
                 let values: Vec<&str> = arr.iter().filter_map(|v| v.as_str()).collect();
-                let line = find_key_line(content, &prefix, line_index);
+                let line = find_key_line(content, &prefix, separator, line_index);
                 let context = MessageContext::new(
                     MessageLocation::with_line(file_path.to_string(), line),
                     prefix.clone(),
@@ -187,9 +457,11 @@ fn flatten_json(
                     let new_prefix = if prefix.is_empty() {
                         index.to_string()
                     } else {
-                        format!("{}.{}", prefix, index)
+                        format!("{}{}{}", prefix, separator, index)
                     };
-                    flatten_json(val, new_prefix, file_path, content, line_index, result);
+                    flatten_json(
+                        val, new_prefix, separator, file_path, content, line_index, result,
+                    );
                 }
             }
         }
@@ -197,6 +469,222 @@ fn flatten_json(
     }
 }
 
+/// Find keys that more than one JSON path flattens to.
+///
+/// `{"a.b": "x", "a": {"b": "y"}}` yields `a.b` twice, and loaders disagree
+/// on which value wins. A flat key next to a nested object at the same path
+/// (`"a.b": "x"` and `"a": {"b": {...}}`) is ambiguous the same way.
+///
+/// Returns one error message per ambiguous key, naming every definition.
+fn find_shadowed_keys(
+    json: &Value,
+    content: &str,
+    line_index: &[usize],
+    separator: &str,
+) -> Vec<String> {
+    let mut paths: BTreeMap<String, Vec<(Vec<String>, bool)>> = BTreeMap::new();
+    collect_key_paths(json, separator, &mut Vec::new(), &mut paths);
+
+    paths
+        .into_iter()
+        .filter(|(_, definitions)| {
+            definitions.len() > 1 && definitions.iter().any(|(_, is_leaf)| *is_leaf)
+        })
+        .map(|(key, definitions)| {
+            let locations: Vec<String> = definitions
+                .iter()
+                .map(|(segments, _)| {
+                    let parts: Vec<&str> = segments.iter().map(String::as_str).collect();
+                    let line = find_key_parts_line(content, &parts, line_index);
+                    let path: Vec<String> = parts.iter().map(|p| format!("\"{}\"", p)).collect();
+                    format!("{} (line {})", path.join(" > "), line)
+                })
+                .collect();
+            format!(
+                "Key \"{}\" is defined by both {}; loaders disagree on which value wins",
+                key,
+                locations.join(" and ")
+            )
+        })
+        .collect()
+}
+
+/// Entries of a JSON object in file order, with their values left unparsed.
+///
+/// Unlike a `Map`, repeated keys are all kept.
+pub(crate) struct RawEntries<'a>(pub Vec<(String, &'a RawValue)>);
+
+impl<'de> Deserialize<'de> for RawEntries<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = RawEntries<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a JSON object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> std::result::Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(RawEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// A key defined more than once in the same object, by its path segments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RepeatedKey {
+    /// Segments of the key as written, from the root object.
+    pub path: Vec<String>,
+    /// Line of the first definition.
+    pub first_line: usize,
+    /// Line of this definition.
+    pub line: usize,
+}
+
+/// Find keys repeated within the same object.
+///
+/// `serde_json` keeps the last value of a repeated key, so the earlier
+/// definitions are lost silently. Objects are read as `RawEntries` instead,
+/// which keeps every definition along with its position in `content`.
+///
+/// Returns one entry per definition after the first.
+fn find_duplicate_keys(
+    content: &str,
+    line_index: &[usize],
+) -> serde_json::Result<Vec<RepeatedKey>> {
+    let root: &RawValue = serde_json::from_str(content)?;
+    let mut repeated = Vec::new();
+    collect_repeated_keys(root, content, line_index, &mut Vec::new(), &mut repeated)?;
+    Ok(repeated)
+}
+
+fn collect_repeated_keys(
+    raw: &RawValue,
+    content: &str,
+    line_index: &[usize],
+    path: &mut Vec<String>,
+    repeated: &mut Vec<RepeatedKey>,
+) -> serde_json::Result<()> {
+    let text = raw.get();
+    if text.starts_with('{') {
+        let RawEntries(entries) = serde_json::from_str(text)?;
+        let mut first_lines: HashMap<&str, usize> = HashMap::new();
+        for (key, value) in &entries {
+            let line = key_line(content, value, line_index);
+            path.push(key.clone());
+            match first_lines.get(key.as_str()) {
+                Some(&first_line) => repeated.push(RepeatedKey {
+                    path: path.clone(),
+                    first_line,
+                    line,
+                }),
+                None => {
+                    first_lines.insert(key, line);
+                }
+            }
+            collect_repeated_keys(value, content, line_index, path, repeated)?;
+            path.pop();
+        }
+    } else if text.starts_with('[') {
+        let items: Vec<&RawValue> = serde_json::from_str(text)?;
+        for (index, item) in items.into_iter().enumerate() {
+            path.push(index.to_string());
+            collect_repeated_keys(item, content, line_index, path, repeated)?;
+            path.pop();
+        }
+    }
+    Ok(())
+}
+
+/// Line of the key whose value is `value`, which borrows from `content`.
+///
+/// Only whitespace and a `:` can separate a key from its value, so the key
+/// ends right before them.
+fn key_line(content: &str, value: &RawValue, line_index: &[usize]) -> usize {
+    let offset = value.get().as_ptr() as usize - content.as_ptr() as usize;
+    let key_end = content[..offset]
+        .trim_end()
+        .trim_end_matches(':')
+        .trim_end()
+        .len();
+    offset_to_line(line_index, key_end.saturating_sub(1))
+}
+
+/// Record `repeated` keys in `messages`, along with the value that is kept.
+pub(super) fn add_duplicate_keys(
+    messages: &mut LocaleMessages,
+    repeated: Vec<RepeatedKey>,
+    key_separator: &str,
+) {
+    for RepeatedKey {
+        path,
+        first_line,
+        line,
+    } in repeated
+    {
+        let key = path.join(key_separator);
+        let value = messages
+            .get(&key)
+            .map(|entry| entry.context.value.clone())
+            .unwrap_or_default();
+        let location = MessageLocation::with_line(messages.file_path.clone(), line);
+        messages.duplicate_keys.push(DuplicateKey {
+            context: MessageContext::new(location, key, value),
+            first_line,
+        });
+    }
+}
+
+/// Record the flattened key of every object, expanded array and leaf value,
+/// along with its path segments and whether it is a leaf.
+///
+/// Arrays are treated the same way as in `flatten_json`.
+fn collect_key_paths(
+    value: &Value,
+    separator: &str,
+    segments: &mut Vec<String>,
+    paths: &mut BTreeMap<String, Vec<(Vec<String>, bool)>>,
+) {
+    let is_leaf = match value {
+        Value::Object(map) => {
+            for (key, val) in map {
+                segments.push(key.clone());
+                collect_key_paths(val, separator, segments, paths);
+                segments.pop();
+            }
+            false
+        }
+        Value::Array(arr) if !arr.iter().all(|v| matches!(v, Value::String(_))) => {
+            for (index, val) in arr.iter().enumerate() {
+                segments.push(index.to_string());
+                collect_key_paths(val, separator, segments, paths);
+                segments.pop();
+            }
+            false
+        }
+        Value::Array(_) | Value::String(_) => true,
+        _ => return,
+    };
+
+    if !segments.is_empty() {
+        paths
+            .entry(segments.join(separator))
+            .or_default()
+            .push((segments.clone(), is_leaf));
+    }
+}
+
 /// Extracts locale from filename.
 ///
 /// Examples:
@@ -210,7 +698,11 @@ pub fn extract_locale(path: impl AsRef<Path>) -> Option<String> {
         .map(|s| s.to_string())
 }
 
-pub fn scan_message_files(message_dir: impl AsRef<Path>) -> Result<ScanMessagesResult> {
+pub fn scan_message_files(
+    message_dir: impl AsRef<Path>,
+    key_separator: &str,
+    locale_filter: &LocaleFilter,
+) -> Result<ScanMessagesResult> {
     let message_dir = message_dir.as_ref();
 
     if !message_dir.exists() {
@@ -225,60 +717,247 @@ pub fn scan_message_files(message_dir: impl AsRef<Path>) -> Result<ScanMessagesR
         bail!("'{}' is not a directory.", message_dir.display());
     }
 
-    // Collect all JSON file paths first (fs::read_dir iterator is not Send)
+    // Collect all JSON and YAML file paths first (fs::read_dir iterator is not Send)
+    let mut skipped = Vec::new();
     let json_paths: Vec<_> = fs::read_dir(message_dir)?
         .filter_map(|entry| {
             let entry = entry.ok()?;
             let path = entry.path();
-            if path.extension().and_then(|e| e.to_str()) == Some("json") {
+            if path.extension().and_then(|e| e.to_str()) == Some("json") || is_yaml_file(&path) {
                 Some(path)
             } else {
                 None
             }
         })
+        .filter(|path| {
+            let Some(locale) = extract_locale(path) else {
+                return true;
+            };
+            match locale_filter.check(&locale) {
+                Ok(()) => true,
+                Err(reason) => {
+                    skipped.extend(reason.map(|error| MessageScanWarning {
+                        file_path: path.to_string_lossy().to_string(),
+                        error,
+                    }));
+                    false
+                }
+            }
+        })
         .collect();
 
-    // Parse JSON files in parallel
+    // Parse message files in parallel
     let parse_results: Vec<_> = json_paths
         .par_iter()
         .map(|path| {
             if let Some(locale) = extract_locale(path) {
-                match parse_json_file(path, &locale) {
-                    Ok(messages) => (Some((locale, messages)), None),
+                match parse_message_file(path, &locale, key_separator) {
+                    Ok(parsed) => (Some((locale, parsed.messages)), parsed.warnings),
                     Err(e) => (
                         None,
-                        Some(MessageScanWarning {
+                        vec![MessageScanWarning {
                             file_path: path.to_string_lossy().to_string(),
                             error: e.to_string(),
-                        }),
+                        }],
                     ),
                 }
             } else {
                 (
                     None,
-                    Some(MessageScanWarning {
+                    vec![MessageScanWarning {
                         file_path: path.to_string_lossy().to_string(),
                         error: "Failed to extract locale from filename".to_string(),
-                    }),
+                    }],
                 )
             }
         })
         .collect();
 
     // Merge results sequentially (fast since there are few locale files)
-    let mut result = ScanMessagesResult::default();
-    for (messages_opt, warning_opt) in parse_results {
+    let mut result = ScanMessagesResult {
+        skipped,
+        ..Default::default()
+    };
+    for (messages_opt, warnings) in parse_results {
         if let Some((locale, messages)) = messages_opt {
             result.messages.insert(locale, messages);
         }
-        if let Some(warning) = warning_opt {
-            result.warnings.push(warning);
+        result.warnings.extend(warnings);
+    }
+
+    Ok(result)
+}
+
+/// Scans message files matching a `messagePattern` glob.
+///
+/// `pattern` is a path pattern whose `{locale}` placeholder marks the locale
+/// code, e.g. `./locales/{locale}/*.json`. All files of a locale are merged
+/// into one `LocaleMessages`, with each file's keys prefixed by its basename
+/// (`common.json` -> `common.*`). When `{locale}` is part of the file name
+/// itself, each locale has a single file and no prefix is added.
+///
+/// Keys defined by more than one file of the same locale are reported as
+/// warnings; the first file (in path order) wins.
+pub fn scan_message_pattern(
+    pattern: &str,
+    key_separator: &str,
+    locale_filter: &LocaleFilter,
+) -> Result<ScanMessagesResult> {
+    let glob_pattern = pattern.replace(LOCALE_PLACEHOLDER, "*");
+    let locale_regex = locale_regex(pattern)?;
+    let file_name_has_locale = Path::new(pattern)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.contains(LOCALE_PLACEHOLDER));
+
+    let mut paths: Vec<(String, PathBuf)> = glob::glob(&glob_pattern)
+        .with_context(|| format!("Invalid glob pattern in 'messagePattern': \"{}\"", pattern))?
+        .filter_map(|entry| entry.ok())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            let normalized = normalize_path(&path.to_string_lossy());
+            let locale = locale_regex
+                .captures(&normalized)?
+                .get(1)?
+                .as_str()
+                .to_string();
+            Some((locale, path))
+        })
+        .collect();
+    paths.sort();
+
+    if paths.is_empty() {
+        bail!(
+            "No message files match '{}'.\n\
+             Hint: Check your .glotrc.json 'messagePattern' setting.",
+            pattern
+        );
+    }
+
+    let mut skipped = Vec::new();
+    paths.retain(|(locale, path)| match locale_filter.check(locale) {
+        Ok(()) => true,
+        Err(reason) => {
+            skipped.extend(reason.map(|error| MessageScanWarning {
+                file_path: path.to_string_lossy().to_string(),
+                error,
+            }));
+            false
+        }
+    });
+
+    let parse_results: Vec<_> = paths
+        .par_iter()
+        .map(|(locale, path)| {
+            let namespace = (!file_name_has_locale)
+                .then(|| path.file_stem().and_then(|s| s.to_str()))
+                .flatten();
+            let parsed = match namespace {
+                Some(namespace) => {
+                    parse_namespaced_json_file(path, locale, namespace, key_separator)
+                }
+                None => parse_message_file(path, locale, key_separator),
+            };
+            (locale, path, namespace, parsed)
+        })
+        .collect();
+
+    let mut result = ScanMessagesResult {
+        skipped,
+        ..Default::default()
+    };
+    for (locale, path, namespace, parsed) in parse_results {
+        let file_path = path.to_string_lossy().to_string();
+        let file_messages = match parsed {
+            Ok(parsed) => {
+                result.warnings.extend(parsed.warnings);
+                parsed.messages
+            }
+            Err(e) => {
+                result.warnings.push(MessageScanWarning {
+                    file_path,
+                    error: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let merged = result.messages.entry(locale.clone()).or_insert_with(|| {
+            LocaleMessages::new(locale.clone(), pattern.replace(LOCALE_PLACEHOLDER, locale))
+        });
+        if let Some(namespace) = namespace {
+            merged
+                .namespace_files
+                .entry(namespace.to_string())
+                .or_insert_with(|| file_path.clone());
+        }
+
+        let mut entries: Vec<(String, MessageEntry)> = file_messages.entries.into_iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        for (key, entry) in entries {
+            if let Some(existing) = merged.entries.get(&key) {
+                result.warnings.push(MessageScanWarning {
+                    file_path: file_path.clone(),
+                    error: format!(
+                        "Conflicting key \"{}\" is already defined in {}:{}",
+                        key,
+                        existing.context.file_path(),
+                        existing.context.line()
+                    ),
+                });
+                continue;
+            }
+            merged.entries.insert(key, entry);
+        }
+        for (key, location) in file_messages.objects {
+            merged.objects.entry(key).or_insert(location);
+        }
+        for (key, location) in file_messages.empty_arrays {
+            merged.empty_arrays.entry(key).or_insert(location);
         }
+        merged.duplicate_keys.extend(file_messages.duplicate_keys);
     }
 
     Ok(result)
 }
 
+/// Use forward slashes and drop a leading `./`, which `glob` strips from results.
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
+
+/// Build a regex matching paths of `pattern` that captures the locale code.
+fn locale_regex(pattern: &str) -> Result<Regex> {
+    let pattern = normalize_path(pattern);
+    let mut regex = String::from("^");
+    let mut rest = pattern.as_str();
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix(LOCALE_PLACEHOLDER) {
+            regex.push_str("([^/]+)");
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("**/") {
+            regex.push_str("(?:.*/)?");
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("**") {
+            regex.push_str(".*");
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('*') {
+            regex.push_str("[^/]*");
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('?') {
+            regex.push_str("[^/]");
+            rest = after;
+        } else {
+            let c = rest.chars().next().unwrap_or_default();
+            regex.push_str(&regex::escape(&c.to_string()));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).with_context(|| format!("Invalid 'messagePattern': \"{}\"", pattern))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::LocaleMessages;
@@ -294,6 +973,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -310,6 +990,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_flatten_custom_separator() {
+        let content = "{\n  \"Common\": {\n    \"file.name\": \"Name\"\n  }\n}";
+        let json: Value = serde_json::from_str(content).unwrap();
+        let line_index = build_line_index(content);
+
+        let mut result = LocaleMessages::new("en", "test.json");
+        flatten_json(
+            &json,
+            String::new(),
+            "/",
+            "test.json",
+            content,
+            &line_index,
+            &mut result,
+        );
+
+        // Dots are part of the key segment when another separator is used
+        let entry = result.get("Common/file.name").unwrap();
+        assert_eq!(entry.context.value, "Name");
+        assert_eq!(entry.context.location.line, 3);
+        assert!(result.get("Common.file.name").is_none());
+    }
+
     #[test]
     fn test_flatten_nested() {
         let content = r#"{"Auth": {"Login": {"title": "Login", "button": "Submit"}}}"#;
@@ -320,6 +1024,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -334,6 +1039,11 @@ mod tests {
             result.get("Auth.Login.button").map(|e| &e.context.value),
             Some(&"Submit".to_string())
         );
+
+        let mut objects: Vec<&String> = result.objects.keys().collect();
+        objects.sort();
+        assert_eq!(objects, vec!["Auth", "Auth.Login"]);
+        assert!(!result.contains_key("Auth"));
     }
 
     #[test]
@@ -346,6 +1056,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -392,6 +1103,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -440,12 +1152,128 @@ mod tests {
         let mut file = fs::File::create(&file_path).unwrap();
         write!(file, r#"{{"Common": {{"submit": "Submit"}}}}"#).unwrap();
 
-        let messages = parse_json_file(&file_path, "en").unwrap();
+        let messages = parse_json_file(&file_path, "en", ".").unwrap().messages;
         let entry = messages.get("Common.submit").unwrap();
         assert_eq!(entry.context.value, "Submit");
         assert!(entry.context.location.file_path.ends_with("en.json"));
     }
 
+    #[test]
+    fn test_find_shadowed_keys() {
+        let content = r#"{
+  "Common.submit": "Send",
+  "Common": {
+    "submit": "Submit",
+    "cancel": "Cancel"
+  }
+}"#;
+        let json: Value = serde_json::from_str(content).unwrap();
+        let line_index = build_line_index(content);
+
+        let errors = find_shadowed_keys(&json, content, &line_index, ".");
+
+        assert_eq!(
+            errors,
+            vec![
+                "Key \"Common.submit\" is defined by both \"Common.submit\" (line 2) and \"Common\" > \"submit\" (line 4); loaders disagree on which value wins"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_duplicate_keys() {
+        let content = r#"{
+  "Common": {
+    "submit": "Send",
+    "cancel": "Cancel",
+    "submit": "Submit",
+    "submit"
+      : "OK"
+  },
+  "items": [{"a": "1", "a": "2"}],
+  "title": "A \"quoted\" {value}",
+  "Other": {"submit": "Submit"}
+}"#;
+        let line_index = build_line_index(content);
+
+        let repeated = find_duplicate_keys(content, &line_index).unwrap();
+
+        let path = |segments: &[&str]| segments.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            repeated,
+            vec![
+                RepeatedKey {
+                    path: path(&["Common", "submit"]),
+                    first_line: 3,
+                    line: 5,
+                },
+                RepeatedKey {
+                    path: path(&["Common", "submit"]),
+                    first_line: 3,
+                    line: 6,
+                },
+                RepeatedKey {
+                    path: path(&["items", "0", "a"]),
+                    first_line: 9,
+                    line: 9,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_duplicate_keys_none() {
+        let content = r#"{"a": {"b": "x"}, "b": ["a", "a"], "c": {"a": "y"}}"#;
+        let line_index = build_line_index(content);
+
+        assert!(
+            find_duplicate_keys(content, &line_index)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parse_json_file_records_duplicate_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("en.json");
+        fs::write(
+            &path,
+            "{\n  \"Common\": {\n    \"ok\": \"One\",\n    \"ok\": \"Two\"\n  }\n}",
+        )
+        .unwrap();
+
+        let parsed = parse_json_file(&path, "en", "/").unwrap();
+
+        assert!(parsed.warnings.is_empty());
+        let duplicates = &parsed.messages.duplicate_keys;
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].context.key, "Common/ok");
+        assert_eq!(duplicates[0].context.value, "Two");
+        assert_eq!(duplicates[0].context.line(), 4);
+        assert_eq!(duplicates[0].first_line, 3);
+    }
+
+    #[test]
+    fn test_find_shadowed_keys_flat_key_over_object() {
+        let content = r#"{"a.b": "x", "a": {"b": {"c": "y"}}}"#;
+        let json: Value = serde_json::from_str(content).unwrap();
+        let line_index = build_line_index(content);
+
+        let errors = find_shadowed_keys(&json, content, &line_index, ".");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("Key \"a.b\""));
+    }
+
+    #[test]
+    fn test_find_shadowed_keys_ignores_plain_nesting() {
+        let content = r#"{"a": {"b": "x", "c": {"d": ["y"]}}, "items": [{"q": "1"}]}"#;
+        let json: Value = serde_json::from_str(content).unwrap();
+        let line_index = build_line_index(content);
+
+        assert!(find_shadowed_keys(&json, content, &line_index, ".").is_empty());
+    }
+
     #[test]
     fn test_scan_message_files() {
         use std::io::Write;
@@ -461,7 +1289,7 @@ mod tests {
         let mut zh_file = fs::File::create(messages_dir.join("zh.json")).unwrap();
         write!(zh_file, r#"{{"submit": "提交"}}"#).unwrap();
 
-        let result = scan_message_files(messages_dir).unwrap();
+        let result = scan_message_files(messages_dir, ".", &LocaleFilter::default()).unwrap();
 
         assert_eq!(result.messages.len(), 2);
         assert!(result.messages.contains_key("en"));
@@ -484,7 +1312,7 @@ mod tests {
         let mut zh_file = fs::File::create(messages_dir.join("zh.json")).unwrap();
         write!(zh_file, r#"{{ invalid json }}"#).unwrap();
 
-        let result = scan_message_files(messages_dir).unwrap();
+        let result = scan_message_files(messages_dir, ".", &LocaleFilter::default()).unwrap();
 
         // Valid file should be parsed
         assert_eq!(result.messages.len(), 1);
@@ -495,9 +1323,79 @@ mod tests {
         assert!(result.warnings[0].file_path.contains("zh.json"));
     }
 
+    #[test]
+    fn test_is_locale_code() {
+        for name in [
+            "en",
+            "EN",
+            "fil",
+            "en-US",
+            "en_US",
+            "zh-Hans",
+            "zh-Hans-CN",
+            "es-419",
+            "de-CH-1996",
+            "sl-rozaj",
+            // Only the shape is checked, so these are loaded as locales
+            "old",
+            "tmp",
+            "bak",
+        ] {
+            assert!(is_locale_code(name), "{}", name);
+        }
+        for name in [
+            "",
+            "e",
+            "backup",
+            "common",
+            "en.old",
+            "en-",
+            "en-US-1",
+            "de-CH-x996",
+            "12",
+        ] {
+            assert!(!is_locale_code(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_scan_message_files_skips_non_locales() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["en.json", "zh-Hans.json", "backup.json", "english.json"] {
+            write_file(&dir.path().join(name), r#"{"submit": "Submit"}"#);
+        }
+
+        let filter = LocaleFilter {
+            allowlist: Vec::new(),
+            known: vec!["english".to_string()],
+        };
+        let result = scan_message_files(dir.path(), ".", &filter).unwrap();
+        let mut locales: Vec<&String> = result.messages.keys().collect();
+        locales.sort();
+        assert_eq!(locales, vec!["en", "english", "zh-Hans"]);
+        assert_eq!(result.skipped.len(), 1);
+        assert!(result.skipped[0].file_path.ends_with("backup.json"));
+        assert!(result.warnings.is_empty());
+
+        // With an allowlist, other files are skipped quietly
+        let filter = LocaleFilter {
+            allowlist: vec!["zh-Hans".to_string()],
+            known: vec!["en".to_string()],
+        };
+        let result = scan_message_files(dir.path(), ".", &filter).unwrap();
+        let mut locales: Vec<&String> = result.messages.keys().collect();
+        locales.sort();
+        assert_eq!(locales, vec!["en", "zh-Hans"]);
+        assert!(result.skipped.is_empty());
+    }
+
     #[test]
     fn test_scan_message_files_nonexistent_dir() {
-        let result = scan_message_files(Path::new("/nonexistent/path"));
+        let result = scan_message_files(
+            Path::new("/nonexistent/path"),
+            ".",
+            &LocaleFilter::default(),
+        );
 
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
@@ -505,6 +1403,103 @@ mod tests {
         assert!(err.contains("messagesDir"));
     }
 
+    fn write_file(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_scan_message_pattern_merges_namespaces() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_file(
+            &root.join("locales/en/common.json"),
+            r#"{"submit": "Submit"}"#,
+        );
+        write_file(
+            &root.join("locales/en/auth.json"),
+            "{\n  \"login\": {\n    \"title\": \"Login\"\n  }\n}",
+        );
+        write_file(
+            &root.join("locales/zh/common.json"),
+            r#"{"submit": "提交"}"#,
+        );
+
+        let pattern = format!("{}/locales/{{locale}}/*.json", root.display());
+        let result = scan_message_pattern(&pattern, ".", &LocaleFilter::default()).unwrap();
+
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.messages.len(), 2);
+        let en = &result.messages["en"];
+        assert_eq!(en.len(), 2);
+        assert_eq!(
+            en.file_path,
+            format!("{}/locales/en/*.json", root.display())
+        );
+        assert!(en.namespace_files["auth"].ends_with("auth.json"));
+
+        let title = en.get("auth.login.title").unwrap();
+        assert_eq!(title.context.line(), 3);
+        assert_eq!(title.context.file_key("."), "login.title");
+        assert!(title.context.file_path().ends_with("locales/en/auth.json"));
+        assert!(result.messages["zh"].contains_key("common.submit"));
+    }
+
+    #[test]
+    fn test_scan_message_pattern_reports_conflicts() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_file(
+            &root.join("locales/en/a/common.json"),
+            r#"{"submit": "Submit"}"#,
+        );
+        write_file(
+            &root.join("locales/en/b/common.json"),
+            r#"{"submit": "Send"}"#,
+        );
+
+        let pattern = format!("{}/locales/{{locale}}/**/*.json", root.display());
+        let result = scan_message_pattern(&pattern, ".", &LocaleFilter::default()).unwrap();
+
+        let en = &result.messages["en"];
+        assert_eq!(en.get("common.submit").unwrap().context.value, "Submit");
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].file_path.ends_with("b/common.json"));
+        assert!(
+            result.warnings[0]
+                .error
+                .contains("Conflicting key \"common.submit\"")
+        );
+    }
+
+    #[test]
+    fn test_scan_message_pattern_locale_in_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_file(
+            &root.join("i18n/app.en.json"),
+            r#"{"Common": {"submit": "Submit"}}"#,
+        );
+
+        let pattern = format!("{}/i18n/app.{{locale}}.json", root.display());
+        let result = scan_message_pattern(&pattern, ".", &LocaleFilter::default()).unwrap();
+
+        let en = &result.messages["en"];
+        assert!(en.contains_key("Common.submit"));
+        assert!(en.namespace_files.is_empty());
+    }
+
+    #[test]
+    fn test_scan_message_pattern_no_matches() {
+        let dir = tempfile::tempdir().unwrap();
+        let pattern = format!("{}/locales/{{locale}}/*.json", dir.path().display());
+
+        let err = scan_message_pattern(&pattern, ".", &LocaleFilter::default())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("No message files match"));
+    }
+
     #[test]
     fn test_flatten_array() {
         let content = r#"{"faq": {"items": [{"question": "Q1", "answer": "A1"}, {"question": "Q2", "answer": "A2"}]}}"#;
@@ -515,6 +1510,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -549,6 +1545,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -579,6 +1576,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -607,6 +1605,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -645,6 +1644,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -669,6 +1669,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -677,6 +1678,7 @@ mod tests {
 
         // Empty array should not create any keys
         assert!(result.is_empty(), "Empty array should not produce any keys");
+        assert_eq!(result.empty_arrays["Page.items"].line, 1);
     }
 
     #[test]
@@ -690,6 +1692,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -717,6 +1720,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -749,6 +1753,7 @@ mod tests {
         flatten_json(
             &json,
             String::new(),
+            ".",
             "test.json",
             content,
             &line_index,
@@ -1,5 +1,6 @@
 use anyhow::{Result, anyhow};
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 use swc_common::{
     BytePos, FileName, Globals, SourceMap,
@@ -51,6 +52,8 @@ pub struct ParsedJSX {
     pub source_map: Arc<SourceMap>,
     pub comments: ExtractedComments,
     pub astro_template_start_line: Option<usize>,
+    /// Lines of the `<template>` block in Vue files.
+    pub vue_template_lines: Option<RangeInclusive<usize>>,
 }
 
 pub(crate) fn parse_module_source(
@@ -84,6 +87,7 @@ pub(crate) fn parse_module_source(
             source_map,
             comments: extracted_comments,
             astro_template_start_line: None,
+            vue_template_lines: None,
         })
     })
 }
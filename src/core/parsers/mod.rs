@@ -4,7 +4,11 @@
 //! - `json`: JSON message file parser (scans locale directories)
 //! - `jsx`: JSX/TSX source file parser (uses swc for AST generation)
 //! - `astro`: Astro source parser that converts supported Astro syntax into TSX
+//! - `yaml`: YAML message file parser (same output as `json`)
+//! - `vue`: Vue single-file component parser that keeps script code and template `$t()` calls
 
 pub mod astro;
 pub mod json;
 pub mod jsx;
+pub mod vue;
+pub mod yaml;
@@ -0,0 +1,521 @@
+use anyhow::Result;
+use std::{collections::HashSet, ops::Range, ops::RangeInclusive, sync::Arc};
+use swc_common::SourceMap;
+
+use crate::core::{
+    LocaleMessages,
+    parsers::{
+        astro::{blank_preserve_bytes, count_newlines},
+        json::{MessageScanWarning, ScanMessagesResult, parse_embedded_messages},
+        jsx::{DetachedComment, ParsedJSX, parse_module_source},
+    },
+};
+
+/// Callees that vue-i18n exposes to templates and `useI18n()` destructuring.
+pub const VUE_TRANSLATION_CALLEES: &[&str] = &["$t", "t"];
+
+/// Parse a Vue single-file component by keeping only its script code.
+///
+/// `<script>` and `<script setup>` contents stay in place and everything else
+/// is blanked, so lines and columns still match the `.vue` file. Translation
+/// calls in the `<template>` (`{{ $t("key") }}`, `:title="t('key')"`) are
+/// copied back at their original offsets as expression statements, which lets
+/// the regular TS pipeline resolve them.
+pub fn parse_vue_source(
+    code: String,
+    file_path: &str,
+    source_map: Arc<SourceMap>,
+) -> Result<ParsedJSX> {
+    let transformed = transform_vue_to_ts(&code);
+    let mut parsed = parse_module_source(
+        transformed.code,
+        file_path,
+        source_map,
+        transformed.tsx,
+        "vue",
+    )?;
+    parsed.comments.detached = transformed.detached_comments;
+    parsed.vue_template_lines = transformed.template_lines;
+    Ok(parsed)
+}
+
+struct VueTransformResult {
+    code: String,
+    tsx: bool,
+    template_lines: Option<RangeInclusive<usize>>,
+    detached_comments: Vec<DetachedComment>,
+}
+
+/// A top-level block of a single-file component.
+struct SfcBlock<'a> {
+    name: &'a str,
+    attrs: &'a str,
+    /// Byte range of the block body, between the opening and closing tags.
+    content: Range<usize>,
+}
+
+fn transform_vue_to_ts(source: &str) -> VueTransformResult {
+    let mut code = blank_preserve_bytes(source).into_bytes();
+    let mut tsx = false;
+    let mut template_lines = None;
+    let mut detached_comments = top_level_comments(source);
+
+    for block in split_blocks(source) {
+        match block.name {
+            "script" => {
+                code[block.content.clone()]
+                    .copy_from_slice(&source.as_bytes()[block.content.clone()]);
+                tsx |= matches!(attr_value(block.attrs, "lang"), Some("tsx" | "jsx"));
+            }
+            "template" => {
+                let template = &source[block.content.clone()];
+                let base = block.content.start;
+                for call in find_template_calls(template) {
+                    let range = base + call.start..base + call.end;
+                    code[range.clone()].copy_from_slice(&source.as_bytes()[range.clone()]);
+                    // Separate the copied call from whatever precedes or follows it
+                    for index in [range.start.wrapping_sub(1), range.end] {
+                        if code.get(index) == Some(&b' ') {
+                            code[index] = b';';
+                        }
+                    }
+                }
+                detached_comments.extend(html_comments(template, line_at(source, base)));
+                template_lines = Some(line_at(source, base)..=line_at(source, block.content.end));
+            }
+            _ => {}
+        }
+    }
+
+    VueTransformResult {
+        code: String::from_utf8(code).expect("copied ranges should stay valid UTF-8"),
+        tsx,
+        template_lines,
+        detached_comments,
+    }
+}
+
+/// Split a component into its top-level blocks.
+///
+/// Nested `<template>` tags (slots, `v-if` groups) are balanced so the outer
+/// template ends at its own closing tag.
+fn split_blocks(source: &str) -> Vec<SfcBlock<'_>> {
+    let mut blocks = Vec::new();
+    let mut index = 0;
+
+    while let Some(rel) = source[index..].find('<') {
+        let start = index + rel;
+        let rest = &source[start..];
+
+        if rest.starts_with("<!--") {
+            index = rest
+                .find("-->")
+                .map(|pos| start + pos + 3)
+                .unwrap_or(source.len());
+            continue;
+        }
+
+        let name_len = rest[1..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(rest.len() - 1);
+        let Some(open_rel) = rest.find('>') else {
+            break;
+        };
+        if name_len == 0 {
+            index = start + 1;
+            continue;
+        }
+
+        let name = &rest[1..1 + name_len];
+        let open_end = start + open_rel + 1;
+        let Some(close_start) = find_closing_tag(source, open_end, name) else {
+            break;
+        };
+        blocks.push(SfcBlock {
+            name,
+            attrs: &source[start + 1 + name_len..open_end - 1],
+            content: open_end..close_start,
+        });
+        index = source[close_start..]
+            .find('>')
+            .map(|pos| close_start + pos + 1)
+            .unwrap_or(source.len());
+    }
+
+    blocks
+}
+
+/// Byte offset of the `</name>` tag closing a block opened before `from`.
+fn find_closing_tag(source: &str, from: usize, name: &str) -> Option<usize> {
+    let opening = format!("<{}", name);
+    let closing = format!("</{}", name);
+    if name != "template" {
+        return source[from..].find(&closing).map(|pos| from + pos);
+    }
+
+    let mut depth = 1;
+    let mut index = from;
+    while let Some(rel) = source[index..].find('<') {
+        let pos = index + rel;
+        let rest = &source[pos..];
+        if rest.starts_with(&closing) {
+            depth -= 1;
+            if depth == 0 {
+                return Some(pos);
+            }
+        } else if rest.starts_with(&opening)
+            && rest[opening.len()..].starts_with(|c: char| c.is_whitespace() || c == '>')
+        {
+            depth += 1;
+        }
+        index = pos + 1;
+    }
+    None
+}
+
+/// Value of a quoted attribute, e.g. `lang` in `lang="ts" setup`.
+fn attr_value<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attrs;
+    while let Some(pos) = rest.find(name) {
+        let before_ok = rest[..pos]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        let after = rest[pos + name.len()..].trim_start();
+        if before_ok && let Some(value) = after.strip_prefix('=') {
+            let value = value.trim_start();
+            let quote = value.chars().next()?;
+            if quote == '"' || quote == '\'' {
+                let value = &value[1..];
+                return value.find(quote).map(|end| &value[..end]);
+            }
+        }
+        rest = &rest[pos + name.len()..];
+    }
+    None
+}
+
+/// Byte ranges of `$t(...)` and `t("...")` calls in template markup.
+///
+/// A bare `t(` only counts when its first argument is a string, which keeps
+/// stray text such as `t(x)` in prose from being copied into the script.
+fn find_template_calls(template: &str) -> Vec<Range<usize>> {
+    let bytes = template.as_bytes();
+    let mut calls = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let rest = &bytes[index..];
+        if rest.starts_with(b"<!--") {
+            index = template[index..]
+                .find("-->")
+                .map(|pos| index + pos + 3)
+                .unwrap_or(bytes.len());
+            continue;
+        }
+
+        let callee_len = if rest.starts_with(b"$t(") {
+            2
+        } else if rest.starts_with(b"t(") {
+            1
+        } else {
+            index += 1;
+            continue;
+        };
+
+        let preceded_by_name = index > 0
+            && matches!(bytes[index - 1], b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'$' | b'.' | b'-');
+        let string_argument = template[index + callee_len + 1..]
+            .trim_start()
+            .starts_with(['"', '\'', '`']);
+        if preceded_by_name || (callee_len == 1 && !string_argument) {
+            index += 1;
+            continue;
+        }
+
+        match find_closing_paren(bytes, index + callee_len) {
+            Some(end) => {
+                calls.push(index..end + 1);
+                index = end + 1;
+            }
+            None => index += 1,
+        }
+    }
+
+    calls
+}
+
+/// Index of the `)` matching the `(` at `open`, skipping string contents.
+fn find_closing_paren(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote: Option<u8> = None;
+    let mut index = open;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        match quote {
+            Some(q) => {
+                if byte == b'\\' {
+                    index += 1;
+                } else if byte == q {
+                    quote = None;
+                }
+            }
+            None => match byte {
+                b'"' | b'\'' | b'`' => quote = Some(byte),
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(index);
+                    }
+                }
+                _ => {}
+            },
+        }
+        index += 1;
+    }
+    None
+}
+
+/// HTML comments between top-level blocks, e.g. `<!-- glot-disable -->`.
+fn top_level_comments(source: &str) -> Vec<DetachedComment> {
+    let mut outside = blank_preserve_bytes(source).into_bytes();
+    let mut last_end = 0;
+    for block in split_blocks(source) {
+        let block_start = source[..block.content.start]
+            .rfind('<')
+            .unwrap_or(block.content.start);
+        let range = last_end..block_start;
+        outside[range.clone()].copy_from_slice(&source.as_bytes()[range]);
+        last_end = block.content.end;
+    }
+    let range = last_end..source.len();
+    outside[range.clone()].copy_from_slice(&source.as_bytes()[range]);
+    let outside = String::from_utf8(outside).expect("copied ranges should stay valid UTF-8");
+    html_comments(&outside, 1)
+}
+
+fn html_comments(markup: &str, base_line: usize) -> Vec<DetachedComment> {
+    let mut comments = Vec::new();
+    let mut index = 0;
+    while let Some(rel) = markup[index..].find("<!--") {
+        let start = index + rel;
+        let Some(end_rel) = markup[start..].find("-->") else {
+            break;
+        };
+        let end = start + end_rel + 3;
+        comments.push(DetachedComment {
+            line: base_line + count_newlines(&markup[..start]),
+            text: markup[start + 4..end - 3].trim().to_string(),
+        });
+        index = end;
+    }
+    comments
+}
+
+fn line_at(source: &str, offset: usize) -> usize {
+    count_newlines(&source[..offset]) + 1
+}
+
+/// Collect messages declared in the `<i18n>` blocks of `.vue` files.
+///
+/// Only JSON blocks are supported; other languages, external `src` blocks
+/// and invalid JSON are reported as warnings.
+pub fn scan_sfc_messages(files: &HashSet<String>, key_separator: &str) -> ScanMessagesResult {
+    let mut result = ScanMessagesResult::default();
+
+    let mut vue_files: Vec<&String> = files.iter().filter(|f| f.ends_with(".vue")).collect();
+    vue_files.sort();
+
+    for file_path in vue_files {
+        let Ok(source) = std::fs::read_to_string(file_path) else {
+            continue;
+        };
+        let warning = |error: String| MessageScanWarning {
+            file_path: file_path.clone(),
+            error,
+        };
+
+        for block in split_blocks(&source)
+            .into_iter()
+            .filter(|b| b.name == "i18n")
+        {
+            if attr_value(block.attrs, "src").is_some() {
+                result.warnings.push(warning(
+                    "External <i18n src> blocks are not supported".to_string(),
+                ));
+                continue;
+            }
+            if let Some(lang) = attr_value(block.attrs, "lang")
+                && lang != "json"
+            {
+                result.warnings.push(warning(format!(
+                    "Only JSON <i18n> blocks are supported (found lang=\"{}\")",
+                    lang
+                )));
+                continue;
+            }
+
+            let mut content = blank_preserve_bytes(&source).into_bytes();
+            content[block.content.clone()]
+                .copy_from_slice(&source.as_bytes()[block.content.clone()]);
+            let content =
+                String::from_utf8(content).expect("copied ranges should stay valid UTF-8");
+
+            let locale = attr_value(block.attrs, "locale");
+            match parse_embedded_messages(&content, file_path, locale, key_separator) {
+                Ok(messages) => {
+                    for locale_messages in messages {
+                        let target = result
+                            .messages
+                            .entry(locale_messages.locale.clone())
+                            .or_insert_with(|| {
+                                LocaleMessages::new(
+                                    locale_messages.locale.clone(),
+                                    file_path.clone(),
+                                )
+                            });
+                        for (key, entry) in locale_messages.entries {
+                            target.entries.entry(key).or_insert(entry);
+                        }
+                        target.duplicate_keys.extend(locale_messages.duplicate_keys);
+                    }
+                }
+                Err(e) => result.warnings.push(warning(format!("{:#}", e))),
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs, sync::Arc};
+
+    use swc_common::SourceMap;
+
+    use super::*;
+
+    const COMPONENT: &str = r#"<template>
+  <h1>{{ $t("Home.title") }}</h1>
+  <!-- glot-disable-next-line -->
+  <input :placeholder="t('Home.search')" />
+  <p>Don't (really) use t(x)</p>
+</template>
+
+<script setup lang="ts">
+import { useI18n } from "vue-i18n";
+const { t } = useI18n();
+const label = t("Home.label");
+</script>
+"#;
+
+    #[test]
+    fn test_transform_preserves_offsets() {
+        let transformed = transform_vue_to_ts(COMPONENT);
+
+        assert_eq!(transformed.code.len(), COMPONENT.len());
+        assert_eq!(transformed.code.lines().count(), COMPONENT.lines().count());
+        let lines: Vec<&str> = transformed.code.lines().collect();
+        assert_eq!(lines[1].trim(), r#";$t("Home.title");"#);
+        assert_eq!(lines[3].trim(), ";t('Home.search');");
+        assert!(lines[4].trim().is_empty());
+        assert_eq!(lines[10], r#"const label = t("Home.label");"#);
+        assert_eq!(transformed.template_lines, Some(1..=6));
+        assert!(!transformed.tsx);
+    }
+
+    #[test]
+    fn test_parse_vue_collects_template_comments() {
+        let parsed = parse_vue_source(
+            COMPONENT.to_string(),
+            "App.vue",
+            Arc::new(SourceMap::default()),
+        )
+        .unwrap();
+
+        assert_eq!(parsed.vue_template_lines, Some(1..=6));
+        assert_eq!(parsed.comments.detached.len(), 1);
+        assert_eq!(parsed.comments.detached[0].line, 3);
+        assert_eq!(parsed.comments.detached[0].text, "glot-disable-next-line");
+    }
+
+    #[test]
+    fn test_nested_templates() {
+        let source = "<template>\n  <template v-if=\"ok\">{{ $t('a') }}</template>\n</template>\n";
+        let blocks = split_blocks(source);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(
+            &source[blocks[0].content.clone()].trim(),
+            &"<template v-if=\"ok\">{{ $t('a') }}</template>"
+        );
+    }
+
+    #[test]
+    fn test_find_template_calls() {
+        let template = r#"{{ $t('a', { n: t("b") }) }} item.t('c') alert('d') t(count) $t(key)"#;
+        let calls: Vec<&str> = find_template_calls(template)
+            .into_iter()
+            .map(|range| &template[range])
+            .collect();
+        assert_eq!(calls, vec![r#"$t('a', { n: t("b") })"#, "$t(key)"]);
+    }
+
+    #[test]
+    fn test_attr_value() {
+        assert_eq!(attr_value(r#" setup lang="ts""#, "lang"), Some("ts"));
+        assert_eq!(attr_value(" locale='en'", "locale"), Some("en"));
+        assert_eq!(attr_value(r#" xlang="ts""#, "lang"), None);
+        assert_eq!(attr_value(" setup", "lang"), None);
+    }
+
+    #[test]
+    fn test_scan_sfc_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let app = dir.path().join("App.vue");
+        fs::write(
+            &app,
+            r#"<template><p>{{ $t("hello") }}</p></template>
+
+<i18n>
+{
+  "en": { "hello": "Hello", "nested": { "bye": "Bye", "bye": "See you" } },
+  "ja": { "hello": "こんにちは" }
+}
+</i18n>
+
+<i18n locale="fr">{ "hello": "Bonjour" }</i18n>
+<i18n lang="yaml">
+en:
+  hello: Hello
+</i18n>
+"#,
+        )
+        .unwrap();
+        let app = app.to_string_lossy().to_string();
+
+        let result = scan_sfc_messages(&HashSet::from([app.clone()]), ".");
+
+        let en = &result.messages["en"];
+        assert_eq!(en.file_path, app);
+        assert_eq!(en.entries["hello"].context.value, "Hello");
+        assert_eq!(en.entries["hello"].context.location.line, 5);
+        assert_eq!(en.entries["nested.bye"].context.location.line, 5);
+        assert_eq!(en.duplicate_keys.len(), 1);
+        assert_eq!(en.duplicate_keys[0].context.key, "nested.bye");
+        assert_eq!(en.duplicate_keys[0].context.value, "See you");
+        assert!(result.messages["ja"].duplicate_keys.is_empty());
+        assert_eq!(
+            result.messages["ja"].entries["hello"].context.location.line,
+            6
+        );
+        assert_eq!(
+            result.messages["fr"].entries["hello"].context.value,
+            "Bonjour"
+        );
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].error.contains("lang=\"yaml\""));
+    }
+}
@@ -0,0 +1,899 @@
+//! YAML message file parser.
+//!
+//! Loads `.yml`/`.yaml` locale files into the same `LocaleMessages` as JSON
+//! files, so every rule works unchanged. Only the subset of YAML used by
+//! locale files is supported:
+//! - block mappings and sequences, nested by indentation
+//! - plain, single-quoted and double-quoted scalars
+//! - literal (`|`) and folded (`>`) block scalars
+//! - flow sequences of scalars (`[a, b]`) and empty flow mappings (`{}`)
+//! - comments and `---` / `...` document markers
+//!
+//! Anchors, aliases, tags and multi-document files are reported as parse
+//! errors. As in JSON files, only strings and string arrays are messages:
+//! plain scalars that YAML resolves to numbers, booleans or null are skipped.
+
+use std::{collections::HashMap, fs, path::Path, sync::LazyLock};
+
+use anyhow::{Context, Result, anyhow};
+use regex::Regex;
+
+use super::json::{ParsedJsonFile, RepeatedKey, add_duplicate_keys};
+use crate::core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType};
+
+/// Plain scalars that the YAML 1.2 core schema resolves to a non-string.
+static NON_STRING_SCALAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(?:~|null|Null|NULL|true|True|TRUE|false|False|FALSE|[-+]?[0-9]+|0o[0-7]+|0x[0-9a-fA-F]+|[-+]?(?:\.[0-9]+|[0-9]+(?:\.[0-9]*)?)(?:[eE][-+]?[0-9]+)?|[-+]?\.(?:inf|Inf|INF)|\.(?:nan|NaN|NAN))$",
+    )
+    .unwrap()
+});
+
+/// Parse a YAML message file, joining nested keys with `key_separator`.
+pub fn parse_yaml_file(path: &Path, locale: &str, key_separator: &str) -> Result<ParsedJsonFile> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read YAML file: {:?}", path))?;
+    let document = parse_yaml(&content).map_err(|error| {
+        anyhow!(
+            "Failed to parse YAML file: {:?} (line {}: {})",
+            path,
+            error.line,
+            error.message
+        )
+    })?;
+
+    let file_path = path.to_string_lossy().to_string();
+    let mut messages = LocaleMessages::new(locale.to_string(), file_path.clone());
+    flatten_yaml(
+        &document.root,
+        String::new(),
+        (1, 1),
+        key_separator,
+        &file_path,
+        &mut messages,
+    );
+    add_duplicate_keys(&mut messages, document.duplicates, key_separator);
+    Ok(ParsedJsonFile {
+        messages,
+        warnings: Vec::new(),
+    })
+}
+
+/// Whether `path` is a YAML file, by extension.
+pub fn is_yaml_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("yml" | "yaml")
+    )
+}
+
+#[derive(Debug, PartialEq)]
+enum Node {
+    String(String),
+    /// A number, boolean or null, which is not a message.
+    Other,
+    Mapping(Vec<Entry>),
+    Sequence(Vec<Item>),
+}
+
+#[derive(Debug, PartialEq)]
+struct Entry {
+    key: String,
+    line: usize,
+    col: usize,
+    value: Node,
+}
+
+#[derive(Debug, PartialEq)]
+struct Item {
+    line: usize,
+    col: usize,
+    value: Node,
+}
+
+#[derive(Debug)]
+struct Document {
+    root: Node,
+    /// Keys defined twice in the same mapping.
+    duplicates: Vec<RepeatedKey>,
+}
+
+#[derive(Debug, PartialEq)]
+struct YamlError {
+    line: usize,
+    message: String,
+}
+
+fn error(line: usize, message: impl Into<String>) -> YamlError {
+    YamlError {
+        line,
+        message: message.into(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    number: usize,
+    /// Number of leading spaces.
+    indent: usize,
+    /// Content after the indentation, without trailing whitespace.
+    text: &'a str,
+    /// The whole line, without trailing whitespace.
+    raw: &'a str,
+}
+
+/// Whether a line holds nothing but whitespace or a comment.
+fn is_blank(text: &str) -> bool {
+    text.is_empty() || text.starts_with('#')
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+fn is_document_marker(text: &str) -> bool {
+    text == "---" || text == "..." || text.starts_with("--- ")
+}
+
+struct Parser<'a> {
+    lines: Vec<Line<'a>>,
+    pos: usize,
+    /// Keys and indices leading to the node being parsed.
+    path: Vec<String>,
+    duplicates: Vec<RepeatedKey>,
+}
+
+fn parse_yaml(content: &str) -> Result<Document, YamlError> {
+    let lines = content
+        .lines()
+        .enumerate()
+        .map(|(i, raw)| {
+            let raw = raw.trim_end();
+            let text = raw.trim_start_matches(' ');
+            Line {
+                number: i + 1,
+                indent: raw.len() - text.len(),
+                text,
+                raw,
+            }
+        })
+        .collect();
+    let mut parser = Parser {
+        lines,
+        pos: 0,
+        path: Vec::new(),
+        duplicates: Vec::new(),
+    };
+
+    if let Some(line) = parser.peek()? {
+        if line.text.starts_with("--- ") {
+            return Err(error(line.number, "content after `---` is not supported"));
+        }
+        if line.text == "---" {
+            parser.pos += 1;
+        }
+    }
+    let root = parser.parse_block(0)?;
+    if let Some(line) = parser.peek()? {
+        if line.text == "..." {
+            parser.pos += 1;
+        }
+        if let Some(line) = parser.peek()? {
+            let message = if is_document_marker(line.text) {
+                "multiple documents are not supported"
+            } else {
+                "unexpected content"
+            };
+            return Err(error(line.number, message));
+        }
+    }
+
+    Ok(Document {
+        root,
+        duplicates: parser.duplicates,
+    })
+}
+
+impl<'a> Parser<'a> {
+    /// The next line that is not blank or a comment, without consuming it.
+    fn peek(&mut self) -> Result<Option<Line<'a>>, YamlError> {
+        while let Some(line) = self.lines.get(self.pos) {
+            if !is_blank(line.text) {
+                if line.text.starts_with('\t') {
+                    return Err(error(line.number, "tabs are not allowed for indentation"));
+                }
+                return Ok(Some(*line));
+            }
+            self.pos += 1;
+        }
+        Ok(None)
+    }
+
+    /// Parse the mapping, sequence or flow collection starting at the next
+    /// line, if it is indented by at least `min_indent`.
+    fn parse_block(&mut self, min_indent: usize) -> Result<Node, YamlError> {
+        let Some(line) = self.peek()? else {
+            return Ok(Node::Other);
+        };
+        if line.indent < min_indent || is_document_marker(line.text) {
+            return Ok(Node::Other);
+        }
+        if is_sequence_item(line.text) {
+            self.parse_sequence(line.indent)
+        } else if line.text.starts_with(['[', '{']) {
+            self.pos += 1;
+            parse_flow(line.text, line.number, line.indent + 1)
+        } else {
+            self.parse_mapping(line.indent)
+        }
+    }
+
+    fn parse_mapping(&mut self, indent: usize) -> Result<Node, YamlError> {
+        let mut entries: Vec<Entry> = Vec::new();
+        let mut lines_by_key: HashMap<String, Vec<usize>> = HashMap::new();
+
+        while let Some(line) = self.peek()? {
+            if line.indent < indent
+                || is_document_marker(line.text)
+                || (line.indent == indent && is_sequence_item(line.text))
+            {
+                break;
+            }
+            if line.indent > indent {
+                return Err(error(line.number, "unexpected indentation"));
+            }
+            let Some((key, rest)) = split_key(line.text) else {
+                return Err(error(line.number, "expected a `key: value` pair"));
+            };
+            self.pos += 1;
+
+            self.path.push(key.clone());
+            let value = self.parse_value(rest, indent, line, true)?;
+            self.path.pop();
+
+            lines_by_key
+                .entry(key.clone())
+                .or_default()
+                .push(line.number);
+            entries.push(Entry {
+                key,
+                line: line.number,
+                col: line.indent + 1,
+                value,
+            });
+        }
+
+        // Like JSON files, the last definition of a repeated key wins
+        let mut repeated: Vec<(String, Vec<usize>)> = lines_by_key
+            .into_iter()
+            .filter(|(_, lines)| lines.len() > 1)
+            .collect();
+        repeated.sort_by_key(|(_, lines)| lines[0]);
+        for (key, lines) in &repeated {
+            for &line in &lines[1..] {
+                let mut path = self.path.clone();
+                path.push(key.clone());
+                self.duplicates.push(RepeatedKey {
+                    path,
+                    first_line: lines[0],
+                    line,
+                });
+            }
+            let last = *lines.last().expect("duplicates have several lines");
+            entries.retain(|entry| entry.key != *key || entry.line == last);
+        }
+
+        Ok(Node::Mapping(entries))
+    }
+
+    fn parse_sequence(&mut self, indent: usize) -> Result<Node, YamlError> {
+        let mut items = Vec::new();
+
+        while let Some(line) = self.peek()? {
+            if line.indent != indent || !is_sequence_item(line.text) {
+                if line.indent > indent {
+                    return Err(error(line.number, "unexpected indentation"));
+                }
+                break;
+            }
+            let rest = line.text[1..].trim_start();
+            let item_indent = indent + (line.text.len() - rest.len());
+
+            self.path.push(items.len().to_string());
+            let value = if split_key(rest).is_some() || is_sequence_item(rest) {
+                // `- key: value` starts a mapping (or a nested sequence) whose
+                // entries are aligned with `key`
+                self.lines[self.pos] = Line {
+                    indent: item_indent,
+                    text: rest,
+                    ..line
+                };
+                self.parse_block(item_indent)?
+            } else {
+                self.pos += 1;
+                self.parse_value(rest, indent, line, false)?
+            };
+            self.path.pop();
+
+            items.push(Item {
+                line: line.number,
+                col: item_indent + 1,
+                value,
+            });
+        }
+
+        Ok(Node::Sequence(items))
+    }
+
+    /// Parse the value after `key:` or `- `, plus the lines it continues on.
+    ///
+    /// `indent` is the indentation of the key or dash. A mapping value may be
+    /// a sequence at the same indentation (`key:\n- a`), unlike a sequence item.
+    fn parse_value(
+        &mut self,
+        rest: &'a str,
+        indent: usize,
+        line: Line<'a>,
+        in_mapping: bool,
+    ) -> Result<Node, YamlError> {
+        if is_blank(rest) {
+            return match self.peek()? {
+                Some(next) if next.indent > indent => self.parse_block(next.indent),
+                Some(next)
+                    if in_mapping && next.indent == indent && is_sequence_item(next.text) =>
+                {
+                    self.parse_sequence(indent)
+                }
+                _ => Ok(Node::Other),
+            };
+        }
+
+        let col = line.raw.len() - rest.len() + 1;
+        match rest.as_bytes()[0] {
+            b'|' | b'>' => self.parse_block_scalar(rest, indent, line.number),
+            b'&' | b'*' | b'!' => Err(error(
+                line.number,
+                "anchors, aliases and tags are not supported",
+            )),
+            b'[' | b'{' => parse_flow(rest, line.number, col),
+            b'"' | b'\'' => self.parse_quoted_value(rest, line.number),
+            _ => self.parse_plain_value(rest, indent),
+        }
+    }
+
+    /// Parse a quoted scalar, which may continue on the following lines.
+    fn parse_quoted_value(&mut self, rest: &str, line_number: usize) -> Result<Node, YamlError> {
+        let mut text = rest.to_string();
+        loop {
+            match parse_quoted(&text) {
+                Ok((value, remainder)) => {
+                    if !is_blank(remainder.trim_start()) {
+                        return Err(error(line_number, "unexpected text after quoted string"));
+                    }
+                    return Ok(Node::String(value));
+                }
+                Err(QuoteError::Unterminated) => {
+                    let Some(next) = self.lines.get(self.pos) else {
+                        return Err(error(line_number, "unterminated quoted string"));
+                    };
+                    // Line breaks fold into spaces, empty lines into newlines
+                    if next.text.is_empty() {
+                        text.push('\n');
+                    } else {
+                        if !text.ends_with('\n') {
+                            text.push(' ');
+                        }
+                        text.push_str(next.raw.trim_start());
+                    }
+                    self.pos += 1;
+                }
+                Err(QuoteError::Invalid(message)) => return Err(error(line_number, message)),
+            }
+        }
+    }
+
+    /// Parse a plain scalar, folding continuation lines into spaces.
+    fn parse_plain_value(&mut self, rest: &str, indent: usize) -> Result<Node, YamlError> {
+        let mut value = strip_comment(rest).to_string();
+        while let Some(next) = self.lines.get(self.pos) {
+            if is_blank(next.text) || next.indent <= indent {
+                break;
+            }
+            // A more indented key after a value is a misplaced entry
+            if split_key(next.text).is_some() {
+                return Err(error(next.number, "unexpected indentation"));
+            }
+            value.push(' ');
+            value.push_str(strip_comment(next.text));
+            self.pos += 1;
+        }
+
+        Ok(if NON_STRING_SCALAR.is_match(&value) {
+            Node::Other
+        } else {
+            Node::String(value)
+        })
+    }
+
+    /// Parse a literal (`|`) or folded (`>`) block scalar.
+    fn parse_block_scalar(
+        &mut self,
+        header: &str,
+        indent: usize,
+        line_number: usize,
+    ) -> Result<Node, YamlError> {
+        let header = strip_comment(header);
+        let folded = header.starts_with('>');
+        let chomping = match &header[1..] {
+            "" => Chomping::Clip,
+            "-" => Chomping::Strip,
+            "+" => Chomping::Keep,
+            _ => {
+                return Err(error(
+                    line_number,
+                    format!("unsupported block scalar header `{}`", header),
+                ));
+            }
+        };
+
+        let mut lines: Vec<&str> = Vec::new();
+        let mut block_indent = None;
+        while let Some(line) = self.lines.get(self.pos) {
+            if line.text.is_empty() {
+                lines.push("");
+                self.pos += 1;
+                continue;
+            }
+            let content_indent = *block_indent.get_or_insert(line.indent);
+            if line.indent <= indent || line.indent < content_indent {
+                break;
+            }
+            lines.push(&line.raw[content_indent..]);
+            self.pos += 1;
+        }
+
+        let trailing = lines
+            .iter()
+            .rev()
+            .take_while(|line| line.is_empty())
+            .count();
+        let body = &lines[..lines.len() - trailing];
+        let mut value = if folded {
+            fold_lines(body)
+        } else {
+            body.join("\n")
+        };
+        if !body.is_empty() {
+            match chomping {
+                Chomping::Strip => {}
+                Chomping::Clip => value.push('\n'),
+                Chomping::Keep => value.push_str(&"\n".repeat(trailing + 1)),
+            }
+        }
+        Ok(Node::String(value))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Chomping {
+    /// Keep a single final line break (`|`)
+    Clip,
+    /// Remove the final line breaks (`|-`)
+    Strip,
+    /// Keep all final line breaks (`|+`)
+    Keep,
+}
+
+/// Join lines with spaces, turning each empty line into a line break.
+fn fold_lines(lines: &[&str]) -> String {
+    let mut value = String::new();
+    let mut breaks = 0;
+    for line in lines {
+        if line.is_empty() {
+            breaks += 1;
+            continue;
+        }
+        if !value.is_empty() {
+            if breaks == 0 {
+                value.push(' ');
+            } else {
+                value.push_str(&"\n".repeat(breaks));
+            }
+        }
+        value.push_str(line);
+        breaks = 0;
+    }
+    value
+}
+
+/// Remove a trailing ` # comment` from a plain scalar.
+fn strip_comment(text: &str) -> &str {
+    if text.starts_with('#') {
+        return "";
+    }
+    let end = text
+        .char_indices()
+        .zip(text.chars().skip(1))
+        .find(|((_, c), next)| c.is_whitespace() && *next == '#')
+        .map_or(text.len(), |((i, _), _)| i);
+    text[..end].trim_end()
+}
+
+/// Split `key: value` into the key and the rest of the line.
+fn split_key(text: &str) -> Option<(String, &str)> {
+    if text.starts_with(['"', '\'']) {
+        let (key, remainder) = parse_quoted(text).ok()?;
+        let rest = remainder.trim_start().strip_prefix(':')?;
+        return (rest.is_empty() || rest.starts_with([' ', '\t']))
+            .then(|| (key, rest.trim_start()));
+    }
+    if is_sequence_item(text) || text.starts_with(['[', '{', '#', '?', '|', '>', '&', '*', '!']) {
+        return None;
+    }
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|(_, next)| *next);
+        if c == '#' && i > 0 && text[..i].ends_with([' ', '\t']) {
+            return None;
+        }
+        if c == ':' && next.is_none_or(|next| next == ' ' || next == '\t') {
+            let key = text[..i].trim_end();
+            return (!key.is_empty()).then(|| (key.to_string(), text[i + 1..].trim_start()));
+        }
+    }
+    None
+}
+
+#[derive(Debug, PartialEq)]
+enum QuoteError {
+    Unterminated,
+    Invalid(String),
+}
+
+/// Parse a quoted scalar at the start of `text`, returning its value and the
+/// text after the closing quote.
+fn parse_quoted(text: &str) -> Result<(String, &str), QuoteError> {
+    let quote = text.chars().next().ok_or(QuoteError::Unterminated)?;
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1).peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == quote {
+            // `''` is an escaped quote in single-quoted strings
+            if quote == '\'' && chars.peek().is_some_and(|(_, next)| *next == '\'') {
+                chars.next();
+                value.push('\'');
+                continue;
+            }
+            return Ok((value, &text[i + 1..]));
+        }
+        if c != '\\' || quote == '\'' {
+            value.push(c);
+            continue;
+        }
+
+        let Some((_, escape)) = chars.next() else {
+            return Err(QuoteError::Unterminated);
+        };
+        match escape {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            '0' => value.push('\0'),
+            ' ' | '"' | '/' | '\\' => value.push(escape),
+            'x' | 'u' | 'U' => {
+                let len = match escape {
+                    'x' => 2,
+                    'u' => 4,
+                    _ => 8,
+                };
+                let hex: String = (0..len)
+                    .filter_map(|_| chars.next())
+                    .map(|(_, c)| c)
+                    .collect();
+                let c = u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| {
+                        QuoteError::Invalid(format!("invalid escape `\\{}{}`", escape, hex))
+                    })?;
+                value.push(c);
+            }
+            _ => {
+                return Err(QuoteError::Invalid(format!(
+                    "invalid escape `\\{}`",
+                    escape
+                )));
+            }
+        }
+    }
+
+    Err(QuoteError::Unterminated)
+}
+
+/// Parse a single-line flow collection: `{}`, `[]` or a sequence of scalars.
+fn parse_flow(text: &str, line: usize, col: usize) -> Result<Node, YamlError> {
+    let unsupported = || error(line, "only flow sequences of scalars are supported");
+
+    if let Some(rest) = text.strip_prefix('{') {
+        return if is_blank(
+            rest.trim_start()
+                .strip_prefix('}')
+                .ok_or_else(unsupported)?
+                .trim_start(),
+        ) {
+            Ok(Node::Mapping(Vec::new()))
+        } else {
+            Err(unsupported())
+        };
+    }
+
+    let mut rest = text.strip_prefix('[').ok_or_else(unsupported)?.trim_start();
+    let mut items = Vec::new();
+    loop {
+        if let Some(after) = rest.strip_prefix(']') {
+            if !is_blank(after.trim_start()) {
+                return Err(error(line, "unexpected text after flow sequence"));
+            }
+            return Ok(Node::Sequence(items));
+        }
+        let (value, after) = if rest.starts_with(['"', '\'']) {
+            let (value, after) = parse_quoted(rest).map_err(|_| unsupported())?;
+            (Node::String(value), after)
+        } else {
+            let end = rest.find([',', ']']).ok_or_else(unsupported)?;
+            let value = rest[..end].trim();
+            if value.starts_with(['[', '{']) || value.is_empty() {
+                return Err(unsupported());
+            }
+            let node = if NON_STRING_SCALAR.is_match(value) {
+                Node::Other
+            } else {
+                Node::String(value.to_string())
+            };
+            (node, &rest[end..])
+        };
+        items.push(Item { line, col, value });
+
+        rest = after.trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after.trim_start();
+        } else if !rest.starts_with(']') {
+            return Err(unsupported());
+        }
+    }
+}
+
+fn flatten_yaml(
+    node: &Node,
+    prefix: String,
+    (line, col): (usize, usize),
+    separator: &str,
+    file_path: &str,
+    result: &mut LocaleMessages,
+) {
+    let join = |segment: &str| {
+        if prefix.is_empty() {
+            segment.to_string()
+        } else {
+            format!("{}{}{}", prefix, separator, segment)
+        }
+    };
+
+    match node {
+        Node::Mapping(entries) => {
+            if !prefix.is_empty() {
+                result
+                    .objects
+                    .insert(prefix.clone(), MessageLocation::new(file_path, line, col));
+            }
+            for entry in entries {
+                flatten_yaml(
+                    &entry.value,
+                    join(&entry.key),
+                    (entry.line, entry.col),
+                    separator,
+                    file_path,
+                    result,
+                );
+            }
+        }
+        Node::String(value) => {
+            let context = MessageContext::new(
+                MessageLocation::new(file_path, line, col),
+                prefix.clone(),
+                value.clone(),
+            );
+            result.entries.insert(
+                prefix,
+                MessageEntry {
+                    context,
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        Node::Sequence(items) => {
+            if items.is_empty() {
+                if !prefix.is_empty() {
+                    result
+                        .empty_arrays
+                        .insert(prefix, MessageLocation::new(file_path, line, col));
+                }
+                return;
+            }
+
+            // Same as JSON: string arrays are one key, other arrays are indexed
+            let values: Option<Vec<&str>> = items
+                .iter()
+                .map(|item| match &item.value {
+                    Node::String(value) => Some(value.as_str()),
+                    _ => None,
+                })
+                .collect();
+            match values {
+                Some(values) if !prefix.is_empty() => {
+                    let context = MessageContext::new(
+                        MessageLocation::new(file_path, line, col),
+                        prefix.clone(),
+                        values.join(", "),
+                    );
+                    result.entries.insert(
+                        prefix,
+                        MessageEntry {
+                            context,
+                            value_type: ValueType::StringArray,
+                        },
+                    );
+                }
+                _ => {
+                    for (index, item) in items.iter().enumerate() {
+                        flatten_yaml(
+                            &item.value,
+                            join(&index.to_string()),
+                            (item.line, item.col),
+                            separator,
+                            file_path,
+                            result,
+                        );
+                    }
+                }
+            }
+        }
+        Node::Other => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flatten(content: &str) -> LocaleMessages {
+        let document = parse_yaml(content).unwrap();
+        let mut messages = LocaleMessages::new("en", "en.yml");
+        flatten_yaml(
+            &document.root,
+            String::new(),
+            (1, 1),
+            ".",
+            "en.yml",
+            &mut messages,
+        );
+        messages
+    }
+
+    fn value<'a>(messages: &'a LocaleMessages, key: &str) -> &'a str {
+        &messages.entries[key].context.value
+    }
+
+    #[test]
+    fn test_nested_mappings_with_positions() {
+        let messages = flatten(
+            "# Common strings\n\
+             Common:\n  \
+               submit: Submit  # button\n  \
+               nested:\n    \
+                 title: \"Hello \\\"world\\\"\"\n\
+             Auth:\n  \
+               login: 'It''s me'\n",
+        );
+
+        assert_eq!(messages.entries.len(), 3);
+        assert_eq!(value(&messages, "Common.submit"), "Submit");
+        assert_eq!(value(&messages, "Common.nested.title"), "Hello \"world\"");
+        assert_eq!(value(&messages, "Auth.login"), "It's me");
+
+        let location = &messages.entries["Common.nested.title"].context.location;
+        assert_eq!((location.line, location.col), (5, 5));
+
+        let nested = &messages.objects["Common.nested"];
+        assert_eq!((nested.line, nested.col), (4, 3));
+    }
+
+    #[test]
+    fn test_sequences() {
+        let messages = flatten(
+            "Home:\n  \
+               benefits:\n  \
+               - Fast\n  \
+               - \"Simple\"\n  \
+               tags: [one, 'two']\n  \
+               faq:\n    \
+                 - question: Why?\n      \
+                   answer: Because\n    \
+                 - question: How?\n",
+        );
+
+        assert_eq!(value(&messages, "Home.benefits"), "Fast, Simple");
+        assert_eq!(
+            messages.entries["Home.benefits"].value_type,
+            ValueType::StringArray
+        );
+        assert_eq!(value(&messages, "Home.tags"), "one, two");
+        assert_eq!(value(&messages, "Home.faq.0.question"), "Why?");
+        assert_eq!(value(&messages, "Home.faq.0.answer"), "Because");
+        assert_eq!(value(&messages, "Home.faq.1.question"), "How?");
+        assert_eq!(messages.entries["Home.faq.1.question"].context.line(), 9);
+    }
+
+    #[test]
+    fn test_block_scalars() {
+        let messages = flatten(
+            "literal: |\n  \
+               Line one\n  \
+               Line two\n\
+             folded: >-\n  \
+               Folded\n  \
+               text\n\n  \
+               Next\n\
+             plain: multi\n  \
+               line\n",
+        );
+
+        assert_eq!(value(&messages, "literal"), "Line one\nLine two\n");
+        assert_eq!(value(&messages, "folded"), "Folded text\nNext");
+        assert_eq!(value(&messages, "plain"), "multi line");
+    }
+
+    #[test]
+    fn test_non_string_scalars_are_skipped() {
+        let messages =
+            flatten("count: 5\nenabled: true\nempty:\nnone: ~\nyes: yes\nurl: http://x.io\n");
+
+        let mut keys: Vec<&String> = messages.entries.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["url", "yes"]);
+        assert_eq!(value(&messages, "url"), "http://x.io");
+    }
+
+    #[test]
+    fn test_duplicate_keys_keep_last_value() {
+        let document = parse_yaml("Common:\n  ok: One\n  ok: Two\n").unwrap();
+        assert_eq!(
+            document.duplicates,
+            vec![RepeatedKey {
+                path: vec!["Common".to_string(), "ok".to_string()],
+                first_line: 2,
+                line: 3,
+            }]
+        );
+
+        let messages = flatten("Common:\n  ok: One\n  ok: Two\n");
+        assert_eq!(value(&messages, "Common.ok"), "Two");
+    }
+
+    #[test]
+    fn test_unsupported_syntax() {
+        let cases = [
+            ("base: &base\n  a: b\n", 1),
+            ("a: b\n---\nc: d\n", 2),
+            ("a:\n  b: c\n    d: e\n", 3),
+            ("a: \"unterminated\n", 1),
+            ("a: {b: c}\n", 1),
+        ];
+        for (content, line) in cases {
+            let error = parse_yaml(content).unwrap_err();
+            assert_eq!(error.line, line, "{}: {}", content, error.message);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Wall-clock timings of the analysis pipeline (`check --profile`).
+//!
+//! `CheckContext` records how long each phase takes as it runs. Timings are
+//! exclusive: a phase that triggers another one lazily (Phase 1 parsing the
+//! files it needs, a rule running Phase 3) only counts its own time.
+
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+/// One timed step of the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseTiming {
+    /// Step name (e.g., "phase 1: collection", "rule: missing").
+    pub name: String,
+    pub duration: Duration,
+}
+
+/// Timings recorded in the order the steps finished.
+#[derive(Debug, Default)]
+pub struct Profile {
+    timings: RefCell<Vec<PhaseTiming>>,
+}
+
+impl Profile {
+    /// Record a step that was timed by the caller.
+    pub fn record(&self, name: impl Into<String>, duration: Duration) {
+        self.timings.borrow_mut().push(PhaseTiming {
+            name: name.into(),
+            duration,
+        });
+    }
+
+    /// Run `f` and record its time, minus the steps recorded while it ran.
+    pub fn time<T>(&self, name: impl Into<String>, f: impl FnOnce() -> T) -> T {
+        let nested_before = self.total();
+        let start = Instant::now();
+        let value = f();
+        let elapsed = start.elapsed();
+        let nested = self.total().saturating_sub(nested_before);
+        self.record(name, elapsed.saturating_sub(nested));
+        value
+    }
+
+    /// Sum of all recorded steps.
+    pub fn total(&self) -> Duration {
+        self.timings.borrow().iter().map(|t| t.duration).sum()
+    }
+
+    /// All recorded steps.
+    pub fn timings(&self) -> Vec<PhaseTiming> {
+        self.timings.borrow().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_excludes_nested_steps() {
+        let profile = Profile::default();
+        profile.time("outer", || {
+            profile.record("inner", Duration::from_secs(3600));
+        });
+
+        let timings = profile.timings();
+        let names: Vec<&str> = timings.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["inner", "outer"]);
+        // The outer step took far less than the hour recorded inside it
+        assert_eq!(timings[1].duration, Duration::ZERO);
+        assert_eq!(profile.total(), Duration::from_secs(3600));
+    }
+}
@@ -5,11 +5,25 @@
 //! and UnresolvedKeyUsage results.
 //!
 //! Resolution includes:
-//! - Static key extraction from literals and conditionals
+//! - Static key extraction from literals and conditionals, setting aside
+//!   empty and whitespace-only keys (`t("")`) as `EmptyKeyUsage`s
 //! - Dynamic key resolution from object access and array iteration
 //! - Schema function expansion
-//! - glot-message-keys pattern expansion
-//! - Unresolved key warning generation
+//! - glot-message-keys pattern expansion (and patterns matching no key)
+//! - Unresolved key warning generation, including object lookups whose
+//!   candidate keys all miss the primary locale (`NotAKeyMap`)
+//!
+//! ## `NextIntlClientProvider` Namespaces
+//!
+//! Calls in components rendered inside a `NextIntlClientProvider` that passes
+//! only some namespaces carry those namespaces. They are used to suggest where
+//! an unresolved key should be declared when no pattern can be inferred. This
+//! is a heuristic:
+//! - Components are matched by name, so two components with the same name in
+//!   different files share their providers
+//! - Only direct rendering is seen; a component passed through props, context
+//!   or another file's layout isn't associated with the provider
+//! - Keys are never resolved from provided namespaces alone
 
 use std::collections::HashSet;
 
@@ -17,8 +31,8 @@ use std::collections::HashMap;
 
 use crate::core::{CommentStyle, SourceContext, SourceLocation};
 use crate::core::{
-    FileKeyUsages, FullKey, ResolvedKeyUsage, SchemaSource, UnresolvedKeyUsage,
-    UsageUnresolvedKeyReason,
+    EmptyKeyUsage, FileKeyUsages, FullKey, InterpolationUsage, ResolvedKeyUsage, SchemaSource,
+    UnmatchedPatternUsage, UnresolvedKeyUsage, UsageUnresolvedKeyReason,
 };
 use crate::core::{
     collect::SuppressibleRule,
@@ -27,12 +41,19 @@ use crate::core::{
     schema::{ExpandResult, SchemaCallInfo, SchemaRegistry},
 };
 
+/// Fewest candidate keys for a call to be reported as `NotAKeyMap` when none
+/// of them exist. A single missing candidate is reported as a missing key.
+const MIN_KEY_MAP_CANDIDATES: usize = 2;
+
 /// Resolve translation calls and schema calls to key usages.
 ///
 /// This is Phase 3 of the analysis pipeline, processing the raw calls and schema calls
 /// collected in Phase 2. It produces:
 /// - `resolved`: All keys that were successfully resolved (static, dynamic, schema, glot-message-keys)
 /// - `unresolved`: Keys that could not be statically resolved (dynamic key warnings)
+///
+/// Namespaces are joined to keys with `key_separator`.
+#[allow(clippy::too_many_arguments)]
 pub fn resolve_translation_calls(
     raw_calls: &[RawTranslationCall],
     schema_calls: &[SchemaCallInfo],
@@ -41,9 +62,13 @@ pub fn resolve_translation_calls(
     schema_cache: &HashMap<String, ExpandResult>,
     schema_registry: &SchemaRegistry,
     available_keys: &HashSet<String>,
+    key_separator: &str,
 ) -> FileKeyUsages {
     let mut resolved = Vec::new();
     let mut unresolved = Vec::new();
+    let mut unmatched_patterns = Vec::new();
+    let mut interpolations = Vec::new();
+    let mut empty_keys = Vec::new();
 
     // Process raw translation calls
     for call in raw_calls {
@@ -51,8 +76,12 @@ pub fn resolve_translation_calls(
             call,
             file_comments,
             available_keys,
+            key_separator,
             &mut resolved,
             &mut unresolved,
+            &mut unmatched_patterns,
+            &mut interpolations,
+            &mut empty_keys,
         );
     }
 
@@ -64,6 +93,7 @@ pub fn resolve_translation_calls(
             file_comments,
             schema_cache,
             schema_registry,
+            key_separator,
             &mut resolved,
             &mut unresolved,
         );
@@ -72,96 +102,181 @@ pub fn resolve_translation_calls(
     FileKeyUsages {
         resolved,
         unresolved,
+        unmatched_patterns,
+        interpolations,
+        empty_keys,
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn resolve_single_call(
     call: &RawTranslationCall,
     file_comments: &FileComments,
     available_keys: &HashSet<String>,
+    key_separator: &str,
     resolved: &mut Vec<ResolvedKeyUsage>,
     unresolved: &mut Vec<UnresolvedKeyUsage>,
+    unmatched_patterns: &mut Vec<UnmatchedPatternUsage>,
+    interpolations: &mut Vec<InterpolationUsage>,
+    empty_keys: &mut Vec<EmptyKeyUsage>,
 ) {
     let ctx = &call.context;
     let namespaces = call.translation_source.namespaces();
 
     // Collect suppressed rules for this location
     let suppressed_rules = collect_suppressed_rules(file_comments, ctx.line());
+    let rich_text = matches!(
+        &call.call_kind,
+        TranslationCallKind::Method(method) if method == "rich" || method == "markup"
+    );
 
     // Try to extract static keys first
     if let Some(keys) = extract_static_keys(&call.argument) {
         for key in keys {
+            // `t("")` returns the namespace itself rather than a message
+            if key.trim().is_empty() {
+                empty_keys.push(EmptyKeyUsage {
+                    context: ctx.clone(),
+                    key,
+                    suppressed_rules: suppressed_rules.clone(),
+                });
+                continue;
+            }
             for namespace in &namespaces {
-                let full_key = resolve_full_key(namespace, &key);
+                let full_key = resolve_full_key(namespace, &key, key_separator);
                 resolved.push(ResolvedKeyUsage {
-                    key: FullKey::new(full_key),
+                    key: FullKey::new(full_key.clone()),
                     context: ctx.clone(),
                     suppressed_rules: suppressed_rules.clone(),
                     from_schema: None,
+                    rich_text,
+                    namespace: Some(namespace.clone()),
                 });
+                if let Some(values) = &call.values {
+                    interpolations.push(InterpolationUsage {
+                        key: FullKey::new(full_key),
+                        context: ctx.clone(),
+                        values: values.clone(),
+                        suppressed_rules: suppressed_rules.clone(),
+                    });
+                }
             }
         }
         return;
     }
 
     // Not a static key - try to resolve dynamically
-    match call.argument.resolve_keys() {
+    let is_method = matches!(call.call_kind, TranslationCallKind::Method(_));
+    let not_a_key_map = match call.argument.resolve_keys() {
         Ok(keys) => {
-            // Dynamic but resolvable - add all resolved keys
-            for key in keys {
-                for namespace in &namespaces {
-                    let full_key = resolve_full_key(namespace, &key);
+            let full_keys: Vec<(String, &Option<String>)> = keys
+                .iter()
+                .flat_map(|key| {
+                    namespaces.iter().map(|namespace| {
+                        (resolve_full_key(namespace, key, key_separator), namespace)
+                    })
+                })
+                .collect();
+            let not_a_key_map = key_object_name(&call.argument)
+                .filter(|_| !is_method)
+                .and_then(|object_name| {
+                    let candidates: HashSet<&String> =
+                        full_keys.iter().map(|(full_key, _)| full_key).collect();
+                    let disjoint = candidates.len() >= MIN_KEY_MAP_CANDIDATES
+                        && candidates.iter().all(|key| !available_keys.contains(*key));
+                    disjoint.then(|| (object_name.to_string(), candidates.len()))
+                });
+            if not_a_key_map.is_none() {
+                // Dynamic but resolvable - add all resolved keys
+                for (full_key, namespace) in full_keys {
                     resolved.push(ResolvedKeyUsage {
                         key: FullKey::new(full_key),
                         context: ctx.clone(),
                         suppressed_rules: suppressed_rules.clone(),
                         from_schema: None,
+                        rich_text,
+                        namespace: Some(namespace.clone()),
                     });
                 }
-            }
-        }
-        Err(_) => {
-            // Cannot resolve - check for glot-message-keys declaration
-            // Method calls don't generate warnings (preserve original behavior)
-            if matches!(call.call_kind, TranslationCallKind::Method(_)) {
                 return;
             }
+            not_a_key_map
+        }
+        Err(_) => None,
+    };
 
-            if let Some(decl) = file_comments.declarations.get_declaration(ctx.line()) {
-                // Has declaration, expand pattern
-                let expanded_keys = decl.expand_all(&namespaces, available_keys);
-                for key in expanded_keys {
-                    resolved.push(ResolvedKeyUsage {
-                        key: FullKey::new(key),
-                        context: ctx.clone(),
-                        suppressed_rules: suppressed_rules.clone(),
-                        from_schema: None,
-                    });
-                }
-            } else {
-                // No declaration, generate unresolved warning
-                let (reason, hint, pattern) = infer_warning_details(
-                    &call.argument,
-                    &call.translation_source,
-                    ctx.comment_style,
-                );
-                unresolved.push(UnresolvedKeyUsage {
-                    context: ctx.clone(),
-                    reason,
-                    hint,
-                    pattern,
-                });
-            }
+    // Cannot resolve - check for glot-message-keys declaration
+    // Method calls don't generate warnings (preserve original behavior)
+    if is_method {
+        return;
+    }
+
+    if let Some(decl) = file_comments
+        .declarations
+        .get_declaration(ctx.line(), key_separator)
+    {
+        // Has declaration, expand pattern
+        let expanded_keys = decl.expand_all(&namespaces, available_keys, key_separator);
+        for key in expanded_keys {
+            resolved.push(ResolvedKeyUsage {
+                key: FullKey::new(key),
+                context: ctx.clone(),
+                suppressed_rules: suppressed_rules.clone(),
+                from_schema: None,
+                rich_text,
+                namespace: None,
+            });
         }
+        for pattern in decl.unmatched_patterns(&namespaces, available_keys, key_separator) {
+            unmatched_patterns.push(UnmatchedPatternUsage {
+                context: ctx.clone(),
+                pattern,
+                suppressed_rules: suppressed_rules.clone(),
+            });
+        }
+    } else {
+        // No declaration, generate unresolved warning
+        let (reason, hint, pattern) = match not_a_key_map {
+            Some((object_name, candidate_count)) => (
+                UsageUnresolvedKeyReason::NotAKeyMap {
+                    object_name,
+                    candidate_count,
+                },
+                Some(format!(
+                    "none of its {} candidate keys exist; this object probably isn't a translation key map",
+                    candidate_count
+                )),
+                None,
+            ),
+            None => infer_warning_details(
+                &call.argument,
+                &call.translation_source,
+                ctx.comment_style,
+                key_separator,
+            ),
+        };
+        let hint = hint.or_else(|| {
+            call.provided_namespaces
+                .as_deref()
+                .map(format_provider_hint)
+        });
+        unresolved.push(UnresolvedKeyUsage {
+            context: ctx.clone(),
+            reason,
+            hint,
+            pattern,
+        });
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn resolve_schema_call(
     call: &SchemaCallInfo,
     file_path: &str,
     file_comments: &FileComments,
     schema_cache: &HashMap<String, ExpandResult>,
     schema_registry: &SchemaRegistry,
+    key_separator: &str,
     resolved: &mut Vec<ResolvedKeyUsage>,
     unresolved: &mut Vec<UnresolvedKeyUsage>,
 ) {
@@ -189,7 +304,7 @@ fn resolve_schema_call(
         let full_key = match &call.namespace {
             Some(ns) => {
                 // Apply namespace: "Form" + "titleRequired" → "Form.titleRequired"
-                format!("{}.{}", ns, cached_key.raw_key)
+                format!("{}{}{}", ns, key_separator, cached_key.raw_key)
             }
             None => {
                 // No namespace from call site, use raw_key as-is
@@ -219,6 +334,8 @@ fn resolve_schema_call(
                     schema_name: call.schema_name.clone(),
                     schema_file: schema_file.clone(),
                 }),
+                rich_text: false,
+                namespace: Some(call.namespace.clone()),
             });
         }
     }
@@ -230,7 +347,16 @@ fn collect_suppressed_rules(
     line: usize,
 ) -> HashSet<SuppressibleRule> {
     let mut suppressed = HashSet::new();
-    for rule in [SuppressibleRule::Hardcoded, SuppressibleRule::Untranslated] {
+    for rule in [
+        SuppressibleRule::Hardcoded,
+        SuppressibleRule::Untranslated,
+        SuppressibleRule::KeyNaming,
+        SuppressibleRule::MarkupOnly,
+        SuppressibleRule::UnmatchedMessageKeys,
+        SuppressibleRule::NamespaceConsistency,
+        SuppressibleRule::InterpolationMismatch,
+        SuppressibleRule::EmptyKey,
+    ] {
         if file_comments.suppressions.is_suppressed(line, rule) {
             suppressed.insert(rule);
         }
@@ -265,9 +391,19 @@ fn extract_static_keys(source: &ValueSource) -> Option<Vec<String>> {
     }
 }
 
-fn resolve_full_key(namespace: &Option<String>, key: &str) -> String {
+/// Object whose values or keys `source` takes, looking through templates.
+fn key_object_name(source: &ValueSource) -> Option<&str> {
+    match source {
+        ValueSource::ObjectAccess { object_name, .. }
+        | ValueSource::ObjectKeys { object_name, .. } => Some(object_name),
+        ValueSource::Template { inner, .. } => key_object_name(inner),
+        _ => None,
+    }
+}
+
+fn resolve_full_key(namespace: &Option<String>, key: &str, separator: &str) -> String {
     match namespace {
-        Some(ns) => format!("{}.{}", ns, key),
+        Some(ns) => format!("{}{}{}", ns, separator, key),
         None => key.to_string(),
     }
 }
@@ -277,11 +413,13 @@ fn infer_warning_details(
     argument: &ValueSource,
     translation_source: &TranslationSource,
     comment_style: CommentStyle,
+    separator: &str,
 ) -> (UsageUnresolvedKeyReason, Option<String>, Option<String>) {
     match argument {
         ValueSource::Template { prefix, suffix, .. } => {
             // Reconstruct pattern from Template
-            let pattern = infer_pattern_from_template(prefix, suffix, translation_source);
+            let pattern =
+                infer_pattern_from_template(prefix, suffix, translation_source, separator);
             let hint = pattern.as_ref().map(|p| format_hint(p, comment_style));
             (UsageUnresolvedKeyReason::TemplateWithExpr, hint, pattern)
         }
@@ -306,6 +444,7 @@ fn infer_pattern_from_template(
     prefix: &str,
     suffix: &str,
     translation_source: &TranslationSource,
+    separator: &str,
 ) -> Option<String> {
     let pattern = format!("{}*{}", prefix, suffix);
 
@@ -318,17 +457,17 @@ fn infer_pattern_from_template(
         format!(".{}", pattern)
     } else {
         match translation_source.primary_namespace() {
-            Some(ns) => format!("{}.{}", ns, pattern),
+            Some(ns) => format!("{}{}{}", ns, separator, pattern),
             None => pattern,
         }
     };
 
     // Validate pattern
-    validate_pattern(&full_pattern)
+    validate_pattern(&full_pattern, separator)
 }
 
-fn validate_pattern(pattern: &str) -> Option<String> {
-    let segments: Vec<&str> = pattern.split('.').collect();
+fn validate_pattern(pattern: &str, separator: &str) -> Option<String> {
+    let segments: Vec<&str> = pattern.split(separator).collect();
 
     // First segment cannot be wildcard
     if segments.first().is_some_and(|s| *s == "*") && segments.len() > 1 {
@@ -348,6 +487,14 @@ fn validate_pattern(pattern: &str) -> Option<String> {
     Some(pattern.to_string())
 }
 
+fn format_provider_hint(namespaces: &[String]) -> String {
+    let namespaces: Vec<String> = namespaces.iter().map(|ns| format!("\"{}\"", ns)).collect();
+    format!(
+        "`NextIntlClientProvider` only passes {} to this component; add `glot-message-keys` to declare expected keys",
+        namespaces.join(", ")
+    )
+}
+
 fn format_hint(pattern: &str, comment_style: CommentStyle) -> String {
     match comment_style {
         CommentStyle::Jsx => {
@@ -0,0 +1,174 @@
+//! CLDR plural categories per language.
+//!
+//! Only the category names are embedded, not the numeric rules that pick a
+//! category: that's enough to tell whether a `plural`/`selectordinal` message
+//! covers every form a language needs. Data follows CLDR 44.
+//!
+//! Languages not listed here are unknown and skipped by callers.
+
+use super::icu::PluralKind;
+
+/// All CLDR plural categories, in canonical order.
+pub const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
+/// Categories of one language for one plural kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluralCategories {
+    /// Categories a message should provide.
+    pub required: &'static [&'static str],
+    /// Categories that are valid but rarely needed.
+    pub optional: &'static [&'static str],
+}
+
+impl PluralCategories {
+    /// Whether `category` is selected for some number in this language.
+    pub fn allows(&self, category: &str) -> bool {
+        self.required.contains(&category) || self.optional.contains(&category)
+    }
+}
+
+const OTHER: &[&str] = &["other"];
+const ONE_OTHER: &[&str] = &["one", "other"];
+
+/// Cardinal categories, grouped by category set.
+const CARDINAL: &[(&[&str], &[&str])] = &[
+    (
+        &[
+            "bo", "dz", "id", "ig", "ja", "jv", "km", "ko", "lo", "ms", "my", "sah", "th", "to",
+            "vi", "wo", "yo", "yue", "zh",
+        ],
+        OTHER,
+    ),
+    (
+        &[
+            "af", "am", "as", "az", "bg", "bn", "da", "de", "el", "en", "eo", "et", "eu", "fa",
+            "fi", "fil", "fy", "gl", "gu", "ha", "hi", "hu", "hy", "is", "ka", "kk", "kn", "ky",
+            "lb", "mk", "ml", "mn", "mr", "nb", "ne", "nl", "nn", "no", "or", "pa", "ps", "si",
+            "so", "sq", "sv", "sw", "ta", "te", "tk", "tr", "ug", "ur", "uz", "zu",
+        ],
+        ONE_OTHER,
+    ),
+    (&["ca", "es", "fr", "it", "pt"], ONE_OTHER),
+    (&["bs", "hr", "ro", "sr"], &["one", "few", "other"]),
+    (
+        &["be", "cs", "lt", "pl", "ru", "sk", "uk"],
+        &["one", "few", "many", "other"],
+    ),
+    (&["he"], &["one", "two", "other"]),
+    (&["dsb", "gd", "hsb", "sl"], &["one", "two", "few", "other"]),
+    (&["lv"], &["zero", "one", "other"]),
+    (&["br", "ga", "mt"], &["one", "two", "few", "many", "other"]),
+    (
+        &["ar", "cy"],
+        &["zero", "one", "two", "few", "many", "other"],
+    ),
+];
+
+/// Cardinal categories that are valid but not required.
+///
+/// In these languages `many` is only selected for compact large numbers like
+/// `1M`, so messages that omit it are fine.
+const CARDINAL_OPTIONAL: &[(&[&str], &[&str])] = &[(&["ca", "es", "fr", "it", "pt"], &["many"])];
+
+/// Ordinal categories for languages that have more than `other`.
+///
+/// Every other language with cardinal data only uses `other` for ordinals.
+const ORDINAL: &[(&[&str], &[&str])] = &[
+    (
+        &[
+            "fil", "fr", "ga", "hu", "hy", "lo", "ms", "ne", "ro", "sv", "vi",
+        ],
+        ONE_OTHER,
+    ),
+    (&["it", "kk"], &["many", "other"]),
+    (&["be", "tk", "uk"], &["few", "other"]),
+    (&["ka", "sq"], &["one", "many", "other"]),
+    (&["ca", "en", "gd", "mr"], &["one", "two", "few", "other"]),
+    (&["mk"], &["one", "two", "many", "other"]),
+    (&["az"], &["one", "few", "many", "other"]),
+    (
+        &["as", "bn", "gu", "hi", "or"],
+        &["one", "two", "few", "many", "other"],
+    ),
+    (&["cy"], &["zero", "one", "two", "few", "many", "other"]),
+];
+
+/// Language subtag of a locale code (`pt-BR` and `pt_BR` give `pt`).
+fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_lowercase()
+}
+
+fn lookup(
+    table: &[(&[&str], &'static [&'static str])],
+    language: &str,
+) -> Option<&'static [&'static str]> {
+    table
+        .iter()
+        .find(|(languages, _)| languages.contains(&language))
+        .map(|(_, categories)| *categories)
+}
+
+/// Plural categories of `locale`, or `None` when the language is unknown.
+pub fn plural_categories(locale: &str, kind: PluralKind) -> Option<PluralCategories> {
+    let language = language(locale);
+    let cardinal = lookup(CARDINAL, &language)?;
+
+    Some(match kind {
+        PluralKind::Cardinal => PluralCategories {
+            required: cardinal,
+            optional: lookup(CARDINAL_OPTIONAL, &language).unwrap_or(&[]),
+        },
+        PluralKind::Ordinal => PluralCategories {
+            required: lookup(ORDINAL, &language).unwrap_or(OTHER),
+            optional: &[],
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cardinal_categories() {
+        let ru = plural_categories("ru", PluralKind::Cardinal).unwrap();
+        assert_eq!(ru.required, &["one", "few", "many", "other"]);
+
+        let ja = plural_categories("ja", PluralKind::Cardinal).unwrap();
+        assert_eq!(ja.required, &["other"]);
+        assert!(!ja.allows("one"));
+    }
+
+    #[test]
+    fn test_region_and_script_subtags() {
+        let pt = plural_categories("pt-BR", PluralKind::Cardinal).unwrap();
+        assert_eq!(pt.required, &["one", "other"]);
+        assert!(pt.allows("many"));
+        assert!(plural_categories("zh_Hant", PluralKind::Cardinal).is_some());
+    }
+
+    #[test]
+    fn test_ordinal_defaults_to_other() {
+        let en = plural_categories("en-US", PluralKind::Ordinal).unwrap();
+        assert_eq!(en.required, &["one", "two", "few", "other"]);
+        let de = plural_categories("de", PluralKind::Ordinal).unwrap();
+        assert_eq!(de.required, &["other"]);
+    }
+
+    #[test]
+    fn test_unknown_language() {
+        assert!(plural_categories("tlh", PluralKind::Cardinal).is_none());
+        assert!(plural_categories("tlh", PluralKind::Ordinal).is_none());
+    }
+
+    #[test]
+    fn test_tables_use_known_categories() {
+        for (_, categories) in CARDINAL.iter().chain(ORDINAL).chain(CARDINAL_OPTIONAL) {
+            assert!(categories.iter().all(|c| PLURAL_CATEGORIES.contains(c)));
+        }
+    }
+}
@@ -7,12 +7,17 @@ pub fn is_glob_pattern(pattern: &str) -> bool {
 
 /// Expand a glob pattern against a set of available keys.
 ///
-/// The `*` wildcard matches exactly one segment (doesn't cross `.` boundaries).
+/// The `*` wildcard matches exactly one segment (doesn't cross `separator`
+/// boundaries, `.` by default).
 ///
 /// Examples:
 /// - `errors.*` matches `errors.E001`, `errors.E002` but not `errors.network.timeout`
 /// - `form.*.label` matches `form.email.label`, `form.password.label`
-pub fn expand_glob_pattern(pattern: &str, available_keys: &HashSet<String>) -> Vec<String> {
+pub fn expand_glob_pattern(
+    pattern: &str,
+    available_keys: &HashSet<String>,
+    separator: &str,
+) -> Vec<String> {
     if !is_glob_pattern(pattern) {
         // Literal pattern - return as-is if it exists in available_keys
         if available_keys.contains(pattern) {
@@ -21,18 +26,18 @@ pub fn expand_glob_pattern(pattern: &str, available_keys: &HashSet<String>) -> V
         return vec![];
     }
 
-    let pattern_segments: Vec<&str> = pattern.split('.').collect();
+    let pattern_segments: Vec<&str> = pattern.split(separator).collect();
 
     available_keys
         .iter()
-        .filter(|key| matches_pattern(&pattern_segments, key))
+        .filter(|key| matches_pattern(&pattern_segments, key, separator))
         .cloned()
         .collect()
 }
 
 /// Check if a key matches a pattern.
-fn matches_pattern(pattern_segments: &[&str], key: &str) -> bool {
-    let key_segments: Vec<&str> = key.split('.').collect();
+fn matches_pattern(pattern_segments: &[&str], key: &str, separator: &str) -> bool {
+    let key_segments: Vec<&str> = key.split(separator).collect();
 
     // Must have same number of segments
     if pattern_segments.len() != key_segments.len() {
@@ -117,7 +122,7 @@ mod tests {
     #[test]
     fn test_literal_pattern_exists() {
         let keys = make_keys(&["Common.submit", "Common.cancel"]);
-        let result = expand_glob_pattern("Common.submit", &keys);
+        let result = expand_glob_pattern("Common.submit", &keys, ".");
 
         assert_eq!(result, vec!["Common.submit"]);
     }
@@ -125,7 +130,7 @@ mod tests {
     #[test]
     fn test_literal_pattern_not_exists() {
         let keys = make_keys(&["Common.submit", "Common.cancel"]);
-        let result = expand_glob_pattern("Common.delete", &keys);
+        let result = expand_glob_pattern("Common.delete", &keys, ".");
 
         assert!(result.is_empty());
     }
@@ -139,7 +144,7 @@ mod tests {
             "auth.login",
             "auth.logout",
         ]);
-        let mut result = expand_glob_pattern("errors.*", &keys);
+        let mut result = expand_glob_pattern("errors.*", &keys, ".");
         result.sort();
 
         assert_eq!(result, vec!["errors.E001", "errors.E002", "errors.E003"]);
@@ -154,7 +159,7 @@ mod tests {
             "form.password.placeholder",
             "form.submit",
         ]);
-        let mut result = expand_glob_pattern("form.*.label", &keys);
+        let mut result = expand_glob_pattern("form.*.label", &keys, ".");
         result.sort();
 
         assert_eq!(result, vec!["form.email.label", "form.password.label"]);
@@ -167,7 +172,7 @@ mod tests {
             "errors.network.connection",
             "errors.E001",
         ]);
-        let mut result = expand_glob_pattern("errors.*", &keys);
+        let mut result = expand_glob_pattern("errors.*", &keys, ".");
         result.sort();
 
         // Only matches single segment after "errors."
@@ -181,7 +186,7 @@ mod tests {
             "form.password.hint.text",
             "other.field.label.text",
         ]);
-        let mut result = expand_glob_pattern("form.*.*.text", &keys);
+        let mut result = expand_glob_pattern("form.*.*.text", &keys, ".");
         result.sort();
 
         assert_eq!(
@@ -193,7 +198,7 @@ mod tests {
     #[test]
     fn test_no_matches() {
         let keys = make_keys(&["auth.login", "auth.logout"]);
-        let result = expand_glob_pattern("errors.*", &keys);
+        let result = expand_glob_pattern("errors.*", &keys, ".");
 
         assert!(result.is_empty());
     }
@@ -209,7 +214,7 @@ mod tests {
             "step.title",
             "other.title",
         ]);
-        let mut result = expand_glob_pattern("step*.title", &keys);
+        let mut result = expand_glob_pattern("step*.title", &keys, ".");
         result.sort();
 
         // * matches 0 or more characters
@@ -227,7 +232,7 @@ mod tests {
             "Options.other",
             "colorOptions.red",
         ]);
-        let mut result = expand_glob_pattern("*Options.*", &keys);
+        let mut result = expand_glob_pattern("*Options.*", &keys, ".");
         result.sort();
 
         assert_eq!(
@@ -244,12 +249,20 @@ mod tests {
     #[test]
     fn test_partial_segment_both_sides() {
         let keys = make_keys(&["prefixMiddleSuffix.key", "prefixSuffix.key", "other.key"]);
-        let mut result = expand_glob_pattern("prefix*Suffix.*", &keys);
+        let mut result = expand_glob_pattern("prefix*Suffix.*", &keys, ".");
         result.sort();
 
         assert_eq!(result, vec!["prefixMiddleSuffix.key", "prefixSuffix.key"]);
     }
 
+    #[test]
+    fn test_custom_separator() {
+        let keys = make_keys(&["errors/E001", "errors/network/timeout", "errors.E002"]);
+        let result = expand_glob_pattern("errors/*", &keys, "/");
+
+        assert_eq!(result, vec!["errors/E001"]);
+    }
+
     #[test]
     fn test_segment_matches_function() {
         // Direct tests for segment_matches
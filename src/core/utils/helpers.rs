@@ -1,6 +1,12 @@
 //! Helper functions for AST analysis.
 
-use swc_ecma_ast::{CallExpr, Expr, Lit, ObjectPat, ObjectPatProp, Pat};
+use std::collections::HashMap;
+
+use swc_ecma_ast::{
+    CallExpr, Callee, Decl, Expr, ExprOrSpread, Lit, MemberExpr, Module, ModuleDecl, ModuleItem,
+    ObjectPat, ObjectPatProp, OptChainBase, OptChainExpr, Pat, Prop, PropName, PropOrSpread, Stmt,
+    VarDeclKind,
+};
 
 /// Unwrap parentheses and TypeScript type assertions.
 /// Handles: `(expr)`, `expr as T`, `expr as const`, `expr satisfies T`
@@ -14,21 +20,106 @@ pub fn unwrap_paren(expr: &Expr) -> &Expr {
     }
 }
 
+/// Rewrite a callee without non-null assertions, parentheses and optional
+/// member access, or `None` if it is already plain.
+/// Handles: `t!`, `(t)`, `t?.raw`, `t!.raw`
+fn plain_callee(expr: &Expr) -> Option<Expr> {
+    match expr {
+        Expr::TsNonNull(non_null) => {
+            Some(plain_callee(&non_null.expr).unwrap_or_else(|| (*non_null.expr).clone()))
+        }
+        Expr::Paren(paren) => {
+            Some(plain_callee(&paren.expr).unwrap_or_else(|| (*paren.expr).clone()))
+        }
+        Expr::OptChain(chain) => match &*chain.base {
+            OptChainBase::Member(member) => Some(Expr::Member(MemberExpr {
+                obj: Box::new(plain_callee(&member.obj).unwrap_or_else(|| (*member.obj).clone())),
+                ..member.clone()
+            })),
+            OptChainBase::Call(_) => None,
+        },
+        Expr::Member(member) => plain_callee(&member.obj).map(|obj| {
+            Expr::Member(MemberExpr {
+                obj: Box::new(obj),
+                ..member.clone()
+            })
+        }),
+        _ => None,
+    }
+}
+
+/// Rewrite `t!("key")` and `t!.raw("key")` as plain calls, or `None` if the
+/// callee is already plain.
+pub fn normalize_call(call: &CallExpr) -> Option<CallExpr> {
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    let callee = plain_callee(callee)?;
+    Some(CallExpr {
+        callee: Callee::Expr(Box::new(callee)),
+        ..call.clone()
+    })
+}
+
+/// Rewrite an optional call like `t?.("key")` or `t?.raw("key")` as a plain call.
+///
+/// Returns `None` for optional member access that isn't called (`obj?.prop`).
+pub fn opt_chain_call(chain: &OptChainExpr) -> Option<CallExpr> {
+    let OptChainBase::Call(call) = &*chain.base else {
+        return None;
+    };
+    let callee = plain_callee(&call.callee).unwrap_or_else(|| (*call.callee).clone());
+    Some(CallExpr {
+        span: call.span,
+        ctxt: call.ctxt,
+        callee: Callee::Expr(Box::new(callee)),
+        args: call.args.clone(),
+        type_args: call.type_args.clone(),
+    })
+}
+
 /// Translation hook function names from next-intl.
 pub const NEXT_INTL_HOOKS: &[&str] = &["useTranslations", "getTranslations"];
 
+/// Translator factories from next-intl core, which take an options object:
+/// `createTranslator({ locale, messages, namespace })`.
+pub const NEXT_INTL_TRANSLATORS: &[&str] = &["createTranslator", "getTranslator"];
+
 /// Translation hook function names from react-i18next.
 pub const REACT_I18NEXT_HOOKS: &[&str] = &["useTranslation"];
 
+/// Composables from vue-i18n, only recognized in `.vue` files:
+/// `const { t } = useI18n()`.
+pub const VUE_I18N_HOOKS: &[&str] = &["useI18n"];
+
 /// Check if a function name is a translation hook (any supported framework).
 pub fn is_translation_hook(name: &str) -> bool {
-    NEXT_INTL_HOOKS.contains(&name) || REACT_I18NEXT_HOOKS.contains(&name)
+    NEXT_INTL_HOOKS.contains(&name)
+        || NEXT_INTL_TRANSLATORS.contains(&name)
+        || REACT_I18NEXT_HOOKS.contains(&name)
 }
 
-/// Check if the hook returns an object that needs destructuring (react-i18next).
+/// Non-translation hooks from next-intl. Their results (`format`, `now`,
+/// `timeZone`) are never translation functions, even when bound to `t`.
+pub const NEXT_INTL_NON_TRANSLATION_HOOKS: &[&str] = &[
+    "useFormatter",
+    "getFormatter",
+    "useNow",
+    "getNow",
+    "useTimeZone",
+    "getTimeZone",
+];
+
+/// Check if a function name is a hook whose result must not be treated as a
+/// translation function.
+pub fn is_non_translation_hook(name: &str) -> bool {
+    NEXT_INTL_NON_TRANSLATION_HOOKS.contains(&name)
+}
+
+/// Check if the hook returns an object that needs destructuring (react-i18next, vue-i18n).
 /// e.g., `const { t } = useTranslation("ns")`
 pub fn is_destructuring_hook(name: &str) -> bool {
-    REACT_I18NEXT_HOOKS.contains(&name)
+    REACT_I18NEXT_HOOKS.contains(&name) || VUE_I18N_HOOKS.contains(&name)
 }
 
 /// Extract the `t` binding name from a destructuring pattern for react-i18next hooks.
@@ -36,7 +127,7 @@ pub fn is_destructuring_hook(name: &str) -> bool {
 /// Handles:
 /// - `const { t } = useTranslation("ns")` → Some("t")
 /// - `const { t, i18n } = useTranslation("ns")` → Some("t") (only `t`)
-/// - `const { t: translate } = useTranslation("ns")` → None (renamed away from `t`)
+/// - `const { t: translate } = useTranslation("ns")` → Some("translate")
 ///
 /// Only the `t` binding is tracked because `useTranslation()` returns an object
 /// with multiple fields (`t`, `i18n`, `ready`), and only `t` is the translation
@@ -49,45 +140,252 @@ pub fn extract_t_from_destructuring(pat: &Pat) -> Option<String> {
     extract_t_from_object_pat(obj_pat)
 }
 
-/// Inner helper: extract `t` from ObjectPat props.
+/// Inner helper: extract the binding of the `t` property from ObjectPat props.
 fn extract_t_from_object_pat(obj_pat: &ObjectPat) -> Option<String> {
-    for prop in &obj_pat.props {
-        let name = match prop {
-            ObjectPatProp::Assign(assign) => Some(assign.key.sym.to_string()),
-            ObjectPatProp::KeyValue(kv) => {
-                if let Pat::Ident(ident) = &*kv.value {
-                    Some(ident.id.sym.to_string())
-                } else {
-                    None
-                }
-            }
+    obj_pat.props.iter().find_map(|prop| match prop {
+        ObjectPatProp::Assign(assign) if assign.key.sym == "t" => Some("t".to_string()),
+        ObjectPatProp::KeyValue(kv) if prop_name_is_t(&kv.key) => match &*kv.value {
+            Pat::Ident(ident) => Some(ident.id.sym.to_string()),
+            Pat::Assign(assign) => match &*assign.left {
+                Pat::Ident(ident) => Some(ident.id.sym.to_string()),
+                _ => None,
+            },
             _ => None,
-        };
-        if let Some(ref n) = name
-            && n == "t"
-        {
-            return Some(n.clone());
-        }
+        },
+        _ => None,
+    })
+}
+
+fn prop_name_is_t(key: &PropName) -> bool {
+    match key {
+        PropName::Ident(ident) => ident.sym == "t",
+        PropName::Str(s) => s.value.as_str() == Some("t"),
+        _ => false,
     }
-    None
+}
+
+/// Pair the elements of an array pattern with the elements of the array
+/// literal it destructures.
+///
+/// `const [t, format] = [useTranslations("ns"), useFormatter()]` gives
+/// `[(t, useTranslations("ns")), (format, useFormatter())]`. Holes are
+/// skipped; pairing stops at a rest element or a spread, after which
+/// positions no longer match. Returns `None` unless both sides are arrays.
+pub fn zip_array_destructuring<'a>(
+    pat: &'a Pat,
+    init: &'a Expr,
+) -> Option<Vec<(&'a Pat, &'a Expr)>> {
+    let (Pat::Array(array_pat), Expr::Array(array_lit)) = (pat, unwrap_paren(init)) else {
+        return None;
+    };
+    Some(
+        array_pat
+            .elems
+            .iter()
+            .zip(&array_lit.elems)
+            .take_while(|(elem_pat, elem)| {
+                !matches!(elem_pat, Some(Pat::Rest(_)))
+                    && elem.as_ref().is_none_or(|elem| elem.spread.is_none())
+            })
+            .filter_map(|(elem_pat, elem)| Some((elem_pat.as_ref()?, &*elem.as_ref()?.expr)))
+            .collect(),
+    )
 }
 
 /// Extract namespace from translation hook call.
 ///
 /// Example: `useTranslations("MyNamespace")` -> `Some("MyNamespace")`
-pub fn extract_namespace_from_call(call: &CallExpr) -> Option<String> {
-    call.args.first().and_then(|arg| {
-        if let Expr::Lit(Lit::Str(s)) = &*arg.expr {
-            s.value.as_str().map(|s| s.to_string())
+///
+/// For react-i18next's array form, the first namespace is the default one:
+/// `useTranslation(["common", "errors"])` -> `Some("common")`
+///
+/// Translator factories read the `namespace` property of their options object:
+/// `createTranslator({ locale, messages, namespace: "Common" })` -> `Some("Common")`
+///
+/// For next-intl, a namespace given by name is looked up with `resolve_const`,
+/// which returns the value of a string constant: `useTranslations(NS)` with
+/// `const NS = "Common"` -> `Some("Common")`. Other expressions give `None`.
+/// `useTranslation` is left out, since wrappers of that name often take a
+/// locale instead.
+pub fn extract_namespace_from_call(
+    call: &CallExpr,
+    resolve_const: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let callee_name = match &call.callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Ident(ident) => ident.sym.as_str(),
+            _ => "",
+        },
+        _ => "",
+    };
+    if NEXT_INTL_TRANSLATORS.contains(&callee_name) {
+        return extract_namespace_from_options(call, &resolve_const);
+    }
+    let resolve_const = |name: &str| {
+        if NEXT_INTL_HOOKS.contains(&callee_name) {
+            resolve_const(name)
+        } else {
+            None
+        }
+    };
+
+    call.args.first().and_then(|arg| match &*arg.expr {
+        Expr::Array(array) => array.elems.first().and_then(|elem| match elem {
+            Some(ExprOrSpread { spread: None, expr }) => namespace_value(expr, &resolve_const),
+            _ => None,
+        }),
+        expr => namespace_value(expr, &resolve_const),
+    })
+}
+
+/// Extract the string `namespace` property of the options object argument.
+fn extract_namespace_from_options(
+    call: &CallExpr,
+    resolve_const: &impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let arg = call.args.first()?;
+    let Expr::Object(options) = unwrap_paren(&arg.expr) else {
+        return None;
+    };
+    options.props.iter().find_map(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        let Prop::KeyValue(kv) = &**prop else {
+            return None;
+        };
+        let is_namespace = match &kv.key {
+            PropName::Ident(ident) => ident.sym == "namespace",
+            PropName::Str(s) => s.value.as_str() == Some("namespace"),
+            _ => false,
+        };
+        if is_namespace {
+            namespace_value(&kv.value, resolve_const)
         } else {
             None
         }
     })
 }
 
+/// Module-level `const NAME = "value"` declarations, exported or not.
+pub fn module_string_consts(module: &Module) -> HashMap<String, String> {
+    let mut consts = HashMap::new();
+    for item in &module.body {
+        let decl = match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => var,
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => match &export.decl {
+                Decl::Var(var) => var,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        if decl.kind != VarDeclKind::Const {
+            continue;
+        }
+        for declarator in &decl.decls {
+            if let Pat::Ident(ident) = &declarator.name
+                && let Some(init) = &declarator.init
+                && let Expr::Lit(Lit::Str(s)) = unwrap_paren(init)
+                && let Some(value) = s.value.as_str()
+            {
+                consts.insert(ident.id.sym.to_string(), value.to_string());
+            }
+        }
+    }
+    consts
+}
+
+/// A namespace written as a string literal or as the name of a string constant.
+fn namespace_value(expr: &Expr, resolve_const: &impl Fn(&str) -> Option<String>) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => s.value.as_str().map(|s| s.to_string()),
+        Expr::Ident(ident) => resolve_const(ident.sym.as_str()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use swc_common::{FileName, SourceMap};
+    use swc_ecma_ast::{Decl, MemberProp, ModuleItem, Stmt};
+    use swc_ecma_parser::{Parser, StringInput, Syntax, TsSyntax};
+
+    fn parse_expr(code: &str) -> Expr {
+        let source_map = SourceMap::default();
+        let source_file = source_map.new_source_file(FileName::Anon.into(), code.to_string());
+        let syntax = Syntax::Typescript(TsSyntax::default());
+        let mut parser = Parser::new(syntax, StringInput::from(&*source_file), None);
+        let module = parser.parse_module().unwrap();
+        match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Expr(stmt))) => *stmt.expr,
+            other => panic!("Expected an expression statement, got {:?}", other),
+        }
+    }
+
+    /// Binding pattern and initializer of `const <pat> = <init>`.
+    fn parse_var_decl(code: &str) -> (Pat, Expr) {
+        let source_map = SourceMap::default();
+        let source_file = source_map.new_source_file(FileName::Anon.into(), code.to_string());
+        let syntax = Syntax::Typescript(TsSyntax::default());
+        let mut parser = Parser::new(syntax, StringInput::from(&*source_file), None);
+        let module = parser.parse_module().unwrap();
+        match module.body.into_iter().next() {
+            Some(ModuleItem::Stmt(Stmt::Decl(Decl::Var(var)))) => {
+                let decl = var.decls.into_iter().next().unwrap();
+                (decl.name, *decl.init.unwrap())
+            }
+            other => panic!("Expected a variable declaration, got {:?}", other),
+        }
+    }
+
+    /// Plain call of `code`, through `normalize_call` or `opt_chain_call`.
+    fn plain_call(code: &str) -> Option<CallExpr> {
+        match parse_expr(code) {
+            Expr::Call(call) => normalize_call(&call),
+            Expr::OptChain(chain) => opt_chain_call(&chain),
+            other => panic!("Expected a call, got {:?}", other),
+        }
+    }
+
+    /// `t` or `t.method` for a plain callee.
+    fn callee_name(call: &CallExpr) -> String {
+        let Callee::Expr(callee) = &call.callee else {
+            panic!("Expected an expression callee");
+        };
+        match &**callee {
+            Expr::Ident(ident) => ident.sym.to_string(),
+            Expr::Member(member) => match (&*member.obj, &member.prop) {
+                (Expr::Ident(obj), MemberProp::Ident(prop)) => format!("{}.{}", obj.sym, prop.sym),
+                other => panic!("Unexpected member callee {:?}", other),
+            },
+            other => panic!("Unexpected callee {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_optional_and_non_null_calls() {
+        for (code, expected) in [
+            (r#"t?.("x")"#, "t"),
+            (r#"t!("x")"#, "t"),
+            (r#"t?.raw("x")"#, "t.raw"),
+            (r#"t!.raw("x")"#, "t.raw"),
+            (r#"(t!)("x")"#, "t"),
+        ] {
+            let call = plain_call(code).unwrap_or_else(|| panic!("{} is not normalized", code));
+            assert_eq!(callee_name(&call), expected, "{}", code);
+            assert_eq!(call.args.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_plain_calls_are_left_alone() {
+        assert!(plain_call(r#"t("x")"#).is_none());
+        assert!(plain_call(r#"t.raw("x")"#).is_none());
+        assert!(
+            matches!(parse_expr("obj?.prop"), Expr::OptChain(chain) if opt_chain_call(&chain).is_none())
+        );
+    }
 
     #[test]
     fn test_is_translation_hook_next_intl() {
@@ -95,6 +393,13 @@ mod tests {
         assert!(is_translation_hook("getTranslations"));
     }
 
+    #[test]
+    fn test_is_translation_hook_next_intl_translators() {
+        assert!(is_translation_hook("createTranslator"));
+        assert!(is_translation_hook("getTranslator"));
+        assert!(!is_destructuring_hook("createTranslator"));
+    }
+
     #[test]
     fn test_is_translation_hook_react_i18next() {
         assert!(is_translation_hook("useTranslation"));
@@ -116,4 +421,121 @@ mod tests {
         assert!(!is_destructuring_hook("useTranslations"));
         assert!(!is_destructuring_hook("getTranslations"));
     }
+
+    #[test]
+    fn test_extract_t_from_destructuring() {
+        for (code, expected) in [
+            ("const { t } = useTranslation()", Some("t")),
+            ("const { t, i18n } = useTranslation()", Some("t")),
+            (
+                "const { i18n, t: translate } = useTranslation()",
+                Some("translate"),
+            ),
+            ("const { t: tr = fallback } = useTranslation()", Some("tr")),
+            ("const { i18n } = useTranslation()", None),
+            ("const { x: t } = useTranslation()", None),
+            ("const t = useTranslations()", None),
+        ] {
+            let (pat, _) = parse_var_decl(code);
+            assert_eq!(
+                extract_t_from_destructuring(&pat).as_deref(),
+                expected,
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_namespace_from_call() {
+        let resolve_const = |name: &str| (name == "NS").then(|| "Common".to_string());
+        for (code, expected) in [
+            ("useTranslations(\"Auth\")", Some("Auth")),
+            ("useTranslations(NS)", Some("Common")),
+            ("getTranslations(NS)", Some("Common")),
+            ("useTranslation(NS)", None),
+            (
+                "createTranslator({ locale, namespace: NS })",
+                Some("Common"),
+            ),
+            ("useTranslations(other)", None),
+            ("useTranslations(`${NS}.Form`)", None),
+            ("useTranslations(getNamespace())", None),
+        ] {
+            let Expr::Call(call) = parse_expr(code) else {
+                panic!("Expected a call");
+            };
+            assert_eq!(
+                extract_namespace_from_call(&call, resolve_const).as_deref(),
+                expected,
+                "{}",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_module_string_consts() {
+        let source_map = SourceMap::default();
+        let source_file = source_map.new_source_file(
+            FileName::Anon.into(),
+            r#"
+const NS = "Common";
+export const AUTH_NS = "Auth" as const;
+let mutable = "Mutable";
+const count = 1;
+function inner() {
+    const LOCAL = "Local";
+}
+"#
+            .to_string(),
+        );
+        let syntax = Syntax::Typescript(TsSyntax::default());
+        let mut parser = Parser::new(syntax, StringInput::from(&*source_file), None);
+        let module = parser.parse_module().unwrap();
+
+        let consts = module_string_consts(&module);
+        assert_eq!(
+            consts,
+            HashMap::from([
+                ("NS".to_string(), "Common".to_string()),
+                ("AUTH_NS".to_string(), "Auth".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zip_array_destructuring() {
+        let names = |code: &str| {
+            let (pat, init) = parse_var_decl(code);
+            zip_array_destructuring(&pat, &init).map(|pairs| {
+                pairs
+                    .into_iter()
+                    .map(|(pat, expr)| match (pat, expr) {
+                        (Pat::Ident(ident), Expr::Call(call)) => {
+                            format!("{}={}", ident.id.sym, callee_name(call))
+                        }
+                        other => panic!("Unexpected pair {:?}", other),
+                    })
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        assert_eq!(
+            names(r#"const [t, format] = [useTranslations("ns"), useFormatter()]"#),
+            Some(vec![
+                "t=useTranslations".to_string(),
+                "format=useFormatter".to_string()
+            ])
+        );
+        assert_eq!(
+            names(r#"const [, t] = [useFormatter(), useTranslations("ns")] as const"#),
+            Some(vec!["t=useTranslations".to_string()])
+        );
+        assert_eq!(
+            names(r#"const [t] = [...hooks, useTranslations("ns")]"#),
+            Some(vec![])
+        );
+        assert_eq!(names(r#"const [t] = useTranslations("ns")"#), None);
+    }
 }
@@ -0,0 +1,487 @@
+//! Minimal ICU MessageFormat argument tokenizer.
+//!
+//! Extracts argument names and their type keyword from message values such as
+//! `Hello {name}` or `{count, plural, one {# item} other {# items}}`. Messages
+//! are not evaluated; only the set of arguments is collected, including those
+//! nested inside `plural`/`select`/`selectordinal` branches.
+//!
+//! The branch selectors of `plural`/`selectordinal` arguments are collected
+//! too (see `parse_icu_plurals`), for checking them against CLDR categories.
+//!
+//! Rich text tags (`<b>...</b>`, rendered by `t.rich`/`t.markup`) are not ICU
+//! syntax and pass through as text; `strip_rich_tags` removes them and
+//! `parse_rich_tags` lists their names.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// ICU argument types whose remaining content is a list of `selector {message}` branches.
+const BRANCHING_TYPES: &[&str] = &["plural", "select", "selectordinal"];
+
+/// Extract ICU arguments from a message value.
+///
+/// Returns a map of argument name to its type keyword (`None` for simple
+/// `{name}` arguments). When an argument appears more than once, the first
+/// occurrence determines its type.
+///
+/// Apostrophe quoting is respected (`'{literal}'` and `''`), and malformed
+/// input is handled leniently by returning whatever was parsed up to the error.
+///
+/// # Examples
+///
+/// ```
+/// use glot::core::utils::parse_icu_arguments;
+///
+/// let args = parse_icu_arguments("{count, plural, one {# item for {name}} other {# items}}");
+/// assert_eq!(args.get("count"), Some(&Some("plural".to_string())));
+/// assert_eq!(args.get("name"), Some(&None));
+/// ```
+pub fn parse_icu_arguments(message: &str) -> BTreeMap<String, Option<String>> {
+    IcuParser::run(message).args
+}
+
+/// Kind of a plural argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralKind {
+    /// `plural`: cardinal numbers (`1 item`, `2 items`)
+    Cardinal,
+    /// `selectordinal`: ordinal numbers (`1st`, `2nd`)
+    Ordinal,
+}
+
+impl std::fmt::Display for PluralKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PluralKind::Cardinal => write!(f, "plural"),
+            PluralKind::Ordinal => write!(f, "selectordinal"),
+        }
+    }
+}
+
+/// A `plural` or `selectordinal` argument and its branch selectors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluralArgument {
+    pub name: String,
+    pub kind: PluralKind,
+    /// Selectors in source order, e.g. `["=0", "one", "other"]`.
+    pub selectors: Vec<String>,
+}
+
+/// Extract `plural` and `selectordinal` arguments from a message value.
+///
+/// Nested plurals are included. When an argument name appears more than
+/// once, only the first occurrence is returned, as in `parse_icu_arguments`.
+///
+/// # Examples
+///
+/// ```
+/// use glot::core::utils::{PluralKind, parse_icu_plurals};
+///
+/// let plurals = parse_icu_plurals("{count, plural, offset:1 =0 {none} one {# item} other {# items}}");
+/// assert_eq!(plurals[0].kind, PluralKind::Cardinal);
+/// assert_eq!(plurals[0].selectors, vec!["=0", "one", "other"]);
+/// ```
+pub fn parse_icu_plurals(message: &str) -> Vec<PluralArgument> {
+    let mut plurals = IcuParser::run(message).plurals;
+    let mut seen = std::collections::HashSet::new();
+    plurals.retain(|plural| seen.insert(plural.name.clone()));
+    plurals
+}
+
+struct IcuParser {
+    chars: Vec<char>,
+    pos: usize,
+    args: BTreeMap<String, Option<String>>,
+    plurals: Vec<PluralArgument>,
+}
+
+impl IcuParser {
+    fn run(message: &str) -> Self {
+        let mut parser = IcuParser {
+            chars: message.chars().collect(),
+            pos: 0,
+            args: BTreeMap::new(),
+            plurals: Vec::new(),
+        };
+        parser.parse_message(false);
+        parser
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// Parse message text until end of input, or until an unmatched `}` when nested.
+    fn parse_message(&mut self, nested: bool) {
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => self.parse_argument(),
+                '}' if nested => return,
+                '\'' => self.skip_quoted(nested),
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    /// Skip an apostrophe-quoted section.
+    ///
+    /// `''` is a literal apostrophe. An apostrophe only starts quoting when
+    /// followed by a syntax character; otherwise it is plain text.
+    fn skip_quoted(&mut self, nested: bool) {
+        self.pos += 1;
+        match self.peek() {
+            Some('\'') => self.pos += 1,
+            Some('{' | '}' | '|') => self.skip_until_quote(),
+            Some('#') if nested => self.skip_until_quote(),
+            _ => {}
+        }
+    }
+
+    fn skip_until_quote(&mut self) {
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    self.pos += 1;
+                } else {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Read text until one of `stops` (not consumed), returning it trimmed.
+    fn read_until(&mut self, stops: &[char]) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if stops.contains(&c) {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+
+    /// Parse `{name}`, `{name, type}` or `{name, type, ...}` starting at `{`.
+    fn parse_argument(&mut self) {
+        self.pos += 1;
+        let name = self.read_until(&[',', '}', '{']);
+
+        match self.peek() {
+            Some('}') => {
+                self.pos += 1;
+                self.record(name, None);
+            }
+            Some(',') => {
+                self.pos += 1;
+                let kind = self.read_until(&[',', '}', '{']);
+                let is_branching = BRANCHING_TYPES.contains(&kind.as_str());
+                let plural_kind = match kind.as_str() {
+                    "plural" => Some(PluralKind::Cardinal),
+                    "selectordinal" => Some(PluralKind::Ordinal),
+                    _ => None,
+                };
+                let plural = plural_kind
+                    .filter(|_| is_valid_argument_name(&name))
+                    .map(|kind| {
+                        self.plurals.push(PluralArgument {
+                            name: name.clone(),
+                            kind,
+                            selectors: Vec::new(),
+                        });
+                        self.plurals.len() - 1
+                    });
+                self.record(name, Some(kind));
+
+                match self.peek() {
+                    Some('}') => self.pos += 1,
+                    Some(',') if is_branching => {
+                        self.pos += 1;
+                        self.parse_branches(plural);
+                    }
+                    Some(_) => self.skip_style(),
+                    None => {}
+                }
+            }
+            // `{` inside an argument name is malformed; resume scanning from here
+            _ => {}
+        }
+    }
+
+    /// Parse `selector {message} selector {message} ... }` of a plural/select argument.
+    ///
+    /// Selectors are recorded on `self.plurals[plural]` when given.
+    fn parse_branches(&mut self, plural: Option<usize>) {
+        loop {
+            let selector = self.read_until(&['{', '}']);
+            match self.peek() {
+                Some('{') => {
+                    // The first selector may be preceded by `offset:N`
+                    if let Some(index) = plural
+                        && let Some(selector) = selector
+                            .split_whitespace()
+                            .rfind(|part| !part.starts_with("offset:"))
+                    {
+                        self.plurals[index].selectors.push(selector.to_string());
+                    }
+                    self.pos += 1;
+                    self.parse_message(true);
+                    if self.peek() == Some('}') {
+                        self.pos += 1;
+                    }
+                }
+                Some('}') => {
+                    self.pos += 1;
+                    return;
+                }
+                _ => return,
+            }
+        }
+    }
+
+    /// Skip an argument style such as `{price, number, ::currency/EUR}`.
+    fn skip_style(&mut self) {
+        let mut depth = 1;
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn record(&mut self, name: String, kind: Option<String>) {
+        if is_valid_argument_name(&name) {
+            self.args.entry(name).or_insert(kind);
+        }
+    }
+}
+
+/// Remove the rich text tags of a message, keeping the text and arguments.
+///
+/// Tags are `<name>`, `</name>` and `<name/>` where `name` is made of
+/// letters, digits, `_` and `-`, as accepted by `t.rich`/`t.markup`. Any other
+/// `<` is plain text. Returns `None` when the message has no tags.
+///
+/// # Examples
+///
+/// ```
+/// use glot::core::utils::strip_rich_tags;
+///
+/// assert_eq!(strip_rich_tags("Read <link>{title}</link>"), Some("Read {title}".to_string()));
+/// assert_eq!(strip_rich_tags("1 < 2"), None);
+/// ```
+pub fn strip_rich_tags(message: &str) -> Option<String> {
+    let mut text = String::with_capacity(message.len());
+    let mut found = false;
+    let mut rest = message;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        match rich_tag_len(&rest[start..]) {
+            Some(len) => {
+                found = true;
+                rest = &rest[start + len..];
+            }
+            None => {
+                text.push('<');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    text.push_str(rest);
+
+    found.then_some(text)
+}
+
+/// Names of the rich text tags of a message.
+///
+/// Tags are recognized as in `strip_rich_tags`; `<b>`, `</b>` and `<b/>` all
+/// contribute `b`.
+///
+/// # Examples
+///
+/// ```
+/// use glot::core::utils::parse_rich_tags;
+///
+/// let tags = parse_rich_tags("Read <link>the {title}</link> or <br/>call us");
+/// assert_eq!(tags.into_iter().collect::<Vec<_>>(), vec!["br", "link"]);
+/// ```
+pub fn parse_rich_tags(message: &str) -> BTreeSet<String> {
+    let mut tags = BTreeSet::new();
+    let mut rest = message;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        match rich_tag_len(rest) {
+            Some(len) => {
+                let name = rest[1..len].trim_start_matches('/').trim_end_matches('>');
+                tags.insert(name.trim_end_matches('/').to_string());
+                rest = &rest[len..];
+            }
+            None => rest = &rest[1..],
+        }
+    }
+
+    tags
+}
+
+/// Length of the rich text tag at the start of `input`, if there is one.
+fn rich_tag_len(input: &str) -> Option<usize> {
+    let body = input.strip_prefix('<')?;
+    let name_start = usize::from(body.starts_with('/'));
+    let name_len = body[name_start..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(body.len() - name_start);
+    if name_len == 0 {
+        return None;
+    }
+
+    let after_name = &body[name_start + name_len..];
+    let end = if after_name.starts_with('>') {
+        1
+    } else if name_start == 0 && after_name.starts_with("/>") {
+        2
+    } else {
+        return None;
+    };
+    Some(1 + name_start + name_len + end)
+}
+
+/// Argument names are identifiers or numbers; anything with whitespace is prose.
+fn is_valid_argument_name(name: &str) -> bool {
+    !name.is_empty() && !name.chars().any(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(message: &str) -> Vec<String> {
+        parse_icu_arguments(message).into_keys().collect()
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        assert!(parse_icu_arguments("Hello world").is_empty());
+        assert!(parse_icu_arguments("").is_empty());
+    }
+
+    #[test]
+    fn test_simple_arguments() {
+        let args = parse_icu_arguments("Hello {name}, you have {count} messages");
+        assert_eq!(args.len(), 2);
+        assert_eq!(args.get("name"), Some(&None));
+        assert_eq!(args.get("count"), Some(&None));
+    }
+
+    #[test]
+    fn test_typed_argument_with_style() {
+        let args = parse_icu_arguments("Total: {price, number, ::currency/EUR}");
+        assert_eq!(args.get("price"), Some(&Some("number".to_string())));
+        assert_eq!(args.len(), 1);
+    }
+
+    #[test]
+    fn test_plural_with_nested_argument() {
+        let args = parse_icu_arguments(
+            "{count, plural, =0 {No items} one {# item} other {# items by {author}}}",
+        );
+        assert_eq!(args.get("count"), Some(&Some("plural".to_string())));
+        assert_eq!(args.get("author"), Some(&None));
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn test_select() {
+        let args =
+            parse_icu_arguments("{gender, select, male {He} female {She} other {They}} liked it");
+        assert_eq!(args.get("gender"), Some(&Some("select".to_string())));
+        assert_eq!(args.len(), 1);
+    }
+
+    #[test]
+    fn test_plural_with_offset() {
+        assert_eq!(
+            names("{guests, plural, offset:1 =0 {Nobody} other {{host} and # others}}"),
+            vec!["guests", "host"]
+        );
+    }
+
+    #[test]
+    fn test_quoted_braces_are_literal() {
+        assert!(parse_icu_arguments("Use '{name}' as a placeholder").is_empty());
+        assert_eq!(names("It''s {name}"), vec!["name"]);
+    }
+
+    #[test]
+    fn test_plain_apostrophe_is_text() {
+        assert_eq!(names("Don't forget {item}"), vec!["item"]);
+    }
+
+    #[test]
+    fn test_rich_text_tags_ignored() {
+        assert_eq!(names("Read the <link>{title}</link>"), vec!["title"]);
+    }
+
+    #[test]
+    fn test_strip_rich_tags() {
+        assert_eq!(
+            strip_rich_tags("<b>Bold</b> and <br/><my-tag>x</my-tag>").as_deref(),
+            Some("Bold and x")
+        );
+        assert_eq!(strip_rich_tags("<b></b>").as_deref(), Some(""));
+        assert_eq!(strip_rich_tags("a <b c</b> d").as_deref(), Some("a <b c d"));
+        assert_eq!(strip_rich_tags("Plain text"), None);
+        assert_eq!(strip_rich_tags("x < y and </ >"), None);
+    }
+
+    #[test]
+    fn test_parse_rich_tags() {
+        let tags = |message: &str| parse_rich_tags(message).into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            tags("<b>Bold</b> and <br/><my-tag>x</my-tag>"),
+            vec!["b", "br", "my-tag"]
+        );
+        assert_eq!(tags("Closing only</b>"), vec!["b"]);
+        assert_eq!(tags("1 < 2 and <b c</b>"), vec!["b"]);
+        assert!(tags("{count, plural, one {# item} other {# items}}").is_empty());
+    }
+
+    #[test]
+    fn test_plural_selectors() {
+        let plurals = parse_icu_plurals(
+            "{count, plural, offset:1 =0 {None} one {# item} other {# of {place, selectordinal, one {#st} two {#nd} other {#th}}}}",
+        );
+        assert_eq!(plurals.len(), 2);
+        assert_eq!(plurals[0].name, "count");
+        assert_eq!(plurals[0].kind, PluralKind::Cardinal);
+        assert_eq!(plurals[0].selectors, vec!["=0", "one", "other"]);
+        assert_eq!(plurals[1].name, "place");
+        assert_eq!(plurals[1].kind, PluralKind::Ordinal);
+        assert_eq!(plurals[1].selectors, vec!["one", "two", "other"]);
+    }
+
+    #[test]
+    fn test_select_is_not_plural() {
+        assert!(parse_icu_plurals("{gender, select, male {He} other {They}}").is_empty());
+        assert!(parse_icu_plurals("{count} items").is_empty());
+    }
+
+    #[test]
+    fn test_malformed_input_is_lenient() {
+        assert_eq!(names("Hello {name"), Vec::<String>::new());
+        assert_eq!(names("{a} and {b"), vec!["a"]);
+        assert!(parse_icu_arguments("{ }").is_empty());
+    }
+}
@@ -4,11 +4,17 @@
 //!
 //! ## Module Structure
 //!
+//! - `cldr_plurals`: CLDR plural categories per language
 //! - `glob_matcher`: Glob pattern matching utilities
 //! - `helpers`: Helper functions for namespace extraction and translation hook detection
+//! - `icu`: ICU MessageFormat argument extraction
 
+pub mod cldr_plurals;
 pub mod glob_matcher;
 pub mod helpers;
+pub mod icu;
 
+pub use cldr_plurals::*;
 pub use glob_matcher::*;
 pub use helpers::*;
+pub use icu::*;
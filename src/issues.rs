@@ -10,8 +10,15 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
+use crate::config::KeyCase;
 use crate::core::ResolvedKeyUsage;
-use crate::core::{LocaleTypeMismatch, MessageContext, SourceContext, ValueType};
+use crate::core::collect::UnpairedDirectiveKind;
+use crate::core::utils::PluralKind;
+use crate::core::{
+    LocalePlaceholderMismatch, LocaleRichTagMismatch, LocaleTypeMismatch, MessageContext,
+    SourceContext, ValueType,
+};
+use crate::utils::{is_stray_whitespace, relative_path};
 
 // ============================================================
 // Severity and Rule
@@ -42,9 +49,24 @@ pub enum Rule {
     UnresolvedKey,
     ReplicaLag,
     UnusedKey,
+    UnusedNamespace,
     OrphanKey,
     Untranslated,
     TypeMismatch,
+    PlaceholderMismatch,
+    KeyNaming,
+    EmptyValue,
+    PluralCategories,
+    MarkupOnly,
+    RichTagMismatch,
+    UnmatchedMessageKeys,
+    NamespaceConsistency,
+    UnpairedDirective,
+    InterpolationMismatch,
+    DuplicateValue,
+    StrayWhitespace,
+    EmptyKey,
+    DuplicateKey,
     ParseError,
 }
 
@@ -57,15 +79,58 @@ impl std::fmt::Display for Rule {
             Rule::UnresolvedKey => write!(f, "unresolved-key"),
             Rule::ReplicaLag => write!(f, "replica-lag"),
             Rule::UnusedKey => write!(f, "unused-key"),
+            Rule::UnusedNamespace => write!(f, "unused-namespace"),
             Rule::OrphanKey => write!(f, "orphan-key"),
             Rule::Untranslated => write!(f, "untranslated"),
             Rule::TypeMismatch => write!(f, "type-mismatch"),
+            Rule::PlaceholderMismatch => write!(f, "placeholder-mismatch"),
+            Rule::KeyNaming => write!(f, "key-naming"),
+            Rule::EmptyValue => write!(f, "empty-value"),
+            Rule::PluralCategories => write!(f, "plural-categories"),
+            Rule::MarkupOnly => write!(f, "markup-only"),
+            Rule::RichTagMismatch => write!(f, "rich-tag-mismatch"),
+            Rule::UnmatchedMessageKeys => write!(f, "unmatched-message-keys"),
+            Rule::NamespaceConsistency => write!(f, "namespace-consistency"),
+            Rule::UnpairedDirective => write!(f, "unpaired-directive"),
+            Rule::InterpolationMismatch => write!(f, "interpolation-mismatch"),
+            Rule::DuplicateValue => write!(f, "duplicate-value"),
+            Rule::StrayWhitespace => write!(f, "stray-whitespace"),
+            Rule::EmptyKey => write!(f, "empty-key"),
+            Rule::DuplicateKey => write!(f, "duplicate-key"),
             Rule::ParseError => write!(f, "parse-error"),
         }
     }
 }
 
 impl Rule {
+    /// Every rule, in report order.
+    pub const ALL: &[Rule] = &[
+        Rule::HardcodedText,
+        Rule::MissingKey,
+        Rule::UnresolvedKey,
+        Rule::ReplicaLag,
+        Rule::UnusedKey,
+        Rule::UnusedNamespace,
+        Rule::OrphanKey,
+        Rule::Untranslated,
+        Rule::TypeMismatch,
+        Rule::PlaceholderMismatch,
+        Rule::KeyNaming,
+        Rule::EmptyValue,
+        Rule::PluralCategories,
+        Rule::MarkupOnly,
+        Rule::RichTagMismatch,
+        Rule::UnmatchedMessageKeys,
+        Rule::NamespaceConsistency,
+        Rule::UnpairedDirective,
+        Rule::InterpolationMismatch,
+        Rule::DuplicateValue,
+        Rule::StrayWhitespace,
+        Rule::EmptyKey,
+        Rule::DuplicateKey,
+        Rule::ParseError,
+    ];
+
     /// Parse a rule name from user-facing configuration.
     ///
     /// Both check names (`missing`, `unused`) and issue names (`missing-key`,
@@ -77,9 +142,24 @@ impl Rule {
             "unresolved" | "unresolved-key" => Some(Self::UnresolvedKey),
             "replica-lag" => Some(Self::ReplicaLag),
             "unused" | "unused-key" => Some(Self::UnusedKey),
+            "unused-namespace" => Some(Self::UnusedNamespace),
             "orphan" | "orphan-key" => Some(Self::OrphanKey),
             "untranslated" => Some(Self::Untranslated),
             "type-mismatch" => Some(Self::TypeMismatch),
+            "placeholder-mismatch" => Some(Self::PlaceholderMismatch),
+            "key-naming" => Some(Self::KeyNaming),
+            "empty-value" => Some(Self::EmptyValue),
+            "plural-categories" => Some(Self::PluralCategories),
+            "markup-only" => Some(Self::MarkupOnly),
+            "rich-tag-mismatch" => Some(Self::RichTagMismatch),
+            "unmatched-message-keys" => Some(Self::UnmatchedMessageKeys),
+            "namespace-consistency" => Some(Self::NamespaceConsistency),
+            "unpaired-directive" => Some(Self::UnpairedDirective),
+            "interpolation-mismatch" => Some(Self::InterpolationMismatch),
+            "duplicate-value" => Some(Self::DuplicateValue),
+            "stray-whitespace" => Some(Self::StrayWhitespace),
+            "empty-key" => Some(Self::EmptyKey),
+            "duplicate-key" => Some(Self::DuplicateKey),
             "parse-error" => Some(Self::ParseError),
             _ => None,
         }
@@ -146,6 +226,14 @@ pub enum IssueUnresolvedKeyReason {
     /// Namespace cannot be determined for schema-derived keys.
     /// Contains the schema function name (e.g., "loginSchema").
     UnknownNamespace { schema_name: String },
+
+    /// None of the candidate keys from an object exist in the primary locale.
+    /// Contains the object name (e.g., "statusColors").
+    NotAKeyMap { object_name: String },
+
+    /// `t` destructured from a hook that returns it directly, so its calls
+    /// can't be tracked. Contains the hook name (e.g., "useTranslations").
+    DestructuredTranslator { hook: String },
 }
 
 impl std::fmt::Display for IssueUnresolvedKeyReason {
@@ -156,6 +244,12 @@ impl std::fmt::Display for IssueUnresolvedKeyReason {
             IssueUnresolvedKeyReason::UnknownNamespace { schema_name } => {
                 write!(f, "unknown namespace for schema '{}'", schema_name)
             }
+            IssueUnresolvedKeyReason::NotAKeyMap { object_name } => {
+                write!(f, "no value of '{}' is a translation key", object_name)
+            }
+            IssueUnresolvedKeyReason::DestructuredTranslator { hook } => {
+                write!(f, "t destructured from {}()", hook)
+            }
         }
     }
 }
@@ -165,7 +259,7 @@ impl std::fmt::Display for IssueUnresolvedKeyReason {
 // ============================================================
 
 /// Hardcoded text in JSX/TSX that should use translations.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HardcodedTextIssue {
     pub context: SourceContext,
     /// The hardcoded text content.
@@ -224,6 +318,123 @@ impl UnresolvedKeyIssue {
     }
 }
 
+/// `glot-message-keys` pattern that matches no key of the primary locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmatchedMessageKeysIssue {
+    /// Context of the call the declaration applies to.
+    pub context: SourceContext,
+    /// The pattern, joined to the call's namespace if it was relative.
+    pub pattern: String,
+}
+
+impl UnmatchedMessageKeysIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::UnmatchedMessageKeys
+    }
+}
+
+/// Key reached through more than one namespace path, like `t("submit")` under
+/// `useTranslations("Common")` and `t("Common.submit")` without a namespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespaceConsistencyIssue {
+    /// Context of the first usage of the key.
+    pub context: SourceContext,
+    /// The full key.
+    pub key: String,
+    /// Namespace of each path, in order of first use; `None` for the full key.
+    pub namespaces: Vec<Option<String>>,
+    /// First usage of each path other than the one at `context`.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl NamespaceConsistencyIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::NamespaceConsistency
+    }
+}
+
+/// Value of the primary locale shared by many keys, like `"Save"` under
+/// `Common.save`, `Form.save` and `Dialog.save`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateValueIssue {
+    /// Location of the first key holding the value.
+    pub context: MessageContext,
+    /// The shared value, with whitespace collapsed.
+    pub value: String,
+    /// The other keys holding the value.
+    pub other_keys: Vec<String>,
+}
+
+impl DuplicateValueIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::DuplicateValue
+    }
+}
+
+/// Values passed with a key that don't match the ICU arguments of its value in
+/// the primary locale, like `t("greeting")` for `"Hello {name}"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterpolationMismatchIssue {
+    /// Context of the call.
+    pub context: SourceContext,
+    /// The full key.
+    pub key: String,
+    /// Arguments of the primary locale value that the call doesn't pass.
+    pub missing: Vec<String>,
+    /// Values passed that the primary locale value doesn't use.
+    pub extra: Vec<String>,
+}
+
+impl InterpolationMismatchIssue {
+    pub fn severity() -> Severity {
+        Severity::Error
+    }
+
+    /// Missing values make next-intl throw; unused ones are only reported as warnings.
+    pub fn default_severity(&self) -> Severity {
+        if self.missing.is_empty() {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+
+    pub fn rule() -> Rule {
+        Rule::InterpolationMismatch
+    }
+}
+
+/// `glot-enable` without an open `glot-disable`, or `glot-disable` left open
+/// at the end of the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnpairedDirectiveIssue {
+    /// Context of the directive comment.
+    pub context: SourceContext,
+    pub kind: UnpairedDirectiveKind,
+}
+
+impl UnpairedDirectiveIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::UnpairedDirective
+    }
+}
+
 // ============================================================
 // Issue Types - Message Files (MessageContext)
 // ============================================================
@@ -232,6 +443,9 @@ impl UnresolvedKeyIssue {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnusedKeyIssue {
     pub context: MessageContext,
+    /// Dynamic keys whose pattern could match this key, like
+    /// `t(\`Common.${name}\`)`, listed for the user to confirm.
+    pub hint: Option<String>,
 }
 
 impl UnusedKeyIssue {
@@ -244,6 +458,25 @@ impl UnusedKeyIssue {
     }
 }
 
+/// Top-level namespace defined in locale files but never referenced in code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnusedNamespaceIssue {
+    /// Location of the namespace's first key; `key` holds the namespace name.
+    pub context: MessageContext,
+    /// Number of keys defined under this namespace.
+    pub key_count: usize,
+}
+
+impl UnusedNamespaceIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::UnusedNamespace
+    }
+}
+
 /// Key exists in non-primary locale but not in primary locale.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OrphanKeyIssue {
@@ -341,6 +574,220 @@ impl TypeMismatchIssue {
     }
 }
 
+/// ICU placeholders differ between primary and replica locales.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaceholderMismatchIssue {
+    pub context: MessageContext,
+    /// The primary locale code (e.g., "en").
+    pub primary_locale: String,
+    /// Locales whose placeholders differ from the primary locale.
+    pub mismatched_in: Vec<LocalePlaceholderMismatch>,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl PlaceholderMismatchIssue {
+    pub fn severity() -> Severity {
+        Severity::Error
+    }
+
+    pub fn rule() -> Rule {
+        Rule::PlaceholderMismatch
+    }
+}
+
+/// Key in the primary locale violates the configured naming convention.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyNamingIssue {
+    pub context: MessageContext,
+    /// The first segment that doesn't follow the convention.
+    pub segment: String,
+    /// The configured case.
+    pub expected_case: KeyCase,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl KeyNamingIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::KeyNaming
+    }
+}
+
+/// Translation value is an empty or whitespace-only string, or an empty array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyValueIssue {
+    /// Location of the empty value (in the locale file that has it).
+    pub context: MessageContext,
+    /// Locale whose value is empty.
+    pub locale: String,
+    /// Whether `locale` is the primary locale.
+    pub is_primary: bool,
+    /// Type of the empty value.
+    pub value_type: ValueType,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl EmptyValueIssue {
+    pub fn severity() -> Severity {
+        Severity::Error
+    }
+
+    /// Empty primary values break every locale; empty replica values are
+    /// usually just not translated yet.
+    pub fn default_severity(&self) -> Severity {
+        if self.is_primary {
+            Severity::Error
+        } else {
+            Severity::Warning
+        }
+    }
+
+    pub fn rule() -> Rule {
+        Rule::EmptyValue
+    }
+}
+
+/// Value with leading or trailing whitespace in the primary locale, or a
+/// replica value that differs from its source value only by such whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrayWhitespaceIssue {
+    /// Location of the value (in the locale file that has it).
+    pub context: MessageContext,
+    /// Locale of the value.
+    pub locale: String,
+    /// Locale and value of the source value, for replica values.
+    pub differs_from: Option<(String, String)>,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl StrayWhitespaceIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::StrayWhitespace
+    }
+}
+
+/// Translation call with an empty or whitespace-only literal key, like `t("")`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyKeyIssue {
+    /// Context of the call.
+    pub context: SourceContext,
+    /// The key as written.
+    pub key: String,
+}
+
+impl EmptyKeyIssue {
+    pub fn severity() -> Severity {
+        Severity::Error
+    }
+
+    pub fn rule() -> Rule {
+        Rule::EmptyKey
+    }
+}
+
+/// Key defined more than once in the same object of a locale file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKeyIssue {
+    /// Location of the repeated definition, with the value that is kept.
+    pub context: MessageContext,
+    /// Locale of the file.
+    pub locale: String,
+    /// Line of the first definition.
+    pub first_line: usize,
+}
+
+impl DuplicateKeyIssue {
+    pub fn severity() -> Severity {
+        Severity::Error
+    }
+
+    pub fn rule() -> Rule {
+        Rule::DuplicateKey
+    }
+}
+
+/// ICU `plural`/`selectordinal` argument doesn't match the CLDR categories of its locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluralCategoriesIssue {
+    /// Location of the value (in the locale file that has it).
+    pub context: MessageContext,
+    /// Locale of the value.
+    pub locale: String,
+    /// Name of the plural argument (e.g., "count").
+    pub argument: String,
+    pub kind: PluralKind,
+    /// Categories the locale needs but the message doesn't provide.
+    pub missing: Vec<String>,
+    /// Selectors that are not a category of the locale (never selected).
+    pub invalid: Vec<String>,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl PluralCategoriesIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::PluralCategories
+    }
+}
+
+/// Translation value has rich text tags but no text, like `<b></b>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkupOnlyIssue {
+    /// Location of the value (in the locale file that has it).
+    pub context: MessageContext,
+    /// Locale of the value.
+    pub locale: String,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl MarkupOnlyIssue {
+    pub fn severity() -> Severity {
+        Severity::Warning
+    }
+
+    pub fn rule() -> Rule {
+        Rule::MarkupOnly
+    }
+}
+
+/// Rich text tags of a `t.rich`/`t.markup` key differ between primary and replica locales.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichTagMismatchIssue {
+    pub context: MessageContext,
+    /// The primary locale code (e.g., "en").
+    pub primary_locale: String,
+    /// Locales whose tags differ from the primary locale.
+    pub mismatched_in: Vec<LocaleRichTagMismatch>,
+    /// Locations where this key is used in code.
+    pub usages: Vec<ResolvedKeyUsage>,
+}
+
+impl RichTagMismatchIssue {
+    pub fn severity() -> Severity {
+        Severity::Error
+    }
+
+    pub fn rule() -> Rule {
+        Rule::RichTagMismatch
+    }
+}
+
 // ============================================================
 // Special Issue Types
 // ============================================================
@@ -384,10 +831,25 @@ pub enum Issue {
     MissingKey(MissingKeyIssue),
     UnresolvedKey(UnresolvedKeyIssue),
     UnusedKey(UnusedKeyIssue),
+    UnusedNamespace(UnusedNamespaceIssue),
     OrphanKey(OrphanKeyIssue),
     ReplicaLag(ReplicaLagIssue),
     Untranslated(UntranslatedIssue),
     TypeMismatch(TypeMismatchIssue),
+    PlaceholderMismatch(PlaceholderMismatchIssue),
+    KeyNaming(KeyNamingIssue),
+    EmptyValue(EmptyValueIssue),
+    PluralCategories(PluralCategoriesIssue),
+    MarkupOnly(MarkupOnlyIssue),
+    RichTagMismatch(RichTagMismatchIssue),
+    UnmatchedMessageKeys(UnmatchedMessageKeysIssue),
+    NamespaceConsistency(NamespaceConsistencyIssue),
+    UnpairedDirective(UnpairedDirectiveIssue),
+    InterpolationMismatch(InterpolationMismatchIssue),
+    DuplicateValue(DuplicateValueIssue),
+    StrayWhitespace(StrayWhitespaceIssue),
+    EmptyKey(EmptyKeyIssue),
+    DuplicateKey(DuplicateKeyIssue),
     ParseError(ParseErrorIssue),
 }
 
@@ -398,10 +860,25 @@ impl Issue {
             Issue::MissingKey(_) => MissingKeyIssue::severity(),
             Issue::UnresolvedKey(_) => UnresolvedKeyIssue::severity(),
             Issue::UnusedKey(_) => UnusedKeyIssue::severity(),
+            Issue::UnusedNamespace(_) => UnusedNamespaceIssue::severity(),
             Issue::OrphanKey(_) => OrphanKeyIssue::severity(),
             Issue::ReplicaLag(_) => ReplicaLagIssue::severity(),
             Issue::Untranslated(issue) => issue.default_severity(),
             Issue::TypeMismatch(_) => TypeMismatchIssue::severity(),
+            Issue::PlaceholderMismatch(_) => PlaceholderMismatchIssue::severity(),
+            Issue::KeyNaming(_) => KeyNamingIssue::severity(),
+            Issue::EmptyValue(issue) => issue.default_severity(),
+            Issue::PluralCategories(_) => PluralCategoriesIssue::severity(),
+            Issue::MarkupOnly(_) => MarkupOnlyIssue::severity(),
+            Issue::RichTagMismatch(_) => RichTagMismatchIssue::severity(),
+            Issue::UnmatchedMessageKeys(_) => UnmatchedMessageKeysIssue::severity(),
+            Issue::NamespaceConsistency(_) => NamespaceConsistencyIssue::severity(),
+            Issue::UnpairedDirective(_) => UnpairedDirectiveIssue::severity(),
+            Issue::InterpolationMismatch(issue) => issue.default_severity(),
+            Issue::DuplicateValue(_) => DuplicateValueIssue::severity(),
+            Issue::StrayWhitespace(_) => StrayWhitespaceIssue::severity(),
+            Issue::EmptyKey(_) => EmptyKeyIssue::severity(),
+            Issue::DuplicateKey(_) => DuplicateKeyIssue::severity(),
             Issue::ParseError(_) => ParseErrorIssue::severity(),
         }
     }
@@ -412,75 +889,576 @@ impl Issue {
             Issue::MissingKey(_) => MissingKeyIssue::rule(),
             Issue::UnresolvedKey(_) => UnresolvedKeyIssue::rule(),
             Issue::UnusedKey(_) => UnusedKeyIssue::rule(),
+            Issue::UnusedNamespace(_) => UnusedNamespaceIssue::rule(),
             Issue::OrphanKey(_) => OrphanKeyIssue::rule(),
             Issue::ReplicaLag(_) => ReplicaLagIssue::rule(),
             Issue::Untranslated(_) => UntranslatedIssue::rule(),
             Issue::TypeMismatch(_) => TypeMismatchIssue::rule(),
+            Issue::PlaceholderMismatch(_) => PlaceholderMismatchIssue::rule(),
+            Issue::KeyNaming(_) => KeyNamingIssue::rule(),
+            Issue::EmptyValue(_) => EmptyValueIssue::rule(),
+            Issue::PluralCategories(_) => PluralCategoriesIssue::rule(),
+            Issue::MarkupOnly(_) => MarkupOnlyIssue::rule(),
+            Issue::RichTagMismatch(_) => RichTagMismatchIssue::rule(),
+            Issue::UnmatchedMessageKeys(_) => UnmatchedMessageKeysIssue::rule(),
+            Issue::NamespaceConsistency(_) => NamespaceConsistencyIssue::rule(),
+            Issue::UnpairedDirective(_) => UnpairedDirectiveIssue::rule(),
+            Issue::InterpolationMismatch(_) => InterpolationMismatchIssue::rule(),
+            Issue::DuplicateValue(_) => DuplicateValueIssue::rule(),
+            Issue::StrayWhitespace(_) => StrayWhitespaceIssue::rule(),
+            Issue::EmptyKey(_) => EmptyKeyIssue::rule(),
+            Issue::DuplicateKey(_) => DuplicateKeyIssue::rule(),
             Issue::ParseError(_) => ParseErrorIssue::rule(),
         }
     }
-}
+}
+
+// ============================================================
+// Report Trait (for CLI output)
+// ============================================================
+
+/// Location information for report output.
+pub enum ReportLocation<'a> {
+    /// Source code location (has source_line for context display).
+    Source(&'a SourceContext),
+    /// Message file location (no source_line, but has key/value).
+    Message(&'a MessageContext),
+    /// File-level only (for ParseError - no line context).
+    File { path: &'a str },
+}
+
+/// Trait for types that can be reported to CLI.
+///
+/// This trait is implemented by all issue types to provide a consistent
+/// interface for the report functions. Uses `enum_dispatch` for zero-cost
+/// dispatch on the `Issue` enum.
+#[enum_dispatch]
+pub trait Report {
+    /// Get the location for this issue.
+    fn location(&self) -> ReportLocation<'_>;
+
+    /// Primary message to display (key name, text, error, etc.).
+    fn message(&self) -> String;
+
+    /// Translation key the issue is about, if any.
+    fn key(&self) -> Option<&str> {
+        None
+    }
+
+    /// Severity level.
+    fn report_severity(&self) -> Severity;
+
+    /// Rule identifier.
+    fn report_rule(&self) -> Rule;
+
+    /// Optional hint for fixing the issue.
+    fn hint(&self) -> Option<&str> {
+        None
+    }
+
+    /// Optional details for the "= note:" line.
+    fn details(&self) -> Option<String> {
+        None
+    }
+
+    /// Usage locations (for replica-lag, untranslated, type-mismatch).
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &[]
+    }
+}
+
+// ============================================================
+// Report Implementations
+// ============================================================
+
+impl Report for HardcodedTextIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.text.clone()
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+}
+
+impl Report for MissingKeyIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        self.from_schema
+            .as_ref()
+            .map(|(name, file)| format!("from {} ({})", name, file))
+    }
+}
+
+impl Report for UnresolvedKeyIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.reason.to_string()
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+}
+
+impl Report for UnmatchedMessageKeysIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.pattern.clone()
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        Some("glot-message-keys pattern matches no key in the primary locale".to_string())
+    }
+}
+
+impl Report for NamespaceConsistencyIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        let paths: Vec<String> = self
+            .namespaces
+            .iter()
+            .map(|namespace| match namespace {
+                Some(namespace) => format!("namespace {}", namespace),
+                None => "full key".to_string(),
+            })
+            .collect();
+        Some(format!("reached through: {}", paths.join(", ")))
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
+    }
+}
+
+impl Report for DuplicateValueIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(format!(
+            "value \"{}\" also used by: {}",
+            self.value,
+            self.other_keys.join(", ")
+        ))
+    }
+}
+
+impl Report for InterpolationMismatchIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        self.default_severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if !self.missing.is_empty() {
+            parts.push(format!("missing values: {}", self.missing.join(", ")));
+        }
+        if !self.extra.is_empty() {
+            parts.push(format!("unused values: {}", self.extra.join(", ")));
+        }
+        Some(parts.join("; "))
+    }
+}
+
+impl Report for UnpairedDirectiveIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Source(&self.context)
+    }
+
+    fn message(&self) -> String {
+        match self.kind {
+            UnpairedDirectiveKind::UnmatchedEnable => "glot-enable".to_string(),
+            UnpairedDirectiveKind::UnterminatedDisable => "glot-disable".to_string(),
+        }
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(match self.kind {
+            UnpairedDirectiveKind::UnmatchedEnable => {
+                "no glot-disable is open for its rules, so it has no effect".to_string()
+            }
+            UnpairedDirectiveKind::UnterminatedDisable => {
+                "never closed by glot-enable, so it suppresses the rest of the file".to_string()
+            }
+        })
+    }
+}
+
+impl Report for UnusedKeyIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(format!("(\"{}\")", self.context.value))
+    }
+
+    fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+}
+
+impl Report for UnusedNamespaceIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        let noun = if self.key_count == 1 { "key" } else { "keys" };
+        Some(format!("({} {})", self.key_count, noun))
+    }
+}
+
+impl Report for OrphanKeyIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(format!("in {} (\"{}\")", self.locale, self.context.value))
+    }
+}
+
+impl Report for ReplicaLagIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(format!(
+            "(\"{}\") missing in: {}",
+            self.context.value,
+            self.missing_in.join(", ")
+        ))
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
+    }
+}
+
+impl Report for UntranslatedIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        self.default_severity()
+    }
+
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
+
+    fn details(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if !self.identical_in.is_empty() {
+            parts.push(format!("identical in: {}", self.identical_in.join(", ")));
+        }
+        if !self.empty_in.is_empty() {
+            parts.push(format!("empty in: {}", self.empty_in.join(", ")));
+        }
+        Some(format!("(\"{}\") {}", self.context.value, parts.join("; ")))
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
+    }
+}
+
+impl Report for TypeMismatchIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
+
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
 
-// ============================================================
-// Report Trait (for CLI output)
-// ============================================================
+    fn report_rule(&self) -> Rule {
+        Self::rule()
+    }
 
-/// Location information for report output.
-pub enum ReportLocation<'a> {
-    /// Source code location (has source_line for context display).
-    Source(&'a SourceContext),
-    /// Message file location (no source_line, but has key/value).
-    Message(&'a MessageContext),
-    /// File-level only (for ParseError - no line context).
-    File { path: &'a str },
+    fn details(&self) -> Option<String> {
+        let mismatches: Vec<String> = self
+            .mismatched_in
+            .iter()
+            .map(|m| format!("{} ({})", m.locale, m.actual_type))
+            .collect();
+        Some(format!(
+            "expected {}, got: {}",
+            self.expected_type,
+            mismatches.join(", ")
+        ))
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
+    }
 }
 
-/// Trait for types that can be reported to CLI.
-///
-/// This trait is implemented by all issue types to provide a consistent
-/// interface for the report functions. Uses `enum_dispatch` for zero-cost
-/// dispatch on the `Issue` enum.
-#[enum_dispatch]
-pub trait Report {
-    /// Get the location for this issue.
-    fn location(&self) -> ReportLocation<'_>;
+impl Report for PlaceholderMismatchIssue {
+    fn location(&self) -> ReportLocation<'_> {
+        ReportLocation::Message(&self.context)
+    }
 
-    /// Primary message to display (key name, text, error, etc.).
-    fn message(&self) -> String;
+    fn message(&self) -> String {
+        self.context.key.clone()
+    }
 
-    /// Severity level.
-    fn report_severity(&self) -> Severity;
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
 
-    /// Rule identifier.
-    fn report_rule(&self) -> Rule;
+    fn report_severity(&self) -> Severity {
+        Self::severity()
+    }
 
-    /// Optional hint for fixing the issue.
-    fn hint(&self) -> Option<&str> {
-        None
+    fn report_rule(&self) -> Rule {
+        Self::rule()
     }
 
-    /// Optional details for the "= note:" line.
     fn details(&self) -> Option<String> {
-        None
+        let braced = |names: &[String]| {
+            names
+                .iter()
+                .map(|n| format!("{{{}}}", n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mismatches: Vec<String> = self
+            .mismatched_in
+            .iter()
+            .map(|m| {
+                let mut parts = Vec::new();
+                if m.missing.len() == 1 && m.extra.len() == 1 {
+                    parts.push(format!(
+                        "renamed {{{}}} to {{{}}}",
+                        m.missing[0], m.extra[0]
+                    ));
+                } else {
+                    if !m.missing.is_empty() {
+                        parts.push(format!("missing {}", braced(&m.missing)));
+                    }
+                    if !m.extra.is_empty() {
+                        parts.push(format!("unexpected {}", braced(&m.extra)));
+                    }
+                }
+                if !m.changed_type.is_empty() {
+                    parts.push(format!("type changed for {}", braced(&m.changed_type)));
+                }
+                format!("{}: {}", m.locale, parts.join("; "))
+            })
+            .collect();
+        Some(mismatches.join(", "))
     }
 
-    /// Usage locations (for replica-lag, untranslated, type-mismatch).
     fn usages(&self) -> &[ResolvedKeyUsage] {
-        &[]
+        &self.usages
     }
 }
 
-// ============================================================
-// Report Implementations
-// ============================================================
-
-impl Report for HardcodedTextIssue {
+impl Report for KeyNamingIssue {
     fn location(&self) -> ReportLocation<'_> {
-        ReportLocation::Source(&self.context)
+        ReportLocation::Message(&self.context)
     }
 
     fn message(&self) -> String {
-        self.text.clone()
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
     }
 
     fn report_severity(&self) -> Severity {
@@ -490,19 +1468,34 @@ impl Report for HardcodedTextIssue {
     fn report_rule(&self) -> Rule {
         Self::rule()
     }
+
+    fn details(&self) -> Option<String> {
+        Some(format!(
+            "segment \"{}\" is not {}",
+            self.segment, self.expected_case
+        ))
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
+    }
 }
 
-impl Report for MissingKeyIssue {
+impl Report for EmptyValueIssue {
     fn location(&self) -> ReportLocation<'_> {
-        ReportLocation::Source(&self.context)
+        ReportLocation::Message(&self.context)
     }
 
     fn message(&self) -> String {
-        self.key.clone()
+        self.context.key.clone()
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
     }
 
     fn report_severity(&self) -> Severity {
-        Self::severity()
+        self.default_severity()
     }
 
     fn report_rule(&self) -> Rule {
@@ -510,19 +1503,36 @@ impl Report for MissingKeyIssue {
     }
 
     fn details(&self) -> Option<String> {
-        self.from_schema
-            .as_ref()
-            .map(|(name, file)| format!("from {} ({})", name, file))
+        let kind = match self.value_type {
+            ValueType::StringArray => "empty array",
+            ValueType::Object => "empty object",
+            ValueType::String if self.context.value.is_empty() => "empty string",
+            ValueType::String => "whitespace-only string",
+        };
+        let locale = if self.is_primary {
+            format!("{} (primary locale)", self.locale)
+        } else {
+            self.locale.clone()
+        };
+        Some(format!("{} in {}", kind, locale))
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
     }
 }
 
-impl Report for UnresolvedKeyIssue {
+impl Report for EmptyKeyIssue {
     fn location(&self) -> ReportLocation<'_> {
         ReportLocation::Source(&self.context)
     }
 
     fn message(&self) -> String {
-        self.reason.to_string()
+        if self.key.is_empty() {
+            "empty key".to_string()
+        } else {
+            "blank key".to_string()
+        }
     }
 
     fn report_severity(&self) -> Severity {
@@ -534,11 +1544,19 @@ impl Report for UnresolvedKeyIssue {
     }
 
     fn hint(&self) -> Option<&str> {
-        self.hint.as_deref()
+        Some("pass the key of the message to translate")
+    }
+
+    fn details(&self) -> Option<String> {
+        Some(if self.key.is_empty() {
+            "an empty key looks up the namespace itself instead of a message".to_string()
+        } else {
+            format!("key {:?} is only whitespace", self.key)
+        })
     }
 }
 
-impl Report for UnusedKeyIssue {
+impl Report for DuplicateKeyIssue {
     fn location(&self) -> ReportLocation<'_> {
         ReportLocation::Message(&self.context)
     }
@@ -547,6 +1565,10 @@ impl Report for UnusedKeyIssue {
         self.context.key.clone()
     }
 
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
     fn report_severity(&self) -> Severity {
         Self::severity()
     }
@@ -555,12 +1577,19 @@ impl Report for UnusedKeyIssue {
         Self::rule()
     }
 
+    fn hint(&self) -> Option<&str> {
+        Some("keep a single definition of the key")
+    }
+
     fn details(&self) -> Option<String> {
-        Some(format!("(\"{}\")", self.context.value))
+        Some(format!(
+            "also defined on line {} of the same object; only the last value is kept",
+            self.first_line
+        ))
     }
 }
 
-impl Report for OrphanKeyIssue {
+impl Report for StrayWhitespaceIssue {
     fn location(&self) -> ReportLocation<'_> {
         ReportLocation::Message(&self.context)
     }
@@ -569,6 +1598,10 @@ impl Report for OrphanKeyIssue {
         self.context.key.clone()
     }
 
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
     fn report_severity(&self) -> Severity {
         Self::severity()
     }
@@ -578,11 +1611,34 @@ impl Report for OrphanKeyIssue {
     }
 
     fn details(&self) -> Option<String> {
-        Some(format!("in {} (\"{}\")", self.locale, self.context.value))
+        let value = &self.context.value;
+        Some(match &self.differs_from {
+            Some((locale, source)) => format!(
+                "{:?} in {} differs from {:?} in {} only by whitespace",
+                value, self.locale, source, locale
+            ),
+            None => {
+                let leading = value.starts_with(is_stray_whitespace);
+                let trailing = value.ends_with(is_stray_whitespace);
+                let kind = match (leading, trailing) {
+                    (true, true) => "leading and trailing whitespace",
+                    (true, false) => "leading whitespace",
+                    _ => "trailing whitespace",
+                };
+                format!(
+                    "{:?} has {} in {} (primary locale)",
+                    value, kind, self.locale
+                )
+            }
+        })
+    }
+
+    fn usages(&self) -> &[ResolvedKeyUsage] {
+        &self.usages
     }
 }
 
-impl Report for ReplicaLagIssue {
+impl Report for PluralCategoriesIssue {
     fn location(&self) -> ReportLocation<'_> {
         ReportLocation::Message(&self.context)
     }
@@ -591,6 +1647,10 @@ impl Report for ReplicaLagIssue {
         self.context.key.clone()
     }
 
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
     fn report_severity(&self) -> Severity {
         Self::severity()
     }
@@ -600,10 +1660,19 @@ impl Report for ReplicaLagIssue {
     }
 
     fn details(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if !self.missing.is_empty() {
+            parts.push(format!("missing {}", self.missing.join(", ")));
+        }
+        if !self.invalid.is_empty() {
+            parts.push(format!("unexpected {}", self.invalid.join(", ")));
+        }
         Some(format!(
-            "(\"{}\") missing in: {}",
-            self.context.value,
-            self.missing_in.join(", ")
+            "{{{}, {}}} in {}: {}",
+            self.argument,
+            self.kind,
+            self.locale,
+            parts.join("; ")
         ))
     }
 
@@ -612,7 +1681,7 @@ impl Report for ReplicaLagIssue {
     }
 }
 
-impl Report for UntranslatedIssue {
+impl Report for MarkupOnlyIssue {
     fn location(&self) -> ReportLocation<'_> {
         ReportLocation::Message(&self.context)
     }
@@ -621,8 +1690,12 @@ impl Report for UntranslatedIssue {
         self.context.key.clone()
     }
 
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
     fn report_severity(&self) -> Severity {
-        self.default_severity()
+        Self::severity()
     }
 
     fn report_rule(&self) -> Rule {
@@ -630,14 +1703,7 @@ impl Report for UntranslatedIssue {
     }
 
     fn details(&self) -> Option<String> {
-        let mut parts = Vec::new();
-        if !self.identical_in.is_empty() {
-            parts.push(format!("identical in: {}", self.identical_in.join(", ")));
-        }
-        if !self.empty_in.is_empty() {
-            parts.push(format!("empty in: {}", self.empty_in.join(", ")));
-        }
-        Some(format!("(\"{}\") {}", self.context.value, parts.join("; ")))
+        Some(format!("only markup, no text in {}", self.locale))
     }
 
     fn usages(&self) -> &[ResolvedKeyUsage] {
@@ -645,7 +1711,7 @@ impl Report for UntranslatedIssue {
     }
 }
 
-impl Report for TypeMismatchIssue {
+impl Report for RichTagMismatchIssue {
     fn location(&self) -> ReportLocation<'_> {
         ReportLocation::Message(&self.context)
     }
@@ -654,6 +1720,10 @@ impl Report for TypeMismatchIssue {
         self.context.key.clone()
     }
 
+    fn key(&self) -> Option<&str> {
+        Some(&self.context.key)
+    }
+
     fn report_severity(&self) -> Severity {
         Self::severity()
     }
@@ -663,16 +1733,28 @@ impl Report for TypeMismatchIssue {
     }
 
     fn details(&self) -> Option<String> {
+        let tags = |names: &[String]| {
+            names
+                .iter()
+                .map(|n| format!("<{}>", n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
         let mismatches: Vec<String> = self
             .mismatched_in
             .iter()
-            .map(|m| format!("{} ({})", m.locale, m.actual_type))
+            .map(|m| {
+                let mut parts = Vec::new();
+                if !m.missing.is_empty() {
+                    parts.push(format!("missing {}", tags(&m.missing)));
+                }
+                if !m.extra.is_empty() {
+                    parts.push(format!("unexpected {}", tags(&m.extra)));
+                }
+                format!("{}: {}", m.locale, parts.join("; "))
+            })
             .collect();
-        Some(format!(
-            "expected {}, got: {}",
-            self.expected_type,
-            mismatches.join(", ")
-        ))
+        Some(mismatches.join(", "))
     }
 
     fn usages(&self) -> &[ResolvedKeyUsage] {
@@ -737,13 +1819,16 @@ impl Ord for Issue {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         use std::cmp::Ordering;
 
-        // Sort by: file_path (None last), line, col, message
+        // Sort by: file_path (None last), line, col, message, rule.
+        // Files are scanned in no particular order, so ties are broken by
+        // rule to keep the output the same from run to run.
         match (self.sort_file_path(), other.sort_file_path()) {
             (Some(a), Some(b)) => a
                 .cmp(b)
                 .then_with(|| self.sort_line().cmp(&other.sort_line()))
                 .then_with(|| self.sort_col().cmp(&other.sort_col()))
-                .then_with(|| self.message().cmp(&other.message())),
+                .then_with(|| self.message().cmp(&other.message()))
+                .then_with(|| self.rule().cmp(&other.rule())),
             (Some(_), None) => Ordering::Less,
             (None, Some(_)) => Ordering::Greater,
             (None, None) => self
@@ -760,6 +1845,50 @@ impl PartialOrd for Issue {
     }
 }
 
+// ============================================================
+// Fingerprint (for baseline files)
+// ============================================================
+
+/// Stable identity of an issue, recorded in baseline files.
+///
+/// Line and column are left out, so an issue keeps its fingerprint when code
+/// above it moves. The hash covers the source line (or message value) the
+/// issue points at, so changing that content makes it a new issue.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct IssueFingerprint {
+    pub rule: Rule,
+    /// File path relative to the source root, with `/` separators.
+    pub file: String,
+    /// Primary message of the issue (key name, hardcoded text, etc.).
+    pub key: String,
+    /// FNV-1a hash of the content at the issue location, in hex.
+    pub hash: String,
+}
+
+impl Issue {
+    /// Fingerprint of this issue, with its file path made relative to `root`.
+    pub fn fingerprint(&self, root: &std::path::Path) -> IssueFingerprint {
+        let (file_path, content) = match self.location() {
+            ReportLocation::Source(ctx) => (ctx.file_path(), ctx.source_line.trim()),
+            ReportLocation::Message(ctx) => (ctx.file_path(), ctx.value.as_str()),
+            ReportLocation::File { path } => (path, ""),
+        };
+        IssueFingerprint {
+            rule: self.rule(),
+            file: relative_path(file_path, root),
+            key: self.message(),
+            hash: format!("{:016x}", fnv1a(content)),
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across Rust versions and platforms.
+fn fnv1a(content: &str) -> u64 {
+    content.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 // ============================================================
 // Tests
 // ============================================================
@@ -783,6 +1912,33 @@ mod tests {
         assert_eq!(issue.text, "Hello");
     }
 
+    #[test]
+    fn test_issue_order_breaks_ties_by_rule() {
+        let ctx = || {
+            SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 3, 7),
+                "t('Common.title')",
+                CommentStyle::Js,
+            )
+        };
+        let hardcoded = Issue::HardcodedText(HardcodedTextIssue {
+            context: ctx(),
+            text: "Common.title".to_string(),
+        });
+        let missing = Issue::MissingKey(MissingKeyIssue {
+            context: ctx(),
+            key: "Common.title".to_string(),
+            from_schema: None,
+        });
+
+        let mut a = vec![hardcoded.clone(), missing.clone()];
+        let mut b = vec![missing, hardcoded];
+        a.sort();
+        b.sort();
+        let rules = |issues: &[Issue]| issues.iter().map(Issue::rule).collect::<Vec<_>>();
+        assert_eq!(rules(&a), rules(&b));
+    }
+
     #[test]
     fn test_missing_key_issue() {
         let loc = SourceLocation::new("./src/app.tsx", 15, 10);
@@ -850,13 +2006,23 @@ mod tests {
             .to_string(),
             "unknown namespace for schema 'formSchema'"
         );
+        assert_eq!(
+            IssueUnresolvedKeyReason::NotAKeyMap {
+                object_name: "statusColors".to_string()
+            }
+            .to_string(),
+            "no value of 'statusColors' is a translation key"
+        );
     }
 
     #[test]
     fn test_unused_key_issue() {
         let loc = MessageLocation::new("./messages/en.json", 5, 3);
         let ctx = MessageContext::new(loc, "Common.unused", "Unused Value");
-        let issue = UnusedKeyIssue { context: ctx };
+        let issue = UnusedKeyIssue {
+            context: ctx,
+            hint: None,
+        };
 
         assert_eq!(UnusedKeyIssue::severity(), Severity::Warning);
         assert_eq!(UnusedKeyIssue::rule(), Rule::UnusedKey);
@@ -891,6 +2057,8 @@ mod tests {
             context: usage_ctx,
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         };
 
         let issue = ReplicaLagIssue {
@@ -923,6 +2091,81 @@ mod tests {
         assert_eq!(issue.identical_in, vec!["zh"]);
     }
 
+    #[test]
+    fn test_empty_value_issue_severity_and_details() {
+        let mut issue = EmptyValueIssue {
+            context: MessageContext::new(
+                MessageLocation::new("./messages/zh.json", 3, 1),
+                "Common.ok",
+                "  ",
+            ),
+            locale: "zh".to_string(),
+            is_primary: false,
+            value_type: ValueType::String,
+            usages: vec![],
+        };
+        assert_eq!(issue.default_severity(), Severity::Warning);
+        assert_eq!(
+            issue.details().as_deref(),
+            Some("whitespace-only string in zh")
+        );
+
+        issue.is_primary = true;
+        issue.locale = "en".to_string();
+        issue.value_type = ValueType::StringArray;
+        issue.context.value = String::new();
+        assert_eq!(issue.default_severity(), Severity::Error);
+        assert_eq!(
+            issue.details().as_deref(),
+            Some("empty array in en (primary locale)")
+        );
+    }
+
+    #[test]
+    fn test_stray_whitespace_issue_details() {
+        let mut issue = StrayWhitespaceIssue {
+            context: MessageContext::new(
+                MessageLocation::new("./messages/en.json", 3, 1),
+                "Common.submit",
+                " Submit\t",
+            ),
+            locale: "en".to_string(),
+            differs_from: None,
+            usages: vec![],
+        };
+        assert_eq!(
+            issue.details().as_deref(),
+            Some(r#"" Submit\t" has leading and trailing whitespace in en (primary locale)"#)
+        );
+
+        issue.locale = "fr".to_string();
+        issue.context.value = "OK ".to_string();
+        issue.differs_from = Some(("en".to_string(), "OK".to_string()));
+        assert_eq!(
+            issue.details().as_deref(),
+            Some(r#""OK " in fr differs from "OK" in en only by whitespace"#)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_key_issue_details() {
+        let issue = DuplicateKeyIssue {
+            context: MessageContext::new(
+                MessageLocation::new("./messages/en.json", 5, 1),
+                "Common.submit",
+                "Submit",
+            ),
+            locale: "en".to_string(),
+            first_line: 3,
+        };
+        assert_eq!(DuplicateKeyIssue::severity(), Severity::Error);
+        assert_eq!(issue.message(), "Common.submit");
+        assert_eq!(
+            issue.details().as_deref(),
+            Some("also defined on line 3 of the same object; only the last value is kept")
+        );
+    }
+
     #[test]
     fn test_type_mismatch_issue() {
         let loc = MessageLocation::new("./messages/en.json", 5, 3);
@@ -975,7 +2218,10 @@ mod tests {
     fn test_issue_enum_rule() {
         let loc = MessageLocation::new("./messages/en.json", 5, 3);
         let ctx = MessageContext::new(loc, "Common.unused", "Unused");
-        let issue = Issue::UnusedKey(UnusedKeyIssue { context: ctx });
+        let issue = Issue::UnusedKey(UnusedKeyIssue {
+            context: ctx,
+            hint: None,
+        });
 
         assert_eq!(issue.severity(), Severity::Warning);
         assert_eq!(issue.rule(), Rule::UnusedKey);
@@ -995,6 +2241,8 @@ mod tests {
             context: usage_ctx,
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         };
         let issue = Issue::Untranslated(UntranslatedIssue {
             context: ctx,
@@ -1014,6 +2262,40 @@ mod tests {
         assert_eq!(Severity::Warning.to_string(), "warning");
     }
 
+    #[test]
+    fn test_fingerprint_ignores_position() {
+        let issue = |line: usize, source_line: &str| {
+            Issue::HardcodedText(HardcodedTextIssue {
+                context: SourceContext::new(
+                    SourceLocation::new("/repo/src/app.tsx", line, 5),
+                    source_line,
+                    CommentStyle::Jsx,
+                ),
+                text: "Submit".to_string(),
+            })
+        };
+        let root = std::path::Path::new("/repo");
+        let fingerprint = issue(3, "  <button>Submit</button>").fingerprint(root);
+
+        assert_eq!(fingerprint.rule, Rule::HardcodedText);
+        assert_eq!(fingerprint.file, "src/app.tsx");
+        assert_eq!(fingerprint.key, "Submit");
+        assert_eq!(
+            issue(10, "<button>Submit</button>").fingerprint(root),
+            fingerprint
+        );
+        assert_ne!(
+            issue(3, "<button type=\"submit\">Submit</button>").fingerprint(root),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
     #[test]
     fn test_rule_display() {
         assert_eq!(Rule::HardcodedText.to_string(), "hardcoded");
@@ -1021,9 +2303,36 @@ mod tests {
         assert_eq!(Rule::UnresolvedKey.to_string(), "unresolved-key");
         assert_eq!(Rule::ReplicaLag.to_string(), "replica-lag");
         assert_eq!(Rule::UnusedKey.to_string(), "unused-key");
+        assert_eq!(Rule::UnusedNamespace.to_string(), "unused-namespace");
         assert_eq!(Rule::OrphanKey.to_string(), "orphan-key");
         assert_eq!(Rule::Untranslated.to_string(), "untranslated");
         assert_eq!(Rule::TypeMismatch.to_string(), "type-mismatch");
+        assert_eq!(
+            Rule::PlaceholderMismatch.to_string(),
+            "placeholder-mismatch"
+        );
+        assert_eq!(Rule::KeyNaming.to_string(), "key-naming");
+        assert_eq!(Rule::EmptyValue.to_string(), "empty-value");
+        assert_eq!(Rule::PluralCategories.to_string(), "plural-categories");
+        assert_eq!(Rule::MarkupOnly.to_string(), "markup-only");
+        assert_eq!(Rule::RichTagMismatch.to_string(), "rich-tag-mismatch");
+        assert_eq!(
+            Rule::UnmatchedMessageKeys.to_string(),
+            "unmatched-message-keys"
+        );
+        assert_eq!(
+            Rule::NamespaceConsistency.to_string(),
+            "namespace-consistency"
+        );
+        assert_eq!(Rule::UnpairedDirective.to_string(), "unpaired-directive");
+        assert_eq!(
+            Rule::InterpolationMismatch.to_string(),
+            "interpolation-mismatch"
+        );
+        assert_eq!(Rule::DuplicateValue.to_string(), "duplicate-value");
+        assert_eq!(Rule::StrayWhitespace.to_string(), "stray-whitespace");
+        assert_eq!(Rule::EmptyKey.to_string(), "empty-key");
+        assert_eq!(Rule::DuplicateKey.to_string(), "duplicate-key");
         assert_eq!(Rule::ParseError.to_string(), "parse-error");
     }
 
@@ -1034,6 +2343,7 @@ mod tests {
         assert_eq!(Rule::parse("missing-key"), Some(Rule::MissingKey));
         assert_eq!(Rule::parse("unused"), Some(Rule::UnusedKey));
         assert_eq!(Rule::parse("unused-key"), Some(Rule::UnusedKey));
+        assert_eq!(Rule::parse("unused-namespace"), Some(Rule::UnusedNamespace));
         assert_eq!(Rule::parse("orphan"), Some(Rule::OrphanKey));
         assert_eq!(Rule::parse("orphan-key"), Some(Rule::OrphanKey));
         assert_eq!(Rule::parse("unresolved"), Some(Rule::UnresolvedKey));
@@ -1042,4 +2352,12 @@ mod tests {
         assert_eq!(Rule::parse("type-mismatch"), Some(Rule::TypeMismatch));
         assert_eq!(Rule::parse("unknown"), None);
     }
+
+    #[test]
+    fn test_rule_all_round_trips_names() {
+        for rule in Rule::ALL {
+            assert_eq!(Rule::parse(&rule.to_string()), Some(*rule));
+        }
+        assert_eq!(Rule::ALL.len(), Rule::ParseError as usize + 1);
+    }
 }
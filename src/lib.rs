@@ -6,6 +6,7 @@
 //!
 //! ## Module Structure
 //!
+//! - `analyze`: Library entry point (`analyze()` on in-memory source files)
 //! - `cli`: Command-line interface layer (user-facing commands and actions)
 //! - `config`: Configuration file loading and parsing
 //! - `core`: Core analysis engine (three-phase pipeline)
@@ -14,6 +15,7 @@
 //! - `rules`: Detection rules for various i18n issues
 //! - `utils`: Shared utility functions
 
+pub mod analyze;
 pub mod cli;
 pub mod config;
 pub mod core;
@@ -21,3 +23,5 @@ pub mod issues;
 pub mod mcp;
 pub mod rules;
 pub mod utils;
+
+pub use analyze::{RunResult, analyze};
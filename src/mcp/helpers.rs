@@ -8,11 +8,14 @@ use crate::mcp::types::{KeyActionDetail, LocaleError, LocaleResult};
 
 /// Process a single locale translation entry.
 ///
+/// Keys are split into nested objects with `key_separator`.
+///
 /// Returns `Ok(LocaleResult)` on success, `Err(LocaleError)` on failure.
 /// The caller can convert `LocaleError` to `LocaleResult` using `LocaleError::into_result()`.
 pub fn process_locale_translation(
     translation: &serde_json::Value,
     messages_dir: &Path,
+    key_separator: &str,
 ) -> Result<LocaleResult, LocaleError> {
     // Extract locale
     let locale = translation
@@ -51,7 +54,7 @@ pub fn process_locale_translation(
 
     for (key, value) in keys {
         // Validate key format
-        validate_key_format(key)
+        validate_key_format(key, key_separator)
             .map_err(|msg| LocaleError::new(locale, format!("Invalid key '{}': {}", key, msg)))?;
 
         // Validate and convert value
@@ -59,7 +62,7 @@ pub fn process_locale_translation(
             LocaleError::new(locale, format!("Invalid value for key '{}': {}", key, msg))
         })?;
 
-        let action = writer.add_value(key, json_value);
+        let action = writer.add_value(key, json_value, key_separator);
         match action {
             KeyAction::Added => added_count += 1,
             KeyAction::Updated => updated_count += 1,
@@ -112,32 +115,36 @@ pub fn validate_locale_name(locale: &str) -> Result<(), String> {
 ///
 /// Keys must:
 /// - Not be empty
-/// - Not start or end with '.'
-/// - Not contain consecutive dots '..'
-/// - Only contain alphanumeric characters, dots, underscores, and hyphens
+/// - Not start or end with `key_separator`
+/// - Not contain consecutive separators (e.g. '..')
+/// - Only contain alphanumeric characters, dots, underscores, hyphens and
+///   the characters of `key_separator`
 /// - Not contain whitespace or control characters
-pub fn validate_key_format(key: &str) -> Result<(), String> {
+pub fn validate_key_format(key: &str, key_separator: &str) -> Result<(), String> {
     if key.is_empty() {
         return Err("Key cannot be empty".to_string());
     }
-    if key.starts_with('.') {
-        return Err("Key cannot start with '.'".to_string());
+    if key.starts_with(key_separator) {
+        return Err(format!("Key cannot start with '{}'", key_separator));
     }
-    if key.ends_with('.') {
-        return Err("Key cannot end with '.'".to_string());
+    if key.ends_with(key_separator) {
+        return Err(format!("Key cannot end with '{}'", key_separator));
     }
-    if key.contains("..") {
-        return Err("Key cannot contain consecutive dots '..'".to_string());
+    if key.contains(&key_separator.repeat(2)) {
+        return Err(format!(
+            "Key cannot contain consecutive separators '{}'",
+            key_separator.repeat(2)
+        ));
     }
     // Security: reject whitespace and control characters, only allow safe charset
-    // Allowed: alphanumeric, dots (for nesting), underscores, hyphens
-    if !key
-        .chars()
-        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-')
-    {
-        return Err(
-            "Key can only contain letters, numbers, dots, underscores, and hyphens".to_string(),
-        );
+    // Allowed: alphanumeric, dots, underscores, hyphens, separator (for nesting)
+    if !key.chars().all(|c| {
+        c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-' || key_separator.contains(c)
+    }) {
+        return Err(format!(
+            "Key can only contain letters, numbers, dots, underscores, hyphens, and '{}'",
+            key_separator
+        ));
     }
     Ok(())
 }
@@ -226,38 +233,49 @@ mod tests {
 
     #[test]
     fn test_validate_key_format_valid() {
-        assert!(validate_key_format("Common.submit").is_ok());
-        assert!(validate_key_format("a.b.c").is_ok());
-        assert!(validate_key_format("singleKey").is_ok());
-        assert!(validate_key_format("Auth.login.button").is_ok());
+        assert!(validate_key_format("Common.submit", ".").is_ok());
+        assert!(validate_key_format("a.b.c", ".").is_ok());
+        assert!(validate_key_format("singleKey", ".").is_ok());
+        assert!(validate_key_format("Auth.login.button", ".").is_ok());
     }
 
     #[test]
     fn test_validate_key_format_empty() {
-        let result = validate_key_format("");
+        let result = validate_key_format("", ".");
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("empty"));
     }
 
     #[test]
     fn test_validate_key_format_starts_with_dot() {
-        let result = validate_key_format(".key");
+        let result = validate_key_format(".key", ".");
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("start with '.'"));
     }
 
     #[test]
     fn test_validate_key_format_ends_with_dot() {
-        let result = validate_key_format("key.");
+        let result = validate_key_format("key.", ".");
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("end with '.'"));
     }
 
     #[test]
     fn test_validate_key_format_consecutive_dots() {
-        let result = validate_key_format("a..b");
+        let result = validate_key_format("a..b", ".");
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("consecutive dots"));
+        assert!(result.unwrap_err().contains("consecutive separators '..'"));
+    }
+
+    #[test]
+    fn test_validate_key_format_custom_separator() {
+        assert!(validate_key_format("Common/v1.0", "/").is_ok());
+        assert!(validate_key_format(".key", "/").is_ok());
+
+        let result = validate_key_format("Common//submit", "/");
+        assert!(result.unwrap_err().contains("consecutive separators '//'"));
+        let result = validate_key_format("/key", "/");
+        assert!(result.unwrap_err().contains("start with '/'"));
     }
 
     // ============================================================
@@ -334,7 +352,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         let locale_result = result.unwrap();
@@ -362,7 +380,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         let locale_result = result.unwrap();
@@ -391,7 +409,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         let locale_result = result.unwrap();
@@ -412,7 +430,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         // Verify file content
@@ -438,7 +456,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         let locale_result = result.unwrap();
@@ -472,7 +490,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         // Verify existing keys are preserved
@@ -495,7 +513,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -515,7 +533,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -533,7 +551,7 @@ mod tests {
             "keys": {}
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -553,7 +571,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -572,7 +590,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -591,7 +609,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -609,7 +627,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -626,7 +644,7 @@ mod tests {
             "locale": "en"
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_err());
 
         let err = result.unwrap_err();
@@ -649,7 +667,7 @@ mod tests {
             }
         });
 
-        let result = process_locale_translation(&translation, &messages_dir);
+        let result = process_locale_translation(&translation, &messages_dir, ".");
         assert!(result.is_ok());
 
         // Verify file was created
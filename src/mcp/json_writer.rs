@@ -1,6 +1,6 @@
 //! JSON writer for MCP translations.
 //!
-//! Supports nested dot-keys and preserves key order.
+//! Supports nested keys joined with the key separator and preserves key order.
 
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -55,8 +55,9 @@ impl JsonWriter {
         })
     }
 
-    pub fn add_value(&mut self, key: &str, value: Value) -> KeyAction {
-        let parts: Vec<&str> = key.split('.').collect();
+    /// Set `value` at `key`, whose segments are joined with `separator`.
+    pub fn add_value(&mut self, key: &str, value: Value, separator: &str) -> KeyAction {
+        let parts: Vec<&str> = key.split(separator).collect();
         if parts.is_empty() {
             return KeyAction::Updated;
         }
@@ -183,7 +184,7 @@ mod tests {
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        let action = writer.add_value("key", json!("value"));
+        let action = writer.add_value("key", json!("value"), ".");
 
         assert_eq!(action, KeyAction::Added);
         assert_eq!(writer.root, json!({"key": "value"}));
@@ -195,19 +196,31 @@ mod tests {
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        let action = writer.add_value("common.submit", json!("Submit"));
+        let action = writer.add_value("common.submit", json!("Submit"), ".");
 
         assert_eq!(action, KeyAction::Added);
         assert_eq!(writer.root, json!({"common": {"submit": "Submit"}}));
     }
 
+    #[test]
+    fn test_add_value_custom_separator() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_json_path(&temp_dir, "test.json");
+
+        let mut writer = JsonWriter::open_or_create(&path).unwrap();
+        let action = writer.add_value("common/v1.0", json!("Version"), "/");
+
+        assert_eq!(action, KeyAction::Added);
+        assert_eq!(writer.root, json!({"common": {"v1.0": "Version"}}));
+    }
+
     #[test]
     fn test_add_value_deep_nesting() {
         let temp_dir = TempDir::new().unwrap();
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        let action = writer.add_value("a.b.c.d.e", json!("deep"));
+        let action = writer.add_value("a.b.c.d.e", json!("deep"), ".");
 
         assert_eq!(action, KeyAction::Added);
         assert_eq!(
@@ -222,8 +235,8 @@ mod tests {
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        writer.add_value("existing.key", json!("first"));
-        let action = writer.add_value("existing.other.nested", json!("second"));
+        writer.add_value("existing.key", json!("first"), ".");
+        let action = writer.add_value("existing.other.nested", json!("second"), ".");
 
         assert_eq!(action, KeyAction::Added);
         assert_eq!(
@@ -240,7 +253,7 @@ mod tests {
         fs::write(&path, r#"{"items": "wrong type"}"#).unwrap();
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        let action = writer.add_value("items.nested.key", json!("value"));
+        let action = writer.add_value("items.nested.key", json!("value"), ".");
 
         assert_eq!(action, KeyAction::Added);
         assert_eq!(writer.root, json!({"items": {"nested": {"key": "value"}}}));
@@ -252,7 +265,7 @@ mod tests {
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        let action = writer.add_value("newKey", json!("value"));
+        let action = writer.add_value("newKey", json!("value"), ".");
 
         assert_eq!(action, KeyAction::Added);
     }
@@ -265,7 +278,7 @@ mod tests {
         fs::write(&path, r#"{"key": "old value"}"#).unwrap();
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        let action = writer.add_value("key", json!("new value"));
+        let action = writer.add_value("key", json!("new value"), ".");
 
         assert_eq!(action, KeyAction::Updated);
         assert_eq!(writer.root, json!({"key": "new value"}));
@@ -277,7 +290,7 @@ mod tests {
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        writer.add_value("items", json!(["one", "two", "three"]));
+        writer.add_value("items", json!(["one", "two", "three"]), ".");
 
         assert_eq!(writer.root, json!({"items": ["one", "two", "three"]}));
     }
@@ -288,7 +301,7 @@ mod tests {
         let path = temp_json_path(&temp_dir, "test.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        writer.add_value("config", json!({"setting": "value", "enabled": true}));
+        writer.add_value("config", json!({"setting": "value", "enabled": true}), ".");
 
         assert_eq!(
             writer.root,
@@ -302,8 +315,8 @@ mod tests {
         let path = temp_json_path(&temp_dir, "output.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        writer.add_value("key1", json!("value1"));
-        writer.add_value("key2", json!(42));
+        writer.add_value("key1", json!("value1"), ".");
+        writer.add_value("key2", json!(42), ".");
         writer.save().unwrap();
 
         let content = fs::read_to_string(&path).unwrap();
@@ -321,7 +334,7 @@ mod tests {
         let path = temp_json_path(&temp_dir, "output.json");
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
-        writer.add_value("test", json!("value"));
+        writer.add_value("test", json!("value"), ".");
         writer.save().unwrap();
 
         let content = fs::read_to_string(&path).unwrap();
@@ -342,10 +355,10 @@ mod tests {
 
         let mut writer = JsonWriter::open_or_create(&path).unwrap();
 
-        assert_eq!(writer.add_value("a", json!(1)), KeyAction::Added);
-        assert_eq!(writer.add_value("b.c", json!(2)), KeyAction::Added);
-        assert_eq!(writer.add_value("a", json!(3)), KeyAction::Updated);
-        assert_eq!(writer.add_value("b.d", json!(4)), KeyAction::Added);
+        assert_eq!(writer.add_value("a", json!(1), "."), KeyAction::Added);
+        assert_eq!(writer.add_value("b.c", json!(2), "."), KeyAction::Added);
+        assert_eq!(writer.add_value("a", json!(3), "."), KeyAction::Updated);
+        assert_eq!(writer.add_value("b.d", json!(4), "."), KeyAction::Added);
 
         assert_eq!(writer.root, json!({"a": 3, "b": {"c": 2, "d": 4}}));
     }
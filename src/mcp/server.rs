@@ -2,6 +2,7 @@ use std::collections::HashSet;
 use std::path::{Component, Path, PathBuf};
 
 use anyhow::Result;
+use clap::ValueEnum;
 use rmcp::{
     ErrorData as McpError, ServerHandler, ServiceExt,
     handler::server::tool::ToolRouter,
@@ -12,23 +13,27 @@ use rmcp::{
 use serde_json;
 
 use crate::{
+    cli::actions::{AnnotateOutcome, InsertMessageKeys},
+    cli::commands::check::{CheckRule, collect_issues},
     config::load_config,
-    core::parsers::json::scan_message_files,
+    core::parsers::json::{LocaleFilter, scan_message_files, scan_message_pattern},
     core::{CheckContext, ResolvedKeyUsage},
     issues::{
-        HardcodedTextIssue, MissingKeyIssue, ReplicaLagIssue, TypeMismatchIssue, UntranslatedIssue,
+        HardcodedTextIssue, Issue, MissingKeyIssue, ReplicaLagIssue, Report, ReportLocation,
+        Severity, TypeMismatchIssue, UntranslatedIssue,
     },
     rules::{
         hardcoded::check_hardcoded_text_issues, missing::check_missing_keys_issues,
         replica_lag::check_replica_lag_issues, type_mismatch::check_type_mismatch_issues,
-        untranslated::check_untranslated_issues,
+        unresolved::check_unresolved_keys_issues, untranslated::check_untranslated_issues,
     },
 };
 
 use crate::mcp::helpers::process_locale_translation;
 use crate::mcp::types::{
-    AddTranslationsParams, AddTranslationsResult, AddTranslationsSummary, ConfigDto, ConfigValues,
-    GetConfigParams, GetLocalesParams, HardcodedItem, HardcodedScanResult, HardcodedStats,
+    AddTranslationsParams, AddTranslationsResult, AddTranslationsSummary, ApplyFixParams,
+    ApplyFixResult, CheckIssueItem, CheckResult, ConfigDto, ConfigValues, GetConfigParams,
+    GetLocalesParams, GlotCheckParams, HardcodedItem, HardcodedScanResult, HardcodedStats,
     KeyUsageLocation, LocaleInfo, LocalesResult, Pagination, PrimaryMissingItem,
     PrimaryMissingScanResult, PrimaryMissingStats, ReplicaLagItem, ReplicaLagScanResult,
     ReplicaLagStats, ScanHardcodedParams, ScanOverviewParams, ScanOverviewResult,
@@ -467,6 +472,68 @@ impl GlotMcpServer {
         Ok(CallToolResult::success(vec![Content::text(json_str)]))
     }
 
+    /// Run `glot check` and return its issues
+    #[tool(
+        description = "Run the same checks as `glot check` and return every issue with rule, effective severity, location, details and usages. Optionally select rules (e.g. [\"missing\", \"type-mismatch\"]) and restrict results to a file or directory. Returns paginated list."
+    )]
+    pub async fn glot_check(
+        &self,
+        params: Parameters<GlotCheckParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let path = &params.0.project_root_path;
+        let limit = params.0.limit.map(|v| v as usize).unwrap_or(50).min(100);
+        let offset = params.0.offset.map(|v| v as usize).unwrap_or(0);
+        let rules = parse_check_rules(params.0.rules.as_deref().unwrap_or_default())?;
+
+        let ctx = create_context(path)?;
+        let filter = params.0.path.as_deref().map(|p| ctx.root_dir.join(p));
+        let all_items: Vec<CheckIssueItem> = collect_issues(&ctx, &rules)
+            .into_iter()
+            .filter(|issue| {
+                filter.as_ref().is_none_or(|filter| {
+                    ctx.root_dir
+                        .join(issue_file_path(issue))
+                        .starts_with(filter)
+                })
+            })
+            .map(|issue| {
+                let severity = ctx.config.severity_for_rule(issue.rule(), issue.severity());
+                to_check_item(&issue, severity)
+            })
+            .collect();
+
+        let total_count = all_items.len();
+        let error_count = all_items
+            .iter()
+            .filter(|item| item.severity == Severity::Error)
+            .count();
+        let warning_count = total_count - error_count;
+
+        // Apply pagination
+        let paginated: Vec<CheckIssueItem> =
+            all_items.into_iter().skip(offset).take(limit).collect();
+
+        let has_more = offset + paginated.len() < total_count;
+
+        let check_result = CheckResult {
+            total_count,
+            error_count,
+            warning_count,
+            items: paginated,
+            pagination: Pagination {
+                offset,
+                limit,
+                has_more,
+            },
+        };
+
+        let json_str = serde_json::to_string_pretty(&check_result).map_err(|e| {
+            McpError::internal_error(format!("JSON serialization failed: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![Content::text(json_str)]))
+    }
+
     /// Add translation keys to multiple locale files
     #[tool(
         description = "Add translation keys to multiple locale files. Supports nested keys (e.g., 'common.title') and string arrays."
@@ -503,7 +570,11 @@ impl GlotMcpServer {
             let translation_value = serde_json::to_value(translation).map_err(|e| {
                 McpError::internal_error(format!("Failed to serialize translation: {}", e), None)
             })?;
-            let locale_result = process_locale_translation(&translation_value, &messages_dir);
+            let locale_result = process_locale_translation(
+                &translation_value,
+                &messages_dir,
+                &config.config.key_separator,
+            );
 
             match &locale_result {
                 Ok(result) => {
@@ -540,6 +611,91 @@ impl GlotMcpServer {
         Ok(CallToolResult::success(vec![Content::text(json_str)]))
     }
 
+    /// Annotate a dynamic key with a glot-message-keys comment
+    #[tool(
+        description = "Resolve an unresolved (dynamic) key warning by inserting a glot-message-keys comment above its line, declaring the keys it can resolve to. The comment style (JSX or JS) matches the code. Refuses if the line is already annotated. Returns the changed snippet."
+    )]
+    pub async fn apply_fix(
+        &self,
+        params: Parameters<ApplyFixParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let ApplyFixParams {
+            project_root_path,
+            file_path,
+            line,
+            keys,
+        } = params.0;
+        let line = line as usize;
+
+        if keys.is_empty() {
+            return Err(McpError::invalid_params("keys must not be empty", None));
+        }
+        if let Some(key) = keys
+            .iter()
+            .find(|key| key.trim().is_empty() || key.contains('"'))
+        {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Invalid key \"{}\": keys must be non-empty and contain no quotes",
+                    key
+                ),
+                None,
+            ));
+        }
+
+        let ctx = create_context(&project_root_path)?;
+        let target = ctx.root_dir.join(&file_path);
+        let failure = |error: String| ApplyFixResult {
+            success: false,
+            file_path: file_path.clone(),
+            comment: None,
+            snippet: None,
+            error: Some(error),
+        };
+        let conflict = |existing: String| {
+            failure(format!(
+                "Line {} is already annotated: {}. Edit the existing comment instead.",
+                line, existing
+            ))
+        };
+
+        let existing = InsertMessageKeys::existing_annotation(&target.to_string_lossy(), line)
+            .map_err(|e| McpError::invalid_params(format!("Failed to read file: {}", e), None))?;
+        let issue = check_unresolved_keys_issues(&ctx)
+            .into_iter()
+            .find(|issue| {
+                issue.context.line() == line
+                    && ctx.root_dir.join(issue.context.file_path()) == target
+            });
+
+        let result = match (existing, issue) {
+            (Some(existing), _) => conflict(existing),
+            (None, None) => failure(format!("No unresolved key found at line {}", line)),
+            (None, Some(issue)) => match InsertMessageKeys::annotate(&issue, &keys) {
+                Ok(AnnotateOutcome::Inserted { comment }) => ApplyFixResult {
+                    success: true,
+                    file_path: file_path.clone(),
+                    comment: Some(comment),
+                    snippet: read_snippet(&target, line),
+                    error: None,
+                },
+                Ok(AnnotateOutcome::Conflict { existing }) => conflict(existing),
+                Err(e) => {
+                    return Err(McpError::internal_error(
+                        format!("Failed to apply fix: {}", e),
+                        None,
+                    ));
+                }
+            },
+        };
+
+        let json_str = serde_json::to_string_pretty(&result).map_err(|e| {
+            McpError::internal_error(format!("JSON serialization failed: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![Content::text(json_str)]))
+    }
+
     /// Get available locales and their file paths
     #[tool(description = "Get available locales and their file paths.")]
     pub async fn get_locales(
@@ -555,9 +711,22 @@ impl GlotMcpServer {
         let messages_dir = resolve_messages_dir(Path::new(path), &config.config.messages_root);
         let messages_dir_str = messages_dir.to_string_lossy().to_string();
 
-        let scan_result = scan_message_files(&messages_dir).map_err(|e| {
-            McpError::internal_error(format!("Failed to scan messages: {}", e), None)
-        })?;
+        let message_pattern = config
+            .config
+            .message_pattern
+            .as_ref()
+            .map(|pattern| resolve_messages_dir(Path::new(path), pattern));
+
+        let locale_filter = LocaleFilter::from_config(&config.config);
+        let scan_result = match &message_pattern {
+            Some(pattern) => scan_message_pattern(
+                &pattern.to_string_lossy(),
+                &config.config.key_separator,
+                &locale_filter,
+            ),
+            None => scan_message_files(&messages_dir, &config.config.key_separator, &locale_filter),
+        }
+        .map_err(|e| McpError::internal_error(format!("Failed to scan messages: {}", e), None))?;
 
         let mut locales: Vec<LocaleInfo> = scan_result
             .messages
@@ -566,6 +735,7 @@ impl GlotMcpServer {
                 locale: messages.locale.clone(),
                 file_path: messages.file_path.clone(),
                 key_count: messages.len(),
+                namespace_files: messages.namespace_files.clone(),
             })
             .collect();
 
@@ -573,6 +743,7 @@ impl GlotMcpServer {
 
         let result = LocalesResult {
             messages_dir: messages_dir_str,
+            message_pattern: message_pattern.map(|pattern| pattern.to_string_lossy().to_string()),
             primary_locale: config.config.primary_locale,
             locales,
         };
@@ -614,11 +785,77 @@ fn create_context(path: &str) -> Result<CheckContext, McpError> {
         primary_locale: None,
         messages_root: None,
         verbose: false,
+        jobs: None,
+        cache: false,
+        cache_location: None,
+        include_tests: false,
+        path_style: None,
     };
     CheckContext::new(&common_args)
         .map_err(|e| McpError::internal_error(format!("Failed to initialize: {}", e), None))
 }
 
+/// Parse `glot check` rule names. An empty list selects all rules.
+fn parse_check_rules(names: &[String]) -> Result<Vec<CheckRule>, McpError> {
+    names
+        .iter()
+        .map(|name| {
+            CheckRule::from_str(name, true).map_err(|_| {
+                let valid: Vec<String> = CheckRule::value_variants()
+                    .iter()
+                    .filter_map(|rule| rule.to_possible_value())
+                    .map(|value| value.get_name().to_string())
+                    .collect();
+                McpError::invalid_params(
+                    format!(
+                        "Unknown rule \"{}\". Valid rules: {}",
+                        name,
+                        valid.join(", ")
+                    ),
+                    None,
+                )
+            })
+        })
+        .collect()
+}
+
+fn issue_file_path(issue: &Issue) -> &str {
+    match issue.location() {
+        ReportLocation::Source(ctx) => ctx.file_path(),
+        ReportLocation::Message(ctx) => ctx.file_path(),
+        ReportLocation::File { path } => path,
+    }
+}
+
+fn to_check_item(issue: &Issue, severity: Severity) -> CheckIssueItem {
+    let (line, col) = match issue.location() {
+        ReportLocation::Source(ctx) => (Some(ctx.line()), Some(ctx.col())),
+        ReportLocation::Message(ctx) => (Some(ctx.line()), Some(ctx.col())),
+        ReportLocation::File { .. } => (None, None),
+    };
+    let (usages, total_usages) = to_usage_locations(issue.usages());
+    CheckIssueItem {
+        rule: issue.report_rule().to_string(),
+        severity,
+        message: issue.message(),
+        file_path: issue_file_path(issue).to_string(),
+        line,
+        col,
+        details: issue.details(),
+        hint: issue.hint().map(str::to_string),
+        usages,
+        total_usages,
+    }
+}
+
+/// The inserted comment line and the annotated line, with one line of context on each side.
+fn read_snippet(path: &Path, comment_line: usize) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let start = comment_line.saturating_sub(2);
+    let lines: Vec<&str> = content.lines().skip(start).take(4).collect();
+    Some(lines.join("\n"))
+}
+
 fn to_usage_locations(usages: &[ResolvedKeyUsage]) -> (Vec<KeyUsageLocation>, usize) {
     let total = usages.len();
     let items = usages
@@ -665,7 +902,9 @@ impl ServerHandler for GlotMcpServer {
                  6. scan_replica_lag - Get keys missing from non-primary locales (paginated)\n\
                  7. scan_untranslated - Get values identical to primary locale (paginated)\n\
                  8. scan_type_mismatch - Get type mismatches between locales (paginated)\n\
-                 9. add_translations - Add keys to locale files\n\n\
+                 9. glot_check - Run `glot check` rules and get all issues (paginated, filter by rules or path)\n\
+                 10. add_translations - Add keys to locale files\n\
+                 11. apply_fix - Annotate an unresolved dynamic key with glot-message-keys\n\n\
                  Recommended Workflow:\n\
                  1. Use scan_overview to understand the overall state\n\
                  2. Fix type_mismatch issues FIRST (these cause runtime crashes!)\n\
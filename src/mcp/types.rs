@@ -3,6 +3,8 @@ use std::collections::BTreeMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::issues::Severity;
+
 // ============================================================
 // Tool Input Parameters
 // ============================================================
@@ -79,6 +81,25 @@ pub struct ScanTypeMismatchParams {
     pub offset: Option<u64>,
 }
 
+/// Parameters for glot_check tool
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GlotCheckParams {
+    /// Path to the project root directory
+    pub project_root_path: String,
+    /// Rules to run, using `glot check` names (e.g., "missing", "type-mismatch"). Default: all rules
+    #[serde(default)]
+    pub rules: Option<Vec<String>>,
+    /// Only return issues in this file or directory (absolute or relative to the project root)
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Maximum number of items to return (default: 50, max: 100)
+    #[serde(default)]
+    pub limit: Option<u64>,
+    /// Number of items to skip (default: 0)
+    #[serde(default)]
+    pub offset: Option<u64>,
+}
+
 /// Parameters for get_locales tool
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GetLocalesParams {
@@ -102,6 +123,19 @@ pub struct AddTranslationsParams {
     pub translations: Vec<TranslationEntry>,
 }
 
+/// Parameters for apply_fix tool
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ApplyFixParams {
+    /// Path to the project root directory
+    pub project_root_path: String,
+    /// Source file with the unresolved key (absolute or relative to the project root)
+    pub file_path: String,
+    /// Line of the unresolved key, as reported by glot_check (1-indexed)
+    pub line: u64,
+    /// Keys or patterns the dynamic key can resolve to (e.g., "Common.status.*")
+    pub keys: Vec<String>,
+}
+
 /// A translation entry for a single locale
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TranslationEntry {
@@ -133,12 +167,14 @@ pub struct ConfigValues {
     pub checked_attributes: Vec<String>,
     pub ignore_texts: Vec<String>,
     pub messages_root: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_pattern: Option<String>,
     pub primary_locale: String,
     pub source_root: String,
     pub ignore_test_files: bool,
     pub extra_translation_callees: Vec<String>,
     pub extra_translation_member_calls: Vec<crate::config::TranslationMemberCallPattern>,
-    pub severities: BTreeMap<String, crate::issues::Severity>,
+    pub severities: BTreeMap<String, crate::config::SeverityOverride>,
 }
 
 impl From<crate::config::Config> for ConfigValues {
@@ -150,6 +186,7 @@ impl From<crate::config::Config> for ConfigValues {
             checked_attributes: c.checked_attributes,
             ignore_texts: c.ignore_texts,
             messages_root: c.messages_root,
+            message_pattern: c.message_pattern,
             primary_locale: c.primary_locale,
             source_root: c.source_root,
             ignore_test_files: c.ignore_test_files,
@@ -173,6 +210,10 @@ impl From<crate::config::Config> for ConfigValues {
 #[serde(rename_all = "camelCase")]
 pub struct LocalesResult {
     pub messages_dir: String,
+    /// The resolved `messagePattern`, when locales are loaded from it
+    /// instead of `messagesDir`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_pattern: Option<String>,
     pub primary_locale: String,
     pub locales: Vec<LocaleInfo>,
 }
@@ -184,6 +225,9 @@ pub struct LocaleInfo {
     pub locale: String,
     pub file_path: String,
     pub key_count: usize,
+    /// File of each namespace, for locales split into one file per namespace.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub namespace_files: BTreeMap<String, String>,
 }
 
 // ============================================================
@@ -513,6 +557,66 @@ pub struct TypeMismatchLocale {
     pub line: usize,
 }
 
+// ============================================================
+// Check Types (glot_check)
+// ============================================================
+
+/// Result of glot_check operation
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResult {
+    pub total_count: usize,
+    /// Issues with effective severity error (after config overrides)
+    pub error_count: usize,
+    /// Issues with effective severity warning (after config overrides)
+    pub warning_count: usize,
+    pub items: Vec<CheckIssueItem>,
+    pub pagination: Pagination,
+}
+
+/// A single issue, as reported by `glot check`
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckIssueItem {
+    /// Rule name as shown by `glot check` (e.g., "missing-key", "type-mismatch")
+    pub rule: String,
+    /// Effective severity (after config overrides)
+    pub severity: Severity,
+    /// Main message (the key, text or error)
+    pub message: String,
+    pub file_path: String,
+    /// Line number (absent for file-level issues such as parse errors)
+    pub line: Option<usize>,
+    /// Column number (absent for file-level issues such as parse errors)
+    pub col: Option<usize>,
+    /// Additional details (e.g., which locales are affected)
+    pub details: Option<String>,
+    /// Suggested fix
+    pub hint: Option<String>,
+    /// Locations where this key is used in code (max 3)
+    pub usages: Vec<KeyUsageLocation>,
+    /// Total number of usages (may be more than usages.len())
+    pub total_usages: usize,
+}
+
+// ============================================================
+// Apply Fix Types (apply_fix)
+// ============================================================
+
+/// Result of apply_fix operation
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyFixResult {
+    pub success: bool,
+    pub file_path: String,
+    /// Inserted comment (only on success)
+    pub comment: Option<String>,
+    /// Lines around the change after it was applied (only on success)
+    pub snippet: Option<String>,
+    /// Why the fix was not applied (only on failure)
+    pub error: Option<String>,
+}
+
 // ============================================================
 // Common Types
 // ============================================================
@@ -625,6 +729,23 @@ mod tests {
         assert_eq!(params.offset, None);
     }
 
+    #[test]
+    fn test_glot_check_params() {
+        let json = json!({
+            "project_root_path": "/path",
+            "rules": ["missing", "type-mismatch"],
+            "path": "src/app"
+        });
+        let params: GlotCheckParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.project_root_path, "/path");
+        assert_eq!(
+            params.rules,
+            Some(vec!["missing".to_string(), "type-mismatch".to_string()])
+        );
+        assert_eq!(params.path.as_deref(), Some("src/app"));
+        assert_eq!(params.limit, None);
+    }
+
     #[test]
     fn test_get_locales_params() {
         let json = json!({
@@ -655,7 +776,7 @@ mod tests {
             }],
             severities: [(
                 crate::issues::Rule::Untranslated,
-                crate::issues::Severity::Warning,
+                crate::config::SeverityOverride::Warning,
             )]
             .into_iter()
             .collect(),
@@ -797,6 +918,8 @@ mod tests {
             schemars::schema_for!(ScanPrimaryMissingParams),
             schemars::schema_for!(ScanReplicaLagParams),
             schemars::schema_for!(ScanUntranslatedParams),
+            schemars::schema_for!(GlotCheckParams),
+            schemars::schema_for!(ApplyFixParams),
             schemars::schema_for!(GetLocalesParams),
             schemars::schema_for!(GetConfigParams),
             schemars::schema_for!(AddTranslationsParams),
@@ -0,0 +1,169 @@
+//! Rule documentation for `glot explain`.
+//!
+//! Each rule reported by `check` has a short description, an example of the
+//! problem and guidance on how to fix or suppress it. The lookup is an
+//! exhaustive match, so adding a `Rule` variant requires documenting it.
+
+use crate::issues::Rule;
+
+/// Explanation of a single rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleDoc {
+    /// One-line description of what the rule reports.
+    pub summary: &'static str,
+    /// Example code or message files that trigger the rule.
+    pub example: &'static str,
+    /// How to fix the issue, or how to suppress it when it's intentional.
+    pub fix: &'static str,
+}
+
+/// Documentation of `rule`.
+pub fn rule_doc(rule: Rule) -> RuleDoc {
+    match rule {
+        Rule::HardcodedText => RuleDoc {
+            summary: "User-facing text in JSX or checked attributes that is not translated.",
+            example: "<button>Submit</button>",
+            fix: "Move the text to the message files and render it with t(\"Common.submit\").\n\
+                  Keep intentional text with `// glot-disable-next-line hardcoded`,\n\
+                  or add it to `ignoreTexts` in .glotrc.json.",
+        },
+        Rule::MissingKey => RuleDoc {
+            summary: "A key used in code is not defined in the primary locale.",
+            example: "t(\"Common.submit\") with no \"Common.submit\" in messages/en.json",
+            fix: "Add the key to the primary locale, or fix the typo in the key used in code.",
+        },
+        Rule::UnresolvedKey => RuleDoc {
+            summary: "A dynamic key that can't be resolved statically, so its usage is unknown.",
+            example: "t(`status.${code}`)",
+            fix: "Declare the possible keys with `// glot-message-keys \"status.*\"`,\n\
+                  or run `glot fix` to insert the comment for you.",
+        },
+        Rule::ReplicaLag => RuleDoc {
+            summary: "A key of the primary locale is missing in another locale.",
+            example: "\"Common.submit\" exists in en.json but not in de.json",
+            fix: "Add the translation to every locale listed in the report.",
+        },
+        Rule::UnusedKey => RuleDoc {
+            summary: "A key of the primary locale is never used in code.",
+            example: "\"Common.oldTitle\" in en.json with no t(\"oldTitle\") anywhere",
+            fix: "Remove the key with `glot clean`, or declare dynamic usages with\n\
+                  `// glot-message-keys`.",
+        },
+        Rule::UnusedNamespace => RuleDoc {
+            summary: "A top-level namespace of the primary locale is never referenced.",
+            example: "{\"Legacy\": {...}} in en.json with no useTranslations(\"Legacy\")",
+            fix: "Remove the namespace from every locale, or use it in code.",
+        },
+        Rule::OrphanKey => RuleDoc {
+            summary: "A key in a non-primary locale does not exist in the primary locale.",
+            example: "\"Common.cancel\" exists in de.json but not in en.json",
+            fix: "Remove the key with `glot clean --rules orphan`, or add it to the primary locale.",
+        },
+        Rule::Untranslated => RuleDoc {
+            summary: "A value is identical to the primary locale or empty, so it was \
+                      likely not translated.",
+            example: "\"Common.submit\": \"Submit\" in both en.json and de.json",
+            fix: "Translate the value. When it is legitimately the same (brand names),\n\
+                  add `// glot-disable-next-line untranslated` above the usage.",
+        },
+        Rule::TypeMismatch => RuleDoc {
+            summary: "A key has a different value type across locales.",
+            example: "\"Nav.items\" is an array in en.json but a string in de.json",
+            fix: "Use the same shape (string, array or object) in every locale.",
+        },
+        Rule::PlaceholderMismatch => RuleDoc {
+            summary: "A translation uses different ICU placeholders than the primary locale.",
+            example: "en: \"Hello {name}\", de: \"Hallo {nom}\"",
+            fix: "Use exactly the placeholders of the primary locale in every translation.",
+        },
+        Rule::KeyNaming => RuleDoc {
+            summary: "A key does not follow the casing configured in `keyNaming`.",
+            example: "\"Common.Submit_Button\" with `\"keyNaming\": { \"case\": \"camel\" }`",
+            fix: "Rename the key and its usages. Suppress a single usage with\n\
+                  `// glot-disable-next-line key-naming`.",
+        },
+        Rule::EmptyValue => RuleDoc {
+            summary: "A value is empty or whitespace-only.",
+            example: "\"Common.submit\": \"\"",
+            fix: "Fill in the value, or remove the key if it is no longer needed.",
+        },
+        Rule::PluralCategories => RuleDoc {
+            summary: "An ICU plural is missing or uses categories that don't exist in the locale.",
+            example: "ru: \"{count, plural, one {# товар} other {# товаров}}\" (missing few, many)",
+            fix: "Add the missing CLDR categories and remove the ones the language never selects.",
+        },
+        Rule::MarkupOnly => RuleDoc {
+            summary: "A value is made only of rich text tags without any text.",
+            example: "\"Terms.link\": \"<link></link>\"",
+            fix: "Put the text inside the tags, or render the markup in code instead.\n\
+                  Suppress with `// glot-disable-next-line markup-only`.",
+        },
+        Rule::RichTagMismatch => RuleDoc {
+            summary: "A t.rich or t.markup key uses different tags than the primary locale.",
+            example: "en: \"Read <link>the terms</link>\", fr: \"Lisez les conditions\"",
+            fix: "Use exactly the tags of the primary locale in every translation.",
+        },
+        Rule::UnmatchedMessageKeys => RuleDoc {
+            summary: "A glot-message-keys pattern matches no key of the primary locale.",
+            example: "// glot-message-keys \"Common.statsu.*\" with no \"Common.statsu\" keys",
+            fix: "Fix the typo in the pattern, or remove the stale comment.\n\
+                  Off by default: run `glot check unmatched-message-keys` or set its severity.",
+        },
+        Rule::NamespaceConsistency => RuleDoc {
+            summary: "A key is reached through different namespaces in different places.",
+            example: "t(\"submit\") with useTranslations(\"Common\"), and t(\"Common.submit\")",
+            fix: "Pick one way to reach the key and use it everywhere.\n\
+                  Off by default: run `glot check namespace-consistency` or set its severity.",
+        },
+        Rule::UnpairedDirective => RuleDoc {
+            summary: "A glot-enable closes no glot-disable, or a glot-disable is never closed.",
+            example: "// glot-enable with no // glot-disable above it",
+            fix: "Remove the stray glot-enable, or close the glot-disable where suppression should end.\n\
+                  Turn it off with `\"severities\": { \"unpaired-directive\": \"off\" }`.",
+        },
+        Rule::InterpolationMismatch => RuleDoc {
+            summary: "A t() call passes other values than the ICU arguments of its message.",
+            example: "t(\"greeting\") for \"Hello {name}\", or t(\"title\", { name }) for \"Welcome\"",
+            fix: "Pass a value for every argument, and drop values the message doesn't use.",
+        },
+        Rule::DuplicateValue => RuleDoc {
+            summary: "Many keys of the primary locale hold the same value.",
+            example: "\"Common.save\", \"Form.save\" and \"Dialog.save\" all set to \"Save\"",
+            fix: "Use one shared key for the value and remove the others.\n\
+                  Off by default: run `glot check duplicate-value` or set its severity.",
+        },
+        Rule::StrayWhitespace => RuleDoc {
+            summary: "A value has leading or trailing whitespace, or differs from the primary value only by it.",
+            example: "en: \"Save \", or en: \"OK\" and fr: \"OK \"",
+            fix: "Trim the value. Use CSS for spacing, not whitespace in messages.",
+        },
+        Rule::EmptyKey => RuleDoc {
+            summary: "A translation call has an empty or whitespace-only key.",
+            example: "t(\"\"), t.raw(\"  \") or t(isNew ? \"new\" : \"\")",
+            fix: "Pass the key of the message to translate; adding a message won't help.",
+        },
+        Rule::DuplicateKey => RuleDoc {
+            summary: "A key is defined more than once in the same object of a locale file.",
+            example: "{ \"submit\": \"Send\", \"submit\": \"Submit\" } (only \"Submit\" is loaded)",
+            fix: "Keep a single definition of the key, with the value that should win.",
+        },
+        Rule::ParseError => RuleDoc {
+            summary: "A source or message file could not be parsed, so it was not checked.",
+            example: "A .tsx file with a syntax error, or invalid JSON in en.json",
+            fix: "Fix the syntax error. Run with --verbose to see the parser message.",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_doc_lookup() {
+        let doc = rule_doc(Rule::ReplicaLag);
+        assert!(doc.summary.contains("missing in another locale"));
+        assert!(!doc.example.is_empty());
+        assert!(!doc.fix.is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+//! Duplicate key detection rule.
+//!
+//! Detects keys defined more than once in the same object of a locale file,
+//! like `{"submit": "Send", "submit": "Submit"}`. JSON loaders keep the last
+//! definition, so the earlier ones are lost without any error.
+//!
+//! - Each repeat is reported on its own line, pointing back to the first one
+//! - The same key in different objects (`Common.submit`, `Auth.submit`) is fine
+
+use crate::{
+    core::{AllLocaleMessages, CheckContext},
+    issues::DuplicateKeyIssue,
+};
+
+pub fn check_duplicate_key_issues(ctx: &CheckContext) -> Vec<DuplicateKeyIssue> {
+    check_duplicate_keys(&ctx.messages().all_messages)
+}
+
+/// Check all locales for keys repeated within the same object.
+///
+/// # Arguments
+/// * `all_messages` - All messages from all locales
+///
+/// # Returns
+/// Vector of DuplicateKeyIssue, one per repeated definition, sorted by file
+/// path and line
+pub fn check_duplicate_keys(all_messages: &AllLocaleMessages) -> Vec<DuplicateKeyIssue> {
+    let mut issues: Vec<DuplicateKeyIssue> = all_messages
+        .iter()
+        .flat_map(|(locale, messages)| {
+            messages
+                .duplicate_keys
+                .iter()
+                .map(move |duplicate| DuplicateKeyIssue {
+                    context: duplicate.context.clone(),
+                    locale: locale.clone(),
+                    first_line: duplicate.first_line,
+                })
+        })
+        .collect();
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{DuplicateKey, LocaleMessages, MessageContext, MessageLocation};
+    use crate::rules::duplicate_key::*;
+
+    fn duplicate(file: &str, key: &str, first_line: usize, line: usize) -> DuplicateKey {
+        DuplicateKey {
+            context: MessageContext::new(MessageLocation::with_line(file, line), key, "Submit"),
+            first_line,
+        }
+    }
+
+    #[test]
+    fn test_reports_every_repeat_in_every_locale() {
+        let mut en = LocaleMessages::new("en", "./messages/en.json");
+        en.duplicate_keys = vec![
+            duplicate("./messages/en.json", "Common.submit", 3, 9),
+            duplicate("./messages/en.json", "Common.submit", 3, 5),
+        ];
+        let mut ja = LocaleMessages::new("ja", "./messages/ja.json");
+        ja.duplicate_keys = vec![duplicate("./messages/ja.json", "title", 2, 4)];
+        let fr = LocaleMessages::new("fr", "./messages/fr.json");
+
+        let all_messages: AllLocaleMessages = HashMap::from([
+            ("en".to_string(), en),
+            ("ja".to_string(), ja),
+            ("fr".to_string(), fr),
+        ]);
+
+        let issues = check_duplicate_keys(&all_messages);
+        let reported: Vec<(&str, usize, usize)> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.locale.as_str(),
+                    issue.first_line,
+                    issue.context.line(),
+                )
+            })
+            .collect();
+        assert_eq!(reported, vec![("en", 3, 5), ("en", 3, 9), ("ja", 2, 4)]);
+    }
+}
@@ -0,0 +1,148 @@
+//! Duplicate value detection rule.
+//!
+//! Detects values of the primary locale shared by many keys, like five keys
+//! all holding `"Save"`. Each is translated in every locale and shipped in
+//! every bundle, so they are candidates for a single shared key.
+//!
+//! - Values are compared after trimming and collapsing whitespace; case matters
+//! - Only string values are compared, and empty values are skipped (covered by
+//!   empty-value)
+//! - A value is reported once `duplicateValue.minKeys` keys (3 by default)
+//!   share it
+
+use std::collections::BTreeMap;
+
+use crate::{
+    core::{CheckContext, LocaleMessages, MessageEntry, ValueType},
+    issues::DuplicateValueIssue,
+};
+
+pub fn check_duplicate_value_issues(ctx: &CheckContext) -> Vec<DuplicateValueIssue> {
+    check_duplicate_values(
+        &ctx.messages().primary_messages,
+        ctx.config.duplicate_value.min_keys,
+    )
+}
+
+/// Check the primary locale for values shared by at least `min_keys` keys.
+///
+/// The issue is reported at the first of the keys, by file and line; the
+/// others are listed with it.
+///
+/// # Arguments
+/// * `primary_messages` - Messages from the primary locale
+/// * `min_keys` - Minimum number of keys sharing a value
+///
+/// # Returns
+/// Vector of DuplicateValueIssue, sorted by file path and line
+pub fn check_duplicate_values(
+    primary_messages: &LocaleMessages,
+    min_keys: usize,
+) -> Vec<DuplicateValueIssue> {
+    let mut by_value: BTreeMap<String, Vec<&MessageEntry>> = BTreeMap::new();
+    for entry in primary_messages.entries.values() {
+        if entry.value_type != ValueType::String {
+            continue;
+        }
+        let value = normalize(&entry.context.value);
+        if !value.is_empty() {
+            by_value.entry(value).or_default().push(entry);
+        }
+    }
+
+    let mut issues: Vec<DuplicateValueIssue> = by_value
+        .into_iter()
+        .filter(|(_, entries)| entries.len() >= min_keys)
+        .map(|(value, mut entries)| {
+            entries.sort_by(|a, b| {
+                let (a, b) = (&a.context, &b.context);
+                a.location
+                    .file_path
+                    .cmp(&b.location.file_path)
+                    .then_with(|| a.location.line.cmp(&b.location.line))
+                    .then_with(|| a.key.cmp(&b.key))
+            });
+            DuplicateValueIssue {
+                context: entries[0].context.clone(),
+                value,
+                other_keys: entries[1..]
+                    .iter()
+                    .map(|entry| entry.context.key.clone())
+                    .collect(),
+            }
+        })
+        .collect();
+
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+/// Trim the value and collapse runs of whitespace into one space.
+fn normalize(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{MessageContext, MessageLocation};
+    use crate::rules::duplicate_value::*;
+
+    fn messages(entries: &[(&str, &str)]) -> LocaleMessages {
+        let mut messages = LocaleMessages::new("en", "./messages/en.json");
+        for (i, (key, value)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                key.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line("./messages/en.json", i + 2),
+                        *key,
+                        *value,
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    #[test]
+    fn test_reports_values_shared_by_min_keys() {
+        let primary = messages(&[
+            ("Common.save", "Save"),
+            ("Form.save", " Save "),
+            ("Dialog.save", "Save"),
+            ("Dialog.saveLower", "save"),
+            ("Common.cancel", "Cancel"),
+            ("Form.cancel", "Cancel"),
+            ("Common.empty", ""),
+            ("Form.empty", ""),
+            ("Dialog.empty", " "),
+        ]);
+
+        let issues = check_duplicate_values(&primary, 3);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].context.key, "Common.save");
+        assert_eq!(issues[0].value, "Save");
+        assert_eq!(issues[0].other_keys, vec!["Form.save", "Dialog.save"]);
+
+        let values: Vec<String> = check_duplicate_values(&primary, 2)
+            .into_iter()
+            .map(|issue| issue.value)
+            .collect();
+        assert_eq!(values, vec!["Save", "Cancel"]);
+    }
+
+    #[test]
+    fn test_normalize_collapses_whitespace() {
+        assert_eq!(normalize("  Save\n  changes "), "Save changes");
+        assert_eq!(normalize(" \t"), "");
+    }
+}
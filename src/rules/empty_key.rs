@@ -0,0 +1,111 @@
+//! Empty key detection rule.
+//!
+//! Detects translation calls whose key is an empty or whitespace-only
+//! literal, like `t("")` or `t.raw("  ")`. Such a call is never a missing key:
+//! an empty key looks up the namespace itself rather than a message, so the
+//! call has to be fixed rather than the locale files.
+//!
+//! - Every branch of a conditional key is checked, so `t(isNew ? "new" : "")`
+//!   is reported for its empty branch while `"new"` is still resolved
+//! - Dynamic keys are left to unresolved-key
+
+use std::collections::HashSet;
+
+use crate::{
+    core::{AllKeyUsages, CheckContext, collect::SuppressibleRule},
+    issues::EmptyKeyIssue,
+};
+
+pub fn check_empty_key_issues(ctx: &CheckContext) -> Vec<EmptyKeyIssue> {
+    check_empty_key(ctx.all_key_usages())
+}
+
+/// Check for translation calls with an empty or whitespace-only key.
+///
+/// A key is reported once per call, even when the call has several
+/// namespaces. Calls on lines disabled with `glot-disable` comments are
+/// skipped.
+///
+/// # Arguments
+/// * `all_key_usages` - All key usages extracted from source files
+///
+/// # Returns
+/// Vector of EmptyKeyIssue, sorted by file path, line and column
+pub fn check_empty_key(all_key_usages: &AllKeyUsages) -> Vec<EmptyKeyIssue> {
+    let mut seen = HashSet::new();
+    let mut issues: Vec<EmptyKeyIssue> = all_key_usages
+        .values()
+        .flat_map(|file_usages| &file_usages.empty_keys)
+        .filter(|usage| !usage.suppressed_rules.contains(&SuppressibleRule::EmptyKey))
+        .filter(|usage| {
+            seen.insert((
+                usage.context.file_path().to_string(),
+                usage.context.line(),
+                usage.context.col(),
+                usage.key.clone(),
+            ))
+        })
+        .map(|usage| EmptyKeyIssue {
+            context: usage.context.clone(),
+            key: usage.key.clone(),
+        })
+        .collect();
+
+    issues.sort_by(|a, b| {
+        a.context
+            .file_path()
+            .cmp(b.context.file_path())
+            .then_with(|| a.context.line().cmp(&b.context.line()))
+            .then_with(|| a.context.col().cmp(&b.context.col()))
+    });
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{CommentStyle, EmptyKeyUsage, FileKeyUsages, SourceContext, SourceLocation};
+    use crate::rules::empty_key::*;
+
+    fn empty_key(line: usize, key: &str, suppressed: bool) -> EmptyKeyUsage {
+        EmptyKeyUsage {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", line, 5),
+                "t(\"\")",
+                CommentStyle::Js,
+            ),
+            key: key.to_string(),
+            suppressed_rules: if suppressed {
+                HashSet::from([SuppressibleRule::EmptyKey])
+            } else {
+                HashSet::new()
+            },
+        }
+    }
+
+    #[test]
+    fn test_reports_each_call_once() {
+        let mut all_key_usages: AllKeyUsages = HashMap::new();
+        all_key_usages.insert(
+            "./src/app.tsx".to_string(),
+            FileKeyUsages {
+                empty_keys: vec![
+                    empty_key(4, "  ", false),
+                    empty_key(4, "  ", false),
+                    empty_key(2, "", false),
+                    empty_key(8, "", true),
+                ],
+                ..Default::default()
+            },
+        );
+
+        let issues = check_empty_key(&all_key_usages);
+        let reported: Vec<(usize, &str)> = issues
+            .iter()
+            .map(|issue| (issue.context.line(), issue.key.as_str()))
+            .collect();
+        assert_eq!(reported, vec![(2, ""), (4, "  ")]);
+    }
+}
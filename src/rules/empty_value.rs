@@ -0,0 +1,182 @@
+//! Empty value detection rule.
+//!
+//! Detects translation values that are empty or whitespace-only strings, or
+//! empty arrays (for `t.raw()` keys). These pass JSON validation but render
+//! as blank UI.
+//!
+//! - Empty in the primary locale: error, reported once for the primary file
+//! - Empty in a replica only: warning, the value is likely not translated yet
+
+use crate::{
+    core::{
+        AllLocaleMessages, CheckContext, LocaleMessages, MessageContext, MessageEntry, ValueType,
+    },
+    issues::EmptyValueIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key},
+    },
+};
+
+pub fn check_empty_value_issues(ctx: &CheckContext) -> Vec<EmptyValueIssue> {
+    let primary_locale = &ctx.config.primary_locale;
+    let all_messages = &ctx.messages().all_messages;
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_empty_values(primary_locale, all_messages, &key_usages_map)
+}
+
+/// Check all locales for empty values.
+///
+/// When the primary value is empty, replicas are not reported for the same
+/// key, since fixing the primary comes first.
+///
+/// # Arguments
+/// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `all_messages` - All messages from all locales
+/// * `key_usages` - Map of key to usage locations (for showing where keys are used)
+///
+/// # Returns
+/// Vector of EmptyValueIssue, one per empty value
+pub fn check_empty_values(
+    primary_locale: &str,
+    all_messages: &AllLocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<EmptyValueIssue> {
+    let primary_messages = all_messages.get(primary_locale);
+    let empty_in_primary =
+        |key: &str| primary_messages.is_some_and(|msgs| has_empty_value(msgs, key));
+
+    let mut issues: Vec<EmptyValueIssue> = all_messages
+        .iter()
+        .flat_map(|(locale, messages)| {
+            let is_primary = locale == primary_locale;
+            let empty_strings = messages
+                .entries
+                .iter()
+                .filter(|(_, entry)| is_empty(entry))
+                .map(|(key, entry)| (key, entry.context.clone(), entry.value_type));
+            let empty_arrays = messages.empty_arrays.iter().map(|(key, location)| {
+                let context = MessageContext::new(location.clone(), key.clone(), "");
+                (key, context, ValueType::StringArray)
+            });
+            empty_strings
+                .chain(empty_arrays)
+                .filter(move |(key, _, _)| is_primary || !empty_in_primary(key))
+                .map(move |(key, context, value_type)| EmptyValueIssue {
+                    context,
+                    locale: locale.clone(),
+                    is_primary,
+                    value_type,
+                    usages: get_usages_for_key(key_usages, key),
+                })
+        })
+        .collect();
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+fn is_empty(entry: &MessageEntry) -> bool {
+    entry.context.value.trim().is_empty()
+}
+
+fn has_empty_value(messages: &LocaleMessages, key: &str) -> bool {
+    messages.empty_arrays.contains_key(key) || messages.get(key).is_some_and(is_empty)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType};
+    use crate::rules::empty_value::*;
+
+    fn create_message_map(file: &str, entries: &[(&str, &str, ValueType)]) -> LocaleMessages {
+        let locale = file.trim_end_matches(".json");
+        let mut messages = LocaleMessages::new(locale, file);
+        for (i, (k, v, value_type)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(file, i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: *value_type,
+                },
+            );
+        }
+        messages
+    }
+
+    fn run(en: &[(&str, &str, ValueType)], zh: &[(&str, &str, ValueType)]) -> Vec<EmptyValueIssue> {
+        let mut all = HashMap::new();
+        all.insert("en".to_string(), create_message_map("en.json", en));
+        all.insert("zh".to_string(), create_message_map("zh.json", zh));
+        check_empty_values("en", &all, &KeyUsageMap::new())
+    }
+
+    #[test]
+    fn test_no_empty_values() {
+        let issues = run(
+            &[("Common.ok", "OK", ValueType::String)],
+            &[("Common.ok", "好", ValueType::String)],
+        );
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_empty_in_replica_only() {
+        let issues = run(
+            &[("Common.ok", "OK", ValueType::String)],
+            &[("Common.ok", "   ", ValueType::String)],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].locale, "zh");
+        assert!(!issues[0].is_primary);
+        assert_eq!(issues[0].context.file_path(), "zh.json");
+    }
+
+    #[test]
+    fn test_empty_in_primary_skips_replicas() {
+        let issues = run(
+            &[("Common.ok", "", ValueType::String)],
+            &[("Common.ok", "", ValueType::String)],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].locale, "en");
+        assert!(issues[0].is_primary);
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let mut all = HashMap::new();
+        all.insert(
+            "en".to_string(),
+            create_message_map("en.json", &[("Page.tags", "a, b", ValueType::StringArray)]),
+        );
+        let mut zh = create_message_map("zh.json", &[]);
+        zh.empty_arrays.insert(
+            "Page.tags".to_string(),
+            MessageLocation::with_line("zh.json", 3),
+        );
+        all.insert("zh".to_string(), zh);
+
+        let issues = check_empty_values("en", &all, &KeyUsageMap::new());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].value_type, ValueType::StringArray);
+        assert_eq!(issues[0].context.key, "Page.tags");
+        assert_eq!(issues[0].context.line(), 3);
+    }
+}
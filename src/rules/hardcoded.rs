@@ -1,15 +1,23 @@
 //! Hardcoded text detection rule.
 //!
 //! Detects untranslated text in JSX/TSX files that should use i18n translation functions.
+//!
+//! Text shorter than `hardcoded.minLength` or matching one of
+//! `hardcoded.ignorePatterns` is not reported, so symbols like `-` or `×`
+//! can be skipped.
+
+use regex::Regex;
 
 use crate::{
+    config::HardcodedConfig,
     core::{AllHardcodedTextIssues, CheckContext},
     issues::HardcodedTextIssue,
+    utils::grapheme_count,
 };
 
 pub fn check_hardcoded_text_issues(ctx: &CheckContext) -> Vec<HardcodedTextIssue> {
     let hardcoded_issues = ctx.hardcoded_issues();
-    check_hardcoded_text(hardcoded_issues)
+    check_hardcoded_text(hardcoded_issues, &ctx.config.hardcoded)
 }
 
 /// Check for hardcoded text issues.
@@ -19,11 +27,28 @@ pub fn check_hardcoded_text_issues(ctx: &CheckContext) -> Vec<HardcodedTextIssue
 ///
 /// # Arguments
 /// * `hardcoded_issues` - All hardcoded text issues from the extraction phase
+/// * `config` - Minimum length and ignore patterns applied before reporting
 ///
 /// # Returns
 /// Vector of HardcodedIssue for reporting
-pub fn check_hardcoded_text(hardcoded_issues: &AllHardcodedTextIssues) -> Vec<HardcodedTextIssue> {
-    hardcoded_issues.values().flatten().cloned().collect()
+pub fn check_hardcoded_text(
+    hardcoded_issues: &AllHardcodedTextIssues,
+    config: &HardcodedConfig,
+) -> Vec<HardcodedTextIssue> {
+    // Patterns are validated when the config is loaded
+    let ignore_patterns = config.ignore_regexes().unwrap_or_default();
+    hardcoded_issues
+        .values()
+        .flatten()
+        .filter(|issue| is_reported(&issue.text, config.min_length, &ignore_patterns))
+        .cloned()
+        .collect()
+}
+
+fn is_reported(text: &str, min_length: usize, ignore_patterns: &[Regex]) -> bool {
+    let text = text.trim();
+    grapheme_count(text) >= min_length
+        && !ignore_patterns.iter().any(|pattern| pattern.is_match(text))
 }
 
 #[cfg(test)]
@@ -56,7 +81,7 @@ mod tests {
     #[test]
     fn test_check_hardcoded_empty() {
         let hardcoded_issues: AllHardcodedTextIssues = HashMap::new();
-        let issues = check_hardcoded_text(&hardcoded_issues);
+        let issues = check_hardcoded_text(&hardcoded_issues, &HardcodedConfig::default());
         assert!(issues.is_empty());
     }
 
@@ -71,7 +96,7 @@ mod tests {
             ],
         );
 
-        let issues = check_hardcoded_text(&hardcoded_issues);
+        let issues = check_hardcoded_text(&hardcoded_issues, &HardcodedConfig::default());
         assert_eq!(issues.len(), 2);
     }
 
@@ -87,7 +112,7 @@ mod tests {
             vec![create_old_hardcoded_issue("b.tsx", 2, 2, "Text B", true)],
         );
 
-        let issues = check_hardcoded_text(&hardcoded_issues);
+        let issues = check_hardcoded_text(&hardcoded_issues, &HardcodedConfig::default());
         assert_eq!(issues.len(), 2);
     }
 
@@ -99,7 +124,7 @@ mod tests {
             vec![create_old_hardcoded_issue("test.tsx", 10, 5, "Hello", true)],
         );
 
-        let issues = check_hardcoded_text(&hardcoded_issues);
+        let issues = check_hardcoded_text(&hardcoded_issues, &HardcodedConfig::default());
         assert_eq!(issues.len(), 1);
 
         let issue = &issues[0];
@@ -109,4 +134,45 @@ mod tests {
         assert_eq!(issue.text, "Hello");
         assert!(issue.context.comment_style.is_jsx());
     }
+
+    #[test]
+    fn test_check_hardcoded_min_length() {
+        let mut hardcoded_issues: AllHardcodedTextIssues = HashMap::new();
+        hardcoded_issues.insert(
+            "test.tsx".to_string(),
+            vec![
+                create_old_hardcoded_issue("test.tsx", 1, 1, "x", true),
+                create_old_hardcoded_issue("test.tsx", 2, 1, " é ", true),
+                create_old_hardcoded_issue("test.tsx", 3, 1, "Go", true),
+            ],
+        );
+        let config = HardcodedConfig {
+            min_length: 2,
+            ..HardcodedConfig::default()
+        };
+
+        let issues = check_hardcoded_text(&hardcoded_issues, &config);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].text, "Go");
+    }
+
+    #[test]
+    fn test_check_hardcoded_ignore_patterns() {
+        let mut hardcoded_issues: AllHardcodedTextIssues = HashMap::new();
+        hardcoded_issues.insert(
+            "test.tsx".to_string(),
+            vec![
+                create_old_hardcoded_issue("test.tsx", 1, 1, "v2.0", true),
+                create_old_hardcoded_issue("test.tsx", 2, 1, "Hello", true),
+            ],
+        );
+        let config = HardcodedConfig {
+            ignore_patterns: vec![r"^v\d".to_string()],
+            ..HardcodedConfig::default()
+        };
+
+        let issues = check_hardcoded_text(&hardcoded_issues, &config);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].text, "Hello");
+    }
 }
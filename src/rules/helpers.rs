@@ -3,10 +3,13 @@
 //! This module provides shared utilities used by multiple rules:
 //! - `KeyUsageMap`: maps translation keys to their usage locations
 //! - Builder functions for these maps
+//! - Source locale resolution for `fallbackLocales`
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::core::{AllKeyUsages, ResolvedKeyUsage};
+use crate::core::{
+    AllKeyUsages, AllLocaleMessages, LocaleMessages, MessageEntry, ResolvedKeyUsage,
+};
 
 /// Type alias for key usage map: full_key -> list of resolved key usages.
 pub type KeyUsageMap = HashMap<String, Vec<ResolvedKeyUsage>>;
@@ -15,6 +18,10 @@ pub type KeyUsageMap = HashMap<String, Vec<ResolvedKeyUsage>>;
 ///
 /// This is used by replica-lag, untranslated, and type-mismatch rules
 /// to show where keys are referenced.
+///
+/// Suppressed usages are kept, with their `suppressed_rules`: a
+/// `glot-disable` comment only hides the issues of the rules it names, so
+/// each rule filters the usages it cares about.
 pub fn build_key_usage_map(extractions: &AllKeyUsages) -> KeyUsageMap {
     let mut map: KeyUsageMap = HashMap::new();
 
@@ -51,10 +58,71 @@ pub fn get_usages_for_key(key_usages: &KeyUsageMap, key: &str) -> Vec<ResolvedKe
     }
 }
 
+/// Get usages of a key and of the keys nested under it, like `Page.hero.title`
+/// for the object `Page.hero`.
+pub fn get_usages_under_key(
+    key_usages: &KeyUsageMap,
+    key: &str,
+    separator: &str,
+) -> Vec<ResolvedKeyUsage> {
+    let prefix = format!("{}{}", key, separator);
+    let mut usages: Vec<ResolvedKeyUsage> = key_usages
+        .iter()
+        .filter(|(used_key, _)| *used_key == key || used_key.starts_with(&prefix))
+        .flat_map(|(_, usages)| usages.iter().cloned())
+        .collect();
+    usages.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.location.col.cmp(&b.context.location.col))
+    });
+    usages
+}
+
+/// Whether `locale` is the primary locale or one of the fallback locales.
+pub fn is_source_locale(locale: &str, primary_locale: &str, fallback_locales: &[String]) -> bool {
+    locale == primary_locale || fallback_locales.iter().any(|l| l == locale)
+}
+
+/// Every key of the source locales with the locale and entry it resolves to.
+///
+/// Keys of `primary_messages` resolve to the primary locale. Keys the primary
+/// locale lacks resolve to the first fallback locale that defines them.
+pub fn resolve_source_entries<'a>(
+    primary_locale: &'a str,
+    primary_messages: &'a LocaleMessages,
+    fallback_locales: &'a [String],
+    all_messages: &'a AllLocaleMessages,
+) -> Vec<(&'a String, &'a str, &'a MessageEntry)> {
+    let mut entries: Vec<(&String, &str, &MessageEntry)> = primary_messages
+        .entries
+        .iter()
+        .map(|(key, entry)| (key, primary_locale, entry))
+        .collect();
+
+    let mut seen: HashSet<&str> = HashSet::new();
+    for locale in fallback_locales {
+        let Some(messages) = all_messages.get(locale) else {
+            continue;
+        };
+        for (key, entry) in &messages.entries {
+            if !primary_messages.contains_key(key) && seen.insert(key) {
+                entries.push((key, locale, entry));
+            }
+        }
+    }
+
+    entries
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
+    use crate::core::collect::SuppressibleRule;
     use crate::core::{CommentStyle, SourceContext, SourceLocation};
     use crate::core::{FileKeyUsages, FullKey};
     use crate::rules::helpers::*;
@@ -69,6 +137,8 @@ mod tests {
             ),
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         }
     }
 
@@ -95,6 +165,8 @@ mod tests {
                         ),
                         suppressed_rules: HashSet::new(),
                         from_schema: None,
+                        rich_text: false,
+                        namespace: None,
                     },
                     ResolvedKeyUsage {
                         key: FullKey::new("Common.submit"),
@@ -105,9 +177,12 @@ mod tests {
                         ),
                         suppressed_rules: HashSet::new(),
                         from_schema: None,
+                        rich_text: false,
+                        namespace: None,
                     },
                 ],
                 unresolved: Vec::new(),
+                ..Default::default()
             },
         );
 
@@ -119,6 +194,30 @@ mod tests {
         assert_eq!(usages[1].context.location.line, 20);
     }
 
+    #[test]
+    fn test_build_key_usage_map_keeps_suppressed_usages() {
+        let mut suppressed = make_usage("test.tsx", 3, 5);
+        suppressed.suppressed_rules = [SuppressibleRule::Hardcoded].into_iter().collect();
+        let mut extractions: AllKeyUsages = HashMap::new();
+        extractions.insert(
+            "test.tsx".to_string(),
+            FileKeyUsages {
+                resolved: vec![suppressed],
+                unresolved: Vec::new(),
+                ..Default::default()
+            },
+        );
+
+        let map = build_key_usage_map(&extractions);
+        let usages = map.get("Common.submit").unwrap();
+        assert_eq!(usages.len(), 1);
+        assert!(
+            usages[0]
+                .suppressed_rules
+                .contains(&SuppressibleRule::Hardcoded)
+        );
+    }
+
     #[test]
     fn test_get_usages_for_key_found() {
         let mut map: KeyUsageMap = HashMap::new();
@@ -141,4 +240,21 @@ mod tests {
         let usages = get_usages_for_key(&map, "Common.submit");
         assert!(usages.is_empty());
     }
+
+    #[test]
+    fn test_get_usages_under_key() {
+        let mut map: KeyUsageMap = HashMap::new();
+        map.insert(
+            "Page.hero.title".to_string(),
+            vec![make_usage("b.tsx", 2, 1)],
+        );
+        map.insert("Page.hero".to_string(), vec![make_usage("a.tsx", 1, 1)]);
+        map.insert("Page.heroes".to_string(), vec![make_usage("c.tsx", 3, 1)]);
+
+        let files: Vec<String> = get_usages_under_key(&map, "Page.hero", ".")
+            .iter()
+            .map(|usage| usage.context.location.file_path.clone())
+            .collect();
+        assert_eq!(files, vec!["a.tsx", "b.tsx"]);
+    }
 }
@@ -0,0 +1,185 @@
+//! Interpolation mismatch detection rule.
+//!
+//! Compares the values passed with a key, like `{ name }` in
+//! `t("greeting", { name })`, with the ICU arguments of the key's value in the
+//! primary locale (`"Hello {name}"`). next-intl throws when an argument has no
+//! value; a value the message doesn't use is harmless but usually a leftover.
+//!
+//! - Only direct `t()` calls with a static key are checked; dynamic keys,
+//!   `t.rich`/`t.markup` (whose values include tag handlers) and values that
+//!   can't be listed statically (variables, spreads) are skipped
+//! - Missing values are errors, unused values are warnings
+//! - Only runs for next-intl, whose messages use ICU arguments
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::{
+    config::Framework,
+    core::{
+        AllKeyUsages, CheckContext, LocaleMessages, ValueType, collect::SuppressibleRule,
+        utils::parse_icu_arguments,
+    },
+    issues::InterpolationMismatchIssue,
+};
+
+pub fn check_interpolation_mismatch_issues(ctx: &CheckContext) -> Vec<InterpolationMismatchIssue> {
+    if ctx.config.framework != Framework::NextIntl {
+        return Vec::new();
+    }
+    check_interpolation_mismatch(&ctx.messages().primary_messages, ctx.all_key_usages())
+}
+
+/// Check the values passed with each key against its ICU arguments.
+///
+/// Keys missing from the primary locale (covered by missing-key) and non-string
+/// values are skipped, as are calls on lines disabled with `glot-disable`
+/// comments.
+///
+/// # Arguments
+/// * `primary_messages` - Messages from the primary locale
+/// * `all_key_usages` - All key usages extracted from source files
+///
+/// # Returns
+/// Vector of InterpolationMismatchIssue, sorted by file path, line and key
+pub fn check_interpolation_mismatch(
+    primary_messages: &LocaleMessages,
+    all_key_usages: &AllKeyUsages,
+) -> Vec<InterpolationMismatchIssue> {
+    let mut seen = HashSet::new();
+    let mut issues: Vec<InterpolationMismatchIssue> = all_key_usages
+        .values()
+        .flat_map(|file_usages| &file_usages.interpolations)
+        .filter(|usage| {
+            !usage
+                .suppressed_rules
+                .contains(&SuppressibleRule::InterpolationMismatch)
+        })
+        .filter_map(|usage| {
+            let entry = primary_messages.get(usage.key.as_str())?;
+            if entry.value_type != ValueType::String {
+                return None;
+            }
+
+            let arguments: BTreeSet<String> = parse_icu_arguments(&entry.context.value)
+                .into_keys()
+                .collect();
+            let values: BTreeSet<String> = usage.values.iter().cloned().collect();
+            let missing: Vec<String> = arguments.difference(&values).cloned().collect();
+            let extra: Vec<String> = values.difference(&arguments).cloned().collect();
+            if missing.is_empty() && extra.is_empty() {
+                return None;
+            }
+
+            seen.insert((
+                usage.context.file_path().to_string(),
+                usage.context.line(),
+                usage.context.col(),
+                usage.key.as_str().to_string(),
+            ))
+            .then(|| InterpolationMismatchIssue {
+                context: usage.context.clone(),
+                key: usage.key.as_str().to_string(),
+                missing,
+                extra,
+            })
+        })
+        .collect();
+
+    issues.sort_by(|a, b| {
+        a.context
+            .file_path()
+            .cmp(b.context.file_path())
+            .then_with(|| a.context.line().cmp(&b.context.line()))
+            .then_with(|| a.context.col().cmp(&b.context.col()))
+            .then_with(|| a.key.cmp(&b.key))
+    });
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{
+        CommentStyle, FileKeyUsages, FullKey, InterpolationUsage, MessageContext, MessageEntry,
+        MessageLocation, SourceContext, SourceLocation,
+    };
+    use crate::issues::Severity;
+    use crate::rules::interpolation_mismatch::*;
+
+    fn messages(entries: &[(&str, &str)]) -> LocaleMessages {
+        let mut messages = LocaleMessages::new("en", "./messages/en.json");
+        for (i, (key, value)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                key.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line("./messages/en.json", i + 2),
+                        *key,
+                        *value,
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn usage(line: usize, key: &str, values: &[&str], suppressed: bool) -> InterpolationUsage {
+        InterpolationUsage {
+            key: FullKey::new(key),
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", line, 5),
+                "",
+                CommentStyle::Jsx,
+            ),
+            values: values.iter().map(|v| v.to_string()).collect(),
+            suppressed_rules: if suppressed {
+                HashSet::from([SuppressibleRule::InterpolationMismatch])
+            } else {
+                HashSet::new()
+            },
+        }
+    }
+
+    #[test]
+    fn test_reports_missing_and_unused_values() {
+        let primary = messages(&[
+            ("greeting", "Hello {name}"),
+            ("items", "{count, plural, one {# item} other {# items}}"),
+            ("title", "Welcome"),
+            ("bye", "Bye {name}"),
+        ]);
+        let mut all_key_usages: AllKeyUsages = HashMap::new();
+        all_key_usages.insert(
+            "./src/app.tsx".to_string(),
+            FileKeyUsages {
+                interpolations: vec![
+                    usage(2, "greeting", &["name"], false),
+                    usage(3, "items", &[], false),
+                    usage(4, "title", &["name"], false),
+                    usage(5, "bye", &["name", "extra"], false),
+                    usage(6, "bye", &[], true),
+                    usage(7, "unknown", &["name"], false),
+                ],
+                ..Default::default()
+            },
+        );
+
+        let issues = check_interpolation_mismatch(&primary, &all_key_usages);
+        let lines: Vec<usize> = issues.iter().map(|issue| issue.context.line()).collect();
+        assert_eq!(lines, vec![3, 4, 5]);
+
+        assert_eq!(issues[0].missing, vec!["count"]);
+        assert!(issues[0].extra.is_empty());
+        assert_eq!(issues[0].default_severity(), Severity::Error);
+
+        assert!(issues[1].missing.is_empty());
+        assert_eq!(issues[1].extra, vec!["name"]);
+        assert_eq!(issues[1].default_severity(), Severity::Warning);
+
+        assert_eq!(issues[2].extra, vec!["extra"]);
+        assert_eq!(issues[2].default_severity(), Severity::Warning);
+    }
+}
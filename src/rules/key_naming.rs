@@ -0,0 +1,245 @@
+//! Key naming convention rule.
+//!
+//! Detects keys in the primary locale whose segments don't follow the casing
+//! configured in `keyNaming`. For example, with `"case": "camel"`, the key
+//! `settings.Submit_button` is reported for the segment `Submit_button`.
+//!
+//! The rule is off unless `keyNaming` is set. Like `untranslated`, a key can be
+//! skipped with `glot-disable-next-line key-naming` at its usages in code.
+
+use crate::{
+    config::{KeyCase, KeyNamingConfig},
+    core::{
+        CheckContext, LocaleMessages, MessageContext, MessageLocation, ResolvedKeyUsage,
+        collect::SuppressibleRule,
+    },
+    issues::KeyNamingIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key},
+    },
+};
+
+pub fn check_key_naming_issues(ctx: &CheckContext) -> Vec<KeyNamingIssue> {
+    let Some(naming) = &ctx.config.key_naming else {
+        return Vec::new();
+    };
+    let primary_messages = &ctx.messages().primary_messages;
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_key_naming(naming, primary_messages, &key_usages_map)
+}
+
+/// Check every key of the primary locale against the naming convention.
+///
+/// Only the first non-conforming segment of each key is reported. Numeric
+/// segments (array indices like `items.0.title`) are always accepted.
+///
+/// # Arguments
+/// * `naming` - The configured convention
+/// * `primary_messages` - Messages from the primary locale
+/// * `key_usages` - Map of key to usage locations (for suppression and display)
+///
+/// # Returns
+/// Vector of KeyNamingIssue for keys violating the convention
+pub fn check_key_naming(
+    naming: &KeyNamingConfig,
+    primary_messages: &LocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<KeyNamingIssue> {
+    let mut issues: Vec<KeyNamingIssue> = primary_messages
+        .entries
+        .iter()
+        .filter_map(|(key, entry)| {
+            let segment = key
+                .split(naming.separator.as_str())
+                .find(|segment| !segment_matches(segment, naming.case))?;
+
+            let all_usages = get_usages_for_key(key_usages, key);
+            let usages: Vec<ResolvedKeyUsage> = all_usages
+                .iter()
+                .filter(|u| !u.suppressed_rules.contains(&SuppressibleRule::KeyNaming))
+                .cloned()
+                .collect();
+            // Every usage opted out of this rule
+            if usages.is_empty() && !all_usages.is_empty() {
+                return None;
+            }
+
+            Some(KeyNamingIssue {
+                context: MessageContext::new(
+                    MessageLocation::new(
+                        &entry.context.location.file_path,
+                        entry.context.location.line,
+                        1,
+                    ),
+                    key.clone(),
+                    entry.context.value.clone(),
+                ),
+                segment: segment.to_string(),
+                expected_case: naming.case,
+                usages,
+            })
+        })
+        .collect();
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+/// Check whether a single key segment follows the case.
+fn segment_matches(segment: &str, case: KeyCase) -> bool {
+    if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+    let Some(first) = segment.chars().next() else {
+        return false;
+    };
+
+    match case {
+        KeyCase::Camel => {
+            first.is_ascii_lowercase() && segment.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+        KeyCase::Pascal => {
+            first.is_ascii_uppercase() && segment.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+        KeyCase::Snake => is_delimited_lowercase(segment, '_'),
+        KeyCase::Kebab => is_delimited_lowercase(segment, '-'),
+    }
+}
+
+/// Lowercase words joined by single `delimiter`s, starting with a letter.
+fn is_delimited_lowercase(segment: &str, delimiter: char) -> bool {
+    segment.starts_with(|c: char| c.is_ascii_lowercase())
+        && segment.split(delimiter).all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::core::{
+        CommentStyle, FullKey, MessageEntry, SourceContext, SourceLocation, ValueType,
+    };
+    use crate::rules::key_naming::*;
+
+    fn create_message_map(keys: &[&str]) -> LocaleMessages {
+        let mut messages = LocaleMessages::new("en", "en.json");
+        for (i, key) in keys.iter().enumerate() {
+            messages.entries.insert(
+                key.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line("en.json", i + 1),
+                        key.to_string(),
+                        "value",
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn naming(case: KeyCase) -> KeyNamingConfig {
+        KeyNamingConfig {
+            case,
+            separator: ".".to_string(),
+        }
+    }
+
+    fn usage(key: &str, suppressed: bool) -> ResolvedKeyUsage {
+        ResolvedKeyUsage {
+            key: FullKey::new(key),
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 3, 1),
+                "t(\"...\")",
+                CommentStyle::Jsx,
+            ),
+            suppressed_rules: if suppressed {
+                HashSet::from([SuppressibleRule::KeyNaming])
+            } else {
+                HashSet::new()
+            },
+            from_schema: None,
+            rich_text: false,
+            namespace: None,
+        }
+    }
+
+    #[test]
+    fn test_segment_matches() {
+        assert!(segment_matches("submitButton", KeyCase::Camel));
+        assert!(!segment_matches("SubmitButton", KeyCase::Camel));
+        assert!(!segment_matches("submit_button", KeyCase::Camel));
+
+        assert!(segment_matches("SubmitButton", KeyCase::Pascal));
+        assert!(!segment_matches("submitButton", KeyCase::Pascal));
+
+        assert!(segment_matches("submit_button2", KeyCase::Snake));
+        assert!(!segment_matches("submit__button", KeyCase::Snake));
+        assert!(!segment_matches("submitButton", KeyCase::Snake));
+
+        assert!(segment_matches("submit-button", KeyCase::Kebab));
+        assert!(!segment_matches("submit-", KeyCase::Kebab));
+
+        assert!(segment_matches("0", KeyCase::Pascal));
+        assert!(!segment_matches("", KeyCase::Camel));
+    }
+
+    #[test]
+    fn test_reports_first_bad_segment() {
+        let messages = create_message_map(&["common.submitButton", "Settings.Dark_mode"]);
+
+        let issues = check_key_naming(&naming(KeyCase::Camel), &messages, &KeyUsageMap::new());
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].context.key, "Settings.Dark_mode");
+        assert_eq!(issues[0].segment, "Settings");
+    }
+
+    #[test]
+    fn test_custom_separator() {
+        let messages = create_message_map(&["common:submit_label"]);
+        let config = KeyNamingConfig {
+            case: KeyCase::Snake,
+            separator: ":".to_string(),
+        };
+
+        assert!(check_key_naming(&config, &messages, &KeyUsageMap::new()).is_empty());
+    }
+
+    #[test]
+    fn test_suppressed_usages_skip_key() {
+        let messages = create_message_map(&["common.Legacy", "common.Other"]);
+        let mut usages = KeyUsageMap::new();
+        usages.insert(
+            "common.Legacy".to_string(),
+            vec![usage("common.Legacy", true)],
+        );
+        usages.insert(
+            "common.Other".to_string(),
+            vec![usage("common.Other", true), usage("common.Other", false)],
+        );
+
+        let issues = check_key_naming(&naming(KeyCase::Camel), &messages, &usages);
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].context.key, "common.Other");
+        assert_eq!(issues[0].usages.len(), 1);
+    }
+}
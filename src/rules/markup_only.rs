@@ -0,0 +1,194 @@
+//! Markup-only value detection rule.
+//!
+//! Detects translation values that consist of rich text tags and nothing
+//! else, like `<b></b>` or `<link></link> `. Rendered with `t.rich` or
+//! `t.markup` they produce empty elements, which usually means the text was
+//! lost while translating.
+//!
+//! - Tags are the ones `t.rich`/`t.markup` accept (see `strip_rich_tags`)
+//! - ICU arguments count as content, so `<b>{name}</b>` is fine
+//! - Values without tags are left to `empty-value`
+//! - Suppressed with `glot-disable-next-line markup-only` at the key's usages
+
+use crate::{
+    core::{
+        AllLocaleMessages, CheckContext, ResolvedKeyUsage, ValueType, collect::SuppressibleRule,
+        utils::strip_rich_tags,
+    },
+    issues::MarkupOnlyIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key},
+    },
+};
+
+pub fn check_markup_only_issues(ctx: &CheckContext) -> Vec<MarkupOnlyIssue> {
+    let all_messages = &ctx.messages().all_messages;
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_markup_only(all_messages, &key_usages_map)
+}
+
+/// Check all locales for values that have tags but no text.
+///
+/// # Arguments
+/// * `all_messages` - All messages from all locales
+/// * `key_usages` - Map of key to usage locations (for suppression and display)
+///
+/// # Returns
+/// Vector of MarkupOnlyIssue, one per key and locale
+pub fn check_markup_only(
+    all_messages: &AllLocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<MarkupOnlyIssue> {
+    let mut issues = Vec::new();
+
+    for (locale, messages) in all_messages {
+        for (key, entry) in &messages.entries {
+            if entry.value_type != ValueType::String || !is_markup_only(&entry.context.value) {
+                continue;
+            }
+
+            let all_usages = get_usages_for_key(key_usages, key);
+            let usages: Vec<ResolvedKeyUsage> = all_usages
+                .iter()
+                .filter(|u| !u.suppressed_rules.contains(&SuppressibleRule::MarkupOnly))
+                .cloned()
+                .collect();
+            // Every usage opted out of this rule
+            if usages.is_empty() && !all_usages.is_empty() {
+                continue;
+            }
+
+            issues.push(MarkupOnlyIssue {
+                context: entry.context.clone(),
+                locale: locale.clone(),
+                usages,
+            });
+        }
+    }
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+fn is_markup_only(value: &str) -> bool {
+    strip_rich_tags(value).is_some_and(|text| text.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::core::{
+        CommentStyle, FullKey, LocaleMessages, MessageContext, MessageEntry, MessageLocation,
+        SourceContext, SourceLocation,
+    };
+    use crate::rules::markup_only::*;
+
+    fn create_message_map(locale: &str, entries: &[(&str, &str)]) -> LocaleMessages {
+        let file = format!("{}.json", locale);
+        let mut messages = LocaleMessages::new(locale, &file);
+        for (i, (k, v)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(&file, i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn usage(key: &str, suppressed: bool) -> ResolvedKeyUsage {
+        ResolvedKeyUsage {
+            key: FullKey::new(key),
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 3, 1),
+                "t.rich(\"...\")",
+                CommentStyle::Jsx,
+            ),
+            suppressed_rules: if suppressed {
+                HashSet::from([SuppressibleRule::MarkupOnly])
+            } else {
+                HashSet::new()
+            },
+            from_schema: None,
+            rich_text: false,
+            namespace: None,
+        }
+    }
+
+    fn run(en: &[(&str, &str)], de: &[(&str, &str)], usages: &KeyUsageMap) -> Vec<MarkupOnlyIssue> {
+        let mut all = HashMap::new();
+        all.insert("en".to_string(), create_message_map("en", en));
+        all.insert("de".to_string(), create_message_map("de", de));
+        check_markup_only(&all, usages)
+    }
+
+    #[test]
+    fn test_is_markup_only() {
+        assert!(is_markup_only("<b></b>"));
+        assert!(is_markup_only(" <link></link> <br/> "));
+        assert!(!is_markup_only("<b>Bold</b>"));
+        assert!(!is_markup_only("<b>{name}</b>"));
+        assert!(!is_markup_only(""));
+        assert!(!is_markup_only("Plain"));
+    }
+
+    #[test]
+    fn test_reports_per_locale() {
+        let issues = run(
+            &[
+                ("Home.intro", "<b>Welcome</b>"),
+                ("Home.terms", "<link></link>"),
+            ],
+            &[("Home.intro", "<b></b>"), ("Home.terms", "<link></link>")],
+            &KeyUsageMap::new(),
+        );
+        let found: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| (i.locale.as_str(), i.context.key.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("de", "Home.intro"),
+                ("de", "Home.terms"),
+                ("en", "Home.terms")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suppressed_usages_skip_key() {
+        let mut usages = KeyUsageMap::new();
+        usages.insert(
+            "Home.terms".to_string(),
+            vec![usage("Home.terms", true), usage("Home.terms", true)],
+        );
+        assert!(run(&[("Home.terms", "<link></link>")], &[], &usages).is_empty());
+
+        usages.insert(
+            "Home.terms".to_string(),
+            vec![usage("Home.terms", true), usage("Home.terms", false)],
+        );
+        let issues = run(&[("Home.terms", "<link></link>")], &[], &usages);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].usages.len(), 1);
+    }
+}
@@ -99,6 +99,8 @@ mod tests {
             ),
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         }
     }
 
@@ -108,9 +110,11 @@ mod tests {
             key_object: HashMap::new(),
             key_array: HashMap::new(),
             string_array: HashMap::new(),
+            re_exports: HashMap::new(),
             translation_prop: HashMap::new(),
             translation_fn_call: HashMap::new(),
             default_exports: HashMap::new(),
+            message_provider: HashMap::new(),
         }
     }
 
@@ -122,6 +126,7 @@ mod tests {
             FileKeyUsages {
                 resolved: vec![create_resolved_usage("test.tsx", 10, "Common.submit")],
                 unresolved: vec![],
+                ..Default::default()
             },
         );
 
@@ -143,6 +148,7 @@ mod tests {
                     create_resolved_usage("test.tsx", 20, "Common.missing"),
                 ],
                 unresolved: vec![],
+                ..Default::default()
             },
         );
 
@@ -163,6 +169,7 @@ mod tests {
             FileKeyUsages {
                 resolved: vec![create_resolved_usage("a.tsx", 1, "Key.a")],
                 unresolved: vec![],
+                ..Default::default()
             },
         );
         extractions.insert(
@@ -170,6 +177,7 @@ mod tests {
             FileKeyUsages {
                 resolved: vec![create_resolved_usage("b.tsx", 2, "Key.b")],
                 unresolved: vec![],
+                ..Default::default()
             },
         );
 
@@ -200,8 +208,11 @@ mod tests {
                         schema_name: "formSchema".to_string(),
                         schema_file: "./schemas/form.ts".to_string(),
                     }),
+                    rich_text: false,
+                    namespace: None,
                 }],
                 unresolved: vec![],
+                ..Default::default()
             },
         );
 
@@ -12,19 +12,51 @@
 //! - `unresolved_key`: Unresolved (dynamic) key detection
 //! - `replica_lag`: Keys missing in non-primary locales
 //! - `unused_key`: Defined but unused keys
+//! - `unused_namespace`: Top-level namespaces with no usages
 //! - `orphan_key`: Keys in non-primary locales but not in primary
 //! - `untranslated`: Identical values across locales
 //! - `type_mismatch`: Type mismatches between locales
+//! - `placeholder_mismatch`: ICU placeholder differences between locales
+//! - `key_naming`: Keys violating the configured casing convention
+//! - `empty_value`: Empty or whitespace-only values in any locale
+//! - `plural_categories`: ICU plurals checked against CLDR categories
+//! - `markup_only`: Values made of rich text tags without text
+//! - `rich_tag_mismatch`: Rich text tag differences between locales
+//! - `unmatched_message_keys`: glot-message-keys patterns matching no key
+//! - `namespace_consistency`: Keys reached through different namespace paths
+//! - `unpaired_directive`: glot-enable/glot-disable comments that don't pair up
+//! - `duplicate_value`: values of the primary locale shared by many keys
+//! - `interpolation_mismatch`: t() values differing from the ICU arguments of the message
+//! - `stray_whitespace`: leading/trailing whitespace, or replicas differing only by it
+//! - `empty_key`: t() calls with an empty or whitespace-only key
+//! - `duplicate_key`: keys defined more than once in the same object of a locale file
+//! - `docs`: Rule explanations for `glot explain`
 
+pub mod docs;
+pub mod duplicate_key;
+pub mod duplicate_value;
+pub mod empty_key;
+pub mod empty_value;
 pub mod hardcoded;
 pub mod helpers;
+pub mod interpolation_mismatch;
+pub mod key_naming;
+pub mod markup_only;
 pub mod missing;
+pub mod namespace_consistency;
 pub mod orphan;
+pub mod placeholder_mismatch;
+pub mod plural_categories;
 pub mod replica_lag;
+pub mod rich_tag_mismatch;
+pub mod stray_whitespace;
 pub mod type_mismatch;
+pub mod unmatched_message_keys;
+pub mod unpaired_directive;
 pub mod unresolved;
 pub mod untranslated;
 pub mod unused;
+pub mod unused_namespace;
 
 // Re-export all check functions for convenient access
 pub use helpers::build_key_usage_map;
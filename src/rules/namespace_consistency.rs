@@ -0,0 +1,195 @@
+//! Namespace consistency detection rule.
+//!
+//! Detects keys that code reaches through different namespace paths, like
+//! `t("submit")` under `useTranslations("Common")` in one file and
+//! `t("Common.submit")` without a namespace in another. Both resolve to the
+//! same key, but moving or renaming the namespace only updates one of them.
+//!
+//! - Keys expanded from `glot-message-keys` declarations are skipped, since
+//!   their namespace path isn't known
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    core::{AllKeyUsages, CheckContext, ResolvedKeyUsage, collect::SuppressibleRule},
+    issues::NamespaceConsistencyIssue,
+};
+
+pub fn check_namespace_consistency_issues(ctx: &CheckContext) -> Vec<NamespaceConsistencyIssue> {
+    check_namespace_consistency(ctx.all_key_usages())
+}
+
+/// Check for keys reached through more than one namespace path.
+///
+/// The issue is reported at the first usage of the key; the first usage of
+/// every other path is listed with it. Usages on lines disabled with
+/// `glot-disable` comments are skipped.
+///
+/// # Arguments
+/// * `all_key_usages` - All key usages extracted from source files
+///
+/// # Returns
+/// Vector of NamespaceConsistencyIssue, sorted by key
+pub fn check_namespace_consistency(
+    all_key_usages: &AllKeyUsages,
+) -> Vec<NamespaceConsistencyIssue> {
+    let mut by_key: BTreeMap<&str, Vec<&ResolvedKeyUsage>> = BTreeMap::new();
+    for usage in all_key_usages
+        .values()
+        .flat_map(|file_usages| &file_usages.resolved)
+    {
+        if usage.namespace.is_none()
+            || usage
+                .suppressed_rules
+                .contains(&SuppressibleRule::NamespaceConsistency)
+        {
+            continue;
+        }
+        by_key.entry(usage.key.as_str()).or_default().push(usage);
+    }
+
+    by_key
+        .into_iter()
+        .filter_map(|(key, mut usages)| {
+            usages.sort_by(|a, b| {
+                a.context
+                    .file_path()
+                    .cmp(b.context.file_path())
+                    .then_with(|| a.context.line().cmp(&b.context.line()))
+                    .then_with(|| a.context.col().cmp(&b.context.col()))
+            });
+
+            let mut seen = HashSet::new();
+            let firsts: Vec<&ResolvedKeyUsage> = usages
+                .into_iter()
+                .filter(|usage| seen.insert(usage.namespace.clone()))
+                .collect();
+            if firsts.len() < 2 {
+                return None;
+            }
+
+            Some(NamespaceConsistencyIssue {
+                context: firsts[0].context.clone(),
+                key: key.to_string(),
+                namespaces: firsts
+                    .iter()
+                    .filter_map(|usage| usage.namespace.clone())
+                    .collect(),
+                usages: firsts[1..].iter().map(|usage| (*usage).clone()).collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{CommentStyle, FileKeyUsages, FullKey, SourceContext, SourceLocation};
+    use crate::rules::namespace_consistency::*;
+
+    fn usage(
+        file: &str,
+        line: usize,
+        key: &str,
+        namespace: Option<Option<&str>>,
+        suppressed: bool,
+    ) -> ResolvedKeyUsage {
+        ResolvedKeyUsage {
+            key: FullKey::new(key),
+            context: SourceContext::new(SourceLocation::new(file, line, 5), "", CommentStyle::Jsx),
+            suppressed_rules: if suppressed {
+                HashSet::from([SuppressibleRule::NamespaceConsistency])
+            } else {
+                HashSet::new()
+            },
+            from_schema: None,
+            rich_text: false,
+            namespace: namespace.map(|ns| ns.map(str::to_string)),
+        }
+    }
+
+    fn usages_in(all: &mut AllKeyUsages, file: &str, resolved: Vec<ResolvedKeyUsage>) {
+        all.insert(
+            file.to_string(),
+            FileKeyUsages {
+                resolved,
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn test_reports_key_reached_through_different_namespaces() {
+        let mut all_key_usages: AllKeyUsages = HashMap::new();
+        usages_in(
+            &mut all_key_usages,
+            "./src/a.tsx",
+            vec![
+                usage(
+                    "./src/a.tsx",
+                    3,
+                    "Common.submit",
+                    Some(Some("Common")),
+                    false,
+                ),
+                usage(
+                    "./src/a.tsx",
+                    4,
+                    "Common.cancel",
+                    Some(Some("Common")),
+                    false,
+                ),
+            ],
+        );
+        usages_in(
+            &mut all_key_usages,
+            "./src/b.tsx",
+            vec![
+                usage("./src/b.tsx", 7, "Common.submit", Some(None), false),
+                usage("./src/b.tsx", 9, "Common.submit", Some(None), false),
+                usage(
+                    "./src/b.tsx",
+                    11,
+                    "Common.cancel",
+                    Some(Some("Common")),
+                    false,
+                ),
+            ],
+        );
+
+        let issues = check_namespace_consistency(&all_key_usages);
+        assert_eq!(issues.len(), 1);
+        let issue = &issues[0];
+        assert_eq!(issue.key, "Common.submit");
+        assert_eq!(issue.context.file_path(), "./src/a.tsx");
+        assert_eq!(
+            issue.namespaces,
+            vec![Some("Common".to_string()), None::<String>]
+        );
+        let lines: Vec<usize> = issue.usages.iter().map(|u| u.context.line()).collect();
+        assert_eq!(lines, vec![7]);
+    }
+
+    #[test]
+    fn test_skips_declared_and_suppressed_usages() {
+        let mut all_key_usages: AllKeyUsages = HashMap::new();
+        usages_in(
+            &mut all_key_usages,
+            "./src/a.tsx",
+            vec![
+                usage(
+                    "./src/a.tsx",
+                    3,
+                    "Common.submit",
+                    Some(Some("Common")),
+                    false,
+                ),
+                usage("./src/a.tsx", 5, "Common.submit", None, false),
+                usage("./src/a.tsx", 8, "Common.submit", Some(None), true),
+            ],
+        );
+
+        assert!(check_namespace_consistency(&all_key_usages).is_empty());
+    }
+}
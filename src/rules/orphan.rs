@@ -2,17 +2,22 @@
 //!
 //! Detects translation keys that exist in non-primary locales
 //! but are missing from the primary locale.
+//!
+//! With `fallbackLocales`, a key defined in any fallback locale is not an
+//! orphan, and the keys of the fallback locales themselves are never orphans.
 
 use crate::{
     core::CheckContext,
     core::{AllLocaleMessages, MessageContext, MessageLocation},
     issues::OrphanKeyIssue,
+    rules::helpers::is_source_locale,
 };
 
 pub fn check_orphan_keys_issues(ctx: &CheckContext) -> Vec<OrphanKeyIssue> {
     let primary_locale = &ctx.config.primary_locale;
-    let all_messages = &ctx.messages().all_messages;
-    check_orphan_keys(primary_locale, all_messages)
+    let fallback_locales = &ctx.config.fallback_locales;
+    let all_messages = ctx.compared_messages();
+    check_orphan_keys(primary_locale, fallback_locales, all_messages)
 }
 
 /// Check for orphan translation keys.
@@ -23,26 +28,34 @@ pub fn check_orphan_keys_issues(ctx: &CheckContext) -> Vec<OrphanKeyIssue> {
 ///
 /// # Arguments
 /// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `fallback_locales` - Locales that also count as a source (e.g., ["en-US"])
 /// * `all_messages` - All messages from all locales
 ///
 /// # Returns
 /// Vector of OrphanKeyIssue for keys missing in primary locale
 pub fn check_orphan_keys(
     primary_locale: &str,
+    fallback_locales: &[String],
     all_messages: &AllLocaleMessages,
 ) -> Vec<OrphanKeyIssue> {
     let Some(primary_messages) = all_messages.get(primary_locale) else {
         return Vec::new();
     };
+    let in_fallback = |key: &str| {
+        fallback_locales
+            .iter()
+            .filter_map(|locale| all_messages.get(locale))
+            .any(|messages| messages.contains_key(key))
+    };
 
     let mut issues: Vec<OrphanKeyIssue> = all_messages
         .iter()
-        .filter(|(locale, _)| *locale != primary_locale)
+        .filter(|(locale, _)| !is_source_locale(locale, primary_locale, fallback_locales))
         .flat_map(|(locale, messages)| {
             messages
                 .entries
                 .iter()
-                .filter(|(key, _)| !primary_messages.contains_key(key))
+                .filter(|(key, _)| !primary_messages.contains_key(key) && !in_fallback(key))
                 .map(|(key, entry)| OrphanKeyIssue {
                     context: MessageContext::new(
                         MessageLocation::new(
@@ -52,7 +65,8 @@ pub fn check_orphan_keys(
                         ),
                         key.clone(),
                         entry.context.value.clone(),
-                    ),
+                    )
+                    .with_namespace(entry.context.namespace.clone()),
                     locale: locale.clone(),
                 })
         })
@@ -109,7 +123,7 @@ mod tests {
             create_message_map("zh.json", &[("Common.submit", "提交")]),
         );
 
-        let issues = check_orphan_keys("en", &all_messages);
+        let issues = check_orphan_keys("en", &[], &all_messages);
         assert!(issues.is_empty());
     }
 
@@ -128,7 +142,7 @@ mod tests {
             ),
         );
 
-        let issues = check_orphan_keys("en", &all_messages);
+        let issues = check_orphan_keys("en", &[], &all_messages);
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].context.key, "Common.orphan");
         assert_eq!(issues[0].locale, "zh");
@@ -156,7 +170,7 @@ mod tests {
             ),
         );
 
-        let issues = check_orphan_keys("en", &all_messages);
+        let issues = check_orphan_keys("en", &[], &all_messages);
         assert_eq!(issues.len(), 2);
 
         let keys: Vec<_> = issues.iter().map(|i| i.context.key.as_str()).collect();
@@ -172,10 +186,33 @@ mod tests {
             create_message_map("zh.json", &[("Common.submit", "提交")]),
         );
 
-        let issues = check_orphan_keys("en", &all_messages);
+        let issues = check_orphan_keys("en", &[], &all_messages);
         assert!(issues.is_empty());
     }
 
+    #[test]
+    fn test_check_orphan_key_fallback_locales() {
+        let mut all_messages = HashMap::new();
+        all_messages.insert(
+            "en".to_string(),
+            create_message_map("en.json", &[("Common.submit", "Submit")]),
+        );
+        all_messages.insert(
+            "en-US".to_string(),
+            create_message_map("en-US.json", &[("Common.color", "Color")]),
+        );
+        all_messages.insert(
+            "zh".to_string(),
+            create_message_map("zh.json", &[("Common.color", "颜色"), ("Common.old", "旧")]),
+        );
+
+        let fallbacks = ["en-US".to_string()];
+        let issues = check_orphan_keys("en", &fallbacks, &all_messages);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].locale, "zh");
+        assert_eq!(issues[0].context.key, "Common.old");
+    }
+
     #[test]
     fn test_check_orphan_key_only_primary() {
         let mut all_messages = HashMap::new();
@@ -184,7 +221,7 @@ mod tests {
             create_message_map("en.json", &[("Common.submit", "Submit")]),
         );
 
-        let issues = check_orphan_keys("en", &all_messages);
+        let issues = check_orphan_keys("en", &[], &all_messages);
         assert!(issues.is_empty());
     }
 }
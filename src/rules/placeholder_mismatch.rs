@@ -0,0 +1,256 @@
+//! Placeholder mismatch detection rule.
+//!
+//! Detects translation values whose ICU arguments differ between primary and
+//! replica locales. For example: primary has `"Hello {name}"`, but replica has
+//! `"你好 {naem}"` or drops the placeholder entirely.
+//!
+//! Output format is consistent with type-mismatch:
+//! - Points to primary locale file (source of truth)
+//! - Shows which locales have missing, unexpected, or retyped placeholders
+//! - Shows where the key is used in code
+
+use std::collections::BTreeMap;
+
+use crate::{
+    core::CheckContext,
+    core::{
+        AllLocaleMessages, LocaleMessages, LocalePlaceholderMismatch, MessageContext,
+        MessageLocation, ValueType, utils::parse_icu_arguments,
+    },
+    issues::PlaceholderMismatchIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key},
+    },
+};
+
+pub fn check_placeholder_mismatch_issues(ctx: &CheckContext) -> Vec<PlaceholderMismatchIssue> {
+    let primary_locale = &ctx.config.primary_locale;
+    let primary_messages = &ctx.messages().primary_messages;
+    let all_messages = ctx.compared_messages();
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_placeholder_mismatch(
+        primary_locale,
+        primary_messages,
+        all_messages,
+        &key_usages_map,
+    )
+}
+
+/// Check for ICU placeholder mismatches between locales.
+///
+/// Compares the argument set of each string value in the primary locale with
+/// the same key in every other locale. Array values are skipped (they are
+/// covered by type-mismatch), as are keys missing from a locale (covered by
+/// replica-lag).
+///
+/// # Arguments
+/// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `primary_messages` - Messages from the primary locale
+/// * `all_messages` - All messages from all locales
+/// * `key_usages` - Map of key to usage locations (for showing where keys are used)
+///
+/// # Returns
+/// Vector of PlaceholderMismatchIssue for keys with differing placeholders
+pub fn check_placeholder_mismatch(
+    primary_locale: &str,
+    primary_messages: &LocaleMessages,
+    all_messages: &AllLocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<PlaceholderMismatchIssue> {
+    let mut issues = Vec::new();
+
+    for (key, primary_entry) in &primary_messages.entries {
+        if primary_entry.value_type != ValueType::String {
+            continue;
+        }
+        let primary_args = parse_icu_arguments(&primary_entry.context.value);
+
+        let mut mismatched_in: Vec<LocalePlaceholderMismatch> = all_messages
+            .iter()
+            .filter(|(locale, _)| *locale != primary_locale)
+            .filter_map(|(locale, msgs)| {
+                let entry = msgs.get(key)?;
+                if entry.value_type != ValueType::String {
+                    return None;
+                }
+                let args = parse_icu_arguments(&entry.context.value);
+                compare_arguments(&primary_args, &args).map(|(missing, extra, changed_type)| {
+                    LocalePlaceholderMismatch {
+                        locale: locale.clone(),
+                        missing,
+                        extra,
+                        changed_type,
+                        location: MessageLocation::new(
+                            &entry.context.location.file_path,
+                            entry.context.location.line,
+                            1,
+                        ),
+                    }
+                })
+            })
+            .collect();
+        mismatched_in.sort();
+
+        if !mismatched_in.is_empty() {
+            let usages = get_usages_for_key(key_usages, key);
+
+            issues.push(PlaceholderMismatchIssue {
+                context: MessageContext::new(
+                    MessageLocation::new(
+                        &primary_entry.context.location.file_path,
+                        primary_entry.context.location.line,
+                        1,
+                    ),
+                    key.clone(),
+                    primary_entry.context.value.clone(),
+                ),
+                primary_locale: primary_locale.to_string(),
+                mismatched_in,
+                usages,
+            });
+        }
+    }
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+/// Compare two argument sets, returning `(missing, extra, changed_type)` if they differ.
+///
+/// Names come out sorted because both inputs are `BTreeMap`s.
+fn compare_arguments(
+    primary: &BTreeMap<String, Option<String>>,
+    replica: &BTreeMap<String, Option<String>>,
+) -> Option<(Vec<String>, Vec<String>, Vec<String>)> {
+    let missing: Vec<String> = primary
+        .keys()
+        .filter(|name| !replica.contains_key(*name))
+        .cloned()
+        .collect();
+    let extra: Vec<String> = replica
+        .keys()
+        .filter(|name| !primary.contains_key(*name))
+        .cloned()
+        .collect();
+    let changed_type: Vec<String> = primary
+        .iter()
+        .filter(|(name, kind)| replica.get(*name).is_some_and(|other| other != *kind))
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    if missing.is_empty() && extra.is_empty() && changed_type.is_empty() {
+        None
+    } else {
+        Some((missing, extra, changed_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType};
+    use crate::rules::placeholder_mismatch::*;
+
+    fn create_message_map(file: &str, entries: &[(&str, &str)]) -> LocaleMessages {
+        let locale = file.trim_end_matches(".json");
+        let mut messages = LocaleMessages::new(locale, file);
+        for (i, (k, v)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(file, i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn run(en: &[(&str, &str)], zh: &[(&str, &str)]) -> Vec<PlaceholderMismatchIssue> {
+        let primary = create_message_map("en.json", en);
+        let mut all = HashMap::new();
+        all.insert("en".to_string(), primary.clone());
+        all.insert("zh".to_string(), create_message_map("zh.json", zh));
+        check_placeholder_mismatch("en", &primary, &all, &KeyUsageMap::new())
+    }
+
+    #[test]
+    fn test_matching_placeholders() {
+        let issues = run(
+            &[("Common.greeting", "Hello {name}")],
+            &[("Common.greeting", "你好 {name}")],
+        );
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_renamed_placeholder() {
+        let issues = run(
+            &[("Common.greeting", "Hello {name}")],
+            &[("Common.greeting", "你好 {naem}")],
+        );
+        assert_eq!(issues.len(), 1);
+        let mismatch = &issues[0].mismatched_in[0];
+        assert_eq!(mismatch.locale, "zh");
+        assert_eq!(mismatch.missing, vec!["name"]);
+        assert_eq!(mismatch.extra, vec!["naem"]);
+    }
+
+    #[test]
+    fn test_dropped_placeholder() {
+        let issues = run(
+            &[("Common.greeting", "Hello {name}")],
+            &[("Common.greeting", "你好")],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].mismatched_in[0].missing, vec!["name"]);
+        assert!(issues[0].mismatched_in[0].extra.is_empty());
+    }
+
+    #[test]
+    fn test_changed_argument_type() {
+        let issues = run(
+            &[(
+                "Cart.items",
+                "{count, plural, one {# item} other {# items}}",
+            )],
+            &[("Cart.items", "{count} 件")],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].mismatched_in[0].changed_type, vec!["count"]);
+    }
+
+    #[test]
+    fn test_branch_text_differences_are_fine() {
+        let issues = run(
+            &[(
+                "Cart.items",
+                "{count, plural, one {# item} other {# items}}",
+            )],
+            &[("Cart.items", "{count, plural, other {# 件}}")],
+        );
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_missing_key_in_replica_is_skipped() {
+        let issues = run(&[("Common.greeting", "Hello {name}")], &[]);
+        assert!(issues.is_empty());
+    }
+}
@@ -0,0 +1,199 @@
+//! Plural category completeness rule.
+//!
+//! Checks the branches of ICU `plural` and `selectordinal` arguments against
+//! the CLDR categories of each locale. For example, Russian needs `one`,
+//! `few`, `many` and `other`, so `{count, plural, one {...} other {...}}` in
+//! `ru.json` is missing `few` and `many`, while a `two` branch in English is
+//! never selected.
+//!
+//! - Exact matches (`=0`, `=1`) are allowed everywhere and don't count as a category
+//! - Locales whose language has no CLDR data are skipped
+
+use crate::{
+    core::{
+        AllLocaleMessages, CheckContext, MessageContext, MessageLocation, ValueType,
+        utils::{PluralArgument, parse_icu_plurals, plural_categories},
+    },
+    issues::PluralCategoriesIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key},
+    },
+};
+
+pub fn check_plural_categories_issues(ctx: &CheckContext) -> Vec<PluralCategoriesIssue> {
+    let all_messages = &ctx.messages().all_messages;
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_plural_categories(all_messages, &key_usages_map)
+}
+
+/// Check every plural argument in every locale.
+///
+/// # Arguments
+/// * `all_messages` - All messages from all locales
+/// * `key_usages` - Map of key to usage locations (for showing where keys are used)
+///
+/// # Returns
+/// Vector of PluralCategoriesIssue, one per plural argument with missing or
+/// unexpected categories
+pub fn check_plural_categories(
+    all_messages: &AllLocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<PluralCategoriesIssue> {
+    let mut issues = Vec::new();
+
+    for (locale, messages) in all_messages {
+        for (key, entry) in &messages.entries {
+            if entry.value_type != ValueType::String {
+                continue;
+            }
+            for plural in parse_icu_plurals(&entry.context.value) {
+                let Some((missing, invalid)) = compare_categories(locale, &plural) else {
+                    continue;
+                };
+                issues.push(PluralCategoriesIssue {
+                    context: MessageContext::new(
+                        MessageLocation::new(
+                            &entry.context.location.file_path,
+                            entry.context.location.line,
+                            1,
+                        ),
+                        key.clone(),
+                        entry.context.value.clone(),
+                    ),
+                    locale: locale.clone(),
+                    argument: plural.name,
+                    kind: plural.kind,
+                    missing,
+                    invalid,
+                    usages: get_usages_for_key(key_usages, key),
+                });
+            }
+        }
+    }
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+            .then_with(|| a.argument.cmp(&b.argument))
+    });
+
+    issues
+}
+
+/// Compare the selectors of an argument with the locale's categories,
+/// returning `(missing, invalid)` if they differ.
+fn compare_categories(locale: &str, plural: &PluralArgument) -> Option<(Vec<String>, Vec<String>)> {
+    let categories = plural_categories(locale, plural.kind)?;
+    let selectors: Vec<&str> = plural
+        .selectors
+        .iter()
+        .map(String::as_str)
+        .filter(|selector| !selector.starts_with('='))
+        .collect();
+
+    let missing: Vec<String> = categories
+        .required
+        .iter()
+        .filter(|category| !selectors.contains(category))
+        .map(|category| category.to_string())
+        .collect();
+    let mut invalid: Vec<String> = Vec::new();
+    for selector in selectors {
+        if !categories.allows(selector) && !invalid.iter().any(|s| s == selector) {
+            invalid.push(selector.to_string());
+        }
+    }
+
+    if missing.is_empty() && invalid.is_empty() {
+        None
+    } else {
+        Some((missing, invalid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType};
+    use crate::rules::plural_categories::*;
+
+    fn create_message_map(locale: &str, entries: &[(&str, &str)]) -> LocaleMessages {
+        let file = format!("{}.json", locale);
+        let mut messages = LocaleMessages::new(locale, &file);
+        for (i, (k, v)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(&file, i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn run(locale: &str, value: &str) -> Vec<PluralCategoriesIssue> {
+        let mut all = HashMap::new();
+        all.insert(
+            locale.to_string(),
+            create_message_map(locale, &[("Cart.items", value)]),
+        );
+        check_plural_categories(&all, &KeyUsageMap::new())
+    }
+
+    #[test]
+    fn test_complete_plural() {
+        assert!(run("en", "{count, plural, one {# item} other {# items}}").is_empty());
+        assert!(run("ja", "{count, plural, other {# 件}}").is_empty());
+    }
+
+    #[test]
+    fn test_missing_categories() {
+        let issues = run("ru", "{count, plural, one {# товар} other {# товаров}}");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].locale, "ru");
+        assert_eq!(issues[0].argument, "count");
+        assert_eq!(issues[0].missing, vec!["few", "many"]);
+        assert!(issues[0].invalid.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_category() {
+        let issues = run("en", "{count, plural, one {#} two {#} other {#}}");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].missing.is_empty());
+        assert_eq!(issues[0].invalid, vec!["two"]);
+    }
+
+    #[test]
+    fn test_exact_matches_and_optional_categories() {
+        assert!(run("en", "{count, plural, =0 {None} one {#} other {#}}").is_empty());
+        assert!(run("fr", "{count, plural, one {#} many {#} other {#}}").is_empty());
+        assert!(run("fr", "{count, plural, one {#} other {#}}").is_empty());
+    }
+
+    #[test]
+    fn test_selectordinal() {
+        let issues = run("en", "{place, selectordinal, one {#st} other {#th}}");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].missing, vec!["two", "few"]);
+        assert!(run("de", "{place, selectordinal, other {#.}}").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_language_is_skipped() {
+        assert!(run("tlh", "{count, plural, one {#} other {#}}").is_empty());
+    }
+}
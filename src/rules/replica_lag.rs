@@ -2,20 +2,28 @@
 //!
 //! Detects translation keys that exist in the primary locale but are missing
 //! in one or more other locales.
+//!
+//! With `fallbackLocales`, keys defined only in a fallback locale are expected
+//! in the other locales too, and the fallback locales themselves are sources,
+//! so they are never reported as lagging.
 
 use crate::{
     core::CheckContext,
     core::{AllLocaleMessages, MessageContext, MessageLocation},
     issues::ReplicaLagIssue,
-    rules::helpers::{KeyUsageMap, build_key_usage_map, get_usages_for_key},
+    rules::helpers::{
+        KeyUsageMap, build_key_usage_map, get_usages_for_key, is_source_locale,
+        resolve_source_entries,
+    },
 };
 
 pub fn check_replica_lag_issues(ctx: &CheckContext) -> Vec<ReplicaLagIssue> {
     let primary_locale = &ctx.config.primary_locale;
-    let all_messages = &ctx.messages().all_messages;
+    let fallback_locales = &ctx.config.fallback_locales;
+    let all_messages = ctx.compared_messages();
     let key_usages = ctx.all_key_usages();
     let key_usages = build_key_usage_map(key_usages);
-    check_replica_lags(primary_locale, all_messages, &key_usages)
+    check_replica_lags(primary_locale, fallback_locales, all_messages, &key_usages)
 }
 
 /// Check for replica lag issues.
@@ -24,6 +32,7 @@ pub fn check_replica_lag_issues(ctx: &CheckContext) -> Vec<ReplicaLagIssue> {
 ///
 /// # Arguments
 /// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `fallback_locales` - Locales that also count as a source (e.g., ["en-US"])
 /// * `all_messages` - All messages from all locales
 /// * `key_usages` - Map of key to usage locations (for showing where keys are used)
 ///
@@ -31,6 +40,7 @@ pub fn check_replica_lag_issues(ctx: &CheckContext) -> Vec<ReplicaLagIssue> {
 /// Vector of ReplicaLagIssue for keys missing in other locales
 pub fn check_replica_lags(
     primary_locale: &str,
+    fallback_locales: &[String],
     all_messages: &AllLocaleMessages,
     key_usages: &KeyUsageMap,
 ) -> Vec<ReplicaLagIssue> {
@@ -38,40 +48,47 @@ pub fn check_replica_lags(
         return Vec::new();
     };
 
-    let mut issues: Vec<ReplicaLagIssue> = primary_messages
-        .entries
-        .iter()
-        .filter_map(|(key, entry)| {
-            // Find all locales that are missing this key
-            let mut missing_in: Vec<String> = all_messages
-                .iter()
-                .filter(|(locale, msgs)| *locale != primary_locale && !msgs.contains_key(key))
-                .map(|(locale, _)| locale.clone())
-                .collect();
-            missing_in.sort();
-
-            if missing_in.is_empty() {
-                None
-            } else {
-                let usages = get_usages_for_key(key_usages, key);
-
-                Some(ReplicaLagIssue {
-                    context: MessageContext::new(
-                        MessageLocation::new(
-                            &entry.context.location.file_path,
-                            entry.context.location.line,
-                            1,
-                        ),
-                        key.clone(),
-                        entry.context.value.clone(),
+    let mut issues: Vec<ReplicaLagIssue> = resolve_source_entries(
+        primary_locale,
+        primary_messages,
+        fallback_locales,
+        all_messages,
+    )
+    .into_iter()
+    .filter_map(|(key, source_locale, entry)| {
+        // Find all locales that are missing this key
+        let mut missing_in: Vec<String> = all_messages
+            .iter()
+            .filter(|(locale, msgs)| {
+                !is_source_locale(locale, primary_locale, fallback_locales)
+                    && !msgs.contains_key(key)
+            })
+            .map(|(locale, _)| locale.clone())
+            .collect();
+        missing_in.sort();
+
+        if missing_in.is_empty() {
+            None
+        } else {
+            let usages = get_usages_for_key(key_usages, key);
+
+            Some(ReplicaLagIssue {
+                context: MessageContext::new(
+                    MessageLocation::new(
+                        &entry.context.location.file_path,
+                        entry.context.location.line,
+                        1,
                     ),
-                    primary_locale: primary_locale.to_string(),
-                    missing_in,
-                    usages,
-                })
-            }
-        })
-        .collect();
+                    key.clone(),
+                    entry.context.value.clone(),
+                ),
+                primary_locale: source_locale.to_string(),
+                missing_in,
+                usages,
+            })
+        }
+    })
+    .collect();
 
     // Sort by file path, then line for deterministic output
     issues.sort_by(|a, b| {
@@ -124,7 +141,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_replica_lags("en", &all_messages, &key_usages);
+        let issues = check_replica_lags("en", &[], &all_messages, &key_usages);
         assert!(issues.is_empty());
     }
 
@@ -141,7 +158,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_replica_lags("en", &all_messages, &key_usages);
+        let issues = check_replica_lags("en", &[], &all_messages, &key_usages);
 
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].context.key, "Common.cancel");
@@ -159,7 +176,7 @@ mod tests {
         all_messages.insert("ja".to_string(), create_message_map(&[]));
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_replica_lags("en", &all_messages, &key_usages);
+        let issues = check_replica_lags("en", &[], &all_messages, &key_usages);
 
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].context.key, "Common.submit");
@@ -176,7 +193,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_replica_lags("en", &all_messages, &key_usages);
+        let issues = check_replica_lags("en", &[], &all_messages, &key_usages);
         assert!(issues.is_empty());
     }
 
@@ -189,10 +206,37 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_replica_lags("en", &all_messages, &key_usages);
+        let issues = check_replica_lags("en", &[], &all_messages, &key_usages);
         assert!(issues.is_empty());
     }
 
+    #[test]
+    fn test_check_replica_lag_fallback_locales() {
+        let mut all_messages = HashMap::new();
+        all_messages.insert(
+            "en".to_string(),
+            create_message_map(&[("Common.submit", "Submit")]),
+        );
+        all_messages.insert(
+            "en-US".to_string(),
+            create_message_map(&[("Common.color", "Color")]),
+        );
+        all_messages.insert(
+            "zh".to_string(),
+            create_message_map(&[("Common.submit", "提交")]),
+        );
+
+        let fallbacks = ["en-US".to_string()];
+        let key_usages = KeyUsageMap::new();
+        let issues = check_replica_lags("en", &fallbacks, &all_messages, &key_usages);
+
+        // en-US is a source, so it doesn't lag behind en; zh needs the en-US key
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].context.key, "Common.color");
+        assert_eq!(issues[0].primary_locale, "en-US");
+        assert_eq!(issues[0].missing_in, vec!["zh"]);
+    }
+
     #[test]
     fn test_check_replica_lag_sorted_output() {
         let mut all_messages = HashMap::new();
@@ -207,7 +251,7 @@ mod tests {
         all_messages.insert("zh".to_string(), create_message_map(&[]));
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_replica_lags("en", &all_messages, &key_usages);
+        let issues = check_replica_lags("en", &[], &all_messages, &key_usages);
 
         assert_eq!(issues.len(), 3);
         // Should be sorted by line number (which corresponds to order in create_message_map)
@@ -0,0 +1,253 @@
+//! Rich text tag mismatch detection rule.
+//!
+//! Detects `t.rich`/`t.markup` keys whose tags differ between primary and
+//! replica locales. For example: primary has `"Read <link>the terms</link>"`,
+//! but replica has `"Lisez les conditions"`. next-intl throws at runtime when
+//! a message uses a tag the call doesn't provide, and a dropped tag loses its
+//! formatting.
+//!
+//! - Only keys used with `t.rich()` or `t.markup()` are checked
+//! - Tags are the ones `t.rich`/`t.markup` accept (see `parse_rich_tags`)
+//! - Output format is consistent with placeholder-mismatch
+
+use std::collections::BTreeSet;
+
+use crate::{
+    core::CheckContext,
+    core::{
+        AllLocaleMessages, LocaleMessages, LocaleRichTagMismatch, MessageContext, MessageLocation,
+        ValueType, utils::parse_rich_tags,
+    },
+    issues::RichTagMismatchIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key},
+    },
+};
+
+pub fn check_rich_tag_mismatch_issues(ctx: &CheckContext) -> Vec<RichTagMismatchIssue> {
+    let primary_locale = &ctx.config.primary_locale;
+    let primary_messages = &ctx.messages().primary_messages;
+    let all_messages = ctx.compared_messages();
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_rich_tag_mismatch(
+        primary_locale,
+        primary_messages,
+        all_messages,
+        &key_usages_map,
+    )
+}
+
+/// Check for rich text tag mismatches between locales.
+///
+/// Compares the tag names of each `t.rich`/`t.markup` key in the primary
+/// locale with the same key in every other locale. Non-string values and
+/// keys missing from a locale are skipped (covered by type-mismatch and
+/// replica-lag).
+///
+/// # Arguments
+/// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `primary_messages` - Messages from the primary locale
+/// * `all_messages` - All messages from all locales
+/// * `key_usages` - Map of key to usage locations (to find rich text keys)
+///
+/// # Returns
+/// Vector of RichTagMismatchIssue for keys with differing tags
+pub fn check_rich_tag_mismatch(
+    primary_locale: &str,
+    primary_messages: &LocaleMessages,
+    all_messages: &AllLocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<RichTagMismatchIssue> {
+    let mut issues = Vec::new();
+
+    for (key, primary_entry) in &primary_messages.entries {
+        if primary_entry.value_type != ValueType::String {
+            continue;
+        }
+        let usages = get_usages_for_key(key_usages, key);
+        if !usages.iter().any(|usage| usage.rich_text) {
+            continue;
+        }
+        let primary_tags = parse_rich_tags(&primary_entry.context.value);
+
+        let mut mismatched_in: Vec<LocaleRichTagMismatch> = all_messages
+            .iter()
+            .filter(|(locale, _)| *locale != primary_locale)
+            .filter_map(|(locale, msgs)| {
+                let entry = msgs.get(key)?;
+                if entry.value_type != ValueType::String {
+                    return None;
+                }
+                let tags = parse_rich_tags(&entry.context.value);
+                compare_tags(&primary_tags, &tags).map(|(missing, extra)| LocaleRichTagMismatch {
+                    locale: locale.clone(),
+                    missing,
+                    extra,
+                    location: MessageLocation::new(
+                        &entry.context.location.file_path,
+                        entry.context.location.line,
+                        1,
+                    ),
+                })
+            })
+            .collect();
+        mismatched_in.sort();
+
+        if !mismatched_in.is_empty() {
+            issues.push(RichTagMismatchIssue {
+                context: MessageContext::new(
+                    MessageLocation::new(
+                        &primary_entry.context.location.file_path,
+                        primary_entry.context.location.line,
+                        1,
+                    ),
+                    key.clone(),
+                    primary_entry.context.value.clone(),
+                ),
+                primary_locale: primary_locale.to_string(),
+                mismatched_in,
+                usages,
+            });
+        }
+    }
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+/// Compare two tag sets, returning `(missing, extra)` if they differ.
+fn compare_tags(
+    primary: &BTreeSet<String>,
+    replica: &BTreeSet<String>,
+) -> Option<(Vec<String>, Vec<String>)> {
+    let missing: Vec<String> = primary.difference(replica).cloned().collect();
+    let extra: Vec<String> = replica.difference(primary).cloned().collect();
+    if missing.is_empty() && extra.is_empty() {
+        None
+    } else {
+        Some((missing, extra))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::core::{
+        CommentStyle, FullKey, MessageEntry, ResolvedKeyUsage, SourceContext, SourceLocation,
+    };
+    use crate::rules::rich_tag_mismatch::*;
+
+    fn create_message_map(locale: &str, entries: &[(&str, &str)]) -> LocaleMessages {
+        let file = format!("{}.json", locale);
+        let mut messages = LocaleMessages::new(locale, &file);
+        for (i, (k, v)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(&file, i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn usage(key: &str, rich_text: bool) -> ResolvedKeyUsage {
+        ResolvedKeyUsage {
+            key: FullKey::new(key),
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", 1, 1),
+                "",
+                CommentStyle::Jsx,
+            ),
+            suppressed_rules: HashSet::new(),
+            from_schema: None,
+            rich_text,
+            namespace: None,
+        }
+    }
+
+    fn check(entries: &[(&str, &str, &str)], usages: &[(&str, bool)]) -> Vec<RichTagMismatchIssue> {
+        let primary = create_message_map(
+            "en",
+            &entries.iter().map(|(k, v, _)| (*k, *v)).collect::<Vec<_>>(),
+        );
+        let replica = create_message_map(
+            "fr",
+            &entries.iter().map(|(k, _, v)| (*k, *v)).collect::<Vec<_>>(),
+        );
+        let mut all_messages = HashMap::new();
+        all_messages.insert("en".to_string(), primary.clone());
+        all_messages.insert("fr".to_string(), replica);
+        let mut key_usages: KeyUsageMap = HashMap::new();
+        for (key, rich_text) in usages {
+            key_usages
+                .entry(key.to_string())
+                .or_default()
+                .push(usage(key, *rich_text));
+        }
+        check_rich_tag_mismatch("en", &primary, &all_messages, &key_usages)
+    }
+
+    #[test]
+    fn test_missing_and_extra_tags() {
+        let issues = check(
+            &[(
+                "terms",
+                "Read <link>the terms</link>",
+                "Lisez <b>les conditions</b>",
+            )],
+            &[("terms", true)],
+        );
+        assert_eq!(issues.len(), 1);
+        let mismatch = &issues[0].mismatched_in[0];
+        assert_eq!(mismatch.locale, "fr");
+        assert_eq!(mismatch.missing, vec!["link"]);
+        assert_eq!(mismatch.extra, vec!["b"]);
+        assert_eq!(mismatch.location.file_path, "fr.json");
+    }
+
+    #[test]
+    fn test_only_rich_text_keys_are_checked() {
+        let entries = [(
+            "terms",
+            "Read <link>the terms</link>",
+            "Lisez les conditions",
+        )];
+        assert!(check(&entries, &[("terms", false)]).is_empty());
+        assert!(check(&entries, &[]).is_empty());
+        assert_eq!(
+            check(&entries, &[("terms", false), ("terms", true)]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_same_tags_in_any_order() {
+        let issues = check(
+            &[(
+                "intro",
+                "<b>Hello</b> <i>{name}</i>",
+                "<i>{name}</i>, <b>bonjour</b><b>!</b>",
+            )],
+            &[("intro", true)],
+        );
+        assert!(issues.is_empty());
+    }
+}
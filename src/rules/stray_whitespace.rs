@@ -0,0 +1,213 @@
+//! Stray whitespace detection rule.
+//!
+//! Detects values with whitespace a translator likely didn't mean to type,
+//! like `"Submit "`. Such values render with odd spacing, and a replica value
+//! of `"Submit "` for a primary `"Submit"` isn't caught by `untranslated`,
+//! which compares values exactly.
+//!
+//! - Primary values with leading or trailing whitespace are reported
+//! - Replica values equal to their source value (as `untranslated` resolves
+//!   it with `fallbackLocales`) once trimmed, but not exactly, are reported,
+//!   unless the source value is reported itself: fixing it comes first
+//! - Only string values are checked; whitespace-only values are left to
+//!   empty-value
+//! - Line breaks don't count as stray whitespace
+
+use crate::{
+    core::{AllLocaleMessages, CheckContext, LocaleMessages, MessageEntry, ValueType},
+    issues::StrayWhitespaceIssue,
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, get_usages_for_key, is_source_locale, resolve_source_entries},
+    },
+    utils::is_stray_whitespace,
+};
+
+pub fn check_stray_whitespace_issues(ctx: &CheckContext) -> Vec<StrayWhitespaceIssue> {
+    let primary_locale = &ctx.config.primary_locale;
+    let fallback_locales = &ctx.config.fallback_locales;
+    let primary_messages = &ctx.messages().primary_messages;
+    let all_messages = ctx.compared_messages();
+    let key_usages = ctx.all_key_usages();
+    let key_usages_map = build_key_usage_map(key_usages);
+    check_stray_whitespace(
+        primary_locale,
+        fallback_locales,
+        primary_messages,
+        all_messages,
+        &key_usages_map,
+    )
+}
+
+/// Check the primary locale and the replicas for stray whitespace.
+///
+/// # Arguments
+/// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `fallback_locales` - Locales that also count as a source (e.g., ["en-US"])
+/// * `primary_messages` - Messages from the primary locale
+/// * `all_messages` - All messages from all locales
+/// * `key_usages` - Map of key to usage locations (for showing where keys are used)
+///
+/// # Returns
+/// Vector of StrayWhitespaceIssue, one per value, sorted by file path and line
+pub fn check_stray_whitespace(
+    primary_locale: &str,
+    fallback_locales: &[String],
+    primary_messages: &LocaleMessages,
+    all_messages: &AllLocaleMessages,
+    key_usages: &KeyUsageMap,
+) -> Vec<StrayWhitespaceIssue> {
+    let mut issues = Vec::new();
+
+    for (key, source_locale, source_entry) in resolve_source_entries(
+        primary_locale,
+        primary_messages,
+        fallback_locales,
+        all_messages,
+    ) {
+        if !is_checked(source_entry) {
+            continue;
+        }
+        let source_value = &source_entry.context.value;
+        if source_locale == primary_locale && has_stray_whitespace(source_value) {
+            issues.push(StrayWhitespaceIssue {
+                context: source_entry.context.clone(),
+                locale: primary_locale.to_string(),
+                differs_from: None,
+                usages: get_usages_for_key(key_usages, key),
+            });
+            continue;
+        }
+
+        for (locale, messages) in all_messages {
+            if is_source_locale(locale, primary_locale, fallback_locales) {
+                continue;
+            }
+            let Some(entry) = messages.get(key).filter(|entry| is_checked(entry)) else {
+                continue;
+            };
+            let value = &entry.context.value;
+            if value != source_value && trim(value) == trim(source_value) {
+                issues.push(StrayWhitespaceIssue {
+                    context: entry.context.clone(),
+                    locale: locale.clone(),
+                    differs_from: Some((source_locale.to_string(), source_value.clone())),
+                    usages: get_usages_for_key(key_usages, key),
+                });
+            }
+        }
+    }
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+/// Non-blank string values; blank ones are empty-value's.
+fn is_checked(entry: &MessageEntry) -> bool {
+    entry.value_type == ValueType::String && !entry.context.value.trim().is_empty()
+}
+
+fn trim(value: &str) -> &str {
+    value.trim_matches(is_stray_whitespace)
+}
+
+fn has_stray_whitespace(value: &str) -> bool {
+    trim(value) != value
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{MessageContext, MessageLocation};
+    use crate::rules::stray_whitespace::*;
+
+    fn create_message_map(file: &str, entries: &[(&str, &str)]) -> LocaleMessages {
+        let locale = file.trim_end_matches(".json");
+        let mut messages = LocaleMessages::new(locale, file);
+        for (i, (k, v)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line(file, i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn run(en: &[(&str, &str)], zh: &[(&str, &str)]) -> Vec<StrayWhitespaceIssue> {
+        let primary = create_message_map("en.json", en);
+        let mut all = HashMap::new();
+        all.insert("en".to_string(), primary.clone());
+        all.insert("zh".to_string(), create_message_map("zh.json", zh));
+        check_stray_whitespace("en", &[], &primary, &all, &KeyUsageMap::new())
+    }
+
+    #[test]
+    fn test_no_stray_whitespace() {
+        let issues = run(
+            &[("Common.ok", "OK"), ("Common.hello", "Hello")],
+            &[("Common.ok", "好"), ("Common.hello", "Hello")],
+        );
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_replica_differs_only_by_whitespace() {
+        let issues = run(
+            &[("Common.submit", "Submit")],
+            &[("Common.submit", " Submit ")],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].locale, "zh");
+        assert_eq!(issues[0].context.file_path(), "zh.json");
+        assert_eq!(
+            issues[0].differs_from,
+            Some(("en".to_string(), "Submit".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_primary_with_stray_whitespace_skips_replicas() {
+        let issues = run(
+            &[("Common.submit", "Submit\t")],
+            &[("Common.submit", "Submit")],
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].locale, "en");
+        assert_eq!(issues[0].differs_from, None);
+    }
+
+    #[test]
+    fn test_line_breaks_are_not_stray() {
+        let issues = run(
+            &[("Common.terms", "Read the terms\n")],
+            &[("Common.terms", "Read the terms")],
+        );
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_blank_values_are_skipped() {
+        let issues = run(
+            &[("Common.a", "  "), ("Common.b", "B")],
+            &[("Common.b", " ")],
+        );
+        assert!(issues.is_empty());
+    }
+}
@@ -1,7 +1,8 @@
 //! Type mismatch detection rule.
 //!
 //! Detects translation values with different types between primary and replica locales.
-//! For example: primary has array, but replica has string.
+//! For example: primary has array, but replica has string, or primary has a
+//! nested object where replica has a string.
 //!
 //! This is a critical error because type mismatches cause runtime crashes when the
 //! application expects one type (e.g., array for iteration) but gets another (e.g., string).
@@ -9,28 +10,32 @@
 //! Output format is consistent with untranslated/replica-lag:
 //! - Points to primary locale file (source of truth)
 //! - Shows which locales have type mismatches with their file locations
-//! - Shows where the key is used in code
+//! - Shows where the key is used in code, or the keys nested under it when
+//!   the primary locale has an object, since a key path nested one level
+//!   deeper in one locale (`a.b.c` vs a leaf `a.b`) diverges at that object
 
 use crate::{
     core::CheckContext,
     core::{
         AllLocaleMessages, LocaleMessages, LocaleTypeMismatch, MessageContext, MessageLocation,
+        ValueType,
     },
     issues::TypeMismatchIssue,
     rules::{
         build_key_usage_map,
-        helpers::{KeyUsageMap, get_usages_for_key},
+        helpers::{KeyUsageMap, get_usages_for_key, get_usages_under_key},
     },
 };
 
 pub fn check_type_mismatch_issues(ctx: &CheckContext) -> Vec<TypeMismatchIssue> {
     let primary_locale = &ctx.config.primary_locale;
     let primary_messages = &ctx.messages().primary_messages;
-    let all_messages = &ctx.messages().all_messages;
+    let all_messages = ctx.compared_messages();
     let key_usages = ctx.all_key_usages();
     let key_usages_map = build_key_usage_map(key_usages);
     check_type_mismatch(
         primary_locale,
+        &ctx.config.key_separator,
         primary_messages,
         all_messages,
         &key_usages_map,
@@ -39,11 +44,12 @@ pub fn check_type_mismatch_issues(ctx: &CheckContext) -> Vec<TypeMismatchIssue>
 
 /// Check for type mismatches between locales.
 ///
-/// Finds all keys where the value type (string vs array) differs between
-/// the primary locale and other locales.
+/// Finds all keys where the value type (string, array or object) differs
+/// between the primary locale and other locales.
 ///
 /// # Arguments
 /// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `key_separator` - Separator between key segments, to find nested keys
 /// * `primary_messages` - Messages from the primary locale
 /// * `all_messages` - All messages from all locales
 /// * `key_usages` - Map of key to usage locations (for showing where keys are used)
@@ -52,13 +58,22 @@ pub fn check_type_mismatch_issues(ctx: &CheckContext) -> Vec<TypeMismatchIssue>
 /// Vector of TypeMismatchIssue for keys with type mismatches
 pub fn check_type_mismatch(
     primary_locale: &str,
+    key_separator: &str,
     primary_messages: &LocaleMessages,
     all_messages: &AllLocaleMessages,
     key_usages: &KeyUsageMap,
 ) -> Vec<TypeMismatchIssue> {
     let mut issues = Vec::new();
 
-    for (key, primary_entry) in &primary_messages.entries {
+    let keys = primary_messages
+        .entries
+        .keys()
+        .chain(primary_messages.objects.keys());
+    for key in keys {
+        let Some((primary_type, primary_location)) = primary_messages.shape(key) else {
+            continue;
+        };
+
         // Collect locales with type mismatch
         let mut mismatched_in: Vec<LocaleTypeMismatch> = all_messages
             .iter()
@@ -66,18 +81,12 @@ pub fn check_type_mismatch(
                 if locale == primary_locale {
                     return None;
                 }
-                msgs.get(key).and_then(|entry| {
-                    let primary_type = primary_entry.value_type;
-                    let entry_type = entry.value_type;
+                msgs.shape(key).and_then(|(entry_type, location)| {
                     if entry_type != primary_type {
                         Some(LocaleTypeMismatch::new(
                             locale.clone(),
                             entry_type,
-                            MessageLocation::new(
-                                &entry.context.location.file_path,
-                                entry.context.location.line,
-                                1,
-                            ),
+                            MessageLocation::new(&location.file_path, location.line, 1),
                         ))
                     } else {
                         None
@@ -88,19 +97,23 @@ pub fn check_type_mismatch(
         mismatched_in.sort();
 
         if !mismatched_in.is_empty() {
-            let usages = get_usages_for_key(key_usages, key);
+            let usages = if primary_type == ValueType::Object {
+                get_usages_under_key(key_usages, key, key_separator)
+            } else {
+                get_usages_for_key(key_usages, key)
+            };
+            let value = primary_messages
+                .get(key)
+                .map(|entry| entry.context.value.clone())
+                .unwrap_or_default();
 
             issues.push(TypeMismatchIssue {
                 context: MessageContext::new(
-                    MessageLocation::new(
-                        &primary_entry.context.location.file_path,
-                        primary_entry.context.location.line,
-                        1,
-                    ),
+                    MessageLocation::new(&primary_location.file_path, primary_location.line, 1),
                     key.clone(),
-                    primary_entry.context.value.clone(),
+                    value,
                 ),
-                expected_type: primary_entry.value_type,
+                expected_type: primary_type,
                 primary_locale: primary_locale.to_string(),
                 mismatched_in,
                 usages,
@@ -167,7 +180,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_type_mismatch("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_type_mismatch("en", ".", &primary_messages, &all_messages, &key_usages);
         assert!(issues.is_empty());
     }
 
@@ -188,7 +201,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_type_mismatch("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_type_mismatch("en", ".", &primary_messages, &all_messages, &key_usages);
 
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].context.key, "Common.items");
@@ -216,7 +229,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_type_mismatch("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_type_mismatch("en", ".", &primary_messages, &all_messages, &key_usages);
 
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].mismatched_in.len(), 2);
@@ -250,7 +263,7 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_type_mismatch("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_type_mismatch("en", ".", &primary_messages, &all_messages, &key_usages);
 
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].mismatched_in.len(), 1);
@@ -272,7 +285,59 @@ mod tests {
         );
 
         let key_usages = KeyUsageMap::new();
-        let issues = check_type_mismatch("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_type_mismatch("en", ".", &primary_messages, &all_messages, &key_usages);
         assert!(issues.is_empty());
     }
+
+    #[test]
+    fn test_check_type_mismatch_object_vs_string() {
+        let mut primary_messages = create_message_map_with_types(
+            "en.json",
+            &[
+                ("Common.title", "Title", ValueType::String),
+                ("Common.submit", "Submit", ValueType::String),
+            ],
+        );
+        primary_messages.objects.insert(
+            "Common".to_string(),
+            MessageLocation::with_line("en.json", 1),
+        );
+        let mut zh = create_message_map_with_types(
+            "zh.json",
+            &[
+                ("Common", "通用", ValueType::String),
+                ("Common.title.text", "标题", ValueType::String),
+            ],
+        );
+        zh.objects.insert(
+            "Common.title".to_string(),
+            MessageLocation::with_line("zh.json", 2),
+        );
+        let mut all_messages = HashMap::new();
+        all_messages.insert("en".to_string(), primary_messages.clone());
+        all_messages.insert("zh".to_string(), zh);
+
+        let key_usages = KeyUsageMap::new();
+        let issues = check_type_mismatch("en", ".", &primary_messages, &all_messages, &key_usages);
+
+        let reported: Vec<(&str, ValueType, ValueType)> = issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.context.key.as_str(),
+                    issue.expected_type,
+                    issue.mismatched_in[0].actual_type,
+                )
+            })
+            .collect();
+        assert_eq!(
+            reported,
+            vec![
+                ("Common", ValueType::Object, ValueType::String),
+                ("Common.title", ValueType::String, ValueType::Object),
+            ]
+        );
+        assert_eq!(issues[0].context.value, "");
+        assert_eq!(issues[1].mismatched_in[0].location.line, 2);
+    }
 }
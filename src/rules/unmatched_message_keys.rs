@@ -0,0 +1,115 @@
+//! Unmatched glot-message-keys pattern detection rule.
+//!
+//! Detects `glot-message-keys` patterns that match no key of the primary
+//! locale after wildcard expansion. Such a pattern is usually a typo or
+//! outlived the keys it declared, so the call it annotates resolves to nothing.
+//!
+//! - Relative patterns are checked with the namespace of the annotated call
+
+use std::collections::HashSet;
+
+use crate::{
+    core::{AllKeyUsages, CheckContext, collect::SuppressibleRule},
+    issues::UnmatchedMessageKeysIssue,
+};
+
+pub fn check_unmatched_message_keys_issues(ctx: &CheckContext) -> Vec<UnmatchedMessageKeysIssue> {
+    check_unmatched_message_keys(ctx.all_key_usages())
+}
+
+/// Check for `glot-message-keys` patterns that match no key.
+///
+/// A declaration applying to several calls on the same line is reported once
+/// per pattern. Patterns on lines disabled with `glot-disable` comments are
+/// skipped.
+///
+/// # Arguments
+/// * `all_key_usages` - All key usages extracted from source files
+///
+/// # Returns
+/// Vector of UnmatchedMessageKeysIssue, sorted by file path, line and pattern
+pub fn check_unmatched_message_keys(
+    all_key_usages: &AllKeyUsages,
+) -> Vec<UnmatchedMessageKeysIssue> {
+    let mut seen = HashSet::new();
+    let mut issues: Vec<UnmatchedMessageKeysIssue> = all_key_usages
+        .values()
+        .flat_map(|file_usages| &file_usages.unmatched_patterns)
+        .filter(|usage| {
+            !usage
+                .suppressed_rules
+                .contains(&SuppressibleRule::UnmatchedMessageKeys)
+        })
+        .filter(|usage| {
+            seen.insert((
+                usage.context.file_path().to_string(),
+                usage.context.line(),
+                usage.pattern.clone(),
+            ))
+        })
+        .map(|usage| UnmatchedMessageKeysIssue {
+            context: usage.context.clone(),
+            pattern: usage.pattern.clone(),
+        })
+        .collect();
+
+    issues.sort_by(|a, b| {
+        a.context
+            .file_path()
+            .cmp(b.context.file_path())
+            .then_with(|| a.context.line().cmp(&b.context.line()))
+            .then_with(|| a.pattern.cmp(&b.pattern))
+    });
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::{
+        CommentStyle, FileKeyUsages, SourceContext, SourceLocation, UnmatchedPatternUsage,
+    };
+    use crate::rules::unmatched_message_keys::*;
+
+    fn unmatched(line: usize, pattern: &str, suppressed: bool) -> UnmatchedPatternUsage {
+        UnmatchedPatternUsage {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", line, 5),
+                "t(`status.${code}`)",
+                CommentStyle::Js,
+            ),
+            pattern: pattern.to_string(),
+            suppressed_rules: if suppressed {
+                HashSet::from([SuppressibleRule::UnmatchedMessageKeys])
+            } else {
+                HashSet::new()
+            },
+        }
+    }
+
+    #[test]
+    fn test_reports_each_pattern_once() {
+        let mut all_key_usages: AllKeyUsages = HashMap::new();
+        all_key_usages.insert(
+            "./src/app.tsx".to_string(),
+            FileKeyUsages {
+                unmatched_patterns: vec![
+                    unmatched(4, "Common.statsu.*", false),
+                    unmatched(4, "Common.statsu.*", false),
+                    unmatched(2, "Common.old", false),
+                    unmatched(8, "Common.legacy.*", true),
+                ],
+                ..Default::default()
+            },
+        );
+
+        let issues = check_unmatched_message_keys(&all_key_usages);
+        let reported: Vec<(usize, &str)> = issues
+            .iter()
+            .map(|issue| (issue.context.line(), issue.pattern.as_str()))
+            .collect();
+        assert_eq!(reported, vec![(2, "Common.old"), (4, "Common.statsu.*")]);
+    }
+}
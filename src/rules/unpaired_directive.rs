@@ -0,0 +1,108 @@
+//! Unpaired glot-disable / glot-enable detection rule.
+//!
+//! Detects `glot-enable` comments with no open `glot-disable` for any of their
+//! rules, and `glot-disable` comments whose range is never closed. The first
+//! does nothing, usually because its `glot-disable` was removed; the second
+//! silently suppresses everything to the end of the file.
+//!
+//! - Comments are paired per rule when the file is collected (Phase 1)
+//! - Reported as a warning, since an unclosed `glot-disable` is allowed
+
+use crate::{
+    core::{CheckContext, CommentStyle, SourceContext, SourceLocation, collect::AllFileComments},
+    issues::UnpairedDirectiveIssue,
+};
+
+pub fn check_unpaired_directive_issues(ctx: &CheckContext) -> Vec<UnpairedDirectiveIssue> {
+    check_unpaired_directives(ctx.file_comments())
+}
+
+/// Check for `glot-enable`/`glot-disable` comments that don't pair up.
+///
+/// # Arguments
+/// * `file_comments` - Comments collected from each source file
+///
+/// # Returns
+/// Vector of UnpairedDirectiveIssue, sorted by file path and line
+pub fn check_unpaired_directives(file_comments: &AllFileComments) -> Vec<UnpairedDirectiveIssue> {
+    let mut issues: Vec<UnpairedDirectiveIssue> = file_comments
+        .iter()
+        .flat_map(|(file_path, comments)| {
+            comments
+                .suppressions
+                .unpaired_directives
+                .iter()
+                .map(move |directive| UnpairedDirectiveIssue {
+                    context: SourceContext::new(
+                        SourceLocation::new(file_path, directive.line, directive.col),
+                        directive.source_line.clone(),
+                        CommentStyle::Js,
+                    ),
+                    kind: directive.kind,
+                })
+        })
+        .collect();
+
+    issues.sort_by(|a, b| {
+        a.context
+            .file_path()
+            .cmp(b.context.file_path())
+            .then_with(|| a.context.line().cmp(&b.context.line()))
+    });
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::core::collect::{FileComments, UnpairedDirective, UnpairedDirectiveKind};
+    use crate::rules::unpaired_directive::*;
+
+    fn comments(directives: Vec<UnpairedDirective>) -> FileComments {
+        let mut comments = FileComments::default();
+        comments.suppressions.unpaired_directives = directives;
+        comments
+    }
+
+    fn directive(line: usize, kind: UnpairedDirectiveKind) -> UnpairedDirective {
+        UnpairedDirective {
+            line,
+            col: 1,
+            source_line: "// glot-enable".to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_reports_directives_sorted_by_file_and_line() {
+        let mut file_comments: AllFileComments = HashMap::new();
+        file_comments.insert(
+            "./src/b.tsx".to_string(),
+            comments(vec![directive(3, UnpairedDirectiveKind::UnmatchedEnable)]),
+        );
+        file_comments.insert(
+            "./src/a.tsx".to_string(),
+            comments(vec![
+                directive(2, UnpairedDirectiveKind::UnterminatedDisable),
+                directive(9, UnpairedDirectiveKind::UnmatchedEnable),
+            ]),
+        );
+        file_comments.insert("./src/c.tsx".to_string(), comments(Vec::new()));
+
+        let issues = check_unpaired_directives(&file_comments);
+        let reported: Vec<(&str, usize, UnpairedDirectiveKind)> = issues
+            .iter()
+            .map(|issue| (issue.context.file_path(), issue.context.line(), issue.kind))
+            .collect();
+        assert_eq!(
+            reported,
+            vec![
+                ("./src/a.tsx", 2, UnpairedDirectiveKind::UnterminatedDisable),
+                ("./src/a.tsx", 9, UnpairedDirectiveKind::UnmatchedEnable),
+                ("./src/b.tsx", 3, UnpairedDirectiveKind::UnmatchedEnable),
+            ]
+        );
+    }
+}
@@ -63,6 +63,12 @@ fn convert_reason(reason: &ExtractedReason) -> IssueUnresolvedKeyReason {
                 schema_name: schema_name.clone(),
             }
         }
+        ExtractedReason::NotAKeyMap { object_name, .. } => IssueUnresolvedKeyReason::NotAKeyMap {
+            object_name: object_name.clone(),
+        },
+        ExtractedReason::DestructuredTranslator { hook } => {
+            IssueUnresolvedKeyReason::DestructuredTranslator { hook: hook.clone() }
+        }
     }
 }
 
@@ -109,6 +115,7 @@ mod tests {
                     10,
                     ExtractedReason::VariableKey,
                 )],
+                ..Default::default()
             },
         );
 
@@ -129,6 +136,7 @@ mod tests {
                     20,
                     ExtractedReason::TemplateWithExpr,
                 )],
+                ..Default::default()
             },
         );
 
@@ -152,6 +160,7 @@ mod tests {
                         raw_key: "email".to_string(),
                     },
                 )],
+                ..Default::default()
             },
         );
 
@@ -182,6 +191,7 @@ mod tests {
                     hint: Some("Use glot-message-keys annotation".to_string()),
                     pattern: Some("status.*".to_string()),
                 }],
+                ..Default::default()
             },
         );
 
@@ -206,6 +216,7 @@ mod tests {
                     1,
                     ExtractedReason::VariableKey,
                 )],
+                ..Default::default()
             },
         );
         extractions.insert(
@@ -217,6 +228,7 @@ mod tests {
                     2,
                     ExtractedReason::TemplateWithExpr,
                 )],
+                ..Default::default()
             },
         );
 
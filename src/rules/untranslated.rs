@@ -3,32 +3,49 @@
 //! Detects translation values that are identical to the primary locale,
 //! which may indicate that the text was not translated.
 //!
+//! With `fallbackLocales`, values are compared with the first source locale
+//! that defines the key, and the fallback locales are not checked themselves
+//! (an `en-US` value identical to `en` is expected).
+//!
+//! Values without letters are never reported, nor are values matching
+//! `untranslated.ignorePatterns` (numbers, percentages, URLs and emails by
+//! default), which are usually meant to be the same in every locale.
+//!
 //! Output format is consistent with replica-lag:
 //! - Points to primary locale file (source of truth)
 //! - Shows which locales have identical values
 //! - Shows where the key is used in code
 
+use regex::Regex;
+
 use crate::{
     core::CheckContext,
     core::ResolvedKeyUsage,
     core::collect::SuppressibleRule,
     core::{AllLocaleMessages, LocaleMessages, MessageContext, MessageLocation},
     issues::UntranslatedIssue,
-    rules::{build_key_usage_map, helpers::KeyUsageMap},
+    rules::{
+        build_key_usage_map,
+        helpers::{KeyUsageMap, is_source_locale, resolve_source_entries},
+    },
     utils::contains_alphabetic,
 };
 
 pub fn check_untranslated_issues(ctx: &CheckContext) -> Vec<UntranslatedIssue> {
     let primary_locale = &ctx.config.primary_locale;
+    let fallback_locales = &ctx.config.fallback_locales;
     let primary_messages = &ctx.messages().primary_messages;
-    let all_messages = &ctx.messages().all_messages;
+    let all_messages = ctx.compared_messages();
     let key_usages = ctx.all_key_usages();
     let key_usages_map = build_key_usage_map(key_usages);
+    let ignore_patterns = ctx.config.untranslated.ignore_regexes().unwrap_or_default();
     check_untranslated(
         primary_locale,
+        fallback_locales,
         primary_messages,
         all_messages,
         &key_usages_map,
+        &ignore_patterns,
     )
 }
 
@@ -39,26 +56,41 @@ pub fn check_untranslated_issues(ctx: &CheckContext) -> Vec<UntranslatedIssue> {
 ///
 /// # Arguments
 /// * `primary_locale` - The primary locale code (e.g., "en")
+/// * `fallback_locales` - Locales that also count as a source (e.g., ["en-US"])
 /// * `primary_messages` - Messages from the primary locale
 /// * `all_messages` - All messages from all locales
 /// * `key_usages` - Map of key to usage locations (for showing where keys are used)
+/// * `ignore_patterns` - Source values matching one of these (trimmed) are skipped
 ///
 /// # Returns
 /// Vector of UntranslatedIssue for keys with identical values across locales
 pub fn check_untranslated(
     primary_locale: &str,
+    fallback_locales: &[String],
     primary_messages: &LocaleMessages,
     all_messages: &AllLocaleMessages,
     key_usages: &KeyUsageMap,
+    ignore_patterns: &[Regex],
 ) -> Vec<UntranslatedIssue> {
     let mut issues = Vec::new();
 
-    for (key, primary_entry) in &primary_messages.entries {
+    for (key, source_locale, primary_entry) in resolve_source_entries(
+        primary_locale,
+        primary_messages,
+        fallback_locales,
+        all_messages,
+    ) {
         // Skip if value has no alphabetic characters (pure numbers/symbols)
         if !contains_alphabetic(&primary_entry.context.value) {
             continue;
         }
 
+        // Skip values meant to be identical across locales (URLs, emails, ...)
+        let trimmed = primary_entry.context.value.trim();
+        if ignore_patterns.iter().any(|regex| regex.is_match(trimmed)) {
+            continue;
+        }
+
         // Get all usages and filter out those with untranslated rule suppressed
         let all_usages = key_usages.get(key).map(|v| v.as_slice()).unwrap_or(&[]);
         let non_suppressed: Vec<ResolvedKeyUsage> = all_usages
@@ -78,7 +110,7 @@ pub fn check_untranslated(
         let mut empty_in: Vec<String> = Vec::new();
 
         for (locale, msgs) in all_messages.iter() {
-            if *locale == primary_locale {
+            if is_source_locale(locale, primary_locale, fallback_locales) {
                 continue;
             }
             if let Some(entry) = msgs.get(key) {
@@ -105,7 +137,7 @@ pub fn check_untranslated(
                     key.clone(),
                     primary_entry.context.value.clone(),
                 ),
-                primary_locale: primary_locale.to_string(),
+                primary_locale: source_locale.to_string(),
                 identical_in,
                 empty_in,
                 usages,
@@ -132,6 +164,7 @@ mod tests {
 
     use crate::rules::untranslated::*;
     use crate::{
+        config::UntranslatedConfig,
         core::FullKey,
         core::{CommentStyle, SourceContext, SourceLocation},
         core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType},
@@ -166,6 +199,8 @@ mod tests {
             ),
             suppressed_rules: HashSet::new(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         }
     }
 
@@ -179,6 +214,8 @@ mod tests {
             ),
             suppressed_rules: [SuppressibleRule::Untranslated].into_iter().collect(),
             from_schema: None,
+            rich_text: false,
+            namespace: None,
         }
     }
 
@@ -194,7 +231,14 @@ mod tests {
 
         let key_usages = KeyUsageMap::new();
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         assert!(issues.is_empty());
     }
 
@@ -210,7 +254,14 @@ mod tests {
 
         let key_usages = KeyUsageMap::new();
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].context.key, "Common.ok");
         assert_eq!(issues[0].identical_in, vec!["zh"]);
@@ -232,7 +283,14 @@ mod tests {
 
         let key_usages = KeyUsageMap::new();
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].identical_in, vec!["ja", "zh"]); // Sorted
     }
@@ -249,7 +307,14 @@ mod tests {
 
         let key_usages = KeyUsageMap::new();
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].context.key, "Common.submit");
         assert!(issues[0].identical_in.is_empty());
@@ -272,7 +337,14 @@ mod tests {
 
         let key_usages = KeyUsageMap::new();
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         assert_eq!(issues.len(), 1);
         assert_eq!(issues[0].identical_in, vec!["ja"]);
         assert_eq!(issues[0].empty_in, vec!["zh"]);
@@ -290,11 +362,58 @@ mod tests {
 
         let key_usages = KeyUsageMap::new();
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         // Should skip because value has no alphabetic characters
         assert!(issues.is_empty());
     }
 
+    #[test]
+    fn test_check_untranslated_skips_ignore_patterns() {
+        let values = [
+            ("Common.docs", "https://example.com/docs"),
+            ("Common.email", "support@example.com"),
+            ("Common.version", "v2.0"),
+            ("Common.ok", "OK"),
+        ];
+        let primary_messages = create_message_map("en.json", &values);
+        let mut all_messages = HashMap::new();
+        all_messages.insert("en".to_string(), primary_messages.clone());
+        all_messages.insert("zh".to_string(), create_message_map("zh.json", &values));
+        let key_usages = KeyUsageMap::new();
+        let keys = |patterns: &[Regex]| -> Vec<String> {
+            check_untranslated(
+                "en",
+                &[],
+                &primary_messages,
+                &all_messages,
+                &key_usages,
+                patterns,
+            )
+            .into_iter()
+            .map(|issue| issue.context.key)
+            .collect()
+        };
+
+        let defaults = UntranslatedConfig::default().ignore_regexes().unwrap();
+        assert_eq!(keys(&defaults), vec!["Common.version", "Common.ok"]);
+
+        let custom = [Regex::new(r"^v\d+(\.\d+)*$").unwrap()];
+        assert_eq!(
+            keys(&custom),
+            vec!["Common.docs", "Common.email", "Common.ok"]
+        );
+
+        // No patterns: only values without letters are skipped
+        assert_eq!(keys(&[]).len(), 4);
+    }
+
     #[test]
     fn test_check_untranslated_skips_all_suppressed() {
         let primary_messages = create_message_map("en.json", &[("Common.ok", "OK")]);
@@ -314,11 +433,62 @@ mod tests {
             ],
         );
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         // Should skip because all usages are suppressed
         assert!(issues.is_empty());
     }
 
+    #[test]
+    fn test_check_untranslated_fallback_locales() {
+        let primary_messages = create_message_map("en.json", &[("Common.ok", "OK")]);
+        let mut all_messages = HashMap::new();
+        all_messages.insert("en".to_string(), primary_messages.clone());
+        all_messages.insert(
+            "en-US".to_string(),
+            create_message_map(
+                "en-US.json",
+                &[("Common.ok", "OK"), ("Common.color", "Color")],
+            ),
+        );
+        all_messages.insert(
+            "de".to_string(),
+            create_message_map("de.json", &[("Common.ok", "OK"), ("Common.color", "Color")]),
+        );
+
+        let key_usages = KeyUsageMap::new();
+        let fallbacks = ["en-US".to_string()];
+        let issues = check_untranslated(
+            "en",
+            &fallbacks,
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
+
+        // en-US is a source and not reported; de is compared with the resolved value
+        let found: Vec<(&str, &str, &[String])> = issues
+            .iter()
+            .map(|i| {
+                (
+                    i.context.key.as_str(),
+                    i.primary_locale.as_str(),
+                    i.identical_in.as_slice(),
+                )
+            })
+            .collect();
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&("Common.ok", "en", &["de".to_string()][..])));
+        assert!(found.contains(&("Common.color", "en-US", &["de".to_string()][..])));
+    }
+
     #[test]
     fn test_check_untranslated_reports_non_suppressed_usages() {
         let primary_messages = create_message_map("en.json", &[("Common.ok", "OK")]);
@@ -339,7 +509,14 @@ mod tests {
             ],
         );
 
-        let issues = check_untranslated("en", &primary_messages, &all_messages, &key_usages);
+        let issues = check_untranslated(
+            "en",
+            &[],
+            &primary_messages,
+            &all_messages,
+            &key_usages,
+            &[],
+        );
         // Should report because some usages are not suppressed
         assert_eq!(issues.len(), 1);
         // Should only include the non-suppressed usage
@@ -2,19 +2,56 @@
 //!
 //! Detects translation keys that are defined in the primary locale
 //! but never used in any source code.
+//!
+//! With `namespaceRoots`, a key of a mapped namespace is only used when a
+//! file under one of the namespace's roots uses it.
+//!
+//! A key a dynamic key could reach, like `Common.title` for
+//! `t(\`Common.${name}\`)`, is still reported, with the dynamic keys as a hint.
 
 use std::collections::HashSet;
 
 use crate::{
     core::CheckContext,
-    core::{LocaleMessages, MessageContext, MessageLocation},
+    core::{LocaleMessages, MessageContext, MessageLocation, UnresolvedKeyUsage},
     issues::UnusedKeyIssue,
+    utils::relative_path,
 };
 
 pub fn check_unused_keys_issues(ctx: &CheckContext) -> Vec<UnusedKeyIssue> {
-    let used_keys = ctx.used_keys();
     let primary_messages = &ctx.messages().primary_messages;
-    check_unused_keys(used_keys, primary_messages)
+    let mut issues = if ctx.config.namespace_roots.is_empty() {
+        check_unused_keys(ctx.used_keys(), primary_messages)
+    } else {
+        check_unused_keys(&scoped_used_keys(ctx), primary_messages)
+    };
+    let unresolved: Vec<&UnresolvedKeyUsage> = ctx
+        .all_key_usages()
+        .values()
+        .flat_map(|file_usages| &file_usages.unresolved)
+        .collect();
+    add_dynamic_key_hints(&mut issues, &unresolved, &ctx.config.key_separator);
+    issues
+}
+
+/// Keys used in source code, leaving out usages outside `namespaceRoots`.
+///
+/// A `Dashboard.*` key used outside the roots of `Dashboard` does not count,
+/// so it can still be reported as unused.
+fn scoped_used_keys(ctx: &CheckContext) -> HashSet<String> {
+    let separator = &ctx.config.key_separator;
+    let mut used_keys = HashSet::new();
+    for (file_path, file_usages) in ctx.all_key_usages() {
+        let file_path = relative_path(file_path, &ctx.root_dir);
+        for resolved in &file_usages.resolved {
+            let key = resolved.key.as_str();
+            let namespace = key.split(separator.as_str()).next().unwrap_or(key);
+            if ctx.config.namespace_allows_path(namespace, &file_path) {
+                used_keys.insert(key.to_string());
+            }
+        }
+    }
+    used_keys
 }
 
 /// Check for unused translation keys.
@@ -44,7 +81,9 @@ pub fn check_unused_keys(
                 ),
                 key.clone(),
                 entry.context.value.clone(),
-            ),
+            )
+            .with_namespace(entry.context.namespace.clone()),
+            hint: None,
         })
         .collect();
 
@@ -61,6 +100,64 @@ pub fn check_unused_keys(
     issues
 }
 
+/// Hint each unused key with the dynamic keys whose pattern could match it.
+///
+/// Matching is loose, since a `${...}` can hold separators: the key must start
+/// with the pattern's text before the first `*`, which must name at least a
+/// namespace (`Common.`), and end with its text after the last `*`. Relative
+/// patterns (`.status.*`), whose namespace is unknown, are skipped.
+pub fn add_dynamic_key_hints(
+    issues: &mut [UnusedKeyIssue],
+    unresolved: &[&UnresolvedKeyUsage],
+    separator: &str,
+) {
+    let mut candidates: Vec<(&str, &UnresolvedKeyUsage)> = unresolved
+        .iter()
+        .filter_map(|usage| Some((usage.pattern.as_deref()?, *usage)))
+        .filter(|(pattern, _)| !pattern.starts_with(separator))
+        .filter(|(pattern, _)| {
+            pattern
+                .split('*')
+                .next()
+                .is_some_and(|prefix| prefix.contains(separator))
+        })
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+    candidates.sort_by(|(_, a), (_, b)| {
+        a.context
+            .file_path()
+            .cmp(b.context.file_path())
+            .then_with(|| a.context.line().cmp(&b.context.line()))
+            .then_with(|| a.context.col().cmp(&b.context.col()))
+    });
+
+    for issue in issues {
+        let sites: Vec<String> = candidates
+            .iter()
+            .filter(|(pattern, _)| pattern_could_match(pattern, &issue.context.key))
+            .map(|(pattern, usage)| {
+                format!(
+                    "`{}` at {}:{}",
+                    pattern,
+                    usage.context.file_path(),
+                    usage.context.line()
+                )
+            })
+            .collect();
+        if !sites.is_empty() {
+            issue.hint = Some(format!("may be used by dynamic keys: {}", sites.join(", ")));
+        }
+    }
+}
+
+fn pattern_could_match(pattern: &str, key: &str) -> bool {
+    let (prefix, rest) = pattern.split_once('*').unwrap_or((pattern, ""));
+    let suffix = rest.rsplit('*').next().unwrap_or(rest);
+    key.len() >= prefix.len() + suffix.len() && key.starts_with(prefix) && key.ends_with(suffix)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType};
@@ -140,4 +237,61 @@ mod tests {
         assert_eq!(issues[1].context.key, "Common.apple");
         assert_eq!(issues[2].context.key, "Common.mango");
     }
+
+    #[test]
+    fn test_add_dynamic_key_hints() {
+        use crate::core::{CommentStyle, SourceContext, SourceLocation, UsageUnresolvedKeyReason};
+
+        let primary_messages = create_message_map(&[
+            ("Common.title", "Title"),
+            ("Common.nested.title", "Nested"),
+            ("Common.body", "Body"),
+            ("Other.title", "Other"),
+        ]);
+        let usage = |line: usize, pattern: &str| UnresolvedKeyUsage {
+            context: SourceContext::new(
+                SourceLocation::new("./src/app.tsx", line, 5),
+                "",
+                CommentStyle::Jsx,
+            ),
+            reason: UsageUnresolvedKeyReason::TemplateWithExpr,
+            hint: None,
+            pattern: Some(pattern.to_string()),
+        };
+        let unresolved = [
+            usage(4, "Common.*.title"),
+            usage(2, "Common.*"),
+            usage(6, "*.title"),
+            usage(8, ".status.*"),
+        ];
+        let unresolved: Vec<&UnresolvedKeyUsage> = unresolved.iter().collect();
+
+        let mut issues = check_unused_keys(&HashSet::new(), &primary_messages);
+        add_dynamic_key_hints(&mut issues, &unresolved, ".");
+        let hints: Vec<(&str, Option<&str>)> = issues
+            .iter()
+            .map(|issue| (issue.context.key.as_str(), issue.hint.as_deref()))
+            .collect();
+        assert_eq!(
+            hints,
+            vec![
+                (
+                    "Common.title",
+                    Some("may be used by dynamic keys: `Common.*` at ./src/app.tsx:2")
+                ),
+                (
+                    "Common.nested.title",
+                    Some(
+                        "may be used by dynamic keys: `Common.*` at ./src/app.tsx:2, \
+                         `Common.*.title` at ./src/app.tsx:4"
+                    )
+                ),
+                (
+                    "Common.body",
+                    Some("may be used by dynamic keys: `Common.*` at ./src/app.tsx:2")
+                ),
+                ("Other.title", None),
+            ]
+        );
+    }
 }
@@ -0,0 +1,178 @@
+//! Unused namespace detection rule.
+//!
+//! Detects top-level namespaces that are defined in the primary locale
+//! but never referenced by a translation hook or a used key.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    core::CheckContext,
+    core::{LocaleMessages, MessageContext, MessageLocation},
+    issues::UnusedNamespaceIssue,
+};
+
+pub fn check_unused_namespaces_issues(ctx: &CheckContext) -> Vec<UnusedNamespaceIssue> {
+    let used_keys = ctx.used_keys();
+    let used_namespaces = ctx.used_namespaces();
+    let primary_messages = &ctx.messages().primary_messages;
+    check_unused_namespaces(
+        used_keys,
+        used_namespaces,
+        primary_messages,
+        &ctx.config.key_separator,
+    )
+}
+
+/// Check for unused top-level namespaces.
+///
+/// A namespace counts as used when it is passed to a translation hook
+/// (`useTranslations("Common.forms")` uses `Common`) or when any used key
+/// lives under it. Hooks inside `glot-disable` regions still count as usages,
+/// so suppressing a line never makes its namespace look unused.
+///
+/// Top-level keys without a separator are plain messages, not namespaces,
+/// and are left to the unused-key rule.
+///
+/// # Arguments
+/// * `used_keys` - Set of all translation keys found in source code
+/// * `used_namespaces` - Namespaces passed to translation hooks
+/// * `primary_messages` - Messages from the primary locale
+/// * `key_separator` - Separator between the namespace and the rest of a key
+///
+/// # Returns
+/// Vector of UnusedNamespaceIssue, anchored at each namespace's first key
+pub fn check_unused_namespaces(
+    used_keys: &HashSet<String>,
+    used_namespaces: &HashSet<String>,
+    primary_messages: &LocaleMessages,
+    key_separator: &str,
+) -> Vec<UnusedNamespaceIssue> {
+    let referenced: HashSet<&str> = used_namespaces
+        .iter()
+        .chain(used_keys.iter())
+        .filter_map(|key| key.split(key_separator).next())
+        .collect();
+
+    // namespace -> (first location, key count)
+    let mut defined: BTreeMap<&str, (&MessageLocation, usize)> = BTreeMap::new();
+    for (key, entry) in &primary_messages.entries {
+        let Some((namespace, _)) = key.split_once(key_separator) else {
+            continue;
+        };
+        let location = &entry.context.location;
+        defined
+            .entry(namespace)
+            .and_modify(|(first, count)| {
+                if location.line < first.line {
+                    *first = location;
+                }
+                *count += 1;
+            })
+            .or_insert((location, 1));
+    }
+
+    let mut issues: Vec<UnusedNamespaceIssue> = defined
+        .into_iter()
+        .filter(|(namespace, _)| !referenced.contains(namespace))
+        .map(|(namespace, (location, key_count))| UnusedNamespaceIssue {
+            context: MessageContext::new(
+                MessageLocation::new(&location.file_path, location.line, 1),
+                namespace,
+                String::new(),
+            ),
+            key_count,
+        })
+        .collect();
+
+    // Sort by file path, then line for deterministic output
+    issues.sort_by(|a, b| {
+        a.context
+            .location
+            .file_path
+            .cmp(&b.context.location.file_path)
+            .then_with(|| a.context.location.line.cmp(&b.context.location.line))
+            .then_with(|| a.context.key.cmp(&b.context.key))
+    });
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{LocaleMessages, MessageContext, MessageEntry, MessageLocation, ValueType};
+    use crate::rules::unused_namespace::*;
+
+    fn create_message_map(entries: &[(&str, &str)]) -> LocaleMessages {
+        let mut messages = LocaleMessages::new("en", "en.json");
+        for (i, (k, v)) in entries.iter().enumerate() {
+            messages.entries.insert(
+                k.to_string(),
+                MessageEntry {
+                    context: MessageContext::new(
+                        MessageLocation::with_line("en.json", i + 1),
+                        k.to_string(),
+                        v.to_string(),
+                    ),
+                    value_type: ValueType::String,
+                },
+            );
+        }
+        messages
+    }
+
+    fn set(items: &[&str]) -> HashSet<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_namespace_used_by_key() {
+        let primary_messages = create_message_map(&[("Common.submit", "Submit")]);
+        let issues =
+            check_unused_namespaces(&set(&["Common.submit"]), &set(&[]), &primary_messages, ".");
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_namespace_used_by_hook_only() {
+        let primary_messages = create_message_map(&[("Common.submit", "Submit")]);
+        let issues = check_unused_namespaces(&set(&[]), &set(&["Common"]), &primary_messages, ".");
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_nested_hook_namespace_marks_top_level_used() {
+        let primary_messages = create_message_map(&[("Common.forms.submit", "Submit")]);
+        let issues =
+            check_unused_namespaces(&set(&[]), &set(&["Common.forms"]), &primary_messages, ".");
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_unused_namespace_reported_once() {
+        let primary_messages = create_message_map(&[
+            ("Common.submit", "Submit"),
+            ("Legacy.title", "Old"),
+            ("Legacy.body", "Old body"),
+        ]);
+        let issues =
+            check_unused_namespaces(&set(&["Common.submit"]), &set(&[]), &primary_messages, ".");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].context.key, "Legacy");
+        assert_eq!(issues[0].key_count, 2);
+        assert_eq!(issues[0].context.location.line, 2);
+    }
+
+    #[test]
+    fn test_top_level_plain_keys_ignored() {
+        let primary_messages = create_message_map(&[("title", "Title")]);
+        let issues = check_unused_namespaces(&set(&[]), &set(&[]), &primary_messages, ".");
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_empty_messages() {
+        let primary_messages = create_message_map(&[]);
+        let issues = check_unused_namespaces(&set(&[]), &set(&[]), &primary_messages, ".");
+        assert!(issues.is_empty());
+    }
+}
@@ -1,5 +1,9 @@
 //! Common utility functions shared across the codebase.
 
+use std::path::Path;
+
+use unicode_segmentation::UnicodeSegmentation;
+
 /// Checks if the text contains at least one Unicode alphabetic character.
 ///
 /// Returns false for empty strings, pure numbers, or pure symbols.
@@ -21,6 +25,198 @@ pub fn contains_alphabetic(text: &str) -> bool {
     text.chars().any(|c| c.is_alphabetic())
 }
 
+/// Whitespace that is out of place around a message value.
+///
+/// Line breaks don't count: YAML block scalars end with one, and multi-line
+/// messages use them on purpose.
+///
+/// # Examples
+///
+/// ```
+/// use glot::utils::is_stray_whitespace;
+///
+/// assert!(is_stray_whitespace(' '));
+/// assert!(is_stray_whitespace('\t'));
+/// assert!(is_stray_whitespace('\u{a0}'));
+/// assert!(!is_stray_whitespace('\n'));
+/// assert!(!is_stray_whitespace('a'));
+/// ```
+pub fn is_stray_whitespace(c: char) -> bool {
+    c.is_whitespace() && c != '\n' && c != '\r'
+}
+
+/// Count user-perceived characters (extended grapheme clusters).
+///
+/// # Examples
+///
+/// ```
+/// use glot::utils::grapheme_count;
+///
+/// assert_eq!(grapheme_count("ab"), 2);
+/// assert_eq!(grapheme_count("e\u{301}"), 1);
+/// assert_eq!(grapheme_count("👍🏽"), 1);
+/// assert_eq!(grapheme_count("🇩🇪"), 1);
+/// ```
+pub fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// Path of `file_path` relative to `root`, with `/` separators and no `./`.
+///
+/// Paths outside `root` are only normalized.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use glot::utils::relative_path;
+///
+/// assert_eq!(relative_path("./src/app.tsx", Path::new(".")), "src/app.tsx");
+/// assert_eq!(relative_path("/repo/src/app.tsx", Path::new("/repo")), "src/app.tsx");
+/// assert_eq!(relative_path("./src/app.tsx", Path::new("/repo")), "src/app.tsx");
+/// ```
+pub fn relative_path(file_path: &str, root: &Path) -> String {
+    let path = Path::new(file_path);
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    relative.strip_prefix("./").unwrap_or(&relative).to_string()
+}
+
+/// Turn JSON with comments into plain JSON.
+///
+/// Drops a leading BOM, `//` and `/* */` comments and trailing commas before
+/// `}` or `]`, leaving strings untouched. Newlines inside comments are kept,
+/// so line numbers in parse errors still match the original file.
+///
+/// # Examples
+///
+/// ```
+/// use glot::utils::strip_jsonc;
+///
+/// let input = "{\n  // comment\n  \"a\": [1, 2,], /* x */\n}";
+/// assert_eq!(strip_jsonc(input), "{\n  \n  \"a\": [1, 2] \n}");
+/// assert_eq!(strip_jsonc(r#"{"url": "https://x"}"#), r#"{"url": "https://x"}"#);
+/// ```
+pub fn strip_jsonc(input: &str) -> String {
+    strip_trailing_commas(&strip_json_comments(input.trim_start_matches('\u{feff}')))
+}
+
+fn strip_json_comments(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut in_line_comment = false;
+    let mut in_block_comment = false;
+
+    while let Some(ch) = chars.next() {
+        if in_line_comment {
+            if ch == '\n' {
+                in_line_comment = false;
+                result.push(ch);
+            }
+            continue;
+        }
+
+        if in_block_comment {
+            if ch == '*' && chars.peek() == Some(&'/') {
+                chars.next();
+                in_block_comment = false;
+            } else if ch == '\n' {
+                result.push('\n');
+            }
+            continue;
+        }
+
+        if in_string {
+            result.push(ch);
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        if ch == '"' {
+            in_string = true;
+            result.push(ch);
+            continue;
+        }
+
+        if ch == '/' {
+            match chars.peek().copied() {
+                Some('/') => {
+                    chars.next();
+                    in_line_comment = true;
+                    continue;
+                }
+                Some('*') => {
+                    chars.next();
+                    in_block_comment = true;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        result.push(ch);
+    }
+
+    result
+}
+
+fn strip_trailing_commas(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut result = String::with_capacity(input.len());
+    let mut idx = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    while idx < chars.len() {
+        let ch = chars[idx];
+
+        if in_string {
+            result.push(ch);
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            idx += 1;
+            continue;
+        }
+
+        if ch == '"' {
+            in_string = true;
+            result.push(ch);
+            idx += 1;
+            continue;
+        }
+
+        if ch == ',' {
+            let mut next = idx + 1;
+            while next < chars.len() && chars[next].is_whitespace() {
+                next += 1;
+            }
+
+            if next < chars.len() && matches!(chars[next], '}' | ']') {
+                idx += 1;
+                continue;
+            }
+        }
+
+        result.push(ch);
+        idx += 1;
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::*;
@@ -44,4 +240,19 @@ mod tests {
         assert!(!contains_alphabetic(""));
         assert!(!contains_alphabetic("123-456"));
     }
+
+    #[test]
+    fn test_grapheme_count() {
+        assert_eq!(grapheme_count(""), 0);
+        assert_eq!(grapheme_count("Hi"), 2);
+        assert_eq!(grapheme_count("你好"), 2);
+        assert_eq!(grapheme_count("×"), 1);
+        assert_eq!(grapheme_count("e\u{301}"), 1);
+        assert_eq!(grapheme_count("❤\u{FE0F}"), 1);
+        assert_eq!(grapheme_count("👨\u{200D}👩\u{200D}👧"), 1);
+        assert_eq!(grapheme_count("🇩🇪🇫🇷"), 2);
+        // Devanagari consonant with a dependent vowel sign
+        assert_eq!(grapheme_count("कि"), 1);
+        assert_eq!(grapheme_count("नमस्ते"), 3);
+    }
 }
@@ -1452,6 +1452,47 @@ export function App() {
     Ok(())
 }
 
+#[test]
+fn test_baseline_rule_alias() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"import { useTranslations } from "next-intl";
+export function App() {
+    const t = useTranslations("Common");
+    return (
+        <div>
+            <span>Hardcoded text</span>
+            <span>{t("greeting")}</span>
+        </div>
+    );
+}
+"#,
+    )?;
+
+    test.write_file("messages/en.json", r#"{"Common": {"greeting": "Hello"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"greeting": "Hello"}}"#)?;
+
+    // `--rule` is an alias of `--rules`
+    let output = test
+        .baseline_command()
+        .args(["--apply", "--quiet", "--rule", "hardcoded"])
+        .output()?;
+    assert!(output.status.success());
+
+    // The comment names the rule, never a blanket disable
+    let content = test.read_file("src/app.tsx")?;
+    assert_comment_insertions(&content, JSX_HARDCODED, &["<span>Hardcoded text</span>"]);
+    assert!(
+        !content.contains(JSX_UNTRANSLATED) && !content.contains("glot-disable-next-line */"),
+        "Only a hardcoded comment should be inserted, got:\n{}",
+        content
+    );
+    Ok(())
+}
+
 #[test]
 fn test_baseline_mixed_rules_same_line() -> Result<()> {
     let test = CliTest::new()?;
@@ -1563,3 +1604,266 @@ export function App() {
     assert_no_comments(&content);
     Ok(())
 }
+
+const PRUNE_SOURCE: &str = r#"export function App() {
+    return (
+        <div>
+            {/* glot-disable-next-line hardcoded */}
+            <span>Still hardcoded</span>
+            {/* glot-disable-next-line hardcoded */}
+            <span>{count}</span>
+        </div>
+    );
+}
+"#;
+
+#[test]
+fn test_baseline_prune_dry_run() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", PRUNE_SOURCE)?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    let mut cmd = test.baseline_command();
+    cmd.arg("--prune");
+    assert_cmd_snapshot!(cmd);
+
+    assert_eq!(test.read_file("src/app.tsx")?, PRUNE_SOURCE);
+    Ok(())
+}
+
+#[test]
+fn test_baseline_prune_apply_keeps_needed_comments() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", PRUNE_SOURCE)?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--prune", "--apply"]);
+    assert_cmd_snapshot!(cmd);
+
+    let content = test.read_file("src/app.tsx")?;
+    assert_comment_insertions(&content, JSX_HARDCODED, &["<span>Still hardcoded</span>"]);
+
+    // Nothing left to prune
+    let mut cmd = test.baseline_command();
+    cmd.arg("--prune");
+    assert_cmd_snapshot!(cmd);
+    Ok(())
+}
+
+#[test]
+fn test_baseline_prune_ranges() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"export function App() {
+    return (
+        <div>
+            {/* glot-disable hardcoded */}
+            <span>Legacy banner</span>
+            {/* glot-enable */}
+            {/* glot-disable hardcoded */}
+            <span>{count}</span>
+            {/* glot-enable */}
+        </div>
+    );
+}
+"#,
+    )?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--prune", "--apply"]);
+    cmd.output()?;
+
+    let content = test.read_file("src/app.tsx")?;
+    assert_eq!(
+        content,
+        r#"export function App() {
+    return (
+        <div>
+            {/* glot-disable hardcoded */}
+            <span>Legacy banner</span>
+            {/* glot-enable */}
+            <span>{count}</span>
+        </div>
+    );
+}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn test_baseline_prune_respects_rules() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"export function App() {
+    // glot-disable-next-line hardcoded untranslated
+    return <div>{count}</div>;
+}
+"#,
+    )?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    // The comment also covers untranslated, which is not being pruned
+    let mut cmd = test.baseline_command();
+    cmd.args(["--prune", "--apply", "--rules", "hardcoded"]);
+    cmd.output()?;
+    assert!(
+        test.read_file("src/app.tsx")?
+            .contains("// glot-disable-next-line hardcoded untranslated")
+    );
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--prune", "--apply"]);
+    cmd.output()?;
+    assert!(!test.read_file("src/app.tsx")?.contains("glot-disable"));
+    Ok(())
+}
+
+#[test]
+fn test_baseline_to_file() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"export function App() {
+    return <button>Legacy submit</button>;
+}
+"#,
+    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"old": "Old"}}"#)?;
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--to", ".glot-baseline.json"]);
+    assert_cmd_snapshot!(cmd);
+    insta::assert_snapshot!(test.read_file(".glot-baseline.json")?);
+
+    // Nothing is inserted in source files
+    assert_no_comments(&test.read_file("src/app.tsx")?);
+    Ok(())
+}
+
+#[test]
+fn test_check_with_baseline_file_reports_new_issues_only() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"export function App() {
+    return <button>Legacy submit</button>;
+}
+"#,
+    )?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--to", ".glot-baseline.json"]);
+    cmd.output()?;
+
+    let mut cmd = test.check_command();
+    cmd.args(["--baseline", ".glot-baseline.json"]);
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    // Moving the accepted issue keeps it suppressed; a new one is reported
+    test.write_file(
+        "src/app.tsx",
+        r#"export function Banner() {
+    return <p>New text</p>;
+}
+
+export function App() {
+    return <button>Legacy submit</button>;
+}
+"#,
+    )?;
+    let mut cmd = test.check_command();
+    cmd.args(["--baseline", ".glot-baseline.json"]);
+    assert_cmd_snapshot!(cmd);
+    Ok(())
+}
+
+#[test]
+fn test_check_with_baseline_file_accepts_duplicate_keys() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const x = 1;\n")?;
+    test.write_file(
+        "messages/en.json",
+        "{\n  \"submit\": \"Send\",\n  \"submit\": \"Submit\"\n}\n",
+    )?;
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--to", ".glot-baseline.json"]);
+    cmd.output()?;
+    assert!(
+        test.read_file(".glot-baseline.json")?
+            .contains("\"duplicate-key\"")
+    );
+
+    // Moving the accepted duplicate keeps it suppressed
+    test.write_file(
+        "messages/en.json",
+        "{\n  \"title\": \"Title\",\n  \"submit\": \"Send\",\n  \"submit\": \"Submit\"\n}\n",
+    )?;
+    let mut cmd = test.check_command();
+    cmd.args(["--baseline", ".glot-baseline.json", "duplicate-key"]);
+    let output = cmd.output()?;
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_baseline_compare() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"export function App() {
+    return <button>Legacy submit</button>;
+}
+
+export function Footer() {
+    return <footer>Old footer</footer>;
+}
+"#,
+    )?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    let mut cmd = test.baseline_command();
+    cmd.args(["--to", ".glot-baseline.json"]);
+    cmd.output()?;
+
+    // Unchanged code: nothing new, so the command passes
+    let mut cmd = test.check_command();
+    cmd.args(["--baseline-compare", ".glot-baseline.json"]);
+    assert_cmd_snapshot!("baseline_compare_unchanged", cmd);
+
+    // The footer text is fixed and a banner is added
+    test.write_file(
+        "src/app.tsx",
+        r#"export function Banner() {
+    return <p>New text</p>;
+}
+
+export function App() {
+    return <button>Legacy submit</button>;
+}
+"#,
+    )?;
+    let mut cmd = test.check_command();
+    cmd.args(["--baseline-compare", ".glot-baseline.json"]);
+    assert_cmd_snapshot!("baseline_compare_new_and_fixed", cmd);
+    Ok(())
+}
@@ -127,6 +127,136 @@ fn test_config_checked_attributes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_config_checked_attributes_wildcard() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "checkedAttributes": ["aria-*"]
+        }"#,
+    )?;
+    test.write_file("messages/en.json", "{}")?;
+
+    // Every aria-* attribute is checked; placeholder is not listed
+    test.write_file(
+        "src/app.tsx",
+        r#"<input aria-label="Search" aria-description="Type a name" placeholder="Name" />"#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_config_check_all_text_attributes() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "checkedAttributes": ["data-tooltip"],
+            "checkAllTextAttributes": true
+        }"#,
+    )?;
+    test.write_file("messages/en.json", "{}")?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"<input data-tooltip="Tip" aria-label="Search" label="Query" name="q" />"#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_config_hardcoded_heuristics() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "hardcoded": {
+                "minLength": 2,
+                "ignorePatterns": ["^v\\d+(\\.\\d+)*$", "^[A-Z]{2,3}$"]
+            }
+        }"#,
+    )?;
+    test.write_file("messages/en.json", "{}")?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+  export function Footer() {
+      return (
+          <footer>
+              <span>x</span>
+              <span>é</span>
+              <span>v1.2</span>
+              <span>EUR</span>
+              <span>Ok</span>
+          </footer>
+      );
+  }
+  "#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_config_hardcoded_ignore_components() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "hardcoded": {
+                "ignoreComponents": ["Code", "UI.Kbd"]
+            }
+        }"#,
+    )?;
+    test.write_file("messages/en.json", "{}")?;
+
+    // Only the nested <span> text and the title attribute are reported
+    test.write_file(
+        "src/app.tsx",
+        r#"
+  export function Docs() {
+      return (
+          <div>
+              <Code>GET</Code>
+              <Code>{"POST"}</Code>
+              <UI.Kbd>Ctrl</UI.Kbd>
+              <Code>
+                  <span>Send request</span>
+              </Code>
+              <Code title="Copy">curl</Code>
+          </div>
+      );
+  }
+  "#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
 #[test]
 fn test_no_config_uses_defaults() -> Result<()> {
     // Without a config file, Framework defaults to NextIntl (backward compatibility).
@@ -220,7 +350,7 @@ fn test_no_missing_key_when_defined() -> Result<()> {
 }
 
 #[test]
-fn test_missing_key_with_hardcoded_text() -> Result<()> {
+fn test_optional_and_non_null_translation_calls() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -232,18 +362,31 @@ fn test_missing_key_with_hardcoded_text() -> Result<()> {
           }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {}}"#)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+              "Common": {
+                  "submit": "Submit",
+                  "cancel": "Cancel",
+                  "terms": "<b>Terms</b>"
+              }
+          }"#,
+    )?;
 
+    // Every call goes through `?.` or `!`; "close" and "help" are missing
     test.write_file(
         "src/app.tsx",
         r#"
   const t = useTranslations("Common");
-  export function Form() {
+  export function Buttons() {
       return (
-          <form>
-              <button>{t("submit")}</button>
-              <span>Hardcoded</span>
-          </form>
+          <div>
+              <button>{t?.("submit")}</button>
+              <button>{t!("cancel")}</button>
+              <p>{t?.raw("terms")}</p>
+              <button>{t?.("close")}</button>
+              <button>{t!.raw("help")}</button>
+          </div>
       );
   }
   "#,
@@ -255,7 +398,7 @@ fn test_missing_key_with_hardcoded_text() -> Result<()> {
 }
 
 #[test]
-fn test_dynamic_key_warning() -> Result<()> {
+fn test_tagged_template_translation_calls() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -267,14 +410,29 @@ fn test_dynamic_key_warning() -> Result<()> {
           }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+              "Common": {
+                  "submit": "Submit",
+                  "a": { "x": "X" }
+              }
+          }"#,
+    )?;
 
+    // t`submit` resolves to Common.submit; t`a.${x}` can't be resolved
     test.write_file(
         "src/app.tsx",
         r#"
   const t = useTranslations("Common");
-  export function Button({ keyName }) {
-      return <button>{t(keyName)}</button>;
+  export function Buttons({ x }) {
+      return (
+          <div>
+              <button>{t`submit`}</button>
+              <p>{t`a.${x}`}</p>
+              <p>{t`close`}</p>
+          </div>
+      );
   }
   "#,
     )?;
@@ -285,7 +443,7 @@ fn test_dynamic_key_warning() -> Result<()> {
 }
 
 #[test]
-fn test_template_with_expr_warning() -> Result<()> {
+fn test_create_translator_namespace_from_options() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -297,14 +455,23 @@ fn test_template_with_expr_warning() -> Result<()> {
           }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+              "Email": {
+                  "subject": "Welcome"
+              }
+          }"#,
+    )?;
 
+    // `subject` resolves to Email.subject, `body` is missing
     test.write_file(
-        "src/app.tsx",
+        "src/email.ts",
         r#"
-  const t = useTranslations("Common");
-  export function Button({ prefix }) {
-      return <button>{t(`${prefix}.submit`)}</button>;
+  import { createTranslator } from "next-intl";
+  export function render(locale: string, messages: object) {
+      const t = createTranslator({ locale, messages, namespace: "Email" });
+      return [t("subject"), t("body")];
   }
   "#,
     )?;
@@ -315,86 +482,82 @@ fn test_template_with_expr_warning() -> Result<()> {
 }
 
 #[test]
-fn test_template_with_expr_non_jsx_context() -> Result<()> {
+fn test_namespace_from_string_const() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
     )?;
 
     test.write_file(
         "messages/en.json",
-        r#"{"Common": {"error": {"unknown": "Unknown error"}}}"#,
+        r#"{
+              "Common": {
+                  "title": "Title"
+              }
+          }"#,
     )?;
 
-    // t() call in a callback, not in JSX - should use // comment style
+    // `NS` is a module-level constant; the `ns` parameter is not
     test.write_file(
-        "src/app.tsx",
+        "src/page.tsx",
         r#"
-import {useTranslations} from 'next-intl';
-export function Component() {
-    const t = useTranslations('Common');
-    const handleError = (code: string) => {
-        console.log(t(`error.${code}`));
-    };
-    return <button onClick={() => handleError('unknown')}>Click</button>;
-}
-"#,
+  import { useTranslations } from "next-intl";
+  const NS = "Common";
+  export function Page() {
+      const t = useTranslations(NS);
+      return <h1>{t("title")}</h1>;
+  }
+  export function Section({ ns }: { ns: string }) {
+      const t = useTranslations(ns);
+      return <h2>{t("title")}</h2>;
+  }
+  export function Shadowed() {
+      const NS = "Other";
+      const t = useTranslations(NS);
+      return <h3>{t("title")}</h3>;
+  }
+  "#,
     )?;
 
-    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_replica_lag() -> Result<()> {
+fn test_missing_key_with_hardcoded_text() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
-    )?;
-
-    // Primary locale has both keys
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit",
-                "cancel": "Cancel"
-            }
-        }"#,
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
     )?;
 
-    // zh is missing "cancel"
-    test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Common": {
-                "submit": "提交"
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {}}"#)?;
 
-    // Empty source file (no hardcoded text)
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
-}
-"#,
+  const t = useTranslations("Common");
+  export function Form() {
+      return (
+          <form>
+              <button>{t("submit")}</button>
+              <span>Hardcoded</span>
+          </form>
+      );
+  }
+  "#,
     )?;
 
     assert_cmd_snapshot!(test.check_command());
@@ -403,56 +566,106 @@ export function Button() {
 }
 
 #[test]
-fn test_replica_lag_multiple_locales() -> Result<()> {
+fn test_dynamic_key_warning() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
     test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"
+  const t = useTranslations("Common");
+  export function Button({ keyName }) {
+      return <button>{t(keyName)}</button>;
+  }
+  "#,
     )?;
 
-    // Both zh and ja are missing the key
-    test.write_file("messages/zh.json", r#"{}"#)?;
-    test.write_file("messages/ja.json", r#"{}"#)?;
-
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
     assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_replica_lag_none() -> Result<()> {
+fn test_dynamic_key_hint_lists_provider_namespaces() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{"Settings": {"title": "Settings"}, "Common": {"save": "Save"}}"#,
+    )?;
 
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
+    test.write_file(
+        "src/layout.tsx",
+        r#"
+  import { SettingsForm } from "./settings-form";
+  export default function Layout({ messages }) {
+      return (
+          <NextIntlClientProvider messages={pick(messages, ["Settings", "Common"])}>
+              <SettingsForm />
+          </NextIntlClientProvider>
+      );
+  }
+  "#,
+    )?;
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+    test.write_file(
+        "src/settings-form.tsx",
+        r#"
+  export function SettingsForm({ field }) {
+      const t = useTranslations();
+      return <label>{t(field)}</label>;
+  }
+  "#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+
+    Ok(())
+}
+
+#[test]
+fn test_template_with_expr_warning() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
+    )?;
+
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+  const t = useTranslations("Common");
+  export function Button({ prefix }) {
+      return <button>{t(`${prefix}.submit`)}</button>;
+  }
+  "#,
+    )?;
 
     assert_cmd_snapshot!(test.check_command());
 
@@ -460,7 +673,7 @@ fn test_replica_lag_none() -> Result<()> {
 }
 
 #[test]
-fn test_unused_key() -> Result<()> {
+fn test_template_with_expr_non_jsx_context() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -472,19 +685,22 @@ fn test_unused_key() -> Result<()> {
         }"#,
     )?;
 
-    // Define a key that's never used
     test.write_file(
         "messages/en.json",
-        r#"{"Common": {"submit": "Submit", "unused": "Unused Button"}}"#,
+        r#"{"Common": {"error": {"unknown": "Unknown error"}}}"#,
     )?;
 
-    // Only use "submit", not "unused"
+    // t() call in a callback, not in JSX - should use // comment style
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
+import {useTranslations} from 'next-intl';
+export function Component() {
+    const t = useTranslations('Common');
+    const handleError = (code: string) => {
+        console.log(t(`error.${code}`));
+    };
+    return <button onClick={() => handleError('unknown')}>Click</button>;
 }
 "#,
     )?;
@@ -495,7 +711,7 @@ export function Button() {
 }
 
 #[test]
-fn test_no_unused_key_when_used() -> Result<()> {
+fn test_replica_lag() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -507,8 +723,28 @@ fn test_no_unused_key_when_used() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    // Primary locale has both keys
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "submit": "Submit",
+                "cancel": "Cancel"
+            }
+        }"#,
+    )?;
+
+    // zh is missing "cancel"
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "submit": "提交"
+            }
+        }"#,
+    )?;
 
+    // Empty source file (no hardcoded text)
     test.write_file(
         "src/app.tsx",
         r#"
@@ -525,319 +761,3495 @@ export function Button() {
 }
 
 #[test]
-fn test_astro_static_keys_not_reported_as_unused() -> Result<()> {
+fn test_replica_lag_multiple_locales() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/i18n/messages",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "src/i18n/messages/en.json",
+        "messages/en.json",
         r#"{
-            "meta": { "title": "Title" },
-            "nav": {
-                "github": "GitHub",
-                "signIn": "Sign in"
+            "Common": {
+                "submit": "Submit"
             }
         }"#,
     )?;
 
-    test.write_file(
-        "src/pages/index.astro",
-        r#"---
-import { useTranslation } from "@/i18n/messages";
-
-const locale = "en";
-const { t } = useTranslation(locale);
----
-
-<div title={t("nav.github")} aria-label={t("nav.github")}>
-  {t("meta.title")}
-  {t("nav.signIn")}
-</div>
-"#,
-    )?;
+    // Both zh and ja are missing the key
+    test.write_file("messages/zh.json", r#"{}"#)?;
+    test.write_file("messages/ja.json", r#"{}"#)?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for Astro fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("[unused-key]"),
-        "static t() calls inside .astro files should mark keys as used. stdout:\n{}",
-        stdout
-    );
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_astro_template_dynamic_key_warning_uses_html_comment_hint() -> Result<()> {
+fn test_replica_lag_none() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/i18n/messages",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_unused_key() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "src/i18n/messages/en.json",
+        ".glotrc.json",
         r#"{
-            "feature": {
-                "a": { "title": "A" },
-                "b": { "title": "B" }
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
+    // Define a key that's never used
     test.write_file(
-        "src/pages/index.astro",
-        r#"---
-const { t } = useTranslation();
----
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "unused": "Unused Button"}}"#,
+    )?;
 
-<div>
-  {t(`feature.${key}.title`)}
-</div>
+    // Only use "submit", not "unused"
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
+}
 "#,
     )?;
 
-    let output = test.check_command().arg("unresolved").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for Astro unresolved fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        stdout.contains("<!-- glot-message-keys \"feature.*.title\" -->"),
-        "Astro template unresolved hints should recommend HTML comments. stdout:\n{}",
-        stdout
-    );
-    assert!(
-        !stdout.contains("{/* glot-message-keys"),
-        "Astro template unresolved hints should not recommend JSX comments. stdout:\n{}",
-        stdout
-    );
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_astro_template_dynamic_key_with_html_glot_message_keys_resolves() -> Result<()> {
+fn test_no_unused_key_when_used() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/i18n/messages",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "src/i18n/messages/en.json",
-        r#"{
-            "feature": {
-                "a": { "title": "A" },
-                "b": { "title": "B" }
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
 
     test.write_file(
-        "src/pages/index.astro",
-        r#"---
-const { t } = useTranslation();
----
-
-<div>
-  <!-- glot-message-keys "feature.*.title" -->
-  {t(`feature.${key}.title`)}
-</div>
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
+}
 "#,
     )?;
 
-    let output = test
-        .check_command()
-        .arg("unused")
-        .arg("unresolved")
-        .output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for Astro declared dynamic keys. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("[unused-key]") && !stdout.contains("[unresolved-key]"),
-        "Astro HTML glot-message-keys comments should resolve dynamic usages. stdout:\n{}",
-        stdout
-    );
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_astro_multi_file_regression_only_dynamic_frontmatter_keys_remain() -> Result<()> {
+fn test_no_unused_key_when_only_used_in_disabled_regions() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/i18n/messages",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "src/i18n/messages/en.json",
-        r#"{
-            "meta": {
-                "title": "Title",
-                "description": "Description"
-            },
-            "nav": {
-                "github": "GitHub",
-                "signIn": "Sign in"
-            },
-            "hero": {
-                "title": "Hero title",
-                "subtitle": "Hero subtitle"
-            },
-            "features": {
-                "items": {
-                    "zeroInstall": {
-                        "title": "Zero install",
-                        "text": "Read anywhere"
-                    },
-                    "negotiation": {
-                        "title": "One URL",
-                        "text": "Three formats"
-                    }
-                }
-            }
+        "messages/en.json",
+        r#"{"Legacy": {"banner": "Banner", "footer": "Footer", "title": "Title"}}"#,
+    )?;
+
+    // Each key is only used on a line where another rule is disabled
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Legacy");
+export function Legacy() {
+    return (
+        <div>
+            {/* glot-disable hardcoded */}
+            <span>Old {t("banner")}</span>
+            {/* glot-enable */}
+            {/* glot-disable-next-line hardcoded untranslated */}
+            <p>Old {t("footer")}</p>
+            {/* glot-disable-next-line */}
+            <h1>{t("title")}</h1>
+        </div>
+    );
+}
+"#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command().args(["unused", "unused-namespace"]));
+
+    Ok(())
+}
+
+#[test]
+fn test_unused_key_with_namespace_roots() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "namespaceRoots": { "Dashboard": ["src/dashboard/**"] }
         }"#,
     )?;
 
     test.write_file(
-        "src/layouts/BaseLayout.astro",
-        r#"---
-const { title, description } = Astro.props;
----
+        "messages/en.json",
+        r#"{
+            "Dashboard": { "title": "Dashboard", "stale": "Stale" },
+            "Common": { "save": "Save" }
+        }"#,
+    )?;
 
-<html>
-  <head>
-    <title>{title}</title>
-    <meta name="description" content={description} />
-  </head>
-  <body>
-    <slot />
-  </body>
-</html>
+    test.write_file(
+        "src/dashboard/page.tsx",
+        r#"
+const t = useTranslations("Dashboard");
+export function Page() {
+    return <h1>{t("title")}</h1>;
+}
 "#,
     )?;
 
+    // `Dashboard.stale` is only used outside the Dashboard roots
     test.write_file(
-        "src/components/site/SiteHeader.astro",
-        r#"---
-const { t } = useTranslation();
----
+        "src/settings/page.tsx",
+        r#"
+const t = useTranslations();
+export function Settings() {
+    return <p>{t("Dashboard.stale")} {t("Common.save")}</p>;
+}
+"#,
+    )?;
 
-<header>
-  <a title={t("nav.github")} aria-label={t("nav.github")}>GitHub</a>
-  <button>{t("nav.signIn")}</button>
-</header>
+    assert_cmd_snapshot!(test.check_command().arg("unused"));
+
+    Ok(())
+}
+
+#[test]
+fn test_unused_key_dynamic_key_hint() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Status": { "active": "Active", "archived": "Archived" },
+            "Common": { "stale": "Stale" }
+        }"#,
+    )?;
+
+    // `Status.*` keys may be reached through the template literal
+    test.write_file(
+        "src/status.tsx",
+        r#"
+const t = useTranslations();
+export function Status({ status }: { status: string }) {
+    return <span>{t(`Status.${status}`)}</span>;
+}
 "#,
     )?;
 
+    assert_cmd_snapshot!(test.check_command().arg("unused"));
+
+    Ok(())
+}
+
+#[test]
+fn test_astro_static_keys_not_reported_as_unused() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "src/components/home/HomePage.astro",
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/i18n/messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/i18n/messages/en.json",
+        r#"{
+            "meta": { "title": "Title" },
+            "nav": {
+                "github": "GitHub",
+                "signIn": "Sign in"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/pages/index.astro",
         r#"---
-const { t } = useTranslation();
-const featureItems = ["zeroInstall", "negotiation"].map((key) => ({
-  title: t(`features.items.${key}.title`),
-  text: t(`features.items.${key}.text`),
-}));
+import { useTranslation } from "@/i18n/messages";
+
+const locale = "en";
+const { t } = useTranslation(locale);
 ---
 
-<section>
-  <h1>{t("hero.title")}</h1>
-  <p>{t("hero.subtitle")}</p>
-  {featureItems.length}
-</section>
+<div title={t("nav.github")} aria-label={t("nav.github")}>
+  {t("meta.title")}
+  {t("nav.signIn")}
+</div>
 "#,
     )?;
 
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for Astro fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("[unused-key]"),
+        "static t() calls inside .astro files should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_astro_template_dynamic_key_warning_uses_html_comment_hint() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/i18n/messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/i18n/messages/en.json",
+        r#"{
+            "feature": {
+                "a": { "title": "A" },
+                "b": { "title": "B" }
+            }
+        }"#,
+    )?;
+
     test.write_file(
         "src/pages/index.astro",
         r#"---
-import HomePage from "@/components/home/HomePage.astro";
-import SiteHeader from "@/components/site/SiteHeader.astro";
-import BaseLayout from "@/layouts/BaseLayout.astro";
-
 const { t } = useTranslation();
 ---
 
-<BaseLayout title={t("meta.title")} description={t("meta.description")}>
-  <SiteHeader />
-  <HomePage />
-</BaseLayout>
+<div>
+  {t(`feature.${key}.title`)}
+</div>
 "#,
     )?;
 
-    let output = test
-        .check_command()
-        .arg("unused")
-        .arg("unresolved")
-        .output()?;
+    let output = test.check_command().arg("unresolved").output()?;
     assert!(
         output.status.success(),
-        "check command should succeed for realistic Astro fixtures. stderr: {}",
+        "check command should succeed for Astro unresolved fixtures. stderr: {}",
         String::from_utf8_lossy(&output.stderr)
     );
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert_eq!(stdout.matches("[unresolved-key]").count(), 2);
-    assert_eq!(stdout.matches("[unused-key]").count(), 4);
-    assert!(stdout.contains("features.items.*.title"));
-    assert!(stdout.contains("features.items.*.text"));
-    assert!(stdout.contains("features.items.zeroInstall.title"));
-    assert!(stdout.contains("features.items.negotiation.text"));
-    assert!(!stdout.contains("meta.title"));
-    assert!(!stdout.contains("meta.description"));
-    assert!(!stdout.contains("nav.github"));
-    assert!(!stdout.contains("nav.signIn"));
-    assert!(!stdout.contains("hero.title"));
-    assert!(!stdout.contains("hero.subtitle"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("<!-- glot-message-keys \"feature.*.title\" -->"),
+        "Astro template unresolved hints should recommend HTML comments. stdout:\n{}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("{/* glot-message-keys"),
+        "Astro template unresolved hints should not recommend JSX comments. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_astro_template_dynamic_key_with_html_glot_message_keys_resolves() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/i18n/messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/i18n/messages/en.json",
+        r#"{
+            "feature": {
+                "a": { "title": "A" },
+                "b": { "title": "B" }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/pages/index.astro",
+        r#"---
+const { t } = useTranslation();
+---
+
+<div>
+  <!-- glot-message-keys "feature.*.title" -->
+  {t(`feature.${key}.title`)}
+</div>
+"#,
+    )?;
+
+    let output = test
+        .check_command()
+        .arg("unused")
+        .arg("unresolved")
+        .output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for Astro declared dynamic keys. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("[unused-key]") && !stdout.contains("[unresolved-key]"),
+        "Astro HTML glot-message-keys comments should resolve dynamic usages. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_astro_multi_file_regression_only_dynamic_frontmatter_keys_remain() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/i18n/messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/i18n/messages/en.json",
+        r#"{
+            "meta": {
+                "title": "Title",
+                "description": "Description"
+            },
+            "nav": {
+                "github": "GitHub",
+                "signIn": "Sign in"
+            },
+            "hero": {
+                "title": "Hero title",
+                "subtitle": "Hero subtitle"
+            },
+            "features": {
+                "items": {
+                    "zeroInstall": {
+                        "title": "Zero install",
+                        "text": "Read anywhere"
+                    },
+                    "negotiation": {
+                        "title": "One URL",
+                        "text": "Three formats"
+                    }
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/layouts/BaseLayout.astro",
+        r#"---
+const { title, description } = Astro.props;
+---
+
+<html>
+  <head>
+    <title>{title}</title>
+    <meta name="description" content={description} />
+  </head>
+  <body>
+    <slot />
+  </body>
+</html>
+"#,
+    )?;
+
+    test.write_file(
+        "src/components/site/SiteHeader.astro",
+        r#"---
+const { t } = useTranslation();
+---
+
+<header>
+  <a title={t("nav.github")} aria-label={t("nav.github")}>GitHub</a>
+  <button>{t("nav.signIn")}</button>
+</header>
+"#,
+    )?;
+
+    test.write_file(
+        "src/components/home/HomePage.astro",
+        r#"---
+const { t } = useTranslation();
+const featureItems = ["zeroInstall", "negotiation"].map((key) => ({
+  title: t(`features.items.${key}.title`),
+  text: t(`features.items.${key}.text`),
+}));
+---
+
+<section>
+  <h1>{t("hero.title")}</h1>
+  <p>{t("hero.subtitle")}</p>
+  {featureItems.length}
+</section>
+"#,
+    )?;
+
+    test.write_file(
+        "src/pages/index.astro",
+        r#"---
+import HomePage from "@/components/home/HomePage.astro";
+import SiteHeader from "@/components/site/SiteHeader.astro";
+import BaseLayout from "@/layouts/BaseLayout.astro";
+
+const { t } = useTranslation();
+---
+
+<BaseLayout title={t("meta.title")} description={t("meta.description")}>
+  <SiteHeader />
+  <HomePage />
+</BaseLayout>
+"#,
+    )?;
+
+    let output = test
+        .check_command()
+        .arg("unused")
+        .arg("unresolved")
+        .output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for realistic Astro fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("[unresolved-key]").count(), 2);
+    assert_eq!(stdout.matches("[unused-key]").count(), 4);
+    assert!(stdout.contains("features.items.*.title"));
+    assert!(stdout.contains("features.items.*.text"));
+    assert!(stdout.contains("features.items.zeroInstall.title"));
+    assert!(stdout.contains("features.items.negotiation.text"));
+    assert!(!stdout.contains("meta.title"));
+    assert!(!stdout.contains("meta.description"));
+    assert!(!stdout.contains("nav.github"));
+    assert!(!stdout.contains("nav.signIn"));
+    assert!(!stdout.contains("hero.title"));
+    assert!(!stdout.contains("hero.subtitle"));
+
+    Ok(())
+}
+
+#[test]
+fn test_vue_sfc_template_script_and_i18n_block() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "frameworks": ["vue"]
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Home": {
+    "title": "Home",
+    "search": "Search",
+    "label": "Label",
+    "unused": "Unused"
+  }
+}"#,
+    )?;
+
+    // `Home.missing` is the only missing key, `Home.greeting` comes from <i18n>
+    test.write_file(
+        "src/App.vue",
+        r#"<template>
+  <h1>{{ $t("Home.title") }}</h1>
+  <input :placeholder="t('Home.search')" />
+  <p>{{ $t("Home.greeting") }}</p>
+</template>
+
+<script setup lang="ts">
+import { useI18n } from "vue-i18n";
+const { t } = useI18n();
+const label = t("Home.label");
+const missing = t("Home.missing");
+</script>
+
+<i18n>
+{
+  "en": { "Home": { "greeting": "Hello" } }
+}
+</i18n>
+"#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command().args(["missing", "unused"]));
+
+    Ok(())
+}
+
+#[test]
+fn test_vue_files_ignored_without_frameworks() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("messages/en.json", r#"{ "Home": { "title": "Home" } }"#)?;
+    test.write_file(
+        "src/App.vue",
+        r#"<template>
+  <h1>{{ $t("Home.missing") }}</h1>
+</template>
+"#,
+    )?;
+
+    let output = test.check_command().arg("missing").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "stdout:\n{}", stdout);
+    assert!(
+        !stdout.contains("App.vue"),
+        ".vue files should not be scanned unless frameworks includes vue. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_vue_template_dynamic_key_uses_html_comment_hint() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "frameworks": ["vue"]
+        }"#,
+    )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{ "feature": { "a": { "title": "A" }, "b": { "title": "B" } } }"#,
+    )?;
+    test.write_file(
+        "src/Feature.vue",
+        r#"<template>
+  <div>{{ $t(`feature.${name}.title`) }}</div>
+</template>
+
+<script setup>
+defineProps(["name"]);
+</script>
+"#,
+    )?;
+
+    let output = test.check_command().arg("unresolved").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("<!-- glot-message-keys \"feature.*.title\" -->"),
+        "Vue template unresolved hints should recommend HTML comments. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_orphan_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Primary locale only has "submit"
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    // zh has an extra key "oldKey" that doesn't exist in en
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"submit": "提交", "oldKey": "旧的按钮"}}"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_orphan_key_multiple_locales() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"submit": "提交", "orphan1": "孤儿1"}}"#,
+    )?;
+
+    test.write_file(
+        "messages/ja.json",
+        r#"{"Common": {"submit": "送信", "orphan2": "孤児2"}}"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_yaml_locale_files() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.yml",
+        r#"# Shared strings
+Common:
+  submit: Submit
+  steps:
+    - Sign up
+    - Confirm
+  terms: |
+    By continuing you agree
+    to our terms.
+"#,
+    )?;
+    test.write_file(
+        "messages/de.yaml",
+        r#"Common:
+  submit: "Senden"
+  legacy: Alt
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+  const t = useTranslations("Common");
+  export function Form() {
+      return (
+          <form>
+              <button>{t("submit")}</button>
+              <p>{t("terms")}</p>
+              <Steps items={t.raw("steps")} />
+              <button>{t("cancel")}</button>
+          </form>
+      );
+  }
+  "#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_only_changed_locales() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
+    )?;
+    test.write_file(
+        "messages/de.json",
+        r#"{"Common": {"submit": "Senden", "legacy": "Alt"}}"#,
+    )?;
+    test.write_file(
+        "messages/fr.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Annuler", "old": "Vieux"}}"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+  const t = useTranslations("Common");
+  export function Form() {
+      return (
+          <form>
+              <button>{t("submit")}</button>
+              <button>{t("cancel")}</button>
+              <p>Hello</p>
+          </form>
+      );
+  }
+  "#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command().args(["--only-changed-locales", "de"]));
+
+    // Listing every non-primary locale is the same as a full run
+    let full = test.check_command().output()?;
+    let listed = test
+        .check_command()
+        .args(["--only-changed-locales", "de,fr"])
+        .output()?;
+    assert_eq!(
+        String::from_utf8_lossy(&full.stdout),
+        String::from_utf8_lossy(&listed.stdout)
+    );
+
+    assert_cmd_snapshot!(
+        "only_changed_locales_unknown",
+        test.check_command().args(["--only-changed-locales", "es"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_locales_filter() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en"
+          }"#,
+    )?;
+
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/de.json", r#"{"Common": {"submit": "Senden"}}"#)?;
+    test.write_file("messages/fr.json", r#"{"Common": {"old": "Vieux"}}"#)?;
+    test.write_file("messages/ja.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+  const t = useTranslations("Common");
+  export function Form() {
+      return <button>{t("submit")}</button>;
+  }
+  "#,
+    )?;
+
+    // fr and ja aren't loaded, so only en and de are counted
+    assert_cmd_snapshot!(test.check_command().args(["--locales", "de"]));
+    assert_cmd_snapshot!(
+        "locales_filter_replica",
+        test.check_command().args(["--locales", "de,ja"])
+    );
+    assert_cmd_snapshot!(
+        "locales_filter_unknown",
+        test.check_command().args(["--locales", "es"])
+    );
+
+    Ok(())
+}
+
+// ============================================
+// Subcommand tests
+// ============================================
+
+#[test]
+fn test_subcommand_hardcoded() -> Result<()> {
+    let test = CliTest::with_next_intl_file(
+        "src/app/[locale]/app.tsx",
+        r#"
+  export function Button() {
+      return <button>Submit</button>;
+  }
+  "#,
+    )?;
+
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+
+    Ok(())
+}
+
+#[test]
+fn test_subcommand_missing() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"cancel": "取消"}}"#)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+        const t = useTranslations("Common");
+        t("submit");
+        "#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
+
+    Ok(())
+}
+
+#[test]
+fn test_subcommand_orphan() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"submit": "提交", "oldKey": "旧的按钮"}}"#,
+    )?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    assert_cmd_snapshot!(test.check_command().arg("orphan"));
+
+    Ok(())
+}
+
+// ============================================
+// Parameter combination tests
+// ============================================
+
+// #[test]
+// fn test_subcommand_with_path_arg() -> Result<()> {
+//     let test = CliTest::new()?;
+
+//     // Create files in a subdirectory
+//     test.write_file(
+//         "subdir/app.tsx",
+//         r#"
+//   export function Button() {
+//       return <button>Submit</button>;
+//   }
+//   "#,
+//     )?;
+
+//     test.write_file(
+//         "subdir/messages/en.json",
+//         r#"{"Common": {"submit": "Submit"}}"#,
+//     )?;
+
+//     // Without --path, should find nothing (default is "." which has no tsx files at root)
+//     assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+
+//     // With --path subdir, should find the issue (args before subcommand)
+//     assert_cmd_snapshot!(
+//         test.check_command()
+//             .arg("--path")
+//             .arg("subdir")
+//             .arg("hardcoded")
+//     );
+
+//     Ok(())
+// }
+
+#[test]
+fn test_subcommand_with_verbose_arg() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./nonexistent",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Without --verbose, no warning output
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
+
+    // With --verbose, should show warning about missing messages dir (args before subcommand)
+    assert_cmd_snapshot!(test.check_command().arg("--verbose").arg("missing"));
+
+    Ok(())
+}
+
+// ============================================
+// Schema Factory Function Tests
+// ============================================
+
+#[test]
+fn test_schema_factory_keys_tracked_as_used() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Define all keys including those used in schema
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Form": {
+                "titleRequired": "Title is required",
+                "descMax": "Description too long"
+            }
+        }"#,
+    )?;
+
+    // Schema factory function file
+    test.write_file(
+        "src/schemas.ts",
+        r#"
+export const createFormSchema = (t) => z.object({
+    title: z.string().min(1, t("titleRequired")),
+    desc: z.string().max(100, t("descMax")),
+});
+"#,
+    )?;
+
+    // Component that uses the schema
+    test.write_file(
+        "src/form.tsx",
+        r#"
+const tForm = useTranslations("Form");
+const schema = createFormSchema(tForm);
+"#,
+    )?;
+
+    // Keys should be tracked as used, so no unused key warnings
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_factory_missing_key_reported() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Missing "titleRequired" key
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Form": {
+                "descMax": "Description too long"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/schemas.ts",
+        r#"
+export const createFormSchema = (t) => z.object({
+    title: z.string().min(1, t("titleRequired")),
+    desc: z.string().max(100, t("descMax")),
+});
+"#,
+    )?;
+
+    test.write_file(
+        "src/form.tsx",
+        r#"
+const tForm = useTranslations("Form");
+const schema = createFormSchema(tForm);
+"#,
+    )?;
+
+    // Should report missing key
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_factory_nested_calls() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All keys defined
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Form": {
+                "baseKey": "Base field required",
+                "extendedKey": "Extended field required"
+            }
+        }"#,
+    )?;
+
+    // Nested schema functions
+    test.write_file(
+        "src/schemas.ts",
+        r#"
+export const createBaseSchema = (t) => z.object({
+    base: z.string().min(1, t("baseKey")),
+});
+
+export const createExtendedSchema = (t) =>
+    createBaseSchema(t).extend({
+        extended: z.string().min(1, t("extendedKey")),
+    });
+"#,
+    )?;
+
+    test.write_file(
+        "src/form.tsx",
+        r#"
+const tForm = useTranslations("Form");
+const schema = createExtendedSchema(tForm);
+"#,
+    )?;
+
+    // Both base and extended keys should be tracked
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_key_all_candidates_exist() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All candidate keys exist in messages
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Tools": {
+                "createNovel": "Create Novel",
+                "updateNovel": "Update Novel"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const toolKeys = {
+    createNovel: "createNovel",
+    updateNovel: "updateNovel",
+};
+const t = useTranslations("Tools");
+export function Tool({ toolName }) {
+    const key = toolKeys[toolName];
+    return t(key);
+}
+"#,
+    )?;
+
+    // Should pass silently - all candidates exist
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_key_some_candidates_missing() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Only one key exists, other is missing
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Tools": {
+                "createNovel": "Create Novel"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const toolKeys = {
+    createNovel: "createNovel",
+    updateNovel: "updateNovel",
+};
+const t = useTranslations("Tools");
+export function Tool({ toolName }) {
+    const key = toolKeys[toolName];
+    return t(key);
+}
+"#,
+    )?;
+
+    // Should report error for missing candidate
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_dynamic_key_string_enum() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Tools": {
+                "create": "Create",
+                "edit": "Edit",
+                "unused": "Unused"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/tools.ts",
+        r#"
+export enum Tool {
+    Create = "create",
+    Edit = "edit",
+    Delete = "delete",
+}
+
+export const enum Status {
+    Draft,
+    Published,
+}
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { Tool } from "./tools";
+
+const t = useTranslations("Tools");
+export function ToolButton({ tool }) {
+    return t(Tool[tool]);
+}
+export function CreateButton() {
+    return t(Tool.Create);
+}
+"#,
+    )?;
+
+    // "delete" is a missing candidate; "unused" stays unused
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+/// Test that both static and dynamic missing keys are reported together.
+/// This ensures the CLI output is consistent when both types of issues exist.
+///
+/// The test verifies that:
+/// - Static missing keys (directly used t("key")) are reported
+/// - Dynamic missing keys from resolvable objects are expanded and reported
+/// - The total count reflects all individual missing keys
+#[test]
+fn test_missing_key_static_and_dynamic_combined() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Only Common.title exists
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Tools": {
+                "create": "Create"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+// Static missing key
+const t = useTranslations("Tools");
+const missing = t("delete");
+
+// Dynamic missing keys from object (resolvable pattern, partly defined)
+const toolKeys = {
+    create: "create",
+    update: "update",
+    archive: "archive",
+};
+function Tool({ name }: { name: keyof typeof toolKeys }) {
+    const key = toolKeys[name];
+    return t(key);
+}
+"#,
+    )?;
+
+    // Should report:
+    // - 1 static missing key (Tools.delete)
+    // - 2 dynamic missing keys (Tools.update, Tools.archive from toolKeys)
+    // Total: 3 missing keys
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_unresolved_object_is_not_a_key_map() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Status": {
+                "ok": "OK"
+            }
+        }"#,
+    )?;
+
+    // None of the object's values is a key: one warning instead of three missing keys
+    test.write_file(
+        "src/app.tsx",
+        r#"const statusColors = {
+    ok: "green",
+    warn: "orange",
+    error: "red",
+};
+export function Status({ status }: { status: keyof typeof statusColors }) {
+    const t = useTranslations("Status");
+    const color = statusColors[status];
+    return <span>{t("ok")}{t(color)}</span>;
+}
+"#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+// ============================================
+// String Array Iteration Tests
+// ============================================
+
+#[test]
+fn test_string_array_iteration_same_file_all_exist() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All candidate keys exist
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Features": {
+                "prefix.save": "Save",
+                "prefix.load": "Load"
+            }
+        }"#,
+    )?;
+
+    // String array and component in SAME file
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const FEATURE_KEYS = ["save", "load"] as const;
+const t = useTranslations("Features");
+FEATURE_KEYS.map((k) => t(`prefix.${k}`));
+"#,
+    )?;
+
+    // Expected: No errors - all keys exist
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_string_array_iteration_same_file_some_missing() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Only one key exists
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Features": {
+                "prefix.save": "Save"
+            }
+        }"#,
+    )?;
+
+    // String array and component in SAME file
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const FEATURE_KEYS = ["save", "load"] as const;
+const t = useTranslations("Features");
+FEATURE_KEYS.map((k) => t(`prefix.${k}`));
+"#,
+    )?;
+
+    // Expected: Error for missing "Features.prefix.load"
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+// ============================================
+// Object Key Iteration Tests
+// ============================================
+
+#[test]
+fn test_object_keys_iteration_all_exist() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Actions": {
+                "save": "Save",
+                "load": "Load",
+                "saveHint": "Save your work",
+                "loadHint": "Load a file"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const hints = { save: "saveHint", load: "loadHint" };
+const t = useTranslations("Actions");
+Object.keys(hints).map((action) => t(action));
+Object.entries(hints).map(([action, hint]) => t(hint));
+"#,
+    )?;
+
+    // Expected: No errors and no unused keys
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_object_entries_iteration_some_missing() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Actions": {
+                "button": {
+                    "save": "Save"
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/labels.ts",
+        r#"
+export const labels = { save: "Save", load: "Load" };
+"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { labels } from "./labels";
+const t = useTranslations("Actions");
+Object.entries(labels).map(([action]) => t(`button.${action}`));
+"#,
+    )?;
+
+    // Expected: Error for missing "Actions.button.load"
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_key_object_imported_through_barrel_re_exports() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Tools": {
+                "create": "Create"
+            }
+        }"#,
+    )?;
+
+    // src/app.tsx -> src/shared.ts -> src/keys/index.ts -> src/keys/tools.ts
+    test.write_file(
+        "src/keys/tools.ts",
+        r#"
+export const toolKeys = { create: "create", archive: "archive" };
+"#,
+    )?;
+    test.write_file("src/keys/index.ts", r#"export * from "./tools";"#)?;
+    test.write_file(
+        "src/shared.ts",
+        r#"export { toolKeys as keys } from "./keys";"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { keys } from "./shared";
+const t = useTranslations("Tools");
+function Tool({ name }: { name: keyof typeof keys }) {
+    const key = keys[name];
+    return t(key);
+}
+"#,
+    )?;
+
+    // Expected: Error for missing "Tools.archive"
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+// ============================================
+// Translation Props Tests
+// ============================================
+
+#[test]
+fn test_translation_prop_keys_tracked_as_used() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All keys defined
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Landing": {
+                "title": "Welcome",
+                "description": "This is a description"
+            }
+        }"#,
+    )?;
+
+    // Parent component passes t to child
+    test.write_file(
+        "src/page.tsx",
+        r#"
+const t = useTranslations("Landing");
+export function Page() {
+    return <LandingContent t={t} />;
+}
+"#,
+    )?;
+
+    // Child component receives t as prop and uses it
+    test.write_file(
+        "src/landing-content.tsx",
+        r#"
+export function LandingContent({ t }: Props) {
+    return (
+        <div>
+            <h1>{t("title")}</h1>
+            <p>{t("description")}</p>
+        </div>
+    );
+}
+"#,
+    )?;
+
+    // Keys should be tracked as used via the prop, no unused warnings
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_prop_missing_key_reported() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Missing "subtitle" key
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Landing": {
+                "title": "Welcome"
+            }
+        }"#,
+    )?;
+
+    // Parent component passes t to child
+    test.write_file(
+        "src/page.tsx",
+        r#"
+const t = useTranslations("Landing");
+export function Page() {
+    return <LandingContent t={t} />;
+}
+"#,
+    )?;
+
+    // Child component uses a key that doesn't exist
+    test.write_file(
+        "src/landing-content.tsx",
+        r#"
+export function LandingContent({ t }: Props) {
+    return (
+        <div>
+            <h1>{t("title")}</h1>
+            <p>{t("subtitle")}</p>
+        </div>
+    );
+}
+"#,
+    )?;
+
+    // Should report missing key "subtitle"
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_prop_forwarded_through_components() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Landing": {
+                "title": "Welcome",
+                "unused": "Unused"
+            },
+            "About": {
+                "title": "About us"
+            }
+        }"#,
+    )?;
+
+    // Two pages pass t with different namespaces
+    test.write_file(
+        "src/landing.tsx",
+        r#"
+const t = useTranslations("Landing");
+export function Landing() {
+    return <Card t={t} />;
+}
+"#,
+    )?;
+    test.write_file(
+        "src/about.tsx",
+        r#"
+const t = useTranslations("About");
+export function About() {
+    return <Card t={t} />;
+}
+"#,
+    )?;
+
+    // Card forwards t two levels down, renaming it on the way
+    test.write_file(
+        "src/card.tsx",
+        r#"
+import { CardHeader } from "./card-header";
+
+export function Card({ t }: Props) {
+    return <CardHeader translate={t} />;
+}
+"#,
+    )?;
+    test.write_file(
+        "src/card-header.tsx",
+        r#"
+import CardTitle from "./card-title";
+
+export function CardHeader({ translate }: Props) {
+    return <CardTitle t={translate} />;
+}
+"#,
+    )?;
+    test.write_file(
+        "src/card-title.tsx",
+        r#"
+export default function CardTitle({ t }: Props) {
+    return <h1>{t("title")}</h1>;
+}
+"#,
+    )?;
+
+    // Both namespaces reach CardTitle: only Landing.unused is unused
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_prop_arrow_function_component() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Card": {
+                "title": "Card Title"
+            }
+        }"#,
+    )?;
+
+    // Parent passes t to arrow function child
+    test.write_file(
+        "src/page.tsx",
+        r#"
+const t = useTranslations("Card");
+export function Page() {
+    return <CardComponent t={t} />;
+}
+"#,
+    )?;
+
+    // Arrow function component
+    test.write_file(
+        "src/card.tsx",
+        r#"
+export const CardComponent = ({ t }: Props) => {
+    return <div>{t("title")}</div>;
+};
+"#,
+    )?;
+
+    // Should pass - key is tracked via prop
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_prop_with_relative_glot_message_keys() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All dynamic keys exist
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Features": {
+                "items": {
+                    "save": { "title": "Save" },
+                    "load": { "title": "Load" }
+                }
+            }
+        }"#,
+    )?;
+
+    // Parent passes t to child
+    test.write_file(
+        "src/page.tsx",
+        r#"
+const t = useTranslations("Features");
+export function Page() {
+    return <FeatureList t={t} items={items} />;
+}
+"#,
+    )?;
+
+    // Child uses dynamic keys with relative glot-message-keys annotation
+    test.write_file(
+        "src/feature-list.tsx",
+        r#"
+export function FeatureList({ t, items }: Props) {
+    return items.map(item => {
+        // glot-message-keys ".items.*.title"
+        return <span>{t(`items.${item.key}.title`)}</span>;
+    });
+}
+"#,
+    )?;
+
+    // Should pass - relative pattern expanded with namespace from prop
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+// ============================================
+// Translation Function Call Tests
+// ============================================
+
+#[test]
+fn test_translation_fn_call_same_file_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All keys defined
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Usage": {
+                "monthly": "Monthly",
+                "yearly": "Yearly"
+            }
+        }"#,
+    )?;
+
+    // Utility function and component in SAME file
+    // usageLabels receives t as argument and uses it to translate keys
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const usageLabels = (t) => ({
+    monthly: t("monthly"),
+    yearly: t("yearly"),
+});
+
+export function UsagePage() {
+    const t = useTranslations("Usage");
+    const labels = usageLabels(t);
+    return <div>{labels.monthly}</div>;
+}
+"#,
+    )?;
+
+    // Keys should be tracked as used via function call, no unused warnings
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_import_alias_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All keys defined
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Status": {
+                "active": "Active",
+                "inactive": "Inactive"
+            }
+        }"#,
+    )?;
+
+    // Utility file with named export
+    test.write_file(
+        "src/utils.ts",
+        r#"
+export const buildStatusLabels = (t) => ({
+    active: t("active"),
+    inactive: t("inactive"),
+});
+"#,
+    )?;
+
+    // Component imports with alias and calls the function
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { buildStatusLabels as statusLabels } from './utils';
+
+export function StatusPage() {
+    const t = useTranslations("Status");
+    const labels = statusLabels(t);
+    return <div>{labels.active}</div>;
+}
+"#,
+    )?;
+
+    // Keys should be tracked via aliased import, no unused warnings
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_paths_alias_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "tsconfig.json",
+        r#"{
+            "compilerOptions": {
+                "paths": {
+                    "@/*": ["./src/*"]
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "validation": {
+                    "emailRequired": "Email is required."
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/lib/form-schemas.ts",
+        r#"
+export function createSignupSchema(t) {
+    return {
+        email: t("auth.validation.emailRequired"),
+    };
+}
+"#,
+    )?;
+
+    test.write_file(
+        "src/components/app.tsx",
+        r#"
+import { useTranslation } from 'react-i18next';
+import { createSignupSchema } from '@/lib/form-schemas';
+
+export function App() {
+    const { t } = useTranslation();
+    const schema = createSignupSchema(t);
+    return <div>{schema.email}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for paths alias fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("auth.validation.emailRequired") && !stdout.contains("[unused-key]"),
+        "paths alias translation fn calls should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_key_object_paths_alias_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "tsconfig.json",
+        r#"{
+            "compilerOptions": {
+                "baseUrl": ".",
+                "paths": {
+                    "@/*": ["./src/*"]
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Status": {
+                "active": "Active",
+                "inactive": "Inactive",
+                "archived": "Archived"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/constants/status.ts",
+        r#"
+export const STATUS_KEYS = {
+    active: "active",
+    inactive: "inactive",
+} as const;
+"#,
+    )?;
+
+    test.write_file(
+        "src/components/status.tsx",
+        r#"
+import { useTranslations } from 'next-intl';
+import { STATUS_KEYS } from '@/constants/status';
+
+export function Status({ status }) {
+    const t = useTranslations('Status');
+    const key = STATUS_KEYS[status];
+    return <div>{t(key)}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Status.archived"),
+        "keys outside the key object should stay unused. stdout:\n{}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("Status.active") && !stdout.contains("Status.inactive"),
+        "aliased key object values should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_nested_helper_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "validation": {
+                    "otpRequired": "Enter the verification code.",
+                    "otpInvalid": "Enter the 6-digit code."
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/form-schemas.ts",
+        r#"
+function otpField(t) {
+    return {
+        required: t("auth.validation.otpRequired"),
+        invalid: t("auth.validation.otpInvalid"),
+    };
+}
+
+export function createOtpSchema(t) {
+    return otpField(t);
+}
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { useTranslation } from 'react-i18next';
+import { createOtpSchema } from './form-schemas';
+
+export function App() {
+    const { t } = useTranslation();
+    const schema = createOtpSchema(t);
+    return <div>{schema.required}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for nested helper fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("auth.validation.otpRequired")
+            && !stdout.contains("auth.validation.otpInvalid")
+            && !stdout.contains("[unused-key]"),
+        "nested helper forwarding should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_nested_callback_param_shadow_does_not_forward() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "validation": {
+                    "otpRequired": "Enter the verification code.",
+                    "otpInvalid": "Enter the 6-digit code."
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/form-schemas.ts",
+        r#"
+function otpField(t) {
+    return {
+        required: t("auth.validation.otpRequired"),
+        invalid: t("auth.validation.otpInvalid"),
+    };
+}
+
+export function createOtpSchema(t) {
+    return ["noop"].map(t => otpField(t));
+}
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { useTranslation } from 'react-i18next';
+import { createOtpSchema } from './form-schemas';
+
+export function App() {
+    const { t } = useTranslation();
+    const schema = createOtpSchema(t);
+    return <div>{schema.length}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for nested callback shadow fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("auth.validation.otpRequired")
+            && stdout.contains("auth.validation.otpInvalid")
+            && stdout.matches("[unused-key]").count() == 2,
+        "nested callback parameters must not forward outer translation bindings. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_exported_arrow_nested_helper_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "validation": {
+                    "otpRequired": "Enter the verification code."
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/form-schemas.ts",
+        r#"
+function otpField(t) {
+    return t("auth.validation.otpRequired");
+}
+
+export const createOtpSchema = (t) => otpField(t);
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { useTranslation } from 'react-i18next';
+import { createOtpSchema } from './form-schemas';
+
+export function App() {
+    const { t } = useTranslation();
+    return <div>{createOtpSchema(t)}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("auth.validation.otpRequired") && !stdout.contains("[unused-key]"),
+        "exported arrow helpers should propagate translation bindings. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_default_export_nested_helper_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Plan": {
+                "free": "Free Plan"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/plan-labels.ts",
+        r#"
+function planValue(t) {
+    return t("free");
+}
+
+export default function buildPlanLabels(t) {
+    return planValue(t);
+}
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import buildPlanLabels from './plan-labels';
+
+export function PlanPage() {
+    const t = useTranslations("Plan");
+    return <div>{buildPlanLabels(t)}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("Plan.free") && !stdout.contains("[unused-key]"),
+        "named default exports should propagate through the default alias. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_function_expression_nested_helper_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "validation": {
+                    "otpRequired": "Enter the verification code."
+                }
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/form-schemas.ts",
+        r#"
+function otpField(t) {
+    return t("auth.validation.otpRequired");
+}
+
+export const createOtpSchema = function (t) {
+    return otpField(t);
+};
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { useTranslation } from 'react-i18next';
+import { createOtpSchema } from './form-schemas';
+
+export function App() {
+    const { t } = useTranslation();
+    return <div>{createOtpSchema(t)}</div>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("auth.validation.otpRequired") && !stdout.contains("[unused-key]"),
+        "function-expression helpers should propagate translation bindings. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_default_export_used_key() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // All keys defined
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Plan": {
+                "free": "Free Plan",
+                "pro": "Pro Plan"
+            }
+        }"#,
+    )?;
+
+    // Utility file with default export
+    test.write_file(
+        "src/plan-labels.ts",
+        r#"
+export default function buildPlanLabels(t) {
+    return {
+        free: t("free"),
+        pro: t("pro"),
+    };
+}
+"#,
+    )?;
+
+    // Component uses default import
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import buildPlanLabels from './plan-labels';
+
+export function PlanPage() {
+    const t = useTranslations("Plan");
+    const labels = buildPlanLabels(t);
+    return <div>{labels.free}</div>;
+}
+"#,
+    )?;
+
+    // Keys should be tracked via default import, no unused warnings
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_translation_fn_call_param_shadowing() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Only "outer" key is defined, "inner" is NOT defined
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Outer": {
+                "outerKey": "Outer Value"
+            }
+        }"#,
+    )?;
+
+    // Outer function receives t from registry, inner function shadows t
+    // Inner's t("innerKey") should NOT be tracked (parameter shadows outer binding)
+    test.write_file(
+        "src/utils.ts",
+        r#"
+export const outerFunc = (t) => {
+    const innerFunc = (t) => {
+        return t("innerKey");  // Should NOT be tracked - t is shadowed
+    };
+    return t("outerKey");  // Should be tracked
+};
+"#,
+    )?;
+
+    // Component calls outerFunc with translation function
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { outerFunc } from './utils';
+
+export function Page() {
+    const t = useTranslations("Outer");
+    const result = outerFunc(t);
+    return <div>{result}</div>;
+}
+"#,
+    )?;
+
+    // Only "outerKey" should be tracked, "innerKey" should be ignored (shadowed)
+    // No missing key error for "innerKey" since it's not tracked
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_use_formatter_bound_to_t_is_not_a_translation_function() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Shop": {
+                "title": "Shop"
+            }
+        }"#,
+    )?;
+
+    // The inner `t` is a formatter: neither `t.number(...)` nor the call
+    // passing it to `formatTotal` may be attributed to the Shop namespace
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const formatTotal = (t) => t("total");
+
+export function Price({ value }) {
+    const t = useTranslations("Shop");
+    if (value) {
+        const t = useFormatter();
+        return <span>{t.number(value)}{formatTotal(t)}</span>;
+    }
+    return <h1>{t("title")}</h1>;
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("missing").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("Shop.total") && !stdout.contains("Shop.number"),
+        "formatter bindings must not produce key usages. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_i18next_member_call_marks_key_as_used() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "sessionExpired": "Your session has expired."
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/session-expired.ts",
+        r#"
+import i18n from 'i18next';
+
+export function notify() {
+    return i18n.t('auth.sessionExpired');
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for i18next member-call fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("auth.sessionExpired") && !stdout.contains("[unused-key]"),
+        "i18next member calls should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_i18next_member_call_shadowed_import_is_not_counted() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "framework": "react-i18next",
+            "includes": ["src"],
+            "messagesRoot": "./src/locales",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/locales/en.json",
+        r#"{
+            "auth": {
+                "sessionExpired": "Your session has expired."
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/session-expired.ts",
+        r#"
+import i18n from 'i18next';
+
+export function notify(i18n) {
+    return i18n.t('auth.sessionExpired');
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("auth.sessionExpired") && stdout.matches("[unused-key]").count() == 1,
+        "shadowed i18next imports must not be treated as translation member calls. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extra_translation_callee_marks_key_as_used() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "extraTranslationCallees": ["tt"]
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "submit": "Submit"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.ts",
+        r#"
+export function renderLabel() {
+    return tt('Common.submit');
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for configured callee fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("Common.submit") && !stdout.contains("[unused-key]"),
+        "configured bare translation callees should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extra_translation_member_call_shadowed_import_is_not_counted() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "extraTranslationMemberCalls": [
+                {
+                    "objectName": "intl",
+                    "property": "translate",
+                    "importFrom": "./intl",
+                    "importName": "default"
+                }
+            ]
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "submit": "Submit"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/intl.ts",
+        r#"
+export default {
+    translate(key) {
+        return key;
+    },
+};
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.ts",
+        r#"
+import intl from './intl';
+
+export function renderLabel(intl) {
+    return intl.translate('Common.submit');
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Common.submit") && stdout.matches("[unused-key]").count() == 1,
+        "shadowed configured member-call imports must not count as translation usage. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extra_translation_member_call_marks_key_as_used() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "extraTranslationMemberCalls": [
+                {
+                    "objectName": "intl",
+                    "property": "translate",
+                    "importFrom": "./intl",
+                    "importName": "default"
+                }
+            ]
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "submit": "Submit"
+            }
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/intl.ts",
+        r#"
+export default {
+    translate(key) {
+        return key;
+    },
+};
+"#,
+    )?;
+
+    test.write_file(
+        "src/app.ts",
+        r#"
+import intl from './intl';
+
+export function renderLabel() {
+    return intl.translate('Common.submit');
+}
+"#,
+    )?;
+
+    let output = test.check_command().arg("unused").output()?;
+    assert!(
+        output.status.success(),
+        "check command should succeed for configured member-call fixtures. stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !stdout.contains("Common.submit") && !stdout.contains("[unused-key]"),
+        "configured translation member calls should mark keys as used. stdout:\n{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+// ============================================
+// Untranslated Value Tests
+// ============================================
+
+#[test]
+fn test_untranslated_value() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // English (primary locale)
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "submit": "Submit",
+                "cancel": "Cancel"
+            }
+        }"#,
+    )?;
+
+    // Chinese locale has same value as English (not translated)
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "submit": "Submit",
+                "cancel": "Cancel"
+            }
+        }"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should detect untranslated values
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_since() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Outside a git repository the keys have no blame information and count as old
+    assert_cmd_snapshot!(test.check_command().args(["untranslated", "--since", "7d"]));
+
+    assert_cmd_snapshot!(
+        "untranslated_since_invalid_duration",
+        test.check_command().args(["untranslated", "--since", "7y"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_correct_translation() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // English (primary locale)
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "welcome": "Welcome to our platform"
+            }
+        }"#,
+    )?;
+
+    // Correctly translated to Chinese
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "welcome": "欢迎来到我们的平台"
+            }
+        }"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should pass without issues
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_short_text() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "ok": "OK",
+                "no": "No"
+            }
+        }"#,
+    )?;
+
+    // Short texts that are the same - should be caught by untranslated check
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "ok": "OK",
+                "no": "No"
+            }
+        }"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_ignore_patterns() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    let messages = r#"{
+            "Common": {
+                "docs": "https://example.com/docs",
+                "email": "support@example.com",
+                "version": "v2.0"
+            }
+        }"#;
+    test.write_file("messages/en.json", messages)?;
+    test.write_file("messages/zh.json", messages)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // URLs and emails are skipped by default
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    // Custom patterns replace the defaults
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "untranslated": { "ignorePatterns": ["^v\\d+(\\.\\d+)*$"] }
+        }"#,
+    )?;
+    assert_cmd_snapshot!(
+        "untranslated_ignore_patterns_custom",
+        test.check_command().arg("untranslated")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_path_style() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("messages/en.json", r#"{"Common": {}}"#)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"export function App() {
+    return <div>Hello</div>;
+}
+"#,
+    )?;
+    let absolute = test.root().join("src/app.tsx").display().to_string();
+    let root = test.root().display().to_string();
+
+    // An absolute source root still prints paths relative to the config file
+    let output = test
+        .check_command()
+        .args(["hardcoded", "--source-root", &root])
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("--> ./src/app.tsx:2:17"), "{}", stdout);
+    assert!(!stdout.contains(&absolute), "{}", stdout);
+
+    let output = test
+        .check_command()
+        .args(["hardcoded", "--path-style", "absolute"])
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(
+        stdout.contains(&format!("--> {}:2:17", absolute)),
+        "{}",
+        stdout
+    );
+
+    // `--path-style` overrides `pathStyle`
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "pathStyle": "absolute"
+        }"#,
+    )?;
+    let output = test.check_command().arg("hardcoded").output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains(&absolute), "{}", stdout);
+
+    let output = test
+        .check_command()
+        .args(["hardcoded", "--path-style", "relative"])
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("--> ./src/app.tsx:2:17"), "{}", stdout);
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_any_locale() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "greeting": "Hello, welcome to our platform"
+            }
+        }"#,
+    )?;
+
+    // Thai locale - value is same as English, should be flagged as untranslated
+    test.write_file(
+        "messages/th.json",
+        r#"{
+            "Common": {
+                "greeting": "Hello, welcome to our platform"
+            }
+        }"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should detect untranslated
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_skip_primary_locale() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    // Only primary locale exists
+    test.write_file(
+        "messages/en.json",
+        r#"{
+            "Common": {
+                "submit": "Submit"
+            }
+        }"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should pass - primary locale is skipped
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_subcommand_untranslated() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit button"}}"#,
+    )?;
+
+    // Untranslated
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"submit": "Submit button"}}"#,
+    )?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should only run untranslated check
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_untranslated_with_usages() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
+    )?;
+
+    // Untranslated - same value as English
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
+    )?;
+
+    // Use the keys in code
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import {useTranslations} from 'next-intl';
+
+export default function App() {
+    const t = useTranslations('Common');
+    return <button>{t('submit')}</button>;
+}
+"#,
+    )?;
+
+    // Should show usage location
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_replica_lag_with_usages() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
+    )?;
+
+    // Missing cancel in Chinese
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
+
+    // Use both keys in code
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import {useTranslations} from 'next-intl';
+
+export default function App() {
+    const t = useTranslations('Common');
+    return (
+        <div>
+            <button>{t('submit')}</button>
+            <button>{t('cancel')}</button>
+        </div>
+    );
+}
+"#,
+    )?;
+
+    // Should show usage location for replica-lag
+    assert_cmd_snapshot!(test.check_command().arg("replica-lag"));
 
     Ok(())
 }
 
+// ============================================================
+// Untranslated with Disable Tests
+// ============================================================
+
 #[test]
-fn test_orphan_key() -> Result<()> {
+fn test_untranslated_skipped_when_all_usages_disabled() -> Result<()> {
+    // When ALL usages of a key have glot-disable-next-line untranslated,
+    // the untranslated issue should be suppressed.
     let test = CliTest::new()?;
 
     test.write_file(
@@ -849,24 +4261,35 @@ fn test_orphan_key() -> Result<()> {
         }"#,
     )?;
 
-    // Primary locale only has "submit"
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/en.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
 
-    // zh has an extra key "oldKey" that doesn't exist in en
+    // Untranslated - same value as English (intentionally not translated brand name)
+    test.write_file("messages/zh.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
+
+    // All usages have disable comment
     test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"submit": "提交", "oldKey": "旧的按钮"}}"#,
-    )?;
+        "src/app.tsx",
+        r#"
+import {useTranslations} from 'next-intl';
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+export default function App() {
+    const t = useTranslations('Common');
+    // glot-disable-next-line untranslated
+    return <div>{t('brand')}</div>;
+}
+"#,
+    )?;
 
-    assert_cmd_snapshot!(test.check_command());
+    // Should have no errors - the key is intentionally not translated
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_orphan_key_multiple_locales() -> Result<()> {
+fn test_untranslated_reported_when_some_usages_not_disabled() -> Result<()> {
+    // When SOME usages have disable but not all, the issue should still be reported.
+    // This is the "one-vote-veto" principle.
     let test = CliTest::new()?;
 
     test.write_file(
@@ -880,47 +4303,45 @@ fn test_orphan_key_multiple_locales() -> Result<()> {
 
     test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
 
-    test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"submit": "提交", "orphan1": "孤儿1"}}"#,
-    )?;
+    // Untranslated - same value as English
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
 
+    // First file has disable, second does not
     test.write_file(
-        "messages/ja.json",
-        r#"{"Common": {"submit": "送信", "orphan2": "孤児2"}}"#,
-    )?;
-
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    assert_cmd_snapshot!(test.check_command());
+        "src/app.tsx",
+        r#"
+import {useTranslations} from 'next-intl';
 
-    Ok(())
+export function App1() {
+    const t = useTranslations('Common');
+    // glot-disable-next-line untranslated
+    return <button>{t('submit')}</button>;
 }
+"#,
+    )?;
 
-// ============================================
-// Subcommand tests
-// ============================================
-
-#[test]
-fn test_subcommand_hardcoded() -> Result<()> {
-    let test = CliTest::with_next_intl_file(
-        "src/app/[locale]/app.tsx",
+    test.write_file(
+        "src/other.tsx",
         r#"
-  export function Button() {
-      return <button>Submit</button>;
-  }
-  "#,
-    )?;
+import {useTranslations} from 'next-intl';
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+export function App2() {
+    const t = useTranslations('Common');
+    // No disable comment here
+    return <button>{t('submit')}</button>;
+}
+"#,
+    )?;
 
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+    // Should still report the untranslated issue because not all usages are disabled
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_subcommand_missing() -> Result<()> {
+fn test_untranslated_reported_when_hardcoded_only_disabled() -> Result<()> {
+    // glot-disable-next-line hardcoded should NOT affect untranslated rule
     let test = CliTest::new()?;
 
     test.write_file(
@@ -931,23 +4352,35 @@ fn test_subcommand_missing() -> Result<()> {
             "primaryLocale": "en"
         }"#,
     )?;
-    test.write_file("messages/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
-    test.write_file("messages/zh.json", r#"{"Common": {"cancel": "取消"}}"#)?;
+
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    // Untranslated - same value as English
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    // Using hardcoded disable, NOT untranslated
     test.write_file(
         "src/app.tsx",
         r#"
-        const t = useTranslations("Common");
-        t("submit");
-        "#,
+import {useTranslations} from 'next-intl';
+
+export default function App() {
+    const t = useTranslations('Common');
+    // glot-disable-next-line hardcoded
+    return <button>{t('submit')}</button>;
+}
+"#,
     )?;
 
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
+    // Should still report the untranslated issue - hardcoded doesn't affect untranslated
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_subcommand_orphan() -> Result<()> {
+fn test_untranslated_skipped_with_range_disable() -> Result<()> {
+    // glot-disable untranslated / glot-enable untranslated range should work
     let test = CliTest::new()?;
 
     test.write_file(
@@ -958,134 +4391,135 @@ fn test_subcommand_orphan() -> Result<()> {
             "primaryLocale": "en"
         }"#,
     )?;
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"brand": "MyBrand", "submit": "Submit"}}"#,
+    )?;
+
+    // Both untranslated
     test.write_file(
         "messages/zh.json",
-        r#"{"Common": {"submit": "提交", "oldKey": "旧的按钮"}}"#,
+        r#"{"Common": {"brand": "MyBrand", "submit": "Submit"}}"#,
     )?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    assert_cmd_snapshot!(test.check_command().arg("orphan"));
+    // brand is inside disable range, submit is outside
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import {useTranslations} from 'next-intl';
 
-    Ok(())
+export default function App() {
+    const t = useTranslations('Common');
+    // glot-disable untranslated
+    const brandName = t('brand');
+    // glot-enable untranslated
+    return (
+        <div>
+            <span>{brandName}</span>
+            <button>{t('submit')}</button>
+        </div>
+    );
 }
+"#,
+    )?;
 
-// ============================================
-// Parameter combination tests
-// ============================================
-
-// #[test]
-// fn test_subcommand_with_path_arg() -> Result<()> {
-//     let test = CliTest::new()?;
-
-//     // Create files in a subdirectory
-//     test.write_file(
-//         "subdir/app.tsx",
-//         r#"
-//   export function Button() {
-//       return <button>Submit</button>;
-//   }
-//   "#,
-//     )?;
-
-//     test.write_file(
-//         "subdir/messages/en.json",
-//         r#"{"Common": {"submit": "Submit"}}"#,
-//     )?;
-
-//     // Without --path, should find nothing (default is "." which has no tsx files at root)
-//     assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
-
-//     // With --path subdir, should find the issue (args before subcommand)
-//     assert_cmd_snapshot!(
-//         test.check_command()
-//             .arg("--path")
-//             .arg("subdir")
-//             .arg("hardcoded")
-//     );
+    // Should only report submit, not brand (brand is inside disable range)
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
-//     Ok(())
-// }
+    Ok(())
+}
 
 #[test]
-fn test_subcommand_with_verbose_arg() -> Result<()> {
+fn test_untranslated_skipped_with_no_args_disable() -> Result<()> {
+    // glot-disable-next-line (no args) should disable all rules including untranslated
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./nonexistent",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Without --verbose, no warning output
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
+    test.write_file("messages/en.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
 
-    // With --verbose, should show warning about missing messages dir (args before subcommand)
-    assert_cmd_snapshot!(test.check_command().arg("--verbose").arg("missing"));
+    // Untranslated
+    test.write_file("messages/zh.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
 
-    Ok(())
+    // No args = disable all rules
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import {useTranslations} from 'next-intl';
+
+export default function App() {
+    const t = useTranslations('Common');
+    // glot-disable-next-line
+    return <div>{t('brand')}</div>;
 }
+"#,
+    )?;
 
-// ============================================
-// Schema Factory Function Tests
-// ============================================
+    // Should have no errors
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
 
 #[test]
-fn test_schema_factory_keys_tracked_as_used() -> Result<()> {
+fn test_disable_file_suppresses_one_rule() -> Result<()> {
+    // glot-disable-file untranslated covers every usage in the file, but only
+    // for untranslated: hardcoded text is still reported
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
-    )?;
-
-    // Define all keys including those used in schema
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Form": {
-                "titleRequired": "Title is required",
-                "descMax": "Description too long"
-            }
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    // Schema factory function file
     test.write_file(
-        "src/schemas.ts",
-        r#"
-export const createFormSchema = (t) => z.object({
-    title: z.string().min(1, t("titleRequired")),
-    desc: z.string().max(100, t("descMax")),
-});
-"#,
+        "messages/en.json",
+        r#"{"Common": {"brand": "MyBrand", "logo": "MyLogo"}}"#,
+    )?;
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"brand": "MyBrand", "logo": "MyLogo"}}"#,
     )?;
 
-    // Component that uses the schema
     test.write_file(
-        "src/form.tsx",
+        "src/app.tsx",
         r#"
-const tForm = useTranslations("Form");
-const schema = createFormSchema(tForm);
+import {useTranslations} from 'next-intl';
+
+export default function App() {
+    const t = useTranslations('Common');
+    return (
+        <div>
+            <span>{t('brand')}</span>
+            <span>{t('logo')}</span>
+            <p>Hello</p>
+        </div>
+    );
+}
+
+// glot-disable-file untranslated
 "#,
     )?;
 
-    // Keys should be tracked as used, so no unused key warnings
-    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(test.check_command().args(["hardcoded", "untranslated"]));
 
     Ok(())
 }
 
 #[test]
-fn test_schema_factory_missing_key_reported() -> Result<()> {
+fn test_untranslated_empty_string() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1097,42 +4531,37 @@ fn test_schema_factory_missing_key_reported() -> Result<()> {
         }"#,
     )?;
 
-    // Missing "titleRequired" key
     test.write_file(
         "messages/en.json",
         r#"{
-            "Form": {
-                "descMax": "Description too long"
+            "Common": {
+                "submit": "Submit",
+                "cancel": "Cancel"
             }
         }"#,
     )?;
 
+    // Chinese locale has empty string values (not translated yet)
     test.write_file(
-        "src/schemas.ts",
-        r#"
-export const createFormSchema = (t) => z.object({
-    title: z.string().min(1, t("titleRequired")),
-    desc: z.string().max(100, t("descMax")),
-});
-"#,
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "submit": "",
+                "cancel": "取消"
+            }
+        }"#,
     )?;
 
-    test.write_file(
-        "src/form.tsx",
-        r#"
-const tForm = useTranslations("Form");
-const schema = createFormSchema(tForm);
-"#,
-    )?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Should report missing key
-    assert_cmd_snapshot!(test.check_command());
+    // Should detect empty string as untranslated
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_schema_factory_nested_calls() -> Result<()> {
+fn test_untranslated_empty_and_identical() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1144,48 +4573,48 @@ fn test_schema_factory_nested_calls() -> Result<()> {
         }"#,
     )?;
 
-    // All keys defined
     test.write_file(
         "messages/en.json",
         r#"{
-            "Form": {
-                "baseKey": "Base field required",
-                "extendedKey": "Extended field required"
+            "Common": {
+                "submit": "Submit"
             }
         }"#,
     )?;
 
-    // Nested schema functions
+    // zh has empty string, ja has identical value
     test.write_file(
-        "src/schemas.ts",
-        r#"
-export const createBaseSchema = (t) => z.object({
-    base: z.string().min(1, t("baseKey")),
-});
-
-export const createExtendedSchema = (t) =>
-    createBaseSchema(t).extend({
-        extended: z.string().min(1, t("extendedKey")),
-    });
-"#,
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "submit": ""
+            }
+        }"#,
     )?;
 
     test.write_file(
-        "src/form.tsx",
-        r#"
-const tForm = useTranslations("Form");
-const schema = createExtendedSchema(tForm);
-"#,
+        "messages/ja.json",
+        r#"{
+            "Common": {
+                "submit": "Submit"
+            }
+        }"#,
     )?;
 
-    // Both base and extended keys should be tracked
-    assert_cmd_snapshot!(test.check_command());
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should detect both empty and identical as untranslated
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
+// ============================================================
+// Type Mismatch Tests
+// ============================================================
+
 #[test]
-fn test_dynamic_key_all_candidates_exist() -> Result<()> {
+fn test_type_mismatch_array_vs_string() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1197,40 +4626,36 @@ fn test_dynamic_key_all_candidates_exist() -> Result<()> {
         }"#,
     )?;
 
-    // All candidate keys exist in messages
+    // English (primary locale) has an array
     test.write_file(
         "messages/en.json",
         r#"{
-            "Tools": {
-                "createNovel": "Create Novel",
-                "updateNovel": "Update Novel"
+            "Page": {
+                "benefits": ["Fast", "Easy", "Reliable"]
             }
         }"#,
     )?;
 
+    // Chinese locale has string instead of array (TYPE MISMATCH!)
     test.write_file(
-        "src/app.tsx",
-        r#"
-const toolKeys = {
-    createNovel: "createNovel",
-    updateNovel: "updateNovel",
-};
-const t = useTranslations("Tools");
-export function Tool({ toolName }) {
-    const key = toolKeys[toolName];
-    return t(key);
-}
-"#,
+        "messages/zh.json",
+        r#"{
+            "Page": {
+                "benefits": "快速、简单、可靠"
+            }
+        }"#,
     )?;
 
-    // Should pass silently - all candidates exist
-    assert_cmd_snapshot!(test.check_command());
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should detect type mismatch
+    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
 
     Ok(())
 }
 
 #[test]
-fn test_dynamic_key_some_candidates_missing() -> Result<()> {
+fn test_type_mismatch_object_vs_string() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1242,46 +4667,47 @@ fn test_dynamic_key_some_candidates_missing() -> Result<()> {
         }"#,
     )?;
 
-    // Only one key exists, other is missing
+    // English (primary locale) nests "Page.hero" and has a string "Page.footer"
     test.write_file(
         "messages/en.json",
         r#"{
-            "Tools": {
-                "createNovel": "Create Novel"
+            "Page": {
+                "hero": {
+                    "title": "Welcome"
+                },
+                "footer": "All rights reserved"
+            }
+        }"#,
+    )?;
+
+    // Chinese locale flips both shapes
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+            "Page": {
+                "hero": "欢迎",
+                "footer": {
+                    "text": "版权所有"
+                }
             }
         }"#,
     )?;
 
     test.write_file(
         "src/app.tsx",
-        r#"
-const toolKeys = {
-    createNovel: "createNovel",
-    updateNovel: "updateNovel",
-};
-const t = useTranslations("Tools");
-export function Tool({ toolName }) {
-    const key = toolKeys[toolName];
-    return t(key);
-}
-"#,
+        r#"export function Page() {
+    const t = useTranslations("Page");
+    return <h1>{t("hero.title")}</h1>;
+}"#,
     )?;
 
-    // Should report error for missing candidate
-    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
 
     Ok(())
 }
 
-/// Test that both static and dynamic missing keys are reported together.
-/// This ensures the CLI output is consistent when both types of issues exist.
-///
-/// The test verifies that:
-/// - Static missing keys (directly used t("key")) are reported
-/// - Dynamic missing keys from resolvable objects are expanded and reported
-/// - The total count reflects all individual missing keys
 #[test]
-fn test_missing_key_static_and_dynamic_combined() -> Result<()> {
+fn test_type_mismatch_no_issue_when_types_match() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1293,50 +4719,36 @@ fn test_missing_key_static_and_dynamic_combined() -> Result<()> {
         }"#,
     )?;
 
-    // Only Common.title exists
+    // English (primary locale) has an array
     test.write_file(
         "messages/en.json",
         r#"{
-            "Tools": {
-                "create": "Create"
+            "Page": {
+                "benefits": ["Fast", "Easy", "Reliable"]
             }
         }"#,
     )?;
 
+    // Chinese locale also has array (correct!)
     test.write_file(
-        "src/app.tsx",
-        r#"
-// Static missing key
-const t = useTranslations("Tools");
-const missing = t("delete");
-
-// Dynamic missing keys from object (resolvable pattern)
-const toolKeys = {
-    update: "update",
-    archive: "archive",
-};
-function Tool({ name }: { name: keyof typeof toolKeys }) {
-    const key = toolKeys[name];
-    return t(key);
-}
-"#,
+        "messages/zh.json",
+        r#"{
+            "Page": {
+                "benefits": ["快速", "简单", "可靠"]
+            }
+        }"#,
     )?;
 
-    // Should report:
-    // - 1 static missing key (Tools.delete)
-    // - 2 dynamic missing keys (Tools.update, Tools.archive from toolKeys)
-    // Total: 3 missing keys
-    assert_cmd_snapshot!(test.check_command());
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should have no issues
+    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
 
     Ok(())
 }
 
-// ============================================
-// String Array Iteration Tests
-// ============================================
-
 #[test]
-fn test_string_array_iteration_same_file_all_exist() -> Result<()> {
+fn test_type_mismatch_multiple_locales() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1348,35 +4760,46 @@ fn test_string_array_iteration_same_file_all_exist() -> Result<()> {
         }"#,
     )?;
 
-    // All candidate keys exist
+    // English (primary locale) has an array
     test.write_file(
         "messages/en.json",
         r#"{
-            "Features": {
-                "prefix.save": "Save",
-                "prefix.load": "Load"
+            "Common": {
+                "tags": ["tag1", "tag2"]
             }
         }"#,
     )?;
 
-    // String array and component in SAME file
+    // Chinese locale has string (TYPE MISMATCH!)
     test.write_file(
-        "src/app.tsx",
-        r#"
-const FEATURE_KEYS = ["save", "load"] as const;
-const t = useTranslations("Features");
-FEATURE_KEYS.map((k) => t(`prefix.${k}`));
-"#,
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "tags": "标签1, 标签2"
+            }
+        }"#,
+    )?;
+
+    // Japanese locale also has string (TYPE MISMATCH!)
+    test.write_file(
+        "messages/ja.json",
+        r#"{
+            "Common": {
+                "tags": "タグ1、タグ2"
+            }
+        }"#,
     )?;
 
-    // Expected: No errors - all keys exist
-    assert_cmd_snapshot!(test.check_command());
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Should detect type mismatch in both locales
+    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
 
     Ok(())
 }
 
 #[test]
-fn test_string_array_iteration_same_file_some_missing() -> Result<()> {
+fn test_type_mismatch_with_usages() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1388,38 +4811,48 @@ fn test_string_array_iteration_same_file_some_missing() -> Result<()> {
         }"#,
     )?;
 
-    // Only one key exists
+    // English (primary locale) has an array
     test.write_file(
         "messages/en.json",
         r#"{
-            "Features": {
-                "prefix.save": "Save"
+            "Page": {
+                "features": ["Feature 1", "Feature 2"]
             }
         }"#,
     )?;
 
-    // String array and component in SAME file
+    // Chinese locale has string (TYPE MISMATCH!)
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+            "Page": {
+                "features": "功能1, 功能2"
+            }
+        }"#,
+    )?;
+
+    // Source file that uses the key
     test.write_file(
         "src/app.tsx",
         r#"
-const FEATURE_KEYS = ["save", "load"] as const;
-const t = useTranslations("Features");
-FEATURE_KEYS.map((k) => t(`prefix.${k}`));
+import {useTranslations} from 'next-intl';
+
+export default function App() {
+    const t = useTranslations('Page');
+    const features = t.raw('features');
+    return <ul>{features.map(f => <li key={f}>{f}</li>)}</ul>;
+}
 "#,
     )?;
 
-    // Expected: Error for missing "Features.prefix.load"
-    assert_cmd_snapshot!(test.check_command());
+    // Should detect type mismatch with usage location
+    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
 
     Ok(())
 }
 
-// ============================================
-// Translation Props Tests
-// ============================================
-
 #[test]
-fn test_translation_prop_keys_tracked_as_used() -> Result<()> {
+fn test_subcommand_type_mismatch() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1431,51 +4864,61 @@ fn test_translation_prop_keys_tracked_as_used() -> Result<()> {
         }"#,
     )?;
 
-    // All keys defined
+    test.write_file("messages/en.json", r#"{"Page": {"items": ["a", "b"]}}"#)?;
+
+    // Type mismatch
+    test.write_file("messages/zh.json", r#"{"Page": {"items": "a, b"}}"#)?;
+
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Run only type-mismatch check
+    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
+
+    Ok(())
+}
+
+// ============================================================
+// Group A: Multiple Rules & Default Behavior Tests
+// ============================================================
+
+#[test]
+fn test_multiple_rules_hardcoded_missing() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "messages/en.json",
+        ".glotrc.json",
         r#"{
-            "Landing": {
-                "title": "Welcome",
-                "description": "This is a description"
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    // Parent component passes t to child
-    test.write_file(
-        "src/page.tsx",
-        r#"
-const t = useTranslations("Landing");
-export function Page() {
-    return <LandingContent t={t} />;
-}
-"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
 
-    // Child component receives t as prop and uses it
     test.write_file(
-        "src/landing-content.tsx",
+        "src/app.tsx",
         r#"
-export function LandingContent({ t }: Props) {
+const t = useTranslations("Common");
+export function Button() {
     return (
         <div>
-            <h1>{t("title")}</h1>
-            <p>{t("description")}</p>
+            <button>{t("submit")}</button>
+            <span>Hardcoded</span>
         </div>
     );
 }
 "#,
     )?;
 
-    // Keys should be tracked as used via the prop, no unused warnings
-    assert_cmd_snapshot!(test.check_command());
+    // Both hardcoded text and missing key errors should appear
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded").arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_translation_prop_missing_key_reported() -> Result<()> {
+fn test_multiple_rules_unused_orphan() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1487,50 +4930,37 @@ fn test_translation_prop_missing_key_reported() -> Result<()> {
         }"#,
     )?;
 
-    // Missing "subtitle" key
+    // Primary (en): submit + unused
     test.write_file(
         "messages/en.json",
-        r#"{
-            "Landing": {
-                "title": "Welcome"
-            }
-        }"#,
+        r#"{"Common": {"submit": "Submit", "unused": "Unused"}}"#,
     )?;
 
-    // Parent component passes t to child
+    // Secondary (zh): submit + orphan
     test.write_file(
-        "src/page.tsx",
-        r#"
-const t = useTranslations("Landing");
-export function Page() {
-    return <LandingContent t={t} />;
-}
-"#,
+        "messages/zh.json",
+        r#"{"Common": {"submit": "提交", "orphan": "孤儿"}}"#,
     )?;
 
-    // Child component uses a key that doesn't exist
+    // Only uses "submit"
     test.write_file(
-        "src/landing-content.tsx",
+        "src/app.tsx",
         r#"
-export function LandingContent({ t }: Props) {
-    return (
-        <div>
-            <h1>{t("title")}</h1>
-            <p>{t("subtitle")}</p>
-        </div>
-    );
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    // Should report missing key "subtitle"
-    assert_cmd_snapshot!(test.check_command());
+    // Both unused key (en) and orphan key (zh) warnings
+    assert_cmd_snapshot!(test.check_command().arg("unused").arg("orphan"));
 
     Ok(())
 }
 
 #[test]
-fn test_translation_prop_arrow_function_component() -> Result<()> {
+fn test_multiple_rules_all_eight() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1542,44 +4972,68 @@ fn test_translation_prop_arrow_function_component() -> Result<()> {
         }"#,
     )?;
 
+    // Primary (en): key1 (array), key2, key3, unused_key
     test.write_file(
         "messages/en.json",
         r#"{
-            "Card": {
-                "title": "Card Title"
+            "Common": {
+                "key1": ["item1", "item2"],
+                "key2": "Value 2",
+                "key3": "Value 3",
+                "unused_key": "Unused"
             }
         }"#,
     )?;
 
-    // Parent passes t to arrow function child
+    // Secondary (zh): key1 (string - type mismatch), key2 (untranslated), orphan_key, missing key3 (replica lag)
     test.write_file(
-        "src/page.tsx",
-        r#"
-const t = useTranslations("Card");
-export function Page() {
-    return <CardComponent t={t} />;
-}
-"#,
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "key1": "item1, item2",
+                "key2": "Value 2",
+                "orphan_key": "孤儿"
+            }
+        }"#,
     )?;
 
-    // Arrow function component
     test.write_file(
-        "src/card.tsx",
+        "src/app.tsx",
         r#"
-export const CardComponent = ({ t }: Props) => {
-    return <div>{t("title")}</div>;
-};
+const t = useTranslations("Common");
+export function Component({ dynamicKey }) {
+    return (
+        <div>
+            <span>Hardcoded</span>
+            <button>{t("missing_key")}</button>
+            <div>{t("key1")}</div>
+            <div>{t("key2")}</div>
+            <div>{t("key3")}</div>
+            <div>{t(dynamicKey)}</div>
+        </div>
+    );
+}
 "#,
     )?;
 
-    // Should pass - key is tracked via prop
-    assert_cmd_snapshot!(test.check_command());
+    // All 8 rule violations should be reported
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("hardcoded")
+            .arg("missing")
+            .arg("unused")
+            .arg("orphan")
+            .arg("replica-lag")
+            .arg("untranslated")
+            .arg("type-mismatch")
+            .arg("unresolved")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_translation_prop_with_relative_glot_message_keys() -> Result<()> {
+fn test_default_runs_all_rules() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1591,55 +5045,57 @@ fn test_translation_prop_with_relative_glot_message_keys() -> Result<()> {
         }"#,
     )?;
 
-    // All dynamic keys exist
+    // Same setup as test_multiple_rules_all_eight
     test.write_file(
         "messages/en.json",
         r#"{
-            "Features": {
-                "items": {
-                    "save": { "title": "Save" },
-                    "load": { "title": "Load" }
-                }
+            "Common": {
+                "key1": ["item1", "item2"],
+                "key2": "Value 2",
+                "key3": "Value 3",
+                "unused_key": "Unused"
             }
         }"#,
     )?;
 
-    // Parent passes t to child
     test.write_file(
-        "src/page.tsx",
-        r#"
-const t = useTranslations("Features");
-export function Page() {
-    return <FeatureList t={t} items={items} />;
-}
-"#,
+        "messages/zh.json",
+        r#"{
+            "Common": {
+                "key1": "item1, item2",
+                "key2": "Value 2",
+                "orphan_key": "孤儿"
+            }
+        }"#,
     )?;
 
-    // Child uses dynamic keys with relative glot-message-keys annotation
     test.write_file(
-        "src/feature-list.tsx",
+        "src/app.tsx",
         r#"
-export function FeatureList({ t, items }: Props) {
-    return items.map(item => {
-        // glot-message-keys ".items.*.title"
-        return <span>{t(`items.${item.key}.title`)}</span>;
-    });
+const t = useTranslations("Common");
+export function Component({ dynamicKey }) {
+    return (
+        <div>
+            <span>Hardcoded</span>
+            <button>{t("missing_key")}</button>
+            <div>{t("key1")}</div>
+            <div>{t("key2")}</div>
+            <div>{t("key3")}</div>
+            <div>{t(dynamicKey)}</div>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Should pass - relative pattern expanded with namespace from prop
+    // No rule arguments - should run all rules
     assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
-// ============================================
-// Translation Function Call Tests
-// ============================================
-
 #[test]
-fn test_translation_fn_call_same_file_used_key() -> Result<()> {
+fn test_multiple_rules_no_issues() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1651,43 +5107,32 @@ fn test_translation_fn_call_same_file_used_key() -> Result<()> {
         }"#,
     )?;
 
-    // All keys defined
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Usage": {
-                "monthly": "Monthly",
-                "yearly": "Yearly"
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
 
-    // Utility function and component in SAME file
-    // usageLabels receives t as argument and uses it to translate keys
     test.write_file(
         "src/app.tsx",
         r#"
-const usageLabels = (t) => ({
-    monthly: t("monthly"),
-    yearly: t("yearly"),
-});
-
-export function UsagePage() {
-    const t = useTranslations("Usage");
-    const labels = usageLabels(t);
-    return <div>{labels.monthly}</div>;
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    // Keys should be tracked as used via function call, no unused warnings
-    assert_cmd_snapshot!(test.check_command());
+    // Clean project - should have success exit code
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("hardcoded")
+            .arg("missing")
+            .arg("unused")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_import_alias_used_key() -> Result<()> {
+fn test_rule_order_does_not_matter() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -1699,464 +5144,387 @@ fn test_translation_fn_call_import_alias_used_key() -> Result<()> {
         }"#,
     )?;
 
-    // All keys defined
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Status": {
-                "active": "Active",
-                "inactive": "Inactive"
-            }
-        }"#,
-    )?;
-
-    // Utility file with named export
-    test.write_file(
-        "src/utils.ts",
-        r#"
-export const buildStatusLabels = (t) => ({
-    active: t("active"),
-    inactive: t("inactive"),
-});
-"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
 
-    // Component imports with alias and calls the function
     test.write_file(
         "src/app.tsx",
         r#"
-import { buildStatusLabels as statusLabels } from './utils';
-
-export function StatusPage() {
-    const t = useTranslations("Status");
-    const labels = statusLabels(t);
-    return <div>{labels.active}</div>;
+const t = useTranslations("Common");
+export function Button() {
+    return (
+        <div>
+            <button>{t("submit")}</button>
+            <span>Hardcoded</span>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Keys should be tracked via aliased import, no unused warnings
-    assert_cmd_snapshot!(test.check_command());
+    // Order 1: missing then hardcoded
+    assert_cmd_snapshot!(
+        "order1",
+        test.check_command().arg("missing").arg("hardcoded")
+    );
+
+    // Order 2: hardcoded then missing (should be identical)
+    assert_cmd_snapshot!(
+        "order2",
+        test.check_command().arg("hardcoded").arg("missing")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_paths_alias_used_key() -> Result<()> {
+fn test_issue_order_across_files() -> Result<()> {
+    // Issues are sorted by file, line and column, whatever order the files
+    // are scanned in
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/locales",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "tsconfig.json",
-        r#"{
-            "compilerOptions": {
-                "paths": {
-                    "@/*": ["./src/*"]
-                }
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"title": "Title"}}"#)?;
+
+    for file in ["src/b.tsx", "src/a/page.tsx", "src/c.tsx", "src/a.tsx"] {
+        test.write_file(
+            file,
+            r#"
+const t = useTranslations("Common");
+export function Page() {
+    return (
+        <div title="Tooltip">
+            <h1>{t("title")}</h1>
+            <p>{t("missing")}</p>
+            <span>Hardcoded</span>
+        </div>
+    );
+}
+"#,
+        )?;
+    }
+
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded").arg("missing"));
+
+    Ok(())
+}
+
+// ============================================================
+// Group B: Unresolved Rule Tests
+// ============================================================
+
+#[test]
+fn test_subcommand_unresolved() -> Result<()> {
+    let test = CliTest::new()?;
 
     test.write_file(
-        "src/locales/en.json",
+        ".glotrc.json",
         r#"{
-            "auth": {
-                "validation": {
-                    "emailRequired": "Email is required."
-                }
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "src/lib/form-schemas.ts",
-        r#"
-export function createSignupSchema(t) {
-    return {
-        email: t("auth.validation.emailRequired"),
-    };
-}
-"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"key1": "Value 1"}}"#)?;
 
     test.write_file(
-        "src/components/app.tsx",
+        "src/app.tsx",
         r#"
-import { useTranslation } from 'react-i18next';
-import { createSignupSchema } from '@/lib/form-schemas';
-
-export function App() {
-    const { t } = useTranslation();
-    const schema = createSignupSchema(t);
-    return <div>{schema.email}</div>;
+const t = useTranslations("Common");
+export function Dynamic({ keyName }) {
+    return <div>{t(keyName)}</div>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for paths alias fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("auth.validation.emailRequired") && !stdout.contains("[unused-key]"),
-        "paths alias translation fn calls should mark keys as used. stdout:\n{}",
-        stdout
-    );
+    // Unresolved - variable reference
+    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_nested_helper_used_key() -> Result<()> {
+fn test_unresolved_with_glot_message_keys_resolves() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/locales",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "src/locales/en.json",
-        r#"{
-            "auth": {
-                "validation": {
-                    "otpRequired": "Enter the verification code.",
-                    "otpInvalid": "Enter the 6-digit code."
-                }
-            }
-        }"#,
+        "messages/en.json",
+        r#"{"Common": {"key1": "K1", "key2": "K2"}}"#,
     )?;
 
     test.write_file(
-        "src/form-schemas.ts",
+        "src/app.tsx",
         r#"
-function otpField(t) {
-    return {
-        required: t("auth.validation.otpRequired"),
-        invalid: t("auth.validation.otpInvalid"),
-    };
+const t = useTranslations("Common");
+// glot-message-keys "key1" "key2"
+export function Dynamic({ keyName }) {
+    return <div>{t(keyName)}</div>;
 }
+"#,
+    )?;
 
-export function createOtpSchema(t) {
-    return otpField(t);
+    // No unresolved warnings - keys resolved by annotation
+    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+
+    Ok(())
 }
-"#,
+
+#[test]
+fn test_unresolved_template_literal_static() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{"Common": {"static": "Static"}}"#)?;
+
     test.write_file(
         "src/app.tsx",
         r#"
-import { useTranslation } from 'react-i18next';
-import { createOtpSchema } from './form-schemas';
-
-export function App() {
-    const { t } = useTranslation();
-    const schema = createOtpSchema(t);
-    return <div>{schema.required}</div>;
+const t = useTranslations("Common");
+export function Component() {
+    return <div>{t(`static`)}</div>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for nested helper fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("auth.validation.otpRequired")
-            && !stdout.contains("auth.validation.otpInvalid")
-            && !stdout.contains("[unused-key]"),
-        "nested helper forwarding should mark keys as used. stdout:\n{}",
-        stdout
-    );
+    // Static template literal should resolve
+    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_nested_callback_param_shadow_does_not_forward() -> Result<()> {
+fn test_unresolved_ternary_with_variables() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/locales",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "src/locales/en.json",
-        r#"{
-            "auth": {
-                "validation": {
-                    "otpRequired": "Enter the verification code.",
-                    "otpInvalid": "Enter the 6-digit code."
-                }
-            }
-        }"#,
-    )?;
-
-    test.write_file(
-        "src/form-schemas.ts",
-        r#"
-function otpField(t) {
-    return {
-        required: t("auth.validation.otpRequired"),
-        invalid: t("auth.validation.otpInvalid"),
-    };
-}
-
-export function createOtpSchema(t) {
-    return ["noop"].map(t => otpField(t));
-}
-"#,
+        "messages/en.json",
+        r#"{"Common": {"k1": "K1", "k2": "K2"}}"#,
     )?;
 
     test.write_file(
         "src/app.tsx",
         r#"
-import { useTranslation } from 'react-i18next';
-import { createOtpSchema } from './form-schemas';
-
-export function App() {
-    const { t } = useTranslation();
-    const schema = createOtpSchema(t);
-    return <div>{schema.length}</div>;
+const t = useTranslations("Common");
+export function Component({ condition }) {
+    const key1 = "k1";
+    const key2 = "k2";
+    return <div>{t(condition ? key1 : key2)}</div>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for nested callback shadow fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        stdout.contains("auth.validation.otpRequired")
-            && stdout.contains("auth.validation.otpInvalid")
-            && stdout.matches("[unused-key]").count() == 2,
-        "nested callback parameters must not forward outer translation bindings. stdout:\n{}",
-        stdout
-    );
+    // Ternary with variable branches is unresolved
+    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_exported_arrow_nested_helper_used_key() -> Result<()> {
+fn test_unresolved_method_call_result() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/locales",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "src/locales/en.json",
-        r#"{
-            "auth": {
-                "validation": {
-                    "otpRequired": "Enter the verification code."
-                }
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"key": "Value"}}"#)?;
 
     test.write_file(
-        "src/form-schemas.ts",
+        "src/app.tsx",
         r#"
-function otpField(t) {
-    return t("auth.validation.otpRequired");
+const t = useTranslations("Common");
+const obj = { getKey: () => "key" };
+export function Component() {
+    return <div>{t(obj.getKey())}</div>;
 }
-
-export const createOtpSchema = (t) => otpField(t);
 "#,
     )?;
 
-    test.write_file(
-        "src/app.tsx",
-        r#"
-import { useTranslation } from 'react-i18next';
-import { createOtpSchema } from './form-schemas';
+    // Method call result is unresolved
+    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
 
-export function App() {
-    const { t } = useTranslation();
-    return <div>{createOtpSchema(t)}</div>;
+    Ok(())
 }
-"#,
+
+// ============================================================
+// Group C: Common Arguments Tests
+// ============================================================
+
+#[test]
+fn test_primary_locale_override() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(output.status.success());
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/ja.json", r#"{}"#)?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("auth.validation.otpRequired") && !stdout.contains("[unused-key]"),
-        "exported arrow helpers should propagate translation bindings. stdout:\n{}",
-        stdout
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Override primary locale to "ja" - should report "en" has replica lag
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("replica-lag")
+            .arg("--primary-locale")
+            .arg("ja")
     );
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_default_export_nested_helper_used_key() -> Result<()> {
+fn test_source_root_override() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
             "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    test.write_file("custom-src/app.tsx", r#"<div>Hardcoded</div>"#)?;
+
+    // Override source directory
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("hardcoded")
+            .arg("--source-root")
+            .arg("custom-src")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_messages_root_override() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "messages/en.json",
+        ".glotrc.json",
         r#"{
-            "Plan": {
-                "free": "Free Plan"
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "src/plan-labels.ts",
-        r#"
-function planValue(t) {
-    return t("free");
-}
-
-export default function buildPlanLabels(t) {
-    return planValue(t);
-}
-"#,
+        "custom-messages/en.json",
+        r#"{"Common": {"cancel": "Cancel"}}"#,
     )?;
 
     test.write_file(
         "src/app.tsx",
         r#"
-import buildPlanLabels from './plan-labels';
-
-export function PlanPage() {
-    const t = useTranslations("Plan");
-    return <div>{buildPlanLabels(t)}</div>;
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(output.status.success());
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("Plan.free") && !stdout.contains("[unused-key]"),
-        "named default exports should propagate through the default alias. stdout:\n{}",
-        stdout
+    // Override messages directory
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("missing")
+            .arg("--messages-root")
+            .arg("custom-messages")
     );
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_function_expression_nested_helper_used_key() -> Result<()> {
+fn test_all_three_args_combined() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
-            "includes": ["src"],
-            "messagesRoot": "./src/locales",
-            "primaryLocale": "en"
-        }"#,
-    )?;
-
-    test.write_file(
-        "src/locales/en.json",
-        r#"{
-            "auth": {
-                "validation": {
-                    "otpRequired": "Enter the verification code."
-                }
-            }
+            "messagesDir": "./messages",
+            "primaryLocale": "zh"
         }"#,
     )?;
 
-    test.write_file(
-        "src/form-schemas.ts",
-        r#"
-function otpField(t) {
-    return t("auth.validation.otpRequired");
-}
-
-export const createOtpSchema = function (t) {
-    return otpField(t);
-};
-"#,
-    )?;
+    test.write_file("i18n/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
 
     test.write_file(
-        "src/app.tsx",
+        "custom-src/app.tsx",
         r#"
-import { useTranslation } from 'react-i18next';
-import { createOtpSchema } from './form-schemas';
-
-export function App() {
-    const { t } = useTranslation();
-    return <div>{createOtpSchema(t)}</div>;
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(output.status.success());
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("auth.validation.otpRequired") && !stdout.contains("[unused-key]"),
-        "function-expression helpers should propagate translation bindings. stdout:\n{}",
-        stdout
+    // All three overrides together
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("missing")
+            .arg("--primary-locale")
+            .arg("en")
+            .arg("--source-root")
+            .arg("custom-src")
+            .arg("--messages-root")
+            .arg("i18n")
     );
 
     Ok(())
 }
 
 #[test]
-fn test_translation_fn_call_default_export_used_key() -> Result<()> {
+fn test_verbose_shows_scan_info() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2168,52 +5536,89 @@ fn test_translation_fn_call_default_export_used_key() -> Result<()> {
         }"#,
     )?;
 
-    // All keys defined
+    test.write_file("messages/en.json", r#"{"Common": {}}"#)?;
+    test.write_file("src/app.tsx", r#"<div>Hardcoded</div>"#)?;
+
+    // Verbose flag should show diagnostic info
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded").arg("--verbose"));
+
+    Ok(())
+}
+
+#[test]
+fn test_verbose_with_parse_error() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "messages/en.json",
+        ".glotrc.json",
         r#"{
-            "Plan": {
-                "free": "Free Plan",
-                "pro": "Pro Plan"
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    // Utility file with default export
-    test.write_file(
-        "src/plan-labels.ts",
-        r#"
-export default function buildPlanLabels(t) {
-    return {
-        free: t("free"),
-        pro: t("pro"),
-    };
+    test.write_file("messages/en.json", r#"{}"#)?;
+    test.write_file("src/broken.tsx", "<div>Unclosed")?;
+
+    // Verbose should show parse error details
+    assert_cmd_snapshot!(test.check_command().arg("--verbose"));
+
+    Ok(())
 }
-"#,
+
+#[test]
+fn test_common_args_with_multiple_rules() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "messagesDir": "./messages",
+            "primaryLocale": "zh"
+        }"#,
     )?;
 
-    // Component uses default import
+    test.write_file("i18n/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
+
     test.write_file(
-        "src/app.tsx",
+        "custom-src/app.tsx",
         r#"
-import buildPlanLabels from './plan-labels';
-
-export function PlanPage() {
-    const t = useTranslations("Plan");
-    const labels = buildPlanLabels(t);
-    return <div>{labels.free}</div>;
+const t = useTranslations("Common");
+export function Button() {
+    return (
+        <div>
+            <button>{t("submit")}</button>
+            <span>Hardcoded</span>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Keys should be tracked via default import, no unused warnings
-    assert_cmd_snapshot!(test.check_command());
+    // Common args with multiple rules
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("hardcoded")
+            .arg("missing")
+            .arg("--verbose")
+            .arg("--primary-locale")
+            .arg("en")
+            .arg("--source-root")
+            .arg("custom-src")
+            .arg("--messages-root")
+            .arg("i18n")
+    );
 
     Ok(())
 }
 
+// ============================================================
+// Group D: Edge Cases Tests
+// ============================================================
+
 #[test]
-fn test_translation_fn_call_param_shadowing() -> Result<()> {
+fn test_empty_project_no_source_files() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2225,151 +5630,159 @@ fn test_translation_fn_call_param_shadowing() -> Result<()> {
         }"#,
     )?;
 
-    // Only "outer" key is defined, "inner" is NOT defined
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    // Empty directory - no source files
+    // Should succeed with "No problems found"
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_no_messages_files() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "messages/en.json",
+        ".glotrc.json",
         r#"{
-            "Outer": {
-                "outerKey": "Outer Value"
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    // Outer function receives t from registry, inner function shadows t
-    // Inner's t("innerKey") should NOT be tracked (parameter shadows outer binding)
-    test.write_file(
-        "src/utils.ts",
-        r#"
-export const outerFunc = (t) => {
-    const innerFunc = (t) => {
-        return t("innerKey");  // Should NOT be tracked - t is shadowed
-    };
-    return t("outerKey");  // Should be tracked
-};
-"#,
-    )?;
+    // No messages files at all
 
-    // Component calls outerFunc with translation function
     test.write_file(
         "src/app.tsx",
         r#"
-import { outerFunc } from './utils';
-
-export function Page() {
-    const t = useTranslations("Outer");
-    const result = outerFunc(t);
-    return <div>{result}</div>;
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    // Only "outerKey" should be tracked, "innerKey" should be ignored (shadowed)
-    // No missing key error for "innerKey" since it's not tracked
-    assert_cmd_snapshot!(test.check_command());
+    // All keys should be reported as missing
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_i18next_member_call_marks_key_as_used() -> Result<()> {
+fn test_all_files_ignored_by_pattern() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
-            "includes": ["src"],
-            "messagesRoot": "./src/locales",
+            "ignores": ["**/*"],
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{}"#)?;
+    test.write_file("src/app.tsx", r#"<div>Hardcoded</div>"#)?;
+
+    // All files ignored - should find no problems
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_error_in_tsx() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "src/locales/en.json",
+        ".glotrc.json",
         r#"{
-            "auth": {
-                "sessionExpired": "Your session has expired."
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "src/session-expired.ts",
-        r#"
-import i18n from 'i18next';
+    test.write_file("messages/en.json", r#"{}"#)?;
+    test.write_file("src/broken.tsx", "<div>Unclosed")?;
 
-export function notify() {
-    return i18n.t('auth.sessionExpired');
+    // Parse error should be reported
+    assert_cmd_snapshot!(test.check_command());
+
+    Ok(())
 }
-"#,
+
+#[test]
+fn test_invalid_json_in_messages() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
+        }"#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for i18next member-call fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
+    test.write_file("messages/en.json", "{invalid}")?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("auth.sessionExpired") && !stdout.contains("[unused-key]"),
-        "i18next member calls should mark keys as used. stdout:\n{}",
-        stdout
-    );
+    // JSON parse error should be reported
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_i18next_member_call_shadowed_import_is_not_counted() -> Result<()> {
+fn test_mixed_issues_across_rules() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
             "includes": ["src"],
-            "messagesRoot": "./src/locales",
+            "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "src/locales/en.json",
-        r#"{
-            "auth": {
-                "sessionExpired": "Your session has expired."
-            }
-        }"#,
+        "messages/en.json",
+        r#"{"Common": {"key1": "Value 1", "unused": "Unused"}}"#,
     )?;
 
     test.write_file(
-        "src/session-expired.ts",
-        r#"
-import i18n from 'i18next';
-
-export function notify(i18n) {
-    return i18n.t('auth.sessionExpired');
-}
-"#,
+        "messages/zh.json",
+        r#"{"Common": {"key1": "Value 1", "orphan": "孤儿"}}"#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(output.status.success());
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        stdout.contains("auth.sessionExpired") && stdout.matches("[unused-key]").count() == 1,
-        "shadowed i18next imports must not be treated as translation member calls. stdout:\n{}",
-        stdout
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Component() {
+    return (
+        <div>
+            <span>Hardcoded Text</span>
+            <button>{t("key1")}</button>
+            <button>{t("missing")}</button>
+        </div>
     );
+}
+"#,
+    )?;
+
+    // Multiple issue types: hardcoded, missing, unused, orphan, untranslated
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_extra_translation_callee_marks_key_as_used() -> Result<()> {
+fn test_multiple_locales_replica_lag_and_orphan() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2377,48 +5790,32 @@ fn test_extra_translation_callee_marks_key_as_used() -> Result<()> {
         r#"{
             "includes": ["src"],
             "messagesDir": "./messages",
-            "primaryLocale": "en",
-            "extraTranslationCallees": ["tt"]
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
-        }"#,
-    )?;
+    // en (primary): a, b
+    test.write_file("messages/en.json", r#"{"Common": {"a": "A", "b": "B"}}"#)?;
+
+    // zh: missing "b" (replica lag)
+    test.write_file("messages/zh.json", r#"{"Common": {"a": "A"}}"#)?;
 
+    // ja: has extra "c" (orphan)
     test.write_file(
-        "src/app.ts",
-        r#"
-export function renderLabel() {
-    return tt('Common.submit');
-}
-"#,
+        "messages/ja.json",
+        r#"{"Common": {"a": "A", "b": "B", "c": "C"}}"#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for configured callee fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("Common.submit") && !stdout.contains("[unused-key]"),
-        "configured bare translation callees should mark keys as used. stdout:\n{}",
-        stdout
-    );
+    // Both zh replica-lag and ja orphan should be reported
+    assert_cmd_snapshot!(test.check_command().arg("replica-lag").arg("orphan"));
 
     Ok(())
 }
 
 #[test]
-fn test_extra_translation_member_call_shadowed_import_is_not_counted() -> Result<()> {
+fn test_deeply_nested_key_paths() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2426,64 +5823,33 @@ fn test_extra_translation_member_call_shadowed_import_is_not_counted() -> Result
         r#"{
             "includes": ["src"],
             "messagesDir": "./messages",
-            "primaryLocale": "en",
-            "extraTranslationMemberCalls": [
-                {
-                    "objectName": "intl",
-                    "property": "translate",
-                    "importFrom": "./intl",
-                    "importName": "default"
-                }
-            ]
+            "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
         "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
-        }"#,
-    )?;
-
-    test.write_file(
-        "src/intl.ts",
-        r#"
-export default {
-    translate(key) {
-        return key;
-    },
-};
-"#,
+        r#"{"Form": {"validation": {"errors": {"email": {}}}}}"#,
     )?;
 
     test.write_file(
-        "src/app.ts",
+        "src/app.tsx",
         r#"
-import intl from './intl';
-
-export function renderLabel(intl) {
-    return intl.translate('Common.submit');
+const t = useTranslations("Form");
+export function Component() {
+    return <div>{t("validation.errors.email.invalid")}</div>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(output.status.success());
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        stdout.contains("Common.submit") && stdout.matches("[unused-key]").count() == 1,
-        "shadowed configured member-call imports must not count as translation usage. stdout:\n{}",
-        stdout
-    );
+    // Deep nested missing key should be reported
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_extra_translation_member_call_marks_key_as_used() -> Result<()> {
+fn test_empty_namespace() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2491,72 +5857,30 @@ fn test_extra_translation_member_call_marks_key_as_used() -> Result<()> {
         r#"{
             "includes": ["src"],
             "messagesDir": "./messages",
-            "primaryLocale": "en",
-            "extraTranslationMemberCalls": [
-                {
-                    "objectName": "intl",
-                    "property": "translate",
-                    "importFrom": "./intl",
-                    "importName": "default"
-                }
-            ]
-        }"#,
-    )?;
-
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file(
-        "src/intl.ts",
-        r#"
-export default {
-    translate(key) {
-        return key;
-    },
-};
-"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Empty": {}}"#)?;
 
     test.write_file(
-        "src/app.ts",
+        "src/app.tsx",
         r#"
-import intl from './intl';
-
-export function renderLabel() {
-    return intl.translate('Common.submit');
+const t = useTranslations("Empty");
+export function Component() {
+    return <div>Test</div>;
 }
 "#,
     )?;
 
-    let output = test.check_command().arg("unused").output()?;
-    assert!(
-        output.status.success(),
-        "check command should succeed for configured member-call fixtures. stderr: {}",
-        String::from_utf8_lossy(&output.stderr)
-    );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        !stdout.contains("Common.submit") && !stdout.contains("[unused-key]"),
-        "configured translation member calls should mark keys as used. stdout:\n{}",
-        stdout
-    );
+    // Empty namespace should be handled gracefully
+    assert_cmd_snapshot!(test.check_command().arg("unused"));
 
     Ok(())
 }
 
-// ============================================
-// Untranslated Value Tests
-// ============================================
-
 #[test]
-fn test_untranslated_value() -> Result<()> {
+fn test_special_characters_in_keys() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2568,38 +5892,54 @@ fn test_untranslated_value() -> Result<()> {
         }"#,
     )?;
 
-    // English (primary locale)
     test.write_file(
         "messages/en.json",
         r#"{
             "Common": {
-                "submit": "Submit",
-                "cancel": "Cancel"
+                "key-with-dash_and_underscore": "Value",
+                "123numeric": "Numeric"
             }
         }"#,
     )?;
 
-    // Chinese locale has same value as English (not translated)
     test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Common": {
-                "submit": "Submit",
-                "cancel": "Cancel"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Component() {
+    return (
+        <div>
+            <span>{t("key-with-dash_and_underscore")}</span>
+            <span>{t("123numeric")}</span>
+            <span>{t("missing-special")}</span>
+        </div>
+    );
+}
+"#,
     )?;
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+    // Special characters should be handled, missing key reported
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
-    // Should detect untranslated values
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    Ok(())
+}
+
+// ============================================================
+// Group E: Help & CLI Validation Tests
+// ============================================================
+
+#[test]
+fn test_help_shows_all_eight_rules() -> Result<()> {
+    let test = CliTest::new()?;
+
+    // Help should list all 8 rule names
+    assert_cmd_snapshot!(test.check_command().arg("--help"));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_correct_translation() -> Result<()> {
+fn test_invalid_rule_name_error() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2611,36 +5951,21 @@ fn test_untranslated_correct_translation() -> Result<()> {
         }"#,
     )?;
 
-    // English (primary locale)
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "welcome": "Welcome to our platform"
-            }
-        }"#,
-    )?;
-
-    // Correctly translated to Chinese
-    test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Common": {
-                "welcome": "欢迎来到我们的平台"
-            }
-        }"#,
-    )?;
-
+    test.write_file("messages/en.json", r#"{}"#)?;
     test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Should pass without issues
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    // Invalid rule name should show error
+    assert_cmd_snapshot!(test.check_command().arg("invalid-rule"));
 
     Ok(())
 }
 
+// ============================================================
+// Group F: Verbose & Exit Codes Tests
+// ============================================================
+
 #[test]
-fn test_untranslated_short_text() -> Result<()> {
+fn test_verbose_shows_config_source() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2652,36 +5977,39 @@ fn test_untranslated_short_text() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "ok": "OK",
-                "no": "No"
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    // Verbose should show config file path
+    assert_cmd_snapshot!(test.check_command().arg("--verbose"));
+
+    Ok(())
+}
+
+#[test]
+fn test_exit_code_on_errors() -> Result<()> {
+    let test = CliTest::new()?;
 
-    // Short texts that are the same - should be caught by untranslated check
     test.write_file(
-        "messages/zh.json",
+        ".glotrc.json",
         r#"{
-            "Common": {
-                "ok": "OK",
-                "no": "No"
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+    test.write_file("messages/en.json", r#"{}"#)?;
+    test.write_file("src/app.tsx", r#"<div>Hardcoded</div>"#)?;
 
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    // Errors should return exit code 1
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_any_locale() -> Result<()> {
+fn test_exit_code_on_success() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2693,35 +6021,26 @@ fn test_untranslated_any_locale() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "greeting": "Hello, welcome to our platform"
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
 
-    // Thai locale - value is same as English, should be flagged as untranslated
     test.write_file(
-        "messages/th.json",
-        r#"{
-            "Common": {
-                "greeting": "Hello, welcome to our platform"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
+}
+"#,
     )?;
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Should detect untranslated
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    // Clean project should return exit code 0
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_skip_primary_locale() -> Result<()> {
+fn test_error_on_warnings_exits_failure() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2733,26 +6052,20 @@ fn test_untranslated_skip_primary_locale() -> Result<()> {
         }"#,
     )?;
 
-    // Only primary locale exists
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
-        }"#,
-    )?;
-
+    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
     test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Should pass - primary locale is skipped
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("--error-on-warnings")
+            .arg("unused")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_subcommand_untranslated() -> Result<()> {
+fn test_max_warnings_exceeded_exits_failure() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2766,25 +6079,24 @@ fn test_subcommand_untranslated() -> Result<()> {
 
     test.write_file(
         "messages/en.json",
-        r#"{"Common": {"submit": "Submit button"}}"#,
-    )?;
-
-    // Untranslated
-    test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"submit": "Submit button"}}"#,
+        r#"{"Common": {"first": "First", "second": "Second"}}"#,
     )?;
-
     test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Should only run untranslated check
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    assert_cmd_snapshot!(test.check_command().args(["--max-warnings", "1", "unused"]));
+
+    // At the threshold is still fine
+    let output = test
+        .check_command()
+        .args(["--max-warnings", "2", "unused"])
+        .output()?;
+    assert!(output.status.success());
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_with_usages() -> Result<()> {
+fn test_fail_on_parse_error() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2795,39 +6107,31 @@ fn test_untranslated_with_usages() -> Result<()> {
             "primaryLocale": "en"
         }"#,
     )?;
-
-    test.write_file(
-        "messages/en.json",
-        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
-    )?;
-
-    // Untranslated - same value as English
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
+        "src/app.tsx",
+        r#"const t = useTranslations("Common");
+export function App() { return <button>{t("submit")}</button>; }"#,
     )?;
+    test.write_file("src/broken.tsx", r#"export function Broken( {"#)?;
 
-    // Use the keys in code
-    test.write_file(
-        "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
+    // Parse errors are errors like any other issue by default
+    let output = test.check_command().output()?;
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stdout)?.contains("[parse-error]"));
 
-export default function App() {
-    const t = useTranslations('Common');
-    return <button>{t('submit')}</button>;
-}
-"#,
-    )?;
+    assert_cmd_snapshot!(test.check_command().arg("--fail-on-parse-error"));
 
-    // Should show usage location
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    // Config errors have their own exit code
+    test.write_file(".glotrc.json", r#"{ "includes": "#)?;
+    let output = test.check_command().arg("--fail-on-parse-error").output()?;
+    assert_eq!(output.status.code(), Some(2));
 
     Ok(())
 }
 
 #[test]
-fn test_replica_lag_with_usages() -> Result<()> {
+fn test_quiet_prints_nothing_on_success() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2839,46 +6143,33 @@ fn test_replica_lag_with_usages() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file(
-        "messages/en.json",
-        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
-    )?;
-
-    // Missing cancel in Chinese
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
-
-    // Use both keys in code
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
         "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
-
-export default function App() {
-    const t = useTranslations('Common');
-    return (
-        <div>
-            <button>{t('submit')}</button>
-            <button>{t('cancel')}</button>
-        </div>
-    );
-}
-"#,
+        r#"const t = useTranslations("Common");
+export function App() { return <button>{t("submit")}</button>; }"#,
     )?;
+    test.write_file("src/broken.tsx", r#"export function Broken( {"#)?;
 
-    // Should show usage location for replica-lag
-    assert_cmd_snapshot!(test.check_command().arg("replica-lag"));
+    // The parse error is still an issue, only the trailing count line is dropped
+    let output = test
+        .check_command()
+        .args(["--quiet", "--fail-on-parse-error"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8(output.stdout)?.contains("[parse-error]"));
+    assert!(output.stderr.is_empty());
+
+    test.write_file("src/broken.tsx", r#"export const ok = 1;"#)?;
+    let output = test.check_command().arg("-q").output()?;
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
 
     Ok(())
 }
 
-// ============================================================
-// Untranslated with Disable Tests
-// ============================================================
-
 #[test]
-fn test_untranslated_skipped_when_all_usages_disabled() -> Result<()> {
-    // When ALL usages of a key have glot-disable-next-line untranslated,
-    // the untranslated issue should be suppressed.
+fn test_group_by_rule() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2890,35 +6181,25 @@ fn test_untranslated_skipped_when_all_usages_disabled() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
-
-    // Untranslated - same value as English (intentionally not translated brand name)
-    test.write_file("messages/zh.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
-
-    // All usages have disable comment
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "unused": "Unused"}}"#,
+    )?;
     test.write_file(
         "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
-
-export default function App() {
-    const t = useTranslations('Common');
-    // glot-disable-next-line untranslated
-    return <div>{t('brand')}</div>;
-}
-"#,
+        r#"const t = useTranslations("Common");
+export function App() {
+    return <div><button>{t("submit")}</button><p>{t("missing")}</p><span>Hello</span></div>;
+}"#,
     )?;
 
-    // Should have no errors - the key is intentionally not translated
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    assert_cmd_snapshot!(test.check_command().args(["--group-by", "rule"]));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_reported_when_some_usages_not_disabled() -> Result<()> {
-    // When SOME usages have disable but not all, the issue should still be reported.
-    // This is the "one-vote-veto" principle.
+fn test_stats_footer_counts_issues_per_rule() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2931,46 +6212,21 @@ fn test_untranslated_reported_when_some_usages_not_disabled() -> Result<()> {
     )?;
 
     test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-
-    // Untranslated - same value as English
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-
-    // First file has disable, second does not
     test.write_file(
         "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
-
-export function App1() {
-    const t = useTranslations('Common');
-    // glot-disable-next-line untranslated
-    return <button>{t('submit')}</button>;
-}
-"#,
-    )?;
-
-    test.write_file(
-        "src/other.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
-
-export function App2() {
-    const t = useTranslations('Common');
-    // No disable comment here
-    return <button>{t('submit')}</button>;
-}
-"#,
+        r#"const t = useTranslations("Common");
+export function App() {
+    return <div><button>{t("submit")}</button><p>{t("missing")}</p><span>Hello</span><em>World</em></div>;
+}"#,
     )?;
 
-    // Should still report the untranslated issue because not all usages are disabled
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    assert_cmd_snapshot!(test.check_command().arg("--stats-footer"));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_reported_when_hardcoded_only_disabled() -> Result<()> {
-    // glot-disable-next-line hardcoded should NOT affect untranslated rule
+fn test_profile_prints_phases_to_stderr() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -2983,33 +6239,28 @@ fn test_untranslated_reported_when_hardcoded_only_disabled() -> Result<()> {
     )?;
 
     test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-
-    // Untranslated - same value as English
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-
-    // Using hardcoded disable, NOT untranslated
     test.write_file(
         "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
-
-export default function App() {
-    const t = useTranslations('Common');
-    // glot-disable-next-line hardcoded
-    return <button>{t('submit')}</button>;
-}
-"#,
+        r#"const t = useTranslations("Common");
+export function App() {
+    return <button>{t("submit")}</button>;
+}"#,
     )?;
 
-    // Should still report the untranslated issue - hardcoded doesn't affect untranslated
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    // Durations are left out with GLOT_DISABLE_TIMING; stdout stays JSON Lines
+    assert_cmd_snapshot!(test.check_command().args([
+        "missing",
+        "unused",
+        "--profile",
+        "--format",
+        "jsonl"
+    ]));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_skipped_with_range_disable() -> Result<()> {
-    // glot-disable untranslated / glot-enable untranslated range should work
+fn test_explain_resolution() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3023,45 +6274,40 @@ fn test_untranslated_skipped_with_range_disable() -> Result<()> {
 
     test.write_file(
         "messages/en.json",
-        r#"{"Common": {"brand": "MyBrand", "submit": "Submit"}}"#,
-    )?;
-
-    // Both untranslated
-    test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"brand": "MyBrand", "submit": "Submit"}}"#,
+        r#"{"Tools": {"create": "Create", "edit": "Edit", "title": "Tools"}}"#,
     )?;
-
-    // brand is inside disable range, submit is outside
     test.write_file(
         "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
-
-export default function App() {
-    const t = useTranslations('Common');
-    // glot-disable untranslated
-    const brandName = t('brand');
-    // glot-enable untranslated
-    return (
-        <div>
-            <span>{brandName}</span>
-            <button>{t('submit')}</button>
-        </div>
+        r#"const toolKeys = { create: "create", edit: "edit" };
+const t = useTranslations("Tools");
+export function Tool({ name, other }) {
+    return <h1 title={t("title")}>{t(toolKeys[name])}{t(other)}</h1>;
+}"#,
+    )?;
+
+    // The trace goes to stderr, and the check runs as usual
+    assert_cmd_snapshot!(test.check_command().args([
+        "missing",
+        "unresolved",
+        "--explain-resolution",
+        "src/app.tsx:4"
+    ]));
+    assert_cmd_snapshot!(
+        "explain_resolution_no_call",
+        test.check_command()
+            .args(["missing", "--explain-resolution", "./src/app.tsx:1"])
+    );
+    assert_cmd_snapshot!(
+        "explain_resolution_unknown_file",
+        test.check_command()
+            .args(["missing", "--explain-resolution", "src/other.tsx:1"])
     );
-}
-"#,
-    )?;
-
-    // Should only report submit, not brand (brand is inside disable range)
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_skipped_with_no_args_disable() -> Result<()> {
-    // glot-disable-next-line (no args) should disable all rules including untranslated
+fn test_exclude_rule_skips_named_checks() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3073,33 +6319,54 @@ fn test_untranslated_skipped_with_no_args_disable() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
-
-    // Untranslated
-    test.write_file("messages/zh.json", r#"{"Common": {"brand": "MyBrand"}}"#)?;
-
-    // No args = disable all rules
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/de.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
         "src/app.tsx",
-        r#"
-import {useTranslations} from 'next-intl';
+        r#"const t = useTranslations("Common");
+export function App() {
+    return <div><button>{t("submit")}</button><p>{t("missing")}</p><span>Hello</span></div>;
+}"#,
+    )?;
 
-export default function App() {
-    const t = useTranslations('Common');
-    // glot-disable-next-line
-    return <div>{t('brand')}</div>;
+    // Only missing-key remains: untranslated and hardcoded are excluded
+    assert_cmd_snapshot!(test.check_command().args([
+        "--exclude-rule",
+        "untranslated",
+        "--exclude-rule",
+        "hardcoded"
+    ]));
+
+    Ok(())
 }
-"#,
-    )?;
 
-    // Should have no errors
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+#[test]
+fn test_exclude_rule_rejects_unknown_rule() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    assert_cmd_snapshot!(test.check_command().args(["--exclude-rule", "untranslatd"]));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_empty_string() -> Result<()> {
+fn test_exclude_rule_every_selected_check() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file("messages/en.json", r#"{}"#)?;
+
+    assert_cmd_snapshot!(
+        test.check_command()
+            .args(["missing", "--exclude-rule", "missing"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_quiet_and_no_summary_keep_issues() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3111,37 +6378,25 @@ fn test_untranslated_empty_string() -> Result<()> {
         }"#,
     )?;
 
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit",
-                "cancel": "Cancel"
-            }
-        }"#,
-    )?;
-
-    // Chinese locale has empty string values (not translated yet)
-    test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Common": {
-                "submit": "",
-                "cancel": "取消"
-            }
-        }"#,
-    )?;
-
+    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
     test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Should detect empty string as untranslated
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    assert_cmd_snapshot!(test.check_command().args([
+        "--quiet",
+        "--no-summary",
+        "--error-on-warnings",
+        "unused"
+    ]));
+
+    // `--quiet` alone keeps the counts line
+    let output = test.check_command().args(["--quiet", "unused"]).output()?;
+    assert!(String::from_utf8(output.stdout)?.contains("1 problems"));
 
     Ok(())
 }
 
 #[test]
-fn test_untranslated_empty_and_identical() -> Result<()> {
+fn test_format_jsonl() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3153,48 +6408,73 @@ fn test_untranslated_empty_and_identical() -> Result<()> {
         }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
     test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"const t = useTranslations("Common");
+export function App() { return <button>{t("submit")}</button>; }"#,
     )?;
+    test.write_file("src/broken.tsx", r#"export function Broken( {"#)?;
+
+    assert_cmd_snapshot!(
+        test.check_command()
+            .args(["--format", "jsonl", "missing", "unused"])
+    );
+
+    // Every line is a JSON object, the last one being the summary
+    let output = test.check_command().args(["--format", "jsonl"]).output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    let records: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    assert_eq!(records.last().unwrap()["type"], "summary");
+    assert_eq!(
+        records.iter().filter(|r| r["type"] == "issue").count(),
+        records.len() - 1
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_report_file() -> Result<()> {
+    let test = CliTest::new()?;
 
-    // zh has empty string, ja has identical value
     test.write_file(
-        "messages/zh.json",
+        ".glotrc.json",
         r#"{
-            "Common": {
-                "submit": ""
-            }
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en"
         }"#,
     )?;
 
+    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
     test.write_file(
-        "messages/ja.json",
-        r#"{
-            "Common": {
-                "submit": "Submit"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"const t = useTranslations("Common");
+export function App() { return <button>{t("submit")}</button>; }"#,
     )?;
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+    // stdout keeps the text report, the file gets JSON Lines
+    assert_cmd_snapshot!(
+        test.check_command()
+            .args(["--format", "jsonl", "--report-file", "report.jsonl"])
+            .args(["missing", "unused"])
+    );
+    insta::assert_snapshot!("report_file_jsonl", test.read_file("report.jsonl")?);
 
-    // Should detect both empty and identical as untranslated
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    test.check_command()
+        .args(["--report-file", "report.txt", "missing", "unused"])
+        .output()?;
+    insta::assert_snapshot!("report_file_text", test.read_file("report.txt")?);
 
     Ok(())
 }
 
-// ============================================================
-// Type Mismatch Tests
-// ============================================================
-
 #[test]
-fn test_type_mismatch_array_vs_string() -> Result<()> {
+fn test_max_warnings_zero_fails_on_any_warning() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3206,36 +6486,31 @@ fn test_type_mismatch_array_vs_string() -> Result<()> {
         }"#,
     )?;
 
-    // English (primary locale) has an array
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Page": {
-                "benefits": ["Fast", "Easy", "Reliable"]
-            }
-        }"#,
-    )?;
+    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Chinese locale has string instead of array (TYPE MISMATCH!)
+    let output = test
+        .check_command()
+        .args(["--max-warnings", "0", "unused"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
+
+    // Errors still fail regardless of the warning budget
     test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Page": {
-                "benefits": "快速、简单、可靠"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"export function App() { return <div>Hardcoded</div>; }"#,
     )?;
-
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Should detect type mismatch
-    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
+    let output = test
+        .check_command()
+        .args(["--max-warnings", "10"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(1));
 
     Ok(())
 }
 
 #[test]
-fn test_type_mismatch_no_issue_when_types_match() -> Result<()> {
+fn test_stdin_reports_per_file_diagnostics() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3246,37 +6521,40 @@ fn test_type_mismatch_no_issue_when_types_match() -> Result<()> {
             "primaryLocale": "en"
         }"#,
     )?;
-
-    // English (primary locale) has an array
     test.write_file(
         "messages/en.json",
-        r#"{
-            "Page": {
-                "benefits": ["Fast", "Easy", "Reliable"]
-            }
-        }"#,
+        r#"{"Common": {"title": "Title", "unused": "Unused"}}"#,
     )?;
+    // The file on disk is clean; the editor buffer comes from stdin
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Chinese locale also has array (correct!)
-    test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Page": {
-                "benefits": ["快速", "简单", "可靠"]
-            }
-        }"#,
-    )?;
+    let source = r#"import { useTranslations } from "next-intl";
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+export function App({ key }: { key: string }) {
+  const t = useTranslations("Common");
+  return <p title={t(key)}>Café ünïcode</p>;
+}
+"#;
 
-    // Should have no issues
-    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
+    assert_cmd_snapshot!(
+        test.check_command()
+            .args(["--stdin", "--stdin-filename", "src/app.tsx"])
+            .pass_stdin(source)
+    );
+
+    // Only per-file rules run, even when others are selected
+    assert_cmd_snapshot!(
+        "stdin_skips_cross_file_rules",
+        test.check_command()
+            .args(["unused", "--stdin", "--stdin-filename", "src/app.tsx"])
+            .pass_stdin("const x = 1;")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_type_mismatch_multiple_locales() -> Result<()> {
+fn test_diff_file_reports_only_added_lines() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3288,46 +6566,73 @@ fn test_type_mismatch_multiple_locales() -> Result<()> {
         }"#,
     )?;
 
-    // English (primary locale) has an array
+    test.write_file("messages/en.json", r#"{}"#)?;
     test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "tags": ["tag1", "tag2"]
-            }
-        }"#,
+        "src/app.tsx",
+        r#"export function App() {
+  return (
+    <div>
+      <h1>Old title</h1>
+      <p>New paragraph</p>
+    </div>
+  );
+}"#,
+    )?;
+    test.write_file(
+        "changes.diff",
+        r#"diff --git a/src/app.tsx b/src/app.tsx
+--- a/src/app.tsx
++++ b/src/app.tsx
+@@ -4,0 +5 @@ export function App() {
++      <p>New paragraph</p>
+"#,
     )?;
 
-    // Chinese locale has string (TYPE MISMATCH!)
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("--diff-file")
+            .arg("changes.diff")
+            .arg("hardcoded")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_severity_override_untranslated_warning() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "messages/zh.json",
+        ".glotrc.json",
         r#"{
-            "Common": {
-                "tags": "标签1, 标签2"
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "severities": {
+                "untranslated": "warning"
             }
         }"#,
     )?;
 
-    // Japanese locale also has string (TYPE MISMATCH!)
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
-        "messages/ja.json",
-        r#"{
-            "Common": {
-                "tags": "タグ1、タグ2"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
+}
+"#,
     )?;
 
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Should detect type mismatch in both locales
-    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_type_mismatch_with_usages() -> Result<()> {
+fn test_config_severity_override_untranslated_error_without_usage() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3335,52 +6640,60 @@ fn test_type_mismatch_with_usages() -> Result<()> {
         r#"{
             "includes": ["src"],
             "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
-    )?;
-
-    // English (primary locale) has an array
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Page": {
-                "features": ["Feature 1", "Feature 2"]
+            "primaryLocale": "en",
+            "severities": {
+                "untranslated": "error"
             }
         }"#,
     )?;
 
-    // Chinese locale has string (TYPE MISMATCH!)
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_config_severity_off_disables_rule() -> Result<()> {
+    let test = CliTest::new()?;
+
     test.write_file(
-        "messages/zh.json",
+        ".glotrc.json",
         r#"{
-            "Page": {
-                "features": "功能1, 功能2"
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "severities": {
+                "untranslated": "off"
             }
         }"#,
     )?;
 
-    // Source file that uses the key
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
         "src/app.tsx",
         r#"
-import {useTranslations} from 'next-intl';
+import { useTranslations } from "next-intl";
 
-export default function App() {
-    const t = useTranslations('Page');
-    const features = t.raw('features');
-    return <ul>{features.map(f => <li key={f}>{f}</li>)}</ul>;
+export function App() {
+    const t = useTranslations("Common");
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    // Should detect type mismatch with usage location
-    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
+    // Explicitly selecting the rule doesn't bring it back
+    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
 
     Ok(())
 }
 
 #[test]
-fn test_subcommand_type_mismatch() -> Result<()> {
+fn test_config_severity_override_unused_error() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3388,91 +6701,78 @@ fn test_subcommand_type_mismatch() -> Result<()> {
         r#"{
             "includes": ["src"],
             "messagesDir": "./messages",
-            "primaryLocale": "en"
+            "primaryLocale": "en",
+            "severities": {
+                "unused": "error"
+            }
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Page": {"items": ["a", "b"]}}"#)?;
-
-    // Type mismatch
-    test.write_file("messages/zh.json", r#"{"Page": {"items": "a, b"}}"#)?;
-
+    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
     test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Run only type-mismatch check
-    assert_cmd_snapshot!(test.check_command().arg("type-mismatch"));
+    assert_cmd_snapshot!(test.check_command().arg("unused"));
 
     Ok(())
 }
 
 // ============================================================
-// Group A: Multiple Rules & Default Behavior Tests
+// Group G: Integration Scenarios Tests
 // ============================================================
 
 #[test]
-fn test_multiple_rules_hardcoded_missing() -> Result<()> {
+fn test_real_world_nextjs_structure() -> Result<()> {
     let test = CliTest::new()?;
 
-    test.write_file(
-        ".glotrc.json",
-        r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
-    )?;
+    // No explicit config - should use defaults
 
-    test.write_file("messages/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
 
     test.write_file(
-        "src/app.tsx",
+        "src/app/[locale]/page.tsx",
         r#"
 const t = useTranslations("Common");
-export function Button() {
-    return (
-        <div>
-            <button>{t("submit")}</button>
-            <span>Hardcoded</span>
-        </div>
-    );
+export default function Page() {
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    // Both hardcoded text and missing key errors should appear
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded").arg("missing"));
+    test.write_file(
+        "src/app/[locale]/layout.tsx",
+        r#"
+export default function Layout({ children }) {
+    return <html><body>{children}</body></html>;
+}
+"#,
+    )?;
+
+    // Defaults should work without explicit config
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_multiple_rules_unused_orphan() -> Result<()> {
+fn test_monorepo_style_paths() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
             "messagesDir": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    // Primary (en): submit + unused
-    test.write_file(
-        "messages/en.json",
-        r#"{"Common": {"submit": "Submit", "unused": "Unused"}}"#,
-    )?;
-
-    // Secondary (zh): submit + orphan
     test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"submit": "提交", "orphan": "孤儿"}}"#,
+        "packages/web/messages/en.json",
+        r#"{"Common": {"submit": "Submit"}}"#,
     )?;
 
-    // Only uses "submit"
     test.write_file(
-        "src/app.tsx",
+        "packages/web/src/app.tsx",
         r#"
 const t = useTranslations("Common");
 export function Button() {
@@ -3481,14 +6781,20 @@ export function Button() {
 "#,
     )?;
 
-    // Both unused key (en) and orphan key (zh) warnings
-    assert_cmd_snapshot!(test.check_command().arg("unused").arg("orphan"));
+    // Nested paths should work correctly
+    assert_cmd_snapshot!(
+        test.check_command()
+            .arg("--source-root")
+            .arg("packages/web/src")
+            .arg("--messages-root")
+            .arg("packages/web/messages")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_multiple_rules_all_eight() -> Result<()> {
+fn test_ignore_test_files() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3500,68 +6806,47 @@ fn test_multiple_rules_all_eight() -> Result<()> {
         }"#,
     )?;
 
-    // Primary (en): key1 (array), key2, key3, unused_key
+    test.write_file("messages/en.json", r#"{}"#)?;
+
     test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "key1": ["item1", "item2"],
-                "key2": "Value 2",
-                "key3": "Value 3",
-                "unused_key": "Unused"
-            }
-        }"#,
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function Button() {
+    return <button>{t("submit")}</button>;
+}
+"#,
     )?;
 
-    // Secondary (zh): key1 (string - type mismatch), key2 (untranslated), orphan_key, missing key3 (replica lag)
     test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Common": {
-                "key1": "item1, item2",
-                "key2": "Value 2",
-                "orphan_key": "孤儿"
-            }
-        }"#,
+        "src/app.test.tsx",
+        r#"
+test("should render", () => {
+    render(<div>Hardcoded in test</div>);
+});
+"#,
     )?;
 
     test.write_file(
-        "src/app.tsx",
+        "src/Button.stories.tsx",
         r#"
-const t = useTranslations("Common");
-export function Component({ dynamicKey }) {
-    return (
-        <div>
-            <span>Hardcoded</span>
-            <button>{t("missing_key")}</button>
-            <div>{t("key1")}</div>
-            <div>{t("key2")}</div>
-            <div>{t("key3")}</div>
-            <div>{t(dynamicKey)}</div>
-        </div>
-    );
-}
+export const Primary = () => <div>Hardcoded in story</div>;
 "#,
     )?;
 
-    // All 8 rule violations should be reported
+    // Test and story files are skipped by default
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+
     assert_cmd_snapshot!(
-        test.check_command()
-            .arg("hardcoded")
-            .arg("missing")
-            .arg("unused")
-            .arg("orphan")
-            .arg("replica-lag")
-            .arg("untranslated")
-            .arg("type-mismatch")
-            .arg("unresolved")
+        "ignore_test_files_include_tests",
+        test.check_command().args(["hardcoded", "--include-tests"])
     );
 
     Ok(())
 }
 
 #[test]
-fn test_default_runs_all_rules() -> Result<()> {
+fn test_large_message_file() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
@@ -3573,1340 +6858,1723 @@ fn test_default_runs_all_rules() -> Result<()> {
         }"#,
     )?;
 
-    // Same setup as test_multiple_rules_all_eight
-    test.write_file(
-        "messages/en.json",
-        r#"{
-            "Common": {
-                "key1": ["item1", "item2"],
-                "key2": "Value 2",
-                "key3": "Value 3",
-                "unused_key": "Unused"
-            }
-        }"#,
-    )?;
-
-    test.write_file(
-        "messages/zh.json",
-        r#"{
-            "Common": {
-                "key1": "item1, item2",
-                "key2": "Value 2",
-                "orphan_key": "孤儿"
-            }
-        }"#,
-    )?;
+    // Generate 200+ keys
+    let mut keys = Vec::new();
+    for i in 0..250 {
+        keys.push(format!(r#""key{}": "Value {}""#, i, i));
+    }
+    let large_json = format!(r#"{{"Common": {{{}}}}}"#, keys.join(", "));
+    test.write_file("messages/en.json", &large_json)?;
 
     test.write_file(
         "src/app.tsx",
         r#"
 const t = useTranslations("Common");
-export function Component({ dynamicKey }) {
-    return (
-        <div>
-            <span>Hardcoded</span>
-            <button>{t("missing_key")}</button>
-            <div>{t("key1")}</div>
-            <div>{t("key2")}</div>
-            <div>{t("key3")}</div>
-            <div>{t(dynamicKey)}</div>
-        </div>
-    );
+export function Component() {
+    return <div>{t("key0")}</div>;
 }
 "#,
     )?;
 
-    // No rule arguments - should run all rules
-    assert_cmd_snapshot!(test.check_command());
+    // Should complete successfully and report many unused keys
+    assert_cmd_snapshot!(test.check_command().arg("unused"));
 
     Ok(())
 }
 
+// ============================================================
+// Group F: react-i18next Tests
+// ============================================================
+
 #[test]
-fn test_multiple_rules_no_issues() -> Result<()> {
+fn test_react_i18next_hardcoded_text() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
+            "framework": "react-i18next",
+            "includes": ["src/components"],
+            "messagesRoot": "./src/locales",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
+    test.write_file("src/locales/en.json", r#"{}"#)?;
 
     test.write_file(
-        "src/app.tsx",
+        "src/components/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
+import { useTranslation } from 'react-i18next';
+
+export function App() {
+    const { t } = useTranslation();
+    return (
+        <div>
+            <h1>Welcome to our app</h1>
+            <p>{t('description')}</p>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Clean project - should have success exit code
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("hardcoded")
-            .arg("missing")
-            .arg("unused")
-    );
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
 
     Ok(())
 }
 
 #[test]
-fn test_rule_order_does_not_matter() -> Result<()> {
+fn test_react_i18next_translation_recognized() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
+            "framework": "react-i18next",
+            "includes": ["src/components"],
+            "messagesRoot": "./src/locales",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
+    test.write_file(
+        "src/locales/en.json",
+        r#"{ "title": "Welcome", "description": "Hello" }"#,
+    )?;
 
+    // All text uses t() — should be clean
     test.write_file(
-        "src/app.tsx",
+        "src/components/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
+import { useTranslation } from 'react-i18next';
+
+export function App() {
+    const { t } = useTranslation();
     return (
         <div>
-            <button>{t("submit")}</button>
-            <span>Hardcoded</span>
+            <h1>{t('title')}</h1>
+            <p>{t('description')}</p>
         </div>
     );
 }
 "#,
     )?;
 
-    // Order 1: missing then hardcoded
-    assert_cmd_snapshot!(
-        "order1",
-        test.check_command().arg("missing").arg("hardcoded")
-    );
-
-    // Order 2: hardcoded then missing (should be identical)
-    assert_cmd_snapshot!(
-        "order2",
-        test.check_command().arg("hardcoded").arg("missing")
-    );
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
-// ============================================================
-// Group B: Unresolved Rule Tests
-// ============================================================
-
 #[test]
-fn test_subcommand_unresolved() -> Result<()> {
+fn test_react_i18next_missing_key() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
+            "framework": "react-i18next",
+            "includes": ["src/components"],
+            "messagesRoot": "./src/locales",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"key1": "Value 1"}}"#)?;
+    // Only "title" exists, "description" is missing
+    test.write_file("src/locales/en.json", r#"{ "title": "Welcome" }"#)?;
 
     test.write_file(
-        "src/app.tsx",
+        "src/components/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Dynamic({ keyName }) {
-    return <div>{t(keyName)}</div>;
+import { useTranslation } from 'react-i18next';
+
+export function App() {
+    const { t } = useTranslation();
+    return (
+        <div>
+            <h1>{t('title')}</h1>
+            <p>{t('description')}</p>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Unresolved - variable reference
-    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_unresolved_with_glot_message_keys_resolves() -> Result<()> {
+fn test_react_i18next_namespace_separator() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
+            "library": "react-i18next",
+            "includes": ["src/components"],
+            "messagesRoot": "./src/locales",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
-        "messages/en.json",
-        r#"{"Common": {"key1": "K1", "key2": "K2"}}"#,
+        "src/locales/en.json",
+        r#"{
+            "common": { "save": "Save" },
+            "settings": { "title": "Settings" }
+        }"#,
     )?;
 
+    // `common:save` overrides the hook namespace; `common:cancel` is missing
     test.write_file(
-        "src/app.tsx",
+        "src/components/app.tsx",
         r#"
-const t = useTranslations("Common");
-// glot-message-keys "key1" "key2"
-export function Dynamic({ keyName }) {
-    return <div>{t(keyName)}</div>;
+import { useTranslation } from 'react-i18next';
+
+export function App() {
+    const { t } = useTranslation(["settings", "common"]);
+    return (
+        <div>
+            <h1>{t('title', { defaultValue: 'Settings' })}</h1>
+            <button>{t('common:save')}</button>
+            <button>{t('common:cancel')}</button>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // No unresolved warnings - keys resolved by annotation
-    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_unresolved_template_literal_static() -> Result<()> {
+fn test_next_intl_colon_is_part_of_key() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
+            "framework": "next-intl",
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"static": "Static"}}"#)?;
+    test.write_file("messages/en.json", r#"{ "common": { "save": "Save" } }"#)?;
 
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Component() {
-    return <div>{t(`static`)}</div>;
+import { useTranslations } from 'next-intl';
+
+export function App() {
+    const t = useTranslations();
+    return <button>{t('common:save')}</button>;
 }
 "#,
     )?;
 
-    // Static template literal should resolve
-    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+    assert_cmd_snapshot!(test.check_command().arg("missing"));
 
     Ok(())
 }
 
 #[test]
-fn test_unresolved_ternary_with_variables() -> Result<()> {
+fn test_unused_namespace() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
+            "framework": "next-intl",
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
     test.write_file(
         "messages/en.json",
-        r#"{"Common": {"k1": "K1", "k2": "K2"}}"#,
+        r#"{
+  "Common": { "submit": "Submit" },
+  "Settings": { "title": "Settings" },
+  "Legacy": { "title": "Old", "body": "Old body" }
+}"#,
     )?;
 
+    // Settings is referenced by a hook even though no key is used yet
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Component({ condition }) {
-    const key1 = "k1";
-    const key2 = "k2";
-    return <div>{t(condition ? key1 : key2)}</div>;
+import { useTranslations } from 'next-intl';
+
+export function App() {
+    const t = useTranslations();
+    const settings = useTranslations('Settings');
+    return <button>{t('Common.submit')}</button>;
 }
 "#,
     )?;
 
-    // Ternary with variable branches is unresolved
-    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+    assert_cmd_snapshot!(test.check_command().arg("unused-namespace"));
 
     Ok(())
 }
 
 #[test]
-fn test_unresolved_method_call_result() -> Result<()> {
+fn test_placeholder_mismatch() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
+            "framework": "next-intl",
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"key": "Value"}}"#)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "greeting": "Hello {name}",
+    "items": "{count, plural, one {# item} other {# items}}"
+  }
+}"#,
+    )?;
+
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+  "Common": {
+    "greeting": "你好 {naem}",
+    "items": "{count, plural, other {# 件}}"
+  }
+}"#,
+    )?;
+
+    test.write_file(
+        "messages/ja.json",
+        r#"{
+  "Common": {
+    "greeting": "こんにちは",
+    "items": "{count} 件"
+  }
+}"#,
+    )?;
 
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-const obj = { getKey: () => "key" };
-export function Component() {
-    return <div>{t(obj.getKey())}</div>;
+import { useTranslations } from 'next-intl';
+
+export function App() {
+    const t = useTranslations('Common');
+    return <p>{t('greeting', { name: 'A' })} {t('items', { count: 2 })}</p>;
 }
 "#,
     )?;
 
-    // Method call result is unresolved
-    assert_cmd_snapshot!(test.check_command().arg("unresolved"));
+    assert_cmd_snapshot!(test.check_command().arg("placeholder-mismatch"));
 
     Ok(())
 }
 
-// ============================================================
-// Group C: Common Arguments Tests
-// ============================================================
-
 #[test]
-fn test_primary_locale_override() -> Result<()> {
+fn test_jobs_single_thread_matches_default() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-    test.write_file("messages/ja.json", r#"{}"#)?;
-
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Override primary locale to "ja" - should report "en" has replica lag
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("replica-lag")
-            .arg("--primary-locale")
-            .arg("ja")
-    );
-
-    Ok(())
-}
-
-#[test]
-fn test_source_root_override() -> Result<()> {
-    let test = CliTest::new()?;
-
     test.write_file(
-        ".glotrc.json",
-        r#"{
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
+        "messages/en.json",
+        r#"{ "Common": { "submit": "Submit" } }"#,
     )?;
+    for name in ["a", "b", "c", "d"] {
+        test.write_file(
+            &format!("src/{}.tsx", name),
+            &format!(
+                r#"
+import {{ useTranslations }} from 'next-intl';
 
-    test.write_file("messages/en.json", r#"{}"#)?;
-
-    test.write_file("custom-src/app.tsx", r#"<div>Hardcoded</div>"#)?;
+export function {name}() {{
+    const t = useTranslations('Common');
+    return <button title="Hello {name}">{{t('{name}')}}</button>;
+}}
+"#
+            ),
+        )?;
+    }
 
-    // Override source directory
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("hardcoded")
-            .arg("--source-root")
-            .arg("custom-src")
+    let default_output = test.check_command().output()?;
+    let single_output = test.check_command().args(["--jobs", "1"]).output()?;
+    assert_eq!(default_output.status.code(), single_output.status.code());
+    assert_eq!(
+        String::from_utf8_lossy(&default_output.stdout),
+        String::from_utf8_lossy(&single_output.stdout)
     );
 
     Ok(())
 }
 
 #[test]
-fn test_messages_root_override() -> Result<()> {
+fn test_cache_reuses_and_invalidates_results() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
     test.write_file(
-        "custom-messages/en.json",
-        r#"{"Common": {"cancel": "Cancel"}}"#,
+        "messages/en.json",
+        r#"{ "Common": { "submit": "Submit", "cancel": "Cancel" } }"#,
+    )?;
+    test.write_file(
+        "src/keys.ts",
+        r#"export const ACTION_KEYS = ["submit"] as const;"#,
     )?;
-
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
-}
-"#,
-    )?;
+import { useTranslations } from 'next-intl';
+import { ACTION_KEYS } from './keys';
 
-    // Override messages directory
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("missing")
-            .arg("--messages-root")
-            .arg("custom-messages")
-    );
-
-    Ok(())
+export function App() {
+    const t = useTranslations('Common');
+    return <div>{ACTION_KEYS.map((k) => <button key={k}>{t(k)}</button>)}</div>;
 }
-
-#[test]
-fn test_all_three_args_combined() -> Result<()> {
-    let test = CliTest::new()?;
-
-    test.write_file(
-        ".glotrc.json",
-        r#"{
-            "messagesDir": "./messages",
-            "primaryLocale": "zh"
-        }"#,
+"#,
     )?;
 
-    test.write_file("i18n/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
+    let uncached = test.check_command().output()?;
+    let first = test.check_command().arg("--cache").output()?;
+    assert!(test.read_file(".glotcache").is_ok());
+    let second = test.check_command().arg("--cache").output()?;
+    assert_eq!(uncached.stdout, first.stdout);
+    assert_eq!(first.stdout, second.stdout);
 
+    // Changing an imported file must re-resolve the files that depend on it
     test.write_file(
-        "custom-src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
-}
-"#,
+        "src/keys.ts",
+        r#"export const ACTION_KEYS = ["cancel"] as const;"#,
     )?;
-
-    // All three overrides together
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("missing")
-            .arg("--primary-locale")
-            .arg("en")
-            .arg("--source-root")
-            .arg("custom-src")
-            .arg("--messages-root")
-            .arg("i18n")
-    );
+    let uncached = test.check_command().output()?;
+    let cached = test.check_command().arg("--cache").output()?;
+    assert_eq!(uncached.stdout, cached.stdout);
+    assert_ne!(first.stdout, cached.stdout);
 
     Ok(())
 }
 
 #[test]
-fn test_verbose_shows_scan_info() -> Result<()> {
+fn test_cache_corrupt_file_falls_back_to_full_scan() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{ "Common": { "submit": "Submit" } }"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+export function App() {
+    return <button>Click me</button>;
+}
+"#,
+    )?;
+    test.write_file("cache/glot.json", "{ not json")?;
 
-    test.write_file("messages/en.json", r#"{"Common": {}}"#)?;
-    test.write_file("src/app.tsx", r#"<div>Hardcoded</div>"#)?;
-
-    // Verbose flag should show diagnostic info
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded").arg("--verbose"));
+    assert_cmd_snapshot!(test.check_command().args([
+        "--cache",
+        "--cache-location",
+        "cache/glot.json"
+    ]));
+    assert!(test.read_file("cache/glot.json")?.starts_with('{'));
+    assert!(test.read_file("cache/glot.json")?.contains("\"version\""));
 
     Ok(())
 }
 
 #[test]
-fn test_verbose_with_parse_error() -> Result<()> {
+fn test_message_pattern_merges_namespace_files() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagePattern": "./locales/{locale}/**/*.json",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file(
+        "locales/en/common.json",
+        r#"{
+  "submit": "Submit",
+  "cancel": "Cancel"
+}"#,
+    )?;
+    test.write_file(
+        "locales/en/auth.json",
+        r#"{
+  "login": "Log in"
+}"#,
+    )?;
+    test.write_file(
+        "locales/en/legacy/common.json",
+        r#"{
+  "submit": "Send"
+}"#,
+    )?;
+    test.write_file(
+        "locales/zh/common.json",
+        r#"{
+  "submit": "提交",
+  "cancel": "取消"
+}"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { useTranslations } from "next-intl";
 
-    test.write_file("messages/en.json", r#"{}"#)?;
-    test.write_file("src/broken.tsx", "<div>Unclosed")?;
+export function App() {
+    const t = useTranslations("common");
+    const auth = useTranslations("auth");
+    return (
+        <div>
+            <button>{t("submit")}</button>
+            <button>{t("cancel")}</button>
+            <button>{auth("login")}</button>
+            <button>{auth("logout")}</button>
+        </div>
+    );
+}
+"#,
+    )?;
 
-    // Verbose should show parse error details
-    assert_cmd_snapshot!(test.check_command().arg("--verbose"));
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_common_args_with_multiple_rules() -> Result<()> {
+fn test_key_naming() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "messagesDir": "./messages",
-            "primaryLocale": "zh"
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "keyNaming": { "case": "camel" }
         }"#,
     )?;
-
-    test.write_file("i18n/en.json", r#"{"Common": {"cancel": "Cancel"}}"#)?;
-
     test.write_file(
-        "custom-src/app.tsx",
+        "messages/en.json",
+        r#"{
+  "common": {
+    "submitButton": "Submit",
+    "cancel_button": "Cancel",
+    "Legacy_title": "Legacy"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
+import { useTranslations } from "next-intl";
+
+export function App() {
+    const t = useTranslations("common");
     return (
         <div>
-            <button>{t("submit")}</button>
-            <span>Hardcoded</span>
+            <button>{t("submitButton")}</button>
+            <button>{t("cancel_button")}</button>
+            {/* glot-disable-next-line key-naming */}
+            <h1>{t("Legacy_title")}</h1>
         </div>
     );
 }
 "#,
     )?;
 
-    // Common args with multiple rules
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("hardcoded")
-            .arg("missing")
-            .arg("--verbose")
-            .arg("--primary-locale")
-            .arg("en")
-            .arg("--source-root")
-            .arg("custom-src")
-            .arg("--messages-root")
-            .arg("i18n")
-    );
+    assert_cmd_snapshot!(test.check_command().arg("key-naming"));
 
     Ok(())
 }
 
-// ============================================================
-// Group D: Edge Cases Tests
-// ============================================================
-
 #[test]
-fn test_empty_project_no_source_files() -> Result<()> {
+fn test_empty_value() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "title": "",
+    "subtitle": "Welcome",
+    "tags": ["a", "b"]
+  }
+}"#,
+    )?;
+    test.write_file(
+        "messages/zh.json",
+        r#"{
+  "Common": {
+    "title": "",
+    "subtitle": "   ",
+    "tags": []
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { useTranslations } from "next-intl";
 
-    test.write_file("messages/en.json", r#"{}"#)?;
+export function App() {
+    const t = useTranslations("Common");
+    return <h1>{t("title")}</h1>;
+}
+"#,
+    )?;
 
-    // Empty directory - no source files
-    // Should succeed with "No problems found"
-    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(test.check_command().arg("empty-value"));
 
     Ok(())
 }
 
 #[test]
-fn test_no_messages_files() -> Result<()> {
+fn test_nested_key_shadowing_reported_for_any_rule() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    // No messages files at all
-
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Common.submit": "Send",
+  "Common": {
+    "submit": "Submit"
+  }
+}"#,
+    )?;
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
+export function App() {
+    return <div />;
 }
 "#,
     )?;
 
-    // All keys should be reported as missing
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
+    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
 
     Ok(())
 }
 
 #[test]
-fn test_all_files_ignored_by_pattern() -> Result<()> {
+fn test_duplicate_key() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "ignores": ["**/*"],
-            "messagesDir": "./messages",
+            "includes": ["src"],
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "submit": "Send",
+    "submit": "Submit"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+export function App() {
+    return <div />;
+}
+"#,
+    )?;
 
-    test.write_file("messages/en.json", r#"{}"#)?;
-    test.write_file("src/app.tsx", r#"<div>Hardcoded</div>"#)?;
-
-    // All files ignored - should find no problems
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+    assert_cmd_snapshot!(test.check_command().arg("duplicate-key"));
 
     Ok(())
 }
 
 #[test]
-fn test_parse_error_in_tsx() -> Result<()> {
+fn test_duplicate_key_yaml_and_severity_override() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "severities": { "duplicate-key": "warning" }
         }"#,
     )?;
+    test.write_file(
+        "messages/en.yml",
+        "Common:\n  ok: One\n  ok: Two\n  ok: Three\n",
+    )?;
+    test.write_file("src/app.tsx", "export const x = 1;\n")?;
 
-    test.write_file("messages/en.json", r#"{}"#)?;
-    test.write_file("src/broken.tsx", "<div>Unclosed")?;
-
-    // Parse error should be reported
-    assert_cmd_snapshot!(test.check_command());
+    let output = test.check_command().arg("duplicate-key").output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(output.status.success(), "{}", stdout);
+    assert_eq!(
+        stdout
+            .matches("warning: \"Common.ok\"  [duplicate-key]")
+            .count(),
+        2
+    );
+    assert!(stdout.contains("en.yml:3:1"));
+    assert!(stdout.contains("en.yml:4:1"));
+    assert!(!stdout.contains("parse-error"));
 
     Ok(())
 }
 
 #[test]
-fn test_invalid_json_in_messages() -> Result<()> {
+fn test_plural_categories() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    test.write_file("messages/en.json", "{invalid}")?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Cart": {
+    "items": "{count, plural, one {# item} two {# items} other {# items}}",
+    "place": "{rank, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "messages/ru.json",
+        r#"{
+  "Cart": {
+    "items": "{count, plural, one {# товар} other {# товаров}}",
+    "place": "{rank, selectordinal, other {#-й}}"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "messages/ja.json",
+        r#"{
+  "Cart": {
+    "items": "{count, plural, other {# 件}}",
+    "place": "{rank, selectordinal, other {#位}}"
+  }
+}"#,
+    )?;
     test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // JSON parse error should be reported
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
+    assert_cmd_snapshot!(test.check_command().arg("plural-categories"));
 
     Ok(())
 }
 
 #[test]
-fn test_mixed_issues_across_rules() -> Result<()> {
+fn test_fallback_locales() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "fallbackLocales": ["en-US"]
         }"#,
     )?;
-
     test.write_file(
         "messages/en.json",
-        r#"{"Common": {"key1": "Value 1", "unused": "Unused"}}"#,
+        r#"{
+  "Common": {
+    "submit": "Submit"
+  }
+}"#,
     )?;
-
     test.write_file(
-        "messages/zh.json",
-        r#"{"Common": {"key1": "Value 1", "orphan": "孤儿"}}"#,
+        "messages/en-US.json",
+        r#"{
+  "Common": {
+    "submit": "Submit",
+    "color": "Color"
+  }
+}"#,
     )?;
-
     test.write_file(
-        "src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Component() {
-    return (
-        <div>
-            <span>Hardcoded Text</span>
-            <button>{t("key1")}</button>
-            <button>{t("missing")}</button>
-        </div>
-    );
-}
-"#,
+        "messages/de.json",
+        r#"{
+  "Common": {
+    "submit": "Absenden",
+    "color": "Color",
+    "removed": "Entfernt"
+  }
+}"#,
     )?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // Multiple issue types: hardcoded, missing, unused, orphan, untranslated
-    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(
+        test.check_command()
+            .args(["replica-lag", "orphan", "untranslated"])
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_multiple_locales_replica_lag_and_orphan() -> Result<()> {
+fn test_non_locale_message_files_are_skipped() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
+    test.write_file("messages/de.json", r#"{"Common": {}}"#)?;
+    test.write_file("messages/backup.json", r#"{"Common": {}}"#)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
 
-    // en (primary): a, b
-    test.write_file("messages/en.json", r#"{"Common": {"a": "A", "b": "B"}}"#)?;
-
-    // zh: missing "b" (replica lag)
-    test.write_file("messages/zh.json", r#"{"Common": {"a": "A"}}"#)?;
+    // backup.json is not a locale: only de lags behind
+    assert_cmd_snapshot!(
+        "non_locale_files_guessed",
+        test.check_command().arg("replica-lag")
+    );
 
-    // ja: has extra "c" (orphan)
+    // With an allowlist, de is not loaded and backup.json is skipped quietly
     test.write_file(
-        "messages/ja.json",
-        r#"{"Common": {"a": "A", "b": "B", "c": "C"}}"#,
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "locales": ["fr"]
+        }"#,
     )?;
-
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Both zh replica-lag and ja orphan should be reported
-    assert_cmd_snapshot!(test.check_command().arg("replica-lag").arg("orphan"));
+    test.write_file("messages/fr.json", r#"{"Common": {}}"#)?;
+    assert_cmd_snapshot!(
+        "non_locale_files_allowlist",
+        test.check_command().arg("replica-lag")
+    );
 
     Ok(())
 }
 
 #[test]
-fn test_deeply_nested_key_paths() -> Result<()> {
+fn test_markup_only_values() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
     test.write_file(
         "messages/en.json",
-        r#"{"Form": {"validation": {"errors": {"email": {}}}}}"#,
+        r#"{
+  "Home": {
+    "intro": "Read the <link>guide</link>",
+    "legal": "<b>{company}</b>",
+    "terms": "Accept the <link>terms</link>"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "messages/de.json",
+        r#"{
+  "Home": {
+    "intro": "<link></link>",
+    "legal": "<b>{company}</b>",
+    "terms": "<link> </link>"
+  }
+}"#,
     )?;
-
     test.write_file(
         "src/app.tsx",
-        r#"
-const t = useTranslations("Form");
-export function Component() {
-    return <div>{t("validation.errors.email.invalid")}</div>;
+        r#"import { useTranslations } from "next-intl";
+
+export function Home() {
+    const t = useTranslations("Home");
+    return (
+        <div>
+            {t.rich("intro", { link: (chunks) => <a>{chunks}</a> })}
+            {t.rich("legal", { b: (chunks) => <b>{chunks}</b> })}
+            {/* glot-disable-next-line markup-only */}
+            {t.rich("terms", { link: (chunks) => <a>{chunks}</a> })}
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Deep nested missing key should be reported
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
+    assert_cmd_snapshot!(test.check_command().arg("markup-only"));
 
     Ok(())
 }
 
 #[test]
-fn test_empty_namespace() -> Result<()> {
+fn test_rich_tag_mismatch() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    test.write_file("messages/en.json", r#"{"Empty": {}}"#)?;
-
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Home": {
+    "intro": "Read the <link>guide</link>",
+    "legal": "<b>{company}</b> <i>Inc.</i>",
+    "plain": "See <link>docs</link>"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "messages/fr.json",
+        r#"{
+  "Home": {
+    "intro": "Lisez le guide",
+    "legal": "<i>Inc.</i> <strong>{company}</strong>",
+    "plain": "Voir la documentation"
+  }
+}"#,
+    )?;
     test.write_file(
         "src/app.tsx",
-        r#"
-const t = useTranslations("Empty");
-export function Component() {
-    return <div>Test</div>;
+        r#"import { useTranslations } from "next-intl";
+
+export function Home() {
+    const t = useTranslations("Home");
+    return (
+        <div>
+            {t.rich("intro", { link: (chunks) => <a>{chunks}</a> })}
+            {t.markup("legal", { b: (chunks) => `<b>${chunks}</b>` })}
+            {t("plain")}
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Empty namespace should be handled gracefully
-    assert_cmd_snapshot!(test.check_command().arg("unused"));
+    assert_cmd_snapshot!(test.check_command().arg("rich-tag-mismatch"));
 
     Ok(())
 }
 
 #[test]
-fn test_special_characters_in_keys() -> Result<()> {
+fn test_custom_key_separator() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
-        }"#,
+              "includes": ["src"],
+              "messagesDir": "./messages",
+              "primaryLocale": "en",
+              "keySeparator": "/"
+          }"#,
     )?;
 
     test.write_file(
         "messages/en.json",
         r#"{
-            "Common": {
-                "key-with-dash_and_underscore": "Value",
-                "123numeric": "Numeric"
-            }
-        }"#,
+              "Common": {
+                  "submit": "Submit",
+                  "file.name": "File name",
+                  "status": {
+                      "active": "Active",
+                      "archived": "Archived"
+                  }
+              }
+          }"#,
     )?;
 
+    // `Common/missing` is the only missing key; every other key is used
     test.write_file(
         "src/app.tsx",
         r#"
-const t = useTranslations("Common");
-export function Component() {
-    return (
-        <div>
-            <span>{t("key-with-dash_and_underscore")}</span>
-            <span>{t("123numeric")}</span>
-            <span>{t("missing-special")}</span>
-        </div>
-    );
-}
-"#,
+  const t = useTranslations("Common");
+  export function Form({ status }: { status: string }) {
+      return (
+          <form>
+              <label>{t("file.name")}</label>
+              {/* glot-message-keys ".status/*" */}
+              <span>{t(`status/${status}`)}</span>
+              <button>{t("submit")}</button>
+              <button>{t("missing")}</button>
+          </form>
+      );
+  }
+  "#,
     )?;
 
-    // Special characters should be handled, missing key reported
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
-
-    Ok(())
-}
-
-// ============================================================
-// Group E: Help & CLI Validation Tests
-// ============================================================
-
-#[test]
-fn test_help_shows_all_eight_rules() -> Result<()> {
-    let test = CliTest::new()?;
-
-    // Help should list all 8 rule names
-    assert_cmd_snapshot!(test.check_command().arg("--help"));
+    assert_cmd_snapshot!(test.check_command());
 
     Ok(())
 }
 
 #[test]
-fn test_invalid_rule_name_error() -> Result<()> {
+fn test_unmatched_message_keys() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Status": {
+    "state": {
+      "active": "Active",
+      "inactive": "Inactive"
+    }
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"import { useTranslations } from "next-intl";
 
-    test.write_file("messages/en.json", r#"{}"#)?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Invalid rule name should show error
-    assert_cmd_snapshot!(test.check_command().arg("invalid-rule"));
-
-    Ok(())
+export function Status({ state }: { state: string }) {
+    const t = useTranslations("Status");
+    return (
+        <div>
+            {/* glot-message-keys ".state.*" */}
+            <p>{t(`state.${state}`)}</p>
+            {/* glot-message-keys ".satte.*" */}
+            <p>{t(`satte.${state}`)}</p>
+            {/* glot-disable-next-line unmatched-message-keys */}
+            {/* glot-message-keys "Status.legacy.*" */}
+            <p>{t(`legacy.${state}`)}</p>
+        </div>
+    );
 }
+"#,
+    )?;
 
-// ============================================================
-// Group F: Verbose & Exit Codes Tests
-// ============================================================
+    assert_cmd_snapshot!(test.check_command().arg("unmatched-message-keys"));
 
-#[test]
-fn test_verbose_shows_config_source() -> Result<()> {
-    let test = CliTest::new()?;
+    // Off by default
+    let output = test.check_command().output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("[unmatched-message-keys]"));
 
+    // Enabled by giving it a severity
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "severities": { "unmatched-message-keys": "error" }
         }"#,
     )?;
-
-    test.write_file("messages/en.json", r#"{}"#)?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    // Verbose should show config file path
-    assert_cmd_snapshot!(test.check_command().arg("--verbose"));
+    let output = test.check_command().output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("error: \"Status.satte.*\"  [unmatched-message-keys]"));
+    assert!(!stdout.contains("Status.legacy.*\"  [unmatched-message-keys]"));
+    assert_eq!(output.status.code(), Some(1));
 
     Ok(())
 }
 
 #[test]
-fn test_exit_code_on_errors() -> Result<()> {
+fn test_namespace_consistency() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "submit": "Submit",
+    "cancel": "Cancel",
+    "close": "Close"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/form.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Form() {
+    const t = useTranslations("Common");
+    return (
+        <div>
+            <button>{t("submit")}</button>
+            <button>{t("cancel")}</button>
+            <button>{t("close")}</button>
+        </div>
+    );
+}
+"#,
+    )?;
+    test.write_file(
+        "src/dialog.tsx",
+        r#"import { useTranslations } from "next-intl";
 
-    test.write_file("messages/en.json", r#"{}"#)?;
-    test.write_file("src/app.tsx", r#"<div>Hardcoded</div>"#)?;
+export function Dialog() {
+    const t = useTranslations();
+    return (
+        <div>
+            <button>{t("Common.submit")}</button>
+            {/* glot-disable-next-line namespace-consistency */}
+            <button>{t("Common.close")}</button>
+        </div>
+    );
+}
+"#,
+    )?;
 
-    // Errors should return exit code 1
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+    assert_cmd_snapshot!(test.check_command().arg("namespace-consistency"));
+
+    // Off by default
+    let output = test.check_command().output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("[namespace-consistency]"));
 
     Ok(())
 }
 
 #[test]
-fn test_exit_code_on_success() -> Result<()> {
+fn test_unpaired_directive() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-
+    test.write_file("messages/en.json", "{}")?;
     test.write_file(
-        "src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
+        "src/page.tsx",
+        r#"export function Page() {
+    return (
+        <div>
+            {/* glot-disable hardcoded */}
+            <p>Draft</p>
+            {/* glot-enable hardcoded */}
+            {/* glot-enable */}
+            {/* glot-disable */}
+            <p>Hidden</p>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Clean project should return exit code 0
-    assert_cmd_snapshot!(test.check_command());
-
-    Ok(())
-}
+    assert_cmd_snapshot!(test.check_command().arg("unpaired-directive"));
 
-#[test]
-fn test_error_on_warnings_exits_failure() -> Result<()> {
-    let test = CliTest::new()?;
+    // On by default, unless its severity is off
+    let output = test.check_command().output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[unpaired-directive]"));
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "severities": { "unpaired-directive": "off" }
         }"#,
     )?;
-
-    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
-
-    assert_cmd_snapshot!(
-        test.check_command()
-            .arg("--error-on-warnings")
-            .arg("unused")
-    );
+    let output = test.check_command().output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("[unpaired-directive]"));
 
     Ok(())
 }
 
 #[test]
-fn test_config_severity_override_untranslated_warning() -> Result<()> {
+fn test_projects_are_checked_independently() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en",
-            "severities": {
-                "untranslated": "warning"
-            }
+            "projects": [
+                { "root": "apps/web", "messagesDir": "./messages", "includes": ["src"] },
+                { "root": "apps/admin", "messagesDir": "./locales", "primaryLocale": "de", "includes": ["src"] }
+            ]
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
-        "src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
-}
-"#,
+        "apps/web/messages/en.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Cancel"}}"#,
+    )?;
+    test.write_file(
+        "apps/web/src/keys.ts",
+        r#"export const ACTIONS = { save: "submit", stop: "cancel" } as const;"#,
+    )?;
+    test.write_file(
+        "apps/web/src/app.tsx",
+        r#"import { ACTIONS } from "./keys";
+export function App({ action }: { action: keyof typeof ACTIONS }) {
+    const t = useTranslations("Common");
+    const label = ACTIONS[action];
+    return <button>{t(label)}</button>;
+}"#,
     )?;
 
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    // `Common.submit` is only defined in web, and web's key object must not
+    // resolve the dynamic key in admin
+    test.write_file(
+        "apps/admin/locales/de.json",
+        r#"{"Admin": {"title": "Titel"}}"#,
+    )?;
+    test.write_file(
+        "apps/admin/src/page.tsx",
+        r#"import { ACTIONS } from "../../web/src/keys";
+export function Page({ action }: { action: keyof typeof ACTIONS }) {
+    const t = useTranslations("Admin");
+    const c = useTranslations("Common");
+    const label = ACTIONS[action];
+    return <div><h1>{t("title")}</h1><p>{c("submit")}</p><p>{c(label)}</p></div>;
+}"#,
+    )?;
+
+    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(
+        "projects_grouped_by_rule",
+        test.check_command().args(["--group-by", "rule"])
+    );
+
+    // Each JSON Lines record names its project
+    let output = test.check_command().args(["--format", "jsonl"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines.len() > 1);
+    let (summary, issues) = lines.split_last().unwrap();
+    assert!(summary.starts_with(r#"{"type":"summary""#));
+    assert_eq!(issues.len(), 2);
+    assert!(
+        issues
+            .iter()
+            .all(|line| line.contains(r#""project":"apps/admin""#))
+    );
+
+    // With --source-root, only that project is checked
+    let output = test
+        .check_command()
+        .args(["--source-root", "apps/web"])
+        .output()?;
+    assert!(output.status.success());
 
     Ok(())
 }
 
 #[test]
-fn test_config_severity_override_untranslated_error_without_usage() -> Result<()> {
+fn test_react_intl_message_ids() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en",
-            "severities": {
-                "untranslated": "error"
-            }
+            "framework": "react-intl",
+            "primaryLocale": "en"
         }"#,
     )?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+    test.write_file(
+        "lang/en.json",
+        r#"{
+            "app.title": "My app",
+            "app.greeting": "Hello",
+            "app.unused": "Never shown"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+import { FormattedMessage, useIntl } from 'react-intl';
+
+export function App() {
+    const intl = useIntl();
+    const { formatMessage } = intl;
+    return (
+        <div title={intl.formatMessage({ id: "app.greeting" })}>
+            <FormattedMessage id="app.title" defaultMessage="My app" />
+            {formatMessage({ id: "app.missing", defaultMessage: "Missing" })}
+        </div>
+    );
+}
+"#,
+    )?;
 
-    assert_cmd_snapshot!(test.check_command().arg("untranslated"));
+    assert_cmd_snapshot!(test.check_command().arg("missing").arg("unused"));
 
     Ok(())
 }
 
 #[test]
-fn test_config_severity_override_unused_error() -> Result<()> {
-    let test = CliTest::new()?;
-
-    test.write_file(
-        ".glotrc.json",
-        r#"{
-            "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en",
-            "severities": {
-                "unused": "error"
-            }
-        }"#,
+fn test_color_flag_overrides_no_color() -> Result<()> {
+    let test = CliTest::with_file(
+        "src/components/app.tsx",
+        r#"
+export function App() {
+    return <div>Hello World</div>;
+}
+"#,
     )?;
+    test.write_file("messages/en.json", "{}")?;
 
-    test.write_file("messages/en.json", r#"{"Common": {"unused": "Unused"}}"#)?;
-    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+    let output = test.check_command().args(["--color", "always"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("\u{1b}["),
+        "--color always should color output despite NO_COLOR. stdout:\n{}",
+        stdout
+    );
 
-    assert_cmd_snapshot!(test.check_command().arg("unused"));
+    let output = test.check_command().args(["--color", "never"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("[hardcoded]"));
+    assert!(
+        !stdout.contains("\u{1b}["),
+        "--color never should not color output. stdout:\n{}",
+        stdout
+    );
 
     Ok(())
 }
 
-// ============================================================
-// Group G: Integration Scenarios Tests
-// ============================================================
-
 #[test]
-fn test_real_world_nextjs_structure() -> Result<()> {
+fn test_interpolation_mismatch() -> Result<()> {
     let test = CliTest::new()?;
 
-    // No explicit config - should use defaults
-
-    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
-    test.write_file("messages/zh.json", r#"{"Common": {"submit": "提交"}}"#)?;
-
     test.write_file(
-        "src/app/[locale]/page.tsx",
-        r#"
-const t = useTranslations("Common");
-export default function Page() {
-    return <button>{t("submit")}</button>;
-}
-"#,
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en"
+        }"#,
     )?;
-
     test.write_file(
-        "src/app/[locale]/layout.tsx",
-        r#"
-export default function Layout({ children }) {
-    return <html><body>{children}</body></html>;
+        "messages/en.json",
+        r#"{
+  "greeting": "Hello {name}",
+  "items": "{count, plural, one {# item} other {# items}}",
+  "title": "Welcome"
+}"#,
+    )?;
+    test.write_file(
+        "src/page.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Page({ name, count }: { name: string; count: number }) {
+    const t = useTranslations();
+    return (
+        <div>
+            <p>{t("greeting", { name })}</p>
+            <p>{t("items")}</p>
+            <p>{t("title", { name })}</p>
+            <p>{t("items", values)}</p>
+            {/* glot-disable-next-line interpolation-mismatch */}
+            <p>{t("greeting")}</p>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Defaults should work without explicit config
-    assert_cmd_snapshot!(test.check_command());
+    assert_cmd_snapshot!(test.check_command().arg("interpolation-mismatch"));
 
     Ok(())
 }
 
 #[test]
-fn test_monorepo_style_paths() -> Result<()> {
+fn test_empty_key() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "messagesDir": "./messages",
+            "includes": ["src"],
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
     test.write_file(
-        "packages/web/messages/en.json",
-        r#"{"Common": {"submit": "Submit"}}"#,
+        "messages/en.json",
+        r#"{
+  "Page": {
+    "new": "New",
+    "title": "Welcome"
+  }
+}"#,
     )?;
-
     test.write_file(
-        "packages/web/src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
+        "src/page.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Page({ isNew }: { isNew: boolean }) {
+    const t = useTranslations("Page");
+    return (
+        <div>
+            <h1>{t("title")}</h1>
+            <p>{t("")}</p>
+            <p>{t("  ")}</p>
+            <p>{t.raw("")}</p>
+            <p>{t(isNew ? "new" : "")}</p>
+            {/* glot-disable-next-line empty-key */}
+            <p>{t("")}</p>
+        </div>
+    );
 }
 "#,
     )?;
 
-    // Nested paths should work correctly
+    // Empty keys are not reported as missing keys, and the other branch of
+    // the conditional is still resolved
     assert_cmd_snapshot!(
         test.check_command()
-            .arg("--source-root")
-            .arg("packages/web/src")
-            .arg("--messages-root")
-            .arg("packages/web/messages")
+            .args(["empty-key", "missing", "unused"])
     );
 
     Ok(())
 }
 
 #[test]
-fn test_ignore_test_files() -> Result<()> {
+fn test_duplicate_value() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
-            "primaryLocale": "en"
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "duplicateValue": { "minKeys": 2 }
         }"#,
     )?;
-
-    test.write_file("messages/en.json", r#"{}"#)?;
-
     test.write_file(
-        "src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Button() {
-    return <button>{t("submit")}</button>;
-}
-"#,
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "save": "Save",
+    "cancel": "Cancel"
+  },
+  "Form": {
+    "save": "Save",
+    "title": "Edit profile"
+  },
+  "Dialog": {
+    "save": " Save ",
+    "cancel": "Cancel"
+  }
+}"#,
     )?;
-
     test.write_file(
-        "src/app.test.tsx",
-        r#"
-test("should render", () => {
-    render(<div>Hardcoded in test</div>);
-});
+        "src/page.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Page() {
+    const t = useTranslations();
+    return <p>{t("Form.title")}</p>;
+}
 "#,
     )?;
 
-    // Test files should be scanned by default (no default ignore)
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+    assert_cmd_snapshot!(test.check_command().arg("duplicate-value"));
+
+    // Off by default
+    let output = test.check_command().output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("[duplicate-value]"));
 
     Ok(())
 }
 
 #[test]
-fn test_large_message_file() -> Result<()> {
+fn test_stray_whitespace() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
             "includes": ["src"],
-            "messagesDir": "./messages",
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    // Generate 200+ keys
-    let mut keys = Vec::new();
-    for i in 0..250 {
-        keys.push(format!(r#""key{}": "Value {}""#, i, i));
-    }
-    let large_json = format!(r#"{{"Common": {{{}}}}}"#, keys.join(", "));
-    test.write_file("messages/en.json", &large_json)?;
-
     test.write_file(
-        "src/app.tsx",
-        r#"
-const t = useTranslations("Common");
-export function Component() {
-    return <div>{t("key0")}</div>;
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "submit": "Submit",
+    "cancel": "Cancel ",
+    "title": "Welcome"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "messages/fr.json",
+        r#"{
+  "Common": {
+    "submit": "Submit ",
+    "cancel": "Annuler",
+    "title": " Bienvenue"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/page.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Page() {
+    const t = useTranslations("Common");
+    return <button>{t("submit")}</button>;
 }
 "#,
     )?;
 
-    // Should complete successfully and report many unused keys
-    assert_cmd_snapshot!(test.check_command().arg("unused"));
+    assert_cmd_snapshot!(test.check_command().arg("stray-whitespace"));
 
     Ok(())
 }
 
-// ============================================================
-// Group F: react-i18next Tests
-// ============================================================
-
 #[test]
-fn test_react_i18next_hardcoded_text() -> Result<()> {
+fn test_translation_bindings_from_destructuring() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
-            "includes": ["src/components"],
-            "messagesRoot": "./src/locales",
+            "includes": ["src"],
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    test.write_file("src/locales/en.json", r#"{}"#)?;
-
     test.write_file(
-        "src/components/app.tsx",
-        r#"
-import { useTranslation } from 'react-i18next';
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "title": "Title"
+  }
+}"#,
+    )?;
+    test.write_file(
+        "src/page.tsx",
+        r#"import { useFormatter, useTranslations } from "next-intl";
 
-export function App() {
-    const { t } = useTranslation();
-    return (
-        <div>
-            <h1>Welcome to our app</h1>
-            <p>{t('description')}</p>
-        </div>
-    );
+export function Page() {
+    const [t, format] = [useTranslations("Common"), useFormatter()];
+    return <p>{t("title")} {t("subtitle")} {format.number(1)}</p>;
+}
+
+export function Broken() {
+    const { t } = useTranslations("Common");
+    return <p>{t("missing")}</p>;
 }
 "#,
     )?;
 
-    assert_cmd_snapshot!(test.check_command().arg("hardcoded"));
+    assert_cmd_snapshot!(test.check_command().args(["missing", "unresolved"]));
 
     Ok(())
 }
 
 #[test]
-fn test_react_i18next_translation_recognized() -> Result<()> {
+fn test_config_rules() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
-            "includes": ["src/components"],
-            "messagesRoot": "./src/locales",
-            "primaryLocale": "en"
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en",
+            "rules": ["hardcoded", "namespace-consistency"]
         }"#,
     )?;
-
-    test.write_file(
-        "src/locales/en.json",
-        r#"{ "title": "Welcome", "description": "Hello" }"#,
-    )?;
-
-    // All text uses t() — should be clean
+    test.write_file("messages/en.json", r#"{"Common": {"submit": "Submit"}}"#)?;
     test.write_file(
-        "src/components/app.tsx",
-        r#"
-import { useTranslation } from 'react-i18next';
+        "src/page.tsx",
+        r#"import { useTranslations } from "next-intl";
 
-export function App() {
-    const { t } = useTranslation();
+export function Page() {
+    const t = useTranslations("Common");
+    const tAll = useTranslations();
     return (
         <div>
-            <h1>{t('title')}</h1>
-            <p>{t('description')}</p>
+            <p>Hello</p>
+            <p>{t("submit")}</p>
+            <p>{tAll("Common.submit")}</p>
+            <p>{t("cancel")}</p>
         </div>
     );
 }
 "#,
     )?;
 
+    // Only the configured rules run, including opt-in ones
     assert_cmd_snapshot!(test.check_command());
 
+    // Rules named on the command line still override the config
+    assert_cmd_snapshot!(
+        "config_rules_cli_override",
+        test.check_command().arg("missing")
+    );
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "rules": ["hardcode"]
+        }"#,
+    )?;
+    assert_cmd_snapshot!("config_rules_unknown", test.check_command());
+
     Ok(())
 }
 
 #[test]
-fn test_react_i18next_missing_key() -> Result<()> {
+fn test_rules_from_file() -> Result<()> {
     let test = CliTest::new()?;
 
     test.write_file(
         ".glotrc.json",
         r#"{
-            "framework": "react-i18next",
-            "includes": ["src/components"],
-            "messagesRoot": "./src/locales",
+            "includes": ["src"],
+            "messagesRoot": "./messages",
             "primaryLocale": "en"
         }"#,
     )?;
-
-    // Only "title" exists, "description" is missing
-    test.write_file("src/locales/en.json", r#"{ "title": "Welcome" }"#)?;
-
     test.write_file(
-        "src/components/app.tsx",
-        r#"
-import { useTranslation } from 'react-i18next';
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "save": "Save", "ok": "Save", "apply": "Save"}}"#,
+    )?;
+    test.write_file(
+        "src/page.tsx",
+        r#"import { useTranslations } from "next-intl";
 
-export function App() {
-    const { t } = useTranslation();
+export function Page() {
+    const t = useTranslations("Common");
     return (
         <div>
-            <h1>{t('title')}</h1>
-            <p>{t('description')}</p>
+            <p>Hello</p>
+            <p>{t("submit")}</p>
+            <p>{t("cancel")}</p>
         </div>
     );
 }
 "#,
     )?;
+    test.write_file(
+        "rules.txt",
+        "# CI rules\nhardcoded\n\nmissing-key\nduplicate-value\n",
+    )?;
+    test.write_file("rules.json", r#"["missing"]"#)?;
+    test.write_file("unknown.txt", "missing\nhardcode\n")?;
 
-    assert_cmd_snapshot!(test.check_command().arg("missing"));
+    // Names one per line, composed with --exclude-rule
+    assert_cmd_snapshot!(test.check_command().args([
+        "--rules-from-file",
+        "rules.txt",
+        "--exclude-rule",
+        "hardcoded"
+    ]));
+
+    assert_cmd_snapshot!(
+        "rules_from_file_json",
+        test.check_command()
+            .args(["--rules-from-file", "rules.json"])
+    );
+
+    assert_cmd_snapshot!(
+        "rules_from_file_unknown",
+        test.check_command()
+            .args(["--rules-from-file", "unknown.txt"])
+    );
 
     Ok(())
 }
@@ -108,6 +108,50 @@ export function App() {
     Ok(())
 }
 
+#[test]
+fn test_clean_explicit_dry_run_matches_apply() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function App() {
+    return <div>{t("used")}</div>;
+}
+"#,
+    )?;
+    let messages = r#"{
+  "Common": {
+    "used": "Used Key",
+    "unused": "Unused Key",
+    "legacy": "Legacy"
+  }
+}"#;
+    test.write_file("messages/en.json", messages)?;
+
+    let output = test.clean_command().arg("--dry-run").output()?;
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("Would delete 2 unused key(s) and 0 orphan key(s) from 1 file(s)."));
+    assert!(stdout.contains("  - ./messages/en.json: 2 key(s)"));
+    assert_eq!(test.read_file("messages/en.json")?, messages);
+
+    let output = test.clean_command().arg("--apply").output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("Deleted 2 key(s) in 1 file(s)"));
+
+    // `--dry-run` and `--apply` are mutually exclusive
+    let output = test
+        .clean_command()
+        .args(["--dry-run", "--apply"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+
+    Ok(())
+}
+
 #[test]
 fn test_clean_no_keys_to_clean() -> Result<()> {
     let test = CliTest::new()?;
@@ -238,6 +282,91 @@ export function App() {
     Ok(())
 }
 
+#[test]
+fn test_clean_apply_custom_key_separator() -> Result<()> {
+    let test = CliTest::new()?;
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "keySeparator": "/"
+        }"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Release");
+export function App() {
+    return <div>{t("v2.0/title")}</div>;
+}
+"#,
+    )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Release": {
+    "v1.0": {
+      "title": "Old"
+    },
+    "v2.0": {
+      "title": "New"
+    }
+  }
+}"#,
+    )?;
+
+    let output = test.clean_command().args(["--apply", "--quiet"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Deleted 1 key(s)"), "stdout:\n{}", stdout);
+
+    let expected = r#"{
+  "Release": {
+    "v2.0": {
+      "title": "New"
+    }
+  }
+}
+"#;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_clean_apply_quiet_skips_diff() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export function App() {
+    return <div>{t("used")}</div>;
+}
+"#,
+    )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Common": {
+    "used": "Used Key",
+    "unused": "Unused Key"
+  }
+}"#,
+    )?;
+
+    let output = test.clean_command().args(["--apply", "--quiet"]).output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(!stdout.contains("--- a/"), "stdout:\n{}", stdout);
+    assert!(stdout.contains("Deleted 1 key(s)"), "stdout:\n{}", stdout);
+
+    let content = test.read_file("messages/en.json")?;
+    assert_json_structure(&content, &["Common.used"], &["Common.unused"])?;
+    Ok(())
+}
+
 #[test]
 fn test_clean_orphan_keys() -> Result<()> {
     let test = CliTest::new()?;
@@ -927,3 +1056,56 @@ export function App() {
     assert_cmd_snapshot!(test.clean_command());
     Ok(())
 }
+
+#[test]
+fn test_clean_apply_with_message_pattern() -> Result<()> {
+    let test = CliTest::new()?;
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagePattern": "./locales/{locale}/*.json",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("common");
+export function App() {
+    return <div>{t("used")}</div>;
+}
+"#,
+    )?;
+    test.write_file(
+        "locales/en/common.json",
+        r#"{
+  "used": "Used Key",
+  "unused": "Unused Key"
+}"#,
+    )?;
+
+    let mut cmd = test.clean_command();
+    cmd.arg("--apply");
+    assert_cmd_snapshot!(cmd);
+
+    // Keys are removed as written in the file, without the namespace prefix
+    let content = test.read_file("locales/en/common.json")?;
+    assert_json_structure(&content, &["used"], &["unused"])?;
+    Ok(())
+}
+
+#[test]
+fn test_clean_refuses_to_edit_yaml_files() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    let messages = "Common:\n  unused: Unused\n";
+    test.write_file("messages/en.yml", messages)?;
+
+    assert_cmd_snapshot!(test.clean_command().arg("--apply"));
+    assert_eq!(test.read_file("messages/en.yml")?, messages);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use insta_cmd::assert_cmd_snapshot;
+
+use crate::CliTest;
+
+#[test]
+fn test_completions_offer_commands_and_rules() -> Result<()> {
+    let test = CliTest::new()?;
+
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let output = test.command().args(["completions", shell]).output()?;
+        assert!(output.status.success(), "{}", shell);
+        let script = String::from_utf8(output.stdout)?;
+        // PowerShell scripts complete commands and flags, not values
+        let words: &[&str] = if shell == "powershell" {
+            &["check", "explain", "exclude-rule"]
+        } else {
+            &[
+                "check",
+                "explain",
+                "exclude-rule",
+                "replica-lag",
+                "parse-error",
+            ]
+        };
+        for word in words {
+            assert!(script.contains(word), "{} script lacks {}", shell, word);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_completions_unknown_shell() -> Result<()> {
+    let test = CliTest::new()?;
+
+    assert_cmd_snapshot!(test.command().args(["completions", "tcsh"]));
+
+    Ok(())
+}
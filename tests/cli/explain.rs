@@ -0,0 +1,35 @@
+use anyhow::Result;
+use insta_cmd::assert_cmd_snapshot;
+
+use crate::CliTest;
+
+#[test]
+fn test_explain_rule() -> Result<()> {
+    let test = CliTest::new()?;
+
+    assert_cmd_snapshot!(test.explain_command().arg("replica-lag"));
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_accepts_check_names() -> Result<()> {
+    let test = CliTest::new()?;
+
+    // `check` names and report names refer to the same rule
+    let by_check_name = test.explain_command().arg("unused").output()?;
+    let by_report_name = test.explain_command().arg("unused-key").output()?;
+    assert!(by_check_name.status.success());
+    assert_eq!(by_check_name.stdout, by_report_name.stdout);
+
+    Ok(())
+}
+
+#[test]
+fn test_explain_unknown_rule() -> Result<()> {
+    let test = CliTest::new()?;
+
+    assert_cmd_snapshot!(test.explain_command().arg("replica"));
+
+    Ok(())
+}
@@ -609,3 +609,378 @@ export function Button({ t, prefix }: Props) {
     );
     Ok(())
 }
+
+const CREATE_MISSING_SOURCE: &str = r#"import { useTranslations } from "next-intl";
+
+export function Login({ field }: { field: string }) {
+    const t = useTranslations("Auth");
+    return (
+        <form>
+            <h1>{t("login.title")}</h1>
+            <button>{t("submit")}</button>
+            <span>{t(field)}</span>
+        </form>
+    );
+}
+"#;
+
+#[test]
+fn test_fix_create_missing_dry_run() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", CREATE_MISSING_SOURCE)?;
+    test.write_file("messages/en.json", r#"{"Auth": {"submit": "Sign in"}}"#)?;
+
+    assert_cmd_snapshot!(test.fix_command().arg("--create-missing"));
+    assert_eq!(
+        test.read_file("messages/en.json")?,
+        r#"{"Auth": {"submit": "Sign in"}}"#
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fix_create_missing_apply() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", CREATE_MISSING_SOURCE)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"ok": "OK"}, "Auth": {"submit": "Sign in"}}"#,
+    )?;
+
+    assert_cmd_snapshot!(test.fix_command().args(["--create-missing", "--apply"]));
+
+    let expected = r#"{
+  "Common": {
+    "ok": "OK"
+  },
+  "Auth": {
+    "submit": "Sign in",
+    "login": {
+      "title": "title"
+    }
+  }
+}
+"#;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+
+    // A second run finds nothing left to create.
+    test.fix_command()
+        .args(["--create-missing", "--apply"])
+        .output()?;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_fix_create_missing_apply_custom_key_separator() -> Result<()> {
+    let test = CliTest::new()?;
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesDir": "./messages",
+            "primaryLocale": "en",
+            "keySeparator": "/"
+        }"#,
+    )?;
+    test.write_file(
+        "src/app.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Release() {
+    const t = useTranslations("Release");
+    return <h1>{t("v1.0/title")}</h1>;
+}
+"#,
+    )?;
+    test.write_file("messages/en.json", r#"{"Release": {}}"#)?;
+
+    let output = test
+        .fix_command()
+        .args(["--create-missing", "--apply", "--quiet"])
+        .output()?;
+    assert!(output.status.success());
+
+    let expected = r#"{
+  "Release": {
+    "v1.0": {
+      "title": "title"
+    }
+  }
+}
+"#;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_fix_fill_backfills_other_locales() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", CREATE_MISSING_SOURCE)?;
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"ok": "OK"}, "Auth": {"submit": "Sign in"}}"#,
+    )?;
+    test.write_file("messages/de.json", r#"{"Auth": {"submit": "Anmelden"}}"#)?;
+
+    assert_cmd_snapshot!(
+        test.fix_command()
+            .args(["--create-missing", "--fill", "todo"])
+    );
+
+    test.fix_command()
+        .args(["--create-missing", "--fill", "source", "--apply"])
+        .output()?;
+    let expected = r#"{
+  "Auth": {
+    "submit": "Anmelden",
+    "login": {
+      "title": "title"
+    }
+  },
+  "Common": {
+    "ok": "OK"
+  }
+}
+"#;
+    assert_eq!(test.read_file("messages/de.json")?, expected);
+
+    // Every (locale, key) pair is filled, so a second run changes nothing.
+    let output = test
+        .fix_command()
+        .args(["--create-missing", "--fill", "source", "--apply"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(test.read_file("messages/de.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_fix_fill_keeps_primary_placeholder_and_order() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file(
+        "src/app.tsx",
+        r#"import { useTranslations } from "next-intl";
+
+export function Page() {
+    const t = useTranslations("Common");
+    return <p>{t("z")} {t("a")} {t("newKey")}</p>;
+}
+"#,
+    )?;
+    test.write_file(
+        "messages/en.json",
+        "{\n  \"Common\": {\n    \"z\": \"Z\",\n    \"a\": \"A\"\n  }\n}\n",
+    )?;
+    test.write_file("messages/de.json", r#"{"Common": {}}"#)?;
+
+    test.fix_command()
+        .args(["--create-missing", "--fill", "empty", "--apply"])
+        .output()?;
+
+    // The policy only applies to the other locales
+    assert_eq!(
+        test.read_file("messages/en.json")?,
+        r#"{
+  "Common": {
+    "z": "Z",
+    "a": "A",
+    "newKey": "newKey"
+  }
+}
+"#
+    );
+    assert_eq!(
+        test.read_file("messages/de.json")?,
+        r#"{
+  "Common": {
+    "z": "",
+    "a": "",
+    "newKey": ""
+  }
+}
+"#
+    );
+    Ok(())
+}
+
+const UNSORTED_MESSAGES: &str = r#"{
+    "Common": {
+        "submit": "Submit",
+        "cancel": "Cancel"
+    },
+    "Auth": {
+        "steps": [{ "title": "One" }, "b", "a"]
+    }
+}
+"#;
+
+#[test]
+fn test_fix_sort_keys_dry_run() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    test.write_file("messages/en.json", UNSORTED_MESSAGES)?;
+    test.write_file("messages/de.json", r#"{"Auth": {}, "Common": {}}"#)?;
+
+    assert_cmd_snapshot!(test.fix_command().arg("--sort-keys"));
+    assert_eq!(test.read_file("messages/en.json")?, UNSORTED_MESSAGES);
+    Ok(())
+}
+
+#[test]
+fn test_fix_sort_keys_apply() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    test.write_file("messages/en.json", UNSORTED_MESSAGES)?;
+
+    assert_cmd_snapshot!(test.fix_command().args(["--sort-keys", "--apply"]));
+
+    // Keys are sorted with the file's indentation; values are moved as written
+    let expected = r#"{
+    "Auth": {
+        "steps": [{ "title": "One" }, "b", "a"]
+    },
+    "Common": {
+        "cancel": "Cancel",
+        "submit": "Submit"
+    }
+}
+"#;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_fix_sort_keys_apply_keeps_value_formatting() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    test.write_file(
+        "messages/en.json",
+        r#"{
+  "Menu": {
+    "tags": ["x", "y"],
+    "cafe": "caf\u00e9"
+  },
+  "Common": {
+    "count": 1.50
+  }
+}
+"#,
+    )?;
+
+    test.fix_command()
+        .args(["--sort-keys", "--apply", "--quiet"])
+        .output()?;
+
+    let expected = r#"{
+  "Common": {
+    "count": 1.50
+  },
+  "Menu": {
+    "cafe": "caf\u00e9",
+    "tags": ["x", "y"]
+  }
+}
+"#;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_fix_sort_keys_check() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    // The unresolved key is not reported by `--check`
+    test.write_file(
+        "src/app.tsx",
+        r#"
+const t = useTranslations("Common");
+export const App = ({ key }: { key: string }) => <p>{t(key)}</p>;
+"#,
+    )?;
+    test.write_file("messages/en.json", UNSORTED_MESSAGES)?;
+
+    assert_cmd_snapshot!(test.fix_command().args(["--sort-keys", "--check"]));
+    assert_eq!(test.read_file("messages/en.json")?, UNSORTED_MESSAGES);
+
+    test.fix_command()
+        .args(["--sort-keys", "--apply"])
+        .output()?;
+    assert_cmd_snapshot!(
+        "fix_sort_keys_check_sorted",
+        test.fix_command().args(["--sort-keys", "--check"])
+    );
+    Ok(())
+}
+
+const ORPHAN_PRIMARY: &str = r#"{"Common": {"submit": "Submit"}, "Nav": {"home": "Home"}}"#;
+const ORPHAN_SECONDARY: &str = r#"{
+    "Common": {"submit": "Senden", "cancel": "Abbrechen"},
+    "Nav": "Navigation",
+    "Legacy": {"title": "Alt"}
+}"#;
+
+#[test]
+fn test_fix_orphan_dry_run() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    test.write_file("messages/en.json", ORPHAN_PRIMARY)?;
+    test.write_file("messages/de.json", ORPHAN_SECONDARY)?;
+
+    assert_cmd_snapshot!(test.fix_command().arg("orphan"));
+    assert_eq!(test.read_file("messages/en.json")?, ORPHAN_PRIMARY);
+    Ok(())
+}
+
+#[test]
+fn test_fix_orphan_apply() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    test.write_file("messages/en.json", ORPHAN_PRIMARY)?;
+    test.write_file("messages/de.json", ORPHAN_SECONDARY)?;
+
+    assert_cmd_snapshot!(test.fix_command().args(["orphan", "--apply"]));
+
+    let expected = r#"{
+  "Common": {
+    "submit": "Submit",
+    "cancel": "Abbrechen"
+  },
+  "Nav": {
+    "home": "Home"
+  },
+  "Legacy": {
+    "title": "Alt"
+  }
+}
+"#;
+    assert_eq!(test.read_file("messages/en.json")?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_fix_orphan_empty_values() -> Result<()> {
+    let test = CliTest::new()?;
+    setup_config(&test)?;
+    test.write_file("src/app.tsx", "export const App = () => null;")?;
+    test.write_file("messages/en.json", ORPHAN_PRIMARY)?;
+    test.write_file("messages/de.json", ORPHAN_SECONDARY)?;
+
+    test.fix_command()
+        .args(["orphan", "--empty", "--apply"])
+        .output()?;
+
+    let en = test.read_file("messages/en.json")?;
+    assert!(en.contains(r#""cancel": """#));
+    assert!(en.contains(r#""title": """#));
+    Ok(())
+}
@@ -134,3 +134,41 @@ fn test_init_defaults_to_react_i18next_without_package_json() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_init_library_overrides_detection() -> Result<()> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        "package.json",
+        r#"{ "dependencies": { "react-i18next": "^13.0.0" } }"#,
+    )?;
+
+    assert_cmd_snapshot!(test.command().args(["init", "--library", "next-intl"]));
+
+    let content = test.read_file(".glotrc.json")?;
+    let parsed: Value = serde_json::from_str(&content)?;
+    assert_eq!(parsed["framework"], "next-intl");
+    assert_eq!(parsed["messagesRoot"], "./messages");
+
+    Ok(())
+}
+
+#[test]
+fn test_init_force_overwrites() -> Result<()> {
+    let test = CliTest::new()?;
+    test.write_file(".glotrc.json", r#"{ "primaryLocale": "de" }"#)?;
+
+    assert_cmd_snapshot!(
+        test.command()
+            .args(["init", "--force", "--library", "react-i18next"])
+    );
+
+    let content = test.read_file(".glotrc.json")?;
+    assert_config_content(&content)?;
+    let parsed: Value = serde_json::from_str(&content)?;
+    assert_eq!(parsed["primaryLocale"], "en");
+    assert_eq!(parsed["messagesRoot"], "./src/locales");
+
+    Ok(())
+}
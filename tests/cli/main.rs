@@ -11,8 +11,12 @@ use tempfile::TempDir;
 mod baseline;
 mod check;
 mod clean;
+mod completions;
+mod explain;
 mod fix;
 mod init;
+mod migrate;
+mod stats;
 
 const BIN_NAME: &str = "glot";
 
@@ -97,6 +101,24 @@ impl CliTest {
         cmd
     }
 
+    pub fn stats_command(&self) -> Command {
+        let mut cmd = self.command();
+        cmd.arg("stats");
+        cmd
+    }
+
+    pub fn migrate_command(&self) -> Command {
+        let mut cmd = self.command();
+        cmd.arg("migrate");
+        cmd
+    }
+
+    pub fn explain_command(&self) -> Command {
+        let mut cmd = self.command();
+        cmd.arg("explain");
+        cmd
+    }
+
     pub fn read_file(&self, path: &str) -> Result<String> {
         let file_path = self.project_dir.join(path);
         fs::read_to_string(&file_path)
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use insta_cmd::assert_cmd_snapshot;
+
+use crate::CliTest;
+
+fn setup(en: &str, de: &str) -> Result<CliTest> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file("messages/en.json", en)?;
+    test.write_file("messages/de.json", de)?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    Ok(test)
+}
+
+#[test]
+fn test_migrate_to_nested() -> Result<()> {
+    let test = setup(
+        "{\n  \"Common.submit\": \"Submit\",\n  \"Common.cancel\": \"Cancel\",\n  \"Home.title\": \"Home\"\n}\n",
+        "{\n  \"Common\": {\n    \"submit\": \"Absenden\"\n  }\n}\n",
+    )?;
+
+    assert_cmd_snapshot!(test.migrate_command().args(["--to", "nested"]));
+    assert_eq!(
+        test.read_file("messages/en.json")?,
+        "{\n  \"Common\": {\n    \"submit\": \"Submit\",\n    \"cancel\": \"Cancel\"\n  },\n  \"Home\": {\n    \"title\": \"Home\"\n  }\n}\n"
+    );
+    // Already nested, so untouched
+    assert_eq!(
+        test.read_file("messages/de.json")?,
+        "{\n  \"Common\": {\n    \"submit\": \"Absenden\"\n  }\n}\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_check() -> Result<()> {
+    let en = "{\n  \"Common\": {\n    \"submit\": \"Submit\"\n  }\n}\n";
+    let test = setup(en, "{\n  \"Common.submit\": \"Absenden\"\n}\n")?;
+
+    assert_cmd_snapshot!(test.migrate_command().args(["--to", "flat", "--check"]));
+    assert_eq!(test.read_file("messages/en.json")?, en);
+
+    test.migrate_command().args(["--to", "flat"]).output()?;
+    assert_eq!(
+        test.read_file("messages/en.json")?,
+        "{\n  \"Common.submit\": \"Submit\"\n}\n"
+    );
+    assert_cmd_snapshot!(
+        "migrate_check_up_to_date",
+        test.migrate_command().args(["--to", "flat", "--check"])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_collision_leaves_files_untouched() -> Result<()> {
+    let en = "{\n  \"Common.submit\": \"Submit\"\n}\n";
+    let de = "{\n  \"Common.submit\": \"Absenden\",\n  \"Common.submit.label\": \"Los\"\n}\n";
+    let test = setup(en, de)?;
+
+    assert_cmd_snapshot!(test.migrate_command().args(["--to", "nested"]));
+    assert_eq!(test.read_file("messages/en.json")?, en);
+    assert_eq!(test.read_file("messages/de.json")?, de);
+
+    Ok(())
+}
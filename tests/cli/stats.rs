@@ -0,0 +1,59 @@
+use anyhow::Result;
+use insta_cmd::assert_cmd_snapshot;
+
+use crate::CliTest;
+
+fn setup() -> Result<CliTest> {
+    let test = CliTest::new()?;
+
+    test.write_file(
+        ".glotrc.json",
+        r#"{
+            "includes": ["src"],
+            "messagesRoot": "./messages",
+            "primaryLocale": "en"
+        }"#,
+    )?;
+    test.write_file(
+        "messages/en.json",
+        r#"{"Common": {"submit": "Submit", "cancel": "Cancel", "title": "Welcome", "ok": "OK"}}"#,
+    )?;
+    test.write_file(
+        "messages/de.json",
+        r#"{"Common": {"submit": "Absenden", "cancel": "Abbrechen", "title": "Willkommen", "ok": "OK"}}"#,
+    )?;
+    test.write_file(
+        "messages/zh.json",
+        r#"{"Common": {"submit": "提交", "cancel": "Cancel", "legacy": "旧"}}"#,
+    )?;
+    test.write_file("src/app.tsx", r#"const x = 1;"#)?;
+
+    Ok(test)
+}
+
+#[test]
+fn test_stats_table() -> Result<()> {
+    let test = setup()?;
+
+    assert_cmd_snapshot!(test.stats_command());
+
+    Ok(())
+}
+
+#[test]
+fn test_stats_json() -> Result<()> {
+    let test = setup()?;
+
+    assert_cmd_snapshot!(test.stats_command().arg("--format").arg("json"));
+
+    Ok(())
+}
+
+#[test]
+fn test_stats_missing_primary_locale() -> Result<()> {
+    let test = setup()?;
+
+    assert_cmd_snapshot!(test.stats_command().arg("--primary-locale").arg("fr"));
+
+    Ok(())
+}
@@ -78,6 +78,29 @@ impl McpTestFixture {
         Ok(())
     }
 
+    /// Write a namespace JSON file to messages/<locale>/<namespace>.json
+    ///
+    /// Pair with a `"messagePattern": "./messages/{locale}/*.json"` config.
+    pub fn write_namespaced_locale_file(
+        &self,
+        locale: &str,
+        namespace: &str,
+        content: &Value,
+    ) -> Result<()> {
+        let dir = self.project_root.join("messages").join(locale);
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.json", namespace));
+        let json_str = serde_json::to_string_pretty(content).with_context(|| {
+            format!(
+                "Failed to serialize JSON for namespace: {}/{}",
+                locale, namespace
+            )
+        })?;
+        fs::write(&path, format!("{}\n", json_str))
+            .with_context(|| format!("Failed to write locale file: {}", path.display()))?;
+        Ok(())
+    }
+
     /// Read a locale JSON file from messages/<locale>.json
     pub fn read_locale_file(&self, locale: &str) -> Result<Value> {
         let path = self
@@ -172,3 +172,58 @@ async fn test_get_locales_empty_messages_dir() {
     let locales = json_result["locales"].as_array().unwrap();
     assert_eq!(locales.len(), 0);
 }
+
+#[tokio::test]
+async fn test_get_locales_message_pattern() {
+    let fixture = McpTestFixture::new().unwrap();
+    fixture
+        .write_config(&json!({
+            "framework": "next-intl",
+            "messagePattern": "./messages/{locale}/*.json"
+        }))
+        .unwrap();
+    fixture
+        .write_namespaced_locale_file("en", "common", &json!({"submit": "Submit"}))
+        .unwrap();
+    fixture
+        .write_namespaced_locale_file(
+            "en",
+            "auth",
+            &json!({"login": "Log in", "logout": "Log out"}),
+        )
+        .unwrap();
+    fixture
+        .write_namespaced_locale_file("zh", "common", &json!({"submit": "提交"}))
+        .unwrap();
+
+    let server = GlotMcpServer::new();
+
+    let params = Parameters(GetLocalesParams {
+        project_root_path: fixture.root(),
+    });
+
+    let result = server.get_locales(params).await.unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert!(
+        json_result["messagePattern"]
+            .as_str()
+            .unwrap()
+            .ends_with("{locale}/*.json")
+    );
+
+    let locales = json_result["locales"].as_array().unwrap();
+    assert_eq!(locales.len(), 2);
+
+    let en = locales.iter().find(|l| l["locale"] == "en").unwrap();
+    assert_eq!(en["keyCount"], 3);
+    assert!(
+        en["namespaceFiles"]["auth"]
+            .as_str()
+            .unwrap()
+            .ends_with("auth.json")
+    );
+
+    let zh = locales.iter().find(|l| l["locale"] == "zh").unwrap();
+    assert_eq!(zh["keyCount"], 1);
+}
@@ -1,9 +1,10 @@
 use glot::mcp::{
     GlotMcpServer,
-    types::{AddTranslationsParams, TranslationEntry},
+    types::{AddTranslationsParams, ApplyFixParams, TranslationEntry},
 };
 use rmcp::handler::server::wrapper::Parameters;
 use serde_json::{Map, json};
+use std::fs;
 
 use crate::{McpTestFixture, extract_tool_result_json};
 
@@ -421,3 +422,116 @@ async fn test_add_translations_summary_statistics() {
     assert_eq!(json_result["summary"]["totalKeysAdded"], 3);
     assert_eq!(json_result["summary"]["totalKeysUpdated"], 1);
 }
+
+// ============================================================================
+// apply_fix tests
+// ============================================================================
+
+const DYNAMIC_KEY_PAGE: &str = r#"import { useTranslations } from "next-intl";
+
+export function Status({ code }: { code: string }) {
+    const t = useTranslations("Common");
+    return (
+        <div>
+            <p>{t(`status.${code}`)}</p>
+        </div>
+    );
+}
+"#;
+
+fn apply_fix_params(
+    fixture: &McpTestFixture,
+    line: u64,
+    keys: &[&str],
+) -> Parameters<ApplyFixParams> {
+    Parameters(ApplyFixParams {
+        project_root_path: fixture.root(),
+        file_path: "components/status.tsx".to_string(),
+        line,
+        keys: keys.iter().map(|k| k.to_string()).collect(),
+    })
+}
+
+#[tokio::test]
+async fn test_apply_fix_inserts_jsx_annotation() {
+    let fixture = McpTestFixture::with_messages(vec![(
+        "en",
+        json!({"Common": {"status": {"active": "Active"}}}),
+    )])
+    .unwrap();
+    fixture
+        .write_tsx_file("components/status.tsx", DYNAMIC_KEY_PAGE)
+        .unwrap();
+    let server = GlotMcpServer::new();
+
+    let result = server
+        .apply_fix(apply_fix_params(&fixture, 7, &["Common.status.*"]))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert_eq!(json_result["success"], true);
+    assert_eq!(
+        json_result["comment"],
+        "{/* glot-message-keys \"Common.status.*\" */}"
+    );
+    assert!(
+        json_result["snippet"]
+            .as_str()
+            .unwrap()
+            .contains("            {/* glot-message-keys \"Common.status.*\" */}\n            <p>")
+    );
+
+    let content = fs::read_to_string(fixture.root_path().join("components/status.tsx")).unwrap();
+    assert!(content.contains("{/* glot-message-keys \"Common.status.*\" */}\n            <p>{t("));
+}
+
+#[tokio::test]
+async fn test_apply_fix_refuses_existing_annotation() {
+    let fixture = McpTestFixture::with_messages(vec![("en", json!({}))]).unwrap();
+    fixture
+        .write_tsx_file("components/status.tsx", DYNAMIC_KEY_PAGE)
+        .unwrap();
+    let server = GlotMcpServer::new();
+
+    server
+        .apply_fix(apply_fix_params(&fixture, 7, &["Common.status.*"]))
+        .await
+        .unwrap();
+    let before = fs::read_to_string(fixture.root_path().join("components/status.tsx")).unwrap();
+
+    // The key is still unresolved (no matching keys), now one line lower
+    let result = server
+        .apply_fix(apply_fix_params(&fixture, 8, &["Common.other"]))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert_eq!(json_result["success"], false);
+    assert!(
+        json_result["error"]
+            .as_str()
+            .unwrap()
+            .contains("already annotated")
+    );
+    let after = fs::read_to_string(fixture.root_path().join("components/status.tsx")).unwrap();
+    assert_eq!(before, after);
+}
+
+#[tokio::test]
+async fn test_apply_fix_no_unresolved_key_at_line() {
+    let fixture = McpTestFixture::with_messages(vec![("en", json!({}))]).unwrap();
+    fixture
+        .write_tsx_file("components/status.tsx", DYNAMIC_KEY_PAGE)
+        .unwrap();
+    let server = GlotMcpServer::new();
+
+    let result = server
+        .apply_fix(apply_fix_params(&fixture, 3, &["Common.status.*"]))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert_eq!(json_result["success"], false);
+    assert_eq!(json_result["error"], "No unresolved key found at line 3");
+}
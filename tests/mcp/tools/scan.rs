@@ -1,6 +1,6 @@
 use glot::mcp::{
     GlotMcpServer,
-    types::{ScanTypeMismatchParams, ScanUntranslatedParams},
+    types::{GlotCheckParams, ScanTypeMismatchParams, ScanUntranslatedParams},
 };
 use rmcp::handler::server::wrapper::Parameters;
 use serde_json::json;
@@ -434,6 +434,38 @@ async fn test_scan_untranslated_empty_string() {
     assert_eq!(empty_in[0], "zh");
 }
 
+#[tokio::test]
+async fn test_scan_untranslated_namespaced_files() {
+    let fixture = McpTestFixture::new().unwrap();
+    fixture
+        .write_config(&json!({
+            "framework": "next-intl",
+            "messagePattern": "./messages/{locale}/*.json"
+        }))
+        .unwrap();
+    fixture
+        .write_namespaced_locale_file("en", "common", &json!({"submit": "Submit"}))
+        .unwrap();
+    fixture
+        .write_namespaced_locale_file("zh", "common", &json!({"submit": ""}))
+        .unwrap();
+    let server = GlotMcpServer::new();
+
+    let params = Parameters(ScanUntranslatedParams {
+        project_root_path: fixture.root(),
+        limit: None,
+        offset: None,
+    });
+
+    let result = server.scan_untranslated(params).await.unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert_eq!(json_result["totalCount"], 1);
+    let item = &json_result["items"][0];
+    assert_eq!(item["key"], "common.submit");
+    assert_eq!(item["emptyIn"], json!(["zh"]));
+}
+
 #[tokio::test]
 async fn test_scan_untranslated_empty_and_identical() {
     let fixture = McpTestFixture::with_messages(vec![
@@ -493,3 +525,163 @@ async fn test_scan_overview_counts_empty_as_untranslated() {
     assert_eq!(affected.len(), 1);
     assert_eq!(affected[0], "zh");
 }
+
+// ============================================================================
+// glot_check tests
+// ============================================================================
+
+fn glot_check_params(
+    fixture: &McpTestFixture,
+    rules: Option<Vec<&str>>,
+    path: Option<&str>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+) -> Parameters<GlotCheckParams> {
+    Parameters(GlotCheckParams {
+        project_root_path: fixture.root(),
+        rules: rules.map(|rules| rules.into_iter().map(String::from).collect()),
+        path: path.map(String::from),
+        limit,
+        offset,
+    })
+}
+
+#[tokio::test]
+async fn test_glot_check_filters_rules() {
+    let fixture = fixture_with_type_mismatch().unwrap();
+    fixture
+        .write_tsx_file(
+            "components/page.tsx",
+            r#"
+        export default function Page() {
+            return <div>Hello world</div>;
+        }
+    "#,
+        )
+        .unwrap();
+    let server = GlotMcpServer::new();
+
+    let result = server
+        .glot_check(glot_check_params(
+            &fixture,
+            Some(vec!["type-mismatch"]),
+            None,
+            None,
+            None,
+        ))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert_eq!(json_result["totalCount"], 2);
+    assert_eq!(json_result["errorCount"], 2);
+    assert_eq!(json_result["warningCount"], 0);
+    let items = json_result["items"].as_array().unwrap();
+    assert!(items.iter().all(|item| item["rule"] == "type-mismatch"));
+    assert_eq!(items[0]["severity"], "error");
+    assert!(items[0]["filePath"].as_str().unwrap().ends_with("en.json"));
+    assert!(items[0]["line"].is_number());
+
+    // Without a filter, hardcoded text is reported too
+    let result = server
+        .glot_check(glot_check_params(&fixture, None, None, None, None))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+    let items = json_result["items"].as_array().unwrap();
+    assert!(items.iter().any(|item| item["rule"] == "hardcoded"));
+}
+
+#[tokio::test]
+async fn test_glot_check_filters_path() {
+    let fixture = McpTestFixture::with_messages(vec![("en", json!({}))]).unwrap();
+    fixture
+        .write_tsx_file(
+            "components/a.tsx",
+            "export const A = () => <div>First</div>;\n",
+        )
+        .unwrap();
+    fixture
+        .write_tsx_file(
+            "app/[locale]/b.tsx",
+            "export const B = () => <div>Second</div>;\n",
+        )
+        .unwrap();
+    let server = GlotMcpServer::new();
+
+    let result = server
+        .glot_check(glot_check_params(
+            &fixture,
+            Some(vec!["hardcoded"]),
+            Some("components"),
+            None,
+            None,
+        ))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+
+    assert_eq!(json_result["totalCount"], 1);
+    let item = &json_result["items"][0];
+    assert_eq!(item["message"], "First");
+    assert!(item["filePath"].as_str().unwrap().ends_with("a.tsx"));
+}
+
+#[tokio::test]
+async fn test_glot_check_pagination() {
+    let fixture = McpTestFixture::with_messages(vec![
+        ("en", json!({"a": "A", "b": "B", "c": "C"})),
+        ("zh", json!({})),
+    ])
+    .unwrap();
+    let server = GlotMcpServer::new();
+
+    let result = server
+        .glot_check(glot_check_params(
+            &fixture,
+            Some(vec!["replica-lag"]),
+            None,
+            Some(2),
+            Some(0),
+        ))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+    assert_eq!(json_result["totalCount"], 3);
+    assert_eq!(json_result["items"].as_array().unwrap().len(), 2);
+    assert_pagination(&json_result, 0, 2, true);
+
+    let result = server
+        .glot_check(glot_check_params(
+            &fixture,
+            Some(vec!["replica-lag"]),
+            None,
+            Some(2),
+            Some(2),
+        ))
+        .await
+        .unwrap();
+    let json_result = extract_tool_result_json(&result);
+    assert_eq!(json_result["items"].as_array().unwrap().len(), 1);
+    assert_pagination(&json_result, 2, 2, false);
+}
+
+#[tokio::test]
+async fn test_glot_check_unknown_rule() {
+    let fixture = McpTestFixture::with_messages(vec![("en", json!({}))]).unwrap();
+    let server = GlotMcpServer::new();
+
+    let error = server
+        .glot_check(glot_check_params(
+            &fixture,
+            Some(vec!["not-a-rule"]),
+            None,
+            None,
+            None,
+        ))
+        .await
+        .unwrap_err();
+
+    assert!(error.message.contains("Unknown rule \"not-a-rule\""));
+    assert!(error.message.contains("type-mismatch"));
+}